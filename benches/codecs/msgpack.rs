@@ -0,0 +1,114 @@
+use std::time::Duration;
+
+use bytes::BytesMut;
+use criterion::{
+    criterion_group, measurement::WallTime, BatchSize, BenchmarkGroup, Criterion, SamplingMode,
+    Throughput,
+};
+use tokio_util::codec::Encoder;
+use vector::event::{Event, LogEvent};
+use vector_lib::codecs::{
+    decoding::format::Deserializer, JsonDeserializerConfig, JsonSerializerConfig,
+    MsgPackDeserializerConfig, MsgPackSerializerConfig,
+};
+use vector_lib::{btreemap, config::LogNamespace};
+
+const EVENTS: usize = 10_000;
+
+fn events() -> Vec<Event> {
+    (0..EVENTS)
+        .map(|i| {
+            Event::Log(LogEvent::from(btreemap! {
+                "message" => format!("message number {}", i),
+                "host" => "localhost",
+                "status" => 200,
+                "duration_ms" => 12.5,
+                "success" => true,
+                "tags" => vec!["a", "b", "c"],
+            }))
+        })
+        .collect()
+}
+
+fn encode_all<E: Encoder<Event, Error = vector_lib::Error>>(
+    encoder: &mut E,
+    events: &[Event],
+) -> Vec<BytesMut> {
+    events
+        .iter()
+        .map(|event| {
+            let mut bytes = BytesMut::new();
+            encoder.encode(event.clone(), &mut bytes).unwrap();
+            bytes
+        })
+        .collect()
+}
+
+fn msgpack(c: &mut Criterion) {
+    let mut group: BenchmarkGroup<WallTime> = c.benchmark_group("msgpack");
+    group.sampling_mode(SamplingMode::Auto);
+    group.throughput(Throughput::Elements(EVENTS as u64));
+
+    let input = events();
+
+    group.bench_function("json/encode", |b| {
+        b.iter_batched(
+            || JsonSerializerConfig::default().build(),
+            |mut encoder| encode_all(&mut encoder, &input),
+            BatchSize::SmallInput,
+        )
+    });
+
+    group.bench_function("msgpack/encode", |b| {
+        b.iter_batched(
+            || MsgPackSerializerConfig::default().build(),
+            |mut encoder| encode_all(&mut encoder, &input),
+            BatchSize::SmallInput,
+        )
+    });
+
+    let json_frames = encode_all(&mut JsonSerializerConfig::default().build(), &input);
+    let msgpack_frames = encode_all(&mut MsgPackSerializerConfig::default().build(), &input);
+
+    group.bench_function("json/decode", |b| {
+        let deserializer = JsonDeserializerConfig::default().build();
+        b.iter_batched(
+            || json_frames.clone(),
+            |frames| {
+                for frame in frames {
+                    deserializer
+                        .parse(frame.freeze(), LogNamespace::Legacy)
+                        .unwrap();
+                }
+            },
+            BatchSize::SmallInput,
+        )
+    });
+
+    group.bench_function("msgpack/decode", |b| {
+        let deserializer = MsgPackDeserializerConfig.build();
+        b.iter_batched(
+            || msgpack_frames.clone(),
+            |frames| {
+                for frame in frames {
+                    deserializer
+                        .parse(frame.freeze(), LogNamespace::Legacy)
+                        .unwrap();
+                }
+            },
+            BatchSize::SmallInput,
+        )
+    });
+}
+
+criterion_group!(
+    name = benches;
+    config = Criterion::default()
+        .warm_up_time(Duration::from_secs(5))
+        .measurement_time(Duration::from_secs(30))
+        .noise_threshold(0.01)
+        .significance_level(0.05)
+        .confidence_level(0.95)
+        .sample_size(50);
+    targets = msgpack
+);
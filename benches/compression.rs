@@ -0,0 +1,84 @@
+use std::io::Write;
+
+use criterion::{criterion_group, BatchSize, Criterion, SamplingMode, Throughput};
+use vector::{
+    sinks::util::{buffer::compression::CompressionLevel, Compression, Compressor},
+    test_util::random_lines,
+};
+
+/// Compares gzip and zstd on a payload shaped like the JSON array sent by the `datadog_logs`
+/// sink, so that the compression ratio can be weighed against the CPU cost.
+fn benchmark_compression(c: &mut Criterion) {
+    let payload = datadog_logs_payload(1_000_000);
+
+    let mut group = c.benchmark_group("compression");
+    group.throughput(Throughput::Bytes(payload.len() as u64));
+    group.sampling_mode(SamplingMode::Flat);
+
+    let cases = [
+        ("gzip_default", Compression::gzip_default()),
+        ("zstd_3", Compression::Zstd(CompressionLevel::Val(3))),
+        ("zstd_9", Compression::Zstd(CompressionLevel::Val(9))),
+    ];
+
+    for (name, compression) in cases {
+        let compressed_len = compress(compression, &payload).len();
+        #[allow(clippy::print_stdout)]
+        {
+            println!(
+                "{}: {} bytes compressed to {} bytes ({:.1}%)",
+                name,
+                payload.len(),
+                compressed_len,
+                compressed_len as f64 * 100.0 / payload.len() as f64
+            );
+        }
+
+        group.bench_function(name, |b| {
+            b.iter_batched(
+                || payload.clone(),
+                |payload| compress(compression, &payload),
+                BatchSize::LargeInput,
+            )
+        });
+    }
+
+    group.finish();
+}
+
+fn compress(compression: Compression, payload: &[u8]) -> bytes::Bytes {
+    let mut compressor = Compressor::from(compression);
+    compressor.write_all(payload).unwrap();
+    compressor.finish().unwrap().freeze()
+}
+
+/// Builds a JSON array of log events of roughly `size` bytes.
+fn datadog_logs_payload(size: usize) -> Vec<u8> {
+    let mut payload = vec![b'['];
+    for (i, message) in random_lines(100).enumerate() {
+        if payload.len() >= size {
+            break;
+        }
+        if i > 0 {
+            payload.push(b',');
+        }
+        let event = serde_json::json!({
+            "message": message,
+            "hostname": format!("host-{}", i % 16),
+            "service": "checkout",
+            "ddsource": "vector",
+            "status": "info",
+            "ddtags": "env:prod,team:payments",
+            "timestamp": 1_700_000_000_000_i64 + i as i64,
+        });
+        serde_json::to_writer(&mut payload, &event).unwrap();
+    }
+    payload.push(b']');
+    payload
+}
+
+criterion_group!(
+    name = benches;
+    config = Criterion::default().noise_threshold(0.05);
+    targets = benchmark_compression
+);
@@ -0,0 +1,131 @@
+use std::collections::VecDeque;
+
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion, Throughput};
+use vector::{
+    event::{Event, LogEvent},
+    sinks::datadog::logs::sink::{serialize_with_capacity, serialize_with_exact_capacity},
+};
+use vector_lib::{json_size::JsonSize, EstimatedJsonEncodedSizeOf};
+
+const MAX_PAYLOAD_BYTES: usize = 5_000_000;
+
+/// Builds events whose messages are mostly multi-byte Unicode, where the estimated JSON size of an
+/// event is furthest from its serialized size.
+fn events(count: usize) -> VecDeque<(Event, JsonSize)> {
+    (0..count)
+        .map(|i| {
+            let message = format!("{} 日本語のログメッセージ 👋🌍 журнал событий", i).repeat(8);
+            let event = Event::Log(LogEvent::from(message));
+            let size = event.estimated_json_encoded_size_of();
+            (event, size)
+        })
+        .collect()
+}
+
+fn benchmark_serialize(c: &mut Criterion) {
+    let input = events(1_000);
+    let payload_len =
+        serialize_with_capacity(&mut input.clone(), MAX_PAYLOAD_BYTES, false, false, None)
+            .unwrap()
+            .1
+            .len();
+
+    let mut group = c.benchmark_group("datadog_logs/serialize");
+    group.throughput(Throughput::Bytes(payload_len as u64));
+
+    group.bench_function("estimated", |b| {
+        b.iter_batched(
+            || input.clone(),
+            |mut events| {
+                serialize_with_capacity(&mut events, MAX_PAYLOAD_BYTES, false, false, None).unwrap()
+            },
+            BatchSize::SmallInput,
+        )
+    });
+
+    group.bench_function("exact", |b| {
+        let mut scratch = Vec::new();
+        b.iter_batched(
+            || input.clone(),
+            |mut events| {
+                serialize_with_exact_capacity(
+                    &mut events,
+                    MAX_PAYLOAD_BYTES,
+                    false,
+                    false,
+                    None,
+                    &mut scratch,
+                )
+                .unwrap()
+            },
+            BatchSize::SmallInput,
+        )
+    });
+
+    group.finish();
+}
+
+/// Builds a batch of events shaped like those received from the Datadog Agent, with messages
+/// alternating between ASCII and Unicode.
+fn agent_events(count: usize) -> Vec<LogEvent> {
+    (0..count)
+        .map(|i| {
+            let mut log = LogEvent::default();
+            let message = if i % 2 == 0 {
+                format!("GET /api/v1/orders/{i} 200 12ms user_agent=\"curl/8.5.0\"")
+            } else {
+                format!("注文 {i} の処理に失敗しました 👋 ошибка обработки заказа")
+            };
+            log.insert("message", message);
+            log.insert("status", if i % 10 == 0 { "error" } else { "info" });
+            log.insert("timestamp", 1_705_314_600_000_i64 + i as i64);
+            log.insert("hostname", format!("web-{:02}.eu-west-1.internal", i % 16));
+            log.insert("service", "orders");
+            log.insert("ddsource", "nginx");
+            log.insert("ddtags", "env:production,team:checkout,version:1.42.0");
+            log.insert("http.method", "GET");
+            log.insert("http.status_code", 200);
+            log.insert("usr.name", if i % 2 == 0 { "alice" } else { "zoë" });
+            log
+        })
+        .collect()
+}
+
+fn benchmark_json_writer(c: &mut Criterion) {
+    let input = agent_events(10_000);
+    let mut payload = Vec::new();
+    for log in &input {
+        serde_json::to_writer(&mut payload, log).unwrap();
+    }
+
+    let mut group = c.benchmark_group("datadog_logs/json_writer");
+    group.throughput(Throughput::Bytes(payload.len() as u64));
+
+    group.bench_function("serde_json", |b| {
+        let mut buf = Vec::with_capacity(payload.len());
+        b.iter(|| {
+            buf.clear();
+            for log in &input {
+                serde_json::to_writer(&mut buf, log).unwrap();
+            }
+        })
+    });
+
+    // Only built with `--features simd-json-serialize`, which also makes `serialize_with_capacity`
+    // use `simd-json`.
+    #[cfg(feature = "simd-json-serialize")]
+    group.bench_function("simd_json", |b| {
+        let mut buf = Vec::with_capacity(payload.len());
+        b.iter(|| {
+            buf.clear();
+            for log in &input {
+                simd_json::to_writer(&mut buf, log).unwrap();
+            }
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, benchmark_serialize, benchmark_json_writer);
+criterion_main!(benches);
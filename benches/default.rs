@@ -1,6 +1,7 @@
 use criterion::criterion_main;
 
 mod batch;
+mod compression;
 mod event;
 mod files;
 mod http;
@@ -10,6 +11,7 @@ mod template;
 
 criterion_main!(
     batch::benches,
+    compression::benches,
     event::benches,
     files::benches,
     http::benches,
@@ -1,3 +1,4 @@
+use serde::{de, Deserialize, Deserializer};
 use tokio_util::codec::LengthDelimitedCodec;
 use vector_config::configurable_component;
 
@@ -9,8 +10,11 @@ pub struct LengthDelimitedCoderOptions {
     #[serde(default = "default_max_frame_length")]
     pub max_frame_length: usize,
 
-    /// Number of bytes representing the field length
-    #[serde(default = "default_length_field_length")]
+    /// Number of bytes representing the field length, from 1 to 8
+    #[serde(
+        default = "default_length_field_length",
+        deserialize_with = "deserialize_length_field_length"
+    )]
     pub length_field_length: usize,
 
     /// Number of bytes in the header before the length field
@@ -30,6 +34,21 @@ const fn default_length_field_length() -> usize {
     4
 }
 
+fn deserialize_length_field_length<'de, D>(deserializer: D) -> Result<usize, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let length = usize::deserialize(deserializer)?;
+    if (1..=8).contains(&length) {
+        Ok(length)
+    } else {
+        Err(de::Error::invalid_value(
+            de::Unexpected::Unsigned(length as u64),
+            &"a length from 1 to 8",
+        ))
+    }
+}
+
 const fn default_length_field_offset() -> usize {
     0
 }
@@ -64,3 +83,29 @@ impl LengthDelimitedCoderOptions {
         builder.new_codec()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_unsupported_length_field_length() {
+        for length in 1..=8 {
+            let options: LengthDelimitedCoderOptions =
+                serde_json::from_str(&format!(r#"{{ "length_field_length": {length} }}"#)).unwrap();
+            assert_eq!(options.length_field_length, length);
+            options.build_codec();
+        }
+
+        for length in [0, 9] {
+            let error = serde_json::from_str::<LengthDelimitedCoderOptions>(&format!(
+                r#"{{ "length_field_length": {length} }}"#
+            ))
+            .unwrap_err();
+            assert!(
+                error.to_string().contains("expected a length from 1 to 8"),
+                "{error}"
+            );
+        }
+    }
+}
@@ -8,6 +8,7 @@ mod bytes;
 mod gelf;
 mod influxdb;
 mod json;
+mod msgpack;
 mod native;
 mod native_json;
 mod protobuf;
@@ -21,6 +22,7 @@ use dyn_clone::DynClone;
 pub use gelf::{GelfDeserializer, GelfDeserializerConfig, GelfDeserializerOptions};
 pub use influxdb::{InfluxdbDeserializer, InfluxdbDeserializerConfig};
 pub use json::{JsonDeserializer, JsonDeserializerConfig, JsonDeserializerOptions};
+pub use msgpack::{MsgPackDeserializer, MsgPackDeserializerConfig};
 pub use native::{NativeDeserializer, NativeDeserializerConfig};
 pub use native_json::{
     NativeJsonDeserializer, NativeJsonDeserializerConfig, NativeJsonDeserializerOptions,
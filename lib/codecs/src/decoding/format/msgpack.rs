@@ -0,0 +1,287 @@
+use bytes::Bytes;
+use chrono::{DateTime, Utc};
+use ordered_float::NotNan;
+use rmpv::Value as MsgPackValue;
+use smallvec::{smallvec, SmallVec};
+use vector_config::configurable_component;
+use vector_core::{
+    config::{log_schema, DataType, LogNamespace},
+    event::{Event, LogEvent, ObjectMap, Value},
+    schema,
+};
+use vrl::value::Kind;
+
+use super::Deserializer;
+
+/// The MessagePack extension type reserved for timestamps.
+///
+/// See <https://github.com/msgpack/msgpack/blob/master/spec.md#timestamp-extension-type>.
+const MSGPACK_TIMESTAMP_EXT_TYPE: i8 = -1;
+
+/// Config used to build a `MsgPackDeserializer`.
+#[configurable_component]
+#[derive(Debug, Clone, Default)]
+pub struct MsgPackDeserializerConfig;
+
+impl MsgPackDeserializerConfig {
+    /// Build the `MsgPackDeserializer` from this configuration.
+    pub const fn build(&self) -> MsgPackDeserializer {
+        MsgPackDeserializer
+    }
+
+    /// Return the type of event build by this deserializer.
+    pub fn output_type(&self) -> DataType {
+        DataType::Log
+    }
+
+    /// The schema produced by the deserializer.
+    pub fn schema_definition(&self, log_namespace: LogNamespace) -> schema::Definition {
+        let kind = Kind::json().or_timestamp();
+        match log_namespace {
+            LogNamespace::Legacy => {
+                let mut definition =
+                    schema::Definition::empty_legacy_namespace().unknown_fields(kind.clone());
+
+                if let Some(timestamp_key) = log_schema().timestamp_key() {
+                    definition = definition.try_with_field(timestamp_key, kind, Some("timestamp"));
+                }
+                definition
+            }
+            LogNamespace::Vector => {
+                schema::Definition::new_with_default_metadata(kind, [log_namespace])
+            }
+        }
+    }
+}
+
+/// Deserializer that builds `Event`s from a byte frame containing [MessagePack][msgpack].
+///
+/// This reverses the mapping done by the `MsgPackSerializer`: `str` and `bin` are both decoded
+/// as bytes, and the timestamp extension type (`-1`) is decoded as a timestamp. A frame holding
+/// a top-level array is split into one event per element, as is done for JSON.
+///
+/// [msgpack]: https://msgpack.org/
+#[derive(Debug, Clone, Default)]
+pub struct MsgPackDeserializer;
+
+impl Deserializer for MsgPackDeserializer {
+    fn parse(
+        &self,
+        bytes: Bytes,
+        log_namespace: LogNamespace,
+    ) -> vector_common::Result<SmallVec<[Event; 1]>> {
+        if bytes.is_empty() {
+            return Ok(smallvec![]);
+        }
+
+        let mut reader = bytes.as_ref();
+        let value = rmpv::decode::read_value(&mut reader)
+            .map_err(|error| format!("Error parsing MessagePack: {}", error))?;
+        if !reader.is_empty() {
+            return Err(format!(
+                "Error parsing MessagePack: {} trailing bytes after value",
+                reader.len()
+            )
+            .into());
+        }
+
+        let mut events = match value {
+            MsgPackValue::Array(values) => values
+                .into_iter()
+                .map(|value| to_event(value, log_namespace))
+                .collect::<Result<SmallVec<[Event; 1]>, _>>()?,
+            value => smallvec![to_event(value, log_namespace)?],
+        };
+
+        if log_namespace == LogNamespace::Legacy {
+            let timestamp = Utc::now();
+
+            if let Some(timestamp_key) = log_schema().timestamp_key_target_path() {
+                for event in &mut events {
+                    let log = event.as_mut_log();
+                    if !log.contains(timestamp_key) {
+                        log.insert(timestamp_key, timestamp);
+                    }
+                }
+            }
+        }
+
+        Ok(events)
+    }
+}
+
+fn to_event(value: MsgPackValue, log_namespace: LogNamespace) -> vector_common::Result<Event> {
+    let value = to_value(value)?;
+    match log_namespace {
+        LogNamespace::Vector => Ok(LogEvent::from(value).into()),
+        LogNamespace::Legacy => match value {
+            Value::Object(fields) => Ok(LogEvent::from(fields).into()),
+            _ => Err("Attempted to convert non-map MessagePack into an Event.".into()),
+        },
+    }
+}
+
+fn to_value(value: MsgPackValue) -> vector_common::Result<Value> {
+    Ok(match value {
+        MsgPackValue::Nil => Value::Null,
+        MsgPackValue::Boolean(boolean) => Value::Boolean(boolean),
+        MsgPackValue::Integer(integer) => match integer.as_i64() {
+            Some(integer) => Value::Integer(integer),
+            // Unsigned integers above `i64::MAX` can't be represented as a `Value::Integer`.
+            None => float_value(integer.as_f64().unwrap_or(f64::NAN))?,
+        },
+        MsgPackValue::F32(float) => float_value(f64::from(float))?,
+        MsgPackValue::F64(float) => float_value(float)?,
+        MsgPackValue::String(string) => Value::Bytes(string.into_bytes().into()),
+        MsgPackValue::Binary(bytes) => Value::Bytes(bytes.into()),
+        MsgPackValue::Array(values) => Value::Array(
+            values
+                .into_iter()
+                .map(to_value)
+                .collect::<Result<Vec<_>, _>>()?,
+        ),
+        MsgPackValue::Map(entries) => Value::Object(
+            entries
+                .into_iter()
+                .map(|(key, value)| Ok((to_key(key)?.into(), to_value(value)?)))
+                .collect::<vector_common::Result<ObjectMap>>()?,
+        ),
+        MsgPackValue::Ext(MSGPACK_TIMESTAMP_EXT_TYPE, data) => {
+            Value::Timestamp(read_timestamp(&data)?)
+        }
+        MsgPackValue::Ext(type_id, _) => {
+            return Err(format!("Unsupported MessagePack extension type {}.", type_id).into())
+        }
+    })
+}
+
+fn to_key(key: MsgPackValue) -> vector_common::Result<String> {
+    match key {
+        MsgPackValue::String(string) => Ok(String::from_utf8_lossy(string.as_bytes()).into_owned()),
+        MsgPackValue::Binary(bytes) => Ok(String::from_utf8_lossy(&bytes).into_owned()),
+        MsgPackValue::Integer(integer) => Ok(integer.to_string()),
+        other => Err(format!("Unsupported MessagePack map key: {}.", other).into()),
+    }
+}
+
+fn float_value(float: f64) -> vector_common::Result<Value> {
+    NotNan::new(float)
+        .map(Value::Float)
+        .map_err(|_| "NaN is not a supported float value.".into())
+}
+
+/// Reads a timestamp from any of the 32, 64 and 96-bit timestamp extension formats.
+fn read_timestamp(data: &[u8]) -> vector_common::Result<DateTime<Utc>> {
+    let (seconds, nanos) = match data.len() {
+        4 => (
+            i64::from(u32::from_be_bytes(data.try_into().expect("length checked"))),
+            0,
+        ),
+        8 => {
+            let value = u64::from_be_bytes(data.try_into().expect("length checked"));
+            ((value & 0x0000_0003_ffff_ffff) as i64, (value >> 34) as u32)
+        }
+        12 => (
+            i64::from_be_bytes(data[4..].try_into().expect("length checked")),
+            u32::from_be_bytes(data[..4].try_into().expect("length checked")),
+        ),
+        length => return Err(format!("Invalid MessagePack timestamp length {}.", length).into()),
+    };
+
+    DateTime::from_timestamp(seconds, nanos)
+        .ok_or_else(|| "MessagePack timestamp is out of range.".into())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use tokio_util::codec::Encoder;
+    use vrl::btreemap;
+
+    use super::*;
+    use crate::encoding::{MsgPackSerializerConfig, MsgPackSerializerOptions};
+
+    fn round_trip(value: Value, compact_integers: bool) -> Value {
+        let mut serializer =
+            MsgPackSerializerConfig::new(MsgPackSerializerOptions { compact_integers }).build();
+        let mut bytes = bytes::BytesMut::new();
+        serializer
+            .encode(Event::Log(LogEvent::from(value)), &mut bytes)
+            .unwrap();
+
+        let mut events = MsgPackDeserializerConfig
+            .build()
+            .parse(bytes.freeze(), LogNamespace::Vector)
+            .unwrap();
+        assert_eq!(events.len(), 1);
+        events.remove(0).into_log().value().clone()
+    }
+
+    #[test]
+    fn round_trips_all_value_variants() {
+        let value = Value::from(btreemap! {
+            "bytes" => Value::from("hello"),
+            "binary" => Value::Bytes(vec![0xde, 0xad, 0xbe, 0xef].into()),
+            "integer" => Value::Integer(-42),
+            "big_integer" => Value::Integer(i64::MAX),
+            "small_integer" => Value::Integer(i64::MIN),
+            "float" => Value::from(1.5),
+            "boolean" => Value::Boolean(true),
+            "timestamp_32" => Utc.timestamp_opt(1_700_000_000, 0).unwrap(),
+            "timestamp_64" => Utc.timestamp_opt(1_700_000_000, 123_456_789).unwrap(),
+            "timestamp_96" => Utc.timestamp_opt(-1, 5).unwrap(),
+            "object" => Value::from(btreemap! { "nested" => Value::from("value") }),
+            "array" => Value::Array(vec![Value::from(1), Value::from("two"), Value::Null]),
+            "null" => Value::Null,
+        });
+
+        assert_eq!(round_trip(value.clone(), true), value);
+        assert_eq!(round_trip(value.clone(), false), value);
+    }
+
+    #[test]
+    fn parses_top_level_arrays() {
+        // [{"a": 1}, {"b": 2}]
+        let input = Bytes::from_static(&[0x92, 0x81, 0xa1, b'a', 0x01, 0x81, 0xa1, b'b', 0x02]);
+
+        let events = MsgPackDeserializer
+            .parse(input, LogNamespace::Legacy)
+            .unwrap();
+
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].as_log()["a"], 1.into());
+        assert_eq!(events[1].as_log()["b"], 2.into());
+        assert!(events[0]
+            .as_log()
+            .get((
+                vrl::path::PathPrefix::Event,
+                log_schema().timestamp_key().unwrap()
+            ))
+            .is_some());
+    }
+
+    #[test]
+    fn rejects_non_map_in_legacy_namespace() {
+        // fixstr("a")
+        let input = Bytes::from_static(&[0xa1, b'a']);
+        assert!(MsgPackDeserializer
+            .parse(input, LogNamespace::Legacy)
+            .is_err());
+    }
+
+    #[test]
+    fn rejects_trailing_bytes() {
+        let input = Bytes::from_static(&[0x80, 0x80]);
+        assert!(MsgPackDeserializer
+            .parse(input, LogNamespace::Vector)
+            .is_err());
+    }
+
+    #[test]
+    fn skips_empty_frames() {
+        let events = MsgPackDeserializer
+            .parse(Bytes::new(), LogNamespace::Vector)
+            .unwrap();
+        assert!(events.is_empty());
+    }
+}
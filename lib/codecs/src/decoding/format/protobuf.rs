@@ -1,9 +1,9 @@
 use std::path::PathBuf;
 
 use bytes::Bytes;
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use derivative::Derivative;
-use prost_reflect::{DynamicMessage, MessageDescriptor};
+use prost_reflect::{DynamicMessage, FieldDescriptor, MapKey, MessageDescriptor};
 use smallvec::{smallvec, SmallVec};
 use vector_config::configurable_component;
 use vector_core::event::LogEvent;
@@ -12,7 +12,7 @@ use vector_core::{
     event::Event,
     schema,
 };
-use vrl::value::Kind;
+use vrl::value::{Kind, ObjectMap, Value};
 
 use super::Deserializer;
 
@@ -79,6 +79,8 @@ pub struct ProtobufDeserializerOptions {
 }
 
 /// Deserializer that builds `Event`s from a byte frame containing protobuf.
+///
+/// Fields of type `google.protobuf.Timestamp` are decoded as timestamps.
 #[derive(Debug, Clone)]
 pub struct ProtobufDeserializer {
     message_descriptor: MessageDescriptor,
@@ -100,8 +102,7 @@ impl Deserializer for ProtobufDeserializer {
         let dynamic_message = DynamicMessage::decode(self.message_descriptor.clone(), bytes)
             .map_err(|error| format!("Error parsing protobuf: {:?}", error))?;
 
-        let proto_vrl =
-            vrl::protobuf::proto_to_value(&prost_reflect::Value::Message(dynamic_message), None)?;
+        let proto_vrl = to_value(&prost_reflect::Value::Message(dynamic_message), None)?;
         let mut event = Event::Log(LogEvent::from(proto_vrl));
         let event = match log_namespace {
             LogNamespace::Vector => event,
@@ -121,6 +122,65 @@ impl Deserializer for ProtobufDeserializer {
     }
 }
 
+const TIMESTAMP_MESSAGE: &str = "google.protobuf.Timestamp";
+
+/// Converts a protobuf value into a VRL value, converting `google.protobuf.Timestamp` messages,
+/// including those nested in messages, repeated fields, and maps, into timestamps.
+fn to_value(
+    value: &prost_reflect::Value,
+    field: Option<&FieldDescriptor>,
+) -> Result<Value, String> {
+    match value {
+        prost_reflect::Value::Message(message)
+            if message.descriptor().full_name() == TIMESTAMP_MESSAGE =>
+        {
+            timestamp(message)
+                .map(Value::Timestamp)
+                .ok_or_else(|| format!("Invalid {TIMESTAMP_MESSAGE}: {message:?}"))
+        }
+        prost_reflect::Value::Message(message) => message
+            .fields()
+            .map(|(field, value)| Ok((field.name().into(), to_value(value, Some(&field))?)))
+            .collect::<Result<ObjectMap, String>>()
+            .map(Value::Object),
+        prost_reflect::Value::List(values) => values
+            .iter()
+            .map(|value| to_value(value, field))
+            .collect::<Result<Vec<_>, String>>()
+            .map(Value::Array),
+        prost_reflect::Value::Map(map) => {
+            let value_field = field
+                .and_then(|field| field.kind().as_message().cloned())
+                .map(|entry| entry.map_entry_value_field());
+            map.iter()
+                .map(|(key, value)| {
+                    let value = to_value(value, value_field.as_ref())?;
+                    Ok((map_key(key).into(), value))
+                })
+                .collect::<Result<ObjectMap, String>>()
+                .map(Value::Object)
+        }
+        _ => vrl::protobuf::proto_to_value(value, field),
+    }
+}
+
+fn timestamp(message: &DynamicMessage) -> Option<DateTime<Utc>> {
+    let seconds = message.get_field_by_name("seconds")?.as_i64()?;
+    let nanos = message.get_field_by_name("nanos")?.as_i32()?;
+    DateTime::from_timestamp(seconds, u32::try_from(nanos).ok()?)
+}
+
+fn map_key(key: &MapKey) -> String {
+    match key {
+        MapKey::Bool(key) => key.to_string(),
+        MapKey::I32(key) => key.to_string(),
+        MapKey::I64(key) => key.to_string(),
+        MapKey::U32(key) => key.to_string(),
+        MapKey::U64(key) => key.to_string(),
+        MapKey::String(key) => key.clone(),
+    }
+}
+
 impl TryFrom<&ProtobufDeserializerConfig> for ProtobufDeserializer {
     type Error = vector_common::Error;
     fn try_from(config: &ProtobufDeserializerConfig) -> vector_common::Result<Self> {
@@ -247,6 +307,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn deserialize_timestamps() {
+        use prost_reflect::{prost_types, ReflectMessage};
+
+        let timestamp = prost_types::Timestamp {
+            seconds: 1_700_000_000,
+            nanos: 500,
+        };
+        let expected = Value::Timestamp(DateTime::from_timestamp(1_700_000_000, 500).unwrap());
+
+        let message = prost_reflect::Value::Message(timestamp.transcode_to_dynamic());
+        assert_eq!(to_value(&message, None).unwrap(), expected);
+
+        let list = prost_reflect::Value::List(vec![message]);
+        assert_eq!(to_value(&list, None).unwrap(), Value::Array(vec![expected]));
+    }
+
     #[test]
     fn deserialize_error_invalid_protobuf() {
         let input = Bytes::from("{ foo");
@@ -0,0 +1,250 @@
+use bytes::{BufMut, BytesMut};
+use chrono::{DateTime, Utc};
+use derivative::Derivative;
+use rmp::encode;
+use tokio_util::codec::Encoder;
+use vector_config::configurable_component;
+use vector_core::{
+    config::DataType,
+    event::{Event, Value},
+    schema,
+};
+
+/// The MessagePack extension type reserved for timestamps.
+///
+/// See <https://github.com/msgpack/msgpack/blob/master/spec.md#timestamp-extension-type>.
+pub(crate) const MSGPACK_TIMESTAMP_EXT_TYPE: i8 = -1;
+
+/// Config used to build a `MsgPackSerializer`.
+#[configurable_component]
+#[derive(Debug, Clone, Default)]
+pub struct MsgPackSerializerConfig {
+    /// MessagePack-specific encoding options.
+    #[serde(default, skip_serializing_if = "vector_core::serde::is_default")]
+    pub msgpack: MsgPackSerializerOptions,
+}
+
+impl MsgPackSerializerConfig {
+    /// Creates a new `MsgPackSerializerConfig`.
+    pub const fn new(options: MsgPackSerializerOptions) -> Self {
+        Self { msgpack: options }
+    }
+
+    /// Build the `MsgPackSerializer` from this configuration.
+    pub const fn build(&self) -> MsgPackSerializer {
+        MsgPackSerializer::new(self.msgpack.compact_integers)
+    }
+
+    /// The data type of events that are accepted by `MsgPackSerializer`.
+    pub fn input_type(&self) -> DataType {
+        DataType::Log
+    }
+
+    /// The schema required by the serializer.
+    pub fn schema_requirement(&self) -> schema::Requirement {
+        // While technically we support `Value` variants that can't be losslessly serialized to
+        // MessagePack, we don't want to enforce that limitation to users yet.
+        schema::Requirement::empty()
+    }
+}
+
+/// MessagePack-specific encoding options.
+#[configurable_component]
+#[derive(Debug, Clone, PartialEq, Eq, Derivative)]
+#[derivative(Default)]
+pub struct MsgPackSerializerOptions {
+    /// Whether to encode integers using the smallest MessagePack integer type that fits the value.
+    ///
+    /// When disabled, all integers are encoded as signed 64-bit integers.
+    #[serde(
+        default = "default_compact_integers",
+        skip_serializing_if = "vector_core::serde::is_default"
+    )]
+    #[derivative(Default(value = "default_compact_integers()"))]
+    pub compact_integers: bool,
+}
+
+const fn default_compact_integers() -> bool {
+    true
+}
+
+/// Serializer that converts an `Event` to bytes using the [MessagePack][msgpack] format.
+///
+/// Values are mapped to MessagePack types as follows:
+///
+/// - strings that are valid UTF-8 are encoded as `str`, any other bytes as `bin`
+/// - integers are encoded as `int`, using the smallest type that fits when `compact_integers` is
+///   enabled
+/// - timestamps are encoded with the timestamp extension type (`-1`)
+/// - regular expressions are encoded as `str` containing the pattern
+///
+/// [msgpack]: https://msgpack.org/
+#[derive(Debug, Clone)]
+pub struct MsgPackSerializer {
+    compact_integers: bool,
+}
+
+impl MsgPackSerializer {
+    /// Creates a new `MsgPackSerializer`.
+    pub const fn new(compact_integers: bool) -> Self {
+        Self { compact_integers }
+    }
+
+    fn write_value<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        value: &Value,
+    ) -> Result<(), vector_common::Error> {
+        match value {
+            Value::Bytes(bytes) => match std::str::from_utf8(bytes) {
+                Ok(string) => encode::write_str(writer, string)?,
+                Err(_) => encode::write_bin(writer, bytes)?,
+            },
+            Value::Regex(regex) => encode::write_str(writer, regex.as_str())?,
+            Value::Integer(integer) => {
+                if self.compact_integers {
+                    encode::write_sint(writer, *integer)?;
+                } else {
+                    encode::write_i64(writer, *integer)?;
+                }
+            }
+            Value::Float(float) => encode::write_f64(writer, float.into_inner())?,
+            Value::Boolean(boolean) => encode::write_bool(writer, *boolean)?,
+            Value::Timestamp(timestamp) => write_timestamp(writer, timestamp)?,
+            Value::Object(object) => {
+                encode::write_map_len(writer, object.len() as u32)?;
+                for (key, value) in object {
+                    encode::write_str(writer, key.as_str())?;
+                    self.write_value(writer, value)?;
+                }
+            }
+            Value::Array(array) => {
+                encode::write_array_len(writer, array.len() as u32)?;
+                for value in array {
+                    self.write_value(writer, value)?;
+                }
+            }
+            Value::Null => encode::write_nil(writer)?,
+        }
+        Ok(())
+    }
+}
+
+/// Writes a timestamp using the most compact of the 32, 64 and 96-bit timestamp extension formats.
+fn write_timestamp<W: std::io::Write>(
+    writer: &mut W,
+    timestamp: &DateTime<Utc>,
+) -> Result<(), vector_common::Error> {
+    let seconds = timestamp.timestamp();
+    let nanos = timestamp.timestamp_subsec_nanos();
+
+    if seconds >> 34 == 0 {
+        let data = (u64::from(nanos) << 34) | seconds as u64;
+        if data & 0xffff_ffff_0000_0000 == 0 {
+            encode::write_ext_meta(writer, 4, MSGPACK_TIMESTAMP_EXT_TYPE)?;
+            writer.write_all(&(data as u32).to_be_bytes())?;
+        } else {
+            encode::write_ext_meta(writer, 8, MSGPACK_TIMESTAMP_EXT_TYPE)?;
+            writer.write_all(&data.to_be_bytes())?;
+        }
+    } else {
+        encode::write_ext_meta(writer, 12, MSGPACK_TIMESTAMP_EXT_TYPE)?;
+        writer.write_all(&nanos.to_be_bytes())?;
+        writer.write_all(&seconds.to_be_bytes())?;
+    }
+    Ok(())
+}
+
+impl Encoder<Event> for MsgPackSerializer {
+    type Error = vector_common::Error;
+
+    fn encode(&mut self, event: Event, buffer: &mut BytesMut) -> Result<(), Self::Error> {
+        let log = event.into_log();
+        let mut writer = buffer.writer();
+        self.write_value(&mut writer, log.value())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::BytesMut;
+    use chrono::TimeZone;
+    use vector_core::event::LogEvent;
+    use vrl::btreemap;
+
+    use super::*;
+
+    fn encode(serializer: &mut MsgPackSerializer, value: Value) -> BytesMut {
+        let mut bytes = BytesMut::new();
+        serializer
+            .encode(Event::Log(LogEvent::from(value)), &mut bytes)
+            .unwrap();
+        bytes
+    }
+
+    #[test]
+    fn serialize_map() {
+        let mut serializer = MsgPackSerializerConfig::default().build();
+        let bytes = encode(
+            &mut serializer,
+            Value::from(btreemap! {
+                "foo" => Value::from("bar")
+            }),
+        );
+
+        // fixmap(1), fixstr("foo"), fixstr("bar")
+        assert_eq!(
+            &bytes[..],
+            &[0x81, 0xa3, b'f', b'o', b'o', 0xa3, b'b', b'a', b'r']
+        );
+    }
+
+    #[test]
+    fn serialize_compact_integers() {
+        let mut serializer = MsgPackSerializerConfig::default().build();
+        let bytes = encode(&mut serializer, Value::from(btreemap! { "a" => 1 }));
+
+        // positive fixint
+        assert_eq!(&bytes[..], &[0x81, 0xa1, b'a', 0x01]);
+    }
+
+    #[test]
+    fn serialize_wide_integers() {
+        let mut serializer = MsgPackSerializerConfig::new(MsgPackSerializerOptions {
+            compact_integers: false,
+        })
+        .build();
+        let bytes = encode(&mut serializer, Value::from(btreemap! { "a" => 1 }));
+
+        // int 64
+        assert_eq!(
+            &bytes[..],
+            &[0x81, 0xa1, b'a', 0xd3, 0, 0, 0, 0, 0, 0, 0, 0x01]
+        );
+    }
+
+    #[test]
+    fn serialize_binary() {
+        let mut serializer = MsgPackSerializerConfig::default().build();
+        let bytes = encode(
+            &mut serializer,
+            Value::from(btreemap! { "a" => Value::Bytes(vec![0xff, 0xfe].into()) }),
+        );
+
+        // bin 8
+        assert_eq!(&bytes[..], &[0x81, 0xa1, b'a', 0xc4, 0x02, 0xff, 0xfe]);
+    }
+
+    #[test]
+    fn serialize_timestamp() {
+        let mut serializer = MsgPackSerializerConfig::default().build();
+        let timestamp = Utc.timestamp_opt(1, 0).unwrap();
+        let bytes = encode(&mut serializer, Value::from(btreemap! { "t" => timestamp }));
+
+        // fixext 4 with the timestamp extension type
+        assert_eq!(
+            &bytes[..],
+            &[0x81, 0xa1, b't', 0xd6, 0xff, 0x00, 0x00, 0x00, 0x01]
+        );
+    }
+}
@@ -1,3 +1,26 @@
+use std::sync::OnceLock;
+
+use prost_reflect::DescriptorPool;
+
+/// The fully qualified name of the request message of the logs service.
+pub const LOGS_REQUEST_MESSAGE_TYPE: &str =
+    "opentelemetry.proto.collector.logs.v1.ExportLogsServiceRequest";
+
+/// The fully qualified name of the response message of the logs service.
+pub const LOGS_RESPONSE_MESSAGE_TYPE: &str =
+    "opentelemetry.proto.collector.logs.v1.ExportLogsServiceResponse";
+
+/// Returns the descriptors of the OpenTelemetry protocol messages.
+pub fn protobuf_descriptors() -> &'static DescriptorPool {
+    static PROTOBUF_FDS: OnceLock<DescriptorPool> = OnceLock::new();
+    PROTOBUF_FDS.get_or_init(|| {
+        DescriptorPool::decode(
+            include_bytes!(concat!(env!("OUT_DIR"), "/opentelemetry-fds.bin")).as_ref(),
+        )
+        .expect("should not fail to decode file descriptor set generated from build script")
+    })
+}
+
 /// Service stub and clients.
 pub mod collector {
     pub mod trace {
@@ -0,0 +1,39 @@
+//! Ingest mutations, for writing events directly into a sink.
+
+use graphql_client::GraphQLQuery;
+
+/// IngestEventsMutation writes JSON-encoded events into the input buffer of a sink, returning
+/// the number of events accepted and rejected.
+#[derive(GraphQLQuery, Debug, Copy, Clone)]
+#[graphql(
+    schema_path = "graphql/schema.json",
+    query_path = "graphql/mutations/ingest_events.graphql",
+    response_derives = "Debug"
+)]
+pub struct IngestEventsMutation;
+
+/// Extension methods for ingest mutations.
+pub trait IngestMutationExt {
+    /// Executes an ingest mutation, writing `events` into the sink identified by `sink_id`.
+    async fn ingest_events_mutation(
+        &self,
+        sink_id: String,
+        events: Vec<String>,
+    ) -> crate::QueryResult<IngestEventsMutation>;
+}
+
+impl IngestMutationExt for crate::Client {
+    /// Executes an ingest mutation, writing `events` into the sink identified by `sink_id`.
+    async fn ingest_events_mutation(
+        &self,
+        sink_id: String,
+        events: Vec<String>,
+    ) -> crate::QueryResult<IngestEventsMutation> {
+        let request_body = IngestEventsMutation::build_query(ingest_events_mutation::Variables {
+            sink_id,
+            events,
+        });
+
+        self.query::<IngestEventsMutation>(&request_body).await
+    }
+}
@@ -2,12 +2,14 @@
 
 mod components;
 mod health;
+mod ingest;
 mod meta;
 mod metrics;
 mod tap;
 
 pub use components::*;
 pub use health::*;
+pub use ingest::*;
 pub use metrics::*;
 pub use tap::*;
 
@@ -1,9 +1,35 @@
+use std::cell::Cell;
+
+use serde::{Serialize, Serializer};
 use vector_config::{configurable_component, ConfigurableString};
 
+/// The value of sensitive strings serialized within [`serialize_redacted`].
+pub const REDACTED: &str = "[redacted]";
+
+thread_local! {
+    static REDACT: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Runs `serialize`, serializing the sensitive strings it comes across as [`REDACTED`] instead of
+/// their value, so that configurations can be shown without disclosing their credentials.
+pub fn serialize_redacted<T>(serialize: impl FnOnce() -> T) -> T {
+    /// Restores the previous state on drop, even if `serialize` panics.
+    struct Guard(bool);
+
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            REDACT.set(self.0);
+        }
+    }
+
+    let _guard = Guard(REDACT.replace(true));
+    serialize()
+}
+
 /// Wrapper for sensitive strings containing credentials
 #[configurable_component(no_deser, no_ser)]
-#[derive(::serde::Deserialize, ::serde::Serialize)]
-#[serde(from = "String", into = "String")]
+#[derive(::serde::Deserialize)]
+#[serde(from = "String")]
 #[configurable(metadata(sensitive))]
 #[derive(Clone, Default, PartialEq, Eq)]
 pub struct SensitiveString(String);
@@ -20,6 +46,16 @@ impl From<SensitiveString> for String {
     }
 }
 
+impl Serialize for SensitiveString {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if REDACT.get() {
+            serializer.serialize_str(REDACTED)
+        } else {
+            serializer.serialize_str(&self.0)
+        }
+    }
+}
+
 impl ConfigurableString for SensitiveString {}
 
 impl std::fmt::Display for SensitiveString {
@@ -54,6 +90,16 @@ mod tests {
         assert_eq!(result, json_value);
     }
 
+    #[test]
+    fn redacted_serialization() {
+        let value = SensitiveString("hello world".to_string());
+        let result = serialize_redacted(|| serde_json::to_string(&value)).unwrap();
+        assert_eq!(result, "\"[redacted]\"");
+
+        // Redaction only applies within `serialize_redacted`.
+        assert_eq!(serde_json::to_string(&value).unwrap(), "\"hello world\"");
+    }
+
     #[test]
     fn hide_content() {
         let value = SensitiveString("hello world".to_string());
@@ -207,6 +207,13 @@ impl EventMetadata {
         self.get_mut().secrets.insert(DATADOG_API_KEY, secret);
     }
 
+    /// Remove the datadog API key, if it exists
+    pub fn remove_datadog_api_key(&mut self) {
+        if self.0.secrets.get(DATADOG_API_KEY).is_some() {
+            self.get_mut().secrets.remove(DATADOG_API_KEY);
+        }
+    }
+
     /// Return the splunk hec token, if it exists
     pub fn splunk_hec_token(&self) -> Option<Arc<str>> {
         self.0.secrets.get(SPLUNK_HEC_TOKEN).cloned()
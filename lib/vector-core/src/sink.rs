@@ -1,6 +1,15 @@
-use std::{fmt, iter::IntoIterator, pin::Pin};
+use std::{
+    fmt,
+    iter::IntoIterator,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicU8, Ordering},
+        Arc,
+    },
+};
 
 use futures::{stream, task::Context, task::Poll, Sink, SinkExt, Stream, StreamExt};
+use tokio::sync::Notify;
 
 use crate::event::{into_event_stream, Event, EventArray, EventContainer};
 
@@ -83,6 +92,86 @@ impl fmt::Debug for VectorSink {
     }
 }
 
+// === PauseSignal ===
+
+const RUNNING: u8 = 0;
+const PAUSED: u8 = 1;
+const RELEASED: u8 = 2;
+
+/// Shared flag used to pause a running component without tearing it down.
+///
+/// Components that honor the signal stop processing events while it is paused, so that events
+/// accumulate in their input buffer until they're resumed. Once released, for example because the
+/// component is shutting down, the signal can't be paused anymore.
+#[derive(Clone, Debug, Default)]
+pub struct PauseSignal {
+    inner: Arc<PauseState>,
+}
+
+#[derive(Debug, Default)]
+struct PauseState {
+    state: AtomicU8,
+    resumed: Notify,
+}
+
+impl PauseSignal {
+    /// Pauses the signal, returning `false` if it was already released.
+    pub fn pause(&self) -> bool {
+        match self.inner.state.compare_exchange(
+            RUNNING,
+            PAUSED,
+            Ordering::AcqRel,
+            Ordering::Acquire,
+        ) {
+            Ok(_) => true,
+            Err(state) => state == PAUSED,
+        }
+    }
+
+    pub fn resume(&self) {
+        _ = self
+            .inner
+            .state
+            .compare_exchange(PAUSED, RUNNING, Ordering::AcqRel, Ordering::Acquire);
+        self.inner.resumed.notify_waiters();
+    }
+
+    /// Resumes the signal for good, so that it's no longer held back by later calls to `pause`.
+    pub fn release(&self) {
+        self.inner.state.store(RELEASED, Ordering::Release);
+        self.inner.resumed.notify_waiters();
+    }
+
+    #[must_use]
+    pub fn is_paused(&self) -> bool {
+        self.inner.state.load(Ordering::Acquire) == PAUSED
+    }
+
+    /// Waits until the signal is no longer paused, returning immediately if it isn't.
+    pub async fn wait_until_resumed(&self) {
+        loop {
+            // Register for the notification before checking the flag, so a resume in between
+            // isn't missed.
+            let resumed = self.inner.resumed.notified();
+            if !self.is_paused() {
+                return;
+            }
+            resumed.await;
+        }
+    }
+
+    /// Holds back each item of `stream` while the signal is paused.
+    pub fn hold<S: Stream>(self, stream: S) -> impl Stream<Item = S::Item> {
+        stream.then(move |item| {
+            let pause = self.clone();
+            async move {
+                pause.wait_until_resumed().await;
+                item
+            }
+        })
+    }
+}
+
 // === StreamSink ===
 
 #[async_trait::async_trait]
@@ -178,3 +267,51 @@ impl<T: StreamSink<Event> + Send> StreamSink<EventArray> for EventStream<T> {
         self.sink.run(input).await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::PauseSignal;
+
+    #[tokio::test]
+    async fn pause_signal_waits_until_resumed() {
+        let signal = PauseSignal::default();
+        tokio::time::timeout(Duration::from_secs(1), signal.wait_until_resumed())
+            .await
+            .expect("unpaused signal should not wait");
+
+        signal.pause();
+        let waiter = tokio::spawn({
+            let signal = signal.clone();
+            async move { signal.wait_until_resumed().await }
+        });
+        tokio::task::yield_now().await;
+        assert!(!waiter.is_finished());
+
+        signal.resume();
+        tokio::time::timeout(Duration::from_secs(1), waiter)
+            .await
+            .expect("resumed signal should wake waiters")
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn released_pause_signal_cannot_be_paused() {
+        let signal = PauseSignal::default();
+        assert!(signal.pause());
+
+        let waiter = tokio::spawn({
+            let signal = signal.clone();
+            async move { signal.wait_until_resumed().await }
+        });
+        signal.release();
+        tokio::time::timeout(Duration::from_secs(1), waiter)
+            .await
+            .expect("released signal should wake waiters")
+            .unwrap();
+
+        assert!(!signal.pause());
+        assert!(!signal.is_paused());
+    }
+}
@@ -9,6 +9,7 @@ pub struct BatchConfigParts<L, D> {
     pub batch_limiter: L,
     pub batch_data: D,
     pub timeout: Duration,
+    pub linger: Option<Duration>,
 }
 
 pub trait BatchConfig<T> {
@@ -41,6 +42,13 @@ pub trait BatchConfig<T> {
     /// Returns the maximum amount of time to wait for inputs to a single batch.
     /// The timer starts when the first item is received for a batch.
     fn timeout(&self) -> Duration;
+
+    /// Returns how long to wait for another input before flushing a batch, if batches should be
+    /// flushed as soon as their inputs stop coming in. The timer restarts on every item, but the
+    /// batch is still flushed once it reaches its `timeout`.
+    fn linger(&self) -> Option<Duration> {
+        None
+    }
 }
 
 impl<T, L, B> BatchConfig<T> for BatchConfigParts<L, B>
@@ -77,4 +85,8 @@ where
     fn timeout(&self) -> Duration {
         self.timeout
     }
+
+    fn linger(&self) -> Option<Duration> {
+        self.linger
+    }
 }
@@ -0,0 +1,47 @@
+use std::{
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    task::Context,
+};
+
+use futures::task::AtomicWaker;
+
+/// A handle controlling a running batcher from outside of its stream.
+///
+/// Clones of the handle control the same batcher.
+#[derive(Clone, Debug, Default)]
+pub struct BatcherControl {
+    inner: Arc<ControlState>,
+}
+
+#[derive(Debug, Default)]
+struct ControlState {
+    /// The number of flushes requested so far
+    flushes: AtomicU64,
+    waker: AtomicWaker,
+}
+
+impl BatcherControl {
+    /// Flushes all the batches in progress, as if they had timed out, without waiting for them to
+    /// fill up. The batcher keeps running afterwards.
+    pub fn flush_now(&self) {
+        self.inner.flushes.fetch_add(1, Ordering::AcqRel);
+        self.inner.waker.wake();
+    }
+
+    /// Returns whether a flush was requested since `seen` flushes, registering the task to be woken
+    /// up by the next request.
+    pub(crate) fn poll_flush(&self, seen: &mut u64, cx: &Context<'_>) -> bool {
+        // Register before loading the count, so a request in between isn't missed.
+        self.inner.waker.register(cx.waker());
+        let flushes = self.inner.flushes.load(Ordering::Acquire);
+        if flushes == *seen {
+            false
+        } else {
+            *seen = flushes;
+            true
+        }
+    }
+}
@@ -1,3 +1,8 @@
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::BinaryHeap,
+};
+
 pub trait BatchData<T> {
     type Batch;
 
@@ -67,3 +72,88 @@ where
         (self.reducer)(&mut self.state, item);
     }
 }
+
+/// Batches items in descending order of priority, as given by the `priority` function. Items of the
+/// same priority keep the order they were pushed in.
+pub struct PriorityQueue<T, F> {
+    priority: F,
+    heap: BinaryHeap<Prioritized<T>>,
+    /// The number of items pushed so far, breaking ties between items of the same priority
+    pushed: u64,
+}
+
+impl<T, F> PriorityQueue<T, F>
+where
+    F: Fn(&T) -> i64,
+{
+    pub fn new(priority: F) -> Self {
+        Self {
+            priority,
+            heap: BinaryHeap::new(),
+            pushed: 0,
+        }
+    }
+}
+
+impl<T, F> BatchData<T> for PriorityQueue<T, F>
+where
+    F: Fn(&T) -> i64,
+{
+    type Batch = Vec<T>;
+
+    fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    fn take_batch(&mut self) -> Self::Batch {
+        self.pushed = 0;
+        std::mem::take(&mut self.heap)
+            .into_sorted_vec()
+            .into_iter()
+            .rev()
+            .map(|prioritized| prioritized.item)
+            .collect()
+    }
+
+    fn push_item(&mut self, item: T) {
+        self.heap.push(Prioritized {
+            priority: (self.priority)(&item),
+            order: Reverse(self.pushed),
+            item,
+        });
+        self.pushed += 1;
+    }
+}
+
+/// An item of a [`PriorityQueue`], ordered by its priority and then by the order it was pushed in.
+struct Prioritized<T> {
+    priority: i64,
+    order: Reverse<u64>,
+    item: T,
+}
+
+impl<T> Prioritized<T> {
+    const fn key(&self) -> (i64, Reverse<u64>) {
+        (self.priority, self.order)
+    }
+}
+
+impl<T> PartialEq for Prioritized<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl<T> Eq for Prioritized<T> {}
+
+impl<T> PartialOrd for Prioritized<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for Prioritized<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key().cmp(&other.key())
+    }
+}
@@ -1,13 +1,15 @@
 pub mod config;
+mod control;
 pub mod data;
 pub mod limiter;
 
 use std::{
     pin::Pin,
-    task::{ready, Context, Poll},
+    task::{Context, Poll},
 };
 
 pub use config::BatchConfig;
+pub use control::BatcherControl;
 use futures::{
     stream::{Fuse, Stream},
     Future, StreamExt,
@@ -25,6 +27,10 @@ pub struct Batcher<S, C> {
 
     #[pin]
     timer: Maybe<Sleep>,
+
+    #[pin]
+    /// Flushes the batch once it stops receiving items, if the batch lingers
+    linger: Maybe<Sleep>,
 }
 
 /// An `Option`, but with pin projection
@@ -34,6 +40,16 @@ pub enum Maybe<T> {
     None,
 }
 
+impl Maybe<Sleep> {
+    /// Returns whether the timer is set and has elapsed.
+    fn poll_elapsed(self: Pin<&mut Self>, cx: &mut Context<'_>) -> bool {
+        match self.project() {
+            MaybeProj::Some(timer) => timer.poll(cx).is_ready(),
+            MaybeProj::None => false,
+        }
+    }
+}
+
 impl<S, C> Batcher<S, C>
 where
     S: Stream,
@@ -44,6 +60,7 @@ where
             state: config,
             stream: stream.fuse(),
             timer: Maybe::None,
+            linger: Maybe::None,
         }
     }
 }
@@ -74,33 +91,37 @@ where
                         this.state.push(item, item_metadata);
                         if this.state.is_batch_full() {
                             this.timer.set(Maybe::None);
+                            this.linger.set(Maybe::None);
                             return Poll::Ready(Some(this.state.take_batch()));
                         } else if this.state.len() == 1 {
                             this.timer
                                 .set(Maybe::Some(tokio::time::sleep(this.state.timeout())));
                         }
+                        if let Some(linger) = this.state.linger() {
+                            this.linger.set(Maybe::Some(tokio::time::sleep(linger)));
+                        }
                     } else {
                         let output = Poll::Ready(Some(this.state.take_batch()));
                         this.state.push(item, item_metadata);
                         this.timer
                             .set(Maybe::Some(tokio::time::sleep(this.state.timeout())));
+                        if let Some(linger) = this.state.linger() {
+                            this.linger.set(Maybe::Some(tokio::time::sleep(linger)));
+                        }
                         return output;
                     }
                 }
                 Poll::Pending => {
-                    return {
-                        if let MaybeProj::Some(timer) = this.timer.as_mut().project() {
-                            ready!(timer.poll(cx));
-                            this.timer.set(Maybe::None);
-                            debug_assert!(
-                                this.state.len() != 0,
-                                "timer should have been cancelled"
-                            );
-                            Poll::Ready(Some(this.state.take_batch()))
-                        } else {
-                            Poll::Pending
-                        }
+                    // Poll both timers, so that each of them wakes the task up.
+                    let timed_out = this.timer.as_mut().poll_elapsed(cx);
+                    let lingered = this.linger.as_mut().poll_elapsed(cx);
+                    if !(timed_out || lingered) {
+                        return Poll::Pending;
                     }
+                    this.timer.set(Maybe::None);
+                    this.linger.set(Maybe::None);
+                    debug_assert!(this.state.len() != 0, "timer should have been cancelled");
+                    return Poll::Ready(Some(this.state.take_batch()));
                 }
             }
         }
@@ -183,4 +204,62 @@ mod test {
         let batch = next.await;
         assert_eq!(batch, Some(vec![1, 2]));
     }
+
+    #[tokio::test(start_paused = true)]
+    async fn linger_limit() {
+        let timeout = Duration::from_millis(100);
+        let linger = Duration::from_millis(30);
+        let (tx, rx) = futures::channel::mpsc::unbounded();
+        let batcher = Batcher::new(
+            rx,
+            BatcherSettings::new(
+                timeout,
+                NonZeroUsize::new(100).unwrap(),
+                NonZeroUsize::new(100).unwrap(),
+            )
+            .with_linger_ms(Some(30))
+            .as_item_size_config(|_: &u32| 1),
+        );
+        tokio::pin!(batcher);
+
+        // Each item restarts the wait for the next one.
+        for item in [1, 2, 3] {
+            tx.unbounded_send(item).unwrap();
+            assert_eq!(futures::poll!(batcher.next()), Poll::Pending);
+            tokio::time::advance(linger / 2).await;
+        }
+        tokio::time::advance(linger / 2).await;
+        assert_eq!(batcher.next().await, Some(vec![1, 2, 3]));
+
+        // Items arriving steadily are still flushed on timeout.
+        for item in 4..=10 {
+            tx.unbounded_send(item).unwrap();
+            assert_eq!(futures::poll!(batcher.next()), Poll::Pending);
+            tokio::time::advance(linger / 2).await;
+        }
+        assert_eq!(batcher.next().await, Some((4..=10).collect::<Vec<_>>()));
+    }
+
+    #[tokio::test]
+    async fn priority_queue() {
+        let items = [(1, 'a'), (0, 'b'), (5, 'c'), (1, 'd'), (-1, 'e'), (0, 'f')];
+        let batcher = Batcher::new(
+            stream::iter(items),
+            BatcherSettings::new(
+                Duration::from_millis(100),
+                NonZeroUsize::new(10000).unwrap(),
+                NonZeroUsize::new(4).unwrap(),
+            )
+            .with_priority_queue(|_: &(i64, char)| 1, |(priority, _): &(i64, char)| *priority),
+        );
+        let batches: Vec<_> = batcher.collect().await;
+        // Items of the same priority keep their order, and priorities only apply within a batch.
+        assert_eq!(
+            batches,
+            vec![
+                vec![(5, 'c'), (1, 'a'), (1, 'd'), (0, 'b')],
+                vec![(0, 'f'), (-1, 'e')],
+            ]
+        );
+    }
 }
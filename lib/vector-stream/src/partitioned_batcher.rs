@@ -16,9 +16,9 @@ use vector_core::{partition::Partitioner, time::KeyedTimer};
 
 use crate::batcher::{
     config::BatchConfigParts,
-    data::BatchData,
+    data::{BatchData, PriorityQueue},
     limiter::{ByteSizeOfItemSize, ItemBatchSize, SizeLimit},
-    BatchConfig,
+    BatchConfig, BatcherControl,
 };
 
 /// A `KeyedTimer` based on `DelayQueue`.
@@ -111,9 +111,16 @@ where
 /// with their various specialized batch buffers.
 #[derive(Copy, Clone, Debug)]
 pub struct BatcherSettings {
+    /// The maximum age of a batch. Partitioned batches each have their own timeout, started when
+    /// the first item of the batch arrives, so a partition receiving few items is flushed on time
+    /// regardless of the activity of other partitions.
     pub timeout: Duration,
     pub size_limit: usize,
     pub item_limit: usize,
+    /// How long to wait for another item before flushing a batch, in milliseconds. The wait
+    /// restarts with every item added to the batch, so that bursts of items are batched together,
+    /// but never extends past `timeout`. Batches are only flushed on `timeout` when unset.
+    pub linger_ms: Option<u64>,
 }
 
 impl BatcherSettings {
@@ -126,9 +133,21 @@ impl BatcherSettings {
             timeout,
             size_limit: size_limit.get(),
             item_limit: item_limit.get(),
+            linger_ms: None,
         }
     }
 
+    /// Sets how long to wait for another item before flushing a batch.
+    #[must_use]
+    pub const fn with_linger_ms(mut self, linger_ms: Option<u64>) -> Self {
+        self.linger_ms = linger_ms;
+        self
+    }
+
+    fn linger(&self) -> Option<Duration> {
+        self.linger_ms.map(Duration::from_millis)
+    }
+
     /// A batcher config using the `ByteSizeOf` trait to determine batch sizes.
     /// The output is a  `Vec<T>`.
     pub fn as_byte_size_config<T: ByteSizeOf>(
@@ -152,6 +171,7 @@ impl BatcherSettings {
             },
             batch_data: vec![],
             timeout: self.timeout,
+            linger: self.linger(),
         }
     }
 
@@ -175,8 +195,24 @@ impl BatcherSettings {
             },
             batch_data: reducer,
             timeout: self.timeout,
+            linger: self.linger(),
         }
     }
+
+    /// A batcher config using the `ItemBatchSize` trait to determine batch sizes, queueing items by
+    /// priority. The output is a `Vec<T>` sorted in descending order of `priority`, so that the
+    /// items with the highest priority are sent first when the batch is flushed.
+    pub fn with_priority_queue<I, T, F>(
+        &self,
+        item_size: I,
+        priority: F,
+    ) -> BatchConfigParts<SizeLimit<I>, PriorityQueue<T, F>>
+    where
+        I: ItemBatchSize<T>,
+        F: Fn(&T) -> i64,
+    {
+        self.as_reducer_config(item_size, PriorityQueue::new(priority))
+    }
 }
 
 #[pin_project]
@@ -196,6 +232,10 @@ where
     closed_batches: Vec<(Prt::Key, B)>,
     /// The queue of pending batch expirations
     timer: KT,
+    /// The queue of batches flushed once they stop receiving items, if the batches linger
+    linger: Option<ExpirationQueue<Prt::Key>>,
+    /// The handle requesting flushes, if any, along with the number of flushes already done
+    control: Option<(BatcherControl, u64)>,
     /// The partitioner for this `Batcher`
     partitioner: Prt,
     #[pin]
@@ -213,18 +253,34 @@ where
     F: Fn() -> C + Send,
 {
     pub fn new(stream: St, partitioner: Prt, settings: F) -> Self {
-        let timeout = settings().timeout();
+        let config = settings();
+        let timeout = config.timeout();
+        let linger = config.linger().map(ExpirationQueue::new);
         Self {
             state: settings,
             batches: HashMap::default(),
             closed_batches: Vec::default(),
             timer: ExpirationQueue::new(timeout),
+            linger,
+            control: None,
             partitioner,
             stream: stream.fuse(),
         }
     }
 }
 
+impl<St, Prt, KT, C, F, B> PartitionedBatcher<St, Prt, KT, C, F, B>
+where
+    Prt: Partitioner,
+{
+    /// Flushes all the batches in progress whenever `control` requests it.
+    #[must_use]
+    pub fn with_control(mut self, control: BatcherControl) -> Self {
+        self.control = Some((control, 0));
+        self
+    }
+}
+
 #[cfg(test)]
 impl<St, Prt, KT, C, F, B> PartitionedBatcher<St, Prt, KT, C, F, B>
 where
@@ -241,6 +297,8 @@ where
             batches: HashMap::default(),
             closed_batches: Vec::default(),
             timer,
+            linger: None,
+            control: None,
             partitioner,
             stream: stream.fuse(),
         }
@@ -269,16 +327,48 @@ where
             if !this.closed_batches.is_empty() {
                 return Poll::Ready(this.closed_batches.pop());
             }
+            if let Some((control, seen)) = this.control.as_mut() {
+                if control.poll_flush(seen, cx) && !this.batches.is_empty() {
+                    // Every batch in progress is closed, as if they had all timed out.
+                    this.timer.clear();
+                    if let Some(linger) = this.linger.as_mut() {
+                        linger.clear();
+                    }
+                    this.closed_batches.extend(
+                        this.batches
+                            .drain()
+                            .map(|(key, mut batch)| (key, batch.take_batch())),
+                    );
+                    continue;
+                }
+            }
             match this.stream.as_mut().poll_next(cx) {
                 Poll::Pending => match this.timer.poll_expired(cx) {
                     // Unlike normal streams, `DelayQueue` can return `None`
                     // here but still be usable later if more entries are added.
-                    Poll::Pending | Poll::Ready(None) => return Poll::Pending,
+                    Poll::Pending | Poll::Ready(None) => {
+                        let Some(linger) = this.linger.as_mut() else {
+                            return Poll::Pending;
+                        };
+                        let Poll::Ready(Some(item_key)) = linger.poll_expired(cx) else {
+                            return Poll::Pending;
+                        };
+                        // The batch stopped receiving items before reaching its timeout.
+                        let mut batch = this
+                            .batches
+                            .remove(&item_key)
+                            .expect("batch should exist if it is set to linger");
+                        this.timer.remove(&item_key);
+                        this.closed_batches.push((item_key, batch.take_batch()));
+                    }
                     Poll::Ready(Some(item_key)) => {
                         let mut batch = this
                             .batches
                             .remove(&item_key)
                             .expect("batch should exist if it is set to expire");
+                        if let Some(linger) = this.linger.as_mut() {
+                            linger.remove(&item_key);
+                        }
                         this.closed_batches.push((item_key, batch.take_batch()));
                     }
                 },
@@ -290,6 +380,9 @@ where
                     // we finish.
                     if !this.batches.is_empty() {
                         this.timer.clear();
+                        if let Some(linger) = this.linger.as_mut() {
+                            linger.clear();
+                        }
                         this.closed_batches.extend(
                             this.batches
                                 .drain()
@@ -337,6 +430,12 @@ where
                             .push((item_key.clone(), batch.take_batch()));
                         this.batches.remove(&item_key);
                         this.timer.remove(&item_key);
+                        if let Some(linger) = this.linger.as_mut() {
+                            linger.remove(&item_key);
+                        }
+                    } else if let Some(linger) = this.linger.as_mut() {
+                        // Restart the wait for the next item of the batch.
+                        linger.insert(item_key);
                     }
                 }
             }
@@ -355,13 +454,14 @@ mod test {
         time::Duration,
     };
 
-    use futures::{stream, Stream};
+    use futures::{channel::mpsc, stream, Stream};
     use pin_project::pin_project;
     use proptest::prelude::*;
     use tokio::{pin, time::advance};
     use vector_core::{partition::Partitioner, time::KeyedTimer};
 
     use crate::{
+        batcher::BatcherControl,
         partitioned_batcher::{ExpirationQueue, PartitionedBatcher},
         BatcherSettings,
     };
@@ -687,6 +787,134 @@ mod test {
         assert_eq!(result, Poll::Ready(None));
     }
 
+    #[tokio::test(start_paused = true)]
+    async fn partitions_flush_independently() {
+        // Asserts that each partition is flushed once its own timeout elapses, counted from the
+        // first item of its batch, rather than waiting on busier partitions.
+        let timeout = Duration::from_secs(10);
+        let settings = BatcherSettings::new(
+            timeout,
+            NonZeroUsize::new(1_000_000).unwrap(),
+            NonZeroUsize::new(1_000).unwrap(),
+        );
+        // Even items go to the low-volume partition 0, and odd ones to the high-volume partition 1.
+        let partitioner = TestPartitioner {
+            key_space: NonZeroU8::new(2).unwrap(),
+        };
+        let (tx, rx) = mpsc::unbounded();
+        let batcher =
+            PartitionedBatcher::new(rx, partitioner, || settings.as_byte_size_config::<u64>());
+        pin!(batcher);
+        let mut poll = || single_poll(|cx| batcher.as_mut().poll_next(cx));
+
+        tx.unbounded_send(0).unwrap();
+        assert_eq!(poll(), Poll::Pending);
+        advance(timeout / 2).await;
+
+        // The high-volume partition starts later, and keeps receiving items.
+        for item in [1, 3, 5, 7, 9] {
+            tx.unbounded_send(item).unwrap();
+            assert_eq!(poll(), Poll::Pending);
+            advance(Duration::from_secs(1)).await;
+        }
+
+        advance(Duration::from_millis(1)).await;
+        assert_eq!(poll(), Poll::Ready(Some((0, vec![0]))));
+        assert_eq!(poll(), Poll::Pending);
+
+        advance(timeout / 2).await;
+        assert_eq!(poll(), Poll::Ready(Some((1, vec![1, 3, 5, 7, 9]))));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn partitions_linger_until_timeout() {
+        // Asserts that each partition is flushed once it stops receiving items for the linger
+        // duration, and no later than its timeout when items keep coming in.
+        let timeout = Duration::from_secs(10);
+        let linger = Duration::from_secs(1);
+        let settings = BatcherSettings::new(
+            timeout,
+            NonZeroUsize::new(1_000_000).unwrap(),
+            NonZeroUsize::new(1_000).unwrap(),
+        )
+        .with_linger_ms(Some(1_000));
+        let partitioner = TestPartitioner {
+            key_space: NonZeroU8::new(2).unwrap(),
+        };
+        let (tx, rx) = mpsc::unbounded();
+        let batcher =
+            PartitionedBatcher::new(rx, partitioner, || settings.as_byte_size_config::<u64>());
+        pin!(batcher);
+        let mut poll = || single_poll(|cx| batcher.as_mut().poll_next(cx));
+
+        tx.unbounded_send(0).unwrap();
+        tx.unbounded_send(1).unwrap();
+        assert_eq!(poll(), Poll::Pending);
+        advance(linger / 2).await;
+        tx.unbounded_send(3).unwrap();
+        assert_eq!(poll(), Poll::Pending);
+
+        advance(linger / 2 + Duration::from_millis(1)).await;
+        assert_eq!(poll(), Poll::Ready(Some((0, vec![0]))));
+        assert_eq!(poll(), Poll::Pending);
+        advance(linger / 2).await;
+        assert_eq!(poll(), Poll::Ready(Some((1, vec![1, 3]))));
+        assert_eq!(poll(), Poll::Pending);
+
+        let items = (0..40).step_by(2).collect::<Vec<u64>>();
+        for item in &items {
+            tx.unbounded_send(*item).unwrap();
+            assert_eq!(poll(), Poll::Pending);
+            advance(linger / 2).await;
+        }
+        advance(Duration::from_millis(1)).await;
+        assert_eq!(poll(), Poll::Ready(Some((0, items))));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn partitions_flush_now() {
+        // Asserts that every batch in progress is flushed when requested, before reaching its size
+        // limit or timeout, and that the batcher keeps batching afterwards.
+        let timeout = Duration::from_secs(10);
+        let settings = BatcherSettings::new(
+            timeout,
+            NonZeroUsize::new(1_000_000).unwrap(),
+            NonZeroUsize::new(1_000).unwrap(),
+        );
+        let partitioner = TestPartitioner {
+            key_space: NonZeroU8::new(2).unwrap(),
+        };
+        let control = BatcherControl::default();
+        let (tx, rx) = mpsc::unbounded();
+        let batcher =
+            PartitionedBatcher::new(rx, partitioner, || settings.as_byte_size_config::<u64>())
+                .with_control(control.clone());
+        pin!(batcher);
+        let mut poll = || single_poll(|cx| batcher.as_mut().poll_next(cx));
+
+        // Nothing to flush yet.
+        control.flush_now();
+        assert_eq!(poll(), Poll::Pending);
+
+        for item in [0, 1, 2] {
+            tx.unbounded_send(item).unwrap();
+        }
+        assert_eq!(poll(), Poll::Pending);
+
+        control.flush_now();
+        let mut batches = vec![];
+        while let Poll::Ready(Some(batch)) = poll() {
+            batches.push(batch);
+        }
+        batches.sort_unstable();
+        assert_eq!(batches, vec![(0, vec![0, 2]), (1, vec![1])]);
+
+        tx.unbounded_send(4).unwrap();
+        assert_eq!(poll(), Poll::Pending);
+        advance(timeout + Duration::from_millis(1)).await;
+        assert_eq!(poll(), Poll::Ready(Some((0, vec![4]))));
+    }
+
     fn single_poll<T, F>(mut f: F) -> Poll<T>
     where
         F: FnMut(&mut Context<'_>) -> Poll<T>,
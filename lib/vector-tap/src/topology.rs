@@ -1,9 +1,13 @@
 use std::collections::{HashMap, HashSet};
+use std::sync::Weak;
 use tokio::sync::watch;
+use vector_buffers::topology::channel::BufferSender;
 use vector_common::config::ComponentKey;
 use vector_common::id::Inputs;
 use vector_core::config::OutputId;
+use vector_core::event::EventArray;
 use vector_core::fanout;
+use vector_core::sink::PauseSignal;
 
 /// A tappable output consisting of an output ID and associated metadata
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
@@ -27,6 +31,12 @@ pub struct TapResource {
     pub sink_keys: Vec<String>,
     // Components removed on a reload (used to drop TapSinks)
     pub removals: HashSet<ComponentKey>,
+    // Sinks and their corresponding input buffers, used to ingest events directly into a sink.
+    // The topology holds the buffers, so that they close once the sink is removed or stopped.
+    pub sink_inputs: HashMap<ComponentKey, Weak<BufferSender<EventArray>>>,
+    // Components (transforms, sinks) which can be paused and their corresponding pause signals,
+    // used to pause and resume a running component
+    pub component_pauses: HashMap<ComponentKey, PauseSignal>,
 }
 
 // Watcher types for topology changes.
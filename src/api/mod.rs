@@ -1,5 +1,7 @@
 #![allow(missing_docs)]
 mod handler;
+#[cfg(feature = "api-cpu-profiling")]
+mod profiling;
 mod schema;
 mod server;
 #[cfg(all(
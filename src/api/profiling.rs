@@ -0,0 +1,162 @@
+use std::{
+    sync::atomic::{AtomicBool, Ordering},
+    time::Duration,
+};
+
+use prost::Message;
+use serde::Deserialize;
+use warp::{
+    http::{Response, StatusCode},
+    Rejection, Reply,
+};
+
+/// Sampling frequency used while collecting CPU profiles.
+const CPU_PROFILE_FREQUENCY_HZ: i32 = 99;
+
+/// Bounds of the CPU profile duration, in seconds.
+const MIN_CPU_PROFILE_SECS: u64 = 1;
+const MAX_CPU_PROFILE_SECS: u64 = 60;
+
+const fn default_cpu_profile_secs() -> u64 {
+    10
+}
+
+/// Whether a CPU profile is being collected. The profiler samples the whole process, so only one
+/// profile can be collected at a time.
+static CPU_PROFILING: AtomicBool = AtomicBool::new(false);
+
+/// Marks a CPU profile as being collected until dropped.
+struct CpuProfilingGuard;
+
+impl CpuProfilingGuard {
+    fn acquire() -> Option<Self> {
+        CPU_PROFILING
+            .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+            .is_ok()
+            .then_some(Self)
+    }
+}
+
+impl Drop for CpuProfilingGuard {
+    fn drop(&mut self) {
+        CPU_PROFILING.store(false, Ordering::Release);
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub(super) struct CpuProfileQuery {
+    /// Clamped between `MIN_CPU_PROFILE_SECS` and `MAX_CPU_PROFILE_SECS`.
+    #[serde(default = "default_cpu_profile_secs", alias = "seconds")]
+    duration_secs: u64,
+}
+
+// CPU profile handler, samples the whole process for the requested duration and responds with
+// the collected stack traces encoded as a pprof protobuf.
+pub(super) async fn cpu_profile(query: CpuProfileQuery) -> Result<impl Reply, Rejection> {
+    let Some(guard) = CpuProfilingGuard::acquire() else {
+        return Ok(Response::builder()
+            .status(StatusCode::TOO_MANY_REQUESTS)
+            .body(b"A CPU profile is already being collected.".to_vec())
+            .expect("static response parts are valid"));
+    };
+    let duration = Duration::from_secs(
+        query
+            .duration_secs
+            .clamp(MIN_CPU_PROFILE_SECS, MAX_CPU_PROFILE_SECS),
+    );
+
+    // The profiler guard is not `Send`, so sampling happens on a blocking thread rather than
+    // being held across an await point. The profiling guard moves along with it, so that a client
+    // disconnecting mid-profile doesn't let another profile start while this one is running.
+    let result = tokio::task::spawn_blocking(move || {
+        let _guard = guard;
+        collect_cpu_profile(duration)
+    })
+    .await
+    .map_err(|error| error.to_string())
+    .and_then(|result| result);
+
+    let response = match result {
+        Ok(body) => Response::builder()
+            .header("content-type", "application/octet-stream")
+            .header("content-disposition", "attachment; filename=\"profile.pb\"")
+            .body(body),
+        Err(error) => {
+            error!(message = "Failed to collect CPU profile.", %error);
+            Response::builder()
+                .status(StatusCode::INTERNAL_SERVER_ERROR)
+                .body(error.into_bytes())
+        }
+    };
+
+    Ok(response.expect("static response parts are valid"))
+}
+
+fn collect_cpu_profile(duration: Duration) -> Result<Vec<u8>, String> {
+    let guard = pprof::ProfilerGuardBuilder::default()
+        .frequency(CPU_PROFILE_FREQUENCY_HZ)
+        .blocklist(&["libc", "libgcc", "pthread", "vdso"])
+        .build()
+        .map_err(|error| error.to_string())?;
+
+    std::thread::sleep(duration);
+
+    let profile = guard
+        .report()
+        .build()
+        .and_then(|report| report.pprof())
+        .map_err(|error| error.to_string())?;
+
+    let mut body = Vec::with_capacity(profile.encoded_len());
+    profile
+        .encode(&mut body)
+        .map_err(|error| error.to_string())?;
+    Ok(body)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn cpu_profile_returns_pprof_protobuf() {
+        // Durations are clamped to at least a second.
+        let profile = cpu_profile(CpuProfileQuery { duration_secs: 0 });
+        let concurrent_profile = async {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            cpu_profile(CpuProfileQuery { duration_secs: 1 }).await
+        };
+        let (reply, concurrent_reply) = tokio::join!(profile, concurrent_profile);
+
+        let response = reply.unwrap().into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let profile = pprof::protos::Profile::decode(body).expect("body is a pprof profile");
+        assert!(!profile.sample_type.is_empty());
+        assert!(!profile.string_table.is_empty());
+
+        // Only one profile is collected at a time.
+        let response = concurrent_reply.unwrap().into_response();
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+
+        // A profile keeps running after its client disconnects, and still blocks other profiles.
+        let abandoned = cpu_profile(CpuProfileQuery { duration_secs: 1 });
+        assert!(tokio::time::timeout(Duration::from_millis(100), abandoned)
+            .await
+            .is_err());
+        let response = cpu_profile(CpuProfileQuery { duration_secs: 1 })
+            .await
+            .unwrap()
+            .into_response();
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    }
+
+    #[test]
+    fn duration_defaults_to_ten_seconds() {
+        let query: CpuProfileQuery = serde_json::from_str("{}").unwrap();
+        assert_eq!(query.duration_secs, 10);
+
+        let query: CpuProfileQuery = serde_json::from_str(r#"{"duration_secs": 120}"#).unwrap();
+        assert_eq!(query.duration_secs, 120);
+    }
+}
@@ -0,0 +1,146 @@
+use std::{
+    collections::HashMap,
+    sync::{LazyLock, RwLock},
+};
+
+use async_graphql::{Enum, Object, ID};
+use vector_lib::sensitive_string::serialize_redacted;
+
+use crate::config::{self, ComponentKey, Config, Format};
+
+const INVARIANT: &str = "Couldn't acquire lock on the running config. Please report this.";
+
+/// The configuration of the running components, with their sensitive values redacted.
+static RUNNING_CONFIGS: LazyLock<RwLock<HashMap<ComponentKey, serde_json::Value>>> =
+    LazyLock::new(|| RwLock::new(HashMap::new()));
+
+/// Update the configuration returned for the running components
+pub fn update_config(config: &Config) {
+    let serialize = |value| {
+        serialize_redacted(|| serde_json::to_value(value)).expect("component configs serialize")
+    };
+
+    let sources = config
+        .sources()
+        .map(|(key, source)| (key.clone(), serialize(source)));
+    let transforms = config
+        .transforms()
+        .map(|(key, transform)| (key.clone(), serialize(transform)));
+    let sinks = config
+        .sinks()
+        .map(|(key, sink)| (key.clone(), serialize(sink)));
+
+    *RUNNING_CONFIGS.write().expect(INVARIANT) = sources.chain(transforms).chain(sinks).collect();
+}
+
+/// The format of a serialized configuration
+#[derive(Enum, Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum ConfigFormat {
+    #[default]
+    Toml,
+    Json,
+    Yaml,
+}
+
+impl From<ConfigFormat> for Format {
+    fn from(format: ConfigFormat) -> Self {
+        match format {
+            ConfigFormat::Toml => Format::Toml,
+            ConfigFormat::Json => Format::Json,
+            ConfigFormat::Yaml => Format::Yaml,
+        }
+    }
+}
+
+/// Removes the fields holding `null`, which TOML has no representation for. Unset options are
+/// serialized as `null`.
+pub(super) fn remove_nulls(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(fields) => {
+            fields.retain(|_, value| !value.is_null());
+            fields.values_mut().for_each(remove_nulls);
+        }
+        serde_json::Value::Array(values) => values.iter_mut().for_each(remove_nulls),
+        _ => {}
+    }
+}
+
+/// Serializes the configuration of a component in the given format.
+fn serialize(mut value: serde_json::Value, format: ConfigFormat) -> Result<String, String> {
+    if format == ConfigFormat::Toml {
+        remove_nulls(&mut value);
+    }
+    config::format::serialize(&value, format.into())
+}
+
+#[derive(Default)]
+pub struct ComponentConfigQuery;
+
+#[Object]
+impl ComponentConfigQuery {
+    /// The configuration of a running component, including the options left to their default
+    /// value, serialized as TOML unless another format is given. Sensitive values, such as API keys
+    /// and passwords, are redacted. Returns null if there is no such component.
+    async fn component_config(
+        &self,
+        id: ID,
+        format: Option<ConfigFormat>,
+    ) -> async_graphql::Result<Option<String>> {
+        let value = RUNNING_CONFIGS
+            .read()
+            .expect(INVARIANT)
+            .get(&ComponentKey::from(id.as_str()))
+            .cloned();
+
+        Ok(value
+            .map(|value| serialize(value, format.unwrap_or_default()))
+            .transpose()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIG: &str = r#"
+        [sources.in]
+        type = "demo_logs"
+        format = "json"
+
+        [sinks.out]
+        type = "datadog_logs"
+        inputs = ["in"]
+        default_api_key = "0123456789abcdef"
+    "#;
+
+    fn running_config(id: &str) -> serde_json::Value {
+        let config = config::load_from_str(CONFIG, Format::Toml).unwrap();
+        update_config(&config);
+        RUNNING_CONFIGS.read().unwrap()[&ComponentKey::from(id)].clone()
+    }
+
+    #[test]
+    fn includes_defaults_and_redacts_secrets() {
+        let sink = running_config("out");
+
+        assert_eq!(sink["type"], "datadog_logs");
+        assert_eq!(sink["default_api_key"], "[redacted]");
+        assert_eq!(sink["strip_nulls"], false);
+        assert!(!sink.to_string().contains("0123456789abcdef"));
+    }
+
+    #[test]
+    fn serializes_in_every_format() {
+        let source = running_config("in");
+
+        let toml = serialize(source.clone(), ConfigFormat::Toml).unwrap();
+        assert!(toml.contains(r#"type = "demo_logs""#), "{toml}");
+        let json = serialize(source.clone(), ConfigFormat::Json).unwrap();
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&json).unwrap(),
+            source
+        );
+        let yaml = serialize(source, ConfigFormat::Yaml).unwrap();
+        assert!(yaml.contains("type: demo_logs"), "{yaml}");
+    }
+}
@@ -0,0 +1,196 @@
+use std::collections::HashSet;
+
+use async_graphql::{SimpleObject, Subscription, ID};
+use async_stream::stream;
+use chrono::{DateTime, Utc};
+use futures::stream::select;
+use tokio_stream::{Stream, StreamExt};
+use vrl::{event_path, value::Value};
+
+use super::components::removed_component_keys;
+use crate::{config::ComponentKey, event::LogEvent, trace::TraceSubscription};
+
+/// The error code of the errors reporting dropped events, which have none of their own.
+const EVENTS_DROPPED: &str = "events_dropped";
+
+/// An error reported by a component
+#[derive(SimpleObject, Clone, Debug, PartialEq)]
+pub struct ComponentError {
+    /// ID of the component reporting the error
+    component_id: ID,
+    /// Code of the error, or its type for errors without a code
+    error_code: String,
+    /// Error message
+    message: String,
+    /// Time at which the error was reported
+    timestamp: DateTime<Utc>,
+    /// Number of events dropped, for errors reporting dropped events
+    dropped_events: Option<i32>,
+}
+
+impl ComponentError {
+    /// Reads a component error from an internal log. Component errors are logged at the error
+    /// level, with an `error_code` or `error_type`, or as dropped events, from within the span of
+    /// the component.
+    fn from_log(log: &LogEvent) -> Option<Self> {
+        let field = |name: &str| log.get(event_path!(name)).map(Value::to_string_lossy);
+
+        let level = log.get(event_path!("metadata", "level"))?;
+        if level.to_string_lossy() != "ERROR" {
+            return None;
+        }
+        let component_id = log.get(event_path!("vector", "component_id"))?;
+        let dropped_events = log
+            .get(event_path!("count"))
+            .and_then(Value::as_integer)
+            .filter(|_| log.contains(event_path!("intentional")));
+        let error_code = match (field("error_code"), field("error_type")) {
+            (Some(code), _) | (None, Some(code)) => code.into_owned(),
+            (None, None) if dropped_events.is_some() => EVENTS_DROPPED.to_string(),
+            (None, None) => return None,
+        };
+        let message = match field("reason").filter(|_| dropped_events.is_some()) {
+            Some(reason) => format!("Events dropped: {reason}"),
+            None => field("message").unwrap_or_default().into_owned(),
+        };
+
+        Some(Self {
+            component_id: ID(component_id.to_string_lossy().into_owned()),
+            error_code,
+            message,
+            timestamp: log
+                .get(event_path!("timestamp"))
+                .and_then(Value::as_timestamp)
+                .copied()
+                .unwrap_or_else(Utc::now),
+            dropped_events: dropped_events.map(|count| count as i32),
+        })
+    }
+}
+
+enum Update {
+    Error(ComponentError),
+    Removed(ComponentKey),
+}
+
+#[derive(Debug, Default)]
+pub struct ComponentErrorsSubscription;
+
+#[Subscription]
+impl ComponentErrorsSubscription {
+    /// Subscribes to the errors reported by components, or by the given components only. The
+    /// subscription completes once all of the given components have been removed from the
+    /// topology.
+    ///
+    /// Errors are read from the internal logs of Vector, so they are rate limited in the same way.
+    async fn component_errors(
+        &self,
+        component_ids: Option<Vec<ID>>,
+    ) -> impl Stream<Item = ComponentError> {
+        let mut component_keys = component_ids.map(|ids| {
+            ids.iter()
+                .map(|id| ComponentKey::from(id.as_str()))
+                .collect::<HashSet<_>>()
+        });
+
+        let errors = TraceSubscription::subscribe()
+            .into_stream()
+            .filter_map(|log| ComponentError::from_log(&log))
+            .map(Update::Error);
+        let removed = removed_component_keys().map(Update::Removed);
+        let mut updates = Box::pin(select(errors, removed));
+
+        stream! {
+            while let Some(update) = updates.next().await {
+                match (update, &mut component_keys) {
+                    (Update::Error(error), None) => yield error,
+                    (Update::Error(error), Some(keys)) => {
+                        if keys.contains(&ComponentKey::from(error.component_id.as_str())) {
+                            yield error;
+                        }
+                    }
+                    (Update::Removed(key), Some(keys)) => {
+                        if keys.remove(&key) && keys.is_empty() {
+                            break;
+                        }
+                    }
+                    (Update::Removed(_), None) => {}
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn component_log(fields: &[(&str, Value)]) -> LogEvent {
+        let mut log = LogEvent::default();
+        log.insert(event_path!("metadata", "level"), "ERROR");
+        log.insert(event_path!("vector", "component_id"), "out");
+        for (field, value) in fields {
+            log.insert(event_path!(*field), value.clone());
+        }
+        log
+    }
+
+    #[test]
+    fn reads_errors() {
+        let log = component_log(&[
+            (
+                "message",
+                "Failed to forward event(s), downstream is closed.".into(),
+            ),
+            ("error_code", "stream_closed".into()),
+            ("error_type", "writer_failed".into()),
+        ]);
+
+        let error = ComponentError::from_log(&log).unwrap();
+        assert_eq!(error.component_id, ID::from("out"));
+        assert_eq!(error.error_code, "stream_closed");
+        assert_eq!(
+            error.message,
+            "Failed to forward event(s), downstream is closed."
+        );
+        assert_eq!(error.dropped_events, None);
+
+        let log = component_log(&[
+            ("message", "Failed to build request.".into()),
+            ("error_type", "encoder_failed".into()),
+        ]);
+        assert_eq!(
+            ComponentError::from_log(&log).unwrap().error_code,
+            "encoder_failed"
+        );
+    }
+
+    #[test]
+    fn reads_dropped_events() {
+        let log = component_log(&[
+            ("message", "Events dropped".into()),
+            ("intentional", false.into()),
+            ("count", 3.into()),
+            ("reason", "Downstream is closed.".into()),
+        ]);
+
+        let error = ComponentError::from_log(&log).unwrap();
+        assert_eq!(error.error_code, EVENTS_DROPPED);
+        assert_eq!(error.message, "Events dropped: Downstream is closed.");
+        assert_eq!(error.dropped_events, Some(3));
+    }
+
+    #[test]
+    fn ignores_other_logs() {
+        let mut warning = component_log(&[("error_type", "request_failed".into())]);
+        warning.insert(event_path!("metadata", "level"), "WARN");
+        assert_eq!(ComponentError::from_log(&warning), None);
+
+        let mut outside_component = component_log(&[("error_type", "request_failed".into())]);
+        outside_component.remove(event_path!("vector", "component_id"));
+        assert_eq!(ComponentError::from_log(&outside_component), None);
+
+        let untyped = component_log(&[("message", "Something went wrong.".into())]);
+        assert_eq!(ComponentError::from_log(&untyped), None);
+    }
+}
@@ -35,7 +35,7 @@ pub enum Component {
     Sink(sink::Sink),
 }
 
-#[derive(Enum, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+#[derive(Enum, Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
 pub enum ComponentKind {
     Source,
     Transform,
@@ -244,13 +244,22 @@ impl ComponentsSubscription {
 
     /// Subscribes to all removed components
     async fn component_removed(&self) -> impl Stream<Item = Component> {
-        BroadcastStream::new(COMPONENT_CHANGED.subscribe()).filter_map(|c| match c {
-            Ok(ComponentChanged::Removed(c)) => Some(c),
-            _ => None,
-        })
+        removed_components()
     }
 }
 
+fn removed_components() -> impl Stream<Item = Component> {
+    BroadcastStream::new(COMPONENT_CHANGED.subscribe()).filter_map(|c| match c {
+        Ok(ComponentChanged::Removed(c)) => Some(c),
+        _ => None,
+    })
+}
+
+/// Returns a stream of the keys of the components removed from the topology
+pub fn removed_component_keys() -> impl Stream<Item = ComponentKey> {
+    removed_components().map(|c| c.get_component_key().clone())
+}
+
 /// Update the 'global' configuration that will be consumed by component queries
 pub fn update_config(config: &Config) {
     let mut new_components = HashMap::new();
@@ -0,0 +1,244 @@
+use std::{
+    collections::HashMap,
+    sync::{LazyLock, RwLock},
+};
+
+use async_graphql::{Object, SimpleObject};
+
+use super::components::ComponentKind;
+use crate::config::{self, ComponentKey, Config, ConfigPath, CONFIG_PATHS};
+
+const INVARIANT: &str = "Couldn't acquire lock on the running config. Please report this.";
+
+/// The configuration of a component, serialized so that configurations can be compared regardless
+/// of how they were written.
+#[derive(Clone, Debug, PartialEq)]
+struct ComponentConfig {
+    kind: ComponentKind,
+    component_type: String,
+    value: serde_json::Value,
+}
+
+type ComponentConfigs = HashMap<ComponentKey, ComponentConfig>;
+
+/// The configuration of the components of the running topology.
+static RUNNING_COMPONENTS: LazyLock<RwLock<ComponentConfigs>> =
+    LazyLock::new(|| RwLock::new(HashMap::new()));
+
+/// Update the running configuration that the config file is compared against
+pub fn update_config(config: &Config) {
+    *RUNNING_COMPONENTS.write().expect(INVARIANT) = component_configs(config);
+}
+
+fn component_configs(config: &Config) -> ComponentConfigs {
+    // As when diffing configs to reload the topology, components are compared as JSON values, which
+    // can represent `None` and don't depend on the iteration order of maps.
+    let serialize = |value| serde_json::to_value(value).expect("component configs serialize");
+
+    let sources = config.sources().map(|(key, source)| {
+        let config = ComponentConfig {
+            kind: ComponentKind::Source,
+            component_type: source.inner.get_component_name().to_string(),
+            value: serialize(source),
+        };
+        (key.clone(), config)
+    });
+    let transforms = config.transforms().map(|(key, transform)| {
+        let config = ComponentConfig {
+            kind: ComponentKind::Transform,
+            component_type: transform.inner.get_component_name().to_string(),
+            value: serialize(transform),
+        };
+        (key.clone(), config)
+    });
+    let sinks = config.sinks().map(|(key, sink)| {
+        let config = ComponentConfig {
+            kind: ComponentKind::Sink,
+            component_type: sink.inner.get_component_name().to_string(),
+            value: serialize(sink),
+        };
+        (key.clone(), config)
+    });
+
+    sources.chain(transforms).chain(sinks).collect()
+}
+
+/// A component of a config diff.
+#[derive(SimpleObject, Debug, Clone, PartialEq, Eq)]
+pub struct ComponentSummary {
+    /// Component ID
+    component_id: String,
+    /// Component kind
+    component_kind: ComponentKind,
+    /// Component type, such as `datadog_logs`
+    component_type: String,
+}
+
+impl ComponentSummary {
+    fn new(component_key: &ComponentKey, config: &ComponentConfig) -> Self {
+        Self {
+            component_id: component_key.id().to_string(),
+            component_kind: config.kind,
+            component_type: config.component_type.clone(),
+        }
+    }
+}
+
+/// The differences between the running topology and the config file.
+#[derive(SimpleObject, Debug, Default, PartialEq, Eq)]
+pub struct ConfigDiff {
+    /// Components in the config file which aren't running
+    added: Vec<ComponentSummary>,
+    /// Running components which are no longer in the config file
+    removed: Vec<ComponentSummary>,
+    /// Running components whose configuration differs in the config file. They are described as
+    /// configured in the file.
+    changed: Vec<ComponentSummary>,
+}
+
+impl ConfigDiff {
+    fn new(running: &ComponentConfigs, file: &ComponentConfigs) -> Self {
+        let mut diff = Self::default();
+
+        for (component_key, config) in file {
+            match running.get(component_key) {
+                None => diff
+                    .added
+                    .push(ComponentSummary::new(component_key, config)),
+                Some(running) if running != config => diff
+                    .changed
+                    .push(ComponentSummary::new(component_key, config)),
+                Some(_) => {}
+            }
+        }
+        diff.removed = running
+            .iter()
+            .filter(|(component_key, _)| !file.contains_key(component_key))
+            .map(|(component_key, config)| ComponentSummary::new(component_key, config))
+            .collect();
+
+        for summaries in [&mut diff.added, &mut diff.removed, &mut diff.changed] {
+            summaries.sort_by(|a, b| a.component_id.cmp(&b.component_id));
+        }
+        diff
+    }
+}
+
+/// Parses the config files into the configuration of their components, without loading them.
+fn load_component_configs(config_paths: &[ConfigPath]) -> Result<ComponentConfigs, Vec<String>> {
+    config::load_from_paths(config_paths).map(|config| component_configs(&config))
+}
+
+#[derive(Default)]
+pub struct ConfigDiffQuery;
+
+#[Object]
+impl ConfigDiffQuery {
+    /// Compares the running topology against the config files it was loaded from, as they are on
+    /// disk. Only the configuration of the components is compared, so formatting and comments are
+    /// ignored. The topology is never reloaded.
+    ///
+    /// Secrets aren't retrieved, so components configured with secrets are reported as changed.
+    async fn config_diff(&self) -> async_graphql::Result<ConfigDiff> {
+        let config_paths = CONFIG_PATHS.lock().expect(INVARIANT).clone();
+        if config_paths.is_empty() {
+            return Err("Vector wasn't started from config files".into());
+        }
+
+        let file = tokio::task::spawn_blocking(move || load_component_configs(&config_paths))
+            .await?
+            .map_err(|errors| format!("Couldn't load the config files: {}", errors.join("; ")))?;
+        let running = RUNNING_COMPONENTS.read().expect(INVARIANT);
+
+        Ok(ConfigDiff::new(&running, &file))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+    use crate::{config::Format, test_util::temp_file};
+
+    const RUNNING: &str = r#"
+        [sources.in]
+        type = "demo_logs"
+        format = "json"
+
+        [sources.old]
+        type = "demo_logs"
+        format = "json"
+
+        [sinks.out]
+        type = "blackhole"
+        inputs = ["in", "old"]
+    "#;
+
+    fn summary(id: &str, kind: ComponentKind, component_type: &str) -> ComponentSummary {
+        ComponentSummary {
+            component_id: id.to_string(),
+            component_kind: kind,
+            component_type: component_type.to_string(),
+        }
+    }
+
+    #[test]
+    fn identical_configs_have_no_differences() {
+        let running = component_configs(&config::load_from_str(RUNNING, Format::Toml).unwrap());
+
+        // Formatting and comments are ignored.
+        let path = temp_file().with_extension("toml");
+        fs::write(
+            &path,
+            r#"
+            # The source of the logs
+            sources.in = { type = "demo_logs", format = "json" }
+            sources.old = { type = "demo_logs", format = "json" }
+
+            sinks.out = { type = "blackhole", inputs = ["in", "old"] }
+            "#,
+        )
+        .unwrap();
+        let file = load_component_configs(&[ConfigPath::File(path, None)]).unwrap();
+
+        assert_eq!(ConfigDiff::new(&running, &file), ConfigDiff::default());
+    }
+
+    #[test]
+    fn reports_added_removed_and_changed_components() {
+        let running = component_configs(&config::load_from_str(RUNNING, Format::Toml).unwrap());
+
+        let path = temp_file().with_extension("yaml");
+        fs::write(
+            &path,
+            r#"
+            sources:
+              in:
+                type: demo_logs
+                format: syslog
+              new:
+                type: demo_logs
+                format: json
+            sinks:
+              out:
+                type: blackhole
+                inputs: [in, new]
+            "#,
+        )
+        .unwrap();
+        let file = load_component_configs(&[ConfigPath::File(path, None)]).unwrap();
+
+        assert_eq!(
+            ConfigDiff::new(&running, &file),
+            ConfigDiff {
+                added: vec![summary("new", ComponentKind::Source, "demo_logs")],
+                removed: vec![summary("old", ComponentKind::Source, "demo_logs")],
+                changed: vec![
+                    summary("in", ComponentKind::Source, "demo_logs"),
+                    summary("out", ComponentKind::Sink, "blackhole"),
+                ],
+            }
+        );
+    }
+}
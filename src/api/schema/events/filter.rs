@@ -0,0 +1,132 @@
+use async_graphql::ErrorExtensions;
+use snafu::Snafu;
+use vector_lib::{enrichment::TableRegistry, event::Event, tap::controller::TapPayload};
+
+use crate::conditions::{Condition, VrlConfig};
+
+/// Errors raised when subscribing to filtered events.
+#[derive(Debug, Snafu)]
+pub enum TapFilterError {
+    #[snafu(display("Couldn't compile VRL expression: {}", diagnostics))]
+    Compilation { diagnostics: String },
+}
+
+impl ErrorExtensions for TapFilterError {
+    fn extend(&self) -> async_graphql::Error {
+        async_graphql::Error::new(self.to_string()).extend_with(|_, e| match self {
+            Self::Compilation { diagnostics } => {
+                e.set("code", "VRL_COMPILATION_ERROR");
+                e.set("diagnostics", diagnostics.as_str());
+            }
+        })
+    }
+}
+
+/// Compiles a VRL boolean expression used to filter tapped events.
+///
+/// Enrichment tables aren't available to the API, so expressions can't use enrichment functions.
+pub(crate) fn compile_filter(vrl_expression: &str) -> Result<Condition, TapFilterError> {
+    VrlConfig::new(vrl_expression)
+        .build_uncolored(&TableRegistry::default())
+        .map_err(|diagnostics| TapFilterError::Compilation { diagnostics })
+}
+
+/// Drops the events of a tap payload for which `filter` doesn't hold. Notifications are kept.
+pub(crate) fn filter_tap_payload(filter: &Condition, payload: TapPayload) -> TapPayload {
+    match payload {
+        TapPayload::Log(output, logs) => TapPayload::Log(
+            output,
+            filter_events(filter, logs, Event::Log, Event::into_log),
+        ),
+        TapPayload::Metric(output, metrics) => TapPayload::Metric(
+            output,
+            filter_events(filter, metrics, Event::Metric, Event::into_metric),
+        ),
+        TapPayload::Trace(output, traces) => TapPayload::Trace(
+            output,
+            filter_events(filter, traces, Event::Trace, Event::into_trace),
+        ),
+        notification @ TapPayload::Notification(_) => notification,
+    }
+}
+
+fn filter_events<T>(
+    filter: &Condition,
+    events: Vec<T>,
+    into_event: impl Fn(T) -> Event,
+    from_event: impl Fn(Event) -> T,
+) -> Vec<T> {
+    events
+        .into_iter()
+        .filter_map(|event| {
+            let (matched, event) = filter.check(into_event(event));
+            matched.then(|| from_event(event))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use vector_lib::{event::LogEvent, tap::topology::TapOutput};
+
+    use super::*;
+    use crate::config::OutputId;
+
+    fn tap_output() -> TapOutput {
+        TapOutput {
+            output_id: OutputId::from("in"),
+            component_kind: "source",
+            component_type: "demo".to_string(),
+        }
+    }
+
+    #[test]
+    fn filters_logs() {
+        let filter = compile_filter(r#".level == "error""#).unwrap();
+        let logs = ["info", "error", "debug", "error"]
+            .into_iter()
+            .enumerate()
+            .map(|(i, level)| {
+                let mut log = LogEvent::from(i.to_string());
+                log.insert("level", level);
+                log
+            })
+            .collect::<Vec<_>>();
+
+        let TapPayload::Log(_, logs) =
+            filter_tap_payload(&filter, TapPayload::Log(tap_output(), logs))
+        else {
+            panic!("expected logs");
+        };
+        let messages = logs
+            .into_iter()
+            .map(|log| log["message"].to_string_lossy().into_owned())
+            .collect::<Vec<_>>();
+        assert_eq!(messages, vec!["1", "3"]);
+    }
+
+    #[test]
+    fn keeps_notifications() {
+        let filter = compile_filter("false").unwrap();
+        assert!(matches!(
+            filter_tap_payload(&filter, TapPayload::matched("in")),
+            TapPayload::Notification(_)
+        ));
+    }
+
+    #[test]
+    fn reports_compilation_errors() {
+        let error = compile_filter(".level ==").unwrap_err();
+        let TapFilterError::Compilation { diagnostics } = &error;
+        assert!(diagnostics.contains("error[E"), "{}", diagnostics);
+        assert!(
+            !diagnostics.contains('\u{1b}'),
+            "diagnostics shouldn't be colored"
+        );
+
+        assert!(matches!(
+            compile_filter(r#""not a boolean""#),
+            Err(TapFilterError::Compilation { .. })
+        ));
+    }
+}
@@ -1,15 +1,20 @@
 pub mod encoding;
+mod filter;
 pub mod log;
 pub mod metric;
 pub mod output;
 pub mod trace;
 
-use async_graphql::{Context, Subscription};
+use async_graphql::{Context, ErrorExtensions, Subscription, ID};
 use encoding::EventEncodingType;
+use filter::{compile_filter, filter_tap_payload};
 use futures::{stream, Stream, StreamExt};
 use output::{from_tap_payload_to_output_events, OutputEventsPayload};
 use rand::{rngs::SmallRng, Rng, SeedableRng};
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::{
+    collections::HashSet,
+    time::{SystemTime, UNIX_EPOCH},
+};
 use tokio::{select, sync::mpsc, time};
 use tokio_stream::wrappers::ReceiverStream;
 use vector_lib::tap::{
@@ -17,6 +22,8 @@ use vector_lib::tap::{
     topology::WatchRx,
 };
 
+use crate::conditions::Condition;
+
 #[derive(Debug, Default)]
 pub struct EventsSubscription;
 
@@ -40,6 +47,33 @@ impl EventsSubscription {
         // Client input is confined to `u32` to provide sensible bounds.
         create_events_stream(watch_rx, patterns, interval as u64, limit as usize)
     }
+
+    /// A stream of events emitted from a component, limited to those for which the given VRL
+    /// boolean expression holds. The expression is compiled once, when subscribing.
+    pub async fn tap_with_filter<'a>(
+        &'a self,
+        ctx: &'a Context<'a>,
+        component_id: ID,
+        vrl_expression: String,
+        #[graphql(default = 500)] interval: u32,
+        #[graphql(default = 100, validator(minimum = 1, maximum = 10_000))] limit: u32,
+    ) -> async_graphql::Result<impl Stream<Item = OutputEventsPayload> + 'a> {
+        let filter = compile_filter(&vrl_expression).map_err(|error| error.extend())?;
+        let watch_rx = ctx.data_unchecked::<WatchRx>().clone();
+
+        let patterns = TapPatterns {
+            for_outputs: HashSet::from([component_id.to_string()]),
+            for_inputs: HashSet::new(),
+        };
+        Ok(create_filtered_events_stream(
+            watch_rx,
+            patterns,
+            interval as u64,
+            limit as usize,
+            Some(filter),
+        )
+        .flat_map(stream::iter))
+    }
 }
 
 /// Creates an events stream based on component ids, and a provided interval. Will emit
@@ -50,12 +84,29 @@ pub(crate) fn create_events_stream(
     patterns: TapPatterns,
     interval: u64,
     limit: usize,
+) -> impl Stream<Item = Vec<OutputEventsPayload>> {
+    create_filtered_events_stream(watch_rx, patterns, interval, limit, None)
+}
+
+/// Creates an events stream like [`create_events_stream`], only keeping the events for which
+/// `filter` holds, if any. Events are filtered before being sampled.
+fn create_filtered_events_stream(
+    watch_rx: WatchRx,
+    patterns: TapPatterns,
+    interval: u64,
+    limit: usize,
+    filter: Option<Condition>,
 ) -> impl Stream<Item = Vec<OutputEventsPayload>> {
     // Channel for receiving individual tap payloads. Since we can process at most `limit` per
     // interval, this is capped to the same value.
     let (tap_tx, tap_rx) = mpsc::channel(limit);
-    let mut tap_rx = ReceiverStream::new(tap_rx)
-        .flat_map(|payload| stream::iter(from_tap_payload_to_output_events(payload)));
+    let mut tap_rx = ReceiverStream::new(tap_rx).flat_map(move |payload| {
+        let payload = match &filter {
+            Some(filter) => filter_tap_payload(filter, payload),
+            None => payload,
+        };
+        stream::iter(from_tap_payload_to_output_events(payload))
+    });
 
     // The resulting vector of `Event` sent to the client. Only one result set will be streamed
     // back to the client at a time. This value is set higher than `1` to prevent blocking the event
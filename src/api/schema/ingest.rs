@@ -0,0 +1,242 @@
+use std::{sync::Weak, time::Instant};
+
+use async_graphql::{Context, Object, SimpleObject};
+use vector_lib::{config::ComponentKey, tap::topology::WatchRx};
+
+use crate::event::{EventArray, LogEvent};
+
+/// The outcome of ingesting events into a sink.
+#[derive(SimpleObject, Debug, Default, PartialEq, Eq)]
+pub struct IngestResult {
+    /// Number of events written to the sink's buffer
+    accepted: i32,
+    /// Number of events that could not be parsed
+    rejected: i32,
+    /// Parse errors, one per rejected event
+    errors: Vec<String>,
+}
+
+#[derive(Default)]
+pub struct IngestMutation;
+
+#[Object]
+impl IngestMutation {
+    /// Writes events directly into the input buffer of a sink, bypassing the rest of the
+    /// topology. Each event must be a JSON-encoded object, and is encoded by the sink's codec.
+    async fn ingest_events(
+        &self,
+        ctx: &Context<'_>,
+        sink_id: String,
+        events: Vec<String>,
+    ) -> async_graphql::Result<IngestResult> {
+        super::ensure_mutations_allowed(ctx)?;
+
+        // The sender is only held while writing the events, so as not to keep the sink running.
+        let mut sender = ctx
+            .data_unchecked::<WatchRx>()
+            .borrow()
+            .sink_inputs
+            .get(&ComponentKey::from(sink_id.as_str()))
+            .and_then(Weak::upgrade)
+            .map(|sender| (*sender).clone())
+            .ok_or_else(|| format!("Sink \"{}\" not found", sink_id))?;
+
+        let (logs, result) = parse_events(&events);
+        if !logs.is_empty() {
+            sender
+                .send(EventArray::Logs(logs), Some(Instant::now()))
+                .await
+                .map_err(|error| format!("Couldn't write to sink \"{}\": {}", sink_id, error))?;
+        }
+
+        Ok(result)
+    }
+}
+
+/// Parses JSON-encoded events into logs, recording an error for each event that can't be parsed.
+fn parse_events(events: &[String]) -> (Vec<LogEvent>, IngestResult) {
+    let mut result = IngestResult::default();
+    let logs = events
+        .iter()
+        .enumerate()
+        .filter_map(|(index, event)| {
+            match serde_json::from_str::<serde_json::Value>(event)
+                .map_err(Into::into)
+                .and_then(LogEvent::try_from)
+            {
+                Ok(log) => {
+                    result.accepted += 1;
+                    Some(log)
+                }
+                Err(error) => {
+                    result.rejected += 1;
+                    result.errors.push(format!("event {}: {}", index, error));
+                    None
+                }
+            }
+        })
+        .collect();
+
+    (logs, result)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, num::NonZeroUsize, sync::Arc};
+
+    use async_graphql::{Request, Variables};
+    use futures::StreamExt;
+    use tokio::sync::watch;
+    use vector_lib::{
+        buffers::{topology::builder::TopologyBuilder, WhenFull},
+        event::EventContainer,
+        tap::topology::TapResource,
+    };
+
+    use super::*;
+    use crate::api::schema::{build_schema, CrossOrigin, MutationsEnabled};
+
+    #[test]
+    fn parses_json_objects() {
+        let events = vec![
+            r#"{"message":"one"}"#.to_string(),
+            "not json".to_string(),
+            r#"["not", "an", "object"]"#.to_string(),
+            r#"{"message":"two"}"#.to_string(),
+        ];
+
+        let (logs, result) = parse_events(&events);
+
+        assert_eq!(logs.len(), 2);
+        assert_eq!(logs[1]["message"], "two".into());
+        assert_eq!(result.accepted, 2);
+        assert_eq!(result.rejected, 2);
+        assert!(result.errors[0].starts_with("event 1:"));
+        assert!(result.errors[1].starts_with("event 2:"));
+    }
+
+    #[tokio::test]
+    async fn ingests_events_into_sink_buffer() {
+        let (sender, receiver) = TopologyBuilder::standalone_memory(
+            NonZeroUsize::new(100).unwrap(),
+            WhenFull::Block,
+            &tracing::Span::none(),
+        )
+        .await;
+
+        let sender = Arc::new(sender);
+        let (_watch_tx, watch_rx) = watch::channel(TapResource {
+            sink_inputs: HashMap::from([(ComponentKey::from("out"), Arc::downgrade(&sender))]),
+            ..Default::default()
+        });
+        let schema = build_schema()
+            .data(watch_rx)
+            .data(MutationsEnabled(true))
+            .finish();
+
+        let events = (0..100)
+            .map(|i| serde_json::json!({ "message": i.to_string() }).to_string())
+            .collect::<Vec<_>>();
+        let request = Request::new(
+            "mutation($events: [String!]!) { ingestEvents(sinkId: \"out\", events: $events) { accepted rejected } }",
+        )
+        .variables(Variables::from_json(serde_json::json!({ "events": events })));
+
+        let response = schema.execute(request).await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        assert_eq!(
+            response.data.into_json().unwrap(),
+            serde_json::json!({ "ingestEvents": { "accepted": 100, "rejected": 0 } })
+        );
+
+        let received = receiver
+            .into_stream()
+            .next()
+            .await
+            .unwrap()
+            .into_events()
+            .map(|event| event.into_log()["message"].to_string_lossy().into_owned())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            received,
+            (0..100).map(|i| i.to_string()).collect::<Vec<_>>()
+        );
+    }
+
+    #[tokio::test]
+    async fn unknown_sink() {
+        let (_watch_tx, watch_rx) = watch::channel(TapResource::default());
+        let schema = build_schema()
+            .data(watch_rx)
+            .data(MutationsEnabled(true))
+            .finish();
+
+        let response = schema
+            .execute(r#"mutation { ingestEvents(sinkId: "missing", events: []) { accepted } }"#)
+            .await;
+        assert_eq!(response.errors.len(), 1);
+        assert_eq!(response.errors[0].message, "Sink \"missing\" not found");
+    }
+
+    #[tokio::test]
+    async fn removed_sink() {
+        let (sender, _receiver) = TopologyBuilder::standalone_memory(
+            NonZeroUsize::new(100).unwrap(),
+            WhenFull::Block,
+            &tracing::Span::none(),
+        )
+        .await;
+
+        // The topology drops its handle on the input of a sink once it's removed.
+        let sender = Arc::new(sender);
+        let (_watch_tx, watch_rx) = watch::channel(TapResource {
+            sink_inputs: HashMap::from([(ComponentKey::from("out"), Arc::downgrade(&sender))]),
+            ..Default::default()
+        });
+        drop(sender);
+        let schema = build_schema()
+            .data(watch_rx)
+            .data(MutationsEnabled(true))
+            .finish();
+
+        let response = schema
+            .execute(r#"mutation { ingestEvents(sinkId: "out", events: []) { accepted } }"#)
+            .await;
+        assert_eq!(response.errors.len(), 1);
+        assert_eq!(response.errors[0].message, "Sink \"out\" not found");
+    }
+
+    #[tokio::test]
+    async fn mutations_disabled() {
+        let (_watch_tx, watch_rx) = watch::channel(TapResource::default());
+        let schema = build_schema()
+            .data(watch_rx)
+            .data(MutationsEnabled(false))
+            .finish();
+
+        let response = schema
+            .execute(r#"mutation { ingestEvents(sinkId: "out", events: []) { accepted } }"#)
+            .await;
+        assert_eq!(response.errors.len(), 1);
+        assert!(response.errors[0].message.contains("api.allow_mutations"));
+    }
+
+    #[tokio::test]
+    async fn cross_origin_request() {
+        let (_watch_tx, watch_rx) = watch::channel(TapResource::default());
+        let schema = build_schema()
+            .data(watch_rx)
+            .data(MutationsEnabled(true))
+            .finish();
+
+        let request =
+            Request::new(r#"mutation { ingestEvents(sinkId: "out", events: []) { accepted } }"#)
+                .data(CrossOrigin);
+        let response = schema.execute(request).await;
+        assert_eq!(response.errors.len(), 1);
+        assert_eq!(
+            response.errors[0].message,
+            "Mutations can't be sent from another origin."
+        );
+    }
+}
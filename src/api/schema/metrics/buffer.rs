@@ -0,0 +1,198 @@
+use async_graphql::{Object, SimpleObject, ID};
+use async_stream::stream;
+use tokio::time::Duration;
+use tokio_stream::{Stream, StreamExt};
+
+use super::filter::by_component_key;
+use crate::{
+    config::ComponentKey,
+    event::{Metric, MetricValue},
+};
+
+/// The fill level of a component's input buffer, read from the buffer's internal metrics.
+#[derive(SimpleObject, Clone, Debug, Default, PartialEq)]
+pub struct BufferStats {
+    /// Maximum number of events the buffer holds, or 0 if it's only limited in bytes
+    max_size: i32,
+    /// Number of events waiting in the buffer
+    current_size: i32,
+    /// Number of events dropped by the buffer, because it was full or couldn't read them back
+    dropped_total: i32,
+    /// Age of the oldest event waiting in the buffer, in milliseconds. Buffers don't track the age
+    /// of their events, so this is always null for now.
+    oldest_event_age_ms: Option<i32>,
+}
+
+impl BufferStats {
+    /// Sums the buffer metrics among the metrics of a single component, across all the stages of
+    /// its buffer. Returns `None` if the component has no buffer.
+    pub fn from_metrics(metrics: &[Metric]) -> Option<Self> {
+        let buffer_metrics = metrics
+            .iter()
+            .filter(|m| m.name().starts_with("buffer_"))
+            .collect::<Vec<_>>();
+        if buffer_metrics.is_empty() {
+            return None;
+        }
+
+        let total = |name: &str| {
+            buffer_metrics
+                .iter()
+                .filter(|m| m.name() == name)
+                .filter_map(|m| match m.value() {
+                    MetricValue::Counter { value } | MetricValue::Gauge { value } => Some(*value),
+                    _ => None,
+                })
+                .sum::<f64>()
+        };
+
+        Some(Self {
+            max_size: total("buffer_max_event_size") as i32,
+            current_size: total("buffer_events") as i32,
+            dropped_total: total("buffer_discarded_events_total") as i32,
+            oldest_event_age_ms: None,
+        })
+    }
+
+    /// Returns how full the buffer is, in percent, if it's limited in events.
+    fn fill_pct(&self) -> Option<f64> {
+        (self.max_size > 0).then(|| f64::from(self.current_size) * 100.0 / f64::from(self.max_size))
+    }
+}
+
+/// A component's input buffer crossing a fill threshold.
+#[derive(SimpleObject, Clone, Debug, PartialEq)]
+pub struct BufferPressureEvent {
+    /// Whether the buffer is now filled above the threshold, rather than back below it
+    above_threshold: bool,
+    /// How full the buffer is, in percent
+    fill_pct: f64,
+    /// The buffer stats sampled when crossing the threshold
+    stats: BufferStats,
+}
+
+/// Returns the events of `samples` crossing `threshold_pct`, in either direction. Buffers start out
+/// below the threshold, so that a buffer which is already full is reported right away.
+fn pressure_events(
+    samples: impl Stream<Item = BufferStats>,
+    threshold_pct: f64,
+) -> impl Stream<Item = BufferPressureEvent> {
+    let mut above_threshold = false;
+    samples.filter_map(move |stats| {
+        let fill_pct = stats.fill_pct()?;
+        let above = fill_pct >= threshold_pct;
+        (above != above_threshold).then(|| {
+            above_threshold = above;
+            BufferPressureEvent {
+                above_threshold,
+                fill_pct,
+                stats,
+            }
+        })
+    })
+}
+
+#[derive(Default)]
+pub struct BufferQuery;
+
+#[Object]
+impl BufferQuery {
+    /// The fill level of a component's input buffer, or null if the component has no buffer
+    async fn buffer_stats(&self, component_id: ID) -> Option<BufferStats> {
+        BufferStats::from_metrics(&by_component_key(&ComponentKey::from(
+            component_id.as_str(),
+        )))
+    }
+}
+
+/// Returns a stream of [`BufferPressureEvent`]s for a single component, each sent when its input
+/// buffer, sampled every `interval` milliseconds, crosses `threshold_pct` percent full.
+pub fn buffer_pressure(
+    component_key: ComponentKey,
+    threshold_pct: f64,
+    interval: i32,
+) -> impl Stream<Item = BufferPressureEvent> {
+    let mut timer = tokio::time::interval(Duration::from_millis(interval as u64));
+    let samples = stream! {
+        loop {
+            timer.tick().await;
+            if let Some(stats) = BufferStats::from_metrics(&by_component_key(&component_key)) {
+                yield stats;
+            }
+        }
+    };
+
+    pressure_events(samples, threshold_pct)
+}
+
+#[cfg(test)]
+mod tests {
+    use vector_lib::metric_tags;
+
+    use super::*;
+    use crate::event::MetricKind;
+
+    fn metric(name: &str, stage: &str, value: MetricValue) -> Metric {
+        Metric::new(name, MetricKind::Absolute, value).with_tags(Some(
+            metric_tags!("component_id" => "foo", "stage" => stage),
+        ))
+    }
+
+    fn stats(current_size: i32) -> BufferStats {
+        BufferStats {
+            max_size: 100,
+            current_size,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn reads_buffer_metrics() {
+        let gauge = |value| MetricValue::Gauge { value };
+        let counter = |value| MetricValue::Counter { value };
+        let metrics = [
+            metric("buffer_max_event_size", "0", gauge(500.0)),
+            metric("buffer_events", "0", gauge(120.0)),
+            metric("buffer_events", "1", gauge(30.0)),
+            metric("buffer_discarded_events_total", "0", counter(7.0)),
+            metric("component_received_events_total", "0", counter(1_000.0)),
+        ];
+
+        assert_eq!(
+            BufferStats::from_metrics(&metrics),
+            Some(BufferStats {
+                max_size: 500,
+                current_size: 150,
+                dropped_total: 7,
+                oldest_event_age_ms: None,
+            })
+        );
+        assert_eq!(
+            BufferStats::from_metrics(&metrics[4..]),
+            None,
+            "components without buffer metrics have no buffer"
+        );
+    }
+
+    #[tokio::test]
+    async fn reports_threshold_crossings() {
+        let samples = tokio_stream::iter([10, 85, 90, 70, 95, 95]).map(stats);
+
+        let events = pressure_events(samples, 80.0)
+            .map(|event| (event.above_threshold, event.fill_pct))
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(events, vec![(true, 85.0), (false, 70.0), (true, 95.0)]);
+    }
+
+    #[tokio::test]
+    async fn ignores_buffers_limited_in_bytes() {
+        let samples = tokio_stream::iter([BufferStats {
+            current_size: 1_000,
+            ..Default::default()
+        }]);
+
+        let events = pressure_events(samples, 0.0).collect::<Vec<_>>().await;
+        assert!(events.is_empty());
+    }
+}
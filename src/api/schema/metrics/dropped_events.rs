@@ -0,0 +1,129 @@
+use std::collections::BTreeMap;
+
+use async_graphql::{SimpleObject, ID};
+
+use super::throughput::increase;
+use crate::{
+    config::ComponentKey,
+    event::{Metric, MetricValue},
+};
+
+/// Events dropped by a component for a single reason over a sampling interval.
+#[derive(SimpleObject, Clone, Debug, PartialEq, Eq)]
+pub struct DroppedEventsSnapshot {
+    /// ID of the component dropping the events
+    component_id: ID,
+    /// Number of events dropped over the interval
+    count: i64,
+    /// Why the events were dropped: `intentional` for events dropped on purpose, such as by a
+    /// filter, and `unintentional` for events dropped because of an error
+    reason: String,
+    /// Number of events dropped since the component started
+    cumulative: i64,
+}
+
+/// Returns the reason events counted in a `component_discarded_events_total` metric were dropped
+/// for, from its `intentional` tag.
+fn reason(metric: &Metric) -> &'static str {
+    match metric.tag_value("intentional").as_deref() {
+        Some("true") => "intentional",
+        _ => "unintentional",
+    }
+}
+
+/// Running totals of the events dropped by each component, for each reason, captured from the
+/// internal metrics.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DroppedEventsTotals(BTreeMap<(ComponentKey, &'static str), f64>);
+
+impl DroppedEventsTotals {
+    /// Sums the dropped events counters among the metrics of all components.
+    pub fn from_metrics(metrics: &[Metric]) -> Self {
+        let mut totals = BTreeMap::new();
+        for metric in metrics {
+            if metric.name() != "component_discarded_events_total" {
+                continue;
+            }
+            let (Some(component_id), MetricValue::Counter { value }) =
+                (metric.tag_value("component_id"), metric.value())
+            else {
+                continue;
+            };
+            *totals
+                .entry((ComponentKey::from(component_id), reason(metric)))
+                .or_default() += value;
+        }
+        Self(totals)
+    }
+
+    /// Returns the events dropped between `previous` and these totals, for every component and
+    /// reason which dropped events so far.
+    pub fn since(&self, previous: &Self) -> Vec<DroppedEventsSnapshot> {
+        self.0
+            .iter()
+            .map(
+                |(key @ (component_key, reason), total)| DroppedEventsSnapshot {
+                    component_id: ID(component_key.id().to_string()),
+                    count: increase(*total, previous.0.get(key).copied().unwrap_or_default()),
+                    reason: reason.to_string(),
+                    cumulative: *total as i64,
+                },
+            )
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vector_lib::metric_tags;
+
+    use super::*;
+    use crate::event::MetricKind;
+
+    fn dropped(component_id: &str, intentional: &str, value: f64) -> Metric {
+        Metric::new(
+            "component_discarded_events_total",
+            MetricKind::Absolute,
+            MetricValue::Counter { value },
+        )
+        .with_tags(Some(
+            metric_tags!("component_id" => component_id, "intentional" => intentional),
+        ))
+    }
+
+    fn snapshot(
+        component_id: &str,
+        reason: &str,
+        count: i64,
+        cumulative: i64,
+    ) -> DroppedEventsSnapshot {
+        DroppedEventsSnapshot {
+            component_id: ID::from(component_id),
+            count,
+            reason: reason.to_string(),
+            cumulative,
+        }
+    }
+
+    #[test]
+    fn reports_drops_by_component_and_reason() {
+        let previous = DroppedEventsTotals::from_metrics(&[
+            dropped("filter", "true", 10.0),
+            dropped("out", "false", 2.0),
+        ]);
+        let current = DroppedEventsTotals::from_metrics(&[
+            dropped("filter", "true", 25.0),
+            dropped("out", "false", 2.0),
+            dropped("out", "true", 4.0),
+        ]);
+
+        assert_eq!(
+            current.since(&previous),
+            vec![
+                snapshot("filter", "intentional", 15, 25),
+                snapshot("out", "intentional", 4, 4),
+                snapshot("out", "unintentional", 0, 2),
+            ]
+        );
+    }
+}
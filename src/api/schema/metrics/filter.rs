@@ -5,8 +5,9 @@ use tokio::time::Duration;
 use tokio_stream::{Stream, StreamExt};
 
 use super::{
-    filter_output_metric, OutputThroughput, ReceivedBytesTotal, ReceivedEventsTotal,
-    SentBytesTotal, SentEventsTotal,
+    filter_output_metric, DroppedEventsSnapshot, DroppedEventsTotals, OutputThroughput,
+    ReceivedBytesTotal, ReceivedEventsTotal, SentBytesTotal, SentEventsTotal, ThroughputSnapshot,
+    ThroughputTotals,
 };
 use crate::{
     config::ComponentKey,
@@ -171,6 +172,46 @@ pub fn by_component_key(component_key: &ComponentKey) -> Vec<Metric> {
         .collect()
 }
 
+/// Returns a stream of [`ThroughputSnapshot`]s for a single component, each holding the events
+/// and bytes received and sent by the component over the preceding `interval` milliseconds.
+pub fn component_throughput(
+    component_key: ComponentKey,
+    interval: i32,
+) -> impl Stream<Item = ThroughputSnapshot> {
+    let mut timer = tokio::time::interval(Duration::from_millis(interval as u64));
+
+    stream! {
+        // The first tick completes immediately, and only sets the baseline to sample against.
+        let mut last: Option<ThroughputTotals> = None;
+        loop {
+            timer.tick().await;
+            let totals = ThroughputTotals::from_metrics(&by_component_key(&component_key));
+            if let Some(previous) = last.replace(totals) {
+                yield totals.since(&previous, interval);
+            }
+        }
+    }
+}
+
+/// Returns a stream of [`DroppedEventsSnapshot`]s, one for each component and reason for dropping
+/// events every `interval` milliseconds, holding the events dropped over the preceding interval.
+pub fn dropped_events(interval: i32) -> impl Stream<Item = DroppedEventsSnapshot> {
+    let mut samples = Box::pin(get_all_metrics(interval));
+
+    stream! {
+        // The first sample only sets the baseline to sample against.
+        let mut last: Option<DroppedEventsTotals> = None;
+        while let Some(metrics) = samples.next().await {
+            let totals = DroppedEventsTotals::from_metrics(&metrics);
+            if let Some(previous) = last.replace(totals.clone()) {
+                for snapshot in totals.since(&previous) {
+                    yield snapshot;
+                }
+            }
+        }
+    }
+}
+
 type MetricFilterFn = dyn Fn(&Metric) -> bool + Send + Sync;
 
 /// Returns a stream of `Vec<Metric>`, where `metric_name` matches the name of the metric
@@ -1,4 +1,6 @@
 mod allocated_bytes;
+mod buffer;
+mod dropped_events;
 mod errors;
 pub mod filter;
 mod output;
@@ -8,6 +10,7 @@ mod sent_bytes;
 mod sent_events;
 mod sink;
 pub mod source;
+mod throughput;
 mod transform;
 mod uptime;
 
@@ -15,8 +18,10 @@ mod uptime;
 mod host;
 
 pub use allocated_bytes::{AllocatedBytes, ComponentAllocatedBytes};
-use async_graphql::{Interface, Subscription};
+use async_graphql::{Interface, Subscription, ID};
+pub use buffer::BufferQuery;
 use chrono::{DateTime, Utc};
+pub use dropped_events::{DroppedEventsSnapshot, DroppedEventsTotals};
 pub use errors::{ComponentErrorsTotal, ErrorsTotal};
 pub use filter::*;
 pub use output::*;
@@ -30,6 +35,7 @@ pub use sent_bytes::{ComponentSentBytesThroughput, ComponentSentBytesTotal, Sent
 pub use sent_events::{ComponentSentEventsThroughput, ComponentSentEventsTotal, SentEventsTotal};
 pub use sink::{IntoSinkMetrics, SinkMetrics};
 pub use source::{IntoSourceMetrics, SourceMetrics};
+pub use throughput::{ThroughputSnapshot, ThroughputTotals};
 use tokio_stream::{Stream, StreamExt};
 pub use transform::{IntoTransformMetrics, TransformMetrics};
 pub use uptime::Uptime;
@@ -232,6 +238,43 @@ impl MetricsSubscription {
         )
     }
 
+    /// Events and bytes received and sent by a single component, sampled every `interval_ms`
+    async fn component_throughput(
+        &self,
+        component_id: ID,
+        #[graphql(validator(minimum = 10, maximum = 60_000))] interval_ms: i32,
+    ) -> impl Stream<Item = ThroughputSnapshot> {
+        component_throughput(ComponentKey::from(component_id.as_str()), interval_ms)
+    }
+
+    /// Events dropped by each component, sampled every `interval_ms`, with an item per component
+    /// and reason for dropping events
+    async fn dropped_events_total(
+        &self,
+        #[graphql(validator(minimum = 10, maximum = 60_000))] interval_ms: i32,
+    ) -> impl Stream<Item = DroppedEventsSnapshot> {
+        dropped_events(interval_ms)
+    }
+
+    /// Notifies when a component's input buffer, sampled every `interval` milliseconds, fills up
+    /// past `threshold_pct` percent, and when it drains back below it. Only buffers limited in
+    /// events are reported.
+    async fn buffer_pressure(
+        &self,
+        component_id: ID,
+        threshold_pct: f64,
+        #[graphql(default = 1000, validator(minimum = 10, maximum = 60_000))] interval: i32,
+    ) -> async_graphql::Result<impl Stream<Item = buffer::BufferPressureEvent>> {
+        if !(0.0..=100.0).contains(&threshold_pct) {
+            return Err("thresholdPct must be between 0 and 100".into());
+        }
+        Ok(buffer::buffer_pressure(
+            ComponentKey::from(component_id.as_str()),
+            threshold_pct,
+            interval,
+        ))
+    }
+
     /// Total error metrics.
     async fn errors_total(
         &self,
@@ -0,0 +1,130 @@
+use async_graphql::SimpleObject;
+
+use crate::event::{Metric, MetricValue};
+
+/// Events and bytes received and sent by a component over a sampling interval.
+#[derive(SimpleObject, Debug, Default, PartialEq, Eq)]
+pub struct ThroughputSnapshot {
+    /// Number of events received by the component over the interval
+    events_in: i64,
+    /// Number of events sent by the component over the interval
+    events_out: i64,
+    /// Number of bytes received by the component over the interval
+    bytes_in: i64,
+    /// Number of bytes sent by the component over the interval
+    bytes_out: i64,
+    /// Length of the interval, in milliseconds
+    interval_ms: i32,
+}
+
+/// Running totals of a component's throughput counters, captured from the internal metrics.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ThroughputTotals {
+    events_in: f64,
+    events_out: f64,
+    bytes_in: f64,
+    bytes_out: f64,
+}
+
+impl ThroughputTotals {
+    /// Sums the throughput counters among the metrics of a single component.
+    pub fn from_metrics(metrics: &[Metric]) -> Self {
+        let total = |name: &str| {
+            metrics
+                .iter()
+                .filter(|m| m.name() == name)
+                .filter_map(|m| match m.value() {
+                    MetricValue::Counter { value } => Some(*value),
+                    _ => None,
+                })
+                .sum()
+        };
+
+        Self {
+            events_in: total("component_received_events_total"),
+            events_out: total("component_sent_events_total"),
+            bytes_in: total("component_received_bytes_total"),
+            bytes_out: total("component_sent_bytes_total"),
+        }
+    }
+
+    /// Returns the throughput between `previous` and these totals, sampled `interval_ms` apart.
+    pub fn since(&self, previous: &Self, interval_ms: i32) -> ThroughputSnapshot {
+        ThroughputSnapshot {
+            events_in: increase(self.events_in, previous.events_in),
+            events_out: increase(self.events_out, previous.events_out),
+            bytes_in: increase(self.bytes_in, previous.bytes_in),
+            bytes_out: increase(self.bytes_out, previous.bytes_out),
+            interval_ms,
+        }
+    }
+}
+
+/// Counters start over when a component is reloaded, in which case the whole current value is new.
+pub(super) fn increase(current: f64, previous: f64) -> i64 {
+    if current >= previous {
+        (current - previous) as i64
+    } else {
+        current as i64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vector_lib::metric_tags;
+
+    use super::*;
+    use crate::event::MetricKind;
+
+    fn counter(name: &str, output: &str, value: f64) -> Metric {
+        Metric::new(name, MetricKind::Absolute, MetricValue::Counter { value }).with_tags(Some(
+            metric_tags!("component_id" => "foo", "output" => output),
+        ))
+    }
+
+    #[test]
+    fn computes_throughput_between_totals() {
+        let previous = ThroughputTotals::from_metrics(&[
+            counter("component_received_events_total", "_default", 10.0),
+            counter("component_sent_events_total", "_default", 4.0),
+            counter("component_sent_events_total", "errors", 1.0),
+            counter("component_received_bytes_total", "_default", 1_000.0),
+            counter("component_sent_bytes_total", "_default", 500.0),
+        ]);
+        let current = ThroughputTotals::from_metrics(&[
+            counter("component_received_events_total", "_default", 25.0),
+            counter("component_sent_events_total", "_default", 14.0),
+            counter("component_sent_events_total", "errors", 3.0),
+            counter("component_received_bytes_total", "_default", 2_500.0),
+            counter("component_sent_bytes_total", "_default", 1_500.0),
+            counter("component_errors_total", "_default", 7.0),
+        ]);
+
+        assert_eq!(
+            current.since(&previous, 1000),
+            ThroughputSnapshot {
+                events_in: 15,
+                events_out: 12,
+                bytes_in: 1_500,
+                bytes_out: 1_000,
+                interval_ms: 1000,
+            }
+        );
+    }
+
+    #[test]
+    fn counts_reset_counters_from_zero() {
+        let previous = ThroughputTotals::from_metrics(&[counter(
+            "component_received_events_total",
+            "_default",
+            100.0,
+        )]);
+        let current = ThroughputTotals::from_metrics(&[counter(
+            "component_received_events_total",
+            "_default",
+            30.0,
+        )]);
+
+        assert_eq!(current.since(&previous, 500).events_in, 30);
+    }
+}
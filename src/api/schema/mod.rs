@@ -1,31 +1,77 @@
+pub mod component_config;
+pub mod component_errors;
 pub mod components;
+pub mod config_diff;
 pub mod events;
 pub mod filter;
 mod health;
+mod ingest;
 mod meta;
 mod metrics;
+mod pause;
 mod relay;
+pub mod reload;
 pub mod sort;
 
-use async_graphql::{EmptyMutation, MergedObject, MergedSubscription, Schema, SchemaBuilder};
+use async_graphql::{Context, MergedObject, MergedSubscription, Schema, SchemaBuilder};
 
 #[derive(MergedObject, Default)]
 pub struct Query(
     health::HealthQuery,
     components::ComponentsQuery,
+    config_diff::ConfigDiffQuery,
+    component_config::ComponentConfigQuery,
+    metrics::BufferQuery,
     #[cfg(feature = "sources-host_metrics")] metrics::MetricsQuery,
     meta::MetaQuery,
 );
 
+#[derive(MergedObject, Default)]
+pub struct Mutation(
+    ingest::IngestMutation,
+    pause::PauseMutation,
+    reload::ReloadMutation,
+);
+
 #[derive(MergedSubscription, Default)]
 pub struct Subscription(
     health::HealthSubscription,
     metrics::MetricsSubscription,
     components::ComponentsSubscription,
     events::EventsSubscription,
+    component_errors::ComponentErrorsSubscription,
 );
 
 /// Build a new GraphQL schema, comprised of Query, Mutation and Subscription types
-pub fn build_schema() -> SchemaBuilder<Query, EmptyMutation, Subscription> {
-    Schema::build(Query::default(), EmptyMutation, Subscription::default())
+pub fn build_schema() -> SchemaBuilder<Query, Mutation, Subscription> {
+    Schema::build(
+        Query::default(),
+        Mutation::default(),
+        Subscription::default(),
+    )
+}
+
+/// Whether the mutations of the schema are enabled, as set by `api.allow_mutations`.
+#[derive(Clone, Copy, Debug)]
+pub struct MutationsEnabled(pub bool);
+
+/// Marks a request sent from a browser page of another origin than the API.
+#[derive(Clone, Copy, Debug)]
+pub struct CrossOrigin;
+
+/// Returns an error unless mutations are enabled and the request wasn't sent from another origin.
+///
+/// The API doesn't authenticate requests and lets any origin read its responses, so any web page
+/// open in a browser on the host could otherwise change the running topology.
+fn ensure_mutations_allowed(ctx: &Context<'_>) -> async_graphql::Result<()> {
+    if !ctx
+        .data_opt::<MutationsEnabled>()
+        .is_some_and(|enabled| enabled.0)
+    {
+        return Err("Mutations are disabled. Set `api.allow_mutations` to enable them.".into());
+    }
+    if ctx.data_opt::<CrossOrigin>().is_some() {
+        return Err("Mutations can't be sent from another origin.".into());
+    }
+    Ok(())
 }
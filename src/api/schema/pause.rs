@@ -0,0 +1,239 @@
+use async_graphql::{Context, Enum, Object, SimpleObject, ID};
+use vector_lib::{config::ComponentKey, sink::PauseSignal, tap::topology::WatchRx};
+
+use super::metrics::by_component_key;
+use crate::event::MetricValue;
+
+/// Whether a component is sending events.
+#[derive(Enum, Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ComponentState {
+    /// Events are being processed as usual
+    Running,
+    /// Events are held in the component's input buffer until it's resumed
+    Paused,
+}
+
+/// The state of a component after being paused or resumed.
+#[derive(SimpleObject, Debug, PartialEq, Eq)]
+pub struct ComponentStatus {
+    /// Component ID
+    id: ID,
+    /// Whether the component is sending events
+    state: ComponentState,
+    /// Number of events waiting in the component's input buffer
+    queue_depth: i32,
+}
+
+impl ComponentStatus {
+    fn new(component_key: &ComponentKey, pause: &PauseSignal) -> Self {
+        let queue_depth = by_component_key(component_key)
+            .iter()
+            .filter(|m| m.name() == "buffer_events")
+            .filter_map(|m| match m.value() {
+                MetricValue::Gauge { value } => Some(*value),
+                _ => None,
+            })
+            .sum::<f64>();
+
+        Self {
+            id: ID(component_key.id().to_string()),
+            state: if pause.is_paused() {
+                ComponentState::Paused
+            } else {
+                ComponentState::Running
+            },
+            queue_depth: queue_depth as i32,
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct PauseMutation;
+
+#[Object]
+impl PauseMutation {
+    /// Pauses a running component without reloading the topology. The component keeps accepting
+    /// events into its input buffer, but doesn't process them until it's resumed. Transforms and
+    /// sinks which support pausing, such as `datadog_logs`, can be paused.
+    async fn pause_component(
+        &self,
+        ctx: &Context<'_>,
+        id: ID,
+    ) -> async_graphql::Result<ComponentStatus> {
+        super::ensure_mutations_allowed(ctx)?;
+        set_paused(ctx, &id, true)
+    }
+
+    /// Resumes a component paused with `pauseComponent`, processing the events held in its input
+    /// buffer.
+    async fn resume_component(
+        &self,
+        ctx: &Context<'_>,
+        id: ID,
+    ) -> async_graphql::Result<ComponentStatus> {
+        super::ensure_mutations_allowed(ctx)?;
+        set_paused(ctx, &id, false)
+    }
+}
+
+fn set_paused(ctx: &Context<'_>, id: &ID, paused: bool) -> async_graphql::Result<ComponentStatus> {
+    let component_key = ComponentKey::from(id.as_str());
+    let pause = {
+        let resource = ctx.data_unchecked::<WatchRx>().borrow();
+        match resource.component_pauses.get(&component_key) {
+            Some(pause) => pause.clone(),
+            None => {
+                let exists = resource.inputs.contains_key(&component_key)
+                    || resource
+                        .outputs
+                        .keys()
+                        .any(|output| output.output_id.component == component_key);
+                return Err(if exists {
+                    format!("Component \"{}\" doesn't support pausing", id.as_str())
+                } else {
+                    format!("Component \"{}\" not found", id.as_str())
+                }
+                .into());
+            }
+        }
+    };
+
+    if paused {
+        if !pause.pause() {
+            return Err(format!("Component \"{}\" is shutting down", id.as_str()).into());
+        }
+    } else {
+        pause.resume();
+    }
+
+    Ok(ComponentStatus::new(&component_key, &pause))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use tokio::sync::watch;
+    use vector_lib::tap::topology::TapResource;
+
+    use super::*;
+    use crate::api::schema::{build_schema, MutationsEnabled};
+
+    #[tokio::test]
+    async fn pauses_and_resumes_component() {
+        crate::metrics::init_test();
+
+        let pause = PauseSignal::default();
+        let (_watch_tx, watch_rx) = watch::channel(TapResource {
+            component_pauses: HashMap::from([(ComponentKey::from("out"), pause.clone())]),
+            ..Default::default()
+        });
+        let schema = build_schema()
+            .data(watch_rx)
+            .data(MutationsEnabled(true))
+            .finish();
+
+        let response = schema
+            .execute(r#"mutation { pauseComponent(id: "out") { id state queueDepth } }"#)
+            .await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        assert_eq!(
+            response.data.into_json().unwrap(),
+            serde_json::json!({
+                "pauseComponent": { "id": "out", "state": "PAUSED", "queueDepth": 0 }
+            })
+        );
+        assert!(pause.is_paused());
+
+        let response = schema
+            .execute(r#"mutation { resumeComponent(id: "out") { state } }"#)
+            .await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        assert_eq!(
+            response.data.into_json().unwrap(),
+            serde_json::json!({ "resumeComponent": { "state": "RUNNING" } })
+        );
+        assert!(!pause.is_paused());
+    }
+
+    #[tokio::test]
+    async fn unknown_component() {
+        let (_watch_tx, watch_rx) = watch::channel(TapResource::default());
+        let schema = build_schema()
+            .data(watch_rx)
+            .data(MutationsEnabled(true))
+            .finish();
+
+        let response = schema
+            .execute(r#"mutation { pauseComponent(id: "missing") { state } }"#)
+            .await;
+        assert_eq!(response.errors.len(), 1);
+        assert_eq!(
+            response.errors[0].message,
+            "Component \"missing\" not found"
+        );
+    }
+
+    #[tokio::test]
+    async fn component_without_pause_support() {
+        let (_watch_tx, watch_rx) = watch::channel(TapResource {
+            inputs: HashMap::from([(ComponentKey::from("out"), Default::default())]),
+            ..Default::default()
+        });
+        let schema = build_schema()
+            .data(watch_rx)
+            .data(MutationsEnabled(true))
+            .finish();
+
+        let response = schema
+            .execute(r#"mutation { pauseComponent(id: "out") { state } }"#)
+            .await;
+        assert_eq!(response.errors.len(), 1);
+        assert_eq!(
+            response.errors[0].message,
+            "Component \"out\" doesn't support pausing"
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_pause_when_mutations_are_disabled() {
+        let pause = PauseSignal::default();
+        let (_watch_tx, watch_rx) = watch::channel(TapResource {
+            component_pauses: HashMap::from([(ComponentKey::from("out"), pause.clone())]),
+            ..Default::default()
+        });
+        let schema = build_schema()
+            .data(watch_rx)
+            .data(MutationsEnabled(false))
+            .finish();
+
+        let response = schema
+            .execute(r#"mutation { pauseComponent(id: "out") { state } }"#)
+            .await;
+        assert_eq!(response.errors.len(), 1);
+        assert!(!pause.is_paused());
+    }
+
+    #[tokio::test]
+    async fn released_component_cannot_be_paused() {
+        let pause = PauseSignal::default();
+        pause.release();
+        let (_watch_tx, watch_rx) = watch::channel(TapResource {
+            component_pauses: HashMap::from([(ComponentKey::from("out"), pause.clone())]),
+            ..Default::default()
+        });
+        let schema = build_schema()
+            .data(watch_rx)
+            .data(MutationsEnabled(true))
+            .finish();
+
+        let response = schema
+            .execute(r#"mutation { pauseComponent(id: "out") { state } }"#)
+            .await;
+        assert_eq!(response.errors.len(), 1);
+        assert_eq!(
+            response.errors[0].message,
+            "Component \"out\" is shutting down"
+        );
+    }
+}
@@ -0,0 +1,243 @@
+use std::sync::{LazyLock, RwLock};
+
+use async_graphql::{Context, Object, SimpleObject};
+use serde::Serialize;
+use vector_lib::sensitive_string::serialize_redacted;
+
+use super::component_config::remove_nulls;
+use crate::{
+    config::{self, ComponentKey, Config, ConfigBuilder, Format},
+    signal::{ReloadResponder, SignalTo, SignalTx},
+    topology::ReloadOutcome,
+};
+
+const INVARIANT: &str = "Couldn't acquire lock on the running config. Please report this.";
+
+/// The components of the running configuration serialized as TOML, with their sensitive values
+/// redacted.
+static RUNNING_CONFIG: LazyLock<RwLock<Option<String>>> = LazyLock::new(|| RwLock::new(None));
+
+/// Serializes the given components into a map of their configurations, keyed by their IDs.
+fn serialize_components<'a, T>(
+    components: impl Iterator<Item = (&'a ComponentKey, &'a T)>,
+) -> serde_json::Result<serde_json::Value>
+where
+    T: Serialize + 'a,
+{
+    components
+        .map(|(key, component)| Ok((key.to_string(), serde_json::to_value(component)?)))
+        .collect::<serde_json::Result<serde_json::Map<_, _>>>()
+        .map(Into::into)
+}
+
+/// Update the snapshot of the running configuration returned by reloads
+pub fn update_config(config: &Config) {
+    let snapshot = serialize_redacted(|| {
+        Ok(serde_json::json!({
+            "sources": serialize_components(config.sources())?,
+            "transforms": serialize_components(config.transforms())?,
+            "sinks": serialize_components(config.sinks())?,
+        }))
+    })
+    .map_err(|error: serde_json::Error| error.to_string())
+    .and_then(|mut value| {
+        remove_nulls(&mut value);
+        config::format::serialize(&value, Format::Toml)
+    });
+
+    *RUNNING_CONFIG.write().expect(INVARIANT) = match snapshot {
+        Ok(snapshot) => Some(snapshot),
+        Err(error) => {
+            warn!(message = "Couldn't serialize the running config.", %error);
+            None
+        }
+    };
+}
+
+/// The result of reloading the configuration.
+#[derive(SimpleObject, Debug)]
+pub struct ReloadResult {
+    /// Whether the new configuration is running
+    success: bool,
+    /// The reasons the new configuration couldn't be loaded
+    errors: Vec<String>,
+    /// The components of the configuration running before the reload, serialized as TOML, with
+    /// their sensitive values redacted
+    previous_config_snapshot: Option<String>,
+}
+
+impl ReloadResult {
+    fn failed(errors: Vec<String>, previous_config_snapshot: Option<String>) -> Self {
+        Self {
+            success: false,
+            errors,
+            previous_config_snapshot,
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct ReloadMutation;
+
+#[Object]
+impl ReloadMutation {
+    /// Replaces the running configuration with the given TOML configuration. The configuration is
+    /// validated before being applied, and the components it changes are rebuilt, which compiles
+    /// their VRL programs and runs their healthchecks. If any of them fails, the previous
+    /// configuration is restored and the errors are returned.
+    ///
+    /// Global options can't be changed, and the API is stopped if it's disabled by the new
+    /// configuration.
+    async fn reload_config(
+        &self,
+        ctx: &Context<'_>,
+        config_toml: String,
+    ) -> async_graphql::Result<ReloadResult> {
+        super::ensure_mutations_allowed(ctx)?;
+
+        let previous_config_snapshot = RUNNING_CONFIG.read().expect(INVARIANT).clone();
+
+        let builder: ConfigBuilder = match config::load(config_toml.as_bytes(), Format::Toml) {
+            Ok(builder) => builder,
+            Err(errors) => return Ok(ReloadResult::failed(errors, previous_config_snapshot)),
+        };
+        if let Err(errors) = builder.clone().build() {
+            return Ok(ReloadResult::failed(errors, previous_config_snapshot));
+        }
+
+        let (responder, outcome) = ReloadResponder::new();
+        ctx.data_unchecked::<SignalTx>()
+            .send(SignalTo::ReloadFromApi(builder, responder))
+            .map_err(|_| "Vector is shutting down")?;
+        let outcome = outcome
+            .await
+            .map_err(|_| "The reload was cancelled, as Vector is shutting down")?;
+
+        Ok(match outcome {
+            ReloadOutcome::Success => ReloadResult {
+                success: true,
+                errors: Vec::new(),
+                previous_config_snapshot,
+            },
+            ReloadOutcome::RolledBack(errors) => {
+                ReloadResult::failed(errors, previous_config_snapshot)
+            }
+            ReloadOutcome::MissingApiKey => ReloadResult::failed(
+                vec!["The API key is missing".to_owned()],
+                previous_config_snapshot,
+            ),
+            ReloadOutcome::FatalError(error) => {
+                ReloadResult::failed(vec![error.to_string()], previous_config_snapshot)
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::sync::broadcast;
+
+    use super::*;
+    use crate::api::schema::{build_schema, MutationsEnabled};
+
+    const CONFIG: &str = r#"
+        [sources.in]
+        type = "demo_logs"
+        format = "json"
+
+        [sinks.out]
+        type = "datadog_logs"
+        inputs = ["in"]
+        default_api_key = "0123456789abcdef"
+    "#;
+
+    fn mutation(config_toml: &str) -> String {
+        format!(
+            "mutation {{ reloadConfig(configToml: {}) {{ success errors }} }}",
+            serde_json::to_string(config_toml).unwrap()
+        )
+    }
+
+    #[test]
+    fn snapshot_redacts_secrets() {
+        update_config(&config::load_from_str(CONFIG, Format::Toml).unwrap());
+        let snapshot = RUNNING_CONFIG.read().unwrap().clone().unwrap();
+
+        let value: toml::Value = toml::from_str(&snapshot).unwrap();
+        assert_eq!(value["sources"]["in"]["type"].as_str(), Some("demo_logs"));
+        assert_eq!(
+            value["sinks"]["out"]["default_api_key"].as_str(),
+            Some("[redacted]")
+        );
+        assert!(!snapshot.contains("0123456789abcdef"));
+    }
+
+    #[tokio::test]
+    async fn rejects_invalid_config() {
+        let (signal_tx, mut signal_rx) = broadcast::channel::<SignalTo>(1);
+        let schema = build_schema()
+            .data(signal_tx)
+            .data(MutationsEnabled(true))
+            .finish();
+
+        let response = schema
+            .execute(mutation(
+                r#"
+                [sinks.out]
+                type = "blackhole"
+                inputs = ["missing"]
+                "#,
+            ))
+            .await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        let data = response.data.into_json().unwrap();
+        assert_eq!(data["reloadConfig"]["success"], false);
+        assert!(!data["reloadConfig"]["errors"]
+            .as_array()
+            .unwrap()
+            .is_empty());
+        // Nothing is reloaded.
+        assert!(signal_rx.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn reports_reload_outcome() {
+        let (signal_tx, mut signal_rx) = broadcast::channel(1);
+        let schema = build_schema()
+            .data(signal_tx)
+            .data(MutationsEnabled(true))
+            .finish();
+
+        tokio::spawn(async move {
+            let SignalTo::ReloadFromApi(_, responder) = signal_rx.recv().await.unwrap() else {
+                panic!("Expected a reload signal");
+            };
+            responder.respond(ReloadOutcome::RolledBack(
+                vec!["Sinks unhealthy".to_owned()],
+            ));
+        });
+
+        let response = schema.execute(mutation(CONFIG)).await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        assert_eq!(
+            response.data.into_json().unwrap(),
+            serde_json::json!({
+                "reloadConfig": { "success": false, "errors": ["Sinks unhealthy"] }
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn mutations_disabled() {
+        let (signal_tx, mut signal_rx) = broadcast::channel::<SignalTo>(1);
+        let schema = build_schema()
+            .data(signal_tx)
+            .data(MutationsEnabled(false))
+            .finish();
+
+        let response = schema.execute(mutation(CONFIG)).await;
+        assert_eq!(response.errors.len(), 1);
+        assert!(response.errors[0].message.contains("api.allow_mutations"));
+        assert!(signal_rx.try_recv().is_err());
+    }
+}
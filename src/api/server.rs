@@ -17,11 +17,14 @@ use tracing::Span;
 use vector_lib::tap::topology;
 use warp::{filters::BoxedFilter, http::Response, ws::Ws, Filter, Reply};
 
+#[cfg(feature = "api-cpu-profiling")]
+use super::profiling;
 use super::{handler, schema};
 use crate::{
     config::{self, api},
     http::build_http_trace_layer,
     internal_events::{SocketBindError, SocketMode},
+    signal::SignalTx,
 };
 
 pub struct Server {
@@ -36,9 +39,10 @@ impl Server {
         config: &config::Config,
         watch_rx: topology::WatchRx,
         running: Arc<AtomicBool>,
+        signal_tx: SignalTx,
         handle: &Handle,
     ) -> crate::Result<Self> {
-        let routes = make_routes(config.api, watch_rx, running);
+        let routes = make_routes(config.api, watch_rx, running, signal_tx);
 
         let (_shutdown, rx) = oneshot::channel();
         // warp uses `tokio::spawn` and so needs us to enter the runtime context.
@@ -74,6 +78,9 @@ impl Server {
 
         // Update component schema with the config before starting the server.
         schema::components::update_config(config);
+        schema::config_diff::update_config(config);
+        schema::component_config::update_config(config);
+        schema::reload::update_config(config);
 
         // Spawn the server in the background.
         handle.spawn(server);
@@ -90,7 +97,10 @@ impl Server {
     /// directly involve `self`, it provides a neater API to expose an internal implementation
     /// detail than exposing the function of the sub-mod directly.
     pub fn update_config(&self, config: &config::Config) {
-        schema::components::update_config(config)
+        schema::components::update_config(config);
+        schema::config_diff::update_config(config);
+        schema::component_config::update_config(config);
+        schema::reload::update_config(config);
     }
 }
 
@@ -98,6 +108,7 @@ fn make_routes(
     api: api::Options,
     watch_tx: topology::WatchRx,
     running: Arc<AtomicBool>,
+    signal_tx: SignalTx,
 ) -> BoxedFilter<(impl Reply,)> {
     // Routes...
 
@@ -106,48 +117,84 @@ fn make_routes(
         .and(with_shared(running))
         .and_then(handler::health);
 
+    // CPU profiling.
+    #[cfg(feature = "api-cpu-profiling")]
+    let cpu_profile = warp::path!("debug" / "pprof" / "cpu")
+        .and(warp::get())
+        .and(warp::query::<profiling::CpuProfileQuery>())
+        .and_then(profiling::cpu_profile)
+        .map(Reply::into_response)
+        .boxed();
+    #[cfg(not(feature = "api-cpu-profiling"))]
+    let cpu_profile = warp::any()
+        .and_then(|| async { Err::<warp::reply::Response, _>(warp::reject::not_found()) })
+        .boxed();
+
     // 404.
     let not_found_graphql = warp::any().and_then(|| async { Err(warp::reject::not_found()) });
     let not_found = warp::any().and_then(|| async { Err(warp::reject::not_found()) });
 
+    // HTTP queries and mutations share the same topology watcher as subscriptions, so that
+    // mutations can act on the running topology, and the signal sender to reload it.
+    let http_schema = schema::build_schema()
+        .data(watch_tx.clone())
+        .data(signal_tx.clone())
+        .data(schema::MutationsEnabled(api.allow_mutations))
+        .finish();
+
     // GraphQL subscription handler. Creates a Warp WebSocket handler and for each connection,
     // parses the required headers for GraphQL and builds per-connection context based on the
     // provided `WatchTx` channel sender. This allows GraphQL resolvers to subscribe to
     // topology changes.
-    let graphql_subscription_handler =
-        warp::ws()
-            .and(graphql_protocol())
-            .map(move |ws: Ws, protocol: WebSocketProtocols| {
-                let schema = schema::build_schema().finish();
-                let watch_tx = watch_tx.clone();
-
-                let reply = ws.on_upgrade(move |socket| {
-                    let mut data = Data::default();
-                    data.insert(watch_tx);
-
-                    GraphQLWebSocket::new(socket, schema, protocol)
-                        .with_data(data)
-                        .serve()
-                });
-
-                warp::reply::with_header(
-                    reply,
-                    "Sec-WebSocket-Protocol",
-                    protocol.sec_websocket_protocol(),
-                )
+    let graphql_subscription_handler = warp::ws().and(graphql_protocol()).and(cross_origin()).map(
+        move |ws: Ws, protocol: WebSocketProtocols, cross_origin: bool| {
+            let schema = schema::build_schema()
+                .data(schema::MutationsEnabled(api.allow_mutations))
+                .finish();
+            let watch_tx = watch_tx.clone();
+            let signal_tx = signal_tx.clone();
+
+            let reply = ws.on_upgrade(move |socket| {
+                let mut data = Data::default();
+                data.insert(watch_tx);
+                data.insert(signal_tx);
+                if cross_origin {
+                    data.insert(schema::CrossOrigin);
+                }
+
+                GraphQLWebSocket::new(socket, schema, protocol)
+                    .with_data(data)
+                    .serve()
             });
 
+            warp::reply::with_header(
+                reply,
+                "Sec-WebSocket-Protocol",
+                protocol.sec_websocket_protocol(),
+            )
+        },
+    );
+
     // Handle GraphQL queries. Headers will first be parsed to determine whether the query is
     // a subscription and if so, an attempt will be made to upgrade the connection to WebSockets.
     // All other queries will fall back to the default HTTP handler.
     let graphql_handler = if api.graphql {
         warp::path("graphql")
             .and(graphql_subscription_handler.or(
-                async_graphql_warp::graphql(schema::build_schema().finish()).and_then(
-                    |(schema, request): (Schema<_, _, _>, Request)| async move {
-                        Ok::<_, Infallible>(GraphQLResponse::from(schema.execute(request).await))
-                    },
-                ),
+                async_graphql_warp::graphql(http_schema)
+                    .and(cross_origin())
+                    .and_then(
+                        |(schema, request): (Schema<_, _, _>, Request), cross_origin: bool| async move {
+                            let request = if cross_origin {
+                                request.data(schema::CrossOrigin)
+                            } else {
+                                request
+                            };
+                            Ok::<_, Infallible>(GraphQLResponse::from(
+                                schema.execute(request).await,
+                            ))
+                        },
+                    ),
             ))
             .boxed()
     } else {
@@ -169,9 +216,10 @@ fn make_routes(
         not_found.boxed()
     };
 
-    // Wire up the health + GraphQL endpoints. Provides a permissive CORS policy to allow for
-    // cross-origin interaction with the Vector API.
+    // Wire up the health, profiling and GraphQL endpoints. Provides a permissive CORS policy to
+    // allow for cross-origin interaction with the Vector API.
     health
+        .or(cpu_profile)
         .or(graphql_handler)
         .or(graphql_playground)
         .or(not_found)
@@ -198,6 +246,22 @@ fn make_routes(
         .boxed()
 }
 
+/// Extracts whether a request was sent from a browser page of another origin than the API,
+/// according to its `Origin` header. Requests without one, such as those of the `vector` CLI
+/// commands, aren't sent by browsers.
+fn cross_origin() -> impl Filter<Extract = (bool,), Error = warp::Rejection> + Clone {
+    warp::header::optional::<String>("origin")
+        .and(warp::header::optional::<String>("host"))
+        .map(|origin: Option<String>, host: Option<String>| {
+            origin.is_some_and(|origin| {
+                let origin_host = origin
+                    .split_once("://")
+                    .map_or(origin.as_str(), |(_, host)| host);
+                host.as_deref() != Some(origin_host)
+            })
+        })
+}
+
 fn with_shared(
     shared: Arc<AtomicBool>,
 ) -> impl Filter<Extract = (Arc<AtomicBool>,), Error = Infallible> + Clone {
@@ -42,6 +42,8 @@ async fn sink_events() {
         source_keys: Vec::new(),
         sink_keys: Vec::new(),
         removals: HashSet::new(),
+        sink_inputs: HashMap::new(),
+        component_pauses: HashMap::new(),
     };
 
     let (watch_tx, watch_rx) = watch::channel(TapResource::default());
@@ -130,12 +130,17 @@ impl ApplicationConfig {
 
     /// Configure the API server, if applicable
     #[cfg(feature = "api")]
-    pub fn setup_api(&self, handle: &Handle) -> Option<api::Server> {
+    pub fn setup_api(
+        &self,
+        signal_tx: crate::signal::SignalTx,
+        handle: &Handle,
+    ) -> Option<api::Server> {
         if self.api.enabled {
             match api::Server::start(
                 self.topology.config(),
                 self.topology.watch(),
                 std::sync::Arc::clone(&self.topology.running),
+                signal_tx,
                 handle,
             ) {
                 Ok(api_server) => {
@@ -250,7 +255,9 @@ impl Application {
 
         let topology_controller = SharedTopologyController::new(TopologyController {
             #[cfg(feature = "api")]
-            api_server: config.setup_api(handle),
+            api_server: config.setup_api(signals.handler.clone_tx(), handle),
+            #[cfg(feature = "api")]
+            signal_tx: signals.handler.clone_tx(),
             topology: config.topology,
             config_paths: config.config_paths.clone(),
             require_healthy: root_opts.require_healthy,
@@ -361,6 +368,22 @@ async fn handle_signal(
             let topology_controller = topology_controller.lock().await;
             reload_config_from_result(topology_controller, config_builder.build()).await
         }
+        Ok(SignalTo::ReloadFromApi(config_builder, responder)) => {
+            let mut topology_controller = topology_controller.lock().await;
+            let outcome = match config_builder.build() {
+                Ok(new_config) => topology_controller.reload(new_config).await,
+                Err(errors) => {
+                    handle_config_errors(errors.clone());
+                    emit!(VectorConfigLoadError);
+                    ReloadOutcome::RolledBack(errors)
+                }
+            };
+            responder.respond(outcome.clone());
+            match outcome {
+                ReloadOutcome::FatalError(error) => Some(SignalTo::Shutdown(Some(error))),
+                _ => None,
+            }
+        }
         Ok(SignalTo::ReloadFromDisk) => {
             let mut topology_controller = topology_controller.lock().await;
 
@@ -78,7 +78,7 @@ fn check_response(res: &HttpResponse) -> bool {
     // services use a mix of XML and JSON response bodies and the AWS SDK doesn't give us
     // a parsed representation, we resort to a simple string match.
     //
-    // S3: RequestTimeout
+    // S3: RequestTimeout, InvalidDigest
     // SQS: RequestExpired, ThrottlingException
     // ECS: RequestExpired, ThrottlingException
     // Kinesis: RequestExpired, ThrottlingException
@@ -86,8 +86,13 @@ fn check_response(res: &HttpResponse) -> bool {
     //
     // Now just look for those when it's a client_error
     let re = RETRIABLE_CODES.get_or_init(|| {
-        RegexSet::new(["RequestTimeout", "RequestExpired", "ThrottlingException"])
-            .expect("invalid regex")
+        RegexSet::new([
+            "RequestTimeout",
+            "InvalidDigest",
+            "RequestExpired",
+            "ThrottlingException",
+        ])
+        .expect("invalid regex")
     });
 
     let status = res.status();
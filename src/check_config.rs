@@ -0,0 +1,294 @@
+#![allow(missing_docs)]
+
+use std::{collections::HashMap, path::PathBuf};
+
+use clap::Parser;
+use exitcode::ExitCode;
+use serde::Serialize;
+use vector_lib::enrichment::TableRegistry;
+
+use crate::{
+    config::{self, ComponentKey, Config},
+    schema::Definition,
+    topology,
+};
+
+/// The exit code of a configuration with errors.
+const INVALID: ExitCode = 1;
+
+#[derive(Parser, Debug)]
+#[command(rename_all = "kebab-case")]
+pub struct Opts {
+    /// Vector config files to check.
+    /// Format is detected from the file name.
+    /// If none are specified, the default config path `/etc/vector/vector.yaml`
+    /// is targeted.
+    #[arg(
+        id = "config",
+        short,
+        long,
+        env = "VECTOR_CONFIG",
+        value_delimiter(',')
+    )]
+    pub paths: Vec<PathBuf>,
+
+    /// Read configuration from files in one or more directories.
+    /// File format is detected from the file name.
+    ///
+    /// Files not ending in .toml, .json, .yaml, or .yml will be ignored.
+    #[arg(
+        id = "config-dir",
+        short = 'C',
+        long,
+        env = "VECTOR_CONFIG_DIR",
+        value_delimiter(',')
+    )]
+    pub config_dirs: Vec<PathBuf>,
+}
+
+impl Opts {
+    fn paths_with_formats(&self) -> Vec<config::ConfigPath> {
+        config::merge_path_lists(vec![(&self.paths, None)])
+            .map(|(path, hint)| config::ConfigPath::File(path, hint))
+            .chain(
+                self.config_dirs
+                    .iter()
+                    .map(|dir| config::ConfigPath::Dir(dir.to_path_buf())),
+            )
+            .collect()
+    }
+}
+
+/// An error found in the configuration.
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub struct CheckError {
+    /// The component the error is about, if any.
+    component_id: Option<String>,
+    /// The path of the option the error is about, within the component, if known.
+    field_path: Option<String>,
+    message: String,
+}
+
+impl CheckError {
+    fn new(message: impl Into<String>) -> Self {
+        Self {
+            component_id: None,
+            field_path: None,
+            message: message.into(),
+        }
+    }
+
+    fn component(key: &ComponentKey, message: impl Into<String>) -> Self {
+        Self {
+            component_id: Some(key.id().to_string()),
+            ..Self::new(message)
+        }
+    }
+
+    /// An error loading the config files. Errors deserializing a table end with the keys of the
+    /// table, such as ``in `sinks.out.encoding` ``, from which the component and option are
+    /// taken.
+    fn from_load_error(error: &str) -> Self {
+        let mut lines = error.trim_end().lines().collect::<Vec<_>>();
+        let keys = lines
+            .last()
+            .and_then(|line| line.strip_prefix("in `"))
+            .and_then(|keys| keys.strip_suffix('`'))
+            .map(|keys| keys.splitn(3, '.').collect::<Vec<_>>());
+
+        match keys.as_deref() {
+            Some([kind, component_id, field_path @ ..])
+                if matches!(
+                    *kind,
+                    "sources" | "transforms" | "sinks" | "enrichment_tables"
+                ) =>
+            {
+                lines.pop();
+                Self {
+                    component_id: Some(component_id.to_string()),
+                    field_path: field_path.first().map(|path| path.to_string()),
+                    message: lines.join("\n"),
+                }
+            }
+            _ => Self::new(error.trim_end()),
+        }
+    }
+
+    /// An error building the config, about the first of `components` named in it, if any.
+    fn from_build_error(error: &str, components: &[ComponentKey]) -> Self {
+        let is_component = |name: &&str| components.iter().any(|key| key.id() == *name);
+        // Component names are quoted in most errors, and otherwise follow the kind of component
+        // at the start of the error.
+        let component_id = error
+            .split('"')
+            .skip(1)
+            .step_by(2)
+            .find(is_component)
+            .or_else(|| {
+                let mut words = error.split(' ');
+                matches!(words.next(), Some("Source" | "Transform" | "Sink"))
+                    .then(|| words.next())
+                    .flatten()
+                    .filter(is_component)
+            });
+
+        Self {
+            component_id: component_id.map(str::to_string),
+            ..Self::new(error)
+        }
+    }
+}
+
+/// Checks the configuration without starting any component, printing the errors found as JSON.
+pub async fn cmd(opts: &Opts) -> ExitCode {
+    let errors = check(opts).await.err().unwrap_or_default();
+
+    #[allow(clippy::print_stdout)]
+    {
+        println!("{}", serde_json::json!({ "errors": errors }));
+    }
+
+    if errors.is_empty() {
+        exitcode::OK
+    } else {
+        INVALID
+    }
+}
+
+async fn check(opts: &Opts) -> Result<(), Vec<CheckError>> {
+    let paths = config::process_paths(&opts.paths_with_formats())
+        .ok_or_else(|| vec![CheckError::new("No config file paths")])?;
+
+    let builder = config::load_builder_from_paths(&paths).map_err(|errors| {
+        errors
+            .iter()
+            .map(|error| CheckError::from_load_error(error))
+            .collect::<Vec<_>>()
+    })?;
+    config::init_log_schema(builder.global.log_schema.clone(), true);
+
+    let components = builder
+        .sources
+        .keys()
+        .chain(builder.transforms.keys())
+        .chain(builder.sinks.keys())
+        .chain(builder.enrichment_tables.keys())
+        .cloned()
+        .collect::<Vec<_>>();
+    let (config, _warnings) = builder.build_with_warnings().map_err(|errors| {
+        errors
+            .iter()
+            .map(|error| CheckError::from_build_error(error, &components))
+            .collect::<Vec<_>>()
+    })?;
+
+    let errors = check_transforms(&config).await;
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Checks the transforms against the events they receive, which compiles their programs. The
+/// enrichment tables they use are loaded, but no other component is built.
+async fn check_transforms(config: &Config) -> Vec<CheckError> {
+    let mut errors = Vec::new();
+
+    let mut tables = HashMap::new();
+    for (key, table) in config.enrichment_tables() {
+        match table.inner.build(&config.global).await {
+            Ok(table) => {
+                tables.insert(key.to_string(), table);
+            }
+            Err(error) => errors.push(CheckError::component(key, error.to_string())),
+        }
+    }
+    let enrichment_tables = TableRegistry::default();
+    enrichment_tables.load(tables);
+
+    let mut definitions = HashMap::new();
+    for (key, transform) in config.transforms() {
+        let Ok(input_definitions) = topology::schema::input_definitions(
+            &transform.inputs,
+            config,
+            enrichment_tables.clone(),
+            &mut definitions,
+        ) else {
+            errors.push(CheckError::component(
+                key,
+                "The events received can't be determined.",
+            ));
+            continue;
+        };
+        let merged_definition = input_definitions
+            .into_iter()
+            .map(|(_output_id, definition)| definition)
+            .reduce(Definition::merge)
+            .unwrap_or_else(Definition::any);
+
+        if let Err(messages) = transform
+            .inner
+            .check(enrichment_tables.clone(), &merged_definition)
+        {
+            errors.extend(messages.into_iter().map(|message| {
+                // Diagnostics are colored for terminals.
+                let message = strip_ansi_escapes::strip_str(message);
+                CheckError::component(key, message)
+            }));
+        }
+    }
+
+    errors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn attributes_load_errors_to_options() {
+        assert_eq!(
+            CheckError::from_load_error("unknown field `foo`\nin `sinks.out.encoding`\n"),
+            CheckError {
+                component_id: Some("out".to_string()),
+                field_path: Some("encoding".to_string()),
+                message: "unknown field `foo`".to_string(),
+            }
+        );
+        assert_eq!(
+            CheckError::from_load_error("missing field `type`\nin `transforms.parse`\n"),
+            CheckError {
+                component_id: Some("parse".to_string()),
+                field_path: None,
+                message: "missing field `type`".to_string(),
+            }
+        );
+        assert_eq!(
+            CheckError::from_load_error("invalid type: string, expected a map\nin `api`\n"),
+            CheckError::new("invalid type: string, expected a map\nin `api`"),
+        );
+    }
+
+    #[test]
+    fn attributes_build_errors_to_components() {
+        let components = ["in", "parse", "out"].map(ComponentKey::from);
+
+        let error = "Input \"missing\" for sink \"out\" doesn't match any components.";
+        assert_eq!(
+            CheckError::from_build_error(error, &components).component_id,
+            Some("out".to_string())
+        );
+        let error = "Transform parse cannot have a named output with reserved name: `_default`";
+        assert_eq!(
+            CheckError::from_build_error(error, &components).component_id,
+            Some("parse".to_string())
+        );
+        // Component names aren't matched with any word of the error.
+        let error = "No sources defined in the config.";
+        assert_eq!(
+            CheckError::from_build_error(error, &components).component_id,
+            None
+        );
+    }
+}
@@ -4,13 +4,22 @@ use std::{num::NonZeroU64, path::PathBuf};
 
 use clap::{ArgAction, CommandFactory, FromArgMatches, Parser};
 
+#[cfg(feature = "enrichment-tables-rocksdb")]
+use crate::enrichment;
+#[cfg(feature = "api-client")]
+use crate::ingest;
 #[cfg(windows)]
 use crate::service;
 #[cfg(feature = "api-client")]
+use crate::status;
+#[cfg(feature = "api-client")]
 use crate::tap;
 #[cfg(feature = "api-client")]
 use crate::top;
-use crate::{config, convert_config, generate, get_version, graph, list, unit_test, validate};
+use crate::{
+    check_config, config, convert_config, generate, get_version, graph, healthcheck, list,
+    unit_test, validate,
+};
 use crate::{generate_schema, signal};
 
 #[derive(Parser, Debug)]
@@ -274,6 +283,14 @@ pub enum SubCommand {
     /// Validate the target config, then exit.
     Validate(validate::Opts),
 
+    /// Check the target config without starting any component, printing the errors found as JSON,
+    /// then exit. Exits with code 1 if any error is found.
+    CheckConfig(check_config::Opts),
+
+    /// Run the healthchecks of the sinks of the target config, without sending any event, then
+    /// exit. Exits with code 1 if any sink is not healthy.
+    Healthcheck(healthcheck::Opts),
+
     /// Convert a config file from one format to another.
     /// This command can also walk directories recursively and convert all config files that are discovered.
     /// Note that this is a best effort conversion due to the following reasons:
@@ -309,10 +326,24 @@ pub enum SubCommand {
     /// Output the topology as visual representation using the DOT language which can be rendered by GraphViz
     Graph(graph::Opts),
 
+    /// Build the data of enrichment tables ahead of deployment.
+    #[cfg(feature = "enrichment-tables-rocksdb")]
+    Enrichment(enrichment::Opts),
+
     /// Display topology and metrics in the console, for a local or remote Vector instance
     #[cfg(feature = "api-client")]
     Top(top::Opts),
 
+    /// Write events read from stdin, one per line, directly into a sink of a local or remote
+    /// Vector instance. The instance must have `api.allow_mutations` enabled.
+    #[cfg(feature = "api-client")]
+    Ingest(ingest::Opts),
+
+    /// Display the health and throughput of each component of a local or remote Vector instance.
+    /// Exits with code 1 if any component is not healthy.
+    #[cfg(feature = "api-client")]
+    Status(status::Opts),
+
     /// Observe output log events from source or transform components. Logs are sampled at a specified interval.
     #[cfg(feature = "api-client")]
     Tap(tap::Opts),
@@ -332,15 +363,23 @@ impl SubCommand {
         color: bool,
     ) -> exitcode::ExitCode {
         match self {
+            Self::CheckConfig(c) => check_config::cmd(c).await,
             Self::Config(c) => config::cmd(c),
             Self::ConvertConfig(opts) => convert_config::cmd(opts),
+            #[cfg(feature = "enrichment-tables-rocksdb")]
+            Self::Enrichment(e) => enrichment::cmd(e),
             Self::Generate(g) => generate::cmd(g),
             Self::GenerateSchema(opts) => generate_schema::cmd(opts),
             Self::Graph(g) => graph::cmd(g),
+            Self::Healthcheck(h) => healthcheck::cmd(h, color).await,
+            #[cfg(feature = "api-client")]
+            Self::Ingest(i) => ingest::cmd(i).await,
             Self::List(l) => list::cmd(l),
             #[cfg(windows)]
             Self::Service(s) => service::cmd(s),
             #[cfg(feature = "api-client")]
+            Self::Status(s) => status::cmd(s, color).await,
+            #[cfg(feature = "api-client")]
             Self::Tap(t) => tap::cmd(t, signals.receiver).await,
             Self::Test(t) => unit_test::cmd(t, &mut signals.handler).await,
             #[cfg(feature = "api-client")]
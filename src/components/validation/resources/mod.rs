@@ -152,6 +152,7 @@ fn deserializer_config_to_serializer(config: &DeserializerConfig) -> encoding::S
         // `message` field... but it's close enough for now.
         DeserializerConfig::Bytes => SerializerConfig::Text(TextSerializerConfig::default()),
         DeserializerConfig::Json { .. } => SerializerConfig::Json(JsonSerializerConfig::default()),
+        DeserializerConfig::MsgPack => SerializerConfig::MsgPack(Default::default()),
         DeserializerConfig::Protobuf(config) => {
             SerializerConfig::Protobuf(vector_lib::codecs::encoding::ProtobufSerializerConfig {
                 protobuf: vector_lib::codecs::encoding::ProtobufSerializerOptions {
@@ -215,6 +216,7 @@ fn serializer_config_to_deserializer(
         SerializerConfig::Gelf => DeserializerConfig::Gelf(Default::default()),
         SerializerConfig::Json(_) => DeserializerConfig::Json(Default::default()),
         SerializerConfig::Logfmt => todo!(),
+        SerializerConfig::MsgPack(_) => DeserializerConfig::MsgPack,
         SerializerConfig::Native => DeserializerConfig::Native,
         SerializerConfig::NativeJson => DeserializerConfig::NativeJson(Default::default()),
         SerializerConfig::Protobuf(config) => {
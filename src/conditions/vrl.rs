@@ -27,11 +27,28 @@ pub struct VrlConfig {
 
 impl_generate_config_from_default!(VrlConfig);
 
-impl ConditionalConfig for VrlConfig {
-    fn build(
+impl VrlConfig {
+    pub(crate) fn new(source: impl Into<String>) -> Self {
+        Self {
+            source: source.into(),
+            runtime: VrlRuntime::default(),
+        }
+    }
+
+    /// Compiles the condition, formatting any diagnostics without terminal colors so that they
+    /// can be displayed elsewhere, such as in a UI.
+    pub(crate) fn build_uncolored(
         &self,
         enrichment_tables: &vector_lib::enrichment::TableRegistry,
-    ) -> crate::Result<Condition> {
+    ) -> Result<Condition, String> {
+        self.compile(enrichment_tables, false)
+    }
+
+    fn compile(
+        &self,
+        enrichment_tables: &vector_lib::enrichment::TableRegistry,
+        colored: bool,
+    ) -> Result<Condition, String> {
         // TODO(jean): re-add this to VRL
         // let constraint = TypeConstraint {
         //     allow_any: false,
@@ -63,13 +80,16 @@ impl ConditionalConfig for VrlConfig {
             warnings,
             config: _,
         } = compile_vrl(&self.source, &functions, &state, config).map_err(|diagnostics| {
-            Formatter::new(&self.source, diagnostics)
-                .colored()
-                .to_string()
+            let formatter = Formatter::new(&self.source, diagnostics);
+            if colored {
+                formatter.colored().to_string()
+            } else {
+                formatter.to_string()
+            }
         })?;
 
         if !program.final_type_info().result.is_boolean() {
-            return Err("VRL conditions must return a boolean.".into());
+            return Err("VRL conditions must return a boolean.".to_string());
         }
 
         if !warnings.is_empty() {
@@ -86,6 +106,15 @@ impl ConditionalConfig for VrlConfig {
     }
 }
 
+impl ConditionalConfig for VrlConfig {
+    fn build(
+        &self,
+        enrichment_tables: &vector_lib::enrichment::TableRegistry,
+    ) -> crate::Result<Condition> {
+        self.compile(enrichment_tables, true).map_err(Into::into)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Vrl {
     pub(super) program: Program,
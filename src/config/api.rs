@@ -36,6 +36,16 @@ pub struct Options {
     #[serde(default = "default_graphql", skip_serializing_if = "is_true")]
     #[configurable(metadata(docs::common = true, docs::required = false))]
     pub graphql: bool,
+
+    /// Whether the GraphQL mutations changing the running topology, such as ingesting events
+    /// into a sink, are enabled for the API.
+    ///
+    /// The API doesn't authenticate requests, so only enable this when the API address can't be
+    /// reached by untrusted clients. Mutations sent from a browser page of another origin are
+    /// always rejected.
+    #[serde(default)]
+    #[configurable(metadata(docs::common = false, docs::required = false))]
+    pub allow_mutations: bool,
 }
 
 impl_generate_config_from_default!(Options);
@@ -47,6 +57,7 @@ impl Default for Options {
             playground: default_playground(),
             address: default_address(),
             graphql: default_graphql(),
+            allow_mutations: false,
         }
     }
 }
@@ -109,6 +120,7 @@ impl Options {
             enabled: self.enabled | other.enabled,
             playground: self.playground & other.playground,
             graphql: self.graphql & other.graphql,
+            allow_mutations: self.allow_mutations | other.allow_mutations,
         };
 
         *self = options;
@@ -123,6 +135,7 @@ fn bool_merge() {
         address: None,
         playground: false,
         graphql: false,
+        allow_mutations: true,
     };
 
     a.merge(Options::default()).unwrap();
@@ -133,7 +146,8 @@ fn bool_merge() {
             enabled: true,
             address: default_address(),
             playground: false,
-            graphql: false
+            graphql: false,
+            allow_mutations: true,
         }
     );
 }
@@ -146,6 +160,7 @@ fn bind_merge() {
         address: Some(address),
         playground: true,
         graphql: true,
+        allow_mutations: false,
     };
 
     a.merge(Options::default()).unwrap();
@@ -157,6 +172,7 @@ fn bind_merge() {
             address: Some(address),
             playground: true,
             graphql: true,
+            allow_mutations: false,
         }
     );
 }
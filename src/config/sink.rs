@@ -15,7 +15,7 @@ use vector_lib::configurable::{
 use vector_lib::{
     config::{AcknowledgementsConfig, GlobalOptions, Input},
     id::Inputs,
-    sink::VectorSink,
+    sink::{PauseSignal, VectorSink},
 };
 
 use super::{dot_graph::GraphConfig, schema, ComponentKey, ProxyConfig, Resource};
@@ -262,6 +262,11 @@ pub trait SinkConfig: DynClone + NamedComponent + core::fmt::Debug + Send + Sync
 
     /// Gets the acknowledgements configuration for this sink.
     fn acknowledgements(&self) -> &AcknowledgementsConfig;
+
+    /// Whether this sink holds back events while its `SinkContext::pause` signal is paused.
+    fn supports_pause(&self) -> bool {
+        false
+    }
 }
 
 dyn_clone::clone_trait_object!(SinkConfig);
@@ -279,6 +284,10 @@ pub struct SinkContext {
     /// Extra context data provided by the running app and shared across all components. This can be
     /// used to pass shared settings or other data from outside the components.
     pub extra_context: ExtraContext,
+
+    /// Signal used to pause and resume the sink while it's running, through the API. Only honored
+    /// by sinks which support pausing.
+    pub pause: PauseSignal,
 }
 
 impl Default for SinkContext {
@@ -292,6 +301,7 @@ impl Default for SinkContext {
             app_name: crate::get_app_name().to_string(),
             app_name_slug: crate::get_slugified_app_name(),
             extra_context: Default::default(),
+            pause: Default::default(),
         }
     }
 }
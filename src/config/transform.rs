@@ -231,6 +231,23 @@ pub trait TransformConfig: DynClone + NamedComponent + core::fmt::Debug + Send +
         Ok(())
     }
 
+    /// Checks the configuration of the transform further than `validate`, without building it.
+    ///
+    /// This is where the programs run by the transform are compiled, for instance. It's only used
+    /// to check configurations ahead of time, as building the transform reports the same errors.
+    ///
+    /// # Errors
+    ///
+    /// If the check does not succeed, an error variant containing a list of all errors found is
+    /// returned.
+    fn check(
+        &self,
+        _enrichment_tables: vector_lib::enrichment::TableRegistry,
+        _merged_definition: &schema::Definition,
+    ) -> Result<(), Vec<String>> {
+        Ok(())
+    }
+
     /// Whether or not concurrency should be enabled for this transform.
     ///
     /// When enabled, this transform may be run in parallel in order to attempt to maximize
@@ -0,0 +1,67 @@
+//! Enrichment subcommand
+use std::path::PathBuf;
+
+use clap::Parser;
+
+use crate::enrichment_tables::rocksdb;
+
+#[derive(Parser, Debug)]
+#[command(rename_all = "kebab-case")]
+pub struct Opts {
+    #[command(subcommand)]
+    sub_command: SubCommand,
+}
+
+#[derive(clap::Subcommand, Debug)]
+#[command(rename_all = "kebab-case")]
+enum SubCommand {
+    /// Build a RocksDB database, to be used by a `rocksdb` enrichment table, from a CSV file whose
+    /// first row holds the names of its columns.
+    BuildRocksdb(BuildRocksdbOpts),
+}
+
+#[derive(Parser, Debug)]
+#[command(rename_all = "kebab-case")]
+struct BuildRocksdbOpts {
+    /// The CSV file to read the rows from
+    #[arg(long)]
+    input_csv: PathBuf,
+
+    /// The directory to write the database to, which must not exist
+    #[arg(long)]
+    output_path: PathBuf,
+
+    /// The column holding the keys rows are looked up by. Defaults to the first column
+    #[arg(long)]
+    key_column: Option<String>,
+}
+
+/// Runs the enrichment subcommand.
+pub fn cmd(opts: &Opts) -> exitcode::ExitCode {
+    match &opts.sub_command {
+        SubCommand::BuildRocksdb(opts) => build_rocksdb(opts),
+    }
+}
+
+fn build_rocksdb(opts: &BuildRocksdbOpts) -> exitcode::ExitCode {
+    match rocksdb::build_from_csv(
+        &opts.input_csv,
+        &opts.output_path,
+        opts.key_column.as_deref(),
+    ) {
+        Ok(rows) => {
+            #[allow(clippy::print_stdout)]
+            {
+                println!("Wrote {} rows to {}.", rows, opts.output_path.display());
+            }
+            exitcode::OK
+        }
+        Err(error) => {
+            #[allow(clippy::print_stderr)]
+            {
+                eprintln!("Couldn't build the RocksDB database: {}", error);
+            }
+            exitcode::DATAERR
+        }
+    }
+}
@@ -0,0 +1,395 @@
+//! Handles enrichment tables for `type = http`.
+//! Enrichment data is fetched as a JSON array of objects from an HTTP endpoint, and looked up by
+//! the value of one of their fields.
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+use arc_swap::ArcSwap;
+use http::{header, Request};
+use hyper::Body;
+use vector_lib::configurable::configurable_component;
+use vector_lib::enrichment::{Case, Condition, IndexHandle, Table};
+use vrl::value::{ObjectMap, Value};
+
+use crate::{
+    config::{EnrichmentTableConfig, GenerateConfig, ProxyConfig},
+    http::HttpClient,
+    internal_events::HttpEnrichmentTableRefreshError,
+    sinks::util::UriSerde,
+    tls::{TlsConfig, TlsSettings},
+};
+
+/// Configuration for the `http` enrichment table.
+#[configurable_component(enrichment_table("http"))]
+#[derive(Clone, Debug)]
+pub struct HttpConfig {
+    /// The URL to fetch the enrichment data from.
+    ///
+    /// The response must be a JSON array of objects.
+    #[configurable(metadata(docs::examples = "https://example.com/tenants.json"))]
+    pub url: UriSerde,
+
+    /// The field of the objects holding the keys they are looked up by.
+    #[configurable(metadata(docs::examples = "tenant_id"))]
+    pub key_field: String,
+
+    /// How long, in seconds, the fetched data is used before it's fetched again.
+    ///
+    /// Once the data is older than this, the next lookup triggers a refresh in the background,
+    /// and lookups keep using the previously fetched data until the refresh completes. If the
+    /// refresh fails, the previously fetched data is kept.
+    #[serde(default = "default_ttl_secs")]
+    #[configurable(metadata(docs::type_unit = "seconds"))]
+    pub ttl_secs: u64,
+
+    #[configurable(derived)]
+    pub tls: Option<TlsConfig>,
+}
+
+const fn default_ttl_secs() -> u64 {
+    300
+}
+
+impl GenerateConfig for HttpConfig {
+    fn generate_config() -> toml::Value {
+        toml::Value::try_from(Self {
+            url: "https://example.com/tenants.json".parse().unwrap(),
+            key_field: "tenant_id".to_string(),
+            ttl_secs: default_ttl_secs(),
+            tls: None,
+        })
+        .unwrap()
+    }
+}
+
+impl EnrichmentTableConfig for HttpConfig {
+    async fn build(
+        &self,
+        globals: &crate::config::GlobalOptions,
+    ) -> crate::Result<Box<dyn Table + Send + Sync>> {
+        let tls = TlsSettings::from_options(self.tls.as_ref())?;
+        let proxy = ProxyConfig::merge_with_env(&globals.proxy, &ProxyConfig::default());
+        let client = HttpClient::new(tls, &proxy)?;
+        Ok(Box::new(Http::new(self.clone(), client).await?))
+    }
+}
+
+/// The rows fetched from the endpoint, keyed by the value of their key field.
+struct Data {
+    rows: HashMap<String, ObjectMap>,
+    fetched_at: Instant,
+}
+
+#[derive(Clone)]
+/// A struct that implements [vector_lib::enrichment::Table] to handle looking up enrichment data
+/// fetched from an HTTP endpoint.
+pub struct Http {
+    config: HttpConfig,
+    client: HttpClient,
+    data: Arc<ArcSwap<Data>>,
+    refreshing: Arc<AtomicBool>,
+}
+
+impl Http {
+    /// Creates a new Http struct from the provided config, fetching the data from the endpoint.
+    pub async fn new(config: HttpConfig, client: HttpClient) -> crate::Result<Self> {
+        let rows = fetch(&client, &config).await?;
+        Ok(Self {
+            data: Arc::new(ArcSwap::from_pointee(Data {
+                rows,
+                fetched_at: Instant::now(),
+            })),
+            refreshing: Arc::new(AtomicBool::new(false)),
+            client,
+            config,
+        })
+    }
+
+    /// Returns the row holding the given key, if any, refreshing the data in the background if
+    /// it's expired.
+    pub fn query(&self, key: &str) -> Option<ObjectMap> {
+        let data = self.data.load();
+        if data.fetched_at.elapsed() >= Duration::from_secs(self.config.ttl_secs) {
+            self.refresh();
+        }
+        data.rows.get(key).cloned()
+    }
+
+    /// Fetches the data in the background, unless it's already being fetched.
+    fn refresh(&self) {
+        let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+            return;
+        };
+        if self.refreshing.swap(true, Ordering::AcqRel) {
+            return;
+        }
+
+        let table = self.clone();
+        runtime.spawn(async move {
+            match fetch(&table.client, &table.config).await {
+                Ok(rows) => {
+                    debug!(
+                        message = "Refreshed HTTP enrichment table.",
+                        rows = rows.len()
+                    );
+                    table.data.store(Arc::new(Data {
+                        rows,
+                        fetched_at: Instant::now(),
+                    }));
+                }
+                Err(error) => emit!(HttpEnrichmentTableRefreshError { error }),
+            }
+            table.refreshing.store(false, Ordering::Release);
+        });
+    }
+}
+
+async fn fetch(
+    client: &HttpClient,
+    config: &HttpConfig,
+) -> crate::Result<HashMap<String, ObjectMap>> {
+    let url = config.url.with_default_parts();
+    let mut request = Request::get(&url.uri)
+        .header(header::ACCEPT, "application/json")
+        .body(Body::empty())?;
+    if let Some(auth) = &url.auth {
+        auth.apply(&mut request);
+    }
+
+    let response = client.send(request).await?;
+    let status = response.status();
+    let body = hyper::body::to_bytes(response.into_body()).await?;
+    if !status.is_success() {
+        return Err(format!(
+            "The endpoint responded with {}: {}",
+            status,
+            String::from_utf8_lossy(&body)
+        )
+        .into());
+    }
+
+    parse(&body, &config.key_field)
+}
+
+/// Parses the response as an array of objects, keyed by the value of their key field. Objects
+/// without the key field are skipped.
+fn parse(body: &[u8], key_field: &str) -> crate::Result<HashMap<String, ObjectMap>> {
+    let objects = serde_json::from_slice::<Vec<serde_json::Map<String, serde_json::Value>>>(body)?;
+    Ok(objects
+        .into_iter()
+        .filter_map(|object| {
+            let row = object
+                .into_iter()
+                .map(|(field, value)| (field.into(), Value::from(value)))
+                .collect::<ObjectMap>();
+            let key = row.get(key_field)?.to_string_lossy().into_owned();
+            Some((key, row))
+        })
+        .collect())
+}
+
+impl Table for Http {
+    /// Search the enrichment table data with the given condition.
+    /// All conditions must match (AND).
+    ///
+    /// # Errors
+    /// Errors if no rows, or more than 1 row is found.
+    fn find_table_row<'a>(
+        &self,
+        case: Case,
+        condition: &'a [Condition<'a>],
+        select: Option<&[String]>,
+        index: Option<IndexHandle>,
+    ) -> Result<ObjectMap, String> {
+        let mut rows = self.find_table_rows(case, condition, select, index)?;
+
+        match rows.pop() {
+            Some(row) if rows.is_empty() => Ok(row),
+            Some(_) => Err("More than 1 row found".to_string()),
+            None => Err("Key not found".to_string()),
+        }
+    }
+
+    /// Search the enrichment table data with the given condition.
+    /// All conditions must match (AND).
+    /// Can return multiple matched records
+    fn find_table_rows<'a>(
+        &self,
+        _: Case,
+        condition: &'a [Condition<'a>],
+        select: Option<&[String]>,
+        _: Option<IndexHandle>,
+    ) -> Result<Vec<ObjectMap>, String> {
+        match condition.first() {
+            Some(_) if condition.len() > 1 => Err("Only one condition is allowed".to_string()),
+            Some(Condition::Equals { field, value }) if *field == self.config.key_field => {
+                let Some(row) = self.query(&value.to_string_lossy()) else {
+                    return Ok(Vec::new());
+                };
+                let row = match select {
+                    Some(fields) => fields
+                        .iter()
+                        .map(|field| {
+                            let value = row.get(field.as_str()).cloned();
+                            (field.as_str().into(), value.unwrap_or(Value::Null))
+                        })
+                        .collect(),
+                    None => row,
+                };
+                Ok(vec![row])
+            }
+            Some(Condition::Equals { .. }) => Err(format!(
+                "Only the {} field can be searched",
+                self.config.key_field
+            )),
+            Some(_) => Err("Only equality condition is allowed".to_string()),
+            None => Err("Key condition must be specified".to_string()),
+        }
+    }
+
+    /// Hints to the enrichment table what data is going to be searched to allow it to index the
+    /// data in advance.
+    ///
+    /// # Errors
+    /// Errors if the fields are not in the table.
+    fn add_index(&mut self, _: Case, fields: &[&str]) -> Result<IndexHandle, String> {
+        match fields {
+            [] => Err(format!("The {} field is required", self.config.key_field)),
+            [field] if *field == self.config.key_field => Ok(IndexHandle(0)),
+            _ => Err(format!(
+                "Only the {} field is allowed",
+                self.config.key_field
+            )),
+        }
+    }
+
+    /// Returns a list of the field names that are in each index
+    fn index_fields(&self) -> Vec<(Case, Vec<String>)> {
+        Vec::new()
+    }
+
+    /// Returns true if the underlying data has changed and the table needs reloading.
+    ///
+    /// The data is refreshed in place, so the table never needs reloading.
+    fn needs_reload(&self) -> bool {
+        false
+    }
+}
+
+impl std::fmt::Debug for Http {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "HTTP enrichment table {}", self.config.url.uri)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table(rows: HashMap<String, ObjectMap>, fetched_at: Instant) -> Http {
+        Http {
+            config: HttpConfig {
+                // Nothing listens on this address, so refreshes always fail.
+                url: "http://127.0.0.1:1/tenants.json".parse().unwrap(),
+                key_field: "tenant_id".to_string(),
+                ttl_secs: 60,
+                tls: None,
+            },
+            client: HttpClient::new(None, &ProxyConfig::default()).unwrap(),
+            data: Arc::new(ArcSwap::from_pointee(Data { rows, fetched_at })),
+            refreshing: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    fn find(table: &Http, key: &str, select: Option<&[String]>) -> Option<ObjectMap> {
+        table
+            .find_table_rows(
+                Case::Sensitive,
+                &[Condition::Equals {
+                    field: "tenant_id",
+                    value: key.into(),
+                }],
+                select,
+                None,
+            )
+            .unwrap()
+            .pop()
+    }
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<HttpConfig>();
+    }
+
+    #[test]
+    fn parses_rows_by_key() {
+        let rows = parse(
+            br#"[
+                {"tenant_id": "acme", "plan": "enterprise", "seats": 40},
+                {"tenant_id": 7, "plan": "free"},
+                {"plan": "orphan"}
+            ]"#,
+            "tenant_id",
+        )
+        .unwrap();
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows["acme"]["seats"], Value::from(40));
+        assert_eq!(rows["7"]["plan"], Value::from("free"));
+        assert!(parse(br#"{"tenant_id": "acme"}"#, "tenant_id").is_err());
+    }
+
+    #[tokio::test]
+    async fn lookup_and_select() {
+        let rows = parse(br#"[{"tenant_id": "acme", "plan": "pro"}]"#, "tenant_id").unwrap();
+        let table = table(rows, Instant::now());
+
+        let expected = ObjectMap::from([("plan".into(), Value::from("pro"))]);
+        assert_eq!(
+            find(&table, "acme", Some(&["plan".to_string()])),
+            Some(expected)
+        );
+        assert_eq!(find(&table, "globex", None), None);
+        assert!(!table.refreshing.load(Ordering::Acquire));
+    }
+
+    #[tokio::test]
+    async fn serves_stale_rows_while_refreshing() {
+        let rows = parse(br#"[{"tenant_id": "acme", "plan": "pro"}]"#, "tenant_id").unwrap();
+        let stale = Instant::now() - Duration::from_secs(120);
+        let table = table(rows, stale);
+
+        assert!(find(&table, "acme", None).is_some());
+        assert!(table.refreshing.load(Ordering::Acquire));
+
+        // The refresh fails, so the stale rows are kept.
+        while table.refreshing.load(Ordering::Acquire) {
+            tokio::task::yield_now().await;
+        }
+        assert!(find(&table, "acme", None).is_some());
+    }
+
+    #[test]
+    fn rejects_other_fields() {
+        let mut table = table(HashMap::new(), Instant::now());
+
+        assert!(table.add_index(Case::Sensitive, &["plan"]).is_err());
+        assert!(table
+            .find_table_rows(
+                Case::Sensitive,
+                &[Condition::Equals {
+                    field: "plan",
+                    value: "pro".into(),
+                }],
+                None,
+                None,
+            )
+            .is_err());
+    }
+}
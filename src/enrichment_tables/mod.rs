@@ -18,12 +18,24 @@ pub mod geoip;
 #[cfg(feature = "enrichment-tables-mmdb")]
 pub mod mmdb;
 
+#[cfg(feature = "enrichment-tables-sqlite")]
+pub mod sqlite;
+
+#[cfg(feature = "enrichment-tables-http")]
+pub mod http;
+
+#[cfg(feature = "enrichment-tables-rocksdb")]
+pub mod rocksdb;
+
 /// Configuration options for an [enrichment table](https://vector.dev/docs/reference/glossary/#enrichment-tables) to be used in a
 /// [`remap`](https://vector.dev/docs/reference/configuration/transforms/remap/) transform. Currently supported are:
 ///
 /// * [CSV](https://en.wikipedia.org/wiki/Comma-separated_values) files
 /// * [MaxMind](https://www.maxmind.com/en/home) databases
 /// * In-memory storage
+/// * [SQLite](https://www.sqlite.org/) databases
+/// * JSON data fetched from HTTP endpoints
+/// * [RocksDB](https://rocksdb.org/) databases
 ///
 /// For the lookup in the enrichment tables to be as performant as possible, the data is indexed according
 /// to the fields that are used in the search. Note that indices can only be created for fields for which an
@@ -63,6 +75,23 @@ pub enum EnrichmentTables {
     /// [maxmind]: https://www.maxmind.com/
     #[cfg(feature = "enrichment-tables-mmdb")]
     Mmdb(mmdb::MmdbConfig),
+
+    /// Exposes the rows of a [SQLite][sqlite] database table as an enrichment table.
+    ///
+    /// [sqlite]: https://www.sqlite.org/
+    #[cfg(feature = "enrichment-tables-sqlite")]
+    Sqlite(sqlite::SqliteConfig),
+
+    /// Exposes the rows of a JSON array fetched from an HTTP endpoint as an enrichment table.
+    #[cfg(feature = "enrichment-tables-http")]
+    Http(http::HttpConfig),
+
+    /// Exposes the rows of a [RocksDB][rocksdb] database as an enrichment table, without loading
+    /// them into memory.
+    ///
+    /// [rocksdb]: https://rocksdb.org/
+    #[cfg(feature = "enrichment-tables-rocksdb")]
+    Rocksdb(rocksdb::RocksdbConfig),
 }
 
 impl GenerateConfig for EnrichmentTables {
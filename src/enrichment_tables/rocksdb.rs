@@ -0,0 +1,416 @@
+//! Handles enrichment tables for `type = rocksdb`.
+//! Enrichment data is looked up by key in a [RocksDB][rocksdb] database, which is read from the
+//! disk rather than loaded into memory. The database is built from a CSV file with the
+//! `vector enrichment build-rocksdb` command.
+//!
+//! [rocksdb]: https://rocksdb.org/
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::SystemTime,
+};
+
+use rocksdb::{Direction, IteratorMode, Options, WriteBatch, DB};
+use serde_json::Map;
+use vector_lib::configurable::configurable_component;
+use vector_lib::enrichment::{Case, Condition, IndexHandle, Table};
+use vrl::value::{ObjectMap, Value};
+
+use crate::config::{EnrichmentTableConfig, GenerateConfig};
+
+/// The number of rows written to the database at once when it's built.
+const WRITE_BATCH_SIZE: usize = 10_000;
+
+/// Configuration for the `rocksdb` enrichment table.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[configurable_component(enrichment_table("rocksdb"))]
+pub struct RocksdbConfig {
+    /// The path of the [RocksDB][rocksdb] database directory.
+    ///
+    /// The database is opened read-only, and can be built from a CSV file with the
+    /// `vector enrichment build-rocksdb` command.
+    ///
+    /// [rocksdb]: https://rocksdb.org/
+    pub path: PathBuf,
+
+    /// The field holding the keys rows are looked up by.
+    ///
+    /// This is the column the database was built with as the key column.
+    pub key_field: String,
+}
+
+impl GenerateConfig for RocksdbConfig {
+    fn generate_config() -> toml::Value {
+        toml::Value::try_from(Self {
+            path: "/path/to/enrichment.db".into(),
+            key_field: "ip".to_string(),
+        })
+        .unwrap()
+    }
+}
+
+impl EnrichmentTableConfig for RocksdbConfig {
+    async fn build(
+        &self,
+        _: &crate::config::GlobalOptions,
+    ) -> crate::Result<Box<dyn Table + Send + Sync>> {
+        Ok(Box::new(Rocksdb::new(self.clone())?))
+    }
+}
+
+#[derive(Clone)]
+/// A struct that implements [vector_lib::enrichment::Table] to handle looking up enrichment data
+/// in a RocksDB database.
+pub struct Rocksdb {
+    config: RocksdbConfig,
+    db: Arc<DB>,
+    last_modified: Option<SystemTime>,
+}
+
+impl Rocksdb {
+    /// Creates a new Rocksdb struct from the provided config, opening the database read-only.
+    pub fn new(config: RocksdbConfig) -> crate::Result<Self> {
+        let last_modified = modified(&config.path);
+        let db = DB::open_for_read_only(&Options::default(), &config.path, false)?;
+        Ok(Self {
+            config,
+            db: Arc::new(db),
+            last_modified,
+        })
+    }
+
+    /// Returns the row holding the given key, if any.
+    pub fn get(&self, key: &str) -> crate::Result<Option<ObjectMap>> {
+        self.db
+            .get_pinned(key)?
+            .map(|row| parse_row(&row))
+            .transpose()
+    }
+
+    /// Returns the rows whose key starts with the given prefix, in the order of their keys.
+    pub fn find(&self, prefix: &str) -> crate::Result<Vec<ObjectMap>> {
+        let mode = IteratorMode::From(prefix.as_bytes(), Direction::Forward);
+        let mut rows = Vec::new();
+        for entry in self.db.iterator(mode) {
+            let (key, row) = entry?;
+            if !key.starts_with(prefix.as_bytes()) {
+                break;
+            }
+            rows.push(parse_row(&row)?);
+        }
+        Ok(rows)
+    }
+
+    fn key<'a>(&self, condition: &'a [Condition<'a>]) -> Result<String, String> {
+        match condition.first() {
+            Some(_) if condition.len() > 1 => Err("Only one condition is allowed".to_string()),
+            Some(Condition::Equals { field, value }) if *field == self.config.key_field => {
+                Ok(value.to_string_lossy().into_owned())
+            }
+            Some(Condition::Equals { .. }) => Err(format!(
+                "Only the {} field can be searched",
+                self.config.key_field
+            )),
+            Some(_) => Err("Only equality condition is allowed".to_string()),
+            None => Err("Key condition must be specified".to_string()),
+        }
+    }
+}
+
+/// Returns the last time the database was modified, which is when its `CURRENT` file, pointing
+/// to its latest manifest, was written.
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path.join("CURRENT"))
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
+
+fn parse_row(row: &[u8]) -> crate::Result<ObjectMap> {
+    let row = serde_json::from_slice::<Map<String, serde_json::Value>>(row)?;
+    Ok(row
+        .into_iter()
+        .map(|(field, value)| (field.into(), Value::from(value)))
+        .collect())
+}
+
+fn select_fields(row: ObjectMap, select: Option<&[String]>) -> ObjectMap {
+    match select {
+        Some(fields) => fields
+            .iter()
+            .map(|field| {
+                let value = row.get(field.as_str()).cloned();
+                (field.as_str().into(), value.unwrap_or(Value::Null))
+            })
+            .collect(),
+        None => row,
+    }
+}
+
+/// Builds a RocksDB database at `output_path` from the CSV file at `input_csv`, whose first row
+/// holds the names of its columns. Each row is stored as a JSON object, keyed by the value of its
+/// `key_column`, or of its first column if unset.
+///
+/// Returns the number of rows written.
+pub fn build_from_csv(
+    input_csv: &Path,
+    output_path: &Path,
+    key_column: Option<&str>,
+) -> crate::Result<usize> {
+    if output_path.exists() {
+        return Err(format!("{} already exists", output_path.display()).into());
+    }
+
+    let mut reader = csv::Reader::from_path(input_csv)?;
+    let headers = reader.headers()?.clone();
+    let key_index = match key_column {
+        Some(key_column) => headers
+            .iter()
+            .position(|header| header == key_column)
+            .ok_or_else(|| format!("The CSV file has no {} column", key_column))?,
+        None if headers.is_empty() => return Err("The CSV file has no columns".into()),
+        None => 0,
+    };
+
+    let mut options = Options::default();
+    options.create_if_missing(true);
+    options.set_error_if_exists(true);
+    let db = DB::open(&options, output_path)?;
+
+    let mut batch = WriteBatch::default();
+    let mut rows = 0;
+    for record in reader.records() {
+        let record = record?;
+        let key = record
+            .get(key_index)
+            .ok_or_else(|| format!("Row {} has no key", rows + 1))?;
+        let row = headers
+            .iter()
+            .zip(record.iter())
+            .map(|(header, value)| (header.to_string(), value.into()))
+            .collect::<Map<_, _>>();
+        batch.put(key, serde_json::to_vec(&row)?);
+
+        rows += 1;
+        if batch.len() >= WRITE_BATCH_SIZE {
+            db.write(std::mem::take(&mut batch))?;
+        }
+    }
+    db.write(batch)?;
+
+    // Compacting the database up front spares the readers from doing it.
+    db.flush()?;
+    db.compact_range::<&[u8], &[u8]>(None, None);
+    Ok(rows)
+}
+
+impl Table for Rocksdb {
+    /// Search the enrichment table data with the given condition.
+    /// All conditions must match (AND).
+    ///
+    /// # Errors
+    /// Errors if no rows, or more than 1 row is found.
+    fn find_table_row<'a>(
+        &self,
+        _: Case,
+        condition: &'a [Condition<'a>],
+        select: Option<&[String]>,
+        _: Option<IndexHandle>,
+    ) -> Result<ObjectMap, String> {
+        let key = self.key(condition)?;
+        match self.get(&key) {
+            Ok(Some(row)) => Ok(select_fields(row, select)),
+            Ok(None) => Err("Key not found".to_string()),
+            Err(error) => {
+                error!(
+                    message = "Failed to query RocksDB enrichment table.",
+                    %error,
+                    internal_log_rate_limit = true
+                );
+                Err(error.to_string())
+            }
+        }
+    }
+
+    /// Search the enrichment table data with the given condition.
+    /// All conditions must match (AND).
+    /// Can return multiple matched records
+    ///
+    /// The rows whose key starts with the value of the condition are returned.
+    fn find_table_rows<'a>(
+        &self,
+        _: Case,
+        condition: &'a [Condition<'a>],
+        select: Option<&[String]>,
+        _: Option<IndexHandle>,
+    ) -> Result<Vec<ObjectMap>, String> {
+        let prefix = self.key(condition)?;
+        match self.find(&prefix) {
+            Ok(rows) => Ok(rows
+                .into_iter()
+                .map(|row| select_fields(row, select))
+                .collect()),
+            Err(error) => {
+                error!(
+                    message = "Failed to query RocksDB enrichment table.",
+                    %error,
+                    internal_log_rate_limit = true
+                );
+                Err(error.to_string())
+            }
+        }
+    }
+
+    /// Hints to the enrichment table what data is going to be searched to allow it to index the
+    /// data in advance.
+    ///
+    /// # Errors
+    /// Errors if the fields are not in the table.
+    fn add_index(&mut self, _: Case, fields: &[&str]) -> Result<IndexHandle, String> {
+        match fields {
+            [] => Err(format!("The {} field is required", self.config.key_field)),
+            [field] if *field == self.config.key_field => Ok(IndexHandle(0)),
+            _ => Err(format!(
+                "Only the {} field is allowed",
+                self.config.key_field
+            )),
+        }
+    }
+
+    /// Returns a list of the field names that are in each index
+    fn index_fields(&self) -> Vec<(Case, Vec<String>)> {
+        Vec::new()
+    }
+
+    /// Returns true if the underlying data has changed and the table needs reloading.
+    fn needs_reload(&self) -> bool {
+        matches!((modified(&self.config.path), self.last_modified),
+            (Some(modified), Some(last_modified)) if modified > last_modified)
+    }
+}
+
+impl std::fmt::Debug for Rocksdb {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "RocksDB database {}", self.config.path.display())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{temp_dir, temp_file};
+
+    fn database() -> PathBuf {
+        let csv = temp_file();
+        fs::write(
+            &csv,
+            "region,ip,owner\n\
+             eu-west-1,10.0.0.1,alice\n\
+             eu-west-1,10.0.0.2,bob\n\
+             us-east-1,10.0.1.1,carol\n",
+        )
+        .unwrap();
+
+        let path = temp_dir().join("enrichment.db");
+        assert_eq!(build_from_csv(&csv, &path, Some("ip")).unwrap(), 3);
+        path
+    }
+
+    fn table() -> Rocksdb {
+        Rocksdb::new(RocksdbConfig {
+            path: database(),
+            key_field: "ip".to_string(),
+        })
+        .unwrap()
+    }
+
+    fn condition(value: &str) -> [Condition<'static>; 1] {
+        [Condition::Equals {
+            field: "ip",
+            value: value.into(),
+        }]
+    }
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<RocksdbConfig>();
+    }
+
+    #[test]
+    fn lookup_row() {
+        let table = table();
+
+        let expected = ObjectMap::from([
+            ("region".into(), Value::from("eu-west-1")),
+            ("ip".into(), Value::from("10.0.0.2")),
+            ("owner".into(), Value::from("bob")),
+        ]);
+        assert_eq!(
+            table.find_table_row(Case::Sensitive, &condition("10.0.0.2"), None, None),
+            Ok(expected)
+        );
+
+        let expected = ObjectMap::from([("owner".into(), Value::from("carol"))]);
+        assert_eq!(
+            table.find_table_row(
+                Case::Sensitive,
+                &condition("10.0.1.1"),
+                Some(&["owner".to_string()]),
+                None
+            ),
+            Ok(expected)
+        );
+
+        assert_eq!(
+            table.find_table_row(Case::Sensitive, &condition("10.0.0.3"), None, None),
+            Err("Key not found".to_string())
+        );
+    }
+
+    #[test]
+    fn lookup_rows_by_prefix() {
+        let table = table();
+        let select = ["owner".to_string()];
+
+        let rows = table
+            .find_table_rows(Case::Sensitive, &condition("10.0.0."), Some(&select), None)
+            .unwrap();
+        assert_eq!(
+            rows,
+            vec![
+                ObjectMap::from([("owner".into(), Value::from("alice"))]),
+                ObjectMap::from([("owner".into(), Value::from("bob"))]),
+            ]
+        );
+
+        assert!(table.find("10.0.2.").unwrap().is_empty());
+    }
+
+    #[test]
+    fn rejects_other_fields() {
+        let mut table = table();
+
+        assert!(table.add_index(Case::Sensitive, &["owner"]).is_err());
+        assert!(table
+            .find_table_rows(
+                Case::Sensitive,
+                &[Condition::Equals {
+                    field: "owner",
+                    value: "alice".into(),
+                }],
+                None,
+                None,
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn build_rejects_existing_output_and_unknown_key_column() {
+        let path = database();
+        let csv = temp_file();
+        fs::write(&csv, "ip,owner\n10.0.0.1,alice\n").unwrap();
+
+        assert!(build_from_csv(&csv, &path, None).is_err());
+        assert!(build_from_csv(&csv, &temp_dir().join("db"), Some("region")).is_err());
+    }
+}
@@ -0,0 +1,422 @@
+//! Handles enrichment tables for `type = sqlite`.
+//! Enrichment data is looked up by key in a table of a [SQLite][sqlite] database.
+//!
+//! [sqlite]: https://www.sqlite.org/
+use std::{
+    fs,
+    num::NonZeroUsize,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+    time::{Duration, SystemTime},
+};
+
+use bytes::Bytes;
+use lru::LruCache;
+use ordered_float::NotNan;
+use rusqlite::{backup::Backup, types::ValueRef, Connection, OpenFlags, OptionalExtension, Row};
+use vector_lib::configurable::configurable_component;
+use vector_lib::enrichment::{Case, Condition, IndexHandle, Table};
+use vrl::value::{ObjectMap, Value};
+
+use crate::config::{EnrichmentTableConfig, GenerateConfig};
+
+/// Configuration for the `sqlite` enrichment table.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[configurable_component(enrichment_table("sqlite"))]
+pub struct SqliteConfig {
+    /// The path of the [SQLite][sqlite] database file.
+    ///
+    /// The database is opened read-only and copied into memory when the table is loaded.
+    ///
+    /// [sqlite]: https://www.sqlite.org/
+    pub path: PathBuf,
+
+    /// The name of the database table to look rows up in.
+    pub table: String,
+
+    /// The column holding the keys rows are looked up by.
+    pub key_column: String,
+
+    /// The columns returned for each row.
+    ///
+    /// If unset, all the columns of the table are returned.
+    #[configurable(metadata(docs::examples = "name", docs::examples = "region"))]
+    pub columns: Option<Vec<String>>,
+
+    /// The number of lookup results to keep in memory.
+    ///
+    /// The most recently used keys are answered from this cache without querying the database.
+    /// Set to `0` to disable the cache.
+    #[serde(default = "default_cache_size")]
+    pub cache_size: usize,
+}
+
+const fn default_cache_size() -> usize {
+    1000
+}
+
+impl GenerateConfig for SqliteConfig {
+    fn generate_config() -> toml::Value {
+        toml::Value::try_from(Self {
+            path: "/path/to/database.sqlite".into(),
+            table: "hosts".to_string(),
+            key_column: "hostname".to_string(),
+            columns: None,
+            cache_size: default_cache_size(),
+        })
+        .unwrap()
+    }
+}
+
+impl EnrichmentTableConfig for SqliteConfig {
+    async fn build(
+        &self,
+        _: &crate::config::GlobalOptions,
+    ) -> crate::Result<Box<dyn Table + Send + Sync>> {
+        Ok(Box::new(Sqlite::new(self.clone())?))
+    }
+}
+
+/// The rows previously returned for the most recently used keys.
+type Cache = LruCache<String, Option<ObjectMap>>;
+
+#[derive(Clone)]
+/// A struct that implements [vector_lib::enrichment::Table] to handle looking up enrichment data
+/// in a SQLite database.
+pub struct Sqlite {
+    config: SqliteConfig,
+    connection: Arc<Mutex<Connection>>,
+    query: String,
+    column_names: Vec<String>,
+    cache: Option<Arc<Mutex<Cache>>>,
+    last_modified: SystemTime,
+}
+
+impl Sqlite {
+    /// Creates a new Sqlite struct from the provided config.
+    pub fn new(config: SqliteConfig) -> crate::Result<Self> {
+        let last_modified = fs::metadata(&config.path)?.modified()?;
+        let connection = load(&config)?;
+
+        let columns = match &config.columns {
+            Some(columns) => columns
+                .iter()
+                .map(|column| quote_identifier(column))
+                .collect::<Vec<_>>()
+                .join(", "),
+            None => "*".to_string(),
+        };
+        let query = format!(
+            "SELECT {columns} FROM {} WHERE {} = ?1 LIMIT 1",
+            quote_identifier(&config.table),
+            quote_identifier(&config.key_column),
+        );
+
+        // Preparing the query checks that the table and its columns exist.
+        let column_names = connection
+            .prepare(&query)?
+            .column_names()
+            .into_iter()
+            .map(String::from)
+            .collect();
+
+        Ok(Self {
+            cache: NonZeroUsize::new(config.cache_size)
+                .map(|size| Arc::new(Mutex::new(LruCache::new(size)))),
+            connection: Arc::new(Mutex::new(connection)),
+            query,
+            column_names,
+            last_modified,
+            config,
+        })
+    }
+
+    /// Returns the row holding the given key, if any.
+    pub fn query(&self, key: &str) -> Option<ObjectMap> {
+        if let Some(cache) = &self.cache {
+            if let Some(row) = cache.lock().expect("mutex poisoned").get(key) {
+                return row.clone();
+            }
+        }
+
+        let row = self.query_database(key).unwrap_or_else(|error| {
+            error!(
+                message = "Failed to query SQLite enrichment table.",
+                %error,
+                internal_log_rate_limit = true
+            );
+            None
+        });
+
+        if let Some(cache) = &self.cache {
+            cache
+                .lock()
+                .expect("mutex poisoned")
+                .put(key.to_string(), row.clone());
+        }
+        row
+    }
+
+    fn query_database(&self, key: &str) -> rusqlite::Result<Option<ObjectMap>> {
+        let connection = self.connection.lock().expect("mutex poisoned");
+        let mut statement = connection.prepare_cached(&self.query)?;
+        statement
+            .query_row([key], |row| row_to_object(row, &self.column_names))
+            .optional()
+    }
+}
+
+/// Copies the database into memory, so lookups never read from the disk.
+fn load(config: &SqliteConfig) -> rusqlite::Result<Connection> {
+    let file = Connection::open_with_flags(
+        &config.path,
+        OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_NO_MUTEX,
+    )?;
+    let mut connection = Connection::open_in_memory()?;
+    Backup::new(&file, &mut connection)?.run_to_completion(-1, Duration::ZERO, None)?;
+    connection.pragma_update(None, "query_only", true)?;
+    Ok(connection)
+}
+
+fn quote_identifier(identifier: &str) -> String {
+    format!("\"{}\"", identifier.replace('"', "\"\""))
+}
+
+fn row_to_object(row: &Row<'_>, column_names: &[String]) -> rusqlite::Result<ObjectMap> {
+    column_names
+        .iter()
+        .enumerate()
+        .map(|(index, name)| {
+            let value = match row.get_ref(index)? {
+                ValueRef::Null => Value::Null,
+                ValueRef::Integer(value) => Value::Integer(value),
+                ValueRef::Real(value) => NotNan::new(value).map_or(Value::Null, Value::Float),
+                ValueRef::Text(value) | ValueRef::Blob(value) => {
+                    Value::Bytes(Bytes::copy_from_slice(value))
+                }
+            };
+            Ok((name.as_str().into(), value))
+        })
+        .collect()
+}
+
+impl Table for Sqlite {
+    /// Search the enrichment table data with the given condition.
+    /// All conditions must match (AND).
+    ///
+    /// # Errors
+    /// Errors if no rows, or more than 1 row is found.
+    fn find_table_row<'a>(
+        &self,
+        case: Case,
+        condition: &'a [Condition<'a>],
+        select: Option<&[String]>,
+        index: Option<IndexHandle>,
+    ) -> Result<ObjectMap, String> {
+        let mut rows = self.find_table_rows(case, condition, select, index)?;
+
+        match rows.pop() {
+            Some(row) if rows.is_empty() => Ok(row),
+            Some(_) => Err("More than 1 row found".to_string()),
+            None => Err("Key not found".to_string()),
+        }
+    }
+
+    /// Search the enrichment table data with the given condition.
+    /// All conditions must match (AND).
+    /// Can return multiple matched records
+    fn find_table_rows<'a>(
+        &self,
+        _: Case,
+        condition: &'a [Condition<'a>],
+        select: Option<&[String]>,
+        _: Option<IndexHandle>,
+    ) -> Result<Vec<ObjectMap>, String> {
+        match condition.first() {
+            Some(_) if condition.len() > 1 => Err("Only one condition is allowed".to_string()),
+            Some(Condition::Equals { field, value }) if *field == self.config.key_column => {
+                let Some(row) = self.query(&value.to_string_lossy()) else {
+                    return Ok(Vec::new());
+                };
+                let row = match select {
+                    Some(fields) => fields
+                        .iter()
+                        .map(|field| {
+                            let value = row.get(field.as_str()).cloned();
+                            (field.as_str().into(), value.unwrap_or(Value::Null))
+                        })
+                        .collect(),
+                    None => row,
+                };
+                Ok(vec![row])
+            }
+            Some(Condition::Equals { .. }) => Err(format!(
+                "Only the {} field can be searched",
+                self.config.key_column
+            )),
+            Some(_) => Err("Only equality condition is allowed".to_string()),
+            None => Err("Key condition must be specified".to_string()),
+        }
+    }
+
+    /// Hints to the enrichment table what data is going to be searched to allow it to index the
+    /// data in advance.
+    ///
+    /// # Errors
+    /// Errors if the fields are not in the table.
+    fn add_index(&mut self, _: Case, fields: &[&str]) -> Result<IndexHandle, String> {
+        match fields {
+            [] => Err(format!("The {} field is required", self.config.key_column)),
+            [field] if *field == self.config.key_column => Ok(IndexHandle(0)),
+            _ => Err(format!(
+                "Only the {} field is allowed",
+                self.config.key_column
+            )),
+        }
+    }
+
+    /// Returns a list of the field names that are in each index
+    fn index_fields(&self) -> Vec<(Case, Vec<String>)> {
+        Vec::new()
+    }
+
+    /// Returns true if the underlying data has changed and the table needs reloading.
+    fn needs_reload(&self) -> bool {
+        matches!(fs::metadata(&self.config.path)
+            .and_then(|metadata| metadata.modified()),
+            Ok(modified) if modified > self.last_modified)
+    }
+}
+
+impl std::fmt::Debug for Sqlite {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SQLite database {}", self.config.path.display())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::temp_file;
+
+    fn database() -> PathBuf {
+        let path = temp_file();
+        let connection = Connection::open(&path).unwrap();
+        connection
+            .execute_batch(
+                "CREATE TABLE hosts (hostname TEXT PRIMARY KEY, region TEXT, cores INTEGER);
+                INSERT INTO hosts VALUES ('web-1', 'eu-west-1', 8);
+                INSERT INTO hosts VALUES ('web-2', 'us-east-1', NULL);",
+            )
+            .unwrap();
+        path
+    }
+
+    fn config(path: PathBuf) -> SqliteConfig {
+        SqliteConfig {
+            path,
+            table: "hosts".to_string(),
+            key_column: "hostname".to_string(),
+            columns: None,
+            cache_size: default_cache_size(),
+        }
+    }
+
+    fn find(table: &Sqlite, key: &str, select: Option<&[String]>) -> Option<ObjectMap> {
+        table
+            .find_table_rows(
+                Case::Sensitive,
+                &[Condition::Equals {
+                    field: "hostname",
+                    value: key.into(),
+                }],
+                select,
+                None,
+            )
+            .unwrap()
+            .pop()
+    }
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<SqliteConfig>();
+    }
+
+    #[test]
+    fn lookup_all_columns() {
+        let table = Sqlite::new(config(database())).unwrap();
+
+        let expected = ObjectMap::from([
+            ("hostname".into(), Value::from("web-1")),
+            ("region".into(), Value::from("eu-west-1")),
+            ("cores".into(), Value::from(8)),
+        ]);
+        assert_eq!(find(&table, "web-1", None), Some(expected));
+    }
+
+    #[test]
+    fn lookup_configured_and_selected_columns() {
+        let mut config = config(database());
+        config.columns = Some(vec!["region".to_string(), "cores".to_string()]);
+        let table = Sqlite::new(config).unwrap();
+
+        let expected = ObjectMap::from([
+            ("region".into(), Value::from("us-east-1")),
+            ("cores".into(), Value::Null),
+        ]);
+        assert_eq!(find(&table, "web-2", None), Some(expected));
+
+        let expected = ObjectMap::from([("region".into(), Value::from("us-east-1"))]);
+        assert_eq!(
+            find(&table, "web-2", Some(&["region".to_string()])),
+            Some(expected)
+        );
+    }
+
+    #[test]
+    fn lookup_missing() {
+        let table = Sqlite::new(config(database())).unwrap();
+
+        assert_eq!(find(&table, "web-3", None), None);
+    }
+
+    #[test]
+    fn caches_lookups() {
+        let mut config = config(database());
+        config.cache_size = 1;
+        let table = Sqlite::new(config).unwrap();
+
+        assert!(table.query("web-1").is_some());
+        assert!(table.query("web-3").is_none());
+
+        let cache = table.cache.as_ref().unwrap().lock().unwrap();
+        assert_eq!(cache.len(), 1);
+        assert_eq!(cache.peek("web-3"), Some(&None));
+    }
+
+    #[test]
+    fn rejects_unknown_columns() {
+        let mut config = config(database());
+        config.columns = Some(vec!["unknown".to_string()]);
+
+        assert!(Sqlite::new(config).is_err());
+    }
+
+    #[test]
+    fn rejects_other_fields() {
+        let mut table = Sqlite::new(config(database())).unwrap();
+
+        assert!(table.add_index(Case::Sensitive, &["region"]).is_err());
+        assert!(table
+            .find_table_rows(
+                Case::Sensitive,
+                &[Condition::Equals {
+                    field: "region",
+                    value: "eu-west-1".into(),
+                }],
+                None,
+                None,
+            )
+            .is_err());
+    }
+}
@@ -0,0 +1,371 @@
+#![allow(missing_docs)]
+
+use std::{
+    fmt::Write as _,
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+use clap::Parser;
+use colored::*;
+use exitcode::ExitCode;
+use serde::Serialize;
+use tokio::time::timeout;
+
+use crate::config::{
+    self, ComponentKey, Config, OutputId, ProxyConfig, SinkContext, SinkHealthcheckOptions,
+    SinkOuter,
+};
+
+/// Exit code used when at least one sink is not healthy.
+const UNHEALTHY: ExitCode = 1;
+
+/// The format the results are printed in.
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Text,
+    Json,
+}
+
+#[derive(Parser, Debug)]
+#[command(rename_all = "kebab-case")]
+pub struct Opts {
+    /// Vector config files to load the sinks from.
+    /// Format is detected from the file name.
+    /// If none are specified, the default config path `/etc/vector/vector.yaml`
+    /// is targeted.
+    #[arg(
+        id = "config",
+        short,
+        long,
+        env = "VECTOR_CONFIG",
+        value_delimiter(',')
+    )]
+    pub paths: Vec<PathBuf>,
+
+    /// Read configuration from files in one or more directories.
+    /// File format is detected from the file name.
+    ///
+    /// Files not ending in .toml, .json, .yaml, or .yml will be ignored.
+    #[arg(
+        id = "config-dir",
+        short = 'C',
+        long,
+        env = "VECTOR_CONFIG_DIR",
+        value_delimiter(',')
+    )]
+    pub config_dirs: Vec<PathBuf>,
+
+    /// Only check the health of the sink with the given ID
+    #[arg(long)]
+    pub component_id: Option<String>,
+
+    /// The format the results are printed in
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+    pub format: OutputFormat,
+}
+
+impl Opts {
+    fn paths_with_formats(&self) -> Vec<config::ConfigPath> {
+        config::merge_path_lists(vec![(&self.paths, None)])
+            .map(|(path, hint)| config::ConfigPath::File(path, hint))
+            .chain(
+                self.config_dirs
+                    .iter()
+                    .map(|dir| config::ConfigPath::Dir(dir.to_path_buf())),
+            )
+            .collect()
+    }
+}
+
+/// The result of the healthcheck of a sink.
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub struct HealthcheckResult {
+    component_id: String,
+    component_type: String,
+    healthy: bool,
+    /// Why the sink isn't healthy, if it isn't.
+    error: Option<String>,
+    duration_ms: u64,
+}
+
+/// Runs the healthchecks of the sinks, printing their results.
+pub async fn cmd(opts: &Opts, color: bool) -> ExitCode {
+    let config = match load_config(opts) {
+        Ok(config) => config,
+        Err(errors) => {
+            #[allow(clippy::print_stderr)]
+            {
+                for error in errors {
+                    eprintln!("{}", error);
+                }
+            }
+            return exitcode::CONFIG;
+        }
+    };
+
+    let results = match check_sinks(&config, opts.component_id.as_deref()).await {
+        Ok(results) => results,
+        Err(error) => {
+            #[allow(clippy::print_stderr)]
+            {
+                eprintln!("{}", error);
+            }
+            return exitcode::DATAERR;
+        }
+    };
+
+    let output = match (opts.format, &opts.component_id) {
+        (OutputFormat::Json, _) => {
+            format!("{}\n", serde_json::json!({ "healthchecks": results }))
+        }
+        (OutputFormat::Text, Some(_)) => results
+            .iter()
+            .map(|result| format_line(result, color))
+            .collect(),
+        (OutputFormat::Text, None) => format_table(&results, color),
+    };
+
+    #[allow(clippy::print_stdout)]
+    {
+        print!("{}", output);
+    }
+
+    if results.iter().all(|result| result.healthy) {
+        exitcode::OK
+    } else {
+        UNHEALTHY
+    }
+}
+
+fn load_config(opts: &Opts) -> Result<Config, Vec<String>> {
+    let paths = config::process_paths(&opts.paths_with_formats())
+        .ok_or_else(|| vec!["No config file paths".to_string()])?;
+
+    let builder = config::load_builder_from_paths(&paths)?;
+    config::init_log_schema(builder.global.log_schema.clone(), true);
+
+    builder.build()
+}
+
+/// Runs the healthchecks of the sinks in parallel, or only of the sink with the given ID.
+async fn check_sinks(
+    config: &Config,
+    component_id: Option<&str>,
+) -> Result<Vec<HealthcheckResult>, String> {
+    let sinks = config
+        .sinks()
+        .filter(|(key, _)| component_id.is_none_or(|id| key.id() == id))
+        .collect::<Vec<_>>();
+    if let (Some(id), true) = (component_id, sinks.is_empty()) {
+        return Err(format!("Sink \"{}\" not found.", id));
+    }
+
+    Ok(futures::future::join_all(
+        sinks
+            .into_iter()
+            .map(|(key, sink)| check_sink(config, key, sink)),
+    )
+    .await)
+}
+
+/// Builds the sink, without starting it, and runs its healthcheck, even if it's disabled.
+async fn check_sink(
+    config: &Config,
+    key: &ComponentKey,
+    sink: &SinkOuter<OutputId>,
+) -> HealthcheckResult {
+    let healthcheck = SinkHealthcheckOptions {
+        enabled: true,
+        ..sink.healthcheck()
+    };
+    let healthcheck_timeout = healthcheck.timeout;
+    let cx = SinkContext {
+        healthcheck,
+        globals: config.global.clone(),
+        proxy: ProxyConfig::merge_with_env(&config.global.proxy, sink.proxy()),
+        schema: config.schema,
+        ..Default::default()
+    };
+
+    let start = Instant::now();
+    let error = match sink.inner.build(cx).await {
+        Ok((_sink, healthcheck)) => match timeout(healthcheck_timeout, healthcheck).await {
+            Ok(Ok(())) => None,
+            Ok(Err(error)) => Some(error.to_string()),
+            Err(_) => Some(format!(
+                "Timed out after {} seconds",
+                healthcheck_timeout.as_secs_f64()
+            )),
+        },
+        Err(error) => Some(format!("Couldn't build the sink: {}", error)),
+    };
+
+    HealthcheckResult {
+        component_id: key.id().to_string(),
+        component_type: sink.inner.get_component_name().to_string(),
+        healthy: error.is_none(),
+        error,
+        duration_ms: duration_ms(start.elapsed()),
+    }
+}
+
+fn duration_ms(duration: Duration) -> u64 {
+    duration.as_millis().try_into().unwrap_or(u64::MAX)
+}
+
+fn status(result: &HealthcheckResult) -> &'static str {
+    if result.healthy {
+        "healthy"
+    } else {
+        "unhealthy"
+    }
+}
+
+fn colorize_status(result: &HealthcheckResult, color: bool) -> String {
+    match (color, result.healthy) {
+        (false, _) => status(result).to_string(),
+        (true, true) => status(result).green().to_string(),
+        (true, false) => status(result).red().to_string(),
+    }
+}
+
+fn format_line(result: &HealthcheckResult, color: bool) -> String {
+    let mut line = format!(
+        "Sink \"{}\" is {}",
+        result.component_id,
+        colorize_status(result, color)
+    );
+    if let Some(error) = &result.error {
+        write!(line, ": {}", error).expect("writing to a string can't fail");
+    }
+    line.push('\n');
+    line
+}
+
+fn format_table(results: &[HealthcheckResult], color: bool) -> String {
+    const HEADERS: [&str; 5] = ["ID", "TYPE", "STATUS", "DURATION", "ERROR"];
+    const STATUS_COLUMN: usize = 2;
+
+    let rows = results
+        .iter()
+        .map(|result| {
+            [
+                result.component_id.clone(),
+                result.component_type.clone(),
+                status(result).to_string(),
+                format!("{}ms", result.duration_ms),
+                result.error.clone().unwrap_or_default(),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    let mut widths = HEADERS.map(str::len);
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+    }
+
+    let mut output = String::new();
+    let header = HEADERS
+        .iter()
+        .zip(widths)
+        .map(|(header, width)| format!("{:<width$}", header))
+        .collect::<Vec<_>>()
+        .join("  ");
+    writeln!(output, "{}", header.trim_end()).expect("writing to a string can't fail");
+
+    for (row, result) in rows.iter().zip(results) {
+        let line = row
+            .iter()
+            .zip(widths)
+            .enumerate()
+            .map(|(column, (cell, width))| {
+                // Pad before coloring so the escape codes don't count towards the width.
+                let padded = format!("{:<width$}", cell);
+                if column == STATUS_COLUMN {
+                    padded.replace(cell.as_str(), &colorize_status(result, color))
+                } else {
+                    padded
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("  ");
+        writeln!(output, "{}", line.trim_end()).expect("writing to a string can't fail");
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Format;
+
+    const CONFIG: &str = r#"
+        [sources.in]
+        type = "demo_logs"
+        format = "json"
+
+        [sinks.out]
+        type = "blackhole"
+        inputs = ["in"]
+
+        [sinks.console]
+        type = "console"
+        inputs = ["in"]
+        encoding.codec = "json"
+    "#;
+
+    fn result(id: &str, error: Option<&str>) -> HealthcheckResult {
+        HealthcheckResult {
+            component_id: id.to_string(),
+            component_type: "http".to_string(),
+            healthy: error.is_none(),
+            error: error.map(str::to_string),
+            duration_ms: 12,
+        }
+    }
+
+    #[tokio::test]
+    async fn checks_sinks() {
+        let config = config::load_from_str(CONFIG, Format::Toml).unwrap();
+
+        let mut results = check_sinks(&config, None).await.unwrap();
+        results.sort_by(|a, b| a.component_id.cmp(&b.component_id));
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].component_id, "console");
+        assert!(results.iter().all(|result| result.healthy));
+
+        let results = check_sinks(&config, Some("out")).await.unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].component_type, "blackhole");
+
+        assert_eq!(
+            check_sinks(&config, Some("in")).await.unwrap_err(),
+            "Sink \"in\" not found."
+        );
+    }
+
+    #[test]
+    fn formats_results_without_color() {
+        let results = vec![
+            result("out", None),
+            result("upstream", Some("Unexpected status: 503")),
+        ];
+
+        assert_eq!(
+            format_table(&results, false),
+            indoc::indoc! {"
+                ID        TYPE  STATUS     DURATION  ERROR
+                out       http  healthy    12ms
+                upstream  http  unhealthy  12ms      Unexpected status: 503
+            "}
+        );
+        assert_eq!(
+            format_line(&results[1], false),
+            "Sink \"upstream\" is unhealthy: Unexpected status: 503\n"
+        );
+    }
+}
@@ -0,0 +1,335 @@
+//! Ingest subcommand
+use std::io::{self, BufRead};
+
+use clap::{Parser, ValueEnum};
+use serde_json::{Map, Value};
+use url::Url;
+use vector_lib::api_client::{gql::IngestMutationExt, Client};
+
+use crate::config::api::default_graphql_url;
+
+/// Maximum number of events submitted in a single mutation.
+const BATCH_SIZE: usize = 1000;
+
+#[derive(Parser, Debug, Clone)]
+#[command(rename_all = "kebab-case")]
+pub struct Opts {
+    /// ID of the sink to write events into
+    #[arg(short, long)]
+    sink: String,
+
+    /// Format of the events read from stdin, one event per line
+    #[arg(default_value = "json", short, long)]
+    format: Format,
+
+    /// GraphQL API server endpoint
+    #[arg(short, long)]
+    api_address: Option<Url>,
+
+    /// Validate the events without submitting them
+    #[arg(long)]
+    dry_run: bool,
+}
+
+impl Opts {
+    /// Use the provided URL as the Vector GraphQL API server, or default to the local port
+    /// provided by the API config.
+    fn url(&self) -> Url {
+        self.api_address.clone().unwrap_or_else(default_graphql_url)
+    }
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// A JSON object per line
+    Json,
+    /// `key=value` pairs per line, with optionally quoted values
+    Logfmt,
+    /// Comma-separated values, with field names taken from the first line
+    Csv,
+}
+
+/// Converts lines in the given format into JSON-encoded events, keeping the line number of any
+/// line that couldn't be converted alongside the error.
+fn parse_lines(format: Format, lines: &[String]) -> (Vec<String>, Vec<(usize, String)>) {
+    let mut events = Vec::new();
+    let mut errors = Vec::new();
+
+    let mut lines = lines
+        .iter()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line.as_str()))
+        .filter(|(_, line)| !line.trim().is_empty());
+
+    let header = match format {
+        Format::Csv => match lines.next() {
+            Some((number, line)) => match parse_csv_record(line) {
+                Ok(header) => Some(header),
+                Err(error) => {
+                    errors.push((number, error));
+                    return (events, errors);
+                }
+            },
+            None => return (events, errors),
+        },
+        _ => None,
+    };
+
+    for (number, line) in lines {
+        let event = match format {
+            Format::Json => parse_json(line),
+            Format::Logfmt => parse_logfmt(line),
+            Format::Csv => parse_csv(header.as_deref().unwrap_or_default(), line),
+        };
+        match event {
+            Ok(event) => events.push(Value::Object(event).to_string()),
+            Err(error) => errors.push((number, error)),
+        }
+    }
+
+    (events, errors)
+}
+
+fn parse_json(line: &str) -> Result<Map<String, Value>, String> {
+    match serde_json::from_str(line) {
+        Ok(Value::Object(event)) => Ok(event),
+        Ok(_) => Err("expected a JSON object".to_string()),
+        Err(error) => Err(error.to_string()),
+    }
+}
+
+fn parse_logfmt(line: &str) -> Result<Map<String, Value>, String> {
+    let mut event = Map::new();
+    let mut chars = line.trim().chars().peekable();
+
+    while chars.peek().is_some() {
+        let mut key = String::new();
+        while let Some(c) = chars.next_if(|c| *c != '=' && !c.is_whitespace()) {
+            key.push(c);
+        }
+        if key.is_empty() || chars.next() != Some('=') {
+            return Err(format!("expected \"key=value\", found \"{}\"", key));
+        }
+
+        let value = if chars.peek() == Some(&'"') {
+            chars.next();
+            let mut value = String::new();
+            loop {
+                match chars.next() {
+                    Some('\\') => value.extend(chars.next()),
+                    Some('"') => break,
+                    Some(c) => value.push(c),
+                    None => return Err(format!("unterminated quoted value for \"{}\"", key)),
+                }
+            }
+            value
+        } else {
+            chars
+                .by_ref()
+                .take_while(|c| !c.is_whitespace())
+                .collect::<String>()
+        };
+
+        while chars.next_if(|c| c.is_whitespace()).is_some() {}
+        event.insert(key, Value::String(value));
+    }
+
+    Ok(event)
+}
+
+fn parse_csv(header: &[String], line: &str) -> Result<Map<String, Value>, String> {
+    let record = parse_csv_record(line)?;
+    if record.len() != header.len() {
+        return Err(format!(
+            "expected {} fields, found {}",
+            header.len(),
+            record.len()
+        ));
+    }
+
+    Ok(header
+        .iter()
+        .cloned()
+        .zip(record.into_iter().map(Value::String))
+        .collect())
+}
+
+fn parse_csv_record(line: &str) -> Result<Vec<String>, String> {
+    let mut reader = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .from_reader(line.as_bytes());
+    let mut record = csv::StringRecord::new();
+    match reader.read_record(&mut record) {
+        Ok(true) => Ok(record.iter().map(ToOwned::to_owned).collect()),
+        Ok(false) => Err("empty record".to_string()),
+        Err(error) => Err(error.to_string()),
+    }
+}
+
+/// CLI command func for ingesting events from stdin into a sink, via the GraphQL API.
+pub async fn cmd(opts: &Opts) -> exitcode::ExitCode {
+    let lines = match io::stdin().lock().lines().collect::<Result<Vec<_>, _>>() {
+        Ok(lines) => lines,
+        Err(error) => {
+            #[allow(clippy::print_stderr)]
+            {
+                eprintln!("Couldn't read events from stdin: {}", error);
+            }
+            return exitcode::IOERR;
+        }
+    };
+
+    let (events, errors) = parse_lines(opts.format, &lines);
+    #[allow(clippy::print_stderr)]
+    for (number, error) in &errors {
+        eprintln!("Line {}: {}", number, error);
+    }
+
+    if opts.dry_run {
+        #[allow(clippy::print_stdout)]
+        {
+            println!("Valid: {}, invalid: {}", events.len(), errors.len());
+        }
+        return if errors.is_empty() {
+            exitcode::OK
+        } else {
+            exitcode::DATAERR
+        };
+    }
+
+    let url = opts.url();
+    let client = Client::new(url.clone());
+    #[allow(clippy::print_stderr)]
+    if client.healthcheck().await.is_err() {
+        eprintln!(
+            indoc::indoc! {"
+            Vector API server isn't reachable ({}).
+
+            Have you enabled the API?
+
+            To enable the API, add the following to your Vector config file:
+
+            [api]
+                enabled = true"},
+            url
+        );
+        return exitcode::UNAVAILABLE;
+    }
+
+    let mut accepted = 0;
+    let mut rejected = errors.len();
+    for batch in events.chunks(BATCH_SIZE) {
+        let result = match client
+            .ingest_events_mutation(opts.sink.clone(), batch.to_vec())
+            .await
+        {
+            Ok(response) => match response.data {
+                Some(data) => data.ingest_events,
+                None => {
+                    #[allow(clippy::print_stderr)]
+                    for error in response.errors.unwrap_or_default() {
+                        eprintln!("Couldn't ingest events: {}", error.message);
+                    }
+                    return exitcode::DATAERR;
+                }
+            },
+            Err(error) => {
+                #[allow(clippy::print_stderr)]
+                {
+                    eprintln!("Couldn't ingest events: {}", error);
+                }
+                return exitcode::UNAVAILABLE;
+            }
+        };
+
+        accepted += result.accepted as usize;
+        rejected += result.rejected as usize;
+        #[allow(clippy::print_stderr)]
+        for error in result.errors {
+            eprintln!("{}", error);
+        }
+    }
+
+    #[allow(clippy::print_stdout)]
+    {
+        println!("Accepted: {}, rejected: {}", accepted, rejected);
+    }
+
+    if rejected == 0 {
+        exitcode::OK
+    } else {
+        exitcode::DATAERR
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn parse(format: Format, input: &str) -> (Vec<Value>, Vec<(usize, String)>) {
+        let lines = input.lines().map(ToOwned::to_owned).collect::<Vec<_>>();
+        let (events, errors) = parse_lines(format, &lines);
+        (
+            events
+                .iter()
+                .map(|event| serde_json::from_str(event).unwrap())
+                .collect(),
+            errors,
+        )
+    }
+
+    #[test]
+    fn parses_json() {
+        let (events, errors) = parse(
+            Format::Json,
+            "{\"message\":\"one\",\"count\":1}\n\n[1, 2]\nnot json\n{\"message\":\"two\"}",
+        );
+
+        assert_eq!(
+            events,
+            vec![
+                json!({ "message": "one", "count": 1 }),
+                json!({ "message": "two" })
+            ]
+        );
+        assert_eq!(
+            errors.iter().map(|(number, _)| *number).collect::<Vec<_>>(),
+            vec![3, 4]
+        );
+    }
+
+    #[test]
+    fn parses_logfmt() {
+        let (events, errors) = parse(
+            Format::Logfmt,
+            "level=info msg=\"hello \\\"world\\\"\" empty=\nmsg=\"unterminated",
+        );
+
+        assert_eq!(
+            events,
+            vec![json!({ "level": "info", "msg": "hello \"world\"", "empty": "" })]
+        );
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].0, 2);
+    }
+
+    #[test]
+    fn parses_csv() {
+        let (events, errors) = parse(
+            Format::Csv,
+            "host,message\nlocalhost,\"hello, world\"\nlocalhost\nremote,bye",
+        );
+
+        assert_eq!(
+            events,
+            vec![
+                json!({ "host": "localhost", "message": "hello, world" }),
+                json!({ "host": "remote", "message": "bye" }),
+            ]
+        );
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].0, 3);
+    }
+}
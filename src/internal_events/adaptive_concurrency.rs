@@ -1,6 +1,6 @@
 use std::time::Duration;
 
-use metrics::{histogram, Histogram};
+use metrics::{counter, gauge, histogram, Counter, Gauge, Histogram};
 
 #[derive(Clone, Copy)]
 pub struct AdaptiveConcurrencyLimitData {
@@ -10,27 +10,37 @@ pub struct AdaptiveConcurrencyLimitData {
     pub current_rtt: Option<Duration>,
     pub past_rtt: Duration,
     pub past_rtt_deviation: Duration,
+    pub increased: bool,
+    pub decreased: bool,
 }
 
 registered_event! {
     AdaptiveConcurrencyLimit => {
+        limit: Gauge = gauge!("adaptive_concurrency_limit"),
         // These are histograms, as they may have a number of different
         // values over each reporting interval, and each of those values
         // is valuable for diagnosis.
-        limit: Histogram = histogram!("adaptive_concurrency_limit"),
         reached_limit: Histogram = histogram!("adaptive_concurrency_reached_limit"),
         back_pressure: Histogram = histogram!("adaptive_concurrency_back_pressure"),
         past_rtt_mean: Histogram = histogram!("adaptive_concurrency_past_rtt_mean"),
+        increases: Counter = counter!("adaptive_concurrency_increase_total"),
+        decreases: Counter = counter!("adaptive_concurrency_decrease_total"),
     }
 
     fn emit(&self, data: AdaptiveConcurrencyLimitData) {
-        self.limit.record(data.concurrency as f64);
+        self.limit.set(data.concurrency as f64);
         let reached_limit = data.reached_limit.then_some(1.0).unwrap_or_default();
         self.reached_limit.record(reached_limit);
         let back_pressure = data.had_back_pressure.then_some(1.0).unwrap_or_default();
         self.back_pressure.record(back_pressure);
         self.past_rtt_mean.record(data.past_rtt);
         // past_rtt_deviation is unrecorded
+        if data.increased {
+            self.increases.increment(1);
+        }
+        if data.decreased {
+            self.decreases.increment(1);
+        }
     }
 }
 
@@ -63,3 +73,22 @@ registered_event! {
         self.averaged_rtt.record(rtt);
     }
 }
+
+#[derive(Clone, Copy)]
+pub struct AdaptiveConcurrencyRttPercentilesData {
+    pub p50: Duration,
+    pub p90: Duration,
+}
+
+registered_event! {
+    AdaptiveConcurrencyRttPercentiles => {
+        // Gauges, as only the latest window is relevant to the current limit.
+        p50: Gauge = gauge!("adaptive_concurrency_rtt_p50_ms"),
+        p90: Gauge = gauge!("adaptive_concurrency_rtt_p90_ms"),
+    }
+
+    fn emit(&self, data: AdaptiveConcurrencyRttPercentilesData) {
+        self.p50.set(data.p50.as_secs_f64() * 1000.0);
+        self.p90.set(data.p90.as_secs_f64() * 1000.0);
+    }
+}
@@ -27,3 +27,12 @@ impl InternalEvent for AggregateUpdateFailed {
         counter!("aggregate_failed_updates").increment(1);
     }
 }
+
+#[derive(Debug)]
+pub struct AggregateSessionEvicted;
+
+impl InternalEvent for AggregateSessionEvicted {
+    fn emit(self) {
+        counter!("sessions_evicted_total").increment(1);
+    }
+}
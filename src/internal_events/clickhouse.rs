@@ -0,0 +1,20 @@
+use metrics::counter;
+use vector_lib::internal_event::InternalEvent;
+
+#[derive(Debug)]
+pub struct ClickhouseRowsInserted {
+    pub total_rows: usize,
+    pub written_rows: u64,
+}
+
+impl InternalEvent for ClickhouseRowsInserted {
+    fn emit(self) {
+        trace!(
+            message = "Rows inserted.",
+            total_rows = self.total_rows,
+            written_rows = self.written_rows,
+        );
+        counter!("clickhouse_inserted_rows_total").increment(self.total_rows as u64);
+        counter!("clickhouse_written_rows_total").increment(self.written_rows);
+    }
+}
@@ -124,6 +124,9 @@ impl InternalEvent for CollectionCompleted {
 #[derive(Debug)]
 pub struct SinkRequestBuildError<E> {
     pub error: E,
+    /// Tells apart the reasons requests fail to build, such as `payload_too_big`, `io_error` or
+    /// `json_error`.
+    pub error_code: &'static str,
 }
 
 impl<E: std::fmt::Display> InternalEvent for SinkRequestBuildError<E> {
@@ -134,12 +137,14 @@ impl<E: std::fmt::Display> InternalEvent for SinkRequestBuildError<E> {
         error!(
             message = format!("Failed to build request."),
             error = %self.error,
+            error_code = self.error_code,
             error_type = error_type::ENCODER_FAILED,
             stage = error_stage::PROCESSING,
 
         );
         counter!(
             "component_errors_total",
+            "error_code" => self.error_code,
             "error_type" => error_type::ENCODER_FAILED,
             "stage" => error_stage::PROCESSING,
         )
@@ -0,0 +1,323 @@
+use metrics::{counter, gauge, histogram};
+use vector_lib::internal_event::{
+    error_stage, error_type, ComponentEventsDropped, InternalEvent, INTENTIONAL, UNINTENTIONAL,
+};
+
+#[derive(Debug)]
+pub struct DatadogLogsDynamicPayloadLimit {
+    pub limit_bytes: usize,
+}
+
+impl InternalEvent for DatadogLogsDynamicPayloadLimit {
+    fn emit(self) {
+        debug!(
+            message = "Adjusted maximum payload size.",
+            limit_bytes = self.limit_bytes,
+        );
+        gauge!("datadog_logs_dynamic_payload_limit_bytes").set(self.limit_bytes as f64);
+    }
+}
+
+#[derive(Debug)]
+pub struct DatadogLogsEventTooLarge {
+    pub event_bytes: usize,
+    pub limit: usize,
+}
+
+impl InternalEvent for DatadogLogsEventTooLarge {
+    fn emit(self) {
+        warn!(
+            message = "Event is larger than `max_single_event_bytes`.",
+            event_bytes = self.event_bytes,
+            limit = self.limit,
+            internal_log_rate_limit = true,
+        );
+    }
+}
+
+#[derive(Debug)]
+pub struct DatadogLogsFieldsNormalized {
+    pub count: u64,
+}
+
+impl InternalEvent for DatadogLogsFieldsNormalized {
+    fn emit(self) {
+        trace!(
+            message = "Moved non-reserved fields under the message.",
+            count = self.count,
+        );
+        counter!("datadog_logs_fields_normalized_total").increment(self.count);
+    }
+}
+
+#[derive(Debug)]
+pub struct DatadogLogsEstimatedSizeError {
+    pub estimated_bytes: usize,
+    pub actual_bytes: usize,
+}
+
+impl InternalEvent for DatadogLogsEstimatedSizeError {
+    fn emit(self) {
+        // Overestimated events are recorded as negative errors.
+        let error = self.actual_bytes as f64 - self.estimated_bytes as f64;
+        histogram!("estimated_size_error_bytes").record(error);
+    }
+}
+
+#[derive(Debug)]
+pub struct DatadogLogsTimestampParseError<'a> {
+    pub timestamp: &'a str,
+}
+
+impl InternalEvent for DatadogLogsTimestampParseError<'_> {
+    fn emit(self) {
+        warn!(
+            message = "Couldn't parse timestamp as RFC 3339; leaving it as is.",
+            timestamp = self.timestamp,
+            internal_log_rate_limit = true,
+        );
+        counter!("datadog_logs_timestamp_parse_errors_total").increment(1);
+    }
+}
+
+#[derive(Debug)]
+pub struct DatadogLogsInvalidTag<'a> {
+    pub tag: &'a str,
+    pub action: &'static str,
+}
+
+impl InternalEvent for DatadogLogsInvalidTag<'_> {
+    fn emit(self) {
+        warn!(
+            message = "Tag holds commas, which would split it into several tags.",
+            tag = self.tag,
+            action = self.action,
+            internal_log_rate_limit = true,
+        );
+        counter!("datadog_logs_invalid_tags_total", "action" => self.action).increment(1);
+    }
+}
+
+/// An event holds a site which isn't a known Datadog site, and is sent to the configured site
+/// instead.
+#[derive(Debug)]
+pub struct DatadogLogsUnknownSite<'a> {
+    pub site: &'a str,
+}
+
+impl InternalEvent for DatadogLogsUnknownSite<'_> {
+    fn emit(self) {
+        warn!(
+            message = "Event holds an unknown Datadog site; sending it to the configured site.",
+            site = self.site,
+            internal_log_rate_limit = true,
+        );
+    }
+}
+
+#[derive(Debug)]
+pub struct DatadogLogsRetryBudgetExceeded;
+
+impl InternalEvent for DatadogLogsRetryBudgetExceeded {
+    fn emit(self) {
+        warn!(
+            message = "Retry budget exceeded; not retrying the request.",
+            reason = "retry_budget_exceeded",
+            internal_log_rate_limit = true,
+        );
+        counter!("datadog_logs_retry_budget_exceeded_total").increment(1);
+    }
+}
+
+#[derive(Debug)]
+pub struct DatadogLogsApiKeyVrlError<'a> {
+    pub error: &'a str,
+    pub count: usize,
+}
+
+impl InternalEvent for DatadogLogsApiKeyVrlError<'_> {
+    fn emit(self) {
+        warn!(
+            message = "Couldn't compute the API key of events; using the default API key.",
+            error = %self.error,
+            count = self.count,
+            error_type = error_type::SCRIPT_FAILED,
+            stage = error_stage::PROCESSING,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "component_errors_total",
+            "error_type" => error_type::SCRIPT_FAILED,
+            "stage" => error_stage::PROCESSING,
+        )
+        .increment(self.count as u64);
+    }
+}
+
+/// An event was dropped because its API key field doesn't hold a valid API key.
+#[derive(Debug)]
+pub struct DatadogLogsInvalidApiKey {
+    pub reason: &'static str,
+}
+
+impl InternalEvent for DatadogLogsInvalidApiKey {
+    fn emit(self) {
+        warn!(
+            message = "Dropping event with an invalid API key.",
+            reason = self.reason,
+            internal_log_rate_limit = true,
+        );
+        emit!(ComponentEventsDropped::<INTENTIONAL> {
+            count: 1,
+            reason: "invalid_api_key",
+        });
+    }
+}
+
+#[derive(Debug)]
+pub struct DatadogLogsEndpointFailover {
+    /// Whether requests are now sent to the fallback endpoint, rather than back to the primary one
+    pub failed_over: bool,
+    pub consecutive_failures: u32,
+}
+
+impl InternalEvent for DatadogLogsEndpointFailover {
+    fn emit(self) {
+        let endpoint = if self.failed_over {
+            warn!(
+                message = "Primary endpoint is failing; sending requests to the fallback endpoint.",
+                consecutive_failures = self.consecutive_failures,
+            );
+            "fallback"
+        } else {
+            info!(message = "Primary endpoint recovered; sending requests to it again.");
+            "primary"
+        };
+        counter!("datadog_logs_endpoint_switches_total", "endpoint" => endpoint).increment(1);
+    }
+}
+
+#[derive(Debug)]
+pub struct DatadogLogsApiKeyEventsSent<'a> {
+    pub api_key_prefix: &'a str,
+    pub count: usize,
+}
+
+impl InternalEvent for DatadogLogsApiKeyEventsSent<'_> {
+    fn emit(self) {
+        counter!(
+            "datadog_logs_api_key_sent_events_total",
+            "api_key_prefix" => self.api_key_prefix.to_owned(),
+        )
+        .increment(self.count as u64);
+    }
+}
+
+/// Counts the failed requests of an API key. The error itself is reported by the driver sending
+/// the request.
+#[derive(Debug)]
+pub struct DatadogLogsApiKeyRequestError<'a> {
+    pub api_key_prefix: &'a str,
+}
+
+impl InternalEvent for DatadogLogsApiKeyRequestError<'_> {
+    fn emit(self) {
+        counter!(
+            "datadog_logs_api_key_errors_total",
+            "api_key_prefix" => self.api_key_prefix.to_owned(),
+            "error_type" => error_type::REQUEST_FAILED,
+            "stage" => error_stage::SENDING,
+        )
+        .increment(1);
+    }
+}
+
+/// A partial acceptance response from the intake couldn't be matched to the events of the request,
+/// so all of them are sent again.
+#[derive(Debug)]
+pub struct DatadogLogsPartialAcceptanceError<E> {
+    pub error: E,
+}
+
+impl<E: std::fmt::Display> InternalEvent for DatadogLogsPartialAcceptanceError<E> {
+    fn emit(self) {
+        error!(
+            message = "Couldn't read the partial acceptance response; retrying all events.",
+            error = %self.error,
+            error_type = error_type::PARSER_FAILED,
+            stage = error_stage::SENDING,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "component_errors_total",
+            "error_type" => error_type::PARSER_FAILED,
+            "stage" => error_stage::SENDING,
+        )
+        .increment(1);
+    }
+}
+
+/// Events were sent to the dead-letter queue. They're counted apart from the events sent to the
+/// logs intake.
+#[derive(Debug)]
+pub struct DatadogLogsDeadLettersSent {
+    pub count: usize,
+    pub byte_size: usize,
+}
+
+impl InternalEvent for DatadogLogsDeadLettersSent {
+    fn emit(self) {
+        trace!(
+            message = "Events sent to the dead-letter queue.",
+            count = self.count,
+            byte_size = self.byte_size,
+        );
+        counter!("datadog_logs_dead_letter_sent_events_total").increment(self.count as u64);
+        counter!("datadog_logs_dead_letter_sent_event_bytes_total")
+            .increment(self.byte_size as u64);
+    }
+}
+
+/// Events couldn't be sent to the dead-letter queue, and were dropped.
+#[derive(Debug)]
+pub struct DatadogLogsDeadLettersError<E> {
+    pub error: E,
+    pub count: usize,
+}
+
+impl<E: std::fmt::Display> InternalEvent for DatadogLogsDeadLettersError<E> {
+    fn emit(self) {
+        let reason = "Failed to send events to the dead-letter queue.";
+        error!(
+            message = reason,
+            error = %self.error,
+            error_type = error_type::REQUEST_FAILED,
+            stage = error_stage::SENDING,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "component_errors_total",
+            "error_type" => error_type::REQUEST_FAILED,
+            "stage" => error_stage::SENDING,
+        )
+        .increment(1);
+        emit!(ComponentEventsDropped::<UNINTENTIONAL> {
+            count: self.count,
+            reason,
+        });
+    }
+}
+
+/// An event was dropped because the dead-letter queue already holds as many events as it can.
+#[derive(Debug)]
+pub struct DatadogLogsDeadLettersFull;
+
+impl InternalEvent for DatadogLogsDeadLettersFull {
+    fn emit(self) {
+        counter!("datadog_logs_dead_letter_overflow_events_total").increment(1);
+        emit!(ComponentEventsDropped::<UNINTENTIONAL> {
+            count: 1,
+            reason: "Dead-letter queue is full.",
+        });
+    }
+}
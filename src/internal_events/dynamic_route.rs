@@ -0,0 +1,71 @@
+use metrics::counter;
+use vector_lib::internal_event::{
+    error_stage, error_type, ComponentEventsDropped, InternalEvent, INTENTIONAL,
+};
+
+#[derive(Debug)]
+pub struct DynamicRouteExecutionError<'a> {
+    pub error: &'a str,
+}
+
+impl InternalEvent for DynamicRouteExecutionError<'_> {
+    fn emit(self) {
+        error!(
+            message = "VRL route expression execution failed.",
+            error = %self.error,
+            error_type = error_type::SCRIPT_FAILED,
+            stage = error_stage::PROCESSING,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "component_errors_total",
+            "error_type" => error_type::SCRIPT_FAILED,
+            "stage" => error_stage::PROCESSING,
+        )
+        .increment(1);
+    }
+}
+
+#[derive(Debug)]
+pub struct DynamicRouteUnknownOutput<'a> {
+    pub output: &'a str,
+}
+
+impl InternalEvent for DynamicRouteUnknownOutput<'_> {
+    fn emit(self) {
+        debug!(
+            message = "Event routed to an unknown output.",
+            output = %self.output,
+            internal_log_rate_limit = true,
+        );
+        emit!(ComponentEventsDropped::<INTENTIONAL> {
+            count: 1,
+            reason: "Event routed to an unknown output.",
+        });
+    }
+}
+
+#[derive(Debug)]
+pub struct DynamicRouteUndeclaredOutputError<'a> {
+    pub output: &'a str,
+}
+
+impl InternalEvent for DynamicRouteUndeclaredOutputError<'_> {
+    fn emit(self) {
+        error!(
+            message = "Event routed to an output that is not declared.",
+            output = %self.output,
+            error_code = "undeclared_output",
+            error_type = error_type::CONDITION_FAILED,
+            stage = error_stage::PROCESSING,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "component_errors_total",
+            "error_code" => "undeclared_output",
+            "error_type" => error_type::CONDITION_FAILED,
+            "stage" => error_stage::PROCESSING,
+        )
+        .increment(1);
+    }
+}
@@ -0,0 +1,25 @@
+use metrics::counter;
+use vector_lib::internal_event::InternalEvent;
+use vector_lib::internal_event::{error_stage, error_type};
+
+#[derive(Debug)]
+pub struct HttpEnrichmentTableRefreshError {
+    pub error: crate::Error,
+}
+
+impl InternalEvent for HttpEnrichmentTableRefreshError {
+    fn emit(self) {
+        error!(
+            message = "HTTP enrichment table refresh failed, keeping the previously fetched data.",
+            error = %self.error,
+            error_type = error_type::REQUEST_FAILED,
+            stage = error_stage::RECEIVING,
+            internal_log_rate_limit = true,
+        );
+        counter!("enrichment_table_refresh_errors_total").increment(1);
+    }
+
+    fn name(&self) -> Option<&'static str> {
+        Some("HttpEnrichmentTableRefreshError")
+    }
+}
@@ -1,5 +1,7 @@
 use metrics::{counter, gauge};
-use vector_lib::internal_event::InternalEvent;
+use vector_lib::internal_event::{
+    ComponentEventsDropped, InternalEvent, INTENTIONAL, UNINTENTIONAL,
+};
 use vector_lib::{
     internal_event::{error_stage, error_type},
     json_size::JsonSize,
@@ -115,6 +117,20 @@ impl InternalEvent for KafkaReadError {
     }
 }
 
+#[derive(Debug)]
+pub struct KafkaDuplicateEventsDropped {
+    pub count: usize,
+}
+
+impl InternalEvent for KafkaDuplicateEventsDropped {
+    fn emit(self) {
+        emit!(ComponentEventsDropped::<INTENTIONAL> {
+            count: self.count,
+            reason: "Event with a key seen within the deduplication window.",
+        });
+    }
+}
+
 #[derive(Debug)]
 pub struct KafkaStatisticsReceived<'a> {
     pub statistics: &'a rdkafka::Statistics,
@@ -158,7 +174,7 @@ pub struct KafkaHeaderExtractionError<'a> {
 impl InternalEvent for KafkaHeaderExtractionError<'_> {
     fn emit(self) {
         error!(
-            message = "Failed to extract header. Value should be a map of String -> Bytes.",
+            message = "Failed to extract headers. Value should be a map.",
             error_code = "extracting_header",
             error_type = error_type::PARSER_FAILED,
             stage = error_stage::RECEIVING,
@@ -173,3 +189,68 @@ impl InternalEvent for KafkaHeaderExtractionError<'_> {
         .increment(1);
     }
 }
+
+pub struct KafkaHeadersMissing<'a> {
+    pub header_field: &'a OwnedTargetPath,
+}
+
+impl InternalEvent for KafkaHeadersMissing<'_> {
+    fn emit(self) {
+        warn!(
+            message = "Headers field is missing, no headers are written.",
+            header_field = self.header_field.to_string(),
+            internal_log_rate_limit = true,
+        );
+    }
+}
+
+pub struct KafkaKeyVrlError<'a> {
+    pub error: &'a str,
+    pub count: usize,
+}
+
+impl InternalEvent for KafkaKeyVrlError<'_> {
+    fn emit(self) {
+        warn!(
+            message = "Couldn't compute the key of events; sending them without a key.",
+            error = %self.error,
+            count = self.count,
+            error_type = error_type::SCRIPT_FAILED,
+            stage = error_stage::PROCESSING,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "component_errors_total",
+            "error_type" => error_type::SCRIPT_FAILED,
+            "stage" => error_stage::PROCESSING,
+        )
+        .increment(self.count as u64);
+    }
+}
+
+pub struct KafkaSchemaRegistryError<'a> {
+    pub error: &'a crate::Error,
+    pub stage: &'static str,
+}
+
+impl InternalEvent for KafkaSchemaRegistryError<'_> {
+    fn emit(self) {
+        let reason = "Couldn't resolve the schema of the message from the schema registry.";
+        error!(
+            message = reason,
+            error = %self.error,
+            error_code = "schema_registry",
+            error_type = error_type::REQUEST_FAILED,
+            stage = self.stage,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "component_errors_total",
+            "error_code" => "schema_registry",
+            "error_type" => error_type::REQUEST_FAILED,
+            "stage" => self.stage,
+        )
+        .increment(1);
+        emit!(ComponentEventsDropped::<UNINTENTIONAL> { count: 1, reason });
+    }
+}
@@ -0,0 +1,16 @@
+use vector_lib::internal_event::InternalEvent;
+
+#[derive(Debug)]
+pub struct MergeBufferFull {
+    pub max_buffered_events: usize,
+}
+
+impl InternalEvent for MergeBufferFull {
+    fn emit(self) {
+        warn!(
+            message = "Buffer full, merging events before the flush condition matched.",
+            max_buffered_events = self.max_buffered_events,
+            internal_log_rate_limit = true,
+        );
+    }
+}
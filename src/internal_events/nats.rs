@@ -0,0 +1,16 @@
+use std::time::Duration;
+
+use metrics::histogram;
+use vector_lib::internal_event::InternalEvent;
+
+#[derive(Debug)]
+pub struct NatsPublishAckReceived {
+    pub latency: Duration,
+}
+
+impl InternalEvent for NatsPublishAckReceived {
+    fn emit(self) {
+        trace!(message = "Received JetStream publish acknowledgement.", latency = ?self.latency);
+        histogram!("nats_publish_ack_latency_seconds").record(self.latency);
+    }
+}
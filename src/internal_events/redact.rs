@@ -0,0 +1,20 @@
+use metrics::counter;
+use vector_lib::internal_event::InternalEvent;
+
+#[derive(Debug)]
+pub struct RedactFieldsMasked<'a> {
+    pub field: &'a str,
+    pub count: usize,
+}
+
+impl InternalEvent for RedactFieldsMasked<'_> {
+    fn emit(self) {
+        trace!(
+            message = "Redacted sensitive values.",
+            field = self.field,
+            count = self.count,
+        );
+        counter!("redact_fields_masked_total", "field" => self.field.to_owned())
+            .increment(self.count as u64);
+    }
+}
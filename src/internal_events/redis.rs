@@ -34,3 +34,27 @@ impl InternalEvent for RedisReceiveEventError {
         .increment(1);
     }
 }
+
+#[derive(Debug)]
+pub struct RedisStreamAckError {
+    pub error: redis::RedisError,
+}
+
+impl InternalEvent for RedisStreamAckError {
+    fn emit(self) {
+        error!(
+            message = "Failed to acknowledge stream entries.",
+            error = %self.error,
+            error_code = %self.error.code().unwrap_or("UNKNOWN"),
+            error_type = error_type::ACKNOWLEDGMENT_FAILED,
+            stage = error_stage::RECEIVING,
+        );
+        counter!(
+            "component_errors_total",
+            "error_code" => self.error.code().unwrap_or("UNKNOWN").to_string(),
+            "error_type" => error_type::ACKNOWLEDGMENT_FAILED,
+            "stage" => error_stage::RECEIVING,
+        )
+        .increment(1);
+    }
+}
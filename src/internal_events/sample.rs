@@ -1,3 +1,4 @@
+use metrics::counter;
 use vector_lib::internal_event::{ComponentEventsDropped, InternalEvent, INTENTIONAL};
 
 #[derive(Debug)]
@@ -11,3 +12,17 @@ impl InternalEvent for SampleEventDiscarded {
         })
     }
 }
+
+/// An event passed through the `sample` transform configured with a VRL expression.
+#[derive(Debug)]
+pub struct SampleEventPassed {
+    /// Whether the event was passed because the expression returned `true` (`always_pass`), or
+    /// because it was selected by the sampling rate (`rate_pass`).
+    pub outcome: &'static str,
+}
+
+impl InternalEvent for SampleEventPassed {
+    fn emit(self) {
+        counter!("sampled_events_total", "outcome" => self.outcome).increment(1);
+    }
+}
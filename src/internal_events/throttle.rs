@@ -1,6 +1,18 @@
 use metrics::counter;
 use vector_lib::internal_event::{ComponentEventsDropped, InternalEvent, INTENTIONAL};
 
+/// The maximum length of the `key` tag, as keys are rendered from events and can be of any length.
+const MAX_KEY_TAG_BYTES: usize = 64;
+
+/// Truncates a key to at most `MAX_KEY_TAG_BYTES`, on a character boundary.
+fn key_tag(key: &str) -> &str {
+    let mut len = key.len().min(MAX_KEY_TAG_BYTES);
+    while !key.is_char_boundary(len) {
+        len -= 1;
+    }
+    &key[..len]
+}
+
 #[derive(Debug)]
 pub(crate) struct ThrottleEventDiscarded {
     pub key: String,
@@ -22,7 +34,8 @@ impl InternalEvent for ThrottleEventDiscarded {
             // if we should change the specification wording? Sort of a similar situation to the
             // `error_code` tag for the component errors metric, where it's meant to be optional and
             // only specified when relevant.
-            counter!("events_discarded_total", "key" => self.key).increment(1); // Deprecated.
+            let key = key_tag(&self.key).to_string();
+            counter!("events_discarded_total", "key" => key).increment(1); // Deprecated.
         }
 
         emit!(ComponentEventsDropped::<INTENTIONAL> {
@@ -31,3 +44,13 @@ impl InternalEvent for ThrottleEventDiscarded {
         })
     }
 }
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn truncates_key_tag() {
+        assert_eq!(super::key_tag("tenant-1"), "tenant-1");
+        let key = format!("{}😁", "a".repeat(62));
+        assert_eq!(super::key_tag(&key), "a".repeat(62));
+    }
+}
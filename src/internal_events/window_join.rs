@@ -0,0 +1,16 @@
+use vector_lib::internal_event::{ComponentEventsDropped, InternalEvent, INTENTIONAL};
+
+#[derive(Debug)]
+pub struct WindowJoinEventsDropped {
+    pub count: usize,
+    pub reason: &'static str,
+}
+
+impl InternalEvent for WindowJoinEventsDropped {
+    fn emit(self) {
+        emit!(ComponentEventsDropped::<INTENTIONAL> {
+            count: self.count,
+            reason: self.reason,
+        });
+    }
+}
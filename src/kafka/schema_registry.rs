@@ -0,0 +1,221 @@
+//! A client for the [Confluent Schema Registry][schema_registry], which stores the Avro schemas of
+//! Kafka messages.
+//!
+//! Messages written with a registered schema are prefixed with a magic byte and the ID of their
+//! schema, following the [wire format][wire_format].
+//!
+//! [schema_registry]: https://docs.confluent.io/platform/current/schema-registry/index.html
+//! [wire_format]: https://docs.confluent.io/platform/current/schema-registry/fundamentals/serdes-develop/index.html#wire-format
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use apache_avro::Schema;
+use bytes::{BufMut, Bytes, BytesMut};
+use http::{header, Method, Request, StatusCode};
+use hyper::Body;
+use serde::{de::DeserializeOwned, Deserialize};
+use snafu::{ResultExt, Snafu};
+use vector_lib::configurable::configurable_component;
+
+use crate::{config::ProxyConfig, http::HttpClient, sinks::util::UriSerde, tls::TlsSettings};
+
+const MAGIC_BYTE: u8 = 0;
+const PREFIX_LEN: usize = 5;
+const CONTENT_TYPE: &str = "application/vnd.schemaregistry.v1+json";
+const INVARIANT: &str = "Couldn't acquire lock on the schema registry cache. Please report this.";
+
+#[derive(Debug, Snafu)]
+enum SchemaRegistryError {
+    #[snafu(display("The schema registry responded with {}: {}", status, body))]
+    UnexpectedStatus { status: StatusCode, body: String },
+    #[snafu(display("Invalid Avro schema {} in the schema registry: {}", id, source))]
+    InvalidSchema { id: u32, source: apache_avro::Error },
+    #[snafu(display("The message isn't prefixed with the ID of its schema"))]
+    MissingSchemaId,
+}
+
+/// The strategy used to name the subject a schema is registered under.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SubjectNameStrategy {
+    /// The subject is the name of the topic, suffixed with `-value`.
+    #[default]
+    TopicName,
+
+    /// The subject is the fully qualified name of the Avro record.
+    RecordName,
+
+    /// The subject is the name of the topic and the fully qualified name of the Avro record,
+    /// separated by `-`.
+    TopicRecordName,
+}
+
+impl SubjectNameStrategy {
+    /// Returns the subject the schema of a record sent to the given topic is registered under.
+    pub fn subject(self, topic: &str, record_name: &str) -> String {
+        match self {
+            Self::TopicName => format!("{}-value", topic),
+            Self::RecordName => record_name.to_owned(),
+            Self::TopicRecordName => format!("{}-{}", topic, record_name),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct SchemaIdResponse {
+    id: u32,
+}
+
+#[derive(Deserialize)]
+struct SchemaResponse {
+    schema: String,
+}
+
+/// A schema registry client, caching the schemas and schema IDs it resolves.
+#[derive(Clone)]
+pub struct SchemaRegistry {
+    client: HttpClient,
+    endpoint: UriSerde,
+    ids: Arc<Mutex<HashMap<String, u32>>>,
+    schemas: Arc<Mutex<HashMap<u32, Schema>>>,
+}
+
+impl SchemaRegistry {
+    pub fn new(endpoint: &UriSerde, proxy: &ProxyConfig) -> crate::Result<Self> {
+        let tls = TlsSettings::from_options(None)?;
+        Ok(Self {
+            client: HttpClient::new(tls, proxy)?,
+            endpoint: endpoint.with_default_parts(),
+            ids: Default::default(),
+            schemas: Default::default(),
+        })
+    }
+
+    /// Returns the ID of the given schema under the subject, registering it if it isn't yet.
+    pub async fn schema_id(&self, subject: &str, schema: &str) -> crate::Result<u32> {
+        if let Some(id) = self.ids.lock().expect(INVARIANT).get(subject) {
+            return Ok(*id);
+        }
+
+        let body = serde_json::json!({ "schema": schema }).to_string();
+        let path = format!("subjects/{}", subject);
+        let mut response = self.send(Method::POST, &path, Some(body.clone())).await?;
+        if response.0 == StatusCode::NOT_FOUND {
+            debug!(message = "Registering schema.", %subject);
+            let path = format!("{}/versions", path);
+            response = self.send(Method::POST, &path, Some(body)).await?;
+        }
+        let SchemaIdResponse { id } = parse(response)?;
+
+        self.ids
+            .lock()
+            .expect(INVARIANT)
+            .insert(subject.to_owned(), id);
+        Ok(id)
+    }
+
+    /// Returns the schema with the given ID.
+    pub async fn schema(&self, id: u32) -> crate::Result<Schema> {
+        if let Some(schema) = self.schemas.lock().expect(INVARIANT).get(&id) {
+            return Ok(schema.clone());
+        }
+
+        let path = format!("schemas/ids/{}", id);
+        let SchemaResponse { schema } = parse(self.send(Method::GET, &path, None).await?)?;
+        let schema = Schema::parse_str(&schema).context(InvalidSchemaSnafu { id })?;
+
+        self.schemas
+            .lock()
+            .expect(INVARIANT)
+            .insert(id, schema.clone());
+        Ok(schema)
+    }
+
+    async fn send(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<String>,
+    ) -> crate::Result<(StatusCode, Bytes)> {
+        let mut builder = Request::builder()
+            .method(method)
+            .uri(self.endpoint.append_path(path)?.uri)
+            .header(header::ACCEPT, CONTENT_TYPE);
+        if body.is_some() {
+            builder = builder.header(header::CONTENT_TYPE, CONTENT_TYPE);
+        }
+        let mut request = builder.body(body.map_or_else(Body::empty, Body::from))?;
+        if let Some(auth) = &self.endpoint.auth {
+            auth.apply(&mut request);
+        }
+
+        let response = self.client.send(request).await?;
+        let status = response.status();
+        let body = hyper::body::to_bytes(response.into_body()).await?;
+        Ok((status, body))
+    }
+}
+
+fn parse<T: DeserializeOwned>((status, body): (StatusCode, Bytes)) -> crate::Result<T> {
+    if !status.is_success() {
+        return Err(SchemaRegistryError::UnexpectedStatus {
+            status,
+            body: String::from_utf8_lossy(&body).into_owned(),
+        }
+        .into());
+    }
+    Ok(serde_json::from_slice(&body)?)
+}
+
+/// Prefixes the encoded message with the ID of its schema.
+pub fn prefix_schema_id(schema_id: u32, payload: &[u8]) -> Bytes {
+    let mut message = BytesMut::with_capacity(PREFIX_LEN + payload.len());
+    message.put_u8(MAGIC_BYTE);
+    message.put_u32(schema_id);
+    message.put_slice(payload);
+    message.freeze()
+}
+
+/// Returns the ID of the schema the message is prefixed with.
+pub fn parse_schema_id(message: &[u8]) -> crate::Result<u32> {
+    match message {
+        [MAGIC_BYTE, a, b, c, d, ..] => Ok(u32::from_be_bytes([*a, *b, *c, *d])),
+        _ => Err(SchemaRegistryError::MissingSchemaId.into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn subject_names() {
+        let record = "com.example.Log";
+        assert_eq!(
+            SubjectNameStrategy::TopicName.subject("logs", record),
+            "logs-value"
+        );
+        assert_eq!(
+            SubjectNameStrategy::RecordName.subject("logs", record),
+            "com.example.Log"
+        );
+        assert_eq!(
+            SubjectNameStrategy::TopicRecordName.subject("logs", record),
+            "logs-com.example.Log"
+        );
+    }
+
+    #[test]
+    fn schema_id_prefix_roundtrip() {
+        let message = prefix_schema_id(258, b"\x06bar");
+        assert_eq!(&message[..], b"\0\0\0\x01\x02\x06bar");
+        assert_eq!(parse_schema_id(&message).unwrap(), 258);
+
+        assert!(parse_schema_id(b"\x06bar").is_err());
+        assert!(parse_schema_id(b"\0\0\x01").is_err());
+    }
+}
@@ -72,18 +72,25 @@ pub mod app;
 pub mod async_read;
 #[cfg(feature = "aws-config")]
 pub mod aws;
+pub mod check_config;
 #[allow(unreachable_pub)]
 pub mod codecs;
 pub mod common;
 mod convert_config;
 pub mod encoding_transcode;
+#[cfg(feature = "enrichment-tables-rocksdb")]
+pub mod enrichment;
 pub mod enrichment_tables;
 pub mod extra_context;
 #[cfg(feature = "gcp")]
 pub mod gcp;
 pub(crate) mod graph;
+pub mod healthcheck;
 pub mod heartbeat;
 pub mod http;
+#[cfg(feature = "api-client")]
+#[allow(unreachable_pub)]
+pub mod ingest;
 #[allow(unreachable_pub)]
 #[cfg(any(feature = "sources-kafka", feature = "sinks-kafka"))]
 pub mod kafka;
@@ -111,6 +118,9 @@ pub mod sources;
 pub mod stats;
 #[cfg(feature = "api-client")]
 #[allow(unreachable_pub)]
+pub mod status;
+#[cfg(feature = "api-client")]
+#[allow(unreachable_pub)]
 pub mod tap;
 pub mod template;
 pub mod test_util;
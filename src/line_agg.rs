@@ -16,6 +16,12 @@ use pin_project::pin_project;
 use regex::bytes::Regex;
 use tokio_util::time::delay_queue::{DelayQueue, Key};
 use vector_lib::configurable::configurable_component;
+use vector_lib::lookup::metadata_path;
+
+use crate::{
+    conditions::Condition,
+    event::{Event, EventMetadata, LogEvent, Value},
+};
 
 /// Mode of operation of the line aggregator.
 #[configurable_component]
@@ -47,21 +53,34 @@ pub enum Mode {
     HaltWith,
 }
 
-/// Configuration of multi-line aggregation.
+/// How lines are grouped into messages.
 #[derive(Clone, Debug)]
-pub struct Config {
-    /// Regular expression pattern that is used to match the start of a new message.
-    pub start_pattern: Regex,
+pub enum Aggregation {
+    /// Lines are grouped with regular expression patterns.
+    Patterns {
+        /// Regular expression pattern that is used to match the start of a new message.
+        start_pattern: Regex,
 
-    /// Regular expression pattern that is used to determine whether or not more lines should be read.
-    ///
-    /// This setting must be configured in conjunction with `mode`.
-    pub condition_pattern: Regex,
+        /// Regular expression pattern that is used to determine whether or not more lines should
+        /// be read.
+        condition_pattern: Regex,
+
+        /// Aggregation mode.
+        mode: Mode,
+    },
 
-    /// Aggregation mode.
+    /// Lines are grouped until the condition, evaluated against the buffered lines, is true.
     ///
-    /// This setting must be configured in conjunction with `condition_pattern`.
-    pub mode: Mode,
+    /// The condition runs against an event made of the buffered lines joined with newlines, with
+    /// the number of buffered lines in the `accumulated_lines` metadata field.
+    FlushCondition(Condition),
+}
+
+/// Configuration of multi-line aggregation.
+#[derive(Clone, Debug)]
+pub struct Config {
+    /// How lines are grouped into messages.
+    pub aggregation: Aggregation,
 
     /// The maximum amount of time to wait for the next additional line, in milliseconds.
     ///
@@ -79,9 +98,11 @@ impl Config {
         let timeout = Duration::from_millis(timeout_ms);
 
         Self {
-            start_pattern,
-            condition_pattern,
-            mode,
+            aggregation: Aggregation::Patterns {
+                start_pattern,
+                condition_pattern,
+                mode,
+            },
             timeout,
         }
     }
@@ -304,11 +325,22 @@ where
         line: Bytes,
         context: C,
     ) -> Option<(K, Emit<(Bytes, C, Option<C>)>)> {
+        let (start_pattern, condition_pattern, mode) = match &self.config.aggregation {
+            Aggregation::Patterns {
+                start_pattern,
+                condition_pattern,
+                mode,
+            } => (start_pattern, condition_pattern, *mode),
+            Aggregation::FlushCondition(_) => {
+                return self.handle_line_until_flush(src, line, context)
+            }
+        };
+
         // Check if we already have the buffered data for the source.
         match self.buffers.entry(src) {
             Entry::Occupied(mut entry) => {
-                let condition_matched = self.config.condition_pattern.is_match(line.as_ref());
-                let decision = match (self.config.mode, condition_matched) {
+                let condition_matched = condition_pattern.is_match(line.as_ref());
+                let decision = match (mode, condition_matched) {
                     // All consecutive lines matching this pattern are included in
                     // the group.
                     (Mode::ContinueThrough, true) => Decision::Continue,
@@ -349,7 +381,7 @@ where
             }
             Entry::Vacant(entry) => {
                 // This line is a candidate for buffering, or passing through.
-                if self.config.start_pattern.is_match(line.as_ref()) {
+                if start_pattern.is_match(line.as_ref()) {
                     // It was indeed a new line we need to filter.
                     // Set the timeout and buffer this line.
                     let key = self
@@ -364,6 +396,49 @@ where
             }
         }
     }
+
+    /// Handle line when lines are grouped with a flush condition: every line is buffered, and the
+    /// buffer is flushed once the condition is true.
+    fn handle_line_until_flush(
+        &mut self,
+        src: K,
+        line: Bytes,
+        context: C,
+    ) -> Option<(K, Emit<(Bytes, C, Option<C>)>)> {
+        let Self {
+            config,
+            buffers,
+            timeouts,
+        } = self;
+        let Aggregation::FlushCondition(condition) = &config.aggregation else {
+            unreachable!("lines are grouped with a flush condition");
+        };
+
+        match buffers.entry(src) {
+            Entry::Occupied(mut entry) => {
+                let buffered = entry.get_mut();
+                buffered.1.add_next_line(line, context);
+                if buffered.1.is_flushed_by(condition) {
+                    let (src, (key, buffered)) = entry.remove_entry();
+                    timeouts.remove(&key);
+                    Some((src, Emit::One(buffered.merge())))
+                } else {
+                    timeouts.reset(&buffered.0, config.timeout);
+                    None
+                }
+            }
+            Entry::Vacant(entry) => {
+                let aggregate = Aggregate::new(line, context);
+                if aggregate.is_flushed_by(condition) {
+                    Some((entry.into_key(), Emit::One(aggregate.merge())))
+                } else {
+                    let key = timeouts.insert(entry.key().clone(), config.timeout);
+                    entry.insert((key, aggregate));
+                    None
+                }
+            }
+        }
+    }
 }
 
 struct Aggregate<C> {
@@ -387,18 +462,30 @@ impl<C> Aggregate<C> {
     }
 
     fn merge(self) -> (Bytes, C, Option<C>) {
+        (self.join_lines(), self.initial_context, self.last_context)
+    }
+
+    fn join_lines(&self) -> Bytes {
         let capacity = self.lines.iter().map(|line| line.len() + 1).sum::<usize>() - 1;
         let mut bytes_mut = BytesMut::with_capacity(capacity);
         let mut first = true;
-        for line in self.lines {
+        for line in &self.lines {
             if first {
                 first = false;
             } else {
                 bytes_mut.extend_from_slice(b"\n");
             }
-            bytes_mut.extend_from_slice(&line);
+            bytes_mut.extend_from_slice(line);
         }
-        (bytes_mut.freeze(), self.initial_context, self.last_context)
+        bytes_mut.freeze()
+    }
+
+    /// Evaluates the flush condition against the buffered lines.
+    fn is_flushed_by(&self, condition: &Condition) -> bool {
+        let mut log =
+            LogEvent::from_parts(Value::Bytes(self.join_lines()), EventMetadata::default());
+        log.insert(metadata_path!("accumulated_lines"), self.lines.len() as i64);
+        condition.check(Event::Log(log)).0
     }
 }
 
@@ -410,6 +497,7 @@ mod tests {
     use std::fmt::Write as _;
 
     use super::*;
+    use crate::conditions::{ConditionalConfig, VrlConfig};
 
     #[tokio::test]
     async fn mode_continue_through_1() {
@@ -424,9 +512,11 @@ mod tests {
             " last part of the incomplete finishing message",
         ];
         let config = Config {
-            start_pattern: Regex::new("^[^\\s]").unwrap(),
-            condition_pattern: Regex::new("^[\\s]+").unwrap(),
-            mode: Mode::ContinueThrough,
+            aggregation: Aggregation::Patterns {
+                start_pattern: Regex::new("^[^\\s]").unwrap(),
+                condition_pattern: Regex::new("^[\\s]+").unwrap(),
+                mode: Mode::ContinueThrough,
+            },
             timeout: Duration::from_millis(10),
         };
         let expected = vec![
@@ -463,9 +553,11 @@ mod tests {
             "last part of the incomplete finishing message \\",
         ];
         let config = Config {
-            start_pattern: Regex::new("\\\\$").unwrap(),
-            condition_pattern: Regex::new("\\\\$").unwrap(),
-            mode: Mode::ContinuePast,
+            aggregation: Aggregation::Patterns {
+                start_pattern: Regex::new("\\\\$").unwrap(),
+                condition_pattern: Regex::new("\\\\$").unwrap(),
+                mode: Mode::ContinuePast,
+            },
             timeout: Duration::from_millis(10),
         };
         let expected = vec![
@@ -502,9 +594,11 @@ mod tests {
             "last part of the incomplete finishing message",
         ];
         let config = Config {
-            start_pattern: Regex::new("").unwrap(),
-            condition_pattern: Regex::new("^(INFO|ERROR) ").unwrap(),
-            mode: Mode::HaltBefore,
+            aggregation: Aggregation::Patterns {
+                start_pattern: Regex::new("").unwrap(),
+                condition_pattern: Regex::new("^(INFO|ERROR) ").unwrap(),
+                mode: Mode::HaltBefore,
+            },
             timeout: Duration::from_millis(10),
         };
         let expected = vec![
@@ -541,9 +635,11 @@ mod tests {
             "last part of the incomplete finishing message",
         ];
         let config = Config {
-            start_pattern: Regex::new("[^;]$").unwrap(),
-            condition_pattern: Regex::new(";$").unwrap(),
-            mode: Mode::HaltWith,
+            aggregation: Aggregation::Patterns {
+                start_pattern: Regex::new("[^;]$").unwrap(),
+                condition_pattern: Regex::new(";$").unwrap(),
+                mode: Mode::HaltWith,
+            },
             timeout: Duration::from_millis(10),
         };
         let expected = vec![
@@ -575,9 +671,11 @@ mod tests {
             "    at com.foo.baz(baz.java:456)",
         ];
         let config = Config {
-            start_pattern: Regex::new("^[^\\s]").unwrap(),
-            condition_pattern: Regex::new("^[\\s]+at").unwrap(),
-            mode: Mode::ContinueThrough,
+            aggregation: Aggregation::Patterns {
+                start_pattern: Regex::new("^[^\\s]").unwrap(),
+                condition_pattern: Regex::new("^[\\s]+at").unwrap(),
+                mode: Mode::ContinueThrough,
+            },
             timeout: Duration::from_millis(10),
         };
         let expected = vec![(
@@ -601,9 +699,11 @@ mod tests {
             "\tfrom foobar.rb:9:in `<main>'",
         ];
         let config = Config {
-            start_pattern: Regex::new("^[^\\s]").unwrap(),
-            condition_pattern: Regex::new("^[\\s]+from").unwrap(),
-            mode: Mode::ContinueThrough,
+            aggregation: Aggregation::Patterns {
+                start_pattern: Regex::new("^[^\\s]").unwrap(),
+                condition_pattern: Regex::new("^[\\s]+from").unwrap(),
+                mode: Mode::ContinueThrough,
+            },
             timeout: Duration::from_millis(10),
         };
         let expected = vec![(
@@ -639,9 +739,11 @@ mod tests {
             "not merged 6", // will be stashed
         ];
         let config = Config {
-            start_pattern: Regex::new("^\\s").unwrap(),
-            condition_pattern: Regex::new("^\\s").unwrap(),
-            mode: Mode::ContinueThrough,
+            aggregation: Aggregation::Patterns {
+                start_pattern: Regex::new("^\\s").unwrap(),
+                condition_pattern: Regex::new("^\\s").unwrap(),
+                mode: Mode::ContinueThrough,
+            },
             timeout: Duration::from_millis(10),
         };
         let expected = vec![
@@ -677,9 +779,11 @@ mod tests {
             "START msg 5", // will be stashed
         ];
         let config = Config {
-            start_pattern: Regex::new("").unwrap(),
-            condition_pattern: Regex::new("^START ").unwrap(),
-            mode: Mode::HaltBefore,
+            aggregation: Aggregation::Patterns {
+                start_pattern: Regex::new("").unwrap(),
+                condition_pattern: Regex::new("^START ").unwrap(),
+                mode: Mode::HaltBefore,
+            },
             timeout: Duration::from_millis(10),
         };
         let expected = vec![
@@ -693,6 +797,35 @@ mod tests {
         run_and_assert(&lines, config, &expected).await;
     }
 
+    #[tokio::test]
+    async fn flush_condition() {
+        let lines = vec![
+            "{",
+            "  \"a\": 1",
+            "}",
+            "{ \"b\": 2 }",
+            "part 1",
+            "part 2",
+            "part 3",
+            "part 4",
+            "{",
+        ];
+        let condition = VrlConfig::new(r#"ends_with(string!(.), "}") || %accumulated_lines == 3"#)
+            .build(&Default::default())
+            .unwrap();
+        let config = Config {
+            aggregation: Aggregation::FlushCondition(condition),
+            timeout: Duration::from_millis(10),
+        };
+        let expected = vec![
+            ("{\n  \"a\": 1\n}", 0, Some(2)),
+            ("{ \"b\": 2 }", 3, None),
+            ("part 1\npart 2\npart 3", 4, Some(6)),
+            ("part 4\n{", 7, Some(8)),
+        ];
+        run_and_assert(&lines, config, &expected).await;
+    }
+
     #[tokio::test]
     async fn legacy() {
         let lines = vec![
@@ -752,9 +885,11 @@ mod tests {
             lines.push(format!("line {}", i));
         }
         let config = Config {
-            start_pattern: Regex::new("").unwrap(),
-            condition_pattern: Regex::new("^START ").unwrap(),
-            mode: Mode::HaltBefore,
+            aggregation: Aggregation::Patterns {
+                start_pattern: Regex::new("").unwrap(),
+                condition_pattern: Regex::new("^START ").unwrap(),
+                mode: Mode::HaltBefore,
+            },
             timeout: Duration::from_millis(10),
         };
 
@@ -0,0 +1,365 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{LazyLock, Mutex},
+    time::{Duration, Instant},
+};
+
+use bytes::Bytes;
+use http::{Method, Request, StatusCode};
+use hyper::Body;
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::Value;
+use vector_lib::configurable::{component::GenerateConfig, configurable_component};
+use vector_lib::sensitive_string::SensitiveString;
+
+use crate::{
+    config::{ProxyConfig, SecretBackend},
+    http::HttpClient,
+    signal,
+    tls::{TlsConfig, TlsSettings},
+};
+
+const TOKEN_HEADER: &str = "X-Vault-Token";
+const INVARIANT: &str = "Couldn't acquire lock on the Vault secrets cache. Please report this.";
+
+/// The secrets fetched from Vault, keyed by the address and path of the secret they were read
+/// from. The cache outlives the backends, which are rebuilt on each configuration reload.
+static CACHE: LazyLock<Mutex<HashMap<String, CachedSecret>>> = LazyLock::new(Default::default);
+
+#[derive(Clone, Debug)]
+struct CachedSecret {
+    data: HashMap<String, Value>,
+    fetched_at: Instant,
+}
+
+/// Configuration for the `hashicorp_vault` secrets backend.
+///
+/// The secret is read from a [KV version 2][kv_v2] secrets engine, and each of its keys can be
+/// referenced as `SECRET[<backend_name>.<key>]`.
+///
+/// [kv_v2]: https://developer.hashicorp.com/vault/docs/secrets/kv/kv-v2
+#[configurable_component(secrets("hashicorp_vault"))]
+#[derive(Clone, Debug)]
+pub struct HashicorpVaultBackend {
+    /// The address of the Vault server.
+    #[configurable(metadata(docs::examples = "https://vault.example.com:8200"))]
+    pub address: String,
+
+    /// The path of the secret to resolve, relative to `mount_path`.
+    #[configurable(metadata(docs::examples = "vector/production"))]
+    pub secret_path: String,
+
+    /// The path the KV secrets engine is mounted at.
+    #[serde(default = "default_mount_path")]
+    pub mount_path: String,
+
+    /// The token used to authenticate to Vault.
+    ///
+    /// Takes precedence over `approle`.
+    pub token: Option<SensitiveString>,
+
+    #[configurable(derived)]
+    pub approle: Option<AppRoleAuth>,
+
+    /// How long, in seconds, a fetched secret is reused before it's fetched again.
+    ///
+    /// Secrets are only fetched when the configuration is loaded or reloaded, so they aren't
+    /// refreshed in the background once this expires: a changed secret is picked up by the first
+    /// reload after that.
+    ///
+    /// If Vault can't be reached when the secret is fetched again, the previously fetched secret
+    /// is used and a warning is logged.
+    #[serde(default = "default_cache_ttl_secs")]
+    #[configurable(metadata(docs::type_unit = "seconds"))]
+    pub cache_ttl_secs: u64,
+
+    #[configurable(derived)]
+    pub tls: Option<TlsConfig>,
+}
+
+/// Credentials used to log in with the [AppRole][approle] auth method.
+///
+/// [approle]: https://developer.hashicorp.com/vault/docs/auth/approle
+#[configurable_component]
+#[derive(Clone, Debug)]
+pub struct AppRoleAuth {
+    /// The role ID.
+    pub role_id: String,
+
+    /// The secret ID.
+    pub secret_id: SensitiveString,
+
+    /// The path the AppRole auth method is mounted at.
+    #[serde(default = "default_approle_mount_path")]
+    pub mount_path: String,
+}
+
+impl GenerateConfig for HashicorpVaultBackend {
+    fn generate_config() -> toml::Value {
+        toml::Value::try_from(HashicorpVaultBackend {
+            address: String::from("https://vault.example.com:8200"),
+            secret_path: String::from("vector/production"),
+            mount_path: default_mount_path(),
+            token: Some(String::from("${VAULT_TOKEN}").into()),
+            approle: None,
+            cache_ttl_secs: default_cache_ttl_secs(),
+            tls: None,
+        })
+        .unwrap()
+    }
+}
+
+fn default_mount_path() -> String {
+    String::from("secret")
+}
+
+fn default_approle_mount_path() -> String {
+    String::from("approle")
+}
+
+const fn default_cache_ttl_secs() -> u64 {
+    300
+}
+
+#[derive(Deserialize)]
+struct LoginResponse {
+    auth: LoginAuth,
+}
+
+#[derive(Deserialize)]
+struct LoginAuth {
+    client_token: String,
+}
+
+#[derive(Deserialize)]
+struct ReadResponse {
+    data: ReadData,
+}
+
+#[derive(Deserialize)]
+struct ReadData {
+    data: HashMap<String, Value>,
+}
+
+impl HashicorpVaultBackend {
+    fn cache_key(&self) -> String {
+        format!(
+            "{}/{}/{}",
+            self.address.trim_end_matches('/'),
+            self.mount_path.trim_matches('/'),
+            self.secret_path.trim_matches('/')
+        )
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}/v1/{}", self.address.trim_end_matches('/'), path)
+    }
+
+    async fn token(&self, client: &HttpClient) -> crate::Result<String> {
+        if let Some(token) = &self.token {
+            return Ok(token.inner().to_owned());
+        }
+        let Some(approle) = &self.approle else {
+            return Err("either `token` or `approle` must be set".into());
+        };
+
+        let body = serde_json::json!({
+            "role_id": approle.role_id,
+            "secret_id": approle.secret_id.inner(),
+        });
+        let path = format!("auth/{}/login", approle.mount_path.trim_matches('/'));
+        let request = Request::builder()
+            .method(Method::POST)
+            .uri(self.url(&path))
+            .body(Body::from(body.to_string()))?;
+        let LoginResponse { auth } = parse(send(client, request).await?)?;
+        Ok(auth.client_token)
+    }
+
+    async fn fetch(&self) -> crate::Result<HashMap<String, Value>> {
+        let tls = TlsSettings::from_options(self.tls.as_ref())?;
+        let client = HttpClient::new(tls, &ProxyConfig::default())?;
+        let token = self.token(&client).await?;
+
+        let path = format!(
+            "{}/data/{}",
+            self.mount_path.trim_matches('/'),
+            self.secret_path.trim_matches('/')
+        );
+        let request = Request::builder()
+            .method(Method::GET)
+            .uri(self.url(&path))
+            .header(TOKEN_HEADER, token)
+            .body(Body::empty())?;
+        let ReadResponse { data } = parse(send(&client, request).await?)?;
+        Ok(data.data)
+    }
+
+    /// Returns the secret from the cache if it's fresh, and fetches it from Vault otherwise,
+    /// falling back to the cached secret if it can't be fetched.
+    async fn fetch_cached(&self) -> crate::Result<HashMap<String, Value>> {
+        let key = self.cache_key();
+        let ttl = Duration::from_secs(self.cache_ttl_secs);
+        let cached = CACHE.lock().expect(INVARIANT).get(&key).cloned();
+        if let Some(cached) = cached.as_ref().filter(|c| c.fetched_at.elapsed() < ttl) {
+            return Ok(cached.data.clone());
+        }
+
+        match (self.fetch().await, cached) {
+            (Ok(data), _) => {
+                CACHE.lock().expect(INVARIANT).insert(
+                    key,
+                    CachedSecret {
+                        data: data.clone(),
+                        fetched_at: Instant::now(),
+                    },
+                );
+                Ok(data)
+            }
+            (Err(error), Some(cached)) => {
+                warn!(
+                    message = "Couldn't fetch the secret from Vault, using the cached secret.",
+                    path = %self.secret_path,
+                    %error,
+                );
+                Ok(cached.data)
+            }
+            (Err(error), None) => Err(error),
+        }
+    }
+}
+
+impl SecretBackend for HashicorpVaultBackend {
+    async fn retrieve(
+        &mut self,
+        secret_keys: HashSet<String>,
+        _: &mut signal::SignalRx,
+    ) -> crate::Result<HashMap<String, String>> {
+        let output = self.fetch_cached().await?;
+
+        let mut secrets = HashMap::new();
+        for k in secret_keys.into_iter() {
+            let Some(value) = output.get(&k) else {
+                return Err(format!(
+                    "key '{}' in secret at path '{}' does not exist",
+                    k, &self.secret_path
+                )
+                .into());
+            };
+            let Some(secret) = scalar_to_string(value) else {
+                return Err(format!(
+                    "value for key '{}' in secret at path '{}' is not a string, number or boolean",
+                    k, &self.secret_path
+                )
+                .into());
+            };
+            if secret.is_empty() {
+                return Err(format!(
+                    "value for key '{}' in secret at path '{}' was empty",
+                    k, &self.secret_path
+                )
+                .into());
+            }
+            secrets.insert(k, secret);
+        }
+        Ok(secrets)
+    }
+}
+
+/// Vault stores the values of a KV secret as JSON, so scalars other than strings are accepted too.
+fn scalar_to_string(value: &Value) -> Option<String> {
+    match value {
+        Value::String(string) => Some(string.clone()),
+        Value::Number(number) => Some(number.to_string()),
+        Value::Bool(boolean) => Some(boolean.to_string()),
+        Value::Null | Value::Array(_) | Value::Object(_) => None,
+    }
+}
+
+async fn send(client: &HttpClient, request: Request<Body>) -> crate::Result<(StatusCode, Bytes)> {
+    let response = client.send(request).await?;
+    let status = response.status();
+    let body = hyper::body::to_bytes(response.into_body()).await?;
+    Ok((status, body))
+}
+
+fn parse<T: DeserializeOwned>((status, body): (StatusCode, Bytes)) -> crate::Result<T> {
+    if !status.is_success() {
+        return Err(format!(
+            "Vault responded with {}: {}",
+            status,
+            String::from_utf8_lossy(&body)
+        )
+        .into());
+    }
+    Ok(serde_json::from_slice(&body)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn backend(address: &str, ttl: u64) -> HashicorpVaultBackend {
+        HashicorpVaultBackend {
+            address: address.to_owned(),
+            secret_path: "/vector/test/".to_owned(),
+            mount_path: default_mount_path(),
+            token: Some(String::from("token").into()),
+            approle: None,
+            cache_ttl_secs: ttl,
+            tls: None,
+        }
+    }
+
+    fn cache(backend: &HashicorpVaultBackend, fetched_at: Instant) {
+        let data = HashMap::from([("password".to_owned(), Value::from("hunter2"))]);
+        CACHE
+            .lock()
+            .unwrap()
+            .insert(backend.cache_key(), CachedSecret { data, fetched_at });
+    }
+
+    #[test]
+    fn cache_key_normalizes_slashes() {
+        assert_eq!(
+            backend("http://vault:8200/", 0).cache_key(),
+            "http://vault:8200/secret/vector/test"
+        );
+    }
+
+    #[test]
+    fn scalar_values_are_read_as_strings() {
+        let body = r#"{"data":{"data":{"user":"vector","port":5432,"tls":true,"tags":["a"]}}}"#;
+        let ReadResponse { data } = serde_json::from_str(body).unwrap();
+
+        assert_eq!(scalar_to_string(&data.data["user"]).unwrap(), "vector");
+        assert_eq!(scalar_to_string(&data.data["port"]).unwrap(), "5432");
+        assert_eq!(scalar_to_string(&data.data["tls"]).unwrap(), "true");
+        assert_eq!(scalar_to_string(&data.data["tags"]), None);
+    }
+
+    #[tokio::test]
+    async fn fresh_secrets_are_served_from_the_cache() {
+        // Nothing listens on this address, so the secret can only come from the cache.
+        let backend = backend("http://127.0.0.1:1", 300);
+        cache(&backend, Instant::now());
+
+        let secrets = backend.fetch_cached().await.unwrap();
+        assert_eq!(secrets["password"], "hunter2");
+    }
+
+    #[tokio::test]
+    async fn stale_secrets_are_used_when_vault_is_unreachable() {
+        let backend = backend("http://127.0.0.2:1", 0);
+        cache(&backend, Instant::now());
+
+        let secrets = backend.fetch_cached().await.unwrap();
+        assert_eq!(secrets["password"], "hunter2");
+
+        let uncached = HashicorpVaultBackend {
+            secret_path: "vector/missing".to_owned(),
+            ..backend
+        };
+        assert!(uncached.fetch_cached().await.is_err());
+    }
+}
@@ -12,6 +12,8 @@ mod aws_secrets_manager;
 mod directory;
 mod exec;
 mod file;
+#[cfg(feature = "secrets-hashicorp-vault")]
+mod hashicorp_vault;
 mod test;
 
 ///	Configuration options to retrieve secrets from external backend in order to avoid storing secrets in plaintext
@@ -72,6 +74,10 @@ pub enum SecretBackends {
     #[cfg(feature = "secrets-aws-secrets-manager")]
     AwsSecretsManager(aws_secrets_manager::AwsSecretsManagerBackend),
 
+    /// HashiCorp Vault.
+    #[cfg(feature = "secrets-hashicorp-vault")]
+    HashicorpVault(hashicorp_vault::HashicorpVaultBackend),
+
     /// Test.
     #[configurable(metadata(docs::hidden))]
     Test(test::TestBackend),
@@ -1,11 +1,18 @@
 #![allow(missing_docs)]
 
 use snafu::Snafu;
-use std::collections::HashSet;
-use tokio::{runtime::Runtime, sync::broadcast};
+use std::{
+    collections::HashSet,
+    sync::{Arc, Mutex},
+};
+use tokio::{
+    runtime::Runtime,
+    sync::{broadcast, oneshot},
+};
 use tokio_stream::{Stream, StreamExt};
 
 use super::config::{ComponentKey, ConfigBuilder};
+use super::topology::ReloadOutcome;
 
 pub type ShutdownTx = broadcast::Sender<()>;
 pub type SignalTx = broadcast::Sender<SignalTo>;
@@ -19,6 +26,8 @@ pub enum SignalTo {
     ReloadComponents(HashSet<ComponentKey>),
     /// Signal to reload config from a string.
     ReloadFromConfigBuilder(ConfigBuilder),
+    /// Signal to reload config from a config builder, reporting the outcome of the reload.
+    ReloadFromApi(ConfigBuilder, ReloadResponder),
     /// Signal to reload config from the filesystem.
     ReloadFromDisk,
     /// Signal to shutdown process.
@@ -35,6 +44,7 @@ impl PartialEq for SignalTo {
             (ReloadComponents(a), ReloadComponents(b)) => a == b,
             // TODO: This will require a lot of plumbing but ultimately we can derive equality for config builders.
             (ReloadFromConfigBuilder(_), ReloadFromConfigBuilder(_)) => true,
+            (ReloadFromApi(_, _), ReloadFromApi(_, _)) => true,
             (ReloadFromDisk, ReloadFromDisk) => true,
             (Shutdown(a), Shutdown(b)) => a == b,
             (Quit, Quit) => true,
@@ -43,6 +53,34 @@ impl PartialEq for SignalTo {
     }
 }
 
+/// Reports the outcome of a reload to the sender of the reload signal.
+///
+/// Signals are broadcast, so they must be cloneable: the outcome is sent to the first responder
+/// used, and the others are ignored.
+#[derive(Clone, Debug)]
+pub struct ReloadResponder(Arc<Mutex<Option<oneshot::Sender<ReloadOutcome>>>>);
+
+impl ReloadResponder {
+    /// Create a new responder, along with the receiver of the outcome it reports.
+    pub fn new() -> (Self, oneshot::Receiver<ReloadOutcome>) {
+        let (tx, rx) = oneshot::channel();
+        (Self(Arc::new(Mutex::new(Some(tx)))), rx)
+    }
+
+    /// Report the outcome of the reload.
+    pub fn respond(&self, outcome: ReloadOutcome) {
+        let tx = self
+            .0
+            .lock()
+            .expect("reload responder lock poisoned")
+            .take();
+        if let Some(tx) = tx {
+            // The receiver may have been dropped if the API request was cancelled.
+            _ = tx.send(outcome);
+        }
+    }
+}
+
 #[derive(Clone, Debug, Snafu, PartialEq, Eq)]
 pub enum ShutdownError {
     // For future work: It would be nice if we could keep the actual errors in here, but
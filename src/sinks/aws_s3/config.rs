@@ -8,7 +8,10 @@ use vector_lib::configurable::configurable_component;
 use vector_lib::sink::VectorSink;
 use vector_lib::TimeZone;
 
-use super::sink::S3RequestOptions;
+use super::{
+    parquet::S3BatchEncoding,
+    sink::{S3Encoder, S3RequestOptions},
+};
 use crate::{
     aws::{AwsAuthentication, RegionOrEndpoint},
     codecs::{Encoder, EncodingConfigWithFraming, SinkType},
@@ -113,6 +116,14 @@ pub struct S3SinkConfig {
     #[serde(default = "Compression::gzip_default")]
     pub compression: Compression,
 
+    /// Encoding configuration for whole batches of events.
+    ///
+    /// When set, each object is written in this format instead of encoding its events one by one
+    /// with `encoding.codec`. The other `encoding` options still apply, while `compression` is
+    /// ignored as the format compresses its contents itself.
+    #[serde(default)]
+    pub batch_encoding: Option<S3BatchEncoding>,
+
     #[configurable(derived)]
     #[serde(default)]
     pub batch: BatchConfig<BulkSizeBasedDefaultBatchSettings>,
@@ -167,6 +178,7 @@ impl GenerateConfig for S3SinkConfig {
             region: RegionOrEndpoint::default(),
             encoding: (None::<FramingConfig>, TextSerializerConfig::default()).into(),
             compression: Compression::gzip_default(),
+            batch_encoding: None,
             batch: BatchConfig::default(),
             request: TowerRequestConfig::default(),
             tls: Some(TlsConfig::default()),
@@ -190,7 +202,10 @@ impl SinkConfig for S3SinkConfig {
     }
 
     fn input(&self) -> Input {
-        Input::new(self.encoding.config().1.input_type())
+        match self.batch_encoding {
+            Some(S3BatchEncoding::Parquet(_)) => Input::log(),
+            None => Input::new(self.encoding.config().1.input_type()),
+        }
     }
 
     fn acknowledgements(&self) -> &AcknowledgementsConfig {
@@ -234,17 +249,33 @@ impl S3SinkConfig {
         let partitioner = S3KeyPartitioner::new(key_prefix, ssekms_key_id, None);
 
         let transformer = self.encoding.transformer();
-        let (framer, serializer) = self.encoding.build(SinkType::MessageBased)?;
-        let encoder = Encoder::<Framer>::new(framer, serializer);
+        let (encoder, compression, filename_extension) = match &self.batch_encoding {
+            Some(S3BatchEncoding::Parquet(config)) => (
+                S3Encoder::Parquet(config.build(transformer)),
+                Compression::None,
+                self.filename_extension
+                    .clone()
+                    .or_else(|| Some("parquet".to_string())),
+            ),
+            None => {
+                let (framer, serializer) = self.encoding.build(SinkType::MessageBased)?;
+                let encoder = Encoder::<Framer>::new(framer, serializer);
+                (
+                    S3Encoder::Framed((transformer, encoder)),
+                    self.compression,
+                    self.filename_extension.clone(),
+                )
+            }
+        };
 
         let request_options = S3RequestOptions {
             bucket: self.bucket.clone(),
             api_options: self.options.clone(),
-            filename_extension: self.filename_extension.clone(),
+            filename_extension,
             filename_time_format: self.filename_time_format.clone(),
             filename_append_uuid: self.filename_append_uuid,
-            encoder: (transformer, encoder),
-            compression: self.compression,
+            encoder,
+            compression,
             filename_tz_offset: offset,
         };
 
@@ -17,6 +17,7 @@ use aws_smithy_runtime_api::client::result::SdkError;
 use bytes::Buf;
 use flate2::read::MultiGzDecoder;
 use futures::{stream, Stream};
+use parquet::file::reader::{FileReader, SerializedFileReader};
 use similar_asserts::assert_eq;
 use tokio_stream::StreamExt;
 use vector_lib::codecs::{encoding::FramingConfig, TextSerializerConfig};
@@ -31,7 +32,10 @@ use crate::{
     common::s3::S3ClientBuilder,
     config::SinkContext,
     sinks::{
-        aws_s3::config::default_filename_time_format,
+        aws_s3::{
+            config::default_filename_time_format,
+            parquet::{ParquetCompression, ParquetSerializerConfig, S3BatchEncoding},
+        },
         s3_common::config::{S3Options, S3ServerSideEncryption},
         util::{BatchConfig, Compression, TowerRequestConfig},
     },
@@ -296,6 +300,45 @@ async fn s3_zstd() {
     assert_eq!(lines, response_lines);
 }
 
+#[tokio::test]
+async fn s3_parquet() {
+    let cx = SinkContext::default();
+
+    let bucket = uuid::Uuid::new_v4().to_string();
+
+    create_bucket(&bucket, false).await;
+
+    let batch_size = 1_000;
+    let config = S3SinkConfig {
+        batch_encoding: Some(S3BatchEncoding::Parquet(ParquetSerializerConfig {
+            max_rows: 300,
+            parquet_compression: ParquetCompression::Zstd,
+        })),
+        ..config(&bucket, batch_size)
+    };
+
+    let prefix = config.key_prefix.clone();
+    let service = config.create_service(&cx.globals.proxy).await.unwrap();
+    let sink = config.build_processor(service, cx).unwrap();
+
+    let (_lines, events, receiver) = make_events_batch(100, batch_size);
+    run_and_assert_sink_compliance(sink, events, &AWS_SINK_TAGS).await;
+    assert_eq!(receiver.await, BatchStatus::Delivered);
+
+    let keys = get_keys(&bucket, prefix).await;
+    assert_eq!(keys.len(), 1);
+    assert!(keys[0].ends_with(".parquet"));
+
+    let obj = get_object(&bucket, keys[0].clone()).await;
+    assert_eq!(obj.content_encoding, None);
+
+    let body = obj.body.collect().await.unwrap().into_bytes();
+    let reader = SerializedFileReader::new(body).unwrap();
+    let metadata = reader.metadata();
+    assert_eq!(metadata.file_metadata().num_rows(), batch_size as i64);
+    assert_eq!(metadata.num_row_groups(), 4);
+}
+
 // NOTE: this test doesn't actually validate anything because localstack
 // doesn't enforce the required Content-MD5 header on the request for
 // buckets with object lock enabled
@@ -432,6 +475,7 @@ async fn s3_flush_on_exhaustion() {
             region: RegionOrEndpoint::with_both("us-east-1", s3_address()),
             encoding: (None::<FramingConfig>, TextSerializerConfig::default()).into(),
             compression: Compression::None,
+            batch_encoding: None,
             batch,
             request: TowerRequestConfig::default(),
             tls: Default::default(),
@@ -522,6 +566,7 @@ fn config(bucket: &str, batch_size: usize) -> S3SinkConfig {
         region: RegionOrEndpoint::with_both("us-east-1", s3_address()),
         encoding: (None::<FramingConfig>, TextSerializerConfig::default()).into(),
         compression: Compression::None,
+        batch_encoding: None,
         batch,
         request: TowerRequestConfig::default(),
         tls: Default::default(),
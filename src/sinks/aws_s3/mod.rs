@@ -1,4 +1,5 @@
 mod config;
+mod parquet;
 mod sink;
 
 mod integration_tests;
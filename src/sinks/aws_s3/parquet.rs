@@ -0,0 +1,407 @@
+//! Parquet encoding for the `aws_s3` sink.
+
+use std::{io, sync::Arc};
+
+use arrow_array::{
+    builder::{
+        BooleanBuilder, Float64Builder, Int64Builder, StringBuilder, TimestampMicrosecondBuilder,
+    },
+    ArrayRef, RecordBatch,
+};
+use arrow_schema::{DataType, Field, Schema, TimeUnit};
+use parquet::{
+    arrow::ArrowWriter,
+    basic::{Compression, GzipLevel, ZstdLevel},
+    file::properties::{WriterProperties, WriterVersion},
+};
+use vector_lib::{
+    config::telemetry, configurable::configurable_component, event::Value,
+    request_metadata::GroupedCountByteSize, EstimatedJsonEncodedSizeOf,
+};
+
+use crate::{
+    codecs::Transformer,
+    event::{Event, LogEvent},
+    sinks::util::encoding::{write_all, Encoder},
+};
+
+/// Encoding configuration for batches of events.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(tag = "codec", rename_all = "snake_case")]
+#[configurable(metadata(docs::enum_tag_description = "The codec to use for encoding batches."))]
+pub enum S3BatchEncoding {
+    /// Encodes each batch of events as an [Apache Parquet][apache_parquet] file.
+    ///
+    /// The schema of the file is inferred from the top-level fields of the first event of the
+    /// batch, and all the columns are optional. Fields missing from the first event are dropped,
+    /// and values whose type doesn't match their column are written as nulls. Nested objects and
+    /// arrays are written as JSON strings.
+    ///
+    /// [apache_parquet]: https://parquet.apache.org/
+    Parquet(ParquetSerializerConfig),
+}
+
+/// Apache Parquet-specific encoder options.
+#[configurable_component]
+#[derive(Clone, Debug)]
+pub struct ParquetSerializerConfig {
+    /// The maximum number of rows of each row group.
+    ///
+    /// Batches with more events are written as several row groups.
+    #[serde(default = "default_max_rows")]
+    #[configurable(metadata(docs::type_unit = "rows"))]
+    pub max_rows: usize,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub parquet_compression: ParquetCompression,
+}
+
+const fn default_max_rows() -> usize {
+    10_000
+}
+
+/// The compression applied to the columns of the Parquet files.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ParquetCompression {
+    /// [Snappy][snappy] compression.
+    ///
+    /// [snappy]: https://github.com/google/snappy
+    #[default]
+    Snappy,
+
+    /// [Gzip][gzip] compression.
+    ///
+    /// [gzip]: https://www.gzip.org/
+    Gzip,
+
+    /// [Zstandard][zstd] compression.
+    ///
+    /// [zstd]: https://facebook.github.io/zstd/
+    Zstd,
+
+    /// No compression.
+    None,
+}
+
+impl From<ParquetCompression> for Compression {
+    fn from(compression: ParquetCompression) -> Self {
+        match compression {
+            ParquetCompression::Snappy => Self::SNAPPY,
+            ParquetCompression::Gzip => Self::GZIP(GzipLevel::default()),
+            ParquetCompression::Zstd => Self::ZSTD(ZstdLevel::default()),
+            ParquetCompression::None => Self::UNCOMPRESSED,
+        }
+    }
+}
+
+impl ParquetSerializerConfig {
+    pub fn build(&self, transformer: Transformer) -> ParquetEncoder {
+        ParquetEncoder {
+            transformer,
+            properties: WriterProperties::builder()
+                .set_writer_version(WriterVersion::PARQUET_2_0)
+                .set_compression(self.parquet_compression.into())
+                .set_max_row_group_size(self.max_rows.max(1))
+                .build(),
+        }
+    }
+}
+
+/// Encodes batches of log events as Parquet files.
+#[derive(Clone, Debug)]
+pub struct ParquetEncoder {
+    transformer: Transformer,
+    properties: WriterProperties,
+}
+
+impl Encoder<Vec<Event>> for ParquetEncoder {
+    fn encode_input(
+        &self,
+        events: Vec<Event>,
+        writer: &mut dyn io::Write,
+    ) -> io::Result<(usize, GroupedCountByteSize)> {
+        let mut byte_size = telemetry().create_request_count_byte_size();
+        let n_events = events.len();
+
+        let logs = events
+            .into_iter()
+            .map(|mut event| {
+                self.transformer.transform(&mut event);
+                byte_size.add_event(&event, event.estimated_json_encoded_size_of());
+                event.into_log()
+            })
+            .collect::<Vec<_>>();
+
+        let Some(first) = logs.first() else {
+            return Ok((0, byte_size));
+        };
+        let columns = infer_columns(first);
+        let schema = Arc::new(Schema::new(
+            columns
+                .iter()
+                .map(|(name, column_type)| Field::new(name, column_type.data_type(), true))
+                .collect::<Vec<_>>(),
+        ));
+        let arrays = columns
+            .iter()
+            .map(|(name, column_type)| {
+                column_type.build_array(logs.iter().map(|log| field(log, name)))
+            })
+            .collect();
+        let batch = RecordBatch::try_new(Arc::clone(&schema), arrays)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+
+        // The writer splits the batch in row groups of `max_rows` rows.
+        let mut buffer = Vec::new();
+        let mut parquet = ArrowWriter::try_new(&mut buffer, schema, Some(self.properties.clone()))?;
+        parquet.write(&batch)?;
+        parquet.close()?;
+        write_all(writer, n_events, &buffer)?;
+
+        Ok((buffer.len(), byte_size))
+    }
+}
+
+/// Returns the top-level fields of a log event, with the root value as a `message` field if the
+/// event isn't an object.
+fn fields(log: &LogEvent) -> Vec<(String, &Value)> {
+    match log.value() {
+        Value::Object(map) => map
+            .iter()
+            .map(|(name, value)| (name.to_string(), value))
+            .collect(),
+        value => vec![("message".to_string(), value)],
+    }
+}
+
+fn field<'a>(log: &'a LogEvent, name: &str) -> Option<&'a Value> {
+    match log.value() {
+        Value::Object(map) => map.get(name),
+        value => (name == "message").then_some(value),
+    }
+}
+
+/// The time zone of the timestamp columns.
+const UTC: &str = "+00:00";
+
+/// The types of the columns of the Parquet files.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum ColumnType {
+    Boolean,
+    Int64,
+    Float64,
+    Timestamp,
+    Utf8,
+}
+
+impl ColumnType {
+    const fn of(value: &Value) -> Self {
+        match value {
+            Value::Boolean(_) => Self::Boolean,
+            Value::Integer(_) => Self::Int64,
+            Value::Float(_) => Self::Float64,
+            Value::Timestamp(_) => Self::Timestamp,
+            _ => Self::Utf8,
+        }
+    }
+
+    fn data_type(self) -> DataType {
+        match self {
+            Self::Boolean => DataType::Boolean,
+            Self::Int64 => DataType::Int64,
+            Self::Float64 => DataType::Float64,
+            Self::Timestamp => DataType::Timestamp(TimeUnit::Microsecond, Some(UTC.into())),
+            Self::Utf8 => DataType::Utf8,
+        }
+    }
+
+    fn build_array<'a>(self, values: impl Iterator<Item = Option<&'a Value>>) -> ArrayRef {
+        match self {
+            Self::Boolean => {
+                let mut array = BooleanBuilder::new();
+                for value in values {
+                    array.append_option(match value {
+                        Some(Value::Boolean(value)) => Some(*value),
+                        _ => None,
+                    });
+                }
+                Arc::new(array.finish())
+            }
+            Self::Int64 => {
+                let mut array = Int64Builder::new();
+                for value in values {
+                    array.append_option(match value {
+                        Some(Value::Integer(value)) => Some(*value),
+                        _ => None,
+                    });
+                }
+                Arc::new(array.finish())
+            }
+            Self::Float64 => {
+                let mut array = Float64Builder::new();
+                for value in values {
+                    array.append_option(match value {
+                        Some(Value::Float(value)) => Some(value.into_inner()),
+                        Some(Value::Integer(value)) => Some(*value as f64),
+                        _ => None,
+                    });
+                }
+                Arc::new(array.finish())
+            }
+            Self::Timestamp => {
+                let mut array = TimestampMicrosecondBuilder::new().with_timezone(UTC);
+                for value in values {
+                    array.append_option(match value {
+                        Some(Value::Timestamp(value)) => Some(value.timestamp_micros()),
+                        _ => None,
+                    });
+                }
+                Arc::new(array.finish())
+            }
+            Self::Utf8 => {
+                let mut array = StringBuilder::new();
+                for value in values {
+                    array.append_option(value.and_then(value_to_string));
+                }
+                Arc::new(array.finish())
+            }
+        }
+    }
+}
+
+fn value_to_string(value: &Value) -> Option<String> {
+    match value {
+        Value::Null => None,
+        Value::Bytes(bytes) => Some(String::from_utf8_lossy(bytes).into_owned()),
+        Value::Object(_) | Value::Array(_) => serde_json::to_string(value).ok(),
+        value => Some(value.to_string_lossy().into_owned()),
+    }
+}
+
+/// Infers the columns of a Parquet file from the first event of its batch.
+fn infer_columns(log: &LogEvent) -> Vec<(String, ColumnType)> {
+    fields(log)
+        .into_iter()
+        .map(|(name, value)| (name, ColumnType::of(value)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow_array::{cast::AsArray, types::Int64Type};
+    use bytes::Bytes;
+    use chrono::{TimeZone, Utc};
+    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+
+    use super::*;
+
+    fn encode(config: &ParquetSerializerConfig, events: Vec<Event>) -> Vec<u8> {
+        let mut writer = Vec::new();
+        config
+            .build(Transformer::default())
+            .encode_input(events, &mut writer)
+            .unwrap();
+        writer
+    }
+
+    fn config(max_rows: usize) -> ParquetSerializerConfig {
+        ParquetSerializerConfig {
+            max_rows,
+            parquet_compression: ParquetCompression::Snappy,
+        }
+    }
+
+    #[test]
+    fn infers_schema_from_first_event() {
+        let mut log = LogEvent::from("hello");
+        log.insert("count", 3);
+        log.insert("ratio", 0.5);
+        log.insert("ok", true);
+        log.insert(
+            "timestamp",
+            Utc.with_ymd_and_hms(2024, 1, 15, 10, 30, 0).unwrap(),
+        );
+        log.insert("user.id", 42);
+
+        let encoded = Bytes::from(encode(&config(10), vec![log.into()]));
+        let reader = ParquetRecordBatchReaderBuilder::try_new(encoded).unwrap();
+
+        let types = reader
+            .schema()
+            .fields()
+            .iter()
+            .map(|field| {
+                (
+                    field.name().as_str(),
+                    field.data_type().clone(),
+                    field.is_nullable(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            types,
+            [
+                ("count", DataType::Int64, true),
+                ("message", DataType::Utf8, true),
+                ("ok", DataType::Boolean, true),
+                ("ratio", DataType::Float64, true),
+                (
+                    "timestamp",
+                    DataType::Timestamp(TimeUnit::Microsecond, Some(UTC.into())),
+                    true
+                ),
+                ("user", DataType::Utf8, true),
+            ]
+        );
+        assert_eq!(reader.metadata().file_metadata().num_rows(), 1);
+    }
+
+    #[test]
+    fn writes_row_groups_of_max_rows() {
+        let events = (0..5)
+            .map(|index| {
+                let mut log = LogEvent::from(format!("line {index}"));
+                if index != 3 {
+                    log.insert("index", index);
+                }
+                log.into()
+            })
+            .collect();
+
+        let encoded = Bytes::from(encode(&config(2), events));
+        let reader = ParquetRecordBatchReaderBuilder::try_new(encoded).unwrap();
+        let row_groups = reader
+            .metadata()
+            .row_groups()
+            .iter()
+            .map(|row_group| row_group.num_rows())
+            .collect::<Vec<_>>();
+        assert_eq!(row_groups, [2, 2, 1]);
+
+        let batches = reader
+            .build()
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        let indexes = batches
+            .iter()
+            .flat_map(|batch| {
+                batch
+                    .column(0)
+                    .as_primitive::<Int64Type>()
+                    .iter()
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(indexes, [Some(0), Some(1), Some(2), None, Some(4)]);
+    }
+
+    #[test]
+    fn encodes_empty_batch() {
+        assert!(encode(&config(10), Vec::new()).is_empty());
+    }
+}
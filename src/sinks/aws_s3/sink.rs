@@ -5,8 +5,9 @@ use chrono::{FixedOffset, Utc};
 use uuid::Uuid;
 use vector_lib::codecs::encoding::Framer;
 use vector_lib::event::Finalizable;
-use vector_lib::request_metadata::RequestMetadata;
+use vector_lib::request_metadata::{GroupedCountByteSize, RequestMetadata};
 
+use super::parquet::ParquetEncoder;
 use crate::{
     codecs::{Encoder, Transformer},
     event::Event,
@@ -17,12 +18,32 @@ use crate::{
             service::{S3Metadata, S3Request},
         },
         util::{
-            metadata::RequestMetadataBuilder, request_builder::EncodeResult, Compression,
+            encoding, metadata::RequestMetadataBuilder, request_builder::EncodeResult, Compression,
             RequestBuilder,
         },
     },
 };
 
+/// Encodes the events of a batch either one by one, or as a whole.
+#[derive(Clone)]
+pub enum S3Encoder {
+    Framed((Transformer, Encoder<Framer>)),
+    Parquet(ParquetEncoder),
+}
+
+impl encoding::Encoder<Vec<Event>> for S3Encoder {
+    fn encode_input(
+        &self,
+        events: Vec<Event>,
+        writer: &mut dyn io::Write,
+    ) -> io::Result<(usize, GroupedCountByteSize)> {
+        match self {
+            Self::Framed(encoder) => encoder.encode_input(events, writer),
+            Self::Parquet(encoder) => encoder.encode_input(events, writer),
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct S3RequestOptions {
     pub bucket: String,
@@ -30,7 +51,7 @@ pub struct S3RequestOptions {
     pub filename_append_uuid: bool,
     pub filename_extension: Option<String>,
     pub api_options: S3Options,
-    pub encoder: (Transformer, Encoder<Framer>),
+    pub encoder: S3Encoder,
     pub compression: Compression,
     pub filename_tz_offset: Option<FixedOffset>,
 }
@@ -38,7 +59,7 @@ pub struct S3RequestOptions {
 impl RequestBuilder<(S3PartitionKey, Vec<Event>)> for S3RequestOptions {
     type Metadata = S3Metadata;
     type Events = Vec<Event>;
-    type Encoder = (Transformer, Encoder<Framer>);
+    type Encoder = S3Encoder;
     type Payload = Bytes;
     type Request = S3Request;
     type Error = io::Error; // TODO: this is ugly.
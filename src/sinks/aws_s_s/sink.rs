@@ -44,7 +44,10 @@ where
             )
             .filter_map(|req| async move {
                 req.map_err(|error| {
-                    emit!(SinkRequestBuildError { error });
+                    emit!(SinkRequestBuildError {
+                        error,
+                        error_code: "io_error",
+                    });
                 })
                 .ok()
             })
@@ -53,7 +53,10 @@ where
             .filter_map(|request| async move {
                 match request {
                     Err(error) => {
-                        emit!(SinkRequestBuildError { error });
+                        emit!(SinkRequestBuildError {
+                            error,
+                            error_code: "request_build_failed",
+                        });
                         None
                     }
                     Ok(req) => Some(req),
@@ -49,7 +49,10 @@ where
             .filter_map(|request| async {
                 match request {
                     Err(error) => {
-                        emit!(SinkRequestBuildError { error });
+                        emit!(SinkRequestBuildError {
+                            error,
+                            error_code: "io_error",
+                        });
                         None
                     }
                     Ok(req) => Some(req),
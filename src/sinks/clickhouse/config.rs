@@ -2,8 +2,9 @@
 
 use super::{
     request_builder::ClickhouseRequestBuilder,
-    service::{ClickhouseRetryLogic, ClickhouseServiceRequestBuilder},
-    sink::{ClickhouseSink, PartitionKey},
+    request_builder::ClickhouseRequestMetadata,
+    service::{emit_insert_summary, ClickhouseRetryLogic, ClickhouseServiceRequestBuilder},
+    sink::ClickhouseSink,
 };
 use crate::{
     http::{Auth, HttpClient, MaybeAuth},
@@ -16,6 +17,7 @@ use http::{Request, StatusCode, Uri};
 use hyper::Body;
 use std::fmt;
 use vector_lib::codecs::{encoding::Framer, JsonSerializerConfig, NewlineDelimitedEncoderConfig};
+use vector_lib::lookup::lookup_v2::ConfigValuePath;
 
 /// Data format.
 ///
@@ -85,6 +87,14 @@ pub struct ClickhouseConfig {
     #[serde(default)]
     pub insert_random_shard: bool,
 
+    /// The field to deduplicate rows by, for tables using the `ReplacingMergeTree` engine.
+    ///
+    /// When set, the events of each batch are sorted by the value of this field before being
+    /// inserted, and each insert carries a deduplication token computed as the SHA-256 of its
+    /// payload, so that ClickHouse ignores the inserts retried after a failure.
+    #[configurable(metadata(docs::examples = "id"))]
+    pub dedup_key_field: Option<ConfigValuePath>,
+
     #[configurable(derived)]
     #[serde(default = "Compression::gzip_default")]
     pub compression: Compression,
@@ -139,13 +149,14 @@ impl SinkConfig for ClickhouseConfig {
             compression: self.compression,
         };
 
-        let service: HttpService<ClickhouseServiceRequestBuilder, PartitionKey> =
+        let service: HttpService<ClickhouseServiceRequestBuilder, ClickhouseRequestMetadata> =
             HttpService::new(client.clone(), clickhouse_service_request_builder);
 
         let request_limits = self.request.into_settings();
 
         let service = ServiceBuilder::new()
             .settings(request_limits, ClickhouseRetryLogic::default())
+            .map_response(emit_insert_summary)
             .service(service);
 
         let batch_settings = self.batch.into_batcher_settings()?;
@@ -165,6 +176,7 @@ impl SinkConfig for ClickhouseConfig {
                     JsonSerializerConfig::default().build().into(),
                 ),
             ),
+            dedup_key_field: self.dedup_key_field.clone(),
         };
 
         let sink = ClickhouseSink::new(
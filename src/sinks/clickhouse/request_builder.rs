@@ -1,13 +1,27 @@
 //! `RequestBuilder` implementation for the `Clickhouse` sink.
 
+use std::cmp::Ordering;
+
 use super::sink::PartitionKey;
 use crate::sinks::{prelude::*, util::http::HttpRequest};
 use bytes::Bytes;
+use sha2::{Digest, Sha256};
 use vector_lib::codecs::encoding::Framer;
+use vector_lib::lookup::{lookup_v2::ConfigValuePath, PathPrefix};
+
+/// The metadata of a request to insert a batch of events.
+#[derive(Clone, Debug)]
+pub(super) struct ClickhouseRequestMetadata {
+    pub(super) key: PartitionKey,
+
+    /// The token ClickHouse uses to deduplicate retried inserts, if deduplication is enabled.
+    pub(super) deduplication_token: Option<String>,
+}
 
 pub(super) struct ClickhouseRequestBuilder {
     pub(super) compression: Compression,
     pub(super) encoding: (Transformer, Encoder<Framer>),
+    pub(super) dedup_key_field: Option<ConfigValuePath>,
 }
 
 impl RequestBuilder<(PartitionKey, Vec<Event>)> for ClickhouseRequestBuilder {
@@ -15,7 +29,7 @@ impl RequestBuilder<(PartitionKey, Vec<Event>)> for ClickhouseRequestBuilder {
     type Events = Vec<Event>;
     type Encoder = (Transformer, Encoder<Framer>);
     type Payload = Bytes;
-    type Request = HttpRequest<PartitionKey>;
+    type Request = HttpRequest<ClickhouseRequestMetadata>;
     type Error = std::io::Error;
 
     fn compression(&self) -> Compression {
@@ -32,6 +46,10 @@ impl RequestBuilder<(PartitionKey, Vec<Event>)> for ClickhouseRequestBuilder {
     ) -> (Self::Metadata, RequestMetadataBuilder, Self::Events) {
         let (key, mut events) = input;
 
+        if let Some(dedup_key_field) = &self.dedup_key_field {
+            sort_by_dedup_key(&mut events, dedup_key_field);
+        }
+
         let finalizers = events.take_finalizers();
         let builder = RequestMetadataBuilder::from_events(&events);
         ((key, finalizers), builder, events)
@@ -44,15 +62,74 @@ impl RequestBuilder<(PartitionKey, Vec<Event>)> for ClickhouseRequestBuilder {
         payload: EncodeResult<Self::Payload>,
     ) -> Self::Request {
         let (key, finalizers) = metadata;
+        let payload = payload.into_payload();
+        let deduplication_token = self
+            .dedup_key_field
+            .is_some()
+            .then(|| format!("{:x}", Sha256::digest(&payload)));
+
         HttpRequest::new(
-            payload.into_payload(),
+            payload,
             finalizers,
             request_metadata,
-            PartitionKey {
-                database: key.database,
-                table: key.table,
-                format: key.format,
+            ClickhouseRequestMetadata {
+                key,
+                deduplication_token,
             },
         )
     }
 }
+
+/// Sorts the events of a batch by the value of their deduplication key, so that the rows
+/// `ReplacingMergeTree` tables deduplicate are inserted next to each other.
+fn sort_by_dedup_key(events: &mut [Event], dedup_key_field: &ConfigValuePath) {
+    events.sort_by(|a, b| {
+        let path = (PathPrefix::Event, &dedup_key_field.0);
+        compare_dedup_keys(a.as_log().get(path), b.as_log().get(path))
+    });
+}
+
+/// Orders the values of deduplication keys, with events missing the key first.
+fn compare_dedup_keys(a: Option<&Value>, b: Option<&Value>) -> Ordering {
+    match (a, b) {
+        (Some(Value::Integer(a)), Some(Value::Integer(b))) => a.cmp(b),
+        (Some(Value::Float(a)), Some(Value::Float(b))) => a.cmp(b),
+        (Some(Value::Timestamp(a)), Some(Value::Timestamp(b))) => a.cmp(b),
+        (Some(a), Some(b)) => a.to_string_lossy().cmp(&b.to_string_lossy()),
+        (a, b) => a.is_some().cmp(&b.is_some()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vector_lib::event::LogEvent;
+
+    use super::*;
+
+    #[test]
+    fn sorts_events_by_dedup_key() {
+        let mut events = [Some(10_i64), None, Some(2), Some(10), Some(1)]
+            .into_iter()
+            .enumerate()
+            .map(|(index, id)| {
+                let mut log = LogEvent::default();
+                log.insert("index", index as i64);
+                if let Some(id) = id {
+                    log.insert("id", id);
+                }
+                Event::Log(log)
+            })
+            .collect::<Vec<_>>();
+
+        sort_by_dedup_key(
+            &mut events,
+            &ConfigValuePath::try_from("id".to_owned()).unwrap(),
+        );
+
+        let order = events
+            .iter()
+            .map(|event| event.as_log()["index"].clone())
+            .collect::<Vec<_>>();
+        assert_eq!(order, [1_i64, 4, 2, 0, 3].map(Value::from));
+    }
+}
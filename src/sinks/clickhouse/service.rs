@@ -1,8 +1,9 @@
 //! Service implementation for the `Clickhouse` sink.
 
-use super::sink::PartitionKey;
+use super::request_builder::ClickhouseRequestMetadata;
 use crate::{
     http::{Auth, HttpError},
+    internal_events::ClickhouseRowsInserted,
     sinks::{
         clickhouse::config::Format,
         prelude::*,
@@ -18,8 +19,16 @@ use http::{
     header::{CONTENT_ENCODING, CONTENT_LENGTH, CONTENT_TYPE},
     Request, StatusCode, Uri,
 };
+use serde::Deserialize;
+use serde_with::{serde_as, DisplayFromStr};
 use snafu::ResultExt;
 
+/// The header carrying the token ClickHouse deduplicates retried inserts with.
+const DEDUPLICATION_TOKEN_HEADER: &str = "X-ClickHouse-Deduplication-Token";
+
+/// The header carrying the summary of a query, as a JSON object.
+const SUMMARY_HEADER: &str = "X-ClickHouse-Summary";
+
 #[derive(Debug, Default, Clone)]
 pub struct ClickhouseRetryLogic {
     inner: HttpRetryLogic,
@@ -59,6 +68,45 @@ impl RetryLogic for ClickhouseRetryLogic {
     }
 }
 
+/// The summary of an insert query, returned by ClickHouse in the `X-ClickHouse-Summary` header.
+#[serde_as]
+#[derive(Debug, Deserialize)]
+struct InsertSummary {
+    /// The number of rows written to the table, which excludes the rows of deduplicated inserts.
+    #[serde_as(as = "DisplayFromStr")]
+    written_rows: u64,
+}
+
+/// Emits the number of rows sent by a successful insert, along with the number of rows
+/// ClickHouse wrote according to the summary of the query.
+pub(super) fn emit_insert_summary(response: HttpResponse) -> HttpResponse {
+    if !response.http_response.status().is_success() {
+        return response;
+    }
+
+    let summary = response
+        .http_response
+        .headers()
+        .get(SUMMARY_HEADER)
+        .map(|summary| serde_json::from_slice::<InsertSummary>(summary.as_bytes()));
+    match summary {
+        Some(Ok(summary)) => {
+            let total_rows = match response.events_byte_size.sizes() {
+                Some(sizes) => sizes.values().map(|size| size.0).sum(),
+                None => response.events_byte_size.size().map_or(0, |size| size.0),
+            };
+            emit!(ClickhouseRowsInserted {
+                total_rows,
+                written_rows: summary.written_rows,
+            });
+        }
+        Some(Err(error)) => debug!(message = "Failed to parse the insert summary.", %error),
+        None => {}
+    }
+
+    response
+}
+
 #[derive(Debug, Clone)]
 pub(super) struct ClickhouseServiceRequestBuilder {
     pub(super) auth: Option<Auth>,
@@ -69,21 +117,23 @@ pub(super) struct ClickhouseServiceRequestBuilder {
     pub(super) compression: Compression,
 }
 
-impl HttpServiceRequestBuilder<PartitionKey> for ClickhouseServiceRequestBuilder {
+impl HttpServiceRequestBuilder<ClickhouseRequestMetadata> for ClickhouseServiceRequestBuilder {
     fn build(
         &self,
-        mut request: HttpRequest<PartitionKey>,
+        mut request: HttpRequest<ClickhouseRequestMetadata>,
     ) -> Result<Request<Bytes>, crate::Error> {
         let metadata = request.get_additional_metadata();
+        let deduplication_token = metadata.deduplication_token.clone();
 
         let uri = set_uri_query(
             &self.endpoint,
-            &metadata.database,
-            &metadata.table,
-            metadata.format,
+            &metadata.key.database,
+            &metadata.key.table,
+            metadata.key.format,
             self.skip_unknown_fields,
             self.date_time_best_effort,
             self.insert_random_shard,
+            deduplication_token.as_deref(),
         )?;
 
         let auth: Option<Auth> = self.auth.clone();
@@ -96,6 +146,9 @@ impl HttpServiceRequestBuilder<PartitionKey> for ClickhouseServiceRequestBuilder
         if let Some(ce) = self.compression.content_encoding() {
             builder = builder.header(CONTENT_ENCODING, ce);
         }
+        if let Some(token) = deduplication_token {
+            builder = builder.header(DEDUPLICATION_TOKEN_HEADER, token);
+        }
         if let Some(auth) = auth {
             builder = auth.apply_builder(builder);
         }
@@ -115,6 +168,7 @@ fn set_uri_query(
     skip_unknown: Option<bool>,
     date_time_best_effort: bool,
     insert_random_shard: bool,
+    deduplication_token: Option<&str>,
 ) -> crate::Result<Uri> {
     let query = url::form_urlencoded::Serializer::new(String::new())
         .append_pair(
@@ -148,6 +202,12 @@ fn set_uri_query(
     if insert_random_shard {
         uri.push_str("insert_distributed_one_random_shard=1&")
     }
+    if let Some(token) = deduplication_token {
+        // ClickHouse applies the token from the `insert_deduplication_token` setting.
+        uri.push_str("insert_deduplication_token=");
+        uri.push_str(token);
+        uri.push('&');
+    }
     uri.push_str(query.as_str());
 
     uri.parse::<Uri>()
@@ -169,6 +229,7 @@ mod tests {
             Some(false),
             true,
             false,
+            None,
         )
         .unwrap();
         assert_eq!(uri.to_string(), "http://localhost:80/?\
@@ -185,6 +246,7 @@ mod tests {
             Some(false),
             false,
             false,
+            None,
         )
         .unwrap();
         assert_eq!(uri.to_string(), "http://localhost:80/?\
@@ -200,6 +262,7 @@ mod tests {
             Some(true),
             true,
             false,
+            None,
         )
         .unwrap();
         assert_eq!(uri.to_string(), "http://localhost:80/?\
@@ -216,6 +279,7 @@ mod tests {
             None,
             true,
             false,
+            None,
         )
         .unwrap();
         assert_eq!(uri.to_string(), "http://localhost:80/?\
@@ -224,6 +288,15 @@ mod tests {
                                      query=INSERT+INTO+%22my_database%22.%22my_%5C%22table%5C%22%22+FORMAT+JSONAsObject");
     }
 
+    #[test]
+    fn parse_insert_summary() {
+        let summary: InsertSummary = serde_json::from_str(
+            r#"{"read_rows":"0","read_bytes":"0","written_rows":"2","written_bytes":"84"}"#,
+        )
+        .unwrap();
+        assert_eq!(summary.written_rows, 2);
+    }
+
     #[test]
     fn encode_invalid() {
         set_uri_query(
@@ -234,6 +307,7 @@ mod tests {
             Some(false),
             false,
             false,
+            None,
         )
         .unwrap_err();
     }
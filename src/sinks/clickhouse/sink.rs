@@ -1,6 +1,9 @@
 //! Implementation of the `clickhouse` sink.
 
-use super::{config::Format, request_builder::ClickhouseRequestBuilder};
+use super::{
+    config::Format,
+    request_builder::{ClickhouseRequestBuilder, ClickhouseRequestMetadata},
+};
 use crate::sinks::{prelude::*, util::http::HttpRequest};
 
 pub struct ClickhouseSink<S> {
@@ -14,7 +17,7 @@ pub struct ClickhouseSink<S> {
 
 impl<S> ClickhouseSink<S>
 where
-    S: Service<HttpRequest<PartitionKey>> + Send + 'static,
+    S: Service<HttpRequest<ClickhouseRequestMetadata>> + Send + 'static,
     S::Future: Send + 'static,
     S::Response: DriverResponse + Send + 'static,
     S::Error: std::fmt::Debug + Into<crate::Error> + Send,
@@ -53,7 +56,10 @@ where
             .filter_map(|request| async {
                 match request {
                     Err(error) => {
-                        emit!(SinkRequestBuildError { error });
+                        emit!(SinkRequestBuildError {
+                            error,
+                            error_code: "io_error",
+                        });
                         None
                     }
                     Ok(req) => Some(req),
@@ -68,7 +74,7 @@ where
 #[async_trait::async_trait]
 impl<S> StreamSink<Event> for ClickhouseSink<S>
 where
-    S: Service<HttpRequest<PartitionKey>> + Send + 'static,
+    S: Service<HttpRequest<ClickhouseRequestMetadata>> + Send + 'static,
     S::Future: Send + 'static,
     S::Response: DriverResponse + Send + 'static,
     S::Error: std::fmt::Debug + Into<crate::Error> + Send,
@@ -0,0 +1,175 @@
+use aws_sdk_s3::Client as S3Client;
+use tower::ServiceBuilder;
+use vector_lib::configurable::configurable_component;
+use vector_lib::sink::VectorSink;
+
+use super::{
+    encoder::DatadogArchivesEncoder,
+    sink::{DatadogArchivesPartitioner, DatadogArchivesRequestBuilder},
+};
+use crate::{
+    aws::{AwsAuthentication, RegionOrEndpoint},
+    config::{AcknowledgementsConfig, GenerateConfig, Input, ProxyConfig, SinkConfig, SinkContext},
+    sinks::{
+        s3_common::{
+            self,
+            config::{S3Options, S3RetryLogic},
+            service::S3Service,
+            sink::S3Sink,
+        },
+        util::{
+            BatchConfig, Compression, ServiceBuilderExt, SinkBatchSettings, TowerRequestConfig,
+        },
+        Healthcheck,
+    },
+    tls::TlsConfig,
+};
+
+// Archives are meant to be few and large, as they are only read back when rehydrating logs.
+pub const BATCH_GOAL_BYTES: usize = 100_000_000;
+pub const BATCH_DEFAULT_TIMEOUT_SECS: f64 = 900.0;
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DatadogArchivesDefaultBatchSettings;
+
+impl SinkBatchSettings for DatadogArchivesDefaultBatchSettings {
+    const MAX_EVENTS: Option<usize> = None;
+    const MAX_BYTES: Option<usize> = Some(BATCH_GOAL_BYTES);
+    const TIMEOUT_SECS: f64 = BATCH_DEFAULT_TIMEOUT_SECS;
+}
+
+/// Configuration for the `datadog_archives` sink.
+#[configurable_component(sink(
+    "datadog_archives",
+    "Archive log events to S3-compatible object storage in the Datadog log archives format."
+))]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct DatadogArchivesSinkConfig {
+    /// The S3 bucket name.
+    ///
+    /// This must not include a leading `s3://` or a trailing `/`.
+    #[configurable(metadata(docs::examples = "my-bucket"))]
+    pub bucket: String,
+
+    /// A prefix to apply to all object keys.
+    ///
+    /// Archives are written under `<prefix>/year=YYYY/month=MM/day=DD/hour=HH/`, from the
+    /// timestamp of their events. A trailing `/` is added to the prefix if it's missing.
+    #[serde(default)]
+    #[configurable(metadata(docs::examples = "logs/archives"))]
+    pub prefix: String,
+
+    #[serde(flatten)]
+    pub options: S3Options,
+
+    #[serde(flatten)]
+    pub region: RegionOrEndpoint,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub batch: BatchConfig<DatadogArchivesDefaultBatchSettings>,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub request: TowerRequestConfig,
+
+    #[configurable(derived)]
+    pub tls: Option<TlsConfig>,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub auth: AwsAuthentication,
+
+    #[configurable(derived)]
+    #[serde(
+        default,
+        deserialize_with = "crate::serde::bool_or_struct",
+        skip_serializing_if = "crate::serde::is_default"
+    )]
+    pub acknowledgements: AcknowledgementsConfig,
+
+    /// Specifies which addressing style to use.
+    ///
+    /// This controls if the bucket name is in the hostname or part of the URL.
+    #[serde(default = "crate::serde::default_true")]
+    pub force_path_style: bool,
+}
+
+impl GenerateConfig for DatadogArchivesSinkConfig {
+    fn generate_config() -> toml::Value {
+        toml::from_str(
+            r#"bucket = "my-bucket"
+            prefix = "logs/archives"
+            region = "us-east-1""#,
+        )
+        .unwrap()
+    }
+}
+
+#[async_trait::async_trait]
+#[typetag::serde(name = "datadog_archives")]
+impl SinkConfig for DatadogArchivesSinkConfig {
+    async fn build(&self, cx: SinkContext) -> crate::Result<(VectorSink, Healthcheck)> {
+        let service = self.create_service(&cx.proxy).await?;
+        let healthcheck = self.build_healthcheck(service.client())?;
+        let sink = self.build_processor(service)?;
+        Ok((sink, healthcheck))
+    }
+
+    fn input(&self) -> Input {
+        Input::log()
+    }
+
+    fn acknowledgements(&self) -> &AcknowledgementsConfig {
+        &self.acknowledgements
+    }
+}
+
+impl DatadogArchivesSinkConfig {
+    pub fn build_processor(&self, service: S3Service) -> crate::Result<VectorSink> {
+        let request_limits = self.request.into_settings();
+        let service = ServiceBuilder::new()
+            .settings(request_limits, S3RetryLogic)
+            .service(service);
+
+        let batch_settings = self.batch.into_batcher_settings()?;
+
+        let partitioner = DatadogArchivesPartitioner::new(self.prefix.clone());
+        let request_builder = DatadogArchivesRequestBuilder {
+            bucket: self.bucket.clone(),
+            api_options: self.options.clone(),
+            encoder: DatadogArchivesEncoder,
+            compression: Compression::gzip_default(),
+        };
+
+        let sink = S3Sink::new(service, request_builder, partitioner, batch_settings);
+
+        Ok(VectorSink::from_event_streamsink(sink))
+    }
+
+    pub fn build_healthcheck(&self, client: S3Client) -> crate::Result<Healthcheck> {
+        s3_common::config::build_healthcheck(self.bucket.clone(), client)
+    }
+
+    pub async fn create_service(&self, proxy: &ProxyConfig) -> crate::Result<S3Service> {
+        s3_common::config::create_service(
+            &self.region,
+            &self.auth,
+            proxy,
+            self.tls.as_ref(),
+            self.force_path_style,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DatadogArchivesSinkConfig;
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<DatadogArchivesSinkConfig>();
+    }
+}
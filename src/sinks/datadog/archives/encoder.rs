@@ -0,0 +1,179 @@
+use std::io;
+
+use chrono::{SecondsFormat, Utc};
+use uuid::Uuid;
+use vector_lib::{
+    config::telemetry,
+    event::{Event, LogEvent, ObjectMap, Value},
+    request_metadata::GroupedCountByteSize,
+    schema::meaning,
+    EstimatedJsonEncodedSizeOf,
+};
+
+use crate::sinks::util::encoding::{write_all, Encoder};
+
+/// The fields of the Datadog log archives schema, along with the meaning of the event fields they
+/// are taken from. Any other field of the event is nested under `attributes`.
+const ARCHIVE_RESERVED_ATTRS: [(&str, &str); 4] = [
+    (meaning::SERVICE, "service"),
+    (meaning::SOURCE, "source"),
+    (meaning::SEVERITY, "status"),
+    (meaning::TAGS, "tags"),
+];
+
+/// Encodes batches of log events as newline-delimited JSON objects following the schema of the
+/// Datadog log archives.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DatadogArchivesEncoder;
+
+impl Encoder<Vec<Event>> for DatadogArchivesEncoder {
+    fn encode_input(
+        &self,
+        events: Vec<Event>,
+        writer: &mut dyn io::Write,
+    ) -> io::Result<(usize, GroupedCountByteSize)> {
+        let mut byte_size = telemetry().create_request_count_byte_size();
+        let mut bytes_written = 0;
+        let mut n_events_pending = events.len();
+
+        for event in events {
+            byte_size.add_event(&event, event.estimated_json_encoded_size_of());
+
+            let mut line = serde_json::to_vec(&archive_log(event.into_log()))?;
+            line.push(b'\n');
+            write_all(writer, n_events_pending, &line)?;
+            bytes_written += line.len();
+            n_events_pending -= 1;
+        }
+
+        Ok((bytes_written, byte_size))
+    }
+}
+
+/// Converts a log event to the schema of the Datadog log archives:
+///
+/// - `_id` is a unique identifier generated for the event.
+/// - `date` is the timestamp of the event, or the current time if it has none.
+/// - `message` and `host` are taken from the message and host of the event.
+/// - `service`, `source`, `status` and `tags` are taken from the fields with these meanings.
+/// - All the other fields of the event are nested under `attributes`.
+pub fn archive_log(mut log: LogEvent) -> Value {
+    let mut archive = ObjectMap::new();
+    archive.insert("_id".into(), Uuid::now_v7().simple().to_string().into());
+
+    let date = log
+        .remove_timestamp()
+        .and_then(|timestamp| timestamp.as_timestamp().copied())
+        .unwrap_or_else(Utc::now);
+    archive.insert(
+        "date".into(),
+        date.to_rfc3339_opts(SecondsFormat::Millis, true).into(),
+    );
+
+    if !log.value().is_object() {
+        let message = std::mem::replace(log.value_mut(), Value::Object(ObjectMap::new()));
+        archive.insert("message".into(), message);
+    }
+    let paths = [
+        ("message", log.message_path().cloned()),
+        ("host", log.host_path().cloned()),
+    ]
+    .into_iter()
+    .chain(
+        ARCHIVE_RESERVED_ATTRS
+            .iter()
+            .map(|(meaning, name)| (*name, log.find_key_by_meaning(meaning).cloned())),
+    )
+    .collect::<Vec<_>>();
+    for (name, path) in paths {
+        if let Some(value) = path.and_then(|path| log.remove(&path)) {
+            archive.insert(name.into(), value);
+        }
+    }
+
+    // The tags of the archives are a list, while the Datadog logs intake takes them as a single
+    // comma-separated string.
+    if let Some(Value::Bytes(tags)) = archive.get("tags") {
+        let tags = String::from_utf8_lossy(tags)
+            .split(',')
+            .filter(|tag| !tag.is_empty())
+            .map(|tag| Value::from(tag.to_string()))
+            .collect::<Vec<_>>();
+        archive.insert("tags".into(), Value::Array(tags));
+    }
+
+    let (value, _metadata) = log.into_parts();
+    archive.insert("attributes".into(), value);
+
+    Value::Object(archive)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::TimeZone;
+    use vector_lib::{config::LogNamespace, event::EventMetadata, schema::Definition};
+    use vrl::{owned_value_path, path::OwnedTargetPath, value::Kind};
+
+    use super::*;
+
+    fn encode(events: Vec<Event>) -> Vec<serde_json::Value> {
+        let mut writer = Vec::new();
+        DatadogArchivesEncoder
+            .encode_input(events, &mut writer)
+            .unwrap();
+        String::from_utf8(writer)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn encodes_events_in_archive_schema() {
+        let service = OwnedTargetPath::event(owned_value_path!("app"));
+        let tags = OwnedTargetPath::event(owned_value_path!("ddtags"));
+        let definition = Definition::new_with_default_metadata(Kind::any(), [LogNamespace::Legacy])
+            .with_meaning(service, meaning::SERVICE)
+            .with_meaning(tags, meaning::TAGS);
+        let mut log = LogEvent::new_with_metadata(
+            EventMetadata::default().with_schema_definition(&Arc::new(definition)),
+        );
+        let timestamp = Utc.with_ymd_and_hms(2024, 1, 15, 10, 30, 0).unwrap();
+        log.insert("message", "hello");
+        log.insert("timestamp", timestamp);
+        log.insert("host", "web-1");
+        log.insert("app", "checkout");
+        log.insert("ddtags", "env:prod,team:payments");
+        log.insert("user.id", 42);
+
+        let lines = encode(vec![log.into()]);
+
+        assert_eq!(lines.len(), 1);
+        let line = &lines[0];
+        assert_eq!(line["_id"].as_str().unwrap().len(), 32);
+        assert_eq!(line["date"], "2024-01-15T10:30:00.000Z");
+        assert_eq!(line["message"], "hello");
+        assert_eq!(line["host"], "web-1");
+        assert_eq!(line["service"], "checkout");
+        assert_eq!(
+            line["tags"],
+            serde_json::json!(["env:prod", "team:payments"])
+        );
+        assert_eq!(
+            line["attributes"],
+            serde_json::json!({ "user": { "id": 42 } })
+        );
+    }
+
+    #[test]
+    fn generates_unique_ids() {
+        let lines = encode(vec![LogEvent::from("a").into(), LogEvent::from("b").into()]);
+
+        assert_eq!(lines.len(), 2);
+        assert_ne!(lines[0]["_id"], lines[1]["_id"]);
+        assert_eq!(lines[1]["message"], "b");
+        assert_eq!(lines[1]["attributes"], serde_json::json!({}));
+    }
+}
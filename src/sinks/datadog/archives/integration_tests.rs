@@ -0,0 +1,185 @@
+use std::io::{BufRead, BufReader};
+
+use aws_sdk_s3::{operation::create_bucket::CreateBucketError, Client as S3Client};
+use aws_smithy_runtime_api::client::result::SdkError;
+use bytes::Buf;
+use chrono::{TimeZone, Utc};
+use flate2::read::MultiGzDecoder;
+use futures::stream;
+use similar_asserts::assert_eq;
+use vector_lib::{
+    config::proxy::ProxyConfig,
+    event::{BatchNotifier, BatchStatus, Event, LogEvent},
+};
+
+use super::DatadogArchivesSinkConfig;
+use crate::{
+    aws::{create_client, AwsAuthentication, RegionOrEndpoint},
+    common::s3::S3ClientBuilder,
+    config::SinkContext,
+    sinks::{
+        s3_common::config::S3Options,
+        util::{BatchConfig, TowerRequestConfig},
+    },
+    test_util::{
+        components::{run_and_assert_sink_compliance, AWS_SINK_TAGS},
+        random_string,
+    },
+};
+
+fn s3_address() -> String {
+    std::env::var("S3_ADDRESS").unwrap_or_else(|_| "http://localhost:4566".into())
+}
+
+#[tokio::test]
+async fn aws_s3_datadog_archives_writes_hourly_archives() {
+    let cx = SinkContext::default();
+
+    let bucket = uuid::Uuid::new_v4().to_string();
+    create_bucket(&bucket).await;
+
+    let prefix = random_string(10);
+    let config = config(&bucket, &prefix);
+    let service = config.create_service(&cx.globals.proxy).await.unwrap();
+    let sink = config.build_processor(service).unwrap();
+
+    let (batch, receiver) = BatchNotifier::new_with_receiver();
+    let events = (0..10)
+        .map(|i| {
+            let hour = if i < 5 { 7 } else { 8 };
+            let timestamp = Utc.with_ymd_and_hms(2024, 1, 5, hour, i, 0).unwrap();
+            let mut log = LogEvent::from(format!("line {i}"));
+            log.insert("timestamp", timestamp);
+            log.insert("host", "web-1");
+            log.insert("index", i64::from(i));
+            Event::from(log.with_batch_notifier(&batch))
+        })
+        .collect::<Vec<_>>();
+    drop(batch);
+
+    run_and_assert_sink_compliance(sink, stream::iter(events), &AWS_SINK_TAGS).await;
+    assert_eq!(receiver.await, BatchStatus::Delivered);
+
+    let mut keys = get_keys(&bucket, &prefix).await;
+    keys.sort();
+    assert_eq!(keys.len(), 2);
+
+    for (key, hour) in keys.iter().zip(["07", "08"]) {
+        let (key_prefix, file_name) = key.rsplit_once('/').unwrap();
+        assert_eq!(
+            key_prefix,
+            format!("{prefix}/year=2024/month=01/day=05/hour={hour}")
+        );
+        let id = file_name.strip_suffix(".json.gz").unwrap();
+        assert!(uuid::Uuid::parse_str(id).is_ok(), "{file_name}");
+    }
+
+    let mut lines = Vec::new();
+    for key in keys {
+        lines.extend(get_archive_lines(&bucket, key).await);
+    }
+    assert_eq!(lines.len(), 10);
+    for (i, line) in lines.iter().enumerate() {
+        assert_eq!(line["message"], format!("line {i}"));
+        assert_eq!(line["host"], "web-1");
+        assert_eq!(line["attributes"]["index"], i);
+        assert!(line["_id"].is_string());
+        let hour = if i < 5 { 7 } else { 8 };
+        let date = line["date"].as_str().unwrap();
+        assert!(
+            date.starts_with(&format!("2024-01-05T{hour:02}:")),
+            "{date}"
+        );
+    }
+}
+
+fn config(bucket: &str, prefix: &str) -> DatadogArchivesSinkConfig {
+    let mut batch = BatchConfig::default();
+    batch.timeout_secs = Some(5.0);
+
+    DatadogArchivesSinkConfig {
+        bucket: bucket.to_string(),
+        prefix: prefix.to_string(),
+        options: S3Options::default(),
+        region: RegionOrEndpoint::with_both("us-east-1", s3_address()),
+        batch,
+        request: TowerRequestConfig::default(),
+        tls: Default::default(),
+        auth: Default::default(),
+        acknowledgements: Default::default(),
+        force_path_style: true,
+    }
+}
+
+async fn client() -> S3Client {
+    let auth = AwsAuthentication::test_auth();
+    let region = RegionOrEndpoint::with_both("us-east-1", s3_address());
+    let proxy = ProxyConfig::default();
+
+    create_client::<S3ClientBuilder>(
+        &S3ClientBuilder {
+            force_path_style: Some(true),
+        },
+        &auth,
+        region.region(),
+        region.endpoint(),
+        &proxy,
+        None,
+        None,
+    )
+    .await
+    .unwrap()
+}
+
+async fn create_bucket(bucket: &str) {
+    match client()
+        .await
+        .create_bucket()
+        .bucket(bucket.to_string())
+        .send()
+        .await
+    {
+        Ok(_) => {}
+        Err(err) => match err {
+            SdkError::ServiceError(inner) => match &inner.err() {
+                CreateBucketError::BucketAlreadyOwnedByYou(_) => {}
+                err => panic!("Failed to create bucket: {:?}", err),
+            },
+            err => panic!("Failed to create bucket: {:?}", err),
+        },
+    }
+}
+
+async fn get_keys(bucket: &str, prefix: &str) -> Vec<String> {
+    client()
+        .await
+        .list_objects_v2()
+        .bucket(bucket.to_string())
+        .prefix(prefix)
+        .send()
+        .await
+        .unwrap()
+        .contents
+        .unwrap()
+        .into_iter()
+        .map(|obj| obj.key.unwrap())
+        .collect()
+}
+
+async fn get_archive_lines(bucket: &str, key: String) -> Vec<serde_json::Value> {
+    let obj = client()
+        .await
+        .get_object()
+        .bucket(bucket.to_string())
+        .key(key)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(obj.content_encoding.as_deref(), Some("gzip"));
+
+    let body = obj.body.collect().await.unwrap().reader();
+    BufReader::new(MultiGzDecoder::new(body))
+        .lines()
+        .map(|line| serde_json::from_str(&line.unwrap()).unwrap())
+        .collect()
+}
@@ -0,0 +1,23 @@
+//! The Datadog Archives [`vector_lib::sink::VectorSink`]
+//!
+//! This module contains the [`vector_lib::sink::VectorSink`] instance that is responsible for
+//! writing log events to an S3-compatible bucket in the format of [Datadog log archives][archives],
+//! so that they can be rehydrated into Datadog later on.
+//!
+//! Events are grouped by the hour of their timestamp, and each batch is written as a gzipped,
+//! newline-delimited JSON object under the following key:
+//!
+//! ```text
+//! <prefix>year=YYYY/month=MM/day=DD/hour=HH/<uuid>.json.gz
+//! ```
+//!
+//! [archives]: https://docs.datadoghq.com/logs/log_configuration/archives/
+
+#[cfg(all(test, feature = "datadog-archives-integration-tests"))]
+mod integration_tests;
+
+pub mod config;
+pub mod encoder;
+pub mod sink;
+
+pub use self::config::DatadogArchivesSinkConfig;
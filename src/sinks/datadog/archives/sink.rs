@@ -0,0 +1,170 @@
+use std::io;
+
+use bytes::Bytes;
+use chrono::Utc;
+use uuid::Uuid;
+use vector_lib::{event::Finalizable, partition::Partitioner, request_metadata::RequestMetadata};
+
+use super::encoder::DatadogArchivesEncoder;
+use crate::{
+    event::Event,
+    sinks::{
+        s3_common::{
+            config::S3Options,
+            partitioner::S3PartitionKey,
+            service::{S3Metadata, S3Request},
+        },
+        util::{
+            metadata::RequestMetadataBuilder, request_builder::EncodeResult, Compression,
+            RequestBuilder,
+        },
+    },
+};
+
+/// Partitions events by the hour of their timestamp, or of the current time if they have none,
+/// into the key prefix of the archives holding them.
+pub struct DatadogArchivesPartitioner {
+    prefix: String,
+}
+
+impl DatadogArchivesPartitioner {
+    pub fn new(mut prefix: String) -> Self {
+        if !prefix.is_empty() && !prefix.ends_with('/') {
+            prefix.push('/');
+        }
+        Self { prefix }
+    }
+}
+
+impl Partitioner for DatadogArchivesPartitioner {
+    type Item = Event;
+    type Key = Option<S3PartitionKey>;
+
+    fn partition(&self, item: &Self::Item) -> Self::Key {
+        let timestamp = item
+            .as_log()
+            .get_timestamp()
+            .and_then(|timestamp| timestamp.as_timestamp().copied())
+            .unwrap_or_else(Utc::now);
+
+        Some(S3PartitionKey {
+            key_prefix: format!(
+                "{}{}",
+                self.prefix,
+                timestamp.format("year=%Y/month=%m/day=%d/hour=%H/")
+            ),
+            ssekms_key_id: None,
+        })
+    }
+}
+
+#[derive(Clone)]
+pub struct DatadogArchivesRequestBuilder {
+    pub bucket: String,
+    pub api_options: S3Options,
+    pub encoder: DatadogArchivesEncoder,
+    pub compression: Compression,
+}
+
+impl RequestBuilder<(S3PartitionKey, Vec<Event>)> for DatadogArchivesRequestBuilder {
+    type Metadata = S3Metadata;
+    type Events = Vec<Event>;
+    type Encoder = DatadogArchivesEncoder;
+    type Payload = Bytes;
+    type Request = S3Request;
+    type Error = io::Error;
+
+    fn compression(&self) -> Compression {
+        self.compression
+    }
+
+    fn encoder(&self) -> &Self::Encoder {
+        &self.encoder
+    }
+
+    fn split_input(
+        &self,
+        input: (S3PartitionKey, Vec<Event>),
+    ) -> (Self::Metadata, RequestMetadataBuilder, Self::Events) {
+        let (partition_key, mut events) = input;
+        let builder = RequestMetadataBuilder::from_events(&events);
+
+        let finalizers = events.take_finalizers();
+        let s3_key = partition_key.key_prefix.clone();
+
+        let metadata = S3Metadata {
+            partition_key,
+            s3_key,
+            finalizers,
+        };
+
+        (metadata, builder, events)
+    }
+
+    fn build_request(
+        &self,
+        mut s3metadata: Self::Metadata,
+        request_metadata: RequestMetadata,
+        payload: EncodeResult<Self::Payload>,
+    ) -> Self::Request {
+        s3metadata.s3_key = archive_key(&s3metadata.s3_key, Uuid::new_v4());
+
+        S3Request {
+            body: payload.into_payload(),
+            bucket: self.bucket.clone(),
+            metadata: s3metadata,
+            request_metadata,
+            content_encoding: self.compression.content_encoding(),
+            options: self.api_options.clone(),
+        }
+    }
+}
+
+fn archive_key(key_prefix: &str, id: Uuid) -> String {
+    format!("{}{}.json.gz", key_prefix, id.hyphenated())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+    use crate::event::LogEvent;
+
+    fn partition(prefix: &str, event: Event) -> String {
+        DatadogArchivesPartitioner::new(prefix.to_string())
+            .partition(&event)
+            .unwrap()
+            .key_prefix
+    }
+
+    #[test]
+    fn partitions_by_hour_of_timestamp() {
+        let timestamp = Utc.with_ymd_and_hms(2024, 1, 5, 7, 59, 59).unwrap();
+        let mut log = LogEvent::from("hello");
+        log.insert("timestamp", timestamp);
+        let event = Event::from(log);
+
+        assert_eq!(
+            partition("", event.clone()),
+            "year=2024/month=01/day=05/hour=07/"
+        );
+        assert_eq!(
+            partition("archives", event.clone()),
+            "archives/year=2024/month=01/day=05/hour=07/"
+        );
+        assert_eq!(
+            partition("archives/", event),
+            "archives/year=2024/month=01/day=05/hour=07/"
+        );
+    }
+
+    #[test]
+    fn formats_archive_key() {
+        let id = Uuid::parse_str("30f6652c-71da-4f9f-800d-a1189c47c547").unwrap();
+        assert_eq!(
+            archive_key("year=2024/month=01/day=05/hour=07/", id),
+            "year=2024/month=01/day=05/hour=07/30f6652c-71da-4f9f-800d-a1189c47c547.json.gz"
+        );
+    }
+}
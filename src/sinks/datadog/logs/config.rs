@@ -1,10 +1,11 @@
-use std::{convert::TryFrom, sync::Arc};
+use std::{convert::TryFrom, sync::Arc, time::Duration};
 
 use indoc::indoc;
 use tower::ServiceBuilder;
 
 use vector_lib::{
-    config::proxy::ProxyConfig, configurable::configurable_component, schema::meaning,
+    config::proxy::ProxyConfig, configurable::configurable_component,
+    lookup::lookup_v2::ConfigTargetPath, schema::meaning, sink::PauseSignal,
 };
 use vrl::value::Kind;
 
@@ -15,12 +16,22 @@ use crate::{
     sinks::{
         datadog::{logs::service::LogApiService, DatadogCommonConfig, LocalDatadogCommonConfig},
         prelude::*,
-        util::http::RequestConfig,
+        util::{
+            circuit_breaker::CircuitBreaker,
+            http::{http_response_retry_logic, HttpService, RequestConfig},
+            retry_budget::RetryBudget,
+        },
     },
     tls::{MaybeTlsSettings, TlsEnableableConfig},
 };
 
-use super::{service::LogApiRetry, sink::LogSinkBuilder};
+use super::{
+    dlq::{DlqConfig, DlqRequestBuilder},
+    failover::Failover,
+    payload_limit::PayloadLimit,
+    service::{intake_uri, LogApiRetry},
+    sink::LogSinkBuilder,
+};
 
 // The Datadog API has a hard limit of 5MB for uncompressed payloads. Above this
 // threshold the API will toss results. We previously serialized Events as they
@@ -30,6 +41,9 @@ use super::{service::LogApiRetry, sink::LogSinkBuilder};
 // of escaped double-quotes -- but we believe this should be very rare in
 // practice.
 pub const MAX_PAYLOAD_BYTES: usize = 5_000_000;
+// Compressed payloads are expected to be smaller than uncompressed ones, so this limit is only
+// reached when compression goes wrong.
+pub const MAX_COMPRESSED_PAYLOAD_BYTES: usize = 10_000_000;
 pub const BATCH_GOAL_BYTES: usize = 4_250_000;
 pub const BATCH_MAX_EVENTS: usize = 1_000;
 pub const BATCH_DEFAULT_TIMEOUT_SECS: f64 = 5.0;
@@ -43,9 +57,35 @@ impl SinkBatchSettings for DatadogLogsDefaultBatchSettings {
     const TIMEOUT_SECS: f64 = BATCH_DEFAULT_TIMEOUT_SECS;
 }
 
+/// What to do with the `ddtags` entries holding commas.
+///
+/// When `ddtags` is an array, its entries are joined with commas, so an entry holding commas would
+/// otherwise be split into several tags by the logs intake.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum DdtagsInvalidValueAction {
+    /// Drop the entry.
+    Drop,
+
+    /// Replace the commas of the entry with underscores.
+    #[default]
+    Replace,
+}
+
+impl DdtagsInvalidValueAction {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Drop => "drop",
+            Self::Replace => "replace",
+        }
+    }
+}
+
 /// Configuration for the `datadog_logs` sink.
 #[configurable_component(sink("datadog_logs", "Publish log events to Datadog."))]
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Derivative)]
+#[derivative(Default)]
 #[serde(deny_unknown_fields)]
 pub struct DatadogLogsConfig {
     #[serde(flatten)]
@@ -63,6 +103,17 @@ pub struct DatadogLogsConfig {
     #[serde(default)]
     pub batch: BatchConfig<DatadogLogsDefaultBatchSettings>,
 
+    /// How long to wait for another event before flushing a batch, in milliseconds.
+    ///
+    /// The wait restarts with every event added to the batch, so that bursts of events are sent
+    /// in fewer, larger requests. Batches are still flushed once they reach `batch.timeout_secs`,
+    /// which remains the longest time an event waits in a batch. By default, batches are only
+    /// flushed on `batch.timeout_secs`.
+    #[configurable(metadata(docs::advanced))]
+    #[configurable(metadata(docs::examples = 50))]
+    #[configurable(metadata(docs::type_unit = "milliseconds"))]
+    pub linger_ms: Option<u64>,
+
     #[configurable(derived)]
     #[serde(default)]
     pub request: RequestConfig,
@@ -73,6 +124,261 @@ pub struct DatadogLogsConfig {
     /// configuration setting.
     #[serde(default)]
     pub conforms_as_agent: bool,
+
+    /// Root fields left in place when events are normalized to the Datadog Agent standard.
+    ///
+    /// Normalizing an event nests every field that isn't a reserved attribute under `message`.
+    /// The fields listed here stay at the root of the event instead.
+    #[serde(default)]
+    #[configurable(metadata(docs::examples = "_internal_vector_id"))]
+    #[configurable(metadata(docs::advanced))]
+    pub agent_normalization_exclude_fields: Vec<String>,
+
+    /// When enabled, events are sent to the logs intake as newline-delimited JSON instead of a JSON
+    /// array.
+    ///
+    /// The intake may then accept only part of a request, responding with `207 Multi-Status` and
+    /// the list of rejected events. Only the events rejected with a retriable status are retried,
+    /// rather than the whole request.
+    #[serde(default)]
+    pub use_v2_api: bool,
+
+    /// The event field holding the Datadog API key to send each event with.
+    ///
+    /// This allows events from multiple tenants to be sent through the same sink. The field is
+    /// removed from the events before they are sent. Events without the field are sent with the
+    /// default API key, and events where the field is not a non-empty string of printable ASCII
+    /// characters are dropped.
+    ///
+    /// If omitted, the API key attached to the event by the `datadog_agent` source is used.
+    #[configurable(metadata(docs::advanced))]
+    #[configurable(metadata(docs::examples = "_dd_api_key"))]
+    pub api_key_field: Option<ConfigTargetPath>,
+
+    /// A VRL program computing the Datadog API key to send each event with.
+    ///
+    /// The value returned by the program is converted to a string. Events for which the program
+    /// returns `null` or an empty string, or fails, are sent with the default API key. The program
+    /// can't modify the events. Can't be used along with `api_key_field`.
+    #[configurable(metadata(docs::advanced))]
+    #[configurable(metadata(
+        docs::examples = "get_env_var!(\"DD_API_KEY_PREFIX\") + string!(.tenant.id)"
+    ))]
+    pub api_key_vrl: Option<String>,
+
+    /// The prefix added to the name of an attribute that is renamed to make room for a reserved
+    /// attribute.
+    ///
+    /// When an event has a semantically defined reserved attribute, such as the host, outside of
+    /// the location the logs intake expects it in, it is moved there. If another attribute is
+    /// already at that location, it is preserved by renaming it to this prefix followed by the
+    /// meaning of the reserved attribute. Defaults to `_RESERVED_`.
+    #[configurable(metadata(docs::advanced))]
+    #[configurable(metadata(docs::examples = "__orig__"))]
+    pub conflict_rename_prefix: Option<String>,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    #[configurable(metadata(docs::advanced))]
+    pub ddtags_invalid_value_action: DdtagsInvalidValueAction,
+
+    /// The number of consecutive failed requests after which the sink stops sending events to the
+    /// intake.
+    ///
+    /// Requests fail when the intake responds with a server error or times out, or when it can't be
+    /// reached. While the circuit breaker is open, incoming events are dropped and failed requests
+    /// are no longer retried, instead of piling up in memory. After
+    /// `circuit_breaker_recovery_secs`, a single event is sent to check whether the intake has
+    /// recovered.
+    ///
+    /// Set to `0`, the default, to disable the circuit breaker.
+    #[serde(default)]
+    #[configurable(metadata(docs::advanced))]
+    pub circuit_breaker_threshold: u32,
+
+    /// The time to wait after the circuit breaker opens before probing the intake again.
+    #[serde(default = "default_circuit_breaker_recovery_secs")]
+    #[configurable(metadata(docs::advanced))]
+    #[configurable(metadata(docs::type_unit = "seconds"))]
+    #[derivative(Default(value = "default_circuit_breaker_recovery_secs()"))]
+    pub circuit_breaker_recovery_secs: u64,
+
+    /// The endpoint to send requests to while the configured `site` or `endpoint` is failing, such
+    /// as the logs intake of another Datadog region.
+    ///
+    /// After `failover_threshold_errors` consecutive failed requests, requests are sent to this
+    /// endpoint instead. While failed over, a request is sent to the configured endpoint every
+    /// minute, and the sink switches back to it once one succeeds. Events sent to the site read
+    /// from `site_field` don't fail over.
+    ///
+    /// The endpoint has the same format as `endpoint`.
+    #[configurable(metadata(docs::advanced))]
+    #[configurable(metadata(docs::examples = "https://http-intake.logs.datadoghq.eu"))]
+    pub fallback_endpoint: Option<String>,
+
+    /// The number of consecutive failed requests after which requests are sent to the
+    /// `fallback_endpoint`.
+    ///
+    /// Requests fail when the endpoint responds with a server error or times out, or when it can't
+    /// be reached.
+    #[serde(default = "default_failover_threshold_errors")]
+    #[configurable(metadata(docs::advanced))]
+    #[derivative(Default(value = "default_failover_threshold_errors()"))]
+    pub failover_threshold_errors: u32,
+
+    /// The fraction of requests which may be retried, across all the requests of the sink.
+    ///
+    /// Every request sent for the first time adds this many retries to a budget shared by all
+    /// requests, and each retry spends one. Once the budget is spent, failed requests are no longer
+    /// retried, which keeps retries from piling up against a struggling intake. The budget is also
+    /// refilled with `retry_budget_min_per_second` retries every second.
+    ///
+    /// Set both options to `0`, the default, to disable the retry budget.
+    #[serde(default)]
+    #[configurable(metadata(docs::advanced))]
+    #[configurable(validation(range(min = 0.0)))]
+    pub retry_budget_token_ratio: f64,
+
+    /// The number of retries added to the retry budget every second, regardless of the number of
+    /// requests sent.
+    #[serde(default)]
+    #[configurable(metadata(docs::advanced))]
+    pub retry_budget_min_per_second: u32,
+
+    /// When enabled, each event is serialized on its own to measure its exact size before being
+    /// added to a request, rather than relying on its estimated size.
+    ///
+    /// This costs an extra copy of every event, but avoids oversized allocations when the estimated
+    /// size is far off, such as for events with a lot of multi-byte Unicode content.
+    #[serde(default)]
+    #[configurable(metadata(docs::advanced))]
+    pub exact_payload_size: bool,
+
+    /// When enabled, the difference between the serialized size of each event and its estimated
+    /// size is recorded in the `estimated_size_error_bytes` histogram.
+    ///
+    /// Overestimated events are recorded as negative values. This helps tell whether the
+    /// `exact_payload_size` option is worth its cost.
+    #[serde(default)]
+    #[configurable(metadata(docs::advanced))]
+    pub track_estimated_size_error: bool,
+
+    /// When enabled, each request is sent with a `DD-Request-ID` header holding the SHA-256 hash of
+    /// its uncompressed payload.
+    ///
+    /// Retries of a request carry the same ID, which lets the intake recognize them and avoid
+    /// ingesting the same events twice. Retries of partially accepted requests only hold the
+    /// rejected events, so they are sent without it.
+    #[serde(default)]
+    #[configurable(metadata(docs::advanced))]
+    pub send_idempotency_key: bool,
+
+    /// The maximum size of a compressed request body, in bytes.
+    ///
+    /// Requests are built from at most 5 MB of uncompressed events, so a larger compressed body
+    /// is a sign of a compression bug or corrupted data. Such requests are dropped along with their
+    /// events, rather than being rejected by the intake.
+    #[serde(default = "default_max_compressed_bytes")]
+    #[configurable(metadata(docs::advanced))]
+    #[configurable(metadata(docs::type_unit = "bytes"))]
+    #[derivative(Default(value = "default_max_compressed_bytes()"))]
+    pub max_compressed_bytes: usize,
+
+    /// The maximum estimated size of a single event, in bytes.
+    ///
+    /// Larger events are sent to the dead-letter queue if `dlq` is configured, or dropped
+    /// otherwise, before being normalized. Events too large to fit in a request are always
+    /// dropped, so this only makes the limit lower and explicit.
+    #[configurable(metadata(docs::advanced))]
+    #[configurable(metadata(docs::type_unit = "bytes"))]
+    #[configurable(metadata(docs::examples = 1_000_000))]
+    pub max_single_event_bytes: Option<usize>,
+
+    /// When enabled, requests carry the W3C trace context of the first event of their batch in the
+    /// `traceparent` and `tracestate` headers.
+    ///
+    /// The trace context is read from the `_vector_trace_id`, `_vector_span_id`, and
+    /// `_vector_trace_state` metadata fields of the event. Trace and span IDs that are absent or
+    /// invalid are generated at random.
+    #[serde(default)]
+    #[configurable(metadata(docs::advanced))]
+    pub propagate_trace_context: bool,
+
+    /// When enabled, fields holding `null` are removed from events before they are sent.
+    ///
+    /// The logs intake ignores null fields, so removing them only reduces the size of payloads.
+    /// Nested objects, including objects within arrays, are stripped as well, while null array
+    /// elements are kept.
+    #[serde(default)]
+    #[configurable(metadata(docs::advanced))]
+    pub strip_nulls: bool,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    #[configurable(metadata(docs::advanced))]
+    pub dlq: Option<DlqConfig>,
+
+    /// The event field holding the Datadog site to send each event to, such as `datadoghq.eu`.
+    ///
+    /// This allows events for Datadog organizations in different sites to be sent through the same
+    /// sink, along with `api_key_field`. Only the Datadog sites `datadoghq.com`, `datadoghq.eu`,
+    /// `us3.datadoghq.com`, `us5.datadoghq.com`, `ap1.datadoghq.com`, and `ddog-gov.com` are
+    /// accepted. Events without the field, or where it holds another value, are sent to the
+    /// configured `site` or `endpoint`.
+    #[configurable(metadata(docs::advanced))]
+    #[configurable(metadata(docs::examples = "_dd_site"))]
+    pub site_field: Option<ConfigTargetPath>,
+
+    /// The event field holding the priority of each event, such as `_priority`.
+    ///
+    /// When set, the events of each batch are sent in descending order of priority, so that
+    /// important events, such as audit logs, are sent first when a batch is split into several
+    /// requests. Events without the field, or where it doesn't hold an integer, have a priority of
+    /// `0`. Events of the same priority are sent in the order they were received.
+    #[configurable(metadata(docs::advanced))]
+    #[configurable(metadata(docs::examples = "_priority"))]
+    pub priority_field: Option<ConfigTargetPath>,
+
+    /// When enabled, the events sent and the failed requests of each API key are counted in the
+    /// `datadog_logs_api_key_sent_events_total` and `datadog_logs_api_key_errors_total` metrics.
+    ///
+    /// The metrics are tagged with the first six characters of the API key, in the
+    /// `api_key_prefix` tag, to tell apart the tenants of a sink sending events with several API
+    /// keys. This adds a metric series per API key, which may increase the cardinality of metrics
+    /// significantly.
+    #[serde(default)]
+    #[configurable(metadata(docs::advanced))]
+    pub api_key_metrics: bool,
+
+    /// When enabled, requests are sent over HTTP/2, multiplexing concurrent requests over a single
+    /// connection to the intake.
+    ///
+    /// Over TLS, HTTP/2 is negotiated with ALPN unless `tls.alpn_protocols` is set. Over plain
+    /// HTTP, the endpoint must accept HTTP/2 without prior negotiation.
+    #[serde(default)]
+    #[configurable(metadata(docs::advanced))]
+    pub http2: bool,
+
+    /// When enabled, the batches in progress are sent as soon as Vector receives `SIGUSR1`, without
+    /// waiting for them to fill up or time out.
+    ///
+    /// This allows events to be flushed ahead of a rolling restart, for instance, while the sink
+    /// keeps running. Only supported on Unix.
+    #[serde(default)]
+    #[configurable(metadata(docs::advanced))]
+    pub flush_on_signal: bool,
+}
+
+const fn default_circuit_breaker_recovery_secs() -> u64 {
+    30
+}
+
+const fn default_failover_threshold_errors() -> u32 {
+    5
+}
+
+const fn default_max_compressed_bytes() -> usize {
+    MAX_COMPRESSED_PAYLOAD_BYTES
 }
 
 impl GenerateConfig for DatadogLogsConfig {
@@ -93,7 +399,7 @@ impl DatadogLogsConfig {
             .clone()
             .unwrap_or_else(|| format!("https://http-intake.logs.{}", dd_common.site));
 
-        http::Uri::try_from(format!("{}/api/v2/logs", base_url)).expect("URI not valid")
+        intake_uri(&base_url).expect("URI not valid")
     }
 
     pub fn get_protocol(&self, dd_common: &DatadogCommonConfig) -> String {
@@ -108,10 +414,33 @@ impl DatadogLogsConfig {
         dd_common: &DatadogCommonConfig,
         client: HttpClient,
         dd_evp_origin: String,
+        pause: PauseSignal,
     ) -> crate::Result<VectorSink> {
         let default_api_key: Arc<str> = Arc::from(dd_common.default_api_key.inner());
         let request_limits = self.request.tower.into_settings();
 
+        if let Some(compression @ (Compression::Lz4 | Compression::Brotli(_))) = self.compression {
+            if dd_common.endpoint.is_none() {
+                warn!(
+                    message = "The Datadog logs intake may not accept this compression algorithm, which is intended for custom endpoints supporting it.",
+                    %compression,
+                    site = %dd_common.site,
+                );
+            }
+        }
+
+        if self.api_key_field.is_some() && self.api_key_vrl.is_some() {
+            return Err("`api_key_field` and `api_key_vrl` can't be used together".into());
+        }
+
+        if cfg!(not(unix)) && self.flush_on_signal {
+            return Err("`flush_on_signal` is only supported on Unix".into());
+        }
+
+        if self.linger_ms == Some(0) {
+            return Err("`linger_ms` must be greater than zero".into());
+        }
+
         // We forcefully cap the provided batch configuration to the size/log line limits imposed by
         // the Datadog Logs API, but we still allow them to be lowered if need be.
         let batch = self
@@ -119,7 +448,8 @@ impl DatadogLogsConfig {
             .validate()?
             .limit_max_bytes(BATCH_GOAL_BYTES)?
             .limit_max_events(BATCH_MAX_EVENTS)?
-            .into_batcher_settings()?;
+            .into_batcher_settings()?
+            .with_linger_ms(self.linger_ms);
 
         let headers = {
             let mut request_headers = self.request.headers.clone();
@@ -137,19 +467,82 @@ impl DatadogLogsConfig {
             false
         };
 
+        // The payload limit adapts to `413 Payload Too Large` responses from the intake, so it is
+        // shared between the service observing the responses and the sink building the requests.
+        let payload_limit = Arc::new(PayloadLimit::new(MAX_PAYLOAD_BYTES));
+
+        let circuit_breaker = (self.circuit_breaker_threshold > 0).then(|| {
+            Arc::new(CircuitBreaker::new(
+                self.circuit_breaker_threshold,
+                Duration::from_secs(self.circuit_breaker_recovery_secs),
+            ))
+        });
+
+        if self.fallback_endpoint.is_some() && self.failover_threshold_errors == 0 {
+            return Err("`failover_threshold_errors` must be greater than zero".into());
+        }
+        // The failover is shared by the service, picking the endpoint of every request and
+        // recording its outcome, including the retries of requests sent before failing over.
+        let failover = self
+            .fallback_endpoint
+            .as_ref()
+            .map(|endpoint| {
+                intake_uri(endpoint)
+                    .map(|uri| Arc::new(Failover::new(uri, self.failover_threshold_errors)))
+                    .map_err(|error| format!("invalid `fallback_endpoint`: {error}"))
+            })
+            .transpose()?;
+
+        // Events routed to the dead-letter queue are sent with the same client and settings.
+        let dlq_service = self.dlq.as_ref().map(|dlq| {
+            ServiceBuilder::new()
+                .settings(request_limits.clone(), http_response_retry_logic())
+                .service(HttpService::new(
+                    client.clone(),
+                    DlqRequestBuilder::new(dlq.uri.uri.clone()),
+                ))
+        });
+
+        if !self.retry_budget_token_ratio.is_finite() || self.retry_budget_token_ratio < 0.0 {
+            return Err(format!(
+                "`retry_budget_token_ratio` must be a non-negative number, got {}",
+                self.retry_budget_token_ratio
+            )
+            .into());
+        }
+        // The retry budget is shared by the retry logic, spending it, and the sink, funding it with
+        // every request built.
+        let retry_budget = (self.retry_budget_token_ratio > 0.0
+            || self.retry_budget_min_per_second > 0)
+            .then(|| {
+                Arc::new(RetryBudget::new(
+                    self.retry_budget_token_ratio,
+                    self.retry_budget_min_per_second,
+                ))
+            });
+
         let service = ServiceBuilder::new()
-            .settings(request_limits, LogApiRetry)
-            .service(LogApiService::new(
-                client,
-                self.get_uri(dd_common),
-                headers,
-                dd_evp_origin,
-            )?);
+            .settings(
+                request_limits,
+                LogApiRetry::new(circuit_breaker.clone()).with_retry_budget(retry_budget.clone()),
+            )
+            .service(
+                LogApiService::new(
+                    client,
+                    self.get_uri(dd_common),
+                    headers,
+                    dd_evp_origin,
+                    Arc::clone(&payload_limit),
+                )?
+                .with_circuit_breaker(circuit_breaker.clone())
+                .with_failover(failover)
+                .with_retry_budget(retry_budget.clone()),
+            );
 
         let encoding = self.encoding.clone();
         let protocol = self.get_protocol(dd_common);
 
-        let sink = LogSinkBuilder::new(
+        let mut sink = LogSinkBuilder::new(
             encoding,
             service,
             default_api_key,
@@ -157,26 +550,60 @@ impl DatadogLogsConfig {
             protocol,
             conforms_as_agent,
         )
+        .agent_normalization_exclude_fields(self.agent_normalization_exclude_fields.clone())
         .compression(self.compression.unwrap_or_default())
-        .build();
+        .payload_limit(payload_limit)
+        .use_v2_api(self.use_v2_api)
+        .api_key_field(self.api_key_field.clone().map(|field| field.0))
+        .api_key_vrl(self.api_key_vrl.clone())
+        .site_field(self.site_field.clone().map(|field| field.0))
+        .priority_field(self.priority_field.clone().map(|field| field.0))
+        .exact_payload_size(self.exact_payload_size)
+        .track_estimated_size_error(self.track_estimated_size_error)
+        .send_idempotency_key(self.send_idempotency_key)
+        .propagate_trace_context(self.propagate_trace_context)
+        .strip_nulls(self.strip_nulls)
+        .api_key_metrics(self.api_key_metrics)
+        .max_compressed_bytes(self.max_compressed_bytes)
+        .max_single_event_bytes(self.max_single_event_bytes)
+        .ddtags_invalid_value_action(self.ddtags_invalid_value_action)
+        .flush_on_signal(self.flush_on_signal)
+        .pause(pause);
+        if let Some(prefix) = &self.conflict_rename_prefix {
+            sink = sink.conflict_rename_prefix(prefix.clone());
+        }
+        if let Some(circuit_breaker) = circuit_breaker {
+            sink = sink.circuit_breaker(circuit_breaker);
+        }
+        if let Some(retry_budget) = retry_budget {
+            sink = sink.retry_budget(retry_budget);
+        }
+        if let Some(dlq_service) = dlq_service {
+            sink = sink.dlq_service(dlq_service);
+        }
+        let sink = sink.build()?;
 
         Ok(VectorSink::from_event_streamsink(sink))
     }
 
     pub fn create_client(&self, proxy: &ProxyConfig) -> crate::Result<HttpClient> {
-        let default_tls_config;
-
-        let tls_settings = MaybeTlsSettings::from_config(
-            Some(match self.local_dd_common.tls.as_ref() {
-                Some(config) => config,
-                None => {
-                    default_tls_config = TlsEnableableConfig::enabled();
-                    &default_tls_config
-                }
-            }),
-            false,
-        )?;
-        Ok(HttpClient::new(tls_settings, proxy)?)
+        let mut tls_config = self
+            .local_dd_common
+            .tls
+            .clone()
+            .unwrap_or_else(TlsEnableableConfig::enabled);
+        if self.http2 && tls_config.options.alpn_protocols.is_none() {
+            tls_config.options.alpn_protocols = Some(vec!["h2".to_string()]);
+        }
+
+        let tls_settings = MaybeTlsSettings::from_config(Some(&tls_config), false)?;
+        let mut client_builder = hyper::Client::builder();
+        client_builder.http2_only(self.http2);
+        Ok(HttpClient::new_with_custom_client(
+            tls_settings,
+            proxy,
+            &mut client_builder,
+        )?)
     }
 }
 
@@ -190,7 +617,7 @@ impl SinkConfig for DatadogLogsConfig {
 
         let healthcheck = dd_common.build_healthcheck(client.clone())?;
 
-        let sink = self.build_processor(&dd_common, client, cx.app_name_slug)?;
+        let sink = self.build_processor(&dd_common, client, cx.app_name_slug, cx.pause)?;
 
         Ok((sink, healthcheck))
     }
@@ -211,6 +638,10 @@ impl SinkConfig for DatadogLogsConfig {
     fn acknowledgements(&self) -> &AcknowledgementsConfig {
         &self.local_dd_common.acknowledgements
     }
+
+    fn supports_pause(&self) -> bool {
+        true
+    }
 }
 
 #[cfg(test)]
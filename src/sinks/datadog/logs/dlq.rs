@@ -0,0 +1,312 @@
+//! The dead-letter queue of the `datadog_logs` sink.
+//!
+//! Events the sink can't send to the logs intake, because they are too large to fit in a payload
+//! or fail to serialize, are normally dropped. When a dead-letter queue is configured, they are
+//! instead sent as newline-delimited JSON to a fallback HTTP endpoint, along with the reason they
+//! were routed there. The dead-letter queue holds a bounded number of events, and events routed to
+//! it while it's full are dropped.
+
+use std::{fmt::Debug, future::Future};
+
+use bytes::Bytes;
+use http::{header::CONTENT_TYPE, Request, Uri};
+use snafu::ResultExt;
+use tokio::sync::mpsc::{self, error::TrySendError};
+use tokio_stream::wrappers::ReceiverStream;
+use tower::{util::BoxService, ServiceExt};
+use vector_lib::{
+    internal_event::{ComponentEventsDropped, UNINTENTIONAL},
+    lookup::{event_path, metadata_path},
+};
+
+use crate::{
+    internal_events::{
+        DatadogLogsDeadLettersError, DatadogLogsDeadLettersFull, DatadogLogsDeadLettersSent,
+    },
+    sinks::{
+        prelude::*,
+        util::{
+            http::{HttpJsonBatchSizer, HttpRequest, HttpResponse, HttpServiceRequestBuilder},
+            UriSerde,
+        },
+        HTTPRequestBuilderSnafu,
+    },
+};
+
+/// The field holding the reason an event was routed to the dead-letter queue.
+///
+/// It is set in the metadata of the event, and added to the event itself when it is sent.
+pub const DLQ_REASON_FIELD: &str = "_vector_dlq_reason";
+
+/// The number of events waiting to be sent to the dead-letter queue, above which further events are
+/// dropped.
+const DLQ_BUFFER_SIZE: usize = 1000;
+
+/// Configuration of the dead-letter queue of the `datadog_logs` sink.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct DlqConfig {
+    /// The URI of the HTTP endpoint receiving the events that can't be sent to Datadog.
+    ///
+    /// Events are sent as newline-delimited JSON, each with a `_vector_dlq_reason` field holding
+    /// the reason it was routed to the dead-letter queue.
+    #[configurable(metadata(docs::examples = "https://dead-letters.example.com/logs"))]
+    pub uri: UriSerde,
+}
+
+#[derive(Debug, Clone)]
+pub struct DlqRequestBuilder {
+    uri: Uri,
+}
+
+impl DlqRequestBuilder {
+    pub const fn new(uri: Uri) -> Self {
+        Self { uri }
+    }
+}
+
+impl HttpServiceRequestBuilder<()> for DlqRequestBuilder {
+    fn build(&self, mut request: HttpRequest<()>) -> Result<Request<Bytes>, crate::Error> {
+        Request::post(&self.uri)
+            .header(CONTENT_TYPE, "application/x-ndjson")
+            .body(request.take_payload())
+            .context(HTTPRequestBuilderSnafu)
+            .map_err(Into::into)
+    }
+}
+
+/// Hands events over to the dead-letter queue, if one is configured.
+#[derive(Clone, Debug, Default)]
+pub struct DeadLetters(Option<mpsc::Sender<Event>>);
+
+impl DeadLetters {
+    pub const fn is_enabled(&self) -> bool {
+        self.0.is_some()
+    }
+
+    /// Routes the event to the dead-letter queue, tagging it with `reason`. The event is dropped
+    /// if the dead-letter queue is full.
+    ///
+    /// The event is handed back if there is no dead-letter queue to send it to.
+    pub fn send(&self, mut event: Event, reason: &'static str) -> Option<Event> {
+        let Some(sender) = &self.0 else {
+            return Some(event);
+        };
+        event
+            .as_mut_log()
+            .insert(metadata_path!(DLQ_REASON_FIELD), reason);
+        match sender.try_send(event) {
+            Ok(()) => None,
+            Err(TrySendError::Full(_)) => {
+                emit!(DatadogLogsDeadLettersFull);
+                None
+            }
+            Err(TrySendError::Closed(event)) => Some(event),
+        }
+    }
+}
+
+/// Sends the events routed to the dead-letter queue.
+pub struct DlqSink {
+    service: BoxService<HttpRequest<()>, HttpResponse, crate::Error>,
+}
+
+impl Debug for DlqSink {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DlqSink").finish_non_exhaustive()
+    }
+}
+
+impl DlqSink {
+    pub fn new<S>(service: S) -> Self
+    where
+        S: Service<HttpRequest<()>, Response = HttpResponse> + Send + 'static,
+        S::Future: Send + 'static,
+        S::Error: Into<crate::Error>,
+    {
+        Self {
+            service: BoxService::new(service.map_err(Into::into)),
+        }
+    }
+
+    /// Starts the dead-letter queue, returning the handle to send events to it and the future
+    /// sending them. The future completes once every handle has been dropped and the remaining
+    /// events have been sent.
+    ///
+    /// Requests are sent one at a time, and reported with their own telemetry rather than being
+    /// counted as events sent by the sink.
+    pub fn start(
+        self,
+        batch_settings: BatcherSettings,
+    ) -> (DeadLetters, impl Future<Output = Result<(), ()>>) {
+        let (sender, receiver) = mpsc::channel(DLQ_BUFFER_SIZE);
+        let mut requests = Box::pin(
+            ReceiverStream::new(receiver)
+                .batched(batch_settings.as_item_size_config(HttpJsonBatchSizer))
+                .filter_map(|events| future::ready(encode(events))),
+        );
+        let mut service = self.service;
+        let run = async move {
+            while let Some(request) = requests.next().await {
+                send(&mut service, request).await;
+            }
+            Ok(())
+        };
+
+        (DeadLetters(Some(sender)), run)
+    }
+}
+
+async fn send(
+    service: &mut BoxService<HttpRequest<()>, HttpResponse, crate::Error>,
+    mut request: HttpRequest<()>,
+) {
+    let count = request.get_metadata().event_count();
+    let byte_size = request.get_metadata().events_byte_size();
+    let finalizers = request.take_finalizers();
+    let result = match service.ready().await {
+        Ok(service) => service.call(request).await,
+        Err(error) => Err(error),
+    };
+    match result {
+        Ok(response) if response.event_status() == EventStatus::Delivered => {
+            finalizers.update_status(EventStatus::Delivered);
+            emit!(DatadogLogsDeadLettersSent { count, byte_size });
+        }
+        Ok(response) => {
+            finalizers.update_status(EventStatus::Rejected);
+            emit!(DatadogLogsDeadLettersError {
+                error: format!(
+                    "Unexpected response status {}.",
+                    response.http_response.status()
+                ),
+                count,
+            });
+        }
+        Err(error) => {
+            finalizers.update_status(EventStatus::Rejected);
+            emit!(DatadogLogsDeadLettersError { error, count });
+        }
+    }
+}
+
+/// Encodes events as newline-delimited JSON, moving the reason they were routed to the dead-letter
+/// queue from their metadata into the events themselves.
+fn encode(mut events: Vec<Event>) -> Option<HttpRequest<()>> {
+    let mut body = Vec::new();
+    let mut byte_size = telemetry().create_request_count_byte_size();
+    let mut failed = 0;
+    events.retain(|event| {
+        let mut log = event.as_log().clone();
+        if let Some(reason) = log.remove(metadata_path!(DLQ_REASON_FIELD)) {
+            log.insert(event_path!(DLQ_REASON_FIELD), reason);
+        }
+        let existing_len = body.len();
+        match serde_json::to_writer(&mut body, &log) {
+            Ok(()) => {
+                body.push(b'\n');
+                byte_size.add_event(event, event.estimated_json_encoded_size_of());
+                true
+            }
+            Err(error) => {
+                body.truncate(existing_len);
+                error!(message = "Failed to serialize event for the dead-letter queue.", %error);
+                failed += 1;
+                false
+            }
+        }
+    });
+    if failed > 0 {
+        emit!(ComponentEventsDropped::<UNINTENTIONAL> {
+            count: failed,
+            reason: "Failed to serialize event for the dead-letter queue.",
+        });
+    }
+    if events.is_empty() {
+        return None;
+    }
+
+    let finalizers = events.take_finalizers();
+    let metadata_builder = RequestMetadataBuilder::from_events(&events);
+    let payload = EncodeResult::uncompressed(Bytes::from(body), byte_size);
+    let metadata = metadata_builder.build(&payload);
+
+    Some(HttpRequest::new(
+        payload.into_payload(),
+        finalizers,
+        metadata,
+        (),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use vector_lib::event::LogEvent;
+
+    use super::*;
+
+    #[test]
+    fn send_without_dlq_hands_event_back() {
+        let event = Event::from(LogEvent::from("too large"));
+        let event = DeadLetters::default()
+            .send(event, "event_too_large")
+            .expect("event should be handed back");
+        assert!(event
+            .as_log()
+            .get(metadata_path!(DLQ_REASON_FIELD))
+            .is_none());
+    }
+
+    #[test]
+    fn encodes_reason_in_events() {
+        let (sender, mut receiver) = mpsc::channel(2);
+        let dead_letters = DeadLetters(Some(sender));
+        assert!(dead_letters
+            .send(Event::from(LogEvent::from("first")), "event_too_large")
+            .is_none());
+        assert!(dead_letters
+            .send(
+                Event::from(LogEvent::from("second")),
+                "serialization_failed"
+            )
+            .is_none());
+
+        let events = vec![receiver.try_recv().unwrap(), receiver.try_recv().unwrap()];
+        let mut request = encode(events).expect("request should be built");
+        let body = request.take_payload();
+        let lines = body
+            .split(|byte| *byte == b'\n')
+            .filter(|line| !line.is_empty())
+            .map(|line| serde_json::from_slice::<serde_json::Value>(line).unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            lines,
+            vec![
+                serde_json::json!({ "message": "first", "_vector_dlq_reason": "event_too_large" }),
+                serde_json::json!({
+                    "message": "second",
+                    "_vector_dlq_reason": "serialization_failed",
+                }),
+            ]
+        );
+    }
+    #[test]
+    fn drops_events_while_full() {
+        let (sender, mut receiver) = mpsc::channel(1);
+        let dead_letters = DeadLetters(Some(sender));
+        assert!(dead_letters
+            .send(Event::from(LogEvent::from("first")), "event_too_large")
+            .is_none());
+        assert!(dead_letters
+            .send(Event::from(LogEvent::from("second")), "event_too_large")
+            .is_none());
+
+        assert_eq!(
+            receiver.try_recv().unwrap().as_log()["message"],
+            "first".into()
+        );
+        assert!(receiver.try_recv().is_err());
+    }
+}
@@ -0,0 +1,155 @@
+//! Failover of the `datadog_logs` sink to a fallback endpoint.
+//!
+//! After a number of consecutive failed requests to the configured endpoint, requests are sent to
+//! the fallback endpoint instead. While failed over, a request is sent to the configured endpoint
+//! every [`PROBE_INTERVAL`] to check whether it has recovered, switching back once one succeeds.
+
+use std::{sync::Mutex, time::Duration};
+
+use http::Uri;
+use tokio::time::Instant;
+
+use crate::internal_events::DatadogLogsEndpointFailover;
+
+/// The time between two requests sent to the primary endpoint while failed over.
+pub const PROBE_INTERVAL: Duration = Duration::from_secs(60);
+
+/// The endpoint a request is sent to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Endpoint {
+    /// The configured endpoint, or the intake of the configured site.
+    Primary,
+    /// The fallback endpoint.
+    Fallback,
+}
+
+#[derive(Debug)]
+struct Inner {
+    consecutive_failures: u32,
+    failed_over: bool,
+    /// When the last probe was sent to the primary endpoint, or when the sink failed over.
+    probed_at: Instant,
+}
+
+/// Switches requests over to a fallback endpoint while the primary one is failing.
+#[derive(Debug)]
+pub struct Failover {
+    fallback: Uri,
+    threshold: u32,
+    probe_interval: Duration,
+    inner: Mutex<Inner>,
+}
+
+impl Failover {
+    pub fn new(fallback: Uri, threshold: u32) -> Self {
+        Self {
+            fallback,
+            threshold,
+            probe_interval: PROBE_INTERVAL,
+            inner: Mutex::new(Inner {
+                consecutive_failures: 0,
+                failed_over: false,
+                probed_at: Instant::now(),
+            }),
+        }
+    }
+
+    pub const fn fallback(&self) -> &Uri {
+        &self.fallback
+    }
+
+    /// Returns the endpoint to send the next request to, letting a probe through to the primary
+    /// endpoint once the probe interval has elapsed.
+    pub fn select(&self) -> Endpoint {
+        let mut inner = self.inner.lock().expect("lock poisoned");
+        if !inner.failed_over {
+            Endpoint::Primary
+        } else if inner.probed_at.elapsed() >= self.probe_interval {
+            inner.probed_at = Instant::now();
+            Endpoint::Primary
+        } else {
+            Endpoint::Fallback
+        }
+    }
+
+    /// Records the outcome of a request sent to `endpoint`. Only requests failing because of the
+    /// endpoint itself count as failures.
+    pub fn record(&self, endpoint: Endpoint, success: bool) {
+        if endpoint == Endpoint::Fallback {
+            return;
+        }
+
+        let mut inner = self.inner.lock().expect("lock poisoned");
+        if success {
+            inner.consecutive_failures = 0;
+            if inner.failed_over {
+                inner.failed_over = false;
+                emit!(DatadogLogsEndpointFailover {
+                    failed_over: false,
+                    consecutive_failures: 0,
+                });
+            }
+            return;
+        }
+
+        inner.consecutive_failures = inner.consecutive_failures.saturating_add(1);
+        if !inner.failed_over && inner.consecutive_failures >= self.threshold {
+            inner.failed_over = true;
+            inner.probed_at = Instant::now();
+            emit!(DatadogLogsEndpointFailover {
+                failed_over: true,
+                consecutive_failures: inner.consecutive_failures,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn failover(threshold: u32) -> Failover {
+        Failover::new(Uri::from_static("https://fallback.example.com"), threshold)
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn fails_over_after_threshold() {
+        let failover = failover(3);
+
+        failover.record(Endpoint::Primary, false);
+        failover.record(Endpoint::Primary, false);
+        // A success resets the count of consecutive failures.
+        failover.record(Endpoint::Primary, true);
+        failover.record(Endpoint::Primary, false);
+        failover.record(Endpoint::Primary, false);
+        assert_eq!(failover.select(), Endpoint::Primary);
+
+        failover.record(Endpoint::Primary, false);
+        assert_eq!(failover.select(), Endpoint::Fallback);
+        // Failures of the fallback endpoint don't switch back.
+        failover.record(Endpoint::Fallback, false);
+        assert_eq!(failover.select(), Endpoint::Fallback);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn probes_primary_endpoint() {
+        let failover = failover(1);
+        failover.record(Endpoint::Primary, false);
+
+        tokio::time::advance(PROBE_INTERVAL / 2).await;
+        assert_eq!(failover.select(), Endpoint::Fallback);
+
+        // A single probe is sent once the interval elapses, and failing it stays failed over.
+        tokio::time::advance(PROBE_INTERVAL / 2).await;
+        assert_eq!(failover.select(), Endpoint::Primary);
+        assert_eq!(failover.select(), Endpoint::Fallback);
+        failover.record(Endpoint::Primary, false);
+        assert_eq!(failover.select(), Endpoint::Fallback);
+
+        tokio::time::advance(PROBE_INTERVAL).await;
+        assert_eq!(failover.select(), Endpoint::Primary);
+        failover.record(Endpoint::Primary, true);
+        assert_eq!(failover.select(), Endpoint::Primary);
+        assert_eq!(failover.select(), Endpoint::Primary);
+    }
+}
@@ -25,7 +25,11 @@ mod integration_tests;
 mod tests;
 
 pub mod config;
+pub mod dlq;
+pub mod failover;
+pub mod payload_limit;
 pub mod service;
 pub mod sink;
+pub mod vrl_partitioner;
 
 pub use self::config::DatadogLogsConfig;
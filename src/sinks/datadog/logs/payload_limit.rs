@@ -0,0 +1,137 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::{internal_events::DatadogLogsDynamicPayloadLimit, sinks::datadog::DatadogApiError};
+
+use super::config::MAX_PAYLOAD_BYTES;
+
+/// The lowest value the payload limit can be shrunk to.
+///
+/// Without a floor, a sustained run of `413` responses would eventually shrink the limit to the
+/// point where no event fits in a request.
+pub const MIN_PAYLOAD_BYTES: usize = 1_000;
+
+/// Tracks the maximum uncompressed payload size used when building requests.
+///
+/// The limit starts at the configured maximum and is halved every time the Datadog logs intake
+/// rejects a request with `413 Payload Too Large`. Every successful request grows it back by 10%
+/// until the maximum is reached again.
+///
+/// The limit is shared between the request builder, which reads it, and the service, which updates
+/// it based on the responses it receives.
+#[derive(Debug)]
+pub struct PayloadLimit {
+    current: AtomicUsize,
+    max: usize,
+}
+
+impl Default for PayloadLimit {
+    fn default() -> Self {
+        Self::new(MAX_PAYLOAD_BYTES)
+    }
+}
+
+impl PayloadLimit {
+    pub const fn new(max: usize) -> Self {
+        Self {
+            current: AtomicUsize::new(max),
+            max,
+        }
+    }
+
+    /// Returns the current payload limit in bytes.
+    pub fn get(&self) -> usize {
+        self.current.load(Ordering::Relaxed)
+    }
+
+    /// Updates the limit based on the outcome of a request.
+    ///
+    /// Errors other than `413 Payload Too Large` leave the limit untouched, since they say nothing
+    /// about the size of the payload.
+    pub fn record_result<T>(&self, result: &Result<T, DatadogApiError>) {
+        match result {
+            Ok(_) => self.grow(),
+            Err(DatadogApiError::PayloadTooLarge) => self.shrink(),
+            Err(_) => {}
+        }
+    }
+
+    fn shrink(&self) {
+        self.update(|current| (current / 2).max(MIN_PAYLOAD_BYTES));
+    }
+
+    fn grow(&self) {
+        let max = self.max;
+        self.update(|current| current.saturating_add((current / 10).max(1)).min(max));
+    }
+
+    fn update(&self, f: impl Fn(usize) -> usize) {
+        let previous = self
+            .current
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |current| {
+                let next = f(current);
+                (next != current).then_some(next)
+            });
+
+        if let Ok(previous) = previous {
+            emit!(DatadogLogsDynamicPayloadLimit {
+                limit_bytes: f(previous),
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OK: Result<(), DatadogApiError> = Ok(());
+    const TOO_LARGE: Result<(), DatadogApiError> = Err(DatadogApiError::PayloadTooLarge);
+
+    #[test]
+    fn starts_at_max() {
+        assert_eq!(PayloadLimit::default().get(), MAX_PAYLOAD_BYTES);
+    }
+
+    #[test]
+    fn shrinks_on_payload_too_large() {
+        let limit = PayloadLimit::new(8_000);
+
+        limit.record_result(&TOO_LARGE);
+        assert_eq!(limit.get(), 4_000);
+
+        limit.record_result(&TOO_LARGE);
+        assert_eq!(limit.get(), 2_000);
+
+        // Never goes below the floor.
+        for _ in 0..10 {
+            limit.record_result(&TOO_LARGE);
+        }
+        assert_eq!(limit.get(), MIN_PAYLOAD_BYTES);
+    }
+
+    #[test]
+    fn recovers_on_success() {
+        let limit = PayloadLimit::new(MAX_PAYLOAD_BYTES);
+
+        limit.record_result(&TOO_LARGE);
+        assert_eq!(limit.get(), MAX_PAYLOAD_BYTES / 2);
+
+        limit.record_result(&OK);
+        assert_eq!(limit.get(), MAX_PAYLOAD_BYTES / 2 + MAX_PAYLOAD_BYTES / 20);
+
+        // Never exceeds the maximum.
+        for _ in 0..100 {
+            limit.record_result(&OK);
+        }
+        assert_eq!(limit.get(), MAX_PAYLOAD_BYTES);
+    }
+
+    #[test]
+    fn ignores_unrelated_errors() {
+        let limit = PayloadLimit::new(8_000);
+
+        limit.record_result::<()>(&Err(DatadogApiError::ServerError));
+        limit.record_result::<()>(&Err(DatadogApiError::BadRequest));
+        assert_eq!(limit.get(), 8_000);
+    }
+}
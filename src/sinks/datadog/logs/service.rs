@@ -1,45 +1,355 @@
 use std::{
-    sync::Arc,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
     task::{Context, Poll},
 };
 
 use bytes::Bytes;
-use futures::future::BoxFuture;
+use futures::future::{self, BoxFuture};
 use headers::HeaderName;
 use http::{
     header::{CONTENT_ENCODING, CONTENT_LENGTH, CONTENT_TYPE},
-    HeaderValue, Request, Uri,
+    uri::InvalidUri,
+    HeaderMap, HeaderValue, Request, StatusCode, Uri,
 };
 use hyper::Body;
 use indexmap::IndexMap;
+use serde::Deserialize;
+use snafu::Snafu;
 use tower::Service;
 use tracing::Instrument;
 use vector_lib::event::{EventFinalizers, EventStatus, Finalizable};
+use vector_lib::internal_event::{ComponentEventsDropped, UNINTENTIONAL};
 use vector_lib::request_metadata::{GroupedCountByteSize, MetaDescriptive, RequestMetadata};
 use vector_lib::stream::DriverResponse;
 
+use super::{
+    failover::{Endpoint, Failover},
+    payload_limit::PayloadLimit,
+    sink::RequestBuildError,
+};
 use crate::{
-    http::HttpClient,
-    sinks::util::{retries::RetryLogic, Compression},
+    http::{HttpClient, HttpError},
+    internal_events::{
+        DatadogLogsApiKeyEventsSent, DatadogLogsApiKeyRequestError,
+        DatadogLogsPartialAcceptanceError, DatadogLogsRetryBudgetExceeded, SinkRequestBuildError,
+    },
+    sinks::util::{
+        circuit_breaker::{CircuitBreaker, CircuitState},
+        encoding::write_all,
+        retries::{RetryAction, RetryLogic},
+        retry_budget::RetryBudget,
+        Compression, Compressor,
+    },
     sinks::{datadog::DatadogApiError, util::http::validate_headers},
 };
 
 #[derive(Debug, Default, Clone)]
-pub struct LogApiRetry;
+pub struct LogApiRetry {
+    circuit_breaker: Option<Arc<CircuitBreaker>>,
+    retry_budget: Option<Arc<RetryBudget>>,
+}
+
+impl LogApiRetry {
+    pub const fn new(circuit_breaker: Option<Arc<CircuitBreaker>>) -> Self {
+        Self {
+            circuit_breaker,
+            retry_budget: None,
+        }
+    }
+
+    /// Withdraws from the given budget before retrying the events rejected by a partial acceptance
+    /// response, giving up on them once it's spent. Requests failing with an error spend the
+    /// budget in `LogApiService`.
+    pub fn with_retry_budget(mut self, retry_budget: Option<Arc<RetryBudget>>) -> Self {
+        self.retry_budget = retry_budget;
+        self
+    }
+
+    fn withdraw_retry(&self) -> bool {
+        let allowed = self
+            .retry_budget
+            .as_ref()
+            .is_none_or(|retry_budget| retry_budget.withdraw());
+        if !allowed {
+            emit!(DatadogLogsRetryBudgetExceeded);
+        }
+        allowed
+    }
+}
 
 impl RetryLogic for LogApiRetry {
     type Error = DatadogApiError;
     type Response = LogApiResponse;
 
     fn is_retriable_error(&self, error: &Self::Error) -> bool {
-        error.is_retriable()
+        // Requests aren't retried against an endpoint known to be failing.
+        let circuit_closed = self
+            .circuit_breaker
+            .as_ref()
+            .is_none_or(|circuit_breaker| circuit_breaker.state() == CircuitState::Closed);
+        // The driver reports the events of requests which aren't retried as dropped. The retry
+        // budget was already withdrawn from by `LogApiService`, which knows how many events are
+        // dropped once it's spent.
+        error.is_retriable() && circuit_closed
+    }
+
+    fn should_retry_response(&self, response: &Self::Response) -> RetryAction {
+        if response.events_to_retry > 0 && !self.withdraw_retry() {
+            // The rejected events are otherwise only reported as errored.
+            emit!(ComponentEventsDropped::<UNINTENTIONAL> {
+                count: response.events_to_retry,
+                reason: "retry_budget_exceeded",
+            });
+            RetryAction::DontRetry("retry budget exceeded".into())
+        } else if response.events_to_retry > 0 {
+            RetryAction::Retry(
+                format!(
+                    "{} events were rejected by the intake with a retriable status",
+                    response.events_to_retry
+                )
+                .into(),
+            )
+        } else {
+            RetryAction::Successful
+        }
+    }
+}
+
+/// The structured body of a `207 Multi-Status` response from the v2 intake, listing the events
+/// of the request which were not accepted.
+#[derive(Debug, Deserialize)]
+struct PartialAcceptanceResponse {
+    errors: Vec<RejectedEvent>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RejectedEvent {
+    /// The position of the event in the request body.
+    index: usize,
+    /// The HTTP status the event would have been rejected with on its own.
+    status: u16,
+}
+
+impl RejectedEvent {
+    fn is_retriable(&self) -> bool {
+        match StatusCode::from_u16(self.status) {
+            Ok(status) => {
+                status == StatusCode::REQUEST_TIMEOUT
+                    || status == StatusCode::TOO_MANY_REQUESTS
+                    || status.is_server_error()
+            }
+            Err(_) => false,
+        }
+    }
+}
+
+/// A partial acceptance response which can't be matched to the events of the request.
+#[derive(Debug, Snafu)]
+enum PartialAcceptanceError {
+    #[snafu(display("Invalid partial acceptance response body: {}", error))]
+    InvalidBody { error: serde_json::Error },
+    #[snafu(display("Event {} was rejected, but only {} events were sent.", index, sent))]
+    UnknownEvent { index: usize, sent: usize },
+}
+
+/// The events of a v2 intake request which haven't been accepted yet.
+///
+/// Retries send clones of the original request, so this state is shared between attempts: once the
+/// intake partially accepts a request, the following attempts only carry the rejected events.
+#[derive(Debug)]
+pub struct PendingEvents {
+    /// Each event of the request, serialized as a single line of JSON.
+    lines: Vec<Bytes>,
+    /// The finalizers of each event, updated on their own as the intake may only accept some of
+    /// the events of an attempt.
+    finalizers: Mutex<Vec<EventFinalizers>>,
+    /// Indices into `lines` of the events that still need to be sent, or `None` if none of the
+    /// events have been accepted yet.
+    pending: Mutex<Option<Vec<usize>>>,
+    /// The maximum number of pending events sent in a single attempt, halved every time the intake
+    /// rejects an attempt as too large.
+    attempt_limit: AtomicUsize,
+}
+
+impl PendingEvents {
+    pub const fn new(lines: Vec<Bytes>, finalizers: Vec<EventFinalizers>) -> Self {
+        Self {
+            lines,
+            finalizers: Mutex::new(finalizers),
+            pending: Mutex::new(None),
+            attempt_limit: AtomicUsize::new(usize::MAX),
+        }
+    }
+
+    /// Updates the status of the events at `indices` into `lines`, which aren't sent again.
+    fn finalize(&self, indices: impl IntoIterator<Item = usize>, status: EventStatus) {
+        let mut finalizers = self.finalizers.lock().expect("lock poisoned");
+        for index in indices {
+            std::mem::take(&mut finalizers[index]).update_status(status);
+        }
+    }
+
+    /// Returns the indices into `lines` of the events that still need to be sent.
+    fn pending_indices(&self, pending: &Option<Vec<usize>>) -> Vec<usize> {
+        pending
+            .clone()
+            .unwrap_or_else(|| (0..self.lines.len()).collect())
+    }
+
+    /// Returns the number of events that still need to be sent.
+    fn pending_len(&self) -> usize {
+        self.pending
+            .lock()
+            .expect("lock poisoned")
+            .as_ref()
+            .map_or(self.lines.len(), Vec::len)
+    }
+
+    /// Returns how many of the `pending` events are sent in the next attempt.
+    fn attempt_len(&self, pending: usize) -> usize {
+        pending.min(self.attempt_limit.load(Ordering::Relaxed))
+    }
+
+    /// Builds the body of the next attempt, along with its uncompressed size, if only some of the
+    /// events are sent in it.
+    fn partial_body(
+        &self,
+        compression: Compression,
+    ) -> Result<Option<(Bytes, usize)>, RequestBuildError> {
+        let pending = self.pending.lock().expect("lock poisoned");
+        let indices = self.pending_indices(&pending);
+        let attempt_len = self.attempt_len(indices.len());
+        if pending.is_none() && attempt_len == self.lines.len() {
+            return Ok(None);
+        }
+
+        let mut buf = Vec::new();
+        for index in &indices[..attempt_len] {
+            buf.extend_from_slice(&self.lines[*index]);
+            buf.push(b'\n');
+        }
+
+        let mut compressor = Compressor::from(compression);
+        write_all(&mut compressor, attempt_len, &buf)?;
+        Ok(Some((compressor.into_inner().freeze(), buf.len())))
+    }
+
+    /// Narrows the pending events down to the ones rejected with a retriable status, according to
+    /// the body of a partial acceptance response.
+    ///
+    /// Returns the number of events to retry.
+    fn record_partial_acceptance(&self, body: &[u8]) -> Result<usize, PartialAcceptanceError> {
+        let response: PartialAcceptanceResponse = serde_json::from_slice(body)
+            .map_err(|error| PartialAcceptanceError::InvalidBody { error })?;
+        self.record_acceptance(&response.errors)
+    }
+
+    /// Narrows the pending events down to the ones of the last attempt rejected with a retriable
+    /// status, along with the ones left out of it. The other events of the last attempt are
+    /// finalized as delivered, or as rejected if the intake rejected them for good.
+    ///
+    /// Returns the number of events to retry.
+    fn record_acceptance(&self, errors: &[RejectedEvent]) -> Result<usize, PartialAcceptanceError> {
+        let mut pending = self.pending.lock().expect("lock poisoned");
+        // Indices in the response are relative to the events sent in the last attempt.
+        let mut sent = self.pending_indices(&pending);
+        let unsent = sent.split_off(self.attempt_len(sent.len()));
+
+        let mut accepted = vec![true; sent.len()];
+        let mut retry = Vec::new();
+        let mut dropped = Vec::new();
+        for rejected in errors {
+            let Some(index) = sent.get(rejected.index) else {
+                return Err(PartialAcceptanceError::UnknownEvent {
+                    index: rejected.index,
+                    sent: sent.len(),
+                });
+            };
+            accepted[rejected.index] = false;
+            if rejected.is_retriable() {
+                retry.push(*index);
+            } else {
+                dropped.push(*index);
+            }
+        }
+        dropped.sort_unstable();
+        dropped.dedup();
+        retry.sort_unstable();
+        retry.dedup();
+        retry.retain(|index| dropped.binary_search(index).is_err());
+        retry.extend(unsent);
+
+        self.finalize(
+            sent.iter()
+                .zip(accepted)
+                .filter_map(|(index, accepted)| accepted.then_some(*index)),
+            EventStatus::Delivered,
+        );
+        if !dropped.is_empty() {
+            emit!(ComponentEventsDropped::<UNINTENTIONAL> {
+                count: dropped.len(),
+                reason: "Events rejected by the intake with a non-retriable status.",
+            });
+            self.finalize(dropped, EventStatus::Rejected);
+        }
+
+        let events_to_retry = retry.len();
+        *pending = Some(retry);
+        Ok(events_to_retry)
+    }
+
+    /// Halves the number of events sent in the following attempts after the intake rejected the
+    /// last one as too large. An event rejected on its own can't be split further, so it's dropped.
+    ///
+    /// Returns the number of events to retry.
+    fn record_payload_too_large(&self) -> usize {
+        let mut pending = self.pending.lock().expect("lock poisoned");
+        let mut indices = self.pending_indices(&pending);
+        let attempt_len = self.attempt_len(indices.len());
+        if attempt_len > 1 {
+            self.attempt_limit.store(attempt_len / 2, Ordering::Relaxed);
+        } else if !indices.is_empty() {
+            let index = indices.remove(0);
+            emit!(ComponentEventsDropped::<UNINTENTIONAL> {
+                count: 1,
+                reason: "Event too large for the intake.",
+            });
+            self.finalize([index], EventStatus::Rejected);
+        }
+
+        let events_to_retry = indices.len();
+        *pending = Some(indices);
+        events_to_retry
+    }
+}
+
+impl Drop for PendingEvents {
+    fn drop(&mut self) {
+        // Once every attempt is over, the events still pending were never accepted by the intake.
+        if let Ok(finalizers) = self.finalizers.get_mut() {
+            for finalizers in finalizers.drain(..) {
+                finalizers.update_status(EventStatus::Rejected);
+            }
+        }
     }
 }
 
 #[derive(Debug, Clone)]
 pub struct LogApiRequest {
     pub api_key: Arc<str>,
+    /// The URI to send the request to, such as the logs intake of the Datadog site of its events,
+    /// instead of the configured endpoint
+    pub uri: Option<Uri>,
+    /// Headers specific to this request, such as its trace context
+    pub headers: HeaderMap,
+    /// The first characters of the API key, tagging the metrics of the request if they're reported
+    /// per API key
+    pub api_key_prefix: Option<Arc<str>>,
     pub compression: Compression,
+    pub pending: Option<Arc<PendingEvents>>,
     pub body: Bytes,
     pub finalizers: EventFinalizers,
     pub uncompressed_size: usize,
@@ -67,6 +377,9 @@ pub struct LogApiResponse {
     event_status: EventStatus,
     events_byte_size: GroupedCountByteSize,
     raw_byte_size: usize,
+    events_to_retry: usize,
+    /// The number of events given up on without a retry, as the retry budget was spent
+    events_dropped: usize,
 }
 
 impl DriverResponse for LogApiResponse {
@@ -94,6 +407,10 @@ pub struct LogApiService {
     uri: Uri,
     user_provided_headers: IndexMap<HeaderName, HeaderValue>,
     dd_evp_headers: IndexMap<HeaderName, HeaderValue>,
+    payload_limit: Arc<PayloadLimit>,
+    circuit_breaker: Option<Arc<CircuitBreaker>>,
+    failover: Option<Arc<Failover>>,
+    retry_budget: Option<Arc<RetryBudget>>,
 }
 
 impl LogApiService {
@@ -102,6 +419,7 @@ impl LogApiService {
         uri: Uri,
         headers: IndexMap<String, String>,
         dd_evp_origin: String,
+        payload_limit: Arc<PayloadLimit>,
     ) -> crate::Result<Self> {
         let user_provided_headers = validate_headers(&headers)?;
 
@@ -118,8 +436,43 @@ impl LogApiService {
             uri,
             user_provided_headers,
             dd_evp_headers,
+            payload_limit,
+            circuit_breaker: None,
+            failover: None,
+            retry_budget: None,
         })
     }
+
+    /// Records the outcome of every request in the given circuit breaker.
+    pub fn with_circuit_breaker(mut self, circuit_breaker: Option<Arc<CircuitBreaker>>) -> Self {
+        self.circuit_breaker = circuit_breaker;
+        self
+    }
+
+    /// Sends requests to the fallback endpoint of `failover` while the configured one is failing.
+    pub fn with_failover(mut self, failover: Option<Arc<Failover>>) -> Self {
+        self.failover = failover;
+        self
+    }
+
+    /// Withdraws from the given budget for every request failing with a retriable error, dropping
+    /// the events of the request instead of having it retried once the budget is spent.
+    pub fn with_retry_budget(mut self, retry_budget: Option<Arc<RetryBudget>>) -> Self {
+        self.retry_budget = retry_budget;
+        self
+    }
+}
+
+/// Returns the URI of the logs intake at `base_url`, such as
+/// `https://http-intake.logs.datadoghq.com`.
+pub fn intake_uri(base_url: &str) -> Result<Uri, InvalidUri> {
+    Uri::try_from(format!("{}/api/v2/logs", base_url))
+}
+
+/// Returns the URI of the logs intake of a Datadog site, built the same way as the URI of the
+/// configured site.
+pub fn site_uri(site: &str) -> Option<Uri> {
+    intake_uri(&format!("https://http-intake.logs.{}", site)).ok()
 }
 
 impl Service<LogApiRequest> for LogApiService {
@@ -135,8 +488,23 @@ impl Service<LogApiRequest> for LogApiService {
     // Emission of Error internal event is handled upstream by the caller
     fn call(&mut self, mut request: LogApiRequest) -> Self::Future {
         let mut client = self.client.clone();
-        let http_request = Request::post(&self.uri)
-            .header(CONTENT_TYPE, "application/json")
+        let content_type = if request.pending.is_some() {
+            "application/x-ndjson"
+        } else {
+            "application/json"
+        };
+        // Requests for a specific site don't fail over, the fallback endpoint being a replacement
+        // for the configured one only.
+        let (uri, endpoint) = match (request.uri.take(), &self.failover) {
+            (Some(uri), _) => (uri, None),
+            (None, Some(failover)) => match failover.select() {
+                Endpoint::Primary => (self.uri.clone(), Some(Endpoint::Primary)),
+                Endpoint::Fallback => (failover.fallback().clone(), Some(Endpoint::Fallback)),
+            },
+            (None, None) => (self.uri.clone(), None),
+        };
+        let http_request = Request::post(uri)
+            .header(CONTENT_TYPE, content_type)
             .header("DD-API-KEY", request.api_key.to_string());
 
         let http_request = if let Some(ce) = request.compression.content_encoding() {
@@ -146,8 +514,32 @@ impl Service<LogApiRequest> for LogApiService {
         };
 
         let metadata = std::mem::take(request.metadata_mut());
+        let event_count = metadata.event_count();
         let events_byte_size = metadata.into_events_estimated_json_encoded_byte_size();
-        let raw_byte_size = request.uncompressed_size;
+        let mut raw_byte_size = request.uncompressed_size;
+
+        // Once the intake has partially accepted the request, only the rejected events are sent.
+        let pending = request.pending.take();
+        if let Some(pending) = &pending {
+            match pending.partial_body(request.compression) {
+                Ok(Some((body, uncompressed_size))) => {
+                    request.body = body;
+                    raw_byte_size = uncompressed_size;
+                    // The idempotency key is the hash of the original payload, so it would have
+                    // the rejected events taken for duplicates of the accepted ones.
+                    request.headers.remove("DD-Request-ID");
+                }
+                Ok(None) => {}
+                Err(error) => {
+                    emit!(SinkRequestBuildError {
+                        error_code: error.error_code(),
+                        error,
+                    });
+                    // Sending the original body instead would deliver the accepted events twice.
+                    return Box::pin(future::ready(Err(DatadogApiError::ClientError)));
+                }
+            }
+        }
 
         let mut http_request = http_request.header(CONTENT_LENGTH, request.body.len());
 
@@ -156,6 +548,11 @@ impl Service<LogApiRequest> for LogApiService {
                 // Replace rather than append to any existing header values
                 headers.insert(name, value.clone());
             }
+            for (name, value) in std::mem::take(&mut request.headers) {
+                if let Some(name) = name {
+                    headers.insert(name, value);
+                }
+            }
             // Set DD EVP headers last so that they cannot be overridden.
             for (name, value) in &self.dd_evp_headers {
                 headers.insert(name, value.clone());
@@ -166,14 +563,117 @@ impl Service<LogApiRequest> for LogApiService {
             .body(Body::from(request.body))
             .expect("building HTTP request failed unexpectedly");
 
-        Box::pin(async move {
-            DatadogApiError::from_result(client.call(http_request).in_current_span().await).map(
-                |_| LogApiResponse {
-                    event_status: EventStatus::Delivered,
-                    events_byte_size,
-                    raw_byte_size,
+        let payload_limit = Arc::clone(&self.payload_limit);
+        let circuit_breaker = self.circuit_breaker.clone();
+        let failover = self.failover.clone();
+        let retry_budget = self.retry_budget.clone();
+
+        let api_key_prefix = request.api_key_prefix.take();
+
+        let send = async move {
+            let result =
+                DatadogApiError::from_result(client.call(http_request).in_current_span().await);
+            payload_limit.record_result(&result);
+            // Only errors caused by the endpoint itself count towards opening the circuit or
+            // failing over.
+            let endpoint_failed = matches!(
+                result,
+                Err(DatadogApiError::ServerError
+                    | DatadogApiError::RequestTimeout
+                    | DatadogApiError::HttpError { .. }
+                    | DatadogApiError::TlsHandshake { .. })
+            );
+            if let Some(circuit_breaker) = circuit_breaker {
+                if endpoint_failed {
+                    circuit_breaker.record_failure();
+                } else {
+                    circuit_breaker.record_success();
+                }
+            }
+            if let (Some(failover), Some(endpoint)) = (failover, endpoint) {
+                failover.record(endpoint, !endpoint_failed);
+            }
+
+            if let (Err(error), Some(retry_budget)) = (&result, retry_budget) {
+                if error.is_retriable() && !retry_budget.withdraw() {
+                    let events_dropped = pending
+                        .as_deref()
+                        .map_or(event_count, PendingEvents::pending_len);
+                    emit!(DatadogLogsRetryBudgetExceeded);
+                    emit!(ComponentEventsDropped::<UNINTENTIONAL> {
+                        count: events_dropped,
+                        reason: "retry_budget_exceeded",
+                    });
+                    // An errored response isn't retried, nor reported as dropped by the driver.
+                    return Ok(LogApiResponse {
+                        event_status: EventStatus::Errored,
+                        events_byte_size,
+                        raw_byte_size,
+                        events_to_retry: 0,
+                        events_dropped,
+                    });
+                }
+            }
+
+            let mut events_to_retry = 0;
+            if let Some(pending) = pending {
+                let acceptance = match result {
+                    // The events are sent again in smaller attempts, instead of being dropped
+                    // along with the request.
+                    Err(DatadogApiError::PayloadTooLarge) => Ok(pending.record_payload_too_large()),
+                    Err(error) => return Err(error),
+                    Ok(response) if response.status() == StatusCode::MULTI_STATUS => {
+                        let body = hyper::body::to_bytes(response.into_body()).await.map_err(
+                            |source| DatadogApiError::HttpError {
+                                error: HttpError::CallRequest { source },
+                            },
+                        )?;
+                        pending.record_partial_acceptance(&body)
+                    }
+                    Ok(_) => pending.record_acceptance(&[]),
+                };
+                events_to_retry = match acceptance {
+                    Ok(events_to_retry) => events_to_retry,
+                    Err(error) => {
+                        // Without knowing which events were rejected, the safest option is to
+                        // send all of them again.
+                        emit!(DatadogLogsPartialAcceptanceError { error });
+                        return Err(DatadogApiError::ServerError);
+                    }
+                };
+            } else {
+                result?;
+            }
+
+            Ok(LogApiResponse {
+                event_status: if events_to_retry > 0 {
+                    EventStatus::Errored
+                } else {
+                    EventStatus::Delivered
                 },
-            )
+                events_byte_size,
+                raw_byte_size,
+                events_to_retry,
+                events_dropped: 0,
+            })
+        };
+
+        Box::pin(async move {
+            let result = send.await;
+            if let Some(api_key_prefix) = api_key_prefix {
+                match &result {
+                    Ok(response) => emit!(DatadogLogsApiKeyEventsSent {
+                        api_key_prefix: &api_key_prefix,
+                        count: event_count
+                            .saturating_sub(response.events_to_retry)
+                            .saturating_sub(response.events_dropped),
+                    }),
+                    Err(_) => emit!(DatadogLogsApiKeyRequestError {
+                        api_key_prefix: &api_key_prefix,
+                    }),
+                }
+            }
+            result
         })
     }
 }
@@ -1,23 +1,64 @@
-use std::{collections::VecDeque, fmt::Debug, io, sync::Arc};
+use std::{
+    borrow::Cow,
+    collections::{HashSet, VecDeque},
+    fmt::Debug,
+    io,
+    sync::Arc,
+};
 
+use bytes::Bytes;
+use http::{HeaderMap, HeaderValue};
 use itertools::Itertools;
+use sha2::{Digest, Sha256};
 use snafu::Snafu;
+use uuid::Uuid;
 use vector_lib::{
     event::ObjectMap,
     event::Value,
     internal_event::{ComponentEventsDropped, UNINTENTIONAL},
-    lookup::event_path,
+    lookup::{event_path, metadata_path},
+    sink::PauseSignal,
+    stream::batcher::BatcherControl,
 };
 use vrl::path::{OwnedSegment, OwnedTargetPath, PathPrefix};
 
-use super::{config::MAX_PAYLOAD_BYTES, service::LogApiRequest};
+use super::{
+    config::{DdtagsInvalidValueAction, MAX_COMPRESSED_PAYLOAD_BYTES},
+    dlq::{DeadLetters, DlqSink},
+    payload_limit::PayloadLimit,
+    service::{site_uri, LogApiRequest, PendingEvents},
+    vrl_partitioner::VrlPartitioner,
+};
 use crate::{
     common::datadog::{is_reserved_attribute, DDTAGS, DD_RESERVED_SEMANTIC_ATTRS, MESSAGE},
+    internal_events::{
+        DatadogLogsEstimatedSizeError, DatadogLogsEventTooLarge, DatadogLogsFieldsNormalized,
+        DatadogLogsInvalidApiKey, DatadogLogsInvalidTag, DatadogLogsTimestampParseError,
+        DatadogLogsUnknownSite,
+    },
     sinks::{
         prelude::*,
-        util::{http::HttpJsonBatchSizer, Compressor},
+        util::{
+            circuit_breaker::CircuitBreaker,
+            http::{HttpJsonBatchSizer, HttpRequest, HttpResponse},
+            retry_budget::RetryBudget,
+            Compressor,
+        },
     },
 };
+/// The default prefix of reserved attributes renamed to make room for the attribute that
+/// semantically holds them.
+pub const DEFAULT_CONFLICT_RENAME_PREFIX: &str = "_RESERVED_";
+
+/// The metadata field holding the W3C trace ID of an event, as 32 hexadecimal digits.
+pub const TRACE_ID_FIELD: &str = "_vector_trace_id";
+
+/// The metadata field holding the W3C span ID of an event, as 16 hexadecimal digits.
+pub const SPAN_ID_FIELD: &str = "_vector_span_id";
+
+/// The metadata field holding the W3C trace state of an event.
+pub const TRACE_STATE_FIELD: &str = "_vector_trace_state";
+
 #[derive(Default)]
 pub struct EventPartitioner;
 
@@ -30,15 +71,203 @@ impl Partitioner for EventPartitioner {
     }
 }
 
+/// Partitions events by an API key stored in an event field, for events from several tenants
+/// flowing through the same sink.
+///
+/// Events without the field fall back to the default API key.
+pub struct FieldPartitioner {
+    field: OwnedTargetPath,
+}
+
+impl FieldPartitioner {
+    pub const fn new(field: OwnedTargetPath) -> Self {
+        Self { field }
+    }
+
+    /// Checks the API key in the event field, if there is one.
+    ///
+    /// Returns an error if the field holds anything other than a non-empty string of printable
+    /// ASCII characters.
+    pub fn validate(&self, item: &Event) -> Result<(), &'static str> {
+        match item.as_log().get(&self.field) {
+            None => Ok(()),
+            Some(Value::Bytes(bytes))
+                if !bytes.is_empty() && bytes.iter().all(u8::is_ascii_graphic) =>
+            {
+                Ok(())
+            }
+            Some(_) => {
+                Err("API key field is not a non-empty string of printable ASCII characters.")
+            }
+        }
+    }
+}
+
+impl Partitioner for FieldPartitioner {
+    type Item = Event;
+    type Key = Option<Arc<str>>;
+
+    fn partition(&self, item: &Self::Item) -> Self::Key {
+        // Events with an invalid key were already dropped by `validate`.
+        match item.as_log().get(&self.field) {
+            Some(Value::Bytes(bytes)) => Some(Arc::from(String::from_utf8_lossy(bytes).as_ref())),
+            _ => None,
+        }
+    }
+}
+
+/// Selects where the API key of each event is read from.
+pub enum ApiKeyPartitioner {
+    Metadata(EventPartitioner),
+    Field(FieldPartitioner),
+    Vrl(Arc<VrlPartitioner>),
+}
+
+impl Partitioner for ApiKeyPartitioner {
+    type Item = Event;
+    type Key = Option<Arc<str>>;
+
+    fn partition(&self, item: &Self::Item) -> Self::Key {
+        match self {
+            Self::Metadata(partitioner) => partitioner.partition(item),
+            Self::Field(partitioner) => partitioner.partition(item),
+            Self::Vrl(partitioner) => partitioner.partition(item),
+        }
+    }
+}
+
+/// The Datadog sites events can be sent to through `site_field`.
+const DATADOG_SITES: &[&str] = &[
+    "datadoghq.com",
+    "datadoghq.eu",
+    "us3.datadoghq.com",
+    "us5.datadoghq.com",
+    "ap1.datadoghq.com",
+    "ddog-gov.com",
+];
+
+/// Partitions events by the Datadog site stored in an event field, such as `datadoghq.eu`.
+///
+/// Events without the field, or where it isn't one of the known Datadog sites, are sent to the
+/// configured site. Other sites are never used, as the API key of the events is sent along.
+pub struct SiteFieldPartitioner {
+    field: OwnedTargetPath,
+}
+
+impl SiteFieldPartitioner {
+    pub const fn new(field: OwnedTargetPath) -> Self {
+        Self { field }
+    }
+}
+
+impl Partitioner for SiteFieldPartitioner {
+    type Item = Event;
+    type Key = Option<Arc<str>>;
+
+    fn partition(&self, item: &Self::Item) -> Self::Key {
+        match item.as_log().get(&self.field) {
+            Some(Value::Bytes(bytes)) => {
+                let site = DATADOG_SITES
+                    .iter()
+                    .find(|site| site.as_bytes() == bytes.as_ref());
+                if site.is_none() {
+                    emit!(DatadogLogsUnknownSite {
+                        site: &String::from_utf8_lossy(bytes),
+                    });
+                }
+                site.map(|site| Arc::from(*site))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// A partitioner of events whose key is a component of a [`CompositePartitioner`] key.
+pub type ComponentPartitioner =
+    Box<dyn Partitioner<Item = Event, Key = Option<Arc<str>>> + Send + Sync>;
+
+/// Separates the components of a [`CompositePartitioner`] key.
+const COMPOSITE_KEY_SEPARATOR: &str = "\0";
+
+/// Partitions events by several keys at once, such as their API key and their Datadog site.
+///
+/// The keys of the partitioners are joined into a single key, in which absent keys are left empty.
+/// [`CompositePartitioner::split`] splits it back into its components. Events for which none of the
+/// partitioners has a key are partitioned together under `None`.
+pub struct CompositePartitioner {
+    partitioners: Vec<ComponentPartitioner>,
+}
+
+impl CompositePartitioner {
+    pub fn new(partitioners: Vec<ComponentPartitioner>) -> Self {
+        Self { partitioners }
+    }
+
+    /// Splits a key built by this partitioner back into the keys of its partitioners, in order.
+    ///
+    /// Components past the end of the key are `None`.
+    pub fn split(key: Option<&str>) -> impl Iterator<Item = Option<&str>> {
+        key.into_iter()
+            .flat_map(|key| key.split(COMPOSITE_KEY_SEPARATOR))
+            .map(|component| (!component.is_empty()).then_some(component))
+            .chain(std::iter::repeat(None))
+    }
+}
+
+impl Partitioner for CompositePartitioner {
+    type Item = Event;
+    type Key = Option<Arc<str>>;
+
+    fn partition(&self, item: &Self::Item) -> Self::Key {
+        let keys = self
+            .partitioners
+            .iter()
+            .map(|partitioner| partitioner.partition(item))
+            .collect::<Vec<_>>();
+        if keys.iter().all(Option::is_none) {
+            return None;
+        }
+
+        let key = keys
+            .iter()
+            .map(|key| key.as_deref().unwrap_or_default())
+            .join(COMPOSITE_KEY_SEPARATOR);
+        Some(Arc::from(key))
+    }
+}
+
 #[derive(Debug)]
 pub struct LogSinkBuilder<S> {
     transformer: Transformer,
     service: S,
     batch_settings: BatcherSettings,
     compression: Option<Compression>,
+    payload_limit: Option<Arc<PayloadLimit>>,
     default_api_key: Arc<str>,
     protocol: String,
     conforms_as_agent: bool,
+    agent_normalization_exclude_fields: HashSet<String>,
+    use_v2_api: bool,
+    api_key_field: Option<OwnedTargetPath>,
+    api_key_vrl: Option<String>,
+    site_field: Option<OwnedTargetPath>,
+    priority_field: Option<OwnedTargetPath>,
+    conflict_rename_prefix: Option<String>,
+    ddtags_invalid_value_action: DdtagsInvalidValueAction,
+    circuit_breaker: Option<Arc<CircuitBreaker>>,
+    exact_payload_size: bool,
+    track_estimated_size_error: bool,
+    send_idempotency_key: bool,
+    propagate_trace_context: bool,
+    strip_nulls: bool,
+    api_key_metrics: bool,
+    max_compressed_bytes: usize,
+    max_single_event_bytes: Option<usize>,
+    pause: PauseSignal,
+    batcher_control: BatcherControl,
+    flush_on_signal: bool,
+    dlq_sink: Option<DlqSink>,
+    retry_budget: Option<Arc<RetryBudget>>,
 }
 
 impl<S> LogSinkBuilder<S> {
@@ -56,8 +285,31 @@ impl<S> LogSinkBuilder<S> {
             default_api_key,
             batch_settings,
             compression: None,
+            payload_limit: None,
             protocol,
             conforms_as_agent,
+            agent_normalization_exclude_fields: HashSet::new(),
+            use_v2_api: false,
+            api_key_field: None,
+            api_key_vrl: None,
+            site_field: None,
+            priority_field: None,
+            conflict_rename_prefix: None,
+            ddtags_invalid_value_action: DdtagsInvalidValueAction::Replace,
+            circuit_breaker: None,
+            exact_payload_size: false,
+            track_estimated_size_error: false,
+            send_idempotency_key: false,
+            propagate_trace_context: false,
+            strip_nulls: false,
+            api_key_metrics: false,
+            max_compressed_bytes: MAX_COMPRESSED_PAYLOAD_BYTES,
+            max_single_event_bytes: None,
+            pause: PauseSignal::default(),
+            batcher_control: BatcherControl::default(),
+            flush_on_signal: false,
+            dlq_sink: None,
+            retry_budget: None,
         }
     }
 
@@ -66,16 +318,206 @@ impl<S> LogSinkBuilder<S> {
         self
     }
 
-    pub fn build(self) -> LogSink<S> {
-        LogSink {
+    pub fn payload_limit(mut self, payload_limit: Arc<PayloadLimit>) -> Self {
+        self.payload_limit = Some(payload_limit);
+        self
+    }
+
+    /// Keeps the given root fields in place when normalizing events to the agent format, instead of
+    /// nesting them under `message`.
+    pub fn agent_normalization_exclude_fields(
+        mut self,
+        agent_normalization_exclude_fields: impl IntoIterator<Item = String>,
+    ) -> Self {
+        self.agent_normalization_exclude_fields =
+            agent_normalization_exclude_fields.into_iter().collect();
+        self
+    }
+
+    pub const fn use_v2_api(mut self, use_v2_api: bool) -> Self {
+        self.use_v2_api = use_v2_api;
+        self
+    }
+
+    pub fn api_key_field(mut self, api_key_field: Option<OwnedTargetPath>) -> Self {
+        self.api_key_field = api_key_field;
+        self
+    }
+
+    /// Computes the API key of each event with a VRL program, instead of reading it from the event
+    /// metadata or from `api_key_field`. The program is compiled when the sink is built.
+    pub fn api_key_vrl(mut self, api_key_vrl: Option<String>) -> Self {
+        self.api_key_vrl = api_key_vrl;
+        self
+    }
+
+    /// Partitions events by the Datadog site read from `site_field` in addition to their API key.
+    pub fn site_field(mut self, site_field: Option<OwnedTargetPath>) -> Self {
+        self.site_field = site_field;
+        self
+    }
+
+    /// Sends the events of each batch in descending order of the integer priority read from
+    /// `priority_field`.
+    pub fn priority_field(mut self, priority_field: Option<OwnedTargetPath>) -> Self {
+        self.priority_field = priority_field;
+        self
+    }
+
+    pub fn conflict_rename_prefix(mut self, conflict_rename_prefix: String) -> Self {
+        self.conflict_rename_prefix = Some(conflict_rename_prefix);
+        self
+    }
+
+    pub const fn ddtags_invalid_value_action(
+        mut self,
+        ddtags_invalid_value_action: DdtagsInvalidValueAction,
+    ) -> Self {
+        self.ddtags_invalid_value_action = ddtags_invalid_value_action;
+        self
+    }
+
+    pub fn circuit_breaker(mut self, circuit_breaker: Arc<CircuitBreaker>) -> Self {
+        self.circuit_breaker = Some(circuit_breaker);
+        self
+    }
+
+    pub const fn exact_payload_size(mut self, exact_payload_size: bool) -> Self {
+        self.exact_payload_size = exact_payload_size;
+        self
+    }
+
+    /// Records how far off the estimated size of each event is from its serialized size.
+    pub const fn track_estimated_size_error(mut self, track_estimated_size_error: bool) -> Self {
+        self.track_estimated_size_error = track_estimated_size_error;
+        self
+    }
+
+    /// Sends the hash of the uncompressed payload of every request in its `DD-Request-ID` header,
+    /// so that retried requests can be deduplicated by the intake.
+    pub const fn send_idempotency_key(mut self, send_idempotency_key: bool) -> Self {
+        self.send_idempotency_key = send_idempotency_key;
+        self
+    }
+
+    pub const fn propagate_trace_context(mut self, propagate_trace_context: bool) -> Self {
+        self.propagate_trace_context = propagate_trace_context;
+        self
+    }
+
+    pub const fn strip_nulls(mut self, strip_nulls: bool) -> Self {
+        self.strip_nulls = strip_nulls;
+        self
+    }
+
+    /// Reports the events sent and the failed requests of every API key, tagged with the prefix of
+    /// the key.
+    pub const fn api_key_metrics(mut self, api_key_metrics: bool) -> Self {
+        self.api_key_metrics = api_key_metrics;
+        self
+    }
+
+    /// Drops the requests whose compressed body is larger than `max_compressed_bytes`, instead of
+    /// sending them to the intake.
+    pub const fn max_compressed_bytes(mut self, max_compressed_bytes: usize) -> Self {
+        self.max_compressed_bytes = max_compressed_bytes;
+        self
+    }
+
+    /// Drops the events whose estimated size is over `max_single_event_bytes`, or sends them to
+    /// the dead-letter queue, before they are normalized.
+    pub const fn max_single_event_bytes(mut self, max_single_event_bytes: Option<usize>) -> Self {
+        self.max_single_event_bytes = max_single_event_bytes;
+        self
+    }
+
+    pub fn pause(mut self, pause: PauseSignal) -> Self {
+        self.pause = pause;
+        self
+    }
+
+    /// Flushes the batches in progress whenever `batcher_control` requests it.
+    pub fn batcher_control(mut self, batcher_control: BatcherControl) -> Self {
+        self.batcher_control = batcher_control;
+        self
+    }
+
+    /// Flushes the batches in progress whenever Vector receives `SIGUSR1`.
+    pub const fn flush_on_signal(mut self, flush_on_signal: bool) -> Self {
+        self.flush_on_signal = flush_on_signal;
+        self
+    }
+
+    /// Routes the events that would otherwise be dropped to `service`, as newline-delimited JSON.
+    pub fn dlq_service<D>(mut self, service: D) -> Self
+    where
+        D: Service<HttpRequest<()>, Response = HttpResponse> + Send + 'static,
+        D::Future: Send + 'static,
+        D::Error: Into<crate::Error>,
+    {
+        self.dlq_sink = Some(DlqSink::new(service));
+        self
+    }
+
+    pub fn retry_budget(mut self, retry_budget: Arc<RetryBudget>) -> Self {
+        self.retry_budget = Some(retry_budget);
+        self
+    }
+
+    /// Builds the sink, compiling the VRL program computing API keys if there is one.
+    pub fn build(self) -> crate::Result<LogSink<S>> {
+        // The first component of the partition key is the API key of the events, and the second
+        // one, if any, is the Datadog site to send them to.
+        let api_key_vrl = self
+            .api_key_vrl
+            .as_deref()
+            .map(VrlPartitioner::new)
+            .transpose()?
+            .map(Arc::new);
+        let api_key_partitioner = match (&api_key_vrl, &self.api_key_field) {
+            (Some(api_key_vrl), _) => ApiKeyPartitioner::Vrl(Arc::clone(api_key_vrl)),
+            (None, Some(field)) => ApiKeyPartitioner::Field(FieldPartitioner::new(field.clone())),
+            (None, None) => ApiKeyPartitioner::Metadata(EventPartitioner),
+        };
+        let mut partitioners: Vec<ComponentPartitioner> = vec![Box::new(api_key_partitioner)];
+        if let Some(site_field) = self.site_field {
+            partitioners.push(Box::new(SiteFieldPartitioner::new(site_field)));
+        }
+
+        Ok(LogSink {
             default_api_key: self.default_api_key,
             transformer: self.transformer,
             service: self.service,
             batch_settings: self.batch_settings,
             compression: self.compression.unwrap_or_default(),
+            payload_limit: self.payload_limit.unwrap_or_default(),
             protocol: self.protocol,
             conforms_as_agent: self.conforms_as_agent,
-        }
+            agent_normalization_exclude_fields: self.agent_normalization_exclude_fields,
+            use_v2_api: self.use_v2_api,
+            api_key_field: self.api_key_field,
+            priority_field: self.priority_field,
+            conflict_rename_prefix: self
+                .conflict_rename_prefix
+                .unwrap_or_else(|| DEFAULT_CONFLICT_RENAME_PREFIX.to_string()),
+            ddtags_invalid_value_action: self.ddtags_invalid_value_action,
+            circuit_breaker: self.circuit_breaker,
+            exact_payload_size: self.exact_payload_size,
+            track_estimated_size_error: self.track_estimated_size_error,
+            send_idempotency_key: self.send_idempotency_key,
+            propagate_trace_context: self.propagate_trace_context,
+            strip_nulls: self.strip_nulls,
+            api_key_metrics: self.api_key_metrics,
+            max_compressed_bytes: self.max_compressed_bytes,
+            max_single_event_bytes: self.max_single_event_bytes,
+            pause: self.pause,
+            batcher_control: self.batcher_control,
+            flush_on_signal: self.flush_on_signal,
+            dlq_sink: self.dlq_sink,
+            api_key_vrl,
+            partitioner: CompositePartitioner::new(partitioners),
+            retry_budget: self.retry_budget,
+        })
     }
 }
 
@@ -93,18 +535,70 @@ pub struct LogSink<S> {
     transformer: Transformer,
     /// The compression technique to use when building the request body
     compression: Compression,
+    /// The maximum uncompressed size of a request body, adapted to intake responses
+    payload_limit: Arc<PayloadLimit>,
     /// Batch settings: timeout, max events, max bytes, etc.
     batch_settings: BatcherSettings,
     /// The protocol name
     protocol: String,
     /// Normalize events to agent standard and attach associated HTTP header to request
     conforms_as_agent: bool,
+    /// The root fields left in place when normalizing events to the agent format
+    agent_normalization_exclude_fields: HashSet<String>,
+    /// Send events as newline-delimited JSON, retrying only the events rejected by the intake
+    use_v2_api: bool,
+    /// The event field holding the API key of each event, instead of the event metadata
+    api_key_field: Option<OwnedTargetPath>,
+    /// The event field holding the priority of each event within its batch
+    priority_field: Option<OwnedTargetPath>,
+    /// The prefix of reserved attributes renamed to avoid being overwritten during normalization
+    conflict_rename_prefix: String,
+    /// What to do with the `ddtags` entries holding commas
+    ddtags_invalid_value_action: DdtagsInvalidValueAction,
+    /// Short-circuits events while the intake is failing, shared with the service recording the
+    /// outcome of requests
+    circuit_breaker: Option<Arc<CircuitBreaker>>,
+    /// Measure the exact size of each event before adding it to a payload, rather than relying on
+    /// its estimated size
+    exact_payload_size: bool,
+    /// Record the difference between the serialized and estimated size of each event
+    track_estimated_size_error: bool,
+    /// Send the SHA-256 hash of the uncompressed payload of each request as its `DD-Request-ID`
+    send_idempotency_key: bool,
+    /// Send the trace context of the first event of each batch as W3C trace-context headers
+    propagate_trace_context: bool,
+    /// Remove null fields from events before serializing them
+    strip_nulls: bool,
+    /// Tag the metrics of every request with the prefix of its API key
+    api_key_metrics: bool,
+    /// The maximum size of a compressed request body, above which the request is dropped
+    max_compressed_bytes: usize,
+    /// The maximum estimated size of an event, above which it's dropped before being normalized
+    max_single_event_bytes: Option<usize>,
+    /// Holds back batches while the sink is paused through the API
+    pause: PauseSignal,
+    /// Flushes the batches in progress on request, without stopping the sink
+    batcher_control: BatcherControl,
+    /// Request a flush of the batches in progress whenever Vector receives `SIGUSR1`
+    flush_on_signal: bool,
+    /// Receives the events that are too large or fail to serialize, instead of dropping them
+    dlq_sink: Option<DlqSink>,
+    /// Computes the API key of each event, stored in its metadata before it's partitioned
+    api_key_vrl: Option<Arc<VrlPartitioner>>,
+    /// Partitions events by their API key, and by their Datadog site if it's read from the events
+    partitioner: CompositePartitioner,
+    /// Funded by every request sent, shared with the retry logic limiting retries
+    retry_budget: Option<Arc<RetryBudget>>,
 }
 
 // The Datadog logs intake does not require the fields that are set in this
 // function. But if they are present in the event, we normalize the paths
 // (and value in the case of timestamp) to something that intake understands.
-pub fn normalize_event(event: &mut Event) {
+pub fn normalize_event(
+    event: &mut Event,
+    conflict_rename_prefix: &str,
+    ddtags_invalid_value_action: DdtagsInvalidValueAction,
+) {
     let log = event.as_mut_log();
 
     // Will cast the internal value to an object if it already isn't
@@ -118,7 +612,13 @@ pub fn normalize_event(event: &mut Event) {
         // check if there is a semantic meaning for the reserved attribute
         if let Some(current_path) = log.find_key_by_meaning(meaning).cloned() {
             // move it to the desired location
-            position_reserved_attr_event_root(log, &current_path, expected_field_name, meaning);
+            position_reserved_attr_event_root(
+                log,
+                &current_path,
+                expected_field_name,
+                meaning,
+                conflict_rename_prefix,
+            );
         }
     }
 
@@ -135,6 +635,7 @@ pub fn normalize_event(event: &mut Event) {
                         .as_bytes()
                         .map(|bytes| String::from_utf8_lossy(bytes))
                 })
+                .filter_map(|tag| validate_ddtag(tag, ddtags_invalid_value_action))
                 .join(",");
 
             log.insert(ddtags_path, all_tags);
@@ -145,8 +646,55 @@ pub fn normalize_event(event: &mut Event) {
     // NOTE: we don't access by semantic meaning here because in the prior step
     // we ensured reserved attributes are in expected locations.
     let ts_path = event_path!("timestamp");
-    if let Some(Value::Timestamp(ts)) = log.remove(ts_path) {
-        log.insert(ts_path, Value::Integer(ts.timestamp_millis()));
+    if let Some(timestamp) = log.get(ts_path) {
+        if let Some(millis) = timestamp_millis(timestamp) {
+            log.insert(ts_path, Value::Integer(millis));
+        }
+    }
+}
+
+/// Checks that a tag of an array of `ddtags` doesn't hold commas, which would split it into several
+/// tags once the array is joined. Returns the tag to send, if any.
+fn validate_ddtag(tag: Cow<'_, str>, action: DdtagsInvalidValueAction) -> Option<Cow<'_, str>> {
+    if !tag.contains(',') {
+        return Some(tag);
+    }
+    emit!(DatadogLogsInvalidTag {
+        tag: &tag,
+        action: action.as_str(),
+    });
+    match action {
+        DdtagsInvalidValueAction::Drop => None,
+        DdtagsInvalidValueAction::Replace => Some(Cow::Owned(tag.replace(',', "_"))),
+    }
+}
+
+/// Integer timestamps below this are taken to be in seconds rather than milliseconds, as they would
+/// otherwise be before 2001 in milliseconds, but are past the year 33,000 in seconds.
+const MAX_EPOCH_SECONDS: i64 = 1_000_000_000_000;
+
+/// Converts a timestamp to the milliseconds since the Unix epoch expected by the logs intake.
+///
+/// Besides timestamps, this accepts RFC 3339 strings and integers in seconds or milliseconds.
+/// `None` is returned for values that should be left as they are.
+fn timestamp_millis(timestamp: &Value) -> Option<i64> {
+    match timestamp {
+        Value::Timestamp(ts) => Some(ts.timestamp_millis()),
+        Value::Integer(seconds) if seconds.abs() < MAX_EPOCH_SECONDS => {
+            Some(seconds.saturating_mul(1000))
+        }
+        Value::Bytes(bytes) => {
+            let parsed = std::str::from_utf8(bytes)
+                .ok()
+                .and_then(|ts| chrono::DateTime::parse_from_rfc3339(ts).ok());
+            if parsed.is_none() {
+                emit!(DatadogLogsTimestampParseError {
+                    timestamp: String::from_utf8_lossy(bytes).as_ref(),
+                });
+            }
+            parsed.map(|ts| ts.timestamp_millis())
+        }
+        _ => None,
     }
 }
 
@@ -155,8 +703,9 @@ pub fn normalize_event(event: &mut Event) {
 // event originates from an agent. Normalizing the events to the format prepared by the datadog
 // agent resolves any inconsistencies that would be observed when data flows through vector
 // before being ingested by the logs intake. This is because the logs intake interprets the
-// request with slight differences when this header and format are observed.
-pub fn normalize_as_agent_event(event: &mut Event) {
+// request with slight differences when this header and format are observed. The fields in
+// `exclude_fields` are left at the root as well.
+pub fn normalize_as_agent_event(event: &mut Event, exclude_fields: &HashSet<String>) {
     let log = event.as_mut_log();
     // Should never occur since normalize_event forces a conversion of the log value to an Object type
     let Some(object_map) = log.as_map_mut() else {
@@ -166,7 +715,7 @@ pub fn normalize_as_agent_event(event: &mut Event) {
     let mut local_root = ObjectMap::default();
     let keys_to_move = object_map
         .keys()
-        .filter(|ks| !is_reserved_attribute(ks.as_str()))
+        .filter(|ks| !is_reserved_attribute(ks.as_str()) && !exclude_fields.contains(ks.as_str()))
         .cloned()
         .collect::<Vec<_>>();
     for key in keys_to_move {
@@ -174,27 +723,47 @@ pub fn normalize_as_agent_event(event: &mut Event) {
             local_root.insert(entry_k, entry_v);
         }
     }
+    emit!(DatadogLogsFieldsNormalized {
+        count: local_root.len() as u64,
+    });
     // .. nest this object at the root under the reserved key named 'message'
     log.insert(MESSAGE, local_root);
 }
 
+/// Recursively removes the fields holding `null` from the objects in `value`, including objects
+/// nested in arrays. The logs intake ignores them, so they only take up room in payloads.
+///
+/// Null elements of arrays are kept, as removing them would shift the position of the elements
+/// after them.
+pub fn strip_nulls(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            map.retain(|_, value| !value.is_null());
+            map.values_mut().for_each(strip_nulls);
+        }
+        Value::Array(values) => values.iter_mut().for_each(strip_nulls),
+        _ => {}
+    }
+}
+
 // If an expected reserved attribute is not located in the event root, rename it and handle
-// any potential conflicts by preserving the conflicting one with a _RESERVED_ prefix.
+// any potential conflicts by preserving the conflicting one with the given prefix.
 pub fn position_reserved_attr_event_root(
     log: &mut LogEvent,
     current_path: &OwnedTargetPath,
     expected_field_name: &str,
     meaning: &str,
+    prefix: &str,
 ) {
     // the path that DD archives expects this reserved attribute to be in.
     let desired_path = event_path!(expected_field_name);
 
     // if not already be at the expected location
-    if !path_is_field(current_path, expected_field_name) {
+    if !path_matches_segments(current_path, &[expected_field_name]) {
         // if an existing attribute exists here already, move it so to not overwrite it.
         // yes, technically the rename path could exist, but technically that could always be the case.
         if log.contains(desired_path) {
-            let rename_attr = format!("_RESERVED_{}", meaning);
+            let rename_attr = format!("{}{}", prefix, meaning);
             let rename_path = event_path!(rename_attr.as_str());
             warn!(
                 message = "Semantic meaning is defined, but the event path already exists. Renaming to not overwrite.",
@@ -208,14 +777,22 @@ pub fn position_reserved_attr_event_root(
     }
 }
 
-// Test if the named path consists of the single named field. This is rather a hack and should
-// hypothetically be solvable in the `vrl` crate with an implementation of
+// Test if the named path consists of exactly the named fields, such as `network.client.ip`. This is
+// rather a hack and should hypothetically be solvable in the `vrl` crate with an implementation of
 // `PartialEq<BorrowedTargetPath<'_>>`. The alternative is doing a comparison against another
 // `OwnedTargetPath`, but the naïve implementation of that requires multiple allocations and copies
 // just to test equality.
-pub fn path_is_field(path: &OwnedTargetPath, field: &str) -> bool {
+pub fn path_matches_segments(path: &OwnedTargetPath, segments: &[&str]) -> bool {
     path.prefix == PathPrefix::Event
-        && matches!(&path.path.segments[..], [OwnedSegment::Field(f)] if f.as_str() == field)
+        && path.path.segments.len() == segments.len()
+        && path.path.segments.iter().zip(segments).all(
+            |(segment, field)| matches!(segment, OwnedSegment::Field(f) if f.as_str() == *field),
+        )
+}
+
+// Test if the named path consists of the single named field.
+pub fn path_is_field(path: &OwnedTargetPath, field: &str) -> bool {
+    path_matches_segments(path, &[field])
 }
 
 #[derive(Debug, Snafu)]
@@ -226,6 +803,27 @@ pub enum RequestBuildError {
     Io { error: std::io::Error },
     #[snafu(display("Failed to serialize payload with error: {}", error))]
     Json { error: serde_json::Error },
+    #[snafu(display(
+        "Compressed payload of {} bytes is greater than the limit of {} bytes.",
+        compressed_size,
+        limit
+    ))]
+    CompressedPayloadTooBig {
+        compressed_size: usize,
+        limit: usize,
+    },
+}
+
+impl RequestBuildError {
+    /// The code reporting this error in the `component_errors_total` metric.
+    pub const fn error_code(&self) -> &'static str {
+        match self {
+            RequestBuildError::PayloadTooBig { .. }
+            | RequestBuildError::CompressedPayloadTooBig { .. } => "payload_too_big",
+            RequestBuildError::Io { .. } => "io_error",
+            RequestBuildError::Json { .. } => "json_error",
+        }
+    }
 }
 
 impl From<io::Error> for RequestBuildError {
@@ -244,7 +842,22 @@ struct LogRequestBuilder {
     pub default_api_key: Arc<str>,
     pub transformer: Transformer,
     pub compression: Compression,
+    pub payload_limit: Arc<PayloadLimit>,
     pub conforms_as_agent: bool,
+    pub agent_normalization_exclude_fields: HashSet<String>,
+    pub use_v2_api: bool,
+    pub api_key_field: Option<OwnedTargetPath>,
+    pub conflict_rename_prefix: String,
+    pub ddtags_invalid_value_action: DdtagsInvalidValueAction,
+    pub exact_payload_size: bool,
+    pub track_estimated_size_error: bool,
+    pub send_idempotency_key: bool,
+    pub propagate_trace_context: bool,
+    pub strip_nulls: bool,
+    pub api_key_metrics: bool,
+    pub max_compressed_bytes: usize,
+    pub max_single_event_bytes: Option<usize>,
+    pub dead_letters: DeadLetters,
 }
 
 impl LogRequestBuilder {
@@ -252,58 +865,196 @@ impl LogRequestBuilder {
         &self,
         events: Vec<Event>,
         api_key: Arc<str>,
+        site: Option<Arc<str>>,
     ) -> Result<Vec<LogApiRequest>, RequestBuildError> {
+        // Every request built from the batch carries the trace context of its first event.
+        let headers = if self.propagate_trace_context {
+            trace_context_headers(events.first())
+        } else {
+            HeaderMap::new()
+        };
+
         // Transform events and pre-compute their estimated size.
         let mut events_with_estimated_size: VecDeque<(Event, JsonSize)> = events
             .into_iter()
-            .map(|mut event| {
-                normalize_event(&mut event);
+            .filter_map(|mut event| {
+                if let Some(limit) = self.max_single_event_bytes {
+                    let event_bytes = event.estimated_json_encoded_size_of().get();
+                    if event_bytes > limit {
+                        emit!(DatadogLogsEventTooLarge { event_bytes, limit });
+                        self.drop_event(
+                            event,
+                            "event_over_size_limit",
+                            "Event larger than max_single_event_bytes.",
+                        );
+                        return None;
+                    }
+                }
+                // The API key is sent in a header, so it isn't left in the event itself.
+                if let Some(field) = &self.api_key_field {
+                    event.as_mut_log().remove(field);
+                }
+                normalize_event(
+                    &mut event,
+                    &self.conflict_rename_prefix,
+                    self.ddtags_invalid_value_action,
+                );
                 if self.conforms_as_agent {
-                    normalize_as_agent_event(&mut event);
+                    normalize_as_agent_event(&mut event, &self.agent_normalization_exclude_fields);
                 }
                 self.transformer.transform(&mut event);
+                if self.strip_nulls {
+                    strip_nulls(event.as_mut_log().value_mut());
+                }
                 let estimated_json_size = event.estimated_json_encoded_size_of();
-                (event, estimated_json_size)
+                Some((event, estimated_json_size))
             })
             .collect();
 
         // Construct requests respecting the max payload size.
+        let max_payload_bytes = self.payload_limit.get();
         let mut requests: Vec<LogApiRequest> = Vec::new();
+        let mut scratch = Vec::new();
         while !events_with_estimated_size.is_empty() {
-            let (events_serialized, body, byte_size) =
-                serialize_with_capacity(&mut events_with_estimated_size)?;
+            // The payload is hashed as it's serialized, rather than hashing the whole buffer again.
+            let mut hasher = self.send_idempotency_key.then(Sha256::new);
+            let serialized = if self.exact_payload_size {
+                serialize_with_exact_capacity(
+                    &mut events_with_estimated_size,
+                    max_payload_bytes,
+                    self.use_v2_api,
+                    self.track_estimated_size_error,
+                    hasher.as_mut(),
+                    &mut scratch,
+                )
+            } else {
+                serialize_with_capacity(
+                    &mut events_with_estimated_size,
+                    max_payload_bytes,
+                    self.use_v2_api,
+                    self.track_estimated_size_error,
+                    hasher.as_mut(),
+                )
+            };
+            let (events_serialized, body, byte_size) = match serialized {
+                Ok(serialized) => serialized,
+                // Without a dead-letter queue, the whole batch is dropped as it always has been.
+                Err(error) if !self.dead_letters.is_enabled() => return Err(error.into()),
+                Err(error) => {
+                    // The event that failed to serialize is the first one left.
+                    if let Some((event, _)) = events_with_estimated_size.pop_front() {
+                        debug!(message = "Routing event to the dead-letter queue.", %error);
+                        self.drop_event(
+                            event,
+                            "serialization_failed",
+                            "Failed to serialize event.",
+                        );
+                    }
+                    continue;
+                }
+            };
             if events_serialized.is_empty() {
                 // first event was too large for whole request
-                let _too_big = events_with_estimated_size.pop_front();
-                emit!(ComponentEventsDropped::<UNINTENTIONAL> {
-                    count: 1,
-                    reason: "Event too large to encode."
-                });
+                if let Some((event, _)) = events_with_estimated_size.pop_front() {
+                    self.drop_event(event, "event_too_large", "Event too large to encode.");
+                }
             } else {
-                let request =
-                    self.finish_request(body, events_serialized, byte_size, Arc::clone(&api_key))?;
-                requests.push(request);
+                let count = events_serialized.len();
+                match self.finish_request(
+                    body,
+                    events_serialized,
+                    byte_size,
+                    Arc::clone(&api_key),
+                    site.clone(),
+                    headers.clone(),
+                    hasher,
+                ) {
+                    Ok(request) => requests.push(request),
+                    // The intake would reject the request, so its events are dropped while the rest
+                    // of the batch is still sent.
+                    Err(error @ RequestBuildError::CompressedPayloadTooBig { .. }) => {
+                        emit!(SinkRequestBuildError {
+                            error_code: error.error_code(),
+                            error,
+                        });
+                        emit!(ComponentEventsDropped::<UNINTENTIONAL> {
+                            count,
+                            reason: "Compressed payload too large.",
+                        });
+                    }
+                    Err(error) => return Err(error),
+                }
             }
         }
 
         Ok(requests)
     }
 
+    /// Sends an event that can't be sent to the intake to the dead-letter queue, or drops it if
+    /// there is none.
+    fn drop_event(&self, event: Event, dlq_reason: &'static str, reason: &'static str) {
+        if self.dead_letters.send(event, dlq_reason).is_some() {
+            emit!(ComponentEventsDropped::<UNINTENTIONAL> { count: 1, reason });
+        }
+    }
+
     fn finish_request(
         &self,
         buf: Vec<u8>,
         mut events: Vec<Event>,
         byte_size: GroupedCountByteSize,
         api_key: Arc<str>,
+        site: Option<Arc<str>>,
+        mut headers: HeaderMap,
+        hasher: Option<Sha256>,
     ) -> Result<LogApiRequest, RequestBuildError> {
         let n_events = events.len();
         let uncompressed_size = buf.len();
 
+        // Identical payloads get the same ID, so the intake can tell retries from new requests.
+        if let Some(hasher) = hasher {
+            let request_id = format!("{:x}", hasher.finalize());
+            headers.insert(
+                "DD-Request-ID",
+                HeaderValue::from_str(&request_id).expect("a hex digest is a valid header value"),
+            );
+        }
+
         // Now just compress it like normal.
         let mut compressor = Compressor::from(self.compression);
         write_all(&mut compressor, n_events, &buf)?;
         let bytes = compressor.into_inner().freeze();
 
+        // Compression only makes payloads larger when something went wrong, so this catches
+        // compression bugs and corrupted data before the intake rejects the request.
+        if bytes.len() > self.max_compressed_bytes {
+            events
+                .take_finalizers()
+                .update_status(EventStatus::Rejected);
+            return Err(RequestBuildError::CompressedPayloadTooBig {
+                compressed_size: bytes.len(),
+                limit: self.max_compressed_bytes,
+            });
+        }
+
+        // Keep the serialized events around so that a partially accepted request can be retried
+        // with just the events rejected by the intake.
+        let pending = self.use_v2_api.then(|| {
+            let buf = Bytes::from(buf);
+            let lines = buf
+                .split(|byte| *byte == b'\n')
+                .filter(|line| !line.is_empty())
+                .map(|line| buf.slice_ref(line))
+                .collect::<Vec<_>>();
+            // Each event is finalized on its own, according to whether the intake accepted it.
+            let finalizers = events
+                .iter_mut()
+                .map(Finalizable::take_finalizers)
+                .collect::<Vec<_>>();
+            debug_assert_eq!(lines.len(), finalizers.len());
+            Arc::new(PendingEvents::new(lines, finalizers))
+        });
+
         let finalizers = events.take_finalizers();
         let request_metadata_builder = RequestMetadataBuilder::from_events(&events);
 
@@ -314,9 +1065,13 @@ impl LogRequestBuilder {
         };
 
         Ok::<_, RequestBuildError>(LogApiRequest {
+            api_key_prefix: self.api_key_metrics.then(|| api_key_prefix(&api_key)),
             api_key,
+            uri: site.as_deref().and_then(site_uri),
+            headers,
             finalizers,
             compression: self.compression,
+            pending,
             metadata: request_metadata_builder.build(&payload),
             uncompressed_size: payload.uncompressed_byte_size,
             body: payload.into_payload(),
@@ -324,15 +1079,76 @@ impl LogRequestBuilder {
     }
 }
 
-/// Serialize events into a buffer as a JSON array that has a maximum size of
-/// `MAX_PAYLOAD_BYTES`.
+/// The number of characters of the API key tagging the metrics reported per API key.
+const API_KEY_PREFIX_LEN: usize = 6;
+
+/// Returns the first characters of an API key, which identify it in metrics without disclosing it.
+fn api_key_prefix(api_key: &str) -> Arc<str> {
+    let end = api_key
+        .char_indices()
+        .nth(API_KEY_PREFIX_LEN)
+        .map_or(api_key.len(), |(index, _)| index);
+    Arc::from(&api_key[..end])
+}
+
+/// Builds the W3C trace-context headers of a request from the trace context in the metadata of
+/// `event`.
+///
+/// A trace or span ID that is absent, or isn't a valid W3C ID, is generated at random so that
+/// every request carries a valid `traceparent` header.
+fn trace_context_headers(event: Option<&Event>) -> HeaderMap {
+    let metadata = |field: &str| {
+        event
+            .and_then(|event| event.as_log().get(metadata_path!(field)))
+            .and_then(Value::as_str)
+    };
+    let id = |field: &str, len: usize| {
+        metadata(field)
+            .map(|id| id.to_ascii_lowercase())
+            .filter(|id| {
+                id.len() == len
+                    && id
+                        .bytes()
+                        .all(|byte| matches!(byte, b'0'..=b'9' | b'a'..=b'f'))
+                    && id.bytes().any(|byte| byte != b'0')
+            })
+    };
+    let random_id = |len: usize| Uuid::new_v4().simple().to_string()[..len].to_string();
+
+    let trace_id = id(TRACE_ID_FIELD, 32).unwrap_or_else(|| random_id(32));
+    let span_id = id(SPAN_ID_FIELD, 16).unwrap_or_else(|| random_id(16));
+
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        "traceparent",
+        HeaderValue::from_str(&format!("00-{trace_id}-{span_id}-01"))
+            .expect("traceparent is a valid header value"),
+    );
+    if let Some(trace_state) =
+        metadata(TRACE_STATE_FIELD).and_then(|trace_state| HeaderValue::from_str(&trace_state).ok())
+    {
+        headers.insert("tracestate", trace_state);
+    }
+    headers
+}
+
+/// Serialize events into a buffer that has a maximum size of `max_payload_bytes`.
+///
+/// Events are serialized as a JSON array, or as newline-delimited JSON when `ndjson` is set. When
+/// `track_size_error` is set, how far off the estimated size of each event is gets recorded. When
+/// `hasher` is set, it's fed the bytes of the buffer as the events are added to it.
 ///
 /// Returns the serialized events, the buffer, and the byte size of the events.
-/// Events that are not serialized remain in the `events` parameter.
+/// Events that are not serialized remain in the `events` parameter. Serialization stops at the
+/// first event that fails to serialize, which is only reported as an error if it's the first one.
 pub fn serialize_with_capacity(
     events: &mut VecDeque<(Event, JsonSize)>,
-) -> Result<(Vec<Event>, Vec<u8>, GroupedCountByteSize), io::Error> {
-    // Compute estimated size, accounting for the size of the brackets and commas.
+    max_payload_bytes: usize,
+    ndjson: bool,
+    track_size_error: bool,
+    mut hasher: Option<&mut Sha256>,
+) -> Result<(Vec<Event>, Vec<u8>, GroupedCountByteSize), serde_json::Error> {
+    // Compute estimated size, accounting for the size of the brackets and separators.
     let total_estimated =
         events.iter().map(|(_, size)| size.get()).sum::<usize>() + events.len() * 2;
 
@@ -342,28 +1158,152 @@ pub fn serialize_with_capacity(
     let mut events_serialized = Vec::with_capacity(events.len());
 
     // Write entries until the buffer is full.
-    buf.push(b'[');
+    if !ndjson {
+        buf.push(b'[');
+        if let Some(hasher) = &mut hasher {
+            hasher.update(b"[");
+        }
+    }
     let mut first = true;
     while let Some((event, estimated_json_size)) = events.pop_front() {
         // Track the existing length of the buffer so we can truncate it if we need to.
         let existing_len = buf.len();
         if first {
             first = false;
-        } else {
+        } else if !ndjson {
             buf.push(b',');
         }
-        serde_json::to_writer(&mut buf, event.as_log())?;
+        let json_start = buf.len();
+        if let Err(error) = write_json(&mut buf, event.as_log()) {
+            events.push_front((event, estimated_json_size));
+            if events_serialized.is_empty() {
+                return Err(error);
+            }
+            buf.truncate(existing_len);
+            break;
+        }
+        if track_size_error {
+            emit!(DatadogLogsEstimatedSizeError {
+                estimated_bytes: estimated_json_size.get(),
+                actual_bytes: buf.len() - json_start,
+            });
+        }
+        if ndjson {
+            buf.push(b'\n');
+        }
         // If the buffer is too big, truncate it and break out of the loop.
-        if buf.len() >= MAX_PAYLOAD_BYTES {
+        if buf.len() >= max_payload_bytes {
             events.push_front((event, estimated_json_size));
             buf.truncate(existing_len);
             break;
         }
         // Otherwise, track the size of the event and continue.
+        if let Some(hasher) = &mut hasher {
+            hasher.update(&buf[existing_len..]);
+        }
         byte_size.add_event(&event, estimated_json_size);
         events_serialized.push(event);
     }
-    buf.push(b']');
+    if !ndjson {
+        buf.push(b']');
+        if let Some(hasher) = &mut hasher {
+            hasher.update(b"]");
+        }
+    }
+
+    Ok((events_serialized, buf, byte_size))
+}
+
+/// Serializes a log event as JSON at the end of `buf`.
+#[cfg(not(feature = "simd-json-serialize"))]
+fn write_json(buf: &mut Vec<u8>, log: &LogEvent) -> Result<(), serde_json::Error> {
+    serde_json::to_writer(buf, log)
+}
+
+/// Serializes a log event as JSON at the end of `buf` with `simd-json`, which is faster than
+/// `serde_json` on CPUs supporting AVX2. Errors are converted so that callers are the same with or
+/// without the feature.
+#[cfg(feature = "simd-json-serialize")]
+fn write_json(buf: &mut Vec<u8>, log: &LogEvent) -> Result<(), serde_json::Error> {
+    simd_json::to_writer(buf, log).map_err(serde::ser::Error::custom)
+}
+
+/// Serialize events into a buffer that has a maximum size of `max_payload_bytes`, measuring the
+/// exact size of each event before adding it.
+///
+/// Unlike [`serialize_with_capacity`], which sizes the buffer from the estimated size of the events
+/// and truncates it once an event overflows it, each event is first serialized into `scratch`. Only
+/// events that fit are copied into the buffer, which keeps events whose estimated size is far off,
+/// such as those with a lot of multi-byte Unicode content, from causing oversized allocations.
+/// `scratch` is reused across calls to avoid allocating for every event.
+///
+/// Returns the serialized events, the buffer, and the byte size of the events.
+/// Events that are not serialized remain in the `events` parameter. As with
+/// [`serialize_with_capacity`], an event that fails to serialize is only reported as an error if
+/// it's the first one.
+pub fn serialize_with_exact_capacity(
+    events: &mut VecDeque<(Event, JsonSize)>,
+    max_payload_bytes: usize,
+    ndjson: bool,
+    track_size_error: bool,
+    mut hasher: Option<&mut Sha256>,
+    scratch: &mut Vec<u8>,
+) -> Result<(Vec<Event>, Vec<u8>, GroupedCountByteSize), serde_json::Error> {
+    // The estimated size is only used as a hint, so never reserve more than the payload can hold.
+    let total_estimated =
+        events.iter().map(|(_, size)| size.get()).sum::<usize>() + events.len() * 2;
+
+    // Initialize state.
+    let mut buf = Vec::with_capacity(total_estimated.min(max_payload_bytes));
+    let mut byte_size = telemetry().create_request_count_byte_size();
+    let mut events_serialized = Vec::with_capacity(events.len());
+
+    // Write entries until the next one doesn't fit.
+    if !ndjson {
+        buf.push(b'[');
+        if let Some(hasher) = &mut hasher {
+            hasher.update(b"[");
+        }
+    }
+    while let Some((event, estimated_json_size)) = events.pop_front() {
+        scratch.clear();
+        if !ndjson && !events_serialized.is_empty() {
+            scratch.push(b',');
+        }
+        let json_start = scratch.len();
+        if let Err(error) = serde_json::to_writer(&mut *scratch, event.as_log()) {
+            events.push_front((event, estimated_json_size));
+            if events_serialized.is_empty() {
+                return Err(error);
+            }
+            break;
+        }
+        if track_size_error {
+            emit!(DatadogLogsEstimatedSizeError {
+                estimated_bytes: estimated_json_size.get(),
+                actual_bytes: scratch.len() - json_start,
+            });
+        }
+        if ndjson {
+            scratch.push(b'\n');
+        }
+        if buf.len() + scratch.len() >= max_payload_bytes {
+            events.push_front((event, estimated_json_size));
+            break;
+        }
+        buf.extend_from_slice(scratch);
+        if let Some(hasher) = &mut hasher {
+            hasher.update(&scratch[..]);
+        }
+        byte_size.add_event(&event, estimated_json_size);
+        events_serialized.push(event);
+    }
+    if !ndjson {
+        buf.push(b']');
+        if let Some(hasher) = &mut hasher {
+            hasher.update(b"]");
+        }
+    }
 
     Ok((events_serialized, buf, byte_size))
 }
@@ -378,46 +1318,205 @@ where
     async fn run_inner(self: Box<Self>, input: BoxStream<'_, Event>) -> Result<(), ()> {
         let default_api_key = Arc::clone(&self.default_api_key);
 
-        let partitioner = EventPartitioner;
+        let partitioner = self.partitioner;
         let batch_settings = self.batch_settings;
+
+        // The dead-letter queue stops once the request builder, holding the last handle to it, is
+        // dropped along with the input.
+        let (dead_letters, dlq) = match self.dlq_sink {
+            Some(dlq_sink) => {
+                let (dead_letters, run) = dlq_sink.start(batch_settings);
+                (dead_letters, run.boxed())
+            }
+            None => (DeadLetters::default(), future::ok(()).boxed()),
+        };
         let builder = Arc::new(LogRequestBuilder {
             default_api_key,
             transformer: self.transformer,
             compression: self.compression,
+            payload_limit: self.payload_limit,
             conforms_as_agent: self.conforms_as_agent,
+            agent_normalization_exclude_fields: self.agent_normalization_exclude_fields,
+            use_v2_api: self.use_v2_api,
+            api_key_field: self.api_key_field,
+            conflict_rename_prefix: self.conflict_rename_prefix,
+            ddtags_invalid_value_action: self.ddtags_invalid_value_action,
+            exact_payload_size: self.exact_payload_size,
+            track_estimated_size_error: self.track_estimated_size_error,
+            send_idempotency_key: self.send_idempotency_key,
+            propagate_trace_context: self.propagate_trace_context,
+            strip_nulls: self.strip_nulls,
+            api_key_metrics: self.api_key_metrics,
+            max_compressed_bytes: self.max_compressed_bytes,
+            max_single_event_bytes: self.max_single_event_bytes,
+            dead_letters,
         });
 
-        let input = input.batched_partitioned(partitioner, || {
-            batch_settings.as_item_size_config(HttpJsonBatchSizer)
+        // While the intake is failing, events are dropped instead of piling up in retries. A single
+        // event is let through as a probe once the recovery timeout elapses.
+        let circuit_breaker = self.circuit_breaker;
+        let input = input.filter_map(move |mut event| {
+            let allowed = circuit_breaker
+                .as_ref()
+                .is_none_or(|circuit_breaker| circuit_breaker.allow());
+            if !allowed {
+                event.take_finalizers().update_status(EventStatus::Rejected);
+                emit!(ComponentEventsDropped::<UNINTENTIONAL> {
+                    count: 1,
+                    reason: "circuit_breaker_open",
+                });
+            }
+            future::ready(allowed.then_some(event))
+        });
+
+        // The API key computed by the VRL program is stored in the metadata of each event, where
+        // the partitioner reads it.
+        let api_key_vrl = self.api_key_vrl;
+        let input = input.map(move |event| match &api_key_vrl {
+            Some(api_key_vrl) => api_key_vrl.set_api_key(event),
+            None => event,
+        });
+
+        // Events whose API key field holds an invalid key can't be attributed to any API key.
+        let key_field = builder.api_key_field.clone().map(FieldPartitioner::new);
+        let input = input.filter(move |event| {
+            let valid = match key_field
+                .as_ref()
+                .map(|key_field| key_field.validate(event))
+            {
+                Some(Err(reason)) => {
+                    emit!(DatadogLogsInvalidApiKey { reason });
+                    false
+                }
+                _ => true,
+            };
+            future::ready(valid)
+        });
+
+        // Batches are only queued by priority if there's a field to read it from, sparing the cost
+        // of sorting every batch otherwise.
+        let batcher_control = self.batcher_control.clone();
+        let batches = match self.priority_field {
+            Some(priority_field) => input
+                .batched_partitioned(partitioner, || {
+                    let priority_field = priority_field.clone();
+                    batch_settings.with_priority_queue(HttpJsonBatchSizer, move |event: &Event| {
+                        event_priority(event, &priority_field)
+                    })
+                })
+                .with_control(batcher_control)
+                .boxed(),
+            None => input
+                .batched_partitioned(partitioner, || {
+                    batch_settings.as_item_size_config(HttpJsonBatchSizer)
+                })
+                .with_control(batcher_control)
+                .boxed(),
+        };
+
+        // While the sink is paused, each batch is held back before being built into requests, which
+        // stops the input from being read so that events accumulate in the buffer.
+        let pause = self.pause;
+        let input = batches.then(move |batch| {
+            let pause = pause.clone();
+            async move {
+                pause.wait_until_resumed().await;
+                batch
+            }
         });
-        input
+        let retry_budget = self.retry_budget;
+        let sent = input
             .concurrent_map(default_request_builder_concurrency_limit(), move |input| {
                 let builder = Arc::clone(&builder);
 
                 Box::pin(async move {
-                    let (api_key, events) = input;
-                    let api_key = api_key.unwrap_or_else(|| Arc::clone(&builder.default_api_key));
-
-                    builder.build_request(events, api_key)
+                    let (key, events) = input;
+                    let mut components = CompositePartitioner::split(key.as_deref());
+                    let api_key = components
+                        .next()
+                        .flatten()
+                        .map(Arc::from)
+                        .unwrap_or_else(|| Arc::clone(&builder.default_api_key));
+                    let site = components.next().flatten().map(Arc::from);
+
+                    builder.build_request(events, api_key, site)
                 })
             })
-            .filter_map(|request| async move {
-                match request {
-                    Err(error) => {
-                        emit!(SinkRequestBuildError { error });
-                        None
+            .filter_map(move |request| {
+                let retry_budget = retry_budget.clone();
+                async move {
+                    match request {
+                        Err(error) => {
+                            emit!(SinkRequestBuildError {
+                                error_code: error.error_code(),
+                                error,
+                            });
+                            None
+                        }
+                        Ok(reqs) => {
+                            if let Some(retry_budget) = retry_budget {
+                                reqs.iter().for_each(|_| retry_budget.deposit());
+                            }
+                            Some(futures::stream::iter(reqs))
+                        }
                     }
-                    Ok(reqs) => Some(futures::stream::iter(reqs)),
                 }
             })
             .flatten()
             .into_driver(self.service)
             .protocol(self.protocol)
-            .run()
-            .await
+            .run();
+
+        // Signals are listened to for as long as the sink runs.
+        let flushes = flush_on_signal(self.flush_on_signal, self.batcher_control);
+        let sent = async move {
+            tokio::select! {
+                result = sent => result,
+                () = flushes => unreachable!("flushes are requested until the sink stops"),
+            }
+        };
+
+        let (sent, dlq_sent) = futures::join!(sent, dlq);
+        sent.and(dlq_sent)
     }
 }
 
+/// Returns the priority of an event within its batch, read from `priority_field`. Events without
+/// the field, or where it doesn't hold an integer, have a priority of 0.
+fn event_priority(event: &Event, priority_field: &OwnedTargetPath) -> i64 {
+    event
+        .maybe_as_log()
+        .and_then(|log| log.get(priority_field))
+        .and_then(Value::as_integer)
+        .unwrap_or(0)
+}
+
+/// Requests a flush of the batches in progress every time Vector receives `SIGUSR1`, if enabled.
+/// Never completes.
+async fn flush_on_signal(enabled: bool, batcher_control: BatcherControl) {
+    #[cfg(unix)]
+    if enabled {
+        use tokio::signal::unix::{signal, SignalKind};
+
+        match signal(SignalKind::user_defined1()) {
+            Ok(mut signals) => {
+                while signals.recv().await.is_some() {
+                    debug!(message = "Flushing batches on SIGUSR1.");
+                    batcher_control.flush_now();
+                }
+            }
+            Err(error) => error!(
+                message = "Failed to set up SIGUSR1 handler; batches won't be flushed on signal.",
+                %error,
+            ),
+        }
+    }
+    #[cfg(not(unix))]
+    let _ = (enabled, batcher_control);
+
+    future::pending().await
+}
+
 #[async_trait]
 impl<S> StreamSink<Event> for LogSink<S>
 where
@@ -434,22 +1533,41 @@ where
 #[cfg(test)]
 mod tests {
 
-    use std::sync::Arc;
+    use std::{
+        collections::{HashSet, VecDeque},
+        sync::Arc,
+    };
 
     use chrono::Utc;
+    use sha2::{Digest, Sha256};
     use vector_lib::{
         config::{LegacyKey, LogNamespace},
-        event::{Event, EventMetadata, LogEvent},
+        event::{Event, EventMetadata, LogEvent, MetricValue},
+        json_size::JsonSize,
+        metrics::Controller,
         schema::{meaning, Definition},
+        EstimatedJsonEncodedSizeOf,
     };
     use vrl::{
         core::Value,
-        event_path, metadata_path, owned_value_path, value,
+        event_path, metadata_path, owned_value_path,
+        path::OwnedTargetPath,
+        value,
         value::{kind::Collection, Kind},
     };
 
-    use super::{normalize_as_agent_event, normalize_event};
-    use crate::common::datadog::DD_RESERVED_SEMANTIC_ATTRS;
+    use super::{
+        api_key_prefix, normalize_as_agent_event, normalize_event, path_is_field,
+        path_matches_segments, serialize_with_capacity, serialize_with_exact_capacity, strip_nulls,
+        trace_context_headers, CompositePartitioner, EventPartitioner, FieldPartitioner,
+        RequestBuildError, SiteFieldPartitioner, DEFAULT_CONFLICT_RENAME_PREFIX, SPAN_ID_FIELD,
+        TRACE_ID_FIELD, TRACE_STATE_FIELD,
+    };
+    use crate::sinks::prelude::Partitioner;
+    use crate::{
+        common::datadog::DD_RESERVED_SEMANTIC_ATTRS,
+        sinks::datadog::logs::config::DdtagsInvalidValueAction,
+    };
 
     fn assert_normalized_log_has_expected_attrs(log: &LogEvent) {
         assert!(log
@@ -464,9 +1582,11 @@ mod tests {
             "ddtags",
             "service",
             "status",
+            "ddsource",
         ] {
             assert!(log.contains(event_path!(attr)), "missing {}", attr);
         }
+        assert!(!log.contains(event_path!("source")));
 
         assert_eq!(
             log.get(event_path!("ddtags")).expect("should have tags"),
@@ -528,7 +1648,11 @@ mod tests {
         log.insert(event_path!("foo"), "bar");
 
         let mut event = Event::Log(log);
-        normalize_event(&mut event);
+        normalize_event(
+            &mut event,
+            DEFAULT_CONFLICT_RENAME_PREFIX,
+            Default::default(),
+        );
 
         let log = event.as_log();
 
@@ -537,6 +1661,48 @@ mod tests {
         assert!(!log.contains(event_path!("hostname")));
     }
 
+    #[test]
+    fn normalize_event_parses_timestamps() {
+        let cases = [
+            (
+                Value::from("2024-01-15T10:30:00Z"),
+                Value::Integer(1_705_314_600_000),
+            ),
+            (
+                Value::from("2024-01-15T11:30:00.250+01:00"),
+                Value::Integer(1_705_314_600_250),
+            ),
+            (
+                Value::Integer(1_705_314_600),
+                Value::Integer(1_705_314_600_000),
+            ),
+            (
+                Value::Integer(1_705_314_600_250),
+                Value::Integer(1_705_314_600_250),
+            ),
+            // Strings that aren't RFC 3339 timestamps are left untouched.
+            (Value::from("yesterday"), Value::from("yesterday")),
+        ];
+
+        for (timestamp, expected) in cases {
+            let mut log = LogEvent::default();
+            log.insert(event_path!("timestamp"), timestamp.clone());
+            let mut event = Event::Log(log);
+            normalize_event(
+                &mut event,
+                DEFAULT_CONFLICT_RENAME_PREFIX,
+                Default::default(),
+            );
+
+            assert_eq!(
+                event.as_log().get(event_path!("timestamp")),
+                Some(&expected),
+                "{:?}",
+                timestamp
+            );
+        }
+    }
+
     #[test]
     fn normalize_event_normalizes_legacy_namespace() {
         let definition = Definition::new_with_default_metadata(
@@ -563,7 +1729,11 @@ mod tests {
         assert!(log.namespace() == LogNamespace::Legacy);
 
         let mut event = Event::Log(log);
-        normalize_event(&mut event);
+        normalize_event(
+            &mut event,
+            DEFAULT_CONFLICT_RENAME_PREFIX,
+            Default::default(),
+        );
 
         assert_normalized_log_has_expected_attrs(event.as_log());
     }
@@ -574,8 +1744,12 @@ mod tests {
             LogEvent::from_parts(value!("the_message"), agent_event_metadata(definition))
         });
 
-        normalize_event(&mut event);
-        normalize_as_agent_event(&mut event);
+        normalize_event(
+            &mut event,
+            DEFAULT_CONFLICT_RENAME_PREFIX,
+            Default::default(),
+        );
+        normalize_as_agent_event(&mut event, &HashSet::new());
 
         assert_normalized_log_has_expected_attrs(event.as_log());
         assert_only_reserved_fields_at_root(event.as_log());
@@ -585,6 +1759,136 @@ mod tests {
         );
     }
 
+    #[test]
+    fn normalize_event_renames_conflicting_reserved_attrs() {
+        for prefix in [DEFAULT_CONFLICT_RENAME_PREFIX, "__orig__"] {
+            let definition = Definition::new_with_default_metadata(
+                Kind::object(Collection::empty()),
+                [LogNamespace::Legacy],
+            );
+            let mut log = LogEvent::new_with_metadata(agent_event_metadata(definition));
+            log.insert(event_path!("severity"), "the_severity");
+            // `status` is where the severity is expected, so the existing value must be preserved.
+            log.insert(event_path!("status"), "the_status");
+
+            let mut event = Event::Log(log);
+            normalize_event(&mut event, prefix, Default::default());
+
+            let log = event.as_log();
+            let renamed = format!("{}severity", prefix);
+            assert_eq!(
+                log.get(event_path!("status")),
+                Some(&value!("the_severity"))
+            );
+            assert_eq!(
+                log.get(event_path!(renamed.as_str())),
+                Some(&value!("the_status"))
+            );
+            assert!(!log.contains(event_path!("severity")));
+        }
+    }
+
+    #[test]
+    fn normalize_event_promotes_nested_source() {
+        let definition = Definition::new_with_default_metadata(
+            Kind::object(Collection::empty()),
+            [LogNamespace::Legacy],
+        )
+        .with_event_field(
+            &owned_value_path!("origin"),
+            Kind::object(Collection::empty()),
+            None,
+        )
+        .with_event_field(
+            &owned_value_path!("origin", "integration"),
+            Kind::bytes(),
+            Some(meaning::SOURCE),
+        );
+        let mut log = LogEvent::new_with_metadata(
+            EventMetadata::default().with_schema_definition(&Arc::new(definition)),
+        );
+        log.insert(event_path!("message"), "the_message");
+        log.insert(event_path!("origin", "integration"), "nginx");
+        log.insert(event_path!("origin", "region"), "us-east-1");
+
+        let mut event = Event::Log(log);
+        normalize_event(
+            &mut event,
+            DEFAULT_CONFLICT_RENAME_PREFIX,
+            Default::default(),
+        );
+        assert_eq!(
+            event.as_log().get(event_path!("ddsource")),
+            Some(&value!("nginx"))
+        );
+        assert!(!event
+            .as_log()
+            .contains(event_path!("origin", "integration")));
+
+        // `ddsource` stays at the root when conforming as the Agent.
+        normalize_as_agent_event(&mut event, &HashSet::new());
+        let log = event.as_log();
+        assert_eq!(log.get(event_path!("ddsource")), Some(&value!("nginx")));
+        assert_eq!(
+            log.get(event_path!("message")),
+            Some(&value!({
+                "message": "the_message",
+                "origin": { "region": "us-east-1" },
+            }))
+        );
+    }
+
+    #[test]
+    fn normalize_as_agent_event_keeps_excluded_fields_at_root() {
+        let mut log = LogEvent::from("the_message");
+        log.insert(event_path!("_internal_vector_id"), "abc123");
+        log.insert(event_path!("region"), "us-east-1");
+        let mut event = Event::Log(log);
+
+        let exclude_fields = HashSet::from(["_internal_vector_id".to_string()]);
+        normalize_as_agent_event(&mut event, &exclude_fields);
+
+        let log = event.as_log();
+        assert_eq!(
+            log.get(event_path!("_internal_vector_id")),
+            Some(&value!("abc123"))
+        );
+        assert_eq!(
+            log.get(event_path!("message")),
+            Some(&value!({
+                "message": "the_message",
+                "region": "us-east-1",
+            }))
+        );
+    }
+
+    #[test]
+    fn normalize_event_handles_tags_with_commas() {
+        for (action, expected) in [
+            (DdtagsInvalidValueAction::Drop, "env:prod,version:1.2"),
+            (
+                DdtagsInvalidValueAction::Replace,
+                "env:prod,team:a_region:us,version:1.2",
+            ),
+        ] {
+            let mut log = LogEvent::default();
+            log.insert(
+                event_path!("ddtags"),
+                value!(["env:prod", "team:a,region:us", "version:1.2"]),
+            );
+
+            let mut event = Event::Log(log);
+            normalize_event(&mut event, DEFAULT_CONFLICT_RENAME_PREFIX, action);
+
+            assert_eq!(
+                event.as_log().get(event_path!("ddtags")),
+                Some(&value!(expected)),
+                "{:?}",
+                action
+            );
+        }
+    }
+
     fn prepare_event_vector_namespace(log_generator: fn(Definition) -> LogEvent) -> Event {
         let definition =
             Definition::new_with_default_metadata(Kind::bytes(), [LogNamespace::Vector]);
@@ -620,8 +1924,12 @@ mod tests {
             log
         });
 
-        normalize_event(&mut event);
-        normalize_as_agent_event(&mut event);
+        normalize_event(
+            &mut event,
+            DEFAULT_CONFLICT_RENAME_PREFIX,
+            Default::default(),
+        );
+        normalize_as_agent_event(&mut event, &HashSet::new());
 
         assert_normalized_log_has_expected_attrs(event.as_log());
         assert_only_reserved_fields_at_root(event.as_log());
@@ -678,8 +1986,12 @@ mod tests {
         log.insert(event_path!("field_a"), "replaced_field_a_value");
         log.insert(event_path!("field_c"), "replaced_field_c_value");
         let mut event = Event::Log(log);
-        normalize_event(&mut event);
-        normalize_as_agent_event(&mut event);
+        normalize_event(
+            &mut event,
+            DEFAULT_CONFLICT_RENAME_PREFIX,
+            Default::default(),
+        );
+        normalize_as_agent_event(&mut event, &HashSet::new());
 
         let log = event.as_log();
         assert_normalized_log_has_expected_attrs(log);
@@ -711,8 +2023,12 @@ mod tests {
 
         // normalize and validate...
         let mut event = Event::Log(log);
-        normalize_event(&mut event);
-        normalize_as_agent_event(&mut event);
+        normalize_event(
+            &mut event,
+            DEFAULT_CONFLICT_RENAME_PREFIX,
+            Default::default(),
+        );
+        normalize_as_agent_event(&mut event, &HashSet::new());
 
         // that all fields placed at the root no longer exist there
         let log = event.as_log();
@@ -738,4 +2054,302 @@ mod tests {
             }))
         );
     }
+
+    fn events_to_serialize(messages: &[&str]) -> VecDeque<(Event, JsonSize)> {
+        messages
+            .iter()
+            .map(|message| {
+                let mut log = LogEvent::default();
+                log.insert(event_path!("message"), *message);
+                (Event::Log(log), JsonSize::zero())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn serialize_json_array() {
+        let mut events = events_to_serialize(&["one", "two"]);
+
+        let (serialized, buf, _) =
+            serialize_with_capacity(&mut events, 1_000, false, false, None).unwrap();
+
+        assert_eq!(serialized.len(), 2);
+        assert!(events.is_empty());
+        assert_eq!(buf, br#"[{"message":"one"},{"message":"two"}]"#);
+    }
+
+    #[test]
+    fn serialize_ndjson() {
+        let mut events = events_to_serialize(&["one", "two", "three"]);
+
+        // Leave room for the first two events only.
+        let (serialized, buf, _) =
+            serialize_with_capacity(&mut events, 40, true, false, None).unwrap();
+
+        assert_eq!(serialized.len(), 2);
+        assert_eq!(events.len(), 1);
+        assert_eq!(buf, b"{\"message\":\"one\"}\n{\"message\":\"two\"}\n");
+    }
+
+    #[test]
+    fn field_partitioner_reads_api_key() {
+        let partitioner =
+            FieldPartitioner::new(OwnedTargetPath::event(owned_value_path!("_dd_api_key")));
+        let event = |key: Value| {
+            let mut log = LogEvent::default();
+            log.insert(event_path!("_dd_api_key"), key);
+            Event::Log(log)
+        };
+
+        assert_eq!(
+            partitioner.partition(&event(value!("tenant-a"))).as_deref(),
+            Some("tenant-a")
+        );
+        // Events without the field fall back to the default API key.
+        assert_eq!(
+            partitioner.partition(&Event::Log(LogEvent::default())),
+            None
+        );
+
+        for invalid in [value!(""), value!("tenant a"), value!("tenänt"), value!(42)] {
+            assert!(partitioner.validate(&event(invalid)).is_err());
+        }
+    }
+
+    #[test]
+    fn composite_partitioner_round_trips_components() {
+        let partitioner = CompositePartitioner::new(vec![
+            Box::new(EventPartitioner),
+            Box::new(SiteFieldPartitioner::new(OwnedTargetPath::event(
+                owned_value_path!("_dd_site"),
+            ))),
+        ]);
+        let event = |api_key: Option<&str>, site: Option<Value>| {
+            let mut log = LogEvent::default();
+            if let Some(site) = site {
+                log.insert(event_path!("_dd_site"), site);
+            }
+            if let Some(api_key) = api_key {
+                log.metadata_mut().set_datadog_api_key(Arc::from(api_key));
+            }
+            Event::Log(log)
+        };
+        let components = |event: &Event| {
+            let key = partitioner.partition(event);
+            CompositePartitioner::split(key.as_deref())
+                .take(2)
+                .map(|component| component.map(str::to_owned))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            components(&event(Some("key"), Some(value!("datadoghq.eu")))),
+            vec![Some("key".to_owned()), Some("datadoghq.eu".to_owned())]
+        );
+        assert_eq!(
+            components(&event(None, Some(value!("datadoghq.eu")))),
+            vec![None, Some("datadoghq.eu".to_owned())]
+        );
+        assert_eq!(
+            components(&event(Some("key"), None)),
+            vec![Some("key".to_owned()), None]
+        );
+        assert_eq!(partitioner.partition(&event(None, None)), None);
+        // Sites which aren't Datadog sites are ignored.
+        assert_eq!(
+            components(&event(Some("key"), Some(value!("evil.com/path")))),
+            vec![Some("key".to_owned()), None]
+        );
+        assert_eq!(
+            components(&event(Some("key"), Some(value!("attacker.example.com")))),
+            vec![Some("key".to_owned()), None]
+        );
+        assert_eq!(
+            components(&event(Some("key"), Some(value!("us5.datadoghq.com")))),
+            vec![Some("key".to_owned()), Some("us5.datadoghq.com".to_owned())]
+        );
+    }
+
+    #[test]
+    fn path_matches_nested_segments() {
+        let path = OwnedTargetPath::event(owned_value_path!("network", "client"));
+        assert!(path_matches_segments(&path, &["network", "client"]));
+        assert!(!path_matches_segments(&path, &["network", "host"]));
+        assert!(!path_matches_segments(&path, &["client", "network"]));
+
+        // Prefixes of the path, or paths it is a prefix of, don't match.
+        assert!(!path_matches_segments(&path, &["network"]));
+        assert!(!path_matches_segments(&path, &["network", "client", "ip"]));
+        assert!(!path_is_field(&path, "network"));
+
+        let metadata = OwnedTargetPath::metadata(owned_value_path!("network", "client"));
+        assert!(!path_matches_segments(&metadata, &["network", "client"]));
+
+        let field = OwnedTargetPath::event(owned_value_path!("host"));
+        assert!(path_is_field(&field, "host"));
+        assert!(!path_is_field(&field, "hostname"));
+    }
+
+    #[test]
+    fn api_key_prefix_is_truncated() {
+        assert_eq!(
+            &*api_key_prefix("0123456789abcdef0123456789abcdef"),
+            "012345"
+        );
+        assert_eq!(&*api_key_prefix("0123"), "0123");
+        assert_eq!(&*api_key_prefix("clé-clé-clé"), "clé-cl");
+    }
+
+    #[test]
+    fn request_build_errors_have_distinct_codes() {
+        let too_big = RequestBuildError::CompressedPayloadTooBig {
+            compressed_size: 10,
+            limit: 5,
+        };
+        assert_eq!(too_big.error_code(), "payload_too_big");
+        let io = RequestBuildError::from(std::io::Error::other("closed"));
+        assert_eq!(io.error_code(), "io_error");
+        let json = RequestBuildError::from(serde_json::from_str::<u8>("x").unwrap_err());
+        assert_eq!(json.error_code(), "json_error");
+    }
+
+    #[test]
+    fn strip_nulls_removes_nested_nulls() {
+        let mut log = LogEvent::from(value!({
+            "message": "hello",
+            "empty": null,
+            "attributes": {
+                "http": { "status": 200, "route": null },
+                "spans": [{ "id": 1, "parent": null }, null],
+            },
+        }));
+
+        strip_nulls(log.value_mut());
+
+        let json = serde_json::to_value(&log).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "message": "hello",
+                "attributes": {
+                    "http": { "status": 200 },
+                    "spans": [{ "id": 1 }, null],
+                },
+            })
+        );
+        let serialized = serde_json::to_string(&log).unwrap();
+        assert!(!serialized.contains("route"));
+        assert!(!serialized.contains("parent"));
+    }
+
+    #[test]
+    fn trace_context_from_metadata() {
+        let mut log = LogEvent::from("message");
+        log.insert(
+            metadata_path!(TRACE_ID_FIELD),
+            "4BF92F3577B34DA6A3CE929D0E0E4736",
+        );
+        log.insert(metadata_path!(SPAN_ID_FIELD), "00f067aa0ba902b7");
+        log.insert(metadata_path!(TRACE_STATE_FIELD), "vendor=value");
+
+        let headers = trace_context_headers(Some(&Event::Log(log)));
+        assert_eq!(
+            headers["traceparent"],
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01"
+        );
+        assert_eq!(headers["tracestate"], "vendor=value");
+    }
+
+    #[test]
+    fn trace_context_generates_missing_ids() {
+        let mut log = LogEvent::from("message");
+        // All-zero IDs are invalid.
+        log.insert(metadata_path!(SPAN_ID_FIELD), "0000000000000000");
+
+        for event in [None, Some(Event::Log(log))] {
+            let headers = trace_context_headers(event.as_ref());
+            let traceparent = headers["traceparent"].to_str().unwrap();
+            let parts = traceparent.split('-').collect::<Vec<_>>();
+            assert_eq!(parts.len(), 4, "{traceparent}");
+            assert_eq!((parts[0], parts[3]), ("00", "01"));
+            assert_eq!(parts[1].len(), 32);
+            assert_eq!(parts[2].len(), 16);
+            assert_ne!(parts[2], "0000000000000000");
+            assert!(headers.get("tracestate").is_none());
+        }
+    }
+
+    #[test]
+    fn serialize_exact_matches_estimated() {
+        let messages = ["one", "двадцать два", "三十三", "👋🌍"];
+        for ndjson in [false, true] {
+            for max_payload_bytes in [1, 20, 40, 60, 1_000] {
+                let mut estimated = events_to_serialize(&messages);
+                let mut exact = events_to_serialize(&messages);
+                let mut scratch = Vec::new();
+                let mut estimated_hasher = Sha256::new();
+                let mut exact_hasher = Sha256::new();
+
+                let (_, estimated_buf, _) = serialize_with_capacity(
+                    &mut estimated,
+                    max_payload_bytes,
+                    ndjson,
+                    false,
+                    Some(&mut estimated_hasher),
+                )
+                .unwrap();
+                let (_, exact_buf, _) = serialize_with_exact_capacity(
+                    &mut exact,
+                    max_payload_bytes,
+                    ndjson,
+                    false,
+                    Some(&mut exact_hasher),
+                    &mut scratch,
+                )
+                .unwrap();
+
+                assert_eq!(exact_buf, estimated_buf);
+                assert_eq!(exact.len(), estimated.len());
+                // Only the bytes left in the buffer are hashed, not those of the events that
+                // didn't fit.
+                let digest = Sha256::digest(&exact_buf);
+                assert_eq!(estimated_hasher.finalize(), digest);
+                assert_eq!(exact_hasher.finalize(), digest);
+            }
+        }
+    }
+
+    #[test]
+    fn serialize_tracks_estimated_size_error() {
+        vector_lib::metrics::init_test();
+
+        // Control characters and quotes are escaped, and invalid UTF-8 is replaced, so the size of
+        // these messages is underestimated by 6 bytes per repetition and 2 bytes per byte.
+        let messages = [
+            Value::from("\u{1}👋\"".repeat(1_000)),
+            Value::Bytes(vec![0xE9; 1_000].into()),
+        ];
+        let mut events = messages
+            .into_iter()
+            .map(|message| {
+                let mut log = LogEvent::default();
+                log.insert(event_path!("message"), message);
+                let size = log.estimated_json_encoded_size_of();
+                (Event::Log(log), size)
+            })
+            .collect::<VecDeque<_>>();
+
+        serialize_with_capacity(&mut events, 1_000_000, false, true, None).unwrap();
+
+        let metrics = Controller::get().unwrap().capture_metrics();
+        let histogram = metrics
+            .iter()
+            .find(|metric| metric.name() == "estimated_size_error_bytes")
+            .expect("missing estimated size error histogram");
+        let MetricValue::AggregatedHistogram { count, sum, .. } = histogram.value() else {
+            panic!("unexpected metric value: {:?}", histogram.value());
+        };
+        assert_eq!(*count, 2);
+        assert_eq!(*sum, 8_000.0);
+    }
 }
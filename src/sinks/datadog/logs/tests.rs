@@ -1,6 +1,13 @@
 #![allow(clippy::print_stdout)] // tests
 
-use std::sync::Arc;
+use std::{
+    num::NonZeroUsize,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
 
 use bytes::Bytes;
 use chrono::Utc;
@@ -10,9 +17,12 @@ use futures::{
 };
 use http::request::Parts;
 use indoc::indoc;
+use sha2::{Digest, Sha256};
 use vector_lib::{
-    config::{init_telemetry, Tags, Telemetry},
+    config::{init_telemetry, proxy::ProxyConfig, Tags, Telemetry},
     event::{BatchNotifier, BatchStatus, Event, LogEvent},
+    sink::StreamSink,
+    stream::{batcher::BatcherControl, BatcherSettings},
 };
 
 use crate::sinks::datadog::test_utils::{test_server, ApiStatus};
@@ -20,10 +30,11 @@ use crate::{
     common::datadog,
     config::{SinkConfig, SinkContext},
     extra_context::ExtraContext,
-    http::HttpError,
+    http::{HttpClient, HttpError},
     sinks::{
+        util::circuit_breaker::CircuitBreaker,
         util::retries::RetryLogic,
-        util::test::{load_sink, load_sink_with_context},
+        util::test::{build_test_server_generic, load_sink, load_sink_with_context},
     },
     test_util::{
         components::{
@@ -35,7 +46,14 @@ use crate::{
     tls::TlsError,
 };
 
-use super::{super::DatadogApiError, config::DatadogLogsConfig, service::LogApiRetry};
+use super::{
+    super::DatadogApiError,
+    config::{DatadogLogsConfig, MAX_PAYLOAD_BYTES},
+    dlq::DlqConfig,
+    payload_limit::PayloadLimit,
+    service::{LogApiRetry, LogApiService},
+    sink::LogSinkBuilder,
+};
 
 fn event_with_api_key(msg: &str, key: &str) -> Event {
     let mut e = Event::Log(LogEvent::from(msg));
@@ -405,7 +423,7 @@ async fn headers_inner(api_status: ApiStatus) {
 #[tokio::test]
 /// Assert the RetryLogic implementation of LogApiRetry
 async fn error_is_retriable() {
-    let retry = LogApiRetry;
+    let retry = LogApiRetry::default();
 
     // not retry-able
     assert!(!retry.is_retriable_error(&DatadogApiError::BadRequest));
@@ -434,6 +452,58 @@ async fn error_is_retriable() {
     //       the crates they originate from.
 }
 
+#[tokio::test]
+/// Assert that LogApiRetry stops retrying once the circuit breaker opens
+async fn error_is_not_retriable_with_open_circuit() {
+    let circuit_breaker = Arc::new(CircuitBreaker::new(2, Duration::from_secs(30)));
+    let retry = LogApiRetry::new(Some(Arc::clone(&circuit_breaker)));
+
+    circuit_breaker.record_failure();
+    assert!(retry.is_retriable_error(&DatadogApiError::ServerError));
+
+    circuit_breaker.record_failure();
+    assert!(!retry.is_retriable_error(&DatadogApiError::ServerError));
+}
+
+#[tokio::test]
+/// Assert that failed requests are given up on once the retry budget is spent
+///
+/// A single request only funds a fraction of a retry, so it's sent once and its events are
+/// reported as errored.
+async fn drops_failed_requests_with_spent_retry_budget() {
+    let (mut config, cx) = load_sink::<DatadogLogsConfig>(indoc! {r#"
+            default_api_key = "atoken"
+            compression = "none"
+            retry_budget_token_ratio = 0.1
+        "#})
+    .unwrap();
+
+    let addr = next_addr();
+    config.local_dd_common.endpoint = Some(format!("http://{}", addr));
+
+    let (sink, _) = config.build(cx).await.unwrap();
+
+    let requests = Arc::new(AtomicUsize::new(0));
+    let (_rx, _trigger, server) = build_test_server_generic(addr, {
+        let requests = Arc::clone(&requests);
+        move || {
+            requests.fetch_add(1, Ordering::Relaxed);
+            http::Response::builder()
+                .status(http::StatusCode::SERVICE_UNAVAILABLE)
+                .body(hyper::Body::empty())
+                .unwrap()
+        }
+    });
+    tokio::spawn(server);
+
+    let (batch, receiver) = BatchNotifier::new_with_receiver();
+    let (_expected, events) = random_lines_with_stream(100, 5, Some(batch));
+
+    sink.run(events).await.unwrap();
+    assert_eq!(receiver.await, BatchStatus::Errored);
+    assert_eq!(requests.load(Ordering::Relaxed), 1);
+}
+
 #[tokio::test]
 async fn does_not_send_too_big_payloads() {
     crate::test_util::trace_init();
@@ -570,3 +640,722 @@ async fn override_global_options() {
         .iter()
         .all(|value| value.to_str().unwrap() == "local-key"));
 }
+
+#[tokio::test]
+async fn sends_ndjson_and_retries_partially_accepted_events() {
+    let (mut config, cx) = load_sink::<DatadogLogsConfig>(indoc! {r#"
+            default_api_key = "atoken"
+            compression = "none"
+            use_v2_api = true
+        "#})
+    .unwrap();
+
+    let addr = next_addr();
+    let endpoint = format!("http://{}", addr);
+    config.local_dd_common.endpoint = Some(endpoint.clone());
+
+    let (sink, _) = config.build(cx).await.unwrap();
+
+    // The first request is partially accepted: the second event is rejected with a retriable
+    // status, and the fourth one with a status that isn't worth retrying.
+    let requests = Arc::new(AtomicUsize::new(0));
+    let (rx, _trigger, server) = build_test_server_generic(addr, move || {
+        let (status, body) = if requests.fetch_add(1, Ordering::Relaxed) == 0 {
+            (
+                http::StatusCode::MULTI_STATUS,
+                r#"{"errors":[{"index":1,"status":500},{"index":3,"status":400}]}"#,
+            )
+        } else {
+            (http::StatusCode::ACCEPTED, "")
+        };
+        http::Response::builder()
+            .status(status)
+            .body(hyper::Body::from(body))
+            .unwrap()
+    });
+    tokio::spawn(server);
+
+    let (batch, receiver) = BatchNotifier::new_with_receiver();
+    let (expected, events) = random_lines_with_stream(100, 5, Some(batch));
+
+    sink.run(events).await.unwrap();
+    // One of the events was dropped by the intake.
+    assert_eq!(receiver.await, BatchStatus::Rejected);
+
+    let output = rx.take(2).collect::<Vec<_>>().await;
+    let messages = |body: &Bytes| {
+        body.split(|byte| *byte == b'\n')
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                let event: serde_json::Value = serde_json::from_slice(line).unwrap();
+                event["message"].as_str().unwrap().to_string()
+            })
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        output[0].0.headers.get("Content-Type").unwrap(),
+        "application/x-ndjson"
+    );
+    assert!(output[0].1.ends_with(b"\n"));
+    assert_eq!(messages(&output[0].1), expected);
+    // Only the event rejected with a retriable status is sent again.
+    assert_eq!(messages(&output[1].1), vec![expected[1].clone()]);
+}
+
+#[tokio::test]
+async fn finalizes_partially_accepted_events_on_their_own() {
+    let (mut config, cx) = load_sink::<DatadogLogsConfig>(indoc! {r#"
+            default_api_key = "atoken"
+            compression = "none"
+            use_v2_api = true
+        "#})
+    .unwrap();
+
+    let addr = next_addr();
+    let endpoint = format!("http://{}", addr);
+    config.local_dd_common.endpoint = Some(endpoint.clone());
+
+    let (sink, _) = config.build(cx).await.unwrap();
+
+    let requests = Arc::new(AtomicUsize::new(0));
+    let (_rx, _trigger, server) = build_test_server_generic(addr, move || {
+        let (status, body) = if requests.fetch_add(1, Ordering::Relaxed) == 0 {
+            (
+                http::StatusCode::MULTI_STATUS,
+                r#"{"errors":[{"index":1,"status":500},{"index":3,"status":400}]}"#,
+            )
+        } else {
+            (http::StatusCode::ACCEPTED, "")
+        };
+        http::Response::builder()
+            .status(status)
+            .body(hyper::Body::from(body))
+            .unwrap()
+    });
+    tokio::spawn(server);
+
+    // Each event has its own batch, so that its status can be told apart from the others.
+    let (events, receivers): (Vec<_>, Vec<_>) = (0..5)
+        .map(|index| {
+            let (batch, receiver) = BatchNotifier::new_with_receiver();
+            let event = LogEvent::from(format!("event {}", index)).with_batch_notifier(&batch);
+            (Event::Log(event), receiver)
+        })
+        .unzip();
+
+    sink.run_events(events).await.unwrap();
+
+    let statuses = futures::future::join_all(receivers).await;
+    assert_eq!(
+        statuses,
+        vec![
+            BatchStatus::Delivered,
+            BatchStatus::Delivered,
+            BatchStatus::Delivered,
+            BatchStatus::Rejected,
+            BatchStatus::Delivered,
+        ]
+    );
+}
+
+#[tokio::test]
+async fn splits_requests_rejected_as_too_large() {
+    let (mut config, cx) = load_sink::<DatadogLogsConfig>(indoc! {r#"
+            default_api_key = "atoken"
+            compression = "none"
+            use_v2_api = true
+        "#})
+    .unwrap();
+
+    let addr = next_addr();
+    let endpoint = format!("http://{}", addr);
+    config.local_dd_common.endpoint = Some(endpoint.clone());
+
+    let (sink, _) = config.build(cx).await.unwrap();
+
+    // The first request, holding all of the events, is rejected as too large.
+    let requests = Arc::new(AtomicUsize::new(0));
+    let (rx, _trigger, server) = build_test_server_generic(addr, move || {
+        let status = if requests.fetch_add(1, Ordering::Relaxed) == 0 {
+            http::StatusCode::PAYLOAD_TOO_LARGE
+        } else {
+            http::StatusCode::ACCEPTED
+        };
+        http::Response::builder()
+            .status(status)
+            .body(hyper::Body::empty())
+            .unwrap()
+    });
+    tokio::spawn(server);
+
+    let (batch, receiver) = BatchNotifier::new_with_receiver();
+    let (expected, events) = random_lines_with_stream(100, 5, Some(batch));
+
+    sink.run(events).await.unwrap();
+    assert_eq!(receiver.await, BatchStatus::Delivered);
+
+    let output = rx.take(4).collect::<Vec<_>>().await;
+    let messages = |body: &Bytes| {
+        body.split(|byte| *byte == b'\n')
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                let event: serde_json::Value = serde_json::from_slice(line).unwrap();
+                event["message"].as_str().unwrap().to_string()
+            })
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(messages(&output[0].1), expected);
+    // The events are then sent two at a time.
+    assert_eq!(messages(&output[1].1), expected[..2]);
+    assert_eq!(messages(&output[2].1), expected[2..4]);
+    assert_eq!(messages(&output[3].1), expected[4..]);
+}
+
+#[tokio::test]
+/// Assert that events are batched by the API key stored in an event field
+///
+/// Events without the field are sent with the default API key, and events with an invalid key are
+/// dropped.
+async fn api_key_field() {
+    let (mut config, cx) = load_sink::<DatadogLogsConfig>(indoc! {r#"
+            default_api_key = "atoken"
+            compression = "none"
+            api_key_field = "._dd_api_key"
+        "#})
+    .unwrap();
+
+    let addr = next_addr();
+    let endpoint = format!("http://{}", addr);
+    config.local_dd_common.endpoint = Some(endpoint.clone());
+
+    let (sink, _) = config.build(cx).await.unwrap();
+
+    let (rx, _trigger, server) = test_server(addr, ApiStatus::OKv2);
+    tokio::spawn(server);
+
+    let event_with_key_field = |message: &str, key: &str| {
+        let mut log = LogEvent::from(message);
+        log.insert("_dd_api_key", key);
+        Event::Log(log)
+    };
+    let events = vec![
+        event_with_key_field("a1", "tenant-a"),
+        event_with_key_field("b1", "tenant-b"),
+        event_with_key_field("a2", "tenant-a"),
+        event_with_key_field("invalid", "not a key"),
+        Event::Log(LogEvent::from("no API key field")),
+    ];
+
+    sink.run_events(events).await.unwrap();
+
+    let mut requests = rx
+        .take(3)
+        .map(|(parts, body)| {
+            let key = parts
+                .headers
+                .get("DD-API-KEY")
+                .unwrap()
+                .to_str()
+                .unwrap()
+                .to_string();
+            let events: Vec<serde_json::Value> = serde_json::from_slice(&body).unwrap();
+            let messages = events
+                .iter()
+                .map(|event| {
+                    // The API key is not sent as part of the event.
+                    assert!(event.get("_dd_api_key").is_none());
+                    event["message"].as_str().unwrap().to_string()
+                })
+                .collect::<Vec<_>>();
+            (key, messages)
+        })
+        .collect::<Vec<_>>()
+        .await;
+    requests.sort();
+
+    assert_eq!(
+        requests,
+        vec![
+            ("atoken".to_string(), vec!["no API key field".to_string()]),
+            (
+                "tenant-a".to_string(),
+                vec!["a1".to_string(), "a2".to_string()]
+            ),
+            ("tenant-b".to_string(), vec!["b1".to_string()]),
+        ]
+    );
+}
+
+#[tokio::test]
+/// Assert that events are batched by the API key computed by a VRL program
+///
+/// Events for which the program returns no key are sent with the default API key.
+async fn api_key_vrl() {
+    let (mut config, cx) = load_sink::<DatadogLogsConfig>(indoc! {r#"
+            default_api_key = "atoken"
+            compression = "none"
+            api_key_vrl = 'if exists(.tenant.id) { "tenant-" + string!(.tenant.id) } else { null }'
+        "#})
+    .unwrap();
+
+    let addr = next_addr();
+    let endpoint = format!("http://{}", addr);
+    config.local_dd_common.endpoint = Some(endpoint.clone());
+
+    let (sink, _) = config.build(cx).await.unwrap();
+
+    let (rx, _trigger, server) = test_server(addr, ApiStatus::OKv2);
+    tokio::spawn(server);
+
+    let event_with_tenant = |message: &str, tenant: &str| {
+        let mut log = LogEvent::from(message);
+        log.insert("tenant.id", tenant);
+        Event::Log(log)
+    };
+    let events = vec![
+        event_with_tenant("a1", "a"),
+        event_with_tenant("b1", "b"),
+        event_with_tenant("a2", "a"),
+        Event::Log(LogEvent::from("no tenant")),
+    ];
+
+    sink.run_events(events).await.unwrap();
+
+    let mut requests = rx
+        .take(3)
+        .map(|(parts, body)| {
+            let key = parts
+                .headers
+                .get("DD-API-KEY")
+                .unwrap()
+                .to_str()
+                .unwrap()
+                .to_string();
+            let events: Vec<serde_json::Value> = serde_json::from_slice(&body).unwrap();
+            let messages = events
+                .iter()
+                .map(|event| event["message"].as_str().unwrap().to_string())
+                .collect::<Vec<_>>();
+            (key, messages)
+        })
+        .collect::<Vec<_>>()
+        .await;
+    requests.sort();
+
+    assert_eq!(
+        requests,
+        vec![
+            ("atoken".to_string(), vec!["no tenant".to_string()]),
+            (
+                "tenant-a".to_string(),
+                vec!["a1".to_string(), "a2".to_string()]
+            ),
+            ("tenant-b".to_string(), vec!["b1".to_string()]),
+        ]
+    );
+}
+
+#[tokio::test]
+/// Assert that a VRL program computing API keys which doesn't compile fails to build the sink
+async fn api_key_vrl_compilation_error() {
+    let (config, cx) = load_sink::<DatadogLogsConfig>(indoc! {r#"
+            default_api_key = "atoken"
+            api_key_vrl = ".tenant.id +"
+        "#})
+    .unwrap();
+
+    assert!(config.build(cx).await.is_err());
+}
+
+#[tokio::test]
+/// Assert that payloads compressed with zstd are sent with the matching content encoding
+async fn zstd_compression() {
+    let (mut config, cx) = load_sink::<DatadogLogsConfig>(indoc! {r#"
+            default_api_key = "atoken"
+            compression = "zstd"
+        "#})
+    .unwrap();
+
+    let addr = next_addr();
+    let endpoint = format!("http://{}", addr);
+    config.local_dd_common.endpoint = Some(endpoint.clone());
+
+    let (sink, _) = config.build(cx).await.unwrap();
+
+    let (rx, _trigger, server) = test_server(addr, ApiStatus::OKv2);
+    tokio::spawn(server);
+
+    let (expected, events) = random_lines_with_stream(100, 10, None);
+    sink.run(events).await.unwrap();
+
+    let (parts, body) = rx.take(1).collect::<Vec<_>>().await.pop().unwrap();
+    assert_eq!(parts.headers.get("Content-Encoding").unwrap(), "zstd");
+
+    let body = zstd::decode_all(&body[..]).unwrap();
+    let events: Vec<serde_json::Value> = serde_json::from_slice(&body).unwrap();
+    let messages = events
+        .iter()
+        .map(|event| event["message"].as_str().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(messages, expected);
+}
+
+#[tokio::test]
+/// Assert that events too large to be sent are routed to the dead-letter queue
+async fn routes_too_large_events_to_dead_letter_queue() {
+    let addr = next_addr();
+    let dlq_addr = next_addr();
+    let (mut config, cx) = load_sink::<DatadogLogsConfig>(indoc! {r#"
+            default_api_key = "atoken"
+            compression = "none"
+        "#})
+    .unwrap();
+    config.local_dd_common.endpoint = Some(format!("http://{}", addr));
+    config.dlq = Some(DlqConfig {
+        uri: format!("http://{}/dead-letters", dlq_addr).parse().unwrap(),
+    });
+
+    let (sink, _) = config.build(cx).await.unwrap();
+
+    let (rx, _trigger, server) = test_server(addr, ApiStatus::OKv2);
+    tokio::spawn(server);
+    let (dlq_rx, _dlq_trigger, dlq_server) = test_server(dlq_addr, ApiStatus::OKv2);
+    tokio::spawn(dlq_server);
+
+    let (batch, receiver) = BatchNotifier::new_with_receiver();
+    let events = vec![
+        Event::Log(LogEvent::from("small").with_batch_notifier(&batch)),
+        Event::Log(LogEvent::from("x".repeat(5_000_000)).with_batch_notifier(&batch)),
+    ];
+    drop(batch);
+
+    sink.run_events(events).await.unwrap();
+    assert_eq!(receiver.await, BatchStatus::Delivered);
+
+    let (_parts, body) = rx.take(1).collect::<Vec<_>>().await.pop().unwrap();
+    let events: Vec<serde_json::Value> = serde_json::from_slice(&body).unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0]["message"], "small");
+
+    let (parts, body) = dlq_rx.take(1).collect::<Vec<_>>().await.pop().unwrap();
+    assert_eq!(parts.uri.path(), "/dead-letters");
+    assert_eq!(
+        parts.headers.get("Content-Type").unwrap(),
+        "application/x-ndjson"
+    );
+    let dead_letter: serde_json::Value =
+        serde_json::from_slice(body.strip_suffix(b"\n").unwrap()).unwrap();
+    assert_eq!(dead_letter["_vector_dlq_reason"], "event_too_large");
+    assert_eq!(dead_letter["message"].as_str().unwrap().len(), 5_000_000);
+}
+
+#[tokio::test]
+/// Assert that events over `max_single_event_bytes` are routed to the dead-letter queue before
+/// being batched
+async fn routes_events_over_size_limit_to_dead_letter_queue() {
+    let addr = next_addr();
+    let dlq_addr = next_addr();
+    let (mut config, cx) = load_sink::<DatadogLogsConfig>(indoc! {r#"
+            default_api_key = "atoken"
+            compression = "none"
+            max_single_event_bytes = 1000
+        "#})
+    .unwrap();
+    config.local_dd_common.endpoint = Some(format!("http://{}", addr));
+    config.dlq = Some(DlqConfig {
+        uri: format!("http://{}/dead-letters", dlq_addr).parse().unwrap(),
+    });
+
+    let (sink, _) = config.build(cx).await.unwrap();
+
+    let (rx, _trigger, server) = test_server(addr, ApiStatus::OKv2);
+    tokio::spawn(server);
+    let (dlq_rx, _dlq_trigger, dlq_server) = test_server(dlq_addr, ApiStatus::OKv2);
+    tokio::spawn(dlq_server);
+
+    let (batch, receiver) = BatchNotifier::new_with_receiver();
+    let events = vec![
+        Event::Log(LogEvent::from("small").with_batch_notifier(&batch)),
+        Event::Log(LogEvent::from("x".repeat(2_000)).with_batch_notifier(&batch)),
+    ];
+    drop(batch);
+
+    sink.run_events(events).await.unwrap();
+    assert_eq!(receiver.await, BatchStatus::Delivered);
+
+    let (_parts, body) = rx.take(1).collect::<Vec<_>>().await.pop().unwrap();
+    let events: Vec<serde_json::Value> = serde_json::from_slice(&body).unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0]["message"], "small");
+
+    let (_parts, body) = dlq_rx.take(1).collect::<Vec<_>>().await.pop().unwrap();
+    let dead_letter: serde_json::Value =
+        serde_json::from_slice(body.strip_suffix(b"\n").unwrap()).unwrap();
+    assert_eq!(dead_letter["_vector_dlq_reason"], "event_over_size_limit");
+    assert_eq!(dead_letter["message"].as_str().unwrap().len(), 2_000);
+}
+
+#[tokio::test]
+/// Assert that requests with identical events are sent with the same `DD-Request-ID`, holding the
+/// hash of their payload
+async fn sends_idempotency_key() {
+    let addr = next_addr();
+    let (mut config, cx) = load_sink::<DatadogLogsConfig>(indoc! {r#"
+            default_api_key = "atoken"
+            compression = "none"
+            send_idempotency_key = true
+        "#})
+    .unwrap();
+    config.local_dd_common.endpoint = Some(format!("http://{}", addr));
+
+    let (rx, _trigger, server) = test_server(addr, ApiStatus::OKv2);
+    tokio::spawn(server);
+
+    let events = vec![
+        Event::Log(LogEvent::from("one")),
+        Event::Log(LogEvent::from("two")),
+    ];
+    for _ in 0..2 {
+        let (sink, _) = config.build(cx.clone()).await.unwrap();
+        sink.run_events(events.clone()).await.unwrap();
+    }
+
+    let requests = rx.take(2).collect::<Vec<_>>().await;
+    let request_ids = requests
+        .iter()
+        .map(|(parts, _body)| parts.headers.get("DD-Request-ID").unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(request_ids[0], request_ids[1]);
+
+    let (_parts, body) = &requests[0];
+    let expected = format!("{:x}", Sha256::digest(body));
+    assert_eq!(request_ids[0], expected.as_str());
+}
+
+#[tokio::test]
+/// Assert that payloads compressed with lz4 are sent as lz4 frames with the matching content
+/// encoding
+async fn lz4_compression() {
+    let (mut config, cx) = load_sink::<DatadogLogsConfig>(indoc! {r#"
+            default_api_key = "atoken"
+            compression = "lz4"
+        "#})
+    .unwrap();
+
+    let addr = next_addr();
+    let endpoint = format!("http://{}", addr);
+    config.local_dd_common.endpoint = Some(endpoint.clone());
+
+    let (sink, _) = config.build(cx).await.unwrap();
+
+    let (rx, _trigger, server) = test_server(addr, ApiStatus::OKv2);
+    tokio::spawn(server);
+
+    let (expected, events) = random_lines_with_stream(100, 10, None);
+    sink.run(events).await.unwrap();
+
+    let (parts, body) = rx.take(1).collect::<Vec<_>>().await.pop().unwrap();
+    assert_eq!(parts.headers.get("Content-Encoding").unwrap(), "lz4");
+
+    let mut decoded = Vec::new();
+    std::io::Read::read_to_end(
+        &mut lz4_flex::frame::FrameDecoder::new(&body[..]),
+        &mut decoded,
+    )
+    .unwrap();
+    let events: Vec<serde_json::Value> = serde_json::from_slice(&decoded).unwrap();
+    let messages = events
+        .iter()
+        .map(|event| event["message"].as_str().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(messages, expected);
+}
+
+#[tokio::test]
+/// Assert that payloads compressed with brotli are sent with the `br` content encoding
+async fn brotli_compression() {
+    let (mut config, cx) = load_sink::<DatadogLogsConfig>(indoc! {r#"
+            default_api_key = "atoken"
+            compression = { algorithm = "brotli", level = 4 }
+        "#})
+    .unwrap();
+
+    let addr = next_addr();
+    let endpoint = format!("http://{}", addr);
+    config.local_dd_common.endpoint = Some(endpoint.clone());
+
+    let (sink, _) = config.build(cx).await.unwrap();
+
+    let (rx, _trigger, server) = test_server(addr, ApiStatus::OKv2);
+    tokio::spawn(server);
+
+    let (expected, events) = random_lines_with_stream(100, 10, None);
+    sink.run(events).await.unwrap();
+
+    let (parts, body) = rx.take(1).collect::<Vec<_>>().await.pop().unwrap();
+    assert_eq!(parts.headers.get("Content-Encoding").unwrap(), "br");
+
+    let mut decoded = Vec::new();
+    std::io::Read::read_to_end(
+        &mut brotli::Decompressor::new(&body[..], 4_096),
+        &mut decoded,
+    )
+    .unwrap();
+    let events: Vec<serde_json::Value> = serde_json::from_slice(&decoded).unwrap();
+    let messages = events
+        .iter()
+        .map(|event| event["message"].as_str().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(messages, expected);
+}
+
+#[tokio::test]
+/// Assert that requests are sent over HTTP/2 to an intake which only accepts HTTP/2
+async fn http2() {
+    use hyper::{
+        service::{make_service_fn, service_fn},
+        Body, Request, Response, Server, StatusCode,
+    };
+
+    let (mut config, cx) = load_sink::<DatadogLogsConfig>(indoc! {r#"
+            default_api_key = "atoken"
+            compression = "none"
+            http2 = true
+        "#})
+    .unwrap();
+
+    let addr = next_addr();
+    let endpoint = format!("http://{}", addr);
+    config.local_dd_common.endpoint = Some(endpoint.clone());
+
+    let (sink, _) = config.build(cx).await.unwrap();
+
+    let (tx, rx) = futures::channel::mpsc::unbounded();
+    let server = Server::bind(&addr)
+        .http2_only(true)
+        .serve(make_service_fn(move |_| {
+            let tx = tx.clone();
+            async move {
+                Ok::<_, hyper::Error>(service_fn(move |request: Request<Body>| {
+                    let tx = tx.clone();
+                    async move {
+                        let (parts, body) = request.into_parts();
+                        let body = hyper::body::to_bytes(body).await?;
+                        tx.unbounded_send((parts.version, body)).unwrap();
+                        let mut response = Response::new(Body::empty());
+                        *response.status_mut() = StatusCode::ACCEPTED;
+                        Ok::<_, hyper::Error>(response)
+                    }
+                }))
+            }
+        }));
+    tokio::spawn(server);
+
+    let (expected, events) = random_lines_with_stream(100, 10, None);
+    sink.run(events).await.unwrap();
+
+    let (version, body) = rx.take(1).collect::<Vec<_>>().await.pop().unwrap();
+    assert_eq!(version, http::Version::HTTP_2);
+
+    let events: Vec<serde_json::Value> = serde_json::from_slice(&body).unwrap();
+    let messages = events
+        .iter()
+        .map(|event| event["message"].as_str().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(messages, expected);
+}
+
+#[tokio::test]
+/// Assert that the batch in progress is flushed on request, such as on `SIGUSR1`, although it
+/// neither reached its size limit nor timed out
+async fn flushes_batch_on_request() {
+    let addr = next_addr();
+    let (mut rx, _trigger, server) = test_server(addr, ApiStatus::OKv1);
+    tokio::spawn(server);
+
+    let client = HttpClient::new(None, &ProxyConfig::default()).unwrap();
+    let uri = format!("http://{addr}/api/v2/logs").parse().unwrap();
+    let payload_limit = Arc::new(PayloadLimit::new(MAX_PAYLOAD_BYTES));
+    let service = LogApiService::new(
+        client,
+        uri,
+        Default::default(),
+        "vector".to_string(),
+        Arc::clone(&payload_limit),
+    )
+    .unwrap();
+    let batch_settings = BatcherSettings::new(
+        Duration::from_secs(3_600),
+        NonZeroUsize::new(MAX_PAYLOAD_BYTES).unwrap(),
+        NonZeroUsize::new(1_000).unwrap(),
+    );
+    let control = BatcherControl::default();
+    let sink = LogSinkBuilder::new(
+        Default::default(),
+        service,
+        Arc::from("atoken"),
+        batch_settings,
+        "http".to_string(),
+        false,
+    )
+    .payload_limit(payload_limit)
+    .batcher_control(control.clone())
+    .build()
+    .unwrap();
+
+    let (tx, events) = futures::channel::mpsc::unbounded();
+    let sink = tokio::spawn(Box::new(sink).run(events.boxed()));
+    for message in ["one", "two", "three"] {
+        tx.unbounded_send(Event::Log(LogEvent::from(message)))
+            .unwrap();
+    }
+
+    // The events may not have reached the batcher yet, leaving nothing to flush.
+    let (_, body) = loop {
+        control.flush_now();
+        if let Ok(request) = tokio::time::timeout(Duration::from_millis(100), rx.next()).await {
+            break request.unwrap();
+        }
+    };
+    let events: Vec<serde_json::Value> = serde_json::from_slice(&body).unwrap();
+    let messages = events
+        .iter()
+        .map(|event| event["message"].as_str().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(messages, ["one", "two", "three"]);
+
+    // The sink keeps running after the flush.
+    assert!(!sink.is_finished());
+    drop(tx);
+    sink.await.unwrap().unwrap();
+}
+
+#[tokio::test]
+/// Assert that requests whose compressed body exceeds `max_compressed_bytes` are dropped instead of
+/// being sent
+async fn drops_too_big_compressed_payloads() {
+    let (mut config, cx) = load_sink::<DatadogLogsConfig>(indoc! {r#"
+            default_api_key = "atoken"
+            compression = "gzip"
+            max_compressed_bytes = 16
+        "#})
+    .unwrap();
+
+    let addr = next_addr();
+    let endpoint = format!("http://{}", addr);
+    config.local_dd_common.endpoint = Some(endpoint.clone());
+
+    let (sink, _) = config.build(cx).await.unwrap();
+
+    let (mut rx, _trigger, server) = test_server(addr, ApiStatus::OKv1);
+    tokio::spawn(server);
+
+    let (batch, receiver) = BatchNotifier::new_with_receiver();
+    let (_, events) = random_lines_with_stream(100, 10, Some(batch));
+    sink.run(events).await.unwrap();
+
+    assert_eq!(receiver.await, BatchStatus::Rejected);
+    assert!(matches!(rx.try_next(), Ok(None) | Err(_)));
+}
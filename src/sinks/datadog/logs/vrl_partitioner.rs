@@ -0,0 +1,209 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use vector_lib::{
+    compile_vrl, emit,
+    event::{Event, VrlTarget},
+    partition::Partitioner,
+    TimeZone,
+};
+use vrl::{
+    compiler::{
+        runtime::{Runtime, Terminate},
+        CompilationResult, CompileConfig, Program, TypeState,
+    },
+    diagnostic::Formatter,
+    value::Value,
+};
+
+use crate::{
+    config::LogNamespace, event::TargetEvents, internal_events::DatadogLogsApiKeyVrlError,
+};
+
+/// How often the failures of the program are reported, along with the number of events it failed
+/// on since the previous report.
+const FAILURE_REPORT_INTERVAL: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Default)]
+struct Failures {
+    count: usize,
+    reported_at: Option<Instant>,
+}
+
+/// Partitions events by an API key computed by a VRL program, such as
+/// `get_env_var!("DD_API_KEY_PREFIX") + .tenant.id`.
+///
+/// The key is computed once per event by [`VrlPartitioner::set_api_key`], which stores it in the
+/// event metadata for the partitioner to read. The value returned by the program is coerced to a
+/// string. Events for which the program returns `null` or an empty string, or fails, fall back to
+/// the default API key.
+#[derive(Debug)]
+pub struct VrlPartitioner {
+    program: Program,
+    failures: Mutex<Failures>,
+}
+
+impl VrlPartitioner {
+    /// Compiles the VRL program, returning its formatted diagnostics if it doesn't compile.
+    pub fn new(source: &str) -> crate::Result<Self> {
+        let functions = vrl::stdlib::all()
+            .into_iter()
+            .chain(vector_vrl_functions::all())
+            .collect::<Vec<_>>();
+
+        let state = TypeState::default();
+
+        // The program only computes a key, the events are sent as they are.
+        let mut config = CompileConfig::default();
+        config.set_read_only();
+
+        let CompilationResult {
+            program,
+            warnings,
+            config: _,
+        } = compile_vrl(source, &functions, &state, config)
+            .map_err(|diagnostics| Formatter::new(source, diagnostics).colored().to_string())?;
+
+        if !warnings.is_empty() {
+            let warnings = Formatter::new(source, warnings).colored().to_string();
+            warn!(message = "VRL compilation warning.", %warnings);
+        }
+
+        Ok(Self {
+            program,
+            failures: Mutex::default(),
+        })
+    }
+
+    /// Runs the program against the event, storing the API key it computed in the event metadata.
+    pub fn set_api_key(&self, event: Event) -> Event {
+        let log_namespace = event
+            .maybe_as_log()
+            .map(|log| log.namespace())
+            .unwrap_or(LogNamespace::Legacy);
+        let mut target = VrlTarget::new(event, self.program.info(), false);
+        let result = Runtime::default().resolve(&mut target, &self.program, &TimeZone::default());
+        let mut event = match target.into_events(log_namespace) {
+            TargetEvents::One(event) => event,
+            _ => panic!("Event was modified by a read-only program. This is an internal error."),
+        };
+
+        let api_key = match result {
+            Ok(Value::Null) => None,
+            Ok(value) => {
+                let key = value.to_string_lossy();
+                (!key.is_empty()).then(|| Arc::from(key.as_ref()))
+            }
+            Err(error) => {
+                self.report_failure(&error);
+                None
+            }
+        };
+        match api_key {
+            Some(api_key) => event.metadata_mut().set_datadog_api_key(api_key),
+            None => event.metadata_mut().remove_datadog_api_key(),
+        }
+        event
+    }
+
+    /// Counts a failure of the program, reporting it if no failure was reported recently.
+    fn report_failure(&self, error: &Terminate) {
+        let mut failures = self.failures.lock().expect("failures lock poisoned");
+        failures.count += 1;
+        if failures
+            .reported_at
+            .is_some_and(|reported_at| reported_at.elapsed() < FAILURE_REPORT_INTERVAL)
+        {
+            return;
+        }
+
+        emit!(DatadogLogsApiKeyVrlError {
+            error: &error.to_string(),
+            count: failures.count,
+        });
+        *failures = Failures {
+            count: 0,
+            reported_at: Some(Instant::now()),
+        };
+    }
+}
+
+impl Partitioner for VrlPartitioner {
+    type Item = Event;
+    type Key = Option<Arc<str>>;
+
+    fn partition(&self, item: &Self::Item) -> Self::Key {
+        item.metadata().datadog_api_key()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vector_lib::event::LogEvent;
+
+    use super::*;
+
+    fn event(fields: &[(&str, Value)]) -> Event {
+        let mut log = LogEvent::from("message");
+        for (field, value) in fields {
+            log.insert(*field, value.clone());
+        }
+        Event::Log(log)
+    }
+
+    fn api_key(partitioner: &VrlPartitioner, event: Event) -> Option<Arc<str>> {
+        partitioner.partition(&partitioner.set_api_key(event))
+    }
+
+    #[test]
+    fn computes_key() {
+        let partitioner = VrlPartitioner::new(r#""tenant-" + string!(.tenant.id)"#).unwrap();
+
+        let key = api_key(&partitioner, event(&[("tenant.id", Value::from("acme"))]));
+        assert_eq!(key.as_deref(), Some("tenant-acme"));
+    }
+
+    #[test]
+    fn coerces_key_to_string() {
+        let partitioner = VrlPartitioner::new(".tenant_id").unwrap();
+
+        let key = api_key(&partitioner, event(&[("tenant_id", Value::from(42))]));
+        assert_eq!(key.as_deref(), Some("42"));
+    }
+
+    #[test]
+    fn falls_back_without_key() {
+        let mut with_metadata_key = event(&[]);
+        with_metadata_key
+            .metadata_mut()
+            .set_datadog_api_key(Arc::from("metadata"));
+
+        let partitioner = VrlPartitioner::new(".tenant_id").unwrap();
+        assert_eq!(api_key(&partitioner, event(&[])), None);
+        assert_eq!(api_key(&partitioner, with_metadata_key.clone()), None);
+
+        let partitioner = VrlPartitioner::new("string!(.tenant_id)").unwrap();
+        assert_eq!(api_key(&partitioner, event(&[])), None);
+        assert_eq!(api_key(&partitioner, with_metadata_key), None);
+    }
+
+    #[test]
+    fn aggregates_failures() {
+        let partitioner = VrlPartitioner::new("string!(.tenant_id)").unwrap();
+        for _ in 0..3 {
+            partitioner.set_api_key(event(&[]));
+        }
+
+        // The first failure is reported right away, the next ones along with the next report.
+        let failures = partitioner.failures.lock().unwrap();
+        assert_eq!(failures.count, 2);
+        assert!(failures.reported_at.is_some());
+    }
+
+    #[test]
+    fn reports_compilation_errors() {
+        assert!(VrlPartitioner::new(".tenant_id +").is_err());
+    }
+}
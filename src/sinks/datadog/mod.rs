@@ -14,6 +14,8 @@ use crate::{
     sinks::HealthcheckError,
 };
 
+#[cfg(feature = "sinks-datadog_archives")]
+pub mod archives;
 #[cfg(feature = "sinks-datadog_events")]
 pub mod events;
 #[cfg(feature = "sinks-datadog_logs")]
@@ -177,6 +179,8 @@ async fn build_healthcheck_future(
 pub enum DatadogApiError {
     #[snafu(display("Failed to make HTTP(S) request: {}", error))]
     HttpError { error: HttpError },
+    #[snafu(display("TLS handshake with the endpoint failed: {}", error))]
+    TlsHandshake { error: HttpError },
     #[snafu(display("Client request was not valid for unknown reasons."))]
     BadRequest,
     #[snafu(display("Client request was unauthorized."))]
@@ -232,6 +236,9 @@ impl DatadogApiError {
                     _ => Err(DatadogApiError::ServerError),
                 }
             }
+            Err(error) if is_tls_handshake_error(&error) => {
+                Err(DatadogApiError::TlsHandshake { error })
+            }
             Err(error) => Err(DatadogApiError::HttpError { error }),
         }
     }
@@ -244,7 +251,9 @@ impl DatadogApiError {
             //
             // https://github.com/vectordotdev/vector/issues/10870
             // https://github.com/vectordotdev/vector/issues/12220
-            DatadogApiError::HttpError { error } => error.is_retriable(),
+            DatadogApiError::HttpError { error } | DatadogApiError::TlsHandshake { error } => {
+                error.is_retriable()
+            }
             DatadogApiError::BadRequest | DatadogApiError::PayloadTooLarge => false,
             DatadogApiError::ServerError
             | DatadogApiError::ClientError
@@ -256,6 +265,30 @@ impl DatadogApiError {
     }
 }
 
+/// Returns whether a request failed during the TLS handshake, such as when the endpoint rejects
+/// the client certificate set in `tls.crt_file`, or the client rejects the certificate of the
+/// endpoint.
+fn is_tls_handshake_error(error: &HttpError) -> bool {
+    let HttpError::CallRequest { source } = error else {
+        return false;
+    };
+    let mut cause: Option<&(dyn std::error::Error + 'static)> = Some(source);
+    while let Some(error) = cause {
+        if error.is::<openssl::ssl::Error>() {
+            return true;
+        }
+        // I/O errors wrapping another error, as the proxy connector does, skip it as their source.
+        cause = match error
+            .downcast_ref::<std::io::Error>()
+            .and_then(std::io::Error::get_ref)
+        {
+            Some(inner) => Some(inner as &(dyn std::error::Error + 'static)),
+            None => error.source(),
+        };
+    }
+    false
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -311,4 +344,36 @@ mod tests {
         let error = local.with_globals(global).unwrap_err();
         assert_eq!(ConfigurationError::ApiKeyRequired, error);
     }
+
+    #[tokio::test]
+    async fn tls_handshake_error() {
+        use tokio::io::AsyncReadExt;
+        use vector_lib::tls::{MaybeTlsSettings, TlsSettings};
+
+        use crate::{config::ProxyConfig, test_util::next_addr};
+
+        // The test certificate isn't trusted by the client, so the handshake fails.
+        let addr = next_addr();
+        let server_tls =
+            MaybeTlsSettings::from_config(Some(&TlsEnableableConfig::test_config()), true).unwrap();
+        let mut listener = server_tls.bind(&addr).await.unwrap();
+        tokio::spawn(async move {
+            while let Ok(mut stream) = listener.accept().await {
+                let _ = stream.read(&mut [0; 1024]).await;
+            }
+        });
+
+        let tls = TlsSettings::from_options(None).unwrap();
+        let client = HttpClient::new(tls, &ProxyConfig::default()).unwrap();
+        let request = Request::get(format!("https://localhost:{}/", addr.port()))
+            .body(Body::empty())
+            .unwrap();
+
+        let error = DatadogApiError::from_result(client.send(request).await).unwrap_err();
+        assert!(
+            matches!(error, DatadogApiError::TlsHandshake { .. }),
+            "{error:?}"
+        );
+        assert!(error.is_retriable());
+    }
 }
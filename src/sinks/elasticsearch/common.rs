@@ -1,5 +1,5 @@
 use bytes::{Buf, Bytes};
-use http::{Response, StatusCode, Uri};
+use http::{header::CONTENT_TYPE, Response, StatusCode, Uri};
 use hyper::{body, Body};
 use serde::Deserialize;
 use snafu::ResultExt;
@@ -15,7 +15,7 @@ use crate::{
     sinks::{
         elasticsearch::{
             ElasticsearchAuthConfig, ElasticsearchCommonMode, ElasticsearchConfig,
-            OpenSearchServiceType, ParseError,
+            IndexTemplateConfig, OpenSearchServiceType, ParseError,
         },
         util::{auth::Auth, http::RequestConfig, UriSerde},
         HealthcheckError,
@@ -315,6 +315,131 @@ impl ElasticsearchCommon {
             }
         }
     }
+
+    /// Creates the index template configured in `manage_templates`, or updates it if it differs
+    /// from `template_body` and `auto_update` is enabled.
+    pub async fn apply_index_template(
+        &self,
+        client: HttpClient,
+        config: &IndexTemplateConfig,
+        body: &serde_json::Value,
+    ) -> crate::Result<()> {
+        let path = format!("/_index_template/{}", config.template_name);
+        let response = get(
+            &self.base_url,
+            self.auth.as_ref(),
+            #[cfg(feature = "aws-core")]
+            &self.service_type,
+            &self.request,
+            client.clone(),
+            &path,
+        )
+        .await?;
+
+        match response.status() {
+            StatusCode::NOT_FOUND => {}
+            StatusCode::OK => {
+                let (_, existing) = response.into_parts();
+                let existing = existing_index_template(existing).await?;
+                if existing.as_ref() == Some(body) {
+                    debug!(
+                        message = "Index template is up to date.",
+                        template_name = %config.template_name,
+                    );
+                    return Ok(());
+                }
+                if !config.auto_update {
+                    warn!(
+                        message = "Index template differs from `template_body`, leaving it unchanged.",
+                        template_name = %config.template_name,
+                        reason = "`auto_update` is disabled.",
+                    );
+                    return Ok(());
+                }
+            }
+            status => {
+                return Err(format!(
+                    "Unexpected status {} when getting index template {:?}.",
+                    status, config.template_name
+                )
+                .into())
+            }
+        }
+
+        let response = put_json(
+            &self.base_url,
+            self.auth.as_ref(),
+            #[cfg(feature = "aws-core")]
+            &self.service_type,
+            &self.request,
+            client,
+            &path,
+            Bytes::from(serde_json::to_vec(body)?),
+        )
+        .await?;
+
+        match response.status() {
+            StatusCode::OK => {
+                info!(
+                    message = "Applied index template.",
+                    template_name = %config.template_name,
+                );
+                Ok(())
+            }
+            status => {
+                let (_, body) = response.into_parts();
+                let body = body::to_bytes(body).await?;
+                Err(format!(
+                    "Unexpected status {} when applying index template {:?}: {}",
+                    status,
+                    config.template_name,
+                    String::from_utf8_lossy(&body)
+                )
+                .into())
+            }
+        }
+    }
+}
+
+/// Applies the index template configured in `manage_templates` through the first endpoint that
+/// accepts it, as the template is shared by the whole cluster.
+pub async fn apply_index_template(
+    commons: &[ElasticsearchCommon],
+    client: &HttpClient,
+    config: &IndexTemplateConfig,
+) -> crate::Result<()> {
+    let body = serde_json::from_str::<serde_json::Value>(&config.template_body)
+        .map_err(|error| format!("Invalid `template_body` in `manage_templates`: {}", error))?;
+
+    let mut result = Ok(());
+    for common in commons {
+        result = common
+            .apply_index_template(client.clone(), config, &body)
+            .await;
+        if result.is_ok() {
+            break;
+        }
+    }
+    result
+}
+
+/// Extracts the template from the response of the get index template API.
+async fn existing_index_template(body: Body) -> crate::Result<Option<serde_json::Value>> {
+    #[derive(Deserialize)]
+    struct IndexTemplate {
+        index_template: serde_json::Value,
+    }
+    #[derive(Deserialize)]
+    struct ResponsePayload {
+        index_templates: Vec<IndexTemplate>,
+    }
+
+    let body = body::to_bytes(body).await?;
+    let ResponsePayload { index_templates } = serde_json::from_slice(&body)?;
+    Ok(index_templates
+        .into_iter()
+        .next()
+        .map(|template| template.index_template))
 }
 
 #[cfg(feature = "aws-core")]
@@ -392,12 +517,51 @@ async fn get(
     client: HttpClient,
     path: &str,
 ) -> crate::Result<Response<Body>> {
-    let mut builder = Request::get(format!("{}{}", base_url, path));
+    send(
+        Request::get(format!("{}{}", base_url, path)),
+        Bytes::new(),
+        auth,
+        #[cfg(feature = "aws-core")]
+        service_type,
+        request,
+        client,
+    )
+    .await
+}
 
+async fn put_json(
+    base_url: &str,
+    auth: Option<&Auth>,
+    #[cfg(feature = "aws-core")] service_type: &OpenSearchServiceType,
+    request: &RequestConfig,
+    client: HttpClient,
+    path: &str,
+    body: Bytes,
+) -> crate::Result<Response<Body>> {
+    send(
+        Request::put(format!("{}{}", base_url, path)).header(CONTENT_TYPE, "application/json"),
+        body,
+        auth,
+        #[cfg(feature = "aws-core")]
+        service_type,
+        request,
+        client,
+    )
+    .await
+}
+
+async fn send(
+    mut builder: http::request::Builder,
+    body: Bytes,
+    auth: Option<&Auth>,
+    #[cfg(feature = "aws-core")] service_type: &OpenSearchServiceType,
+    request: &RequestConfig,
+    client: HttpClient,
+) -> crate::Result<Response<Body>> {
     for (header, value) in &request.headers {
         builder = builder.header(&header[..], &value[..]);
     }
-    let mut request = builder.body(Bytes::new())?;
+    let mut request = builder.body(body)?;
 
     if let Some(auth) = auth {
         match auth {
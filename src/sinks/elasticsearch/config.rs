@@ -14,6 +14,7 @@ use crate::{
     internal_events::TemplateRenderingError,
     sinks::{
         elasticsearch::{
+            apply_index_template,
             health::ElasticsearchHealthLogic,
             retry::ElasticsearchRetryLogic,
             service::{ElasticsearchService, HttpRequestBuilder},
@@ -211,6 +212,10 @@ pub struct ElasticsearchConfig {
     #[configurable(derived)]
     pub metrics: Option<MetricToLogConfig>,
 
+    #[serde(default)]
+    #[configurable(derived)]
+    pub manage_templates: Option<IndexTemplateConfig>,
+
     #[serde(
         default,
         deserialize_with = "crate::serde::bool_or_struct",
@@ -254,6 +259,7 @@ impl Default for ElasticsearchConfig {
             bulk: BulkConfig::default(), // the default mode is Bulk
             data_stream: None,
             metrics: None,
+            manage_templates: None,
             acknowledgements: Default::default(),
         }
     }
@@ -534,6 +540,44 @@ impl DataStreamConfig {
     }
 }
 
+/// Elasticsearch index template management configuration.
+///
+/// When set, the sink creates or updates an [index template][es_index_templates] when it starts,
+/// so that the indices it writes to are created with the expected mappings and settings.
+///
+/// [es_index_templates]: https://www.elastic.co/guide/en/elasticsearch/reference/current/index-templates.html
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct IndexTemplateConfig {
+    /// The name of the index template to create or update.
+    #[configurable(metadata(docs::examples = "vector-logs"))]
+    pub template_name: String,
+
+    /// The index template, as a JSON object.
+    ///
+    /// This is sent as is to the [index template API][es_put_index_template].
+    ///
+    /// [es_put_index_template]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-put-template.html
+    #[configurable(metadata(
+        docs::examples = r#"{"index_patterns": ["vector-*"], "template": {"mappings": {"properties": {"message": {"type": "text"}}}}}"#
+    ))]
+    pub template_body: String,
+
+    /// Whether to update the index template if it already exists and differs from `template_body`.
+    ///
+    /// The existing template is compared with the one returned by Elasticsearch. If this is
+    /// disabled and they differ, the existing template is left unchanged and a warning is logged.
+    #[serde(default)]
+    pub auto_update: bool,
+
+    /// Whether failing to apply the index template prevents the sink from starting.
+    ///
+    /// If this is disabled, a warning is logged and the sink starts anyway.
+    #[serde(default = "crate::serde::default_true")]
+    pub fail_on_error: bool,
+}
+
 #[async_trait::async_trait]
 #[typetag::serde(name = "elasticsearch")]
 impl SinkConfig for ElasticsearchConfig {
@@ -543,6 +587,19 @@ impl SinkConfig for ElasticsearchConfig {
 
         let client = HttpClient::new(common.tls_settings.clone(), cx.proxy())?;
 
+        if let Some(template) = &self.manage_templates {
+            if let Err(error) = apply_index_template(&commons, &client, template).await {
+                if template.fail_on_error {
+                    return Err(error);
+                }
+                warn!(
+                    message = "Failed to apply index template.",
+                    template_name = %template.template_name,
+                    %error,
+                );
+            }
+        }
+
         let request_limits = self.request.tower.into_settings();
 
         let health_config = self.endpoint_health.clone().unwrap_or_default();
@@ -515,6 +515,72 @@ async fn insert_events_in_data_stream() {
     run_insert_tests_with_config(&cfg, TestType::Normal, BatchStatus::Delivered).await;
 }
 
+#[tokio::test]
+async fn manages_index_template() {
+    trace_init();
+    let template_name = format!("vector-template-{}", random_string(10).to_lowercase());
+    let template_body = |message_type: &str| {
+        json!({
+            "index_patterns": [format!("{}-*", template_name)],
+            "template": {
+                "mappings": { "properties": { "message": { "type": message_type } } },
+            },
+        })
+        .to_string()
+    };
+
+    let mut config = ElasticsearchConfig {
+        endpoints: vec![http_server()],
+        manage_templates: Some(IndexTemplateConfig {
+            template_name: template_name.clone(),
+            template_body: template_body("text"),
+            auto_update: false,
+            fail_on_error: true,
+        }),
+        ..Default::default()
+    };
+    config.build(SinkContext::default()).await.unwrap();
+    assert_eq!(message_mapping_type(&template_name).await, "text");
+
+    // An existing template is only updated with `auto_update`.
+    let templates = config.manage_templates.as_mut().unwrap();
+    templates.template_body = template_body("keyword");
+    config.build(SinkContext::default()).await.unwrap();
+    assert_eq!(message_mapping_type(&template_name).await, "text");
+
+    config.manage_templates.as_mut().unwrap().auto_update = true;
+    config.build(SinkContext::default()).await.unwrap();
+    assert_eq!(message_mapping_type(&template_name).await, "keyword");
+
+    // Failing to apply the template only prevents the sink from starting with `fail_on_error`.
+    let templates = config.manage_templates.as_mut().unwrap();
+    templates.template_body = json!({ "index_patterns": 42 }).to_string();
+    assert!(config.build(SinkContext::default()).await.is_err());
+
+    config.manage_templates.as_mut().unwrap().fail_on_error = false;
+    config.build(SinkContext::default()).await.unwrap();
+    assert_eq!(message_mapping_type(&template_name).await, "keyword");
+}
+
+async fn message_mapping_type(template_name: &str) -> String {
+    let response = create_http_client()
+        .get(format!(
+            "{}/_index_template/{}",
+            http_server(),
+            template_name
+        ))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.json::<Value>().await.unwrap();
+    let mapping = &body["index_templates"][0]["index_template"]["template"]["mappings"];
+    mapping["properties"]["message"]["type"]
+        .as_str()
+        .unwrap()
+        .to_owned()
+}
+
 #[tokio::test]
 async fn distributed_insert_events() {
     trace_init();
@@ -1,9 +1,12 @@
+use std::collections::HashMap;
 use std::convert::TryFrom;
+use std::num::NonZeroUsize;
 use std::time::{Duration, Instant};
 
 use async_compression::tokio::write::{GzipEncoder, ZstdEncoder};
 use async_trait::async_trait;
 use bytes::{Bytes, BytesMut};
+use chrono::Utc;
 use futures::{
     future,
     stream::{BoxStream, StreamExt},
@@ -89,6 +92,10 @@ pub struct FileSinkConfig {
     #[configurable(derived)]
     #[serde(default)]
     pub internal_metrics: FileInternalMetricsConfig,
+
+    #[configurable(derived)]
+    #[serde(default, skip_serializing_if = "crate::serde::is_default")]
+    pub rotation: FileRotationConfig,
 }
 
 impl GenerateConfig for FileSinkConfig {
@@ -101,6 +108,7 @@ impl GenerateConfig for FileSinkConfig {
             acknowledgements: Default::default(),
             timezone: Default::default(),
             internal_metrics: Default::default(),
+            rotation: Default::default(),
         })
         .unwrap()
     }
@@ -110,6 +118,28 @@ const fn default_idle_timeout() -> Duration {
     Duration::from_secs(30)
 }
 
+/// The format of the suffix appended to the path of rotated files.
+const ROTATED_FILE_SUFFIX_FORMAT: &str = "%Y%m%dT%H%M%S%.9fZ";
+
+/// File rotation configuration.
+///
+/// Files can also be rotated over time by using a time-based `path`, such as
+/// `/tmp/vector-%Y-%m-%d.log`.
+#[configurable_component]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct FileRotationConfig {
+    /// The maximum number of events written to a file before it is rotated.
+    ///
+    /// Once this many events have been written to a file since it was opened, the file is closed
+    /// and renamed by appending the current UTC time to its path, such as
+    /// `<path>.20240105T070000.123456789Z`. The events that follow are written to a new file at
+    /// the original path.
+    #[configurable(metadata(docs::type_unit = "events"))]
+    #[configurable(metadata(docs::examples = 100000))]
+    pub max_events: Option<NonZeroUsize>,
+}
+
 /// Compression configuration.
 // TODO: Why doesn't this already use `crate::sinks::util::Compression`
 // `crate::sinks::util::Compression` doesn't support zstd yet
@@ -211,6 +241,8 @@ pub struct FileSink {
     compression: Compression,
     events_sent: Registered<EventsSent>,
     include_file_metric_tag: bool,
+    max_events: Option<NonZeroUsize>,
+    event_counts: HashMap<Bytes, usize>,
 }
 
 impl FileSink {
@@ -233,6 +265,8 @@ impl FileSink {
             compression: config.compression,
             events_sent: register!(EventsSent::from(Output(None))),
             include_file_metric_tag: config.internal_metrics.include_file_tag,
+            max_events: config.rotation.max_events,
+            event_counts: HashMap::new(),
         })
     }
 
@@ -312,6 +346,7 @@ impl FileSink {
                                 });
                             }
                             drop(expired_file); // ignore close error
+                            self.event_counts.remove(path.get_ref());
                             emit!(FileOpen {
                                 count: self.files.len()
                             });
@@ -384,6 +419,9 @@ impl FileSink {
                     file: String::from_utf8_lossy(&path),
                     include_file_metric_tag: self.include_file_metric_tag,
                 });
+                if self.count_event(&path) {
+                    self.rotate_file(path).await;
+                }
             }
             Err(error) => {
                 finalizers.update_status(EventStatus::Errored);
@@ -397,6 +435,55 @@ impl FileSink {
             }
         }
     }
+
+    /// Counts an event written to the file at `path`, returning whether the file is due for
+    /// rotation.
+    fn count_event(&mut self, path: &Bytes) -> bool {
+        let Some(max_events) = self.max_events else {
+            return false;
+        };
+        let count = self.event_counts.entry(path.clone()).or_default();
+        *count += 1;
+        *count >= max_events.get()
+    }
+
+    /// Closes the file at `path` and renames it with the current time as a suffix, so that the
+    /// next event written to `path` opens a new file.
+    async fn rotate_file(&mut self, path: Bytes) {
+        self.event_counts.remove(&path);
+        let Some((mut file, _)) = self.files.remove(&path) else {
+            return;
+        };
+        emit!(FileOpen {
+            count: self.files.len()
+        });
+
+        if let Err(error) = file.close().await {
+            emit!(FileIoError {
+                error,
+                code: "failed_closing_file",
+                message: "Failed to close file.",
+                path: &path,
+                dropped_events: 0,
+            });
+        }
+
+        let suffix = Utc::now().format(ROTATED_FILE_SUFFIX_FORMAT);
+        let mut rotated_path = BytesMut::from(&path[..]);
+        rotated_path.extend_from_slice(format!(".{suffix}").as_bytes());
+        let rotated_path = BytesPath::new(rotated_path.freeze());
+
+        debug!(message = "Rotating file.", ?path, rotated_path = ?rotated_path.as_ref());
+        if let Err(error) = fs::rename(BytesPath::new(path.clone()), rotated_path).await {
+            emit!(FileIoError {
+                error,
+                code: "failed_rotating_file",
+                message: "Failed to rotate file.",
+                path: &path,
+                dropped_events: 0,
+            });
+        }
+    }
 }
 
 async fn open_file(path: impl AsRef<std::path::Path>) -> std::io::Result<File> {
@@ -482,6 +569,7 @@ mod tests {
             internal_metrics: FileInternalMetricsConfig {
                 include_file_tag: true,
             },
+            rotation: Default::default(),
         };
 
         let (input, _events) = random_lines_with_stream(100, 64, None);
@@ -508,6 +596,7 @@ mod tests {
             internal_metrics: FileInternalMetricsConfig {
                 include_file_tag: true,
             },
+            rotation: Default::default(),
         };
 
         let (input, _) = random_lines_with_stream(100, 64, None);
@@ -534,6 +623,7 @@ mod tests {
             internal_metrics: FileInternalMetricsConfig {
                 include_file_tag: true,
             },
+            rotation: Default::default(),
         };
 
         let (input, _) = random_lines_with_stream(100, 64, None);
@@ -565,6 +655,7 @@ mod tests {
             internal_metrics: FileInternalMetricsConfig {
                 include_file_tag: true,
             },
+            rotation: Default::default(),
         };
 
         let (mut input, _events) = random_events_with_stream(32, 8, None);
@@ -647,6 +738,7 @@ mod tests {
             internal_metrics: FileInternalMetricsConfig {
                 include_file_tag: true,
             },
+            rotation: Default::default(),
         };
 
         let (mut input, _events) = random_lines_with_stream(10, 64, None);
@@ -689,6 +781,41 @@ mod tests {
         sink_handle.await.unwrap();
     }
 
+    #[tokio::test]
+    async fn log_rotation_by_event_count() {
+        let directory = temp_dir();
+        let path = directory.join("events.log");
+
+        let config = FileSinkConfig {
+            path: path.clone().try_into().unwrap(),
+            idle_timeout: default_idle_timeout(),
+            encoding: (None::<FramingConfig>, TextSerializerConfig::default()).into(),
+            compression: Compression::None,
+            acknowledgements: Default::default(),
+            timezone: Default::default(),
+            internal_metrics: FileInternalMetricsConfig {
+                include_file_tag: true,
+            },
+            rotation: FileRotationConfig {
+                max_events: NonZeroUsize::new(10),
+            },
+        };
+
+        let (input, _events) = random_lines_with_stream(11, 64, None);
+
+        run_assert_log_sink(&config, input.clone()).await;
+
+        let mut files = std::fs::read_dir(&directory)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .collect::<Vec<_>>();
+        files.sort();
+        assert_eq!(files.len(), 2);
+        assert_eq!(files[0], path);
+        assert_eq!(lines_from_file(&files[0]), input[10..]);
+        assert_eq!(lines_from_file(&files[1]), input[..10]);
+    }
+
     #[tokio::test]
     async fn metric_single_partition() {
         let template = temp_file();
@@ -703,6 +830,7 @@ mod tests {
             internal_metrics: FileInternalMetricsConfig {
                 include_file_tag: true,
             },
+            rotation: Default::default(),
         };
 
         let (input, _events) = random_metrics_with_stream(100, None, None);
@@ -734,6 +862,7 @@ mod tests {
             internal_metrics: FileInternalMetricsConfig {
                 include_file_tag: true,
             },
+            rotation: Default::default(),
         };
 
         let metric_count = 3;
@@ -785,6 +914,7 @@ mod tests {
             internal_metrics: FileInternalMetricsConfig {
                 include_file_tag: true,
             },
+            rotation: Default::default(),
         };
 
         let (input, _events) = random_lines_with_stream(100, 64, None);
@@ -0,0 +1,475 @@
+//! This sink sends data to Google Chronicles UDM events endpoint.
+//! See <https://cloud.google.com/chronicle/docs/reference/ingestion-api#udmevents>
+//! for more information.
+use std::collections::HashMap;
+use std::io;
+
+use goauth::scopes::Scope;
+use http::header::{self, HeaderValue};
+use indoc::indoc;
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+use tower::ServiceBuilder;
+use vector_lib::configurable::configurable_component;
+use vector_lib::internal_event::{ComponentEventsDropped, UNINTENTIONAL};
+use vector_lib::request_metadata::{GroupedCountByteSize, RequestMetadata};
+use vector_lib::{
+    config::{telemetry, AcknowledgementsConfig, Input},
+    event::{Event, EventFinalizers, Finalizable},
+    sink::VectorSink,
+    EstimatedJsonEncodedSizeOf,
+};
+use vrl::value::Kind;
+
+use crate::sinks::util::service::TowerRequestConfigDefaults;
+use crate::{
+    codecs::Transformer,
+    config::{GenerateConfig, SinkConfig, SinkContext},
+    gcp::{GcpAuthConfig, GcpAuthenticator},
+    http::HttpClient,
+    schema,
+    sinks::{
+        gcp_chronicle::{
+            chronicle_unstructured::{
+                build_healthcheck, ChronicleError, ChronicleRequest, ChronicleRequestPayload,
+                ChronicleService, Region,
+            },
+            compression::ChronicleCompression,
+            partitioner::{ChroniclePartitionKey, ChroniclePartitioner},
+            sink::ChronicleSink,
+        },
+        gcs_common::config::GcsRetryLogic,
+        util::{
+            encoding::{as_tracked_write, Encoder},
+            metadata::RequestMetadataBuilder,
+            request_builder::EncodeResult,
+            BatchConfig, Compression, RequestBuilder, SinkBatchSettings, TowerRequestConfig,
+        },
+        Healthcheck,
+    },
+    template::Template,
+    tls::{TlsConfig, TlsSettings},
+};
+
+// Chronicle Ingestion API accepts at most 1MB and 1000 events per UDM events request[1].
+//
+// [1]: https://cloud.google.com/chronicle/docs/reference/ingestion-api#udmevents
+const MAX_REQUEST_BYTES: usize = 1_000_000;
+const MAX_REQUEST_EVENTS: usize = 1_000;
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ChronicleUdmDefaultBatchSettings;
+
+impl SinkBatchSettings for ChronicleUdmDefaultBatchSettings {
+    const MAX_EVENTS: Option<usize> = Some(MAX_REQUEST_EVENTS);
+    const MAX_BYTES: Option<usize> = Some(MAX_REQUEST_BYTES);
+    const TIMEOUT_SECS: f64 = 15.0;
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ChronicleUdmTowerRequestConfigDefaults;
+
+impl TowerRequestConfigDefaults for ChronicleUdmTowerRequestConfigDefaults {
+    const RATE_LIMIT_NUM: u64 = 1_000;
+}
+
+/// Configuration for the `gcp_chronicle_udm` sink.
+#[configurable_component(sink(
+    "gcp_chronicle_udm",
+    "Store log events in Google Chronicle as Unified Data Model (UDM) events."
+))]
+#[derive(Clone, Debug)]
+pub struct ChronicleUdmConfig {
+    /// The endpoint to send data to.
+    ///
+    /// Defaults to the endpoint of the `us` region if neither `endpoint` nor `region` are set.
+    #[configurable(metadata(
+        docs::examples = "127.0.0.1:8080",
+        docs::examples = "example.com:12345"
+    ))]
+    pub endpoint: Option<String>,
+
+    /// The GCP region to use.
+    #[configurable(derived)]
+    pub region: Option<Region>,
+
+    /// The Unique identifier (UUID) corresponding to the Chronicle instance.
+    #[configurable(validation(format = "uuid"))]
+    #[configurable(metadata(docs::examples = "c8c65bfa-5f2c-42d4-9189-64bb7b939f2c"))]
+    pub customer_id: String,
+
+    #[serde(flatten)]
+    pub auth: GcpAuthConfig,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub batch: BatchConfig<ChronicleUdmDefaultBatchSettings>,
+
+    #[configurable(derived)]
+    #[serde(default, skip_serializing_if = "crate::serde::is_default")]
+    pub encoding: Transformer,
+
+    #[serde(default)]
+    #[configurable(derived)]
+    pub compression: ChronicleCompression,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub request: TowerRequestConfig<ChronicleUdmTowerRequestConfigDefaults>,
+
+    #[configurable(derived)]
+    pub tls: Option<TlsConfig>,
+
+    /// The log type of the UDM events, set as their `metadata.log_type`.
+    ///
+    /// Events that already have a `metadata.log_type` field keep it.
+    #[configurable(metadata(docs::examples = "WINDOWS_DNS", docs::examples = "{{ log_type }}"))]
+    pub log_type: Template,
+
+    /// The default `log_type` to attach to events if the template in `log_type` cannot be resolved.
+    #[configurable(metadata(docs::examples = "VECTOR_DEV"))]
+    pub fallback_log_type: Option<String>,
+
+    #[configurable(derived)]
+    #[serde(
+        default,
+        deserialize_with = "crate::serde::bool_or_struct",
+        skip_serializing_if = "crate::serde::is_default"
+    )]
+    acknowledgements: AcknowledgementsConfig,
+}
+
+impl GenerateConfig for ChronicleUdmConfig {
+    fn generate_config() -> toml::Value {
+        toml::from_str(indoc! {r#"
+            credentials_path = "/path/to/credentials.json"
+            customer_id = "customer_id"
+            log_type = "log_type"
+            fallback_log_type = "VECTOR_DEV"
+        "#})
+        .unwrap()
+    }
+}
+
+#[async_trait::async_trait]
+#[typetag::serde(name = "gcp_chronicle_udm")]
+impl SinkConfig for ChronicleUdmConfig {
+    async fn build(&self, cx: SinkContext) -> crate::Result<(VectorSink, Healthcheck)> {
+        let creds = self.auth.build(Scope::MalachiteIngestion).await?;
+
+        let tls = TlsSettings::from_options(self.tls.as_ref())?;
+        let client = HttpClient::new(tls, cx.proxy())?;
+
+        let endpoint = self.create_endpoint("v2/udmevents:batchCreate")?;
+
+        // For the healthcheck we see if we can fetch the list of available log types.
+        let healthcheck_endpoint = self.create_endpoint("v2/logtypes")?;
+
+        let healthcheck = build_healthcheck(client.clone(), &healthcheck_endpoint, creds.clone())?;
+        creds.spawn_regenerate_token();
+        let sink = self.build_sink(client, endpoint, creds)?;
+
+        Ok((sink, healthcheck))
+    }
+
+    fn input(&self) -> Input {
+        let requirement =
+            schema::Requirement::empty().required_meaning("timestamp", Kind::timestamp());
+
+        Input::log().with_schema_requirement(requirement)
+    }
+
+    fn acknowledgements(&self) -> &AcknowledgementsConfig {
+        &self.acknowledgements
+    }
+}
+
+impl ChronicleUdmConfig {
+    fn build_sink(
+        &self,
+        client: HttpClient,
+        base_url: String,
+        creds: GcpAuthenticator,
+    ) -> crate::Result<VectorSink> {
+        use crate::sinks::util::service::ServiceBuilderExt;
+
+        let request = self.request.into_settings();
+
+        let batch_settings = self
+            .batch
+            .validate()?
+            .limit_max_bytes(MAX_REQUEST_BYTES)?
+            .limit_max_events(MAX_REQUEST_EVENTS)?
+            .into_batcher_settings()?;
+
+        let partitioner =
+            ChroniclePartitioner::new(self.log_type.clone(), self.fallback_log_type.clone(), None);
+
+        let svc = ServiceBuilder::new()
+            .settings(request, GcsRetryLogic)
+            .service(ChronicleService::new(client, base_url, creds));
+
+        let request_builder = ChronicleUdmRequestBuilder::new(self);
+
+        let sink = ChronicleSink::new(svc, request_builder, partitioner, batch_settings, "http");
+
+        Ok(VectorSink::from_event_streamsink(sink))
+    }
+
+    fn create_endpoint(&self, path: &str) -> Result<String, ChronicleError> {
+        Ok(format!(
+            "{}/{}",
+            match (&self.endpoint, self.region) {
+                (Some(endpoint), None) => endpoint.trim_end_matches('/'),
+                (None, Some(region)) => region.endpoint(),
+                (Some(_), Some(_)) => return Err(ChronicleError::BothRegionAndEndpoint),
+                (None, None) => Region::Us.endpoint(),
+            },
+            path
+        ))
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+struct ChronicleUdmRequestBody {
+    customer_id: String,
+    events: Vec<Value>,
+}
+
+#[derive(Clone, Debug)]
+struct ChronicleUdmEncoder {
+    customer_id: String,
+    transformer: Transformer,
+}
+
+impl ChronicleUdmEncoder {
+    /// Maps the event to a UDM event, filling in its `metadata.log_type` and
+    /// `metadata.event_timestamp` if they aren't set.
+    fn udm_event(&self, event: Event, log_type: &str) -> Option<Value> {
+        let log = event.into_log();
+        let timestamp = log
+            .get_timestamp()
+            .and_then(|ts| ts.as_timestamp())
+            .map(|ts| ts.to_rfc3339_opts(chrono::SecondsFormat::AutoSi, true));
+
+        let Ok(Value::Object(mut udm_event)) = serde_json::to_value(&log) else {
+            emit!(ComponentEventsDropped::<UNINTENTIONAL> {
+                count: 1,
+                reason: "Event is not an object, so it can't be mapped to a UDM event.",
+            });
+            return None;
+        };
+
+        let metadata = udm_event
+            .entry("metadata")
+            .or_insert_with(|| Value::Object(Map::new()));
+        if let Value::Object(metadata) = metadata {
+            metadata
+                .entry("log_type")
+                .or_insert_with(|| log_type.into());
+            if let Some(timestamp) = timestamp {
+                metadata
+                    .entry("event_timestamp")
+                    .or_insert_with(|| timestamp.into());
+            }
+        }
+
+        Some(Value::Object(udm_event))
+    }
+}
+
+impl Encoder<(ChroniclePartitionKey, Vec<Event>)> for ChronicleUdmEncoder {
+    fn encode_input(
+        &self,
+        input: (ChroniclePartitionKey, Vec<Event>),
+        writer: &mut dyn io::Write,
+    ) -> io::Result<(usize, GroupedCountByteSize)> {
+        let (key, events) = input;
+        let mut byte_size = telemetry().create_request_count_byte_size();
+        let events = events
+            .into_iter()
+            .filter_map(|mut event| {
+                self.transformer.transform(&mut event);
+                byte_size.add_event(&event, event.estimated_json_encoded_size_of());
+                self.udm_event(event, &key.log_type)
+            })
+            .collect::<Vec<_>>();
+
+        let json = json!(ChronicleUdmRequestBody {
+            customer_id: self.customer_id.clone(),
+            events,
+        });
+
+        let size = as_tracked_write::<_, _, io::Error>(writer, &json, |writer, json| {
+            serde_json::to_writer(writer, json)?;
+            Ok(())
+        })?;
+
+        Ok((size, byte_size))
+    }
+}
+
+#[derive(Clone, Debug)]
+struct ChronicleUdmRequestBuilder {
+    encoder: ChronicleUdmEncoder,
+    compression: Compression,
+}
+
+impl ChronicleUdmRequestBuilder {
+    fn new(config: &ChronicleUdmConfig) -> Self {
+        Self {
+            encoder: ChronicleUdmEncoder {
+                customer_id: config.customer_id.clone(),
+                transformer: config.encoding.clone(),
+            },
+            compression: Compression::from(config.compression),
+        }
+    }
+}
+
+impl RequestBuilder<(ChroniclePartitionKey, Vec<Event>)> for ChronicleUdmRequestBuilder {
+    type Metadata = EventFinalizers;
+    type Events = (ChroniclePartitionKey, Vec<Event>);
+    type Encoder = ChronicleUdmEncoder;
+    type Payload = ChronicleRequestPayload;
+    type Request = ChronicleRequest;
+    type Error = io::Error;
+
+    fn compression(&self) -> Compression {
+        self.compression
+    }
+
+    fn encoder(&self) -> &Self::Encoder {
+        &self.encoder
+    }
+
+    fn split_input(
+        &self,
+        input: (ChroniclePartitionKey, Vec<Event>),
+    ) -> (Self::Metadata, RequestMetadataBuilder, Self::Events) {
+        let (partition_key, mut events) = input;
+        let finalizers = events.take_finalizers();
+
+        let builder = RequestMetadataBuilder::from_events(&events);
+        (finalizers, builder, (partition_key, events))
+    }
+
+    fn build_request(
+        &self,
+        finalizers: Self::Metadata,
+        metadata: RequestMetadata,
+        payload: EncodeResult<Self::Payload>,
+    ) -> Self::Request {
+        let mut headers = HashMap::new();
+        headers.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("application/json"),
+        );
+
+        let content_length = match payload.compressed_byte_size {
+            Some(compressed_byte_size) => {
+                headers.insert(
+                    header::CONTENT_ENCODING,
+                    HeaderValue::from_str(self.compression.content_encoding().unwrap()).unwrap(),
+                );
+                compressed_byte_size
+            }
+            None => payload.uncompressed_byte_size,
+        };
+        headers.insert(
+            header::CONTENT_LENGTH,
+            HeaderValue::from_str(&content_length.to_string()).unwrap(),
+        );
+
+        ChronicleRequest {
+            headers,
+            body: payload.into_payload().bytes,
+            finalizers,
+            metadata,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use vector_lib::event::LogEvent;
+
+    use super::*;
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<ChronicleUdmConfig>();
+    }
+
+    fn config(toml: &str) -> ChronicleUdmConfig {
+        toml::from_str(toml).unwrap()
+    }
+
+    #[test]
+    fn defaults_to_us_endpoint() {
+        let config = config(indoc! {r#"
+            customer_id = "customer_id"
+            log_type = "WINDOWS_DNS"
+        "#});
+
+        assert_eq!(
+            config.create_endpoint("v2/udmevents:batchCreate").unwrap(),
+            "https://malachiteingestion-pa.googleapis.com/v2/udmevents:batchCreate"
+        );
+    }
+
+    #[test]
+    fn rejects_batches_over_chronicle_limits() {
+        let config = config(indoc! {r#"
+            customer_id = "customer_id"
+            log_type = "WINDOWS_DNS"
+            batch.max_events = 1001
+        "#});
+
+        assert!(config
+            .batch
+            .validate()
+            .unwrap()
+            .limit_max_events(MAX_REQUEST_EVENTS)
+            .is_err());
+    }
+
+    #[test]
+    fn encodes_udm_events() {
+        let encoder = ChronicleUdmEncoder {
+            customer_id: "customer_id".to_string(),
+            transformer: Default::default(),
+        };
+
+        let mut log = LogEvent::default();
+        log.insert("principal.hostname", "host");
+        log.insert(
+            "timestamp",
+            Utc.with_ymd_and_hms(2024, 1, 2, 3, 4, 5).unwrap(),
+        );
+        let mut typed = LogEvent::default();
+        typed.insert("metadata.log_type", "GCP_DNS");
+
+        let key = ChroniclePartitionKey {
+            log_type: "WINDOWS_DNS".to_string(),
+            namespace: None,
+        };
+        let mut body = Vec::new();
+        encoder
+            .encode_input((key, vec![log.into(), typed.into()]), &mut body)
+            .unwrap();
+
+        let body: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body["customer_id"], "customer_id");
+        let events = body["events"].as_array().unwrap();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0]["principal"]["hostname"], "host");
+        assert_eq!(events[0]["metadata"]["log_type"], "WINDOWS_DNS");
+        assert_eq!(
+            events[0]["metadata"]["event_timestamp"],
+            "2024-01-02T03:04:05Z"
+        );
+        assert_eq!(events[1]["metadata"]["log_type"], "GCP_DNS");
+    }
+}
@@ -124,7 +124,7 @@ pub enum Region {
 
 impl Region {
     /// Each region has a its own endpoint.
-    const fn endpoint(self) -> &'static str {
+    pub(super) const fn endpoint(self) -> &'static str {
         match self {
             Region::Eu => "https://europe-malachiteingestion-pa.googleapis.com",
             Region::Us => "https://malachiteingestion-pa.googleapis.com",
@@ -384,7 +384,7 @@ pub struct ChronicleRequest {
     pub body: Bytes,
     pub finalizers: EventFinalizers,
     pub headers: HashMap<HeaderName, HeaderValue>,
-    metadata: RequestMetadata,
+    pub metadata: RequestMetadata,
 }
 
 impl Finalizable for ChronicleRequest {
@@ -488,8 +488,8 @@ struct ChronicleRequestBuilder {
     compression: Compression,
 }
 
-struct ChronicleRequestPayload {
-    bytes: Bytes,
+pub(super) struct ChronicleRequestPayload {
+    pub(super) bytes: Bytes,
 }
 
 impl From<Bytes> for ChronicleRequestPayload {
@@ -1,4 +1,6 @@
 #[cfg(feature = "sinks-gcp-chronicle")]
+pub mod chronicle_udm;
+#[cfg(feature = "sinks-gcp-chronicle")]
 pub mod chronicle_unstructured;
 pub mod compression;
 pub mod partitioner;
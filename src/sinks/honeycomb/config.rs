@@ -4,6 +4,7 @@ use bytes::Bytes;
 use futures::FutureExt;
 use http::{Request, StatusCode, Uri};
 use vector_lib::configurable::configurable_component;
+use vector_lib::lookup::lookup_v2::ConfigTargetPath;
 use vector_lib::sensitive_string::SensitiveString;
 use vrl::value::Kind;
 
@@ -25,6 +26,9 @@ use super::{
 
 pub(super) const HTTP_HEADER_HONEYCOMB: &str = "X-Honeycomb-Team";
 
+/// The maximum size of the body of the requests accepted by Honeycomb.
+const MAX_BATCH_PAYLOAD_SIZE: usize = 5_000_000;
+
 /// Configuration for the `honeycomb` sink.
 #[configurable_component(sink("honeycomb", "Deliver log events to Honeycomb."))]
 #[derive(Clone, Debug)]
@@ -49,6 +53,15 @@ pub struct HoneycombConfig {
     // but this limits us in how we can do our healthcheck.
     dataset: String,
 
+    /// The event field holding the sample rate of each event.
+    ///
+    /// When set, the field is removed from the events, and its value is sent as the sample rate
+    /// of the event, which Honeycomb uses to weigh the event in its queries. Events without the
+    /// field, or where it doesn't hold a positive integer, are sent without a sample rate, which
+    /// Honeycomb treats as a sample rate of `1`.
+    #[configurable(metadata(docs::examples = "sample_rate"))]
+    sample_rate_field: Option<ConfigTargetPath>,
+
     #[configurable(derived)]
     #[serde(default)]
     batch: BatchConfig<HoneycombDefaultBatchSettings>,
@@ -102,11 +115,16 @@ impl GenerateConfig for HoneycombConfig {
 #[typetag::serde(name = "honeycomb")]
 impl SinkConfig for HoneycombConfig {
     async fn build(&self, cx: SinkContext) -> crate::Result<(VectorSink, Healthcheck)> {
-        let batch_settings = self.batch.validate()?.into_batcher_settings()?;
+        let batch_settings = self
+            .batch
+            .validate()?
+            .limit_max_bytes(MAX_BATCH_PAYLOAD_SIZE)?
+            .into_batcher_settings()?;
 
         let request_builder = HoneycombRequestBuilder {
             encoder: HoneycombEncoder {
                 transformer: self.encoding.clone(),
+                sample_rate_field: self.sample_rate_field.clone().map(|field| field.0),
             },
             compression: self.compression,
         };
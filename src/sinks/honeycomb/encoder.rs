@@ -4,6 +4,7 @@ use bytes::Bytes;
 use chrono::{SecondsFormat, Utc};
 use serde_json::{json, to_vec};
 use std::io;
+use vrl::path::OwnedTargetPath;
 
 use crate::sinks::{
     prelude::*,
@@ -12,6 +13,18 @@ use crate::sinks::{
 
 pub(super) struct HoneycombEncoder {
     pub(super) transformer: Transformer,
+    pub(super) sample_rate_field: Option<OwnedTargetPath>,
+}
+
+impl HoneycombEncoder {
+    /// Removes the sample rate from the event, if it holds a valid one.
+    fn take_sample_rate(&self, log: &mut LogEvent) -> Option<i64> {
+        let field = self.sample_rate_field.as_ref()?;
+        match log.remove(field) {
+            Some(Value::Integer(sample_rate)) if sample_rate > 0 => Some(sample_rate),
+            _ => None,
+        }
+    }
 }
 
 impl SinkEncoder<Vec<Event>> for HoneycombEncoder {
@@ -37,10 +50,15 @@ impl SinkEncoder<Vec<Event>> for HoneycombEncoder {
                 Utc::now()
             };
 
-            let data = json!({
+            let sample_rate = self.take_sample_rate(log);
+
+            let mut data = json!({
                 "time": timestamp.to_rfc3339_opts(SecondsFormat::Nanos, true),
                 "data": log.convert_to_fields(),
             });
+            if let Some(sample_rate) = sample_rate {
+                data["samplerate"] = sample_rate.into();
+            }
 
             json_events.push(data);
         }
@@ -50,3 +68,62 @@ impl SinkEncoder<Vec<Event>> for HoneycombEncoder {
         write_all(writer, n_events, body.as_ref()).map(|()| (body.len(), byte_size))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use vrl::owned_event_path;
+
+    use super::*;
+
+    fn encode(
+        sample_rate_field: Option<OwnedTargetPath>,
+        logs: Vec<LogEvent>,
+    ) -> serde_json::Value {
+        let encoder = HoneycombEncoder {
+            transformer: Transformer::default(),
+            sample_rate_field,
+        };
+        let mut body = Vec::new();
+        encoder
+            .encode_input(logs.into_iter().map(Event::Log).collect(), &mut body)
+            .unwrap();
+        serde_json::from_slice(&body).unwrap()
+    }
+
+    #[test]
+    fn encodes_sample_rates() {
+        let mut sampled = LogEvent::from("sampled");
+        sampled.insert(
+            "timestamp",
+            Utc.with_ymd_and_hms(2024, 3, 5, 8, 9, 10).unwrap(),
+        );
+        sampled.insert("sample_rate", 20);
+        let mut invalid = LogEvent::from("invalid");
+        invalid.insert(
+            "timestamp",
+            Utc.with_ymd_and_hms(2024, 3, 5, 8, 9, 11).unwrap(),
+        );
+        invalid.insert("sample_rate", 0);
+
+        let body = encode(
+            Some(owned_event_path!("sample_rate")),
+            vec![sampled, invalid],
+        );
+
+        assert_eq!(
+            body,
+            json!([
+                {
+                    "time": "2024-03-05T08:09:10.000000000Z",
+                    "data": { "message": "sampled" },
+                    "samplerate": 20,
+                },
+                {
+                    "time": "2024-03-05T08:09:11.000000000Z",
+                    "data": { "message": "invalid" },
+                },
+            ])
+        );
+    }
+}
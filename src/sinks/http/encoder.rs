@@ -7,17 +7,44 @@ use crate::{
 use bytes::{BufMut, BytesMut};
 use std::io;
 use tokio_util::codec::Encoder as _;
-use vector_lib::codecs::{
-    encoding::{
-        Framer,
-        Framer::{CharacterDelimited, NewlineDelimited},
-        Serializer::Json,
+use vector_lib::{
+    codecs::{
+        encoding::{
+            Framer,
+            Framer::{CharacterDelimited, NewlineDelimited},
+            Serializer::{Gelf, Json},
+        },
+        gelf_fields, CharacterDelimitedEncoder,
     },
-    CharacterDelimitedEncoder,
+    event::ObjectMap,
+    schema::meaning,
 };
 
 use crate::sinks::prelude::*;
 
+/// The fields of GELF messages, which the additional fields of events must not collide with.
+/// `_id` is reserved by Graylog for the identifier of stored messages.
+const GELF_RESERVED_FIELDS: [&str; 10] = [
+    gelf_fields::VERSION,
+    gelf_fields::HOST,
+    gelf_fields::SHORT_MESSAGE,
+    gelf_fields::FULL_MESSAGE,
+    gelf_fields::TIMESTAMP,
+    gelf_fields::LEVEL,
+    gelf_fields::FACILITY,
+    gelf_fields::LINE,
+    gelf_fields::FILE,
+    "id",
+];
+
+/// The prefix of additional fields whose names collide with the reserved fields of GELF.
+const GELF_COLLISION_PREFIX: &str = "_vector_";
+
+/// The syslog severity names, in the order of the numeric levels of GELF.
+const SYSLOG_SEVERITIES: [&str; 8] = [
+    "emerg", "alert", "crit", "err", "warning", "notice", "info", "debug",
+];
+
 #[derive(Clone, Debug)]
 pub(super) struct HttpEncoder {
     pub(super) encoder: Encoder<Framer>,
@@ -66,6 +93,10 @@ impl SinkEncoder<Vec<Event>> for HttpEncoder {
 
             byte_size.add_event(&event, event.estimated_json_encoded_size_of());
 
+            if let Gelf(_) = self.encoder.serializer() {
+                event = Event::Log(gelf_log(event.into_log()));
+            }
+
             encoder
                 .encode(event, &mut body)
                 .map_err(|_| io::Error::new(io::ErrorKind::Other, "unable to encode event"))?;
@@ -95,3 +126,136 @@ impl SinkEncoder<Vec<Event>> for HttpEncoder {
         write_all(writer, n_events, body.as_ref()).map(|()| (body.len(), byte_size))
     }
 }
+
+/// Converts a log event to a GELF 1.1 message:
+///
+/// - `short_message`, `host` and `timestamp` are taken from the message, host and timestamp of
+///   the event.
+/// - `level` is taken from the field with the `severity` meaning, either as a numeric level or as
+///   a syslog severity name.
+/// - All the other fields of the event become additional fields, prefixed with `_`, with nested
+///   fields flattened into dotted names. Those colliding with the reserved fields of GELF are
+///   prefixed with `_vector_` instead.
+fn gelf_log(mut log: LogEvent) -> LogEvent {
+    let mut gelf = ObjectMap::new();
+    gelf.insert(
+        gelf_fields::VERSION.into(),
+        gelf_fields::GELF_VERSION.into(),
+    );
+
+    if !log.value().is_object() {
+        let message = std::mem::replace(log.value_mut(), Value::Object(ObjectMap::new()));
+        gelf.insert(gelf_fields::SHORT_MESSAGE.into(), gelf_string(message));
+    }
+    let paths = [
+        (gelf_fields::SHORT_MESSAGE, log.message_path().cloned()),
+        (gelf_fields::HOST, log.host_path().cloned()),
+        (gelf_fields::TIMESTAMP, log.timestamp_path().cloned()),
+        (
+            gelf_fields::LEVEL,
+            log.find_key_by_meaning(meaning::SEVERITY).cloned(),
+        ),
+    ];
+    for (name, path) in paths {
+        let Some(value) = path.and_then(|path| log.remove(&path)) else {
+            continue;
+        };
+        let value = match (name, value) {
+            (gelf_fields::LEVEL, Value::Bytes(severity)) => {
+                syslog_level(&severity).map_or(Value::Bytes(severity), Value::Integer)
+            }
+            (_, value) => value,
+        };
+        let value = match (name, value) {
+            (gelf_fields::SHORT_MESSAGE | gelf_fields::HOST, value) => Some(gelf_string(value)),
+            (gelf_fields::TIMESTAMP, value @ (Value::Timestamp(_) | Value::Integer(_))) => {
+                Some(value)
+            }
+            (gelf_fields::LEVEL, value @ Value::Integer(_)) => Some(value),
+            // Values GELF can't represent in its fields are kept as additional fields.
+            (name, value) => {
+                insert_additional_field(&mut gelf, name.to_string(), value);
+                None
+            }
+        };
+        if let Some(value) = value {
+            gelf.insert(name.into(), value);
+        }
+    }
+
+    let (value, metadata) = log.into_parts();
+    if let Value::Object(fields) = value {
+        for (name, value) in fields {
+            insert_additional_field(&mut gelf, name.to_string(), value);
+        }
+    }
+
+    LogEvent::from_parts(Value::Object(gelf), metadata)
+}
+
+/// Inserts a field of the event as additional fields of a GELF message, whose values can only be
+/// strings or numbers.
+fn insert_additional_field(gelf: &mut ObjectMap, name: String, value: Value) {
+    let value = match value {
+        Value::Object(fields) => {
+            for (key, value) in fields {
+                insert_additional_field(gelf, format!("{name}.{key}"), value);
+            }
+            return;
+        }
+        Value::Null => return,
+        Value::Bytes(_) | Value::Integer(_) | Value::Float(_) => value,
+        Value::Array(_) => match serde_json::to_string(&value) {
+            Ok(json) => json.into(),
+            Err(_) => return,
+        },
+        value => gelf_string(value),
+    };
+    gelf.insert(additional_field_name(&name).into(), value);
+}
+
+/// Returns the name of an additional field of a GELF message, which must be prefixed with `_` and
+/// only contain word characters, dashes, periods and `@`.
+fn additional_field_name(name: &str) -> String {
+    let name = name
+        .chars()
+        .map(|c| match c {
+            c if c.is_alphanumeric() => c,
+            '_' | '-' | '.' | '@' => c,
+            _ => '_',
+        })
+        .collect::<String>();
+    let unprefixed = name.strip_prefix('_').unwrap_or(&name);
+
+    if GELF_RESERVED_FIELDS.contains(&unprefixed) {
+        format!("{GELF_COLLISION_PREFIX}{unprefixed}")
+    } else if name.starts_with('_') {
+        name
+    } else {
+        format!("_{name}")
+    }
+}
+
+fn gelf_string(value: Value) -> Value {
+    match value {
+        Value::Bytes(_) => value,
+        value => value.to_string_lossy().into_owned().into(),
+    }
+}
+
+/// Returns the numeric level of a syslog severity name, such as `warning` or `err`.
+fn syslog_level(severity: &[u8]) -> Option<i64> {
+    let severity = String::from_utf8_lossy(severity).to_lowercase();
+    let severity = match severity.as_str() {
+        "emergency" | "panic" => "emerg",
+        "critical" => "crit",
+        "error" => "err",
+        "warn" => "warning",
+        "informational" => "info",
+        severity => severity,
+    };
+    SYSLOG_SEVERITIES
+        .iter()
+        .position(|name| *name == severity)
+        .map(|level| level as i64)
+}
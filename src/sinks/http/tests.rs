@@ -10,7 +10,8 @@ use hyper::{Body, Method, Response, StatusCode};
 use serde::{de, Deserialize};
 use vector_lib::codecs::{
     encoding::{Framer, FramingConfig},
-    JsonSerializerConfig, NewlineDelimitedEncoderConfig, TextSerializerConfig,
+    GelfSerializerConfig, JsonSerializerConfig, NewlineDelimitedEncoderConfig,
+    TextSerializerConfig,
 };
 
 use vector_lib::event::{BatchNotifier, BatchStatus, Event, LogEvent};
@@ -112,6 +113,54 @@ fn http_encode_event_ndjson() {
     assert_eq!(output.message, "hello world".to_string());
 }
 
+#[test]
+fn http_encode_event_gelf() {
+    let mut log = LogEvent::from("hello world");
+    log.insert("host", "web-1");
+    log.insert(
+        "timestamp",
+        chrono::DateTime::parse_from_rfc3339("2024-01-15T10:30:00.5Z")
+            .unwrap()
+            .with_timezone(&chrono::Utc),
+    );
+    log.insert("user.id", 42);
+    log.insert("tags", vec!["a", "b"]);
+    log.insert("_id", "abc");
+    log.insert("file name", "app.log");
+
+    let cfg = default_cfg(
+        (
+            Some(NewlineDelimitedEncoderConfig::new()),
+            GelfSerializerConfig::new(),
+        )
+            .into(),
+    );
+    let encoder = cfg.build_encoder().unwrap();
+    let transformer = cfg.encoding.transformer();
+
+    let encoder = HttpEncoder::new(encoder, transformer, "".to_owned(), "".to_owned());
+
+    let mut encoded = vec![];
+    encoder
+        .encode_input(vec![log.into()], &mut encoded)
+        .unwrap();
+
+    let output = serde_json::from_slice::<serde_json::Value>(&encoded[..]).unwrap();
+    assert_eq!(
+        output,
+        serde_json::json!({
+            "version": "1.1",
+            "host": "web-1",
+            "short_message": "hello world",
+            "timestamp": 1705314600.5,
+            "_user.id": 42,
+            "_tags": r#"["a","b"]"#,
+            "_vector_id": "abc",
+            "_file_name": "app.log",
+        })
+    );
+}
+
 #[test]
 fn http_validates_normal_headers() {
     let config = r#"
@@ -9,11 +9,12 @@ use vector_lib::lookup::lookup_v2::ConfigTargetPath;
 use vrl::value::Kind;
 
 use crate::{
-    kafka::{KafkaAuthConfig, KafkaCompression},
+    kafka::{schema_registry::SubjectNameStrategy, KafkaAuthConfig, KafkaCompression},
     serde::json::to_string,
     sinks::{
         kafka::sink::{healthcheck, KafkaSink},
         prelude::*,
+        util::UriSerde,
     },
 };
 
@@ -62,9 +63,36 @@ pub struct KafkaSinkConfig {
     #[configurable(metadata(docs::examples = "%my_topic"))]
     pub key_field: Option<ConfigTargetPath>,
 
+    /// A VRL program computing the key of each message.
+    ///
+    /// The value returned by the program is converted to a string. Messages for which the program
+    /// returns `null`, or fails, are sent without a key. The program can't modify the events.
+    /// Can't be used along with `key_field`.
+    #[configurable(metadata(docs::advanced))]
+    #[configurable(metadata(docs::examples = "string(to_int!(.tenant_id) % 16)"))]
+    pub key_vrl: Option<String>,
+
     #[configurable(derived)]
     pub encoding: EncodingConfig,
 
+    /// The URL of a [Confluent Schema Registry][schema_registry] to register the schema of the
+    /// `avro` codec with.
+    ///
+    /// When set, the schema is looked up, or registered if it's missing, under the subject named
+    /// by `subject_name_strategy`, and the messages are prefixed with its ID, following the
+    /// [wire format][wire_format]. Schema IDs are cached for the lifetime of the sink.
+    ///
+    /// [schema_registry]: https://docs.confluent.io/platform/current/schema-registry/index.html
+    /// [wire_format]: https://docs.confluent.io/platform/current/schema-registry/fundamentals/serdes-develop/index.html#wire-format
+    #[configurable(metadata(docs::advanced))]
+    #[configurable(metadata(docs::examples = "http://localhost:8081"))]
+    pub schema_registry_url: Option<UriSerde>,
+
+    #[configurable(derived)]
+    #[configurable(metadata(docs::advanced))]
+    #[serde(default)]
+    pub subject_name_strategy: SubjectNameStrategy,
+
     // These batching options will **not** override librdkafka_options values.
     #[configurable(derived)]
     #[configurable(metadata(docs::advanced))]
@@ -123,12 +151,23 @@ pub struct KafkaSinkConfig {
 
     /// The log field name to use for the Kafka headers.
     ///
+    /// The field must hold an object, whose keys are the names of the headers. The values which
+    /// aren't strings are written as their string representation.
+    ///
     /// If omitted, no headers are written.
     #[configurable(metadata(docs::advanced))]
     #[serde(alias = "headers_field")] // accidentally released as `headers_field` in 0.18
     #[configurable(metadata(docs::examples = "headers"))]
     pub headers_key: Option<ConfigTargetPath>,
 
+    /// The maximum size of the value of a Kafka header, in bytes.
+    ///
+    /// Longer values read from the `headers_key` field are truncated.
+    #[configurable(metadata(docs::advanced))]
+    #[configurable(metadata(docs::type_unit = "bytes"))]
+    #[serde(default = "default_max_header_value_bytes")]
+    pub max_header_value_bytes: usize,
+
     #[configurable(derived)]
     #[serde(
         default,
@@ -154,6 +193,10 @@ const fn default_rate_limit_num() -> u64 {
     i64::MAX as u64 // i64 avoids TOML deserialize issue
 }
 
+const fn default_max_header_value_bytes() -> usize {
+    1024
+}
+
 fn example_librdkafka_options() -> HashMap<String, String> {
     HashMap::<_, _>::from_iter([
         ("client.id".to_string(), "${ENV_VAR}".to_string()),
@@ -259,7 +302,10 @@ impl GenerateConfig for KafkaSinkConfig {
             topic: Template::try_from("topic-1234".to_owned()).unwrap(),
             healthcheck_topic: None,
             key_field: Some(ConfigTargetPath::try_from("user_id".to_owned()).unwrap()),
+            key_vrl: None,
             encoding: JsonSerializerConfig::default().into(),
+            schema_registry_url: None,
+            subject_name_strategy: Default::default(),
             batch: Default::default(),
             compression: KafkaCompression::None,
             auth: Default::default(),
@@ -269,6 +315,7 @@ impl GenerateConfig for KafkaSinkConfig {
             rate_limit_num: default_rate_limit_num(),
             librdkafka_options: Default::default(),
             headers_key: None,
+            max_header_value_bytes: default_max_header_value_bytes(),
             acknowledgements: Default::default(),
         })
         .unwrap()
@@ -279,7 +326,7 @@ impl GenerateConfig for KafkaSinkConfig {
 #[typetag::serde(name = "kafka")]
 impl SinkConfig for KafkaSinkConfig {
     async fn build(&self, cx: SinkContext) -> crate::Result<(VectorSink, Healthcheck)> {
-        let sink = KafkaSink::new(self.clone())?;
+        let sink = KafkaSink::new(self.clone(), cx.proxy())?;
         let hc = healthcheck(self.clone(), cx.healthcheck.clone()).boxed();
         Ok((VectorSink::from_event_streamsink(sink), hc))
     }
@@ -304,4 +351,22 @@ mod tests {
     fn generate_config() {
         KafkaSinkConfig::generate_config();
     }
+
+    #[test]
+    fn schema_registry_requires_avro_codec() {
+        let config: KafkaSinkConfig = toml::from_str(
+            r#"
+            bootstrap_servers = "localhost:9092"
+            topic = "logs"
+            encoding.codec = "json"
+            schema_registry_url = "http://localhost:8081"
+            "#,
+        )
+        .unwrap();
+
+        let Err(error) = KafkaSink::new(config, &Default::default()) else {
+            panic!("The sink shouldn't build without the `avro` codec");
+        };
+        assert!(error.to_string().contains("`avro` codec"));
+    }
 }
@@ -0,0 +1,172 @@
+use std::{
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use bytes::Bytes;
+use vector_lib::{
+    compile_vrl, emit,
+    event::{Event, VrlTarget},
+    TimeZone,
+};
+use vrl::{
+    compiler::{
+        runtime::{Runtime, Terminate},
+        CompilationResult, CompileConfig, Program, TypeState,
+    },
+    diagnostic::Formatter,
+    value::Value,
+};
+
+use crate::{config::LogNamespace, event::TargetEvents, internal_events::KafkaKeyVrlError};
+
+/// How often the failures of the program are reported, along with the number of events it failed
+/// on since the previous report.
+const FAILURE_REPORT_INTERVAL: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Default)]
+struct Failures {
+    count: usize,
+    reported_at: Option<Instant>,
+}
+
+/// Computes the key of the messages with a VRL program, such as
+/// `string(to_int!(.tenant_id) % 16)`.
+///
+/// The value returned by the program is coerced to a string. Messages for which the program
+/// returns `null`, or fails, are sent without a key.
+#[derive(Debug)]
+pub struct KeyVrl {
+    program: Program,
+    failures: Mutex<Failures>,
+}
+
+impl KeyVrl {
+    /// Compiles the VRL program, returning its formatted diagnostics if it doesn't compile.
+    pub fn new(source: &str) -> crate::Result<Self> {
+        let functions = vrl::stdlib::all()
+            .into_iter()
+            .chain(vector_vrl_functions::all())
+            .collect::<Vec<_>>();
+
+        let state = TypeState::default();
+
+        // The program only computes a key, the events are sent as they are.
+        let mut config = CompileConfig::default();
+        config.set_read_only();
+
+        let CompilationResult {
+            program,
+            warnings,
+            config: _,
+        } = compile_vrl(source, &functions, &state, config)
+            .map_err(|diagnostics| Formatter::new(source, diagnostics).colored().to_string())?;
+
+        if !warnings.is_empty() {
+            let warnings = Formatter::new(source, warnings).colored().to_string();
+            warn!(message = "VRL compilation warning.", %warnings);
+        }
+
+        Ok(Self {
+            program,
+            failures: Mutex::default(),
+        })
+    }
+
+    /// Runs the program against the event, returning the event along with the key it computed.
+    pub fn key(&self, event: Event) -> (Event, Option<Bytes>) {
+        let log_namespace = event
+            .maybe_as_log()
+            .map(|log| log.namespace())
+            .unwrap_or(LogNamespace::Legacy);
+        let mut target = VrlTarget::new(event, self.program.info(), false);
+        let result = Runtime::default().resolve(&mut target, &self.program, &TimeZone::default());
+        let event = match target.into_events(log_namespace) {
+            TargetEvents::One(event) => event,
+            _ => panic!("Event was modified by a read-only program. This is an internal error."),
+        };
+
+        let key = match result {
+            Ok(Value::Null) => None,
+            Ok(value) => Some(value.coerce_to_bytes()),
+            Err(error) => {
+                self.report_failure(&error);
+                None
+            }
+        };
+        (event, key)
+    }
+
+    /// Counts a failure of the program, reporting it if no failure was reported recently.
+    fn report_failure(&self, error: &Terminate) {
+        let mut failures = self.failures.lock().expect("failures lock poisoned");
+        failures.count += 1;
+        if failures
+            .reported_at
+            .is_some_and(|reported_at| reported_at.elapsed() < FAILURE_REPORT_INTERVAL)
+        {
+            return;
+        }
+
+        emit!(KafkaKeyVrlError {
+            error: &error.to_string(),
+            count: failures.count,
+        });
+        *failures = Failures {
+            count: 0,
+            reported_at: Some(Instant::now()),
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vector_lib::event::LogEvent;
+
+    use super::*;
+
+    fn event(fields: &[(&str, Value)]) -> Event {
+        let mut log = LogEvent::from("message");
+        for (field, value) in fields {
+            log.insert(*field, value.clone());
+        }
+        Event::Log(log)
+    }
+
+    #[test]
+    fn computes_key() {
+        let key_vrl = KeyVrl::new("to_int!(.tenant_id) % 16").unwrap();
+
+        let input = event(&[("tenant_id", Value::from("42"))]);
+        let (output, key) = key_vrl.key(input.clone());
+        assert_eq!(key.as_deref(), Some("10".as_bytes()));
+        assert_eq!(output, input);
+    }
+
+    #[test]
+    fn sends_without_key() {
+        let key_vrl = KeyVrl::new(".tenant_id").unwrap();
+        assert_eq!(key_vrl.key(event(&[])).1, None);
+
+        let key_vrl = KeyVrl::new("string!(.tenant_id)").unwrap();
+        assert_eq!(key_vrl.key(event(&[])).1, None);
+    }
+
+    #[test]
+    fn aggregates_failures() {
+        let key_vrl = KeyVrl::new("string!(.tenant_id)").unwrap();
+        for _ in 0..3 {
+            key_vrl.key(event(&[]));
+        }
+
+        // The first failure is reported right away, the next ones along with the next report.
+        let failures = key_vrl.failures.lock().unwrap();
+        assert_eq!(failures.count, 2);
+        assert!(failures.reported_at.is_some());
+    }
+
+    #[test]
+    fn reports_compilation_errors() {
+        assert!(KeyVrl::new(".tenant_id +").is_err());
+    }
+}
@@ -1,4 +1,5 @@
 pub(crate) mod config;
+pub(crate) mod key_vrl;
 pub(crate) mod request_builder;
 pub(crate) mod service;
 pub(crate) mod sink;
@@ -3,21 +3,28 @@ use rdkafka::message::{Header, OwnedHeaders};
 use vector_lib::lookup::OwnedTargetPath;
 
 use crate::{
-    internal_events::KafkaHeaderExtractionError,
+    internal_events::{KafkaHeaderExtractionError, KafkaHeadersMissing},
+    kafka::schema_registry::prefix_schema_id,
     sinks::{
-        kafka::service::{KafkaRequest, KafkaRequestMetadata},
+        kafka::{
+            key_vrl::KeyVrl,
+            service::{KafkaRequest, KafkaRequestMetadata},
+        },
         prelude::*,
     },
 };
 
 pub struct KafkaRequestBuilder {
     pub key_field: Option<OwnedTargetPath>,
+    pub key_vrl: Option<KeyVrl>,
     pub headers_key: Option<OwnedTargetPath>,
+    pub max_header_value_bytes: usize,
     pub encoder: (Transformer, Encoder<()>),
 }
 
-impl RequestBuilder<(String, Event)> for KafkaRequestBuilder {
-    type Metadata = KafkaRequestMetadata;
+// The input is the topic of the event, and the ID of the schema its message is prefixed with.
+impl RequestBuilder<(String, Option<u32>, Event)> for KafkaRequestBuilder {
+    type Metadata = (KafkaRequestMetadata, Option<u32>);
     type Events = Event;
     type Encoder = (Transformer, Encoder<()>);
     type Payload = Bytes;
@@ -34,30 +41,45 @@ impl RequestBuilder<(String, Event)> for KafkaRequestBuilder {
 
     fn split_input(
         &self,
-        input: (String, Event),
+        input: (String, Option<u32>, Event),
     ) -> (Self::Metadata, RequestMetadataBuilder, Self::Events) {
-        let (topic, mut event) = input;
+        let (topic, schema_id, event) = input;
+        let (mut event, key) = match &self.key_vrl {
+            Some(key_vrl) => key_vrl.key(event),
+            None => {
+                let key = get_key(&event, self.key_field.as_ref());
+                (event, key)
+            }
+        };
         let builder = RequestMetadataBuilder::from_event(&event);
 
         let metadata = KafkaRequestMetadata {
             finalizers: event.take_finalizers(),
-            key: get_key(&event, self.key_field.as_ref()),
+            key,
             timestamp_millis: get_timestamp_millis(&event),
-            headers: get_headers(&event, self.headers_key.as_ref()),
+            headers: get_headers(
+                &event,
+                self.headers_key.as_ref(),
+                self.max_header_value_bytes,
+            ),
             topic,
         };
 
-        (metadata, builder, event)
+        ((metadata, schema_id), builder, event)
     }
 
     fn build_request(
         &self,
-        metadata: Self::Metadata,
+        (metadata, schema_id): Self::Metadata,
         request_metadata: RequestMetadata,
         payload: EncodeResult<Self::Payload>,
     ) -> Self::Request {
+        let body = payload.into_payload();
         KafkaRequest {
-            body: payload.into_payload(),
+            body: match schema_id {
+                Some(schema_id) => prefix_schema_id(schema_id, &body),
+                None => body,
+            },
             metadata,
             request_metadata,
         }
@@ -84,37 +106,40 @@ fn get_timestamp_millis(event: &Event) -> Option<i64> {
     .map(|ts| ts.timestamp_millis())
 }
 
-fn get_headers(event: &Event, headers_key: Option<&OwnedTargetPath>) -> Option<OwnedHeaders> {
-    headers_key.and_then(|headers_key| {
-        if let Event::Log(log) = event {
-            if let Some(headers) = log.get(headers_key) {
-                match headers {
-                    Value::Object(headers_map) => {
-                        let mut owned_headers = OwnedHeaders::new_with_capacity(headers_map.len());
-                        for (key, value) in headers_map {
-                            if let Value::Bytes(value_bytes) = value {
-                                owned_headers = owned_headers.insert(Header {
-                                    key,
-                                    value: Some(value_bytes.as_ref()),
-                                });
-                            } else {
-                                emit!(KafkaHeaderExtractionError {
-                                    header_field: headers_key
-                                });
-                            }
-                        }
-                        return Some(owned_headers);
-                    }
-                    _ => {
-                        emit!(KafkaHeaderExtractionError {
-                            header_field: headers_key
-                        });
-                    }
-                }
+fn get_headers(
+    event: &Event,
+    headers_key: Option<&OwnedTargetPath>,
+    max_header_value_bytes: usize,
+) -> Option<OwnedHeaders> {
+    let headers_key = headers_key?;
+    let Event::Log(log) = event else {
+        return None;
+    };
+    match log.get(headers_key) {
+        Some(Value::Object(headers_map)) => {
+            let mut owned_headers = OwnedHeaders::new_with_capacity(headers_map.len());
+            for (key, value) in headers_map {
+                let value = value.coerce_to_bytes();
+                owned_headers = owned_headers.insert(Header {
+                    key,
+                    value: Some(&value[..value.len().min(max_header_value_bytes)]),
+                });
             }
+            Some(owned_headers)
         }
-        None
-    })
+        Some(_) => {
+            emit!(KafkaHeaderExtractionError {
+                header_field: headers_key
+            });
+            None
+        }
+        None => {
+            emit!(KafkaHeadersMissing {
+                header_field: headers_key
+            });
+            None
+        }
+    }
 }
 
 #[cfg(test)]
@@ -135,10 +160,36 @@ mod tests {
         let mut event = Event::Log(LogEvent::from("hello"));
         event.as_mut_log().insert(&headers_key, header_values);
 
-        let headers = get_headers(&event, Some(&headers_key)).unwrap();
+        let headers = get_headers(&event, Some(&headers_key), 1024).unwrap();
         assert_eq!(headers.get(0).key, "a-key");
         assert_eq!(headers.get(0).value.unwrap(), "a-value".as_bytes());
         assert_eq!(headers.get(1).key, "b-key");
         assert_eq!(headers.get(1).value.unwrap(), "b-value".as_bytes());
     }
+
+    #[test]
+    fn kafka_get_headers_converts_and_truncates_values() {
+        let headers_key = OwnedTargetPath::try_from("headers".to_string()).unwrap();
+        let mut header_values = ObjectMap::new();
+        header_values.insert("a-key".into(), Value::Bytes(Bytes::from("a-long-value")));
+        header_values.insert("b-key".into(), Value::Integer(42));
+
+        let mut event = Event::Log(LogEvent::from("hello"));
+        event.as_mut_log().insert(&headers_key, header_values);
+
+        let headers = get_headers(&event, Some(&headers_key), 6).unwrap();
+        assert_eq!(headers.get(0).value.unwrap(), "a-long".as_bytes());
+        assert_eq!(headers.get(1).value.unwrap(), "42".as_bytes());
+    }
+
+    #[test]
+    fn kafka_get_headers_skips_invalid_values() {
+        let headers_key = OwnedTargetPath::try_from("headers".to_string()).unwrap();
+        let mut event = Event::Log(LogEvent::from("hello"));
+
+        assert!(get_headers(&event, Some(&headers_key), 1024).is_none());
+
+        event.as_mut_log().insert(&headers_key, "not-an-object");
+        assert!(get_headers(&event, Some(&headers_key), 1024).is_none());
+    }
 }
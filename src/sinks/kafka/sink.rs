@@ -8,14 +8,19 @@ use rdkafka::{
 use snafu::{ResultExt, Snafu};
 use tower::limit::RateLimit;
 use tracing::Span;
+use vector_lib::{codecs::encoding::SerializerConfig, internal_event::error_stage};
 use vrl::path::OwnedTargetPath;
 
 use super::config::KafkaSinkConfig;
 use crate::{
-    config::SinkHealthcheckOptions,
-    kafka::KafkaStatisticsContext,
+    config::{ProxyConfig, SinkHealthcheckOptions},
+    internal_events::KafkaSchemaRegistryError,
+    kafka::{
+        schema_registry::{SchemaRegistry, SubjectNameStrategy},
+        KafkaStatisticsContext,
+    },
     sinks::{
-        kafka::{request_builder::KafkaRequestBuilder, service::KafkaService},
+        kafka::{key_vrl::KeyVrl, request_builder::KafkaRequestBuilder, service::KafkaService},
         prelude::*,
     },
 };
@@ -33,7 +38,46 @@ pub struct KafkaSink {
     service: RateLimit<KafkaService>,
     topic: Template,
     key_field: Option<OwnedTargetPath>,
+    key_vrl: Option<KeyVrl>,
     headers_key: Option<OwnedTargetPath>,
+    max_header_value_bytes: usize,
+    registered_schema: Option<RegisteredSchema>,
+}
+
+/// The Avro schema of the encoder, registered in a schema registry.
+struct RegisteredSchema {
+    registry: SchemaRegistry,
+    subject_name_strategy: SubjectNameStrategy,
+    record_name: String,
+    schema: String,
+}
+
+impl RegisteredSchema {
+    fn new(config: &KafkaSinkConfig, proxy: &ProxyConfig) -> crate::Result<Option<Self>> {
+        let Some(url) = &config.schema_registry_url else {
+            return Ok(None);
+        };
+        let SerializerConfig::Avro { avro } = config.encoding.config() else {
+            return Err("`schema_registry_url` can only be used with the `avro` codec".into());
+        };
+        let record_name = apache_avro::Schema::parse_str(&avro.schema)?
+            .name()
+            .map(|name| name.fullname(None))
+            .ok_or("The Avro schema registered in the schema registry must be named")?;
+
+        Ok(Some(Self {
+            registry: SchemaRegistry::new(url, proxy)?,
+            subject_name_strategy: config.subject_name_strategy,
+            record_name,
+            schema: avro.schema.clone(),
+        }))
+    }
+
+    /// Returns the ID of the schema for the messages sent to the topic.
+    async fn id(&self, topic: &str) -> crate::Result<u32> {
+        let subject = self.subject_name_strategy.subject(topic, &self.record_name);
+        self.registry.schema_id(&subject, &self.schema).await
+    }
 }
 
 pub(crate) fn create_producer(
@@ -49,7 +93,13 @@ pub(crate) fn create_producer(
 }
 
 impl KafkaSink {
-    pub(crate) fn new(config: KafkaSinkConfig) -> crate::Result<Self> {
+    pub(crate) fn new(config: KafkaSinkConfig, proxy: &ProxyConfig) -> crate::Result<Self> {
+        if config.key_field.is_some() && config.key_vrl.is_some() {
+            return Err("`key_field` and `key_vrl` can't be used together".into());
+        }
+        let key_vrl = config.key_vrl.as_deref().map(KeyVrl::new).transpose()?;
+        let registered_schema = RegisteredSchema::new(&config, proxy)?;
+
         let producer_config = config.to_rdkafka()?;
         let producer = create_producer(producer_config)?;
         let transformer = config.encoding.transformer();
@@ -58,6 +108,7 @@ impl KafkaSink {
 
         Ok(KafkaSink {
             headers_key: config.headers_key.map(|key| key.0),
+            max_header_value_bytes: config.max_header_value_bytes,
             transformer,
             encoder,
             service: ServiceBuilder::new()
@@ -68,15 +119,20 @@ impl KafkaSink {
                 .service(KafkaService::new(producer)),
             topic: config.topic,
             key_field: config.key_field.map(|key| key.0),
+            key_vrl,
+            registered_schema,
         })
     }
 
     async fn run_inner(self: Box<Self>, input: BoxStream<'_, Event>) -> Result<(), ()> {
         let request_builder = KafkaRequestBuilder {
             key_field: self.key_field,
+            key_vrl: self.key_vrl,
             headers_key: self.headers_key,
+            max_header_value_bytes: self.max_header_value_bytes,
             encoder: (self.transformer, self.encoder),
         };
+        let registered_schema = self.registered_schema.as_ref();
 
         input
             .filter_map(|event| {
@@ -95,11 +151,30 @@ impl KafkaSink {
                         .map(|topic| (topic, event)),
                 )
             })
+            .filter_map(|(topic, event)| async move {
+                // Resolve the ID of the schema the message is prefixed with.
+                let Some(registered_schema) = registered_schema else {
+                    return Some((topic, None, event));
+                };
+                match registered_schema.id(&topic).await {
+                    Ok(schema_id) => Some((topic, Some(schema_id), event)),
+                    Err(error) => {
+                        emit!(KafkaSchemaRegistryError {
+                            error: &error,
+                            stage: error_stage::PROCESSING,
+                        });
+                        None
+                    }
+                }
+            })
             .request_builder(default_request_builder_concurrency_limit(), request_builder)
             .filter_map(|request| async {
                 match request {
                     Err(error) => {
-                        emit!(SinkRequestBuildError { error });
+                        emit!(SinkRequestBuildError {
+                            error,
+                            error_code: "io_error",
+                        });
                         None
                     }
                     Ok(req) => Some(req),
@@ -53,7 +53,10 @@ mod integration_test {
             topic: Template::try_from(topic.clone()).unwrap(),
             healthcheck_topic: None,
             key_field: None,
+            key_vrl: None,
             encoding: TextSerializerConfig::default().into(),
+            schema_registry_url: None,
+            subject_name_strategy: Default::default(),
             batch: BatchConfig::default(),
             compression: KafkaCompression::None,
             auth: KafkaAuthConfig::default(),
@@ -63,6 +66,7 @@ mod integration_test {
             rate_limit_num: i64::MAX as u64,
             librdkafka_options: HashMap::new(),
             headers_key: None,
+            max_header_value_bytes: 1024,
             acknowledgements: Default::default(),
         };
         self::sink::healthcheck(config, Default::default())
@@ -81,7 +85,10 @@ mod integration_test {
             topic: Template::try_from(topic.clone()).unwrap(),
             healthcheck_topic: Some(String::from("topic-1234")),
             key_field: None,
+            key_vrl: None,
             encoding: TextSerializerConfig::default().into(),
+            schema_registry_url: None,
+            subject_name_strategy: Default::default(),
             batch: BatchConfig::default(),
             compression: KafkaCompression::None,
             auth: KafkaAuthConfig::default(),
@@ -91,6 +98,7 @@ mod integration_test {
             rate_limit_num: i64::MAX as u64,
             librdkafka_options: HashMap::new(),
             headers_key: None,
+            max_header_value_bytes: 1024,
             acknowledgements: Default::default(),
         };
         self::sink::healthcheck(config, Default::default())
@@ -182,7 +190,10 @@ mod integration_test {
             compression: KafkaCompression::None,
             healthcheck_topic: None,
             encoding: TextSerializerConfig::default().into(),
+            schema_registry_url: None,
+            subject_name_strategy: Default::default(),
             key_field: None,
+            key_vrl: None,
             auth: KafkaAuthConfig {
                 sasl: None,
                 tls: None,
@@ -194,11 +205,12 @@ mod integration_test {
             batch,
             librdkafka_options,
             headers_key: None,
+            max_header_value_bytes: 1024,
             acknowledgements: Default::default(),
         };
         config.clone().to_rdkafka()?;
         self::sink::healthcheck(config.clone(), Default::default()).await?;
-        KafkaSink::new(config)
+        KafkaSink::new(config, &Default::default())
     }
 
     #[tokio::test]
@@ -334,7 +346,10 @@ mod integration_test {
             topic: Template::try_from(format!("{}-%Y%m%d", topic)).unwrap(),
             healthcheck_topic: None,
             key_field: None,
+            key_vrl: None,
             encoding: TextSerializerConfig::default().into(),
+            schema_registry_url: None,
+            subject_name_strategy: Default::default(),
             batch: BatchConfig::default(),
             compression,
             auth: kafka_auth.clone(),
@@ -344,6 +359,7 @@ mod integration_test {
             rate_limit_num: i64::MAX as u64,
             librdkafka_options: HashMap::new(),
             headers_key: Some(headers_key.clone()),
+            max_header_value_bytes: 1024,
             acknowledgements: Default::default(),
         };
         let topic = format!("{}-{}", topic, chrono::Utc::now().format("%Y%m%d"));
@@ -370,7 +386,7 @@ mod integration_test {
 
         if test_telemetry_tags {
             assert_data_volume_sink_compliance(&DATA_VOLUME_SINK_TAGS, async move {
-                let sink = KafkaSink::new(config).unwrap();
+                let sink = KafkaSink::new(config, &Default::default()).unwrap();
                 let sink = VectorSink::from_event_streamsink(sink);
                 sink.run(input_events).await
             })
@@ -378,7 +394,7 @@ mod integration_test {
             .expect("Running sink failed");
         } else {
             assert_sink_compliance(&SINK_TAGS, async move {
-                let sink = KafkaSink::new(config).unwrap();
+                let sink = KafkaSink::new(config, &Default::default()).unwrap();
                 let sink = VectorSink::from_event_streamsink(sink);
                 sink.run(input_events).await
             })
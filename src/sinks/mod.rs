@@ -39,6 +39,7 @@ pub mod console;
 #[cfg(feature = "sinks-databend")]
 pub mod databend;
 #[cfg(any(
+    feature = "sinks-datadog_archives",
     feature = "sinks-datadog_events",
     feature = "sinks-datadog_logs",
     feature = "sinks-datadog_metrics",
@@ -106,6 +107,8 @@ pub mod socket;
 pub mod splunk_hec;
 #[cfg(feature = "sinks-statsd")]
 pub mod statsd;
+#[cfg(feature = "sinks-syslog")]
+pub mod syslog;
 #[cfg(feature = "sinks-vector")]
 pub mod vector;
 #[cfg(feature = "sinks-webhdfs")]
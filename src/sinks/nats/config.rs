@@ -1,10 +1,14 @@
+use std::{num::NonZeroUsize, time::Instant};
+
 use bytes::Bytes;
 use futures_util::TryFutureExt;
 use snafu::ResultExt;
+use tokio::sync::Semaphore;
 use vector_lib::codecs::JsonSerializerConfig;
 use vector_lib::tls::TlsEnableableConfig;
 
 use crate::{
+    internal_events::NatsPublishAckReceived,
     nats::{from_tls_auth_config, NatsAuthConfig, NatsConfigError},
     sinks::{prelude::*, util::service::TowerRequestConfigDefaults},
 };
@@ -88,12 +92,28 @@ pub struct NatsSinkConfig {
     /// [jetstream]: https://docs.nats.io/nats-concepts/jetstream
     #[serde(default)]
     pub(super) jetstream: bool,
+
+    /// The maximum number of JetStream publish acknowledgements to wait for at once.
+    ///
+    /// Events are only considered delivered once JetStream acknowledged them. Raising this
+    /// limit lets further messages be published while earlier ones are still awaiting their
+    /// acknowledgement. When `request.concurrency` is left to `none`, the sink sends as many
+    /// requests concurrently as this limit allows.
+    ///
+    /// Only used if `jetstream` is enabled.
+    #[serde(default = "default_max_outstanding_acks")]
+    #[configurable(metadata(docs::examples = 256))]
+    pub(super) max_outstanding_acks: NonZeroUsize,
 }
 
 fn default_name() -> String {
     String::from("vector")
 }
 
+const fn default_max_outstanding_acks() -> NonZeroUsize {
+    NonZeroUsize::MIN
+}
+
 impl GenerateConfig for NatsSinkConfig {
     fn generate_config() -> toml::Value {
         toml::Value::try_from(Self {
@@ -106,6 +126,7 @@ impl GenerateConfig for NatsSinkConfig {
             url: "nats://127.0.0.1:4222".into(),
             request: Default::default(),
             jetstream: Default::default(),
+            max_outstanding_acks: default_max_outstanding_acks(),
         })
         .unwrap()
     }
@@ -176,9 +197,10 @@ impl NatsSinkConfig {
         let connection = self.connect(options).await?;
 
         if self.jetstream {
-            Ok(NatsPublisher::JetStream(async_nats::jetstream::new(
-                connection,
-            )))
+            Ok(NatsPublisher::JetStream {
+                context: async_nats::jetstream::new(connection),
+                outstanding_acks: Semaphore::new(self.max_outstanding_acks.get()),
+            })
         } else {
             Ok(NatsPublisher::Core(connection))
         }
@@ -196,7 +218,11 @@ async fn healthcheck(config: NatsSinkConfig) -> crate::Result<()> {
 
 pub enum NatsPublisher {
     Core(async_nats::Client),
-    JetStream(async_nats::jetstream::Context),
+    JetStream {
+        context: async_nats::jetstream::Context,
+        /// Bounds the number of publish acknowledgements awaited at once.
+        outstanding_acks: Semaphore,
+    },
 }
 
 impl NatsPublisher {
@@ -221,15 +247,27 @@ impl NatsPublisher {
                     })
                     .await
             }
-            NatsPublisher::JetStream(jetstream) => {
-                let ack = jetstream.publish(subject, payload).await.map_err(|e| {
+            NatsPublisher::JetStream {
+                context,
+                outstanding_acks,
+            } => {
+                let _permit = outstanding_acks
+                    .acquire()
+                    .await
+                    .expect("semaphore is never closed");
+                let ack = context.publish(subject, payload).await.map_err(|e| {
                     NatsError::PublishError {
                         source: Box::new(e),
                     }
                 })?;
-                ack.await.map(|_| ()).map_err(|e| NatsError::PublishError {
+                let published_at = Instant::now();
+                ack.await.map_err(|e| NatsError::PublishError {
                     source: Box::new(e),
-                })
+                })?;
+                emit!(NatsPublishAckReceived {
+                    latency: published_at.elapsed(),
+                });
+                Ok(())
             }
         }
     }
@@ -1,4 +1,4 @@
-use std::time::Duration;
+use std::{num::NonZeroUsize, time::Duration};
 use vector_lib::codecs::TextSerializerConfig;
 
 use super::{config::NatsSinkConfig, sink::NatsSink, ConfigSnafu, NatsError};
@@ -81,6 +81,7 @@ async fn nats_no_auth() {
         auth: None,
         request: Default::default(),
         jetstream: false,
+        max_outstanding_acks: NonZeroUsize::MIN,
     };
 
     let r = publish_and_check(conf).await;
@@ -91,6 +92,50 @@ async fn nats_no_auth() {
     );
 }
 
+#[tokio::test]
+async fn nats_jetstream() {
+    trace_init();
+
+    let subject = format!("test-{}", random_string(10));
+    let url =
+        std::env::var("NATS_ADDRESS").unwrap_or_else(|_| String::from("nats://localhost:4222"));
+
+    let conf = NatsSinkConfig {
+        acknowledgements: Default::default(),
+        encoding: TextSerializerConfig::default().into(),
+        connection_name: "".to_owned(),
+        subject: Template::try_from(subject.as_str()).unwrap(),
+        url,
+        tls: None,
+        auth: None,
+        request: Default::default(),
+        jetstream: true,
+        max_outstanding_acks: NonZeroUsize::new(16).unwrap(),
+    };
+
+    // The subject must belong to a stream for JetStream to acknowledge the messages.
+    let options: async_nats::ConnectOptions = (&conf).try_into().unwrap();
+    let client = conf.connect(options).await.unwrap();
+    let mut stream = async_nats::jetstream::new(client)
+        .create_stream(async_nats::jetstream::stream::Config {
+            name: subject.clone(),
+            subjects: vec![subject.clone()],
+            ..Default::default()
+        })
+        .await
+        .expect("failed to create the stream");
+
+    let sink = NatsSink::new(conf).await.unwrap();
+    let sink = VectorSink::from_event_streamsink(sink);
+    let num_events = 10;
+    let (_input, events) = random_lines_with_stream(100, num_events, None);
+
+    run_and_assert_sink_compliance(sink, events, &SINK_TAGS).await;
+
+    let info = stream.info().await.unwrap();
+    assert_eq!(info.state.messages, num_events as u64);
+}
+
 #[tokio::test]
 async fn nats_userpass_auth_valid() {
     trace_init();
@@ -114,6 +159,7 @@ async fn nats_userpass_auth_valid() {
         }),
         request: Default::default(),
         jetstream: false,
+        max_outstanding_acks: NonZeroUsize::MIN,
     };
 
     publish_and_check(conf)
@@ -144,6 +190,7 @@ async fn nats_userpass_auth_invalid() {
         }),
         request: Default::default(),
         jetstream: false,
+        max_outstanding_acks: NonZeroUsize::MIN,
     };
 
     let r = publish_and_check(conf).await;
@@ -176,6 +223,7 @@ async fn nats_token_auth_valid() {
         }),
         request: Default::default(),
         jetstream: false,
+        max_outstanding_acks: NonZeroUsize::MIN,
     };
 
     let r = publish_and_check(conf).await;
@@ -208,6 +256,7 @@ async fn nats_token_auth_invalid() {
         }),
         request: Default::default(),
         jetstream: false,
+        max_outstanding_acks: NonZeroUsize::MIN,
     };
 
     let r = publish_and_check(conf).await;
@@ -241,6 +290,7 @@ async fn nats_nkey_auth_valid() {
         }),
         request: Default::default(),
         jetstream: false,
+        max_outstanding_acks: NonZeroUsize::MIN,
     };
 
     let r = publish_and_check(conf).await;
@@ -274,6 +324,7 @@ async fn nats_nkey_auth_invalid() {
         }),
         request: Default::default(),
         jetstream: false,
+        max_outstanding_acks: NonZeroUsize::MIN,
     };
 
     let r = publish_and_check(conf).await;
@@ -308,6 +359,7 @@ async fn nats_tls_valid() {
         auth: None,
         request: Default::default(),
         jetstream: false,
+        max_outstanding_acks: NonZeroUsize::MIN,
     };
 
     let r = publish_and_check(conf).await;
@@ -336,6 +388,7 @@ async fn nats_tls_invalid() {
         auth: None,
         request: Default::default(),
         jetstream: false,
+        max_outstanding_acks: NonZeroUsize::MIN,
     };
 
     let r = publish_and_check(conf).await;
@@ -372,6 +425,7 @@ async fn nats_tls_client_cert_valid() {
         auth: None,
         request: Default::default(),
         jetstream: false,
+        max_outstanding_acks: NonZeroUsize::MIN,
     };
 
     let r = publish_and_check(conf).await;
@@ -406,6 +460,7 @@ async fn nats_tls_client_cert_invalid() {
         auth: None,
         request: Default::default(),
         jetstream: false,
+        max_outstanding_acks: NonZeroUsize::MIN,
     };
 
     let r = publish_and_check(conf).await;
@@ -444,6 +499,7 @@ async fn nats_tls_jwt_auth_valid() {
         }),
         request: Default::default(),
         jetstream: false,
+        max_outstanding_acks: NonZeroUsize::MIN,
     };
 
     let r = publish_and_check(conf).await;
@@ -482,6 +538,7 @@ async fn nats_tls_jwt_auth_invalid() {
         }),
         request: Default::default(),
         jetstream: false,
+        max_outstanding_acks: NonZeroUsize::MIN,
     };
 
     let r = publish_and_check(conf).await;
@@ -46,7 +46,11 @@ impl NatsSink {
         let transformer = config.encoding.transformer();
         let serializer = config.encoding.build().context(EncodingSnafu)?;
         let encoder = Encoder::<()>::new(serializer);
-        let request = config.request;
+        let mut request = config.request;
+        if config.jetstream && request.concurrency == Concurrency::None {
+            // Unlike core NATS publishes, JetStream acknowledgements can be awaited concurrently.
+            request.concurrency = Concurrency::Fixed(config.max_outstanding_acks.get());
+        }
         let subject = config.subject;
 
         Ok(NatsSink {
@@ -129,7 +129,10 @@ where
                 |request: Result<NewRelicApiRequest, NewRelicSinkError>| async move {
                     match request {
                         Err(error) => {
-                            emit!(SinkRequestBuildError { error });
+                            emit!(SinkRequestBuildError {
+                                error,
+                                error_code: "request_build_failed",
+                            });
                             None
                         }
                         Ok(req) => Some(req),
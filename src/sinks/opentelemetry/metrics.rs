@@ -0,0 +1,688 @@
+//! The `opentelemetry_metrics` sink, exporting metrics to OTLP backends.
+//! See <https://opentelemetry.io/docs/specs/otlp/> for more information.
+use std::{
+    num::NonZeroUsize,
+    task::{Context, Poll},
+};
+
+use chrono::Utc;
+use http::{header::CONTENT_TYPE, Request, StatusCode, Uri};
+use hyper::Body;
+use indoc::indoc;
+use prost::Message;
+use snafu::{ResultExt, Snafu};
+use tonic::body::BoxBody;
+use vector_lib::internal_event::{ComponentEventsDropped, UNINTENTIONAL};
+use vector_lib::opentelemetry::proto::{
+    collector::metrics::v1::{
+        metrics_service_client::MetricsServiceClient, ExportMetricsServiceRequest,
+    },
+    common::v1::{any_value, AnyValue, InstrumentationScope, KeyValue},
+    metrics::v1::{
+        metric::Data, number_data_point, summary_data_point::ValueAtQuantile,
+        AggregationTemporality, Gauge, Histogram, HistogramDataPoint, Metric as OtlpMetric,
+        NumberDataPoint, ResourceMetrics, ScopeMetrics, Sum, Summary, SummaryDataPoint,
+    },
+};
+use vector_lib::stream::batcher::data::BatchReduce;
+
+use crate::{
+    event::metric::{Bucket, Metric, MetricKind, MetricValue, Quantile},
+    http::{HttpClient, HttpError},
+    sinks::{prelude::*, UriParseSnafu},
+};
+
+const PROTOBUF_CONTENT_TYPE: &str = "application/x-protobuf";
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct OpenTelemetryMetricsDefaultBatchSettings;
+
+// OTLP backends commonly limit the size of the requests they accept, for example to the 4MB gRPC
+// default, so requests are kept well below it.
+impl SinkBatchSettings for OpenTelemetryMetricsDefaultBatchSettings {
+    const MAX_EVENTS: Option<usize> = Some(1000);
+    const MAX_BYTES: Option<usize> = Some(1_000_000);
+    const TIMEOUT_SECS: f64 = 1.0;
+}
+
+/// Configuration for the `opentelemetry_metrics` sink.
+#[configurable_component(sink(
+    "opentelemetry_metrics",
+    "Export metrics to OTLP backends with the OpenTelemetry protocol."
+))]
+#[derive(Clone, Debug)]
+pub struct OpenTelemetryMetricsConfig {
+    /// The endpoint to export metrics to.
+    ///
+    /// Over HTTP, this is the URL of the metrics endpoint of the backend, usually ending with
+    /// `/v1/metrics`. Over gRPC, this is the address of the backend, and its path is ignored.
+    #[configurable(metadata(docs::examples = "http://localhost:4318/v1/metrics"))]
+    #[configurable(metadata(docs::examples = "http://localhost:4317"))]
+    pub endpoint: String,
+
+    /// Whether to export metrics over gRPC instead of HTTP.
+    #[serde(default)]
+    pub grpc_mode: bool,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub batch: BatchConfig<OpenTelemetryMetricsDefaultBatchSettings>,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub request: TowerRequestConfig,
+
+    #[configurable(derived)]
+    pub tls: Option<TlsConfig>,
+
+    #[configurable(derived)]
+    #[serde(
+        default,
+        deserialize_with = "crate::serde::bool_or_struct",
+        skip_serializing_if = "crate::serde::is_default"
+    )]
+    pub acknowledgements: AcknowledgementsConfig,
+}
+
+impl GenerateConfig for OpenTelemetryMetricsConfig {
+    fn generate_config() -> toml::Value {
+        toml::from_str(indoc! {r#"
+            endpoint = "http://localhost:4318/v1/metrics"
+        "#})
+        .unwrap()
+    }
+}
+
+#[async_trait::async_trait]
+#[typetag::serde(name = "opentelemetry_metrics")]
+impl SinkConfig for OpenTelemetryMetricsConfig {
+    async fn build(&self, cx: SinkContext) -> crate::Result<(VectorSink, Healthcheck)> {
+        let uri = self.endpoint.parse::<Uri>().context(UriParseSnafu)?;
+        let tls = TlsSettings::from_options(self.tls.as_ref())?;
+
+        let (transport, protocol) = if self.grpc_mode {
+            let client = HttpClient::<BoxBody>::new_with_custom_client(
+                tls,
+                cx.proxy(),
+                hyper::Client::builder().http2_only(true),
+            )?;
+            (
+                Transport::Grpc(MetricsServiceClient::with_origin(client, uri)),
+                "grpc",
+            )
+        } else {
+            let client = HttpClient::new(tls, cx.proxy())?;
+            (Transport::Http { client, uri }, "http")
+        };
+
+        let healthcheck = healthcheck(transport.clone()).boxed();
+
+        let request_settings = self.request.into_settings();
+        let batch_settings = self.batch.into_batcher_settings()?;
+        let service = ServiceBuilder::new()
+            .settings(request_settings, OpenTelemetryMetricsRetryLogic)
+            .service(OpenTelemetryMetricsService { transport });
+
+        let sink = OpenTelemetryMetricsSink {
+            service,
+            batch_settings,
+            protocol,
+        };
+
+        Ok((VectorSink::from_event_streamsink(sink), healthcheck))
+    }
+
+    fn input(&self) -> Input {
+        Input::metric()
+    }
+
+    fn acknowledgements(&self) -> &AcknowledgementsConfig {
+        &self.acknowledgements
+    }
+}
+
+/// Exports an empty request, which backends accept when they are reachable.
+async fn healthcheck(mut transport: Transport) -> crate::Result<()> {
+    transport
+        .export(ExportMetricsServiceRequest::default())
+        .await
+        .map_err(Into::into)
+}
+
+#[derive(Debug, Snafu)]
+pub enum OpenTelemetryMetricsError {
+    #[snafu(display("Failed to make HTTP(S) request: {}", source))]
+    Http { source: HttpError },
+
+    #[snafu(display("Server responded with an error: {}", status))]
+    Server { status: StatusCode },
+
+    #[snafu(display("gRPC request failed: {}", source))]
+    Grpc { source: tonic::Status },
+}
+
+/// The transport metrics are exported over.
+#[derive(Clone, Debug)]
+enum Transport {
+    Http { client: HttpClient, uri: Uri },
+    Grpc(MetricsServiceClient<HttpClient<BoxBody>>),
+}
+
+impl Transport {
+    async fn export(
+        &mut self,
+        request: ExportMetricsServiceRequest,
+    ) -> Result<(), OpenTelemetryMetricsError> {
+        match self {
+            Self::Http { client, uri } => {
+                let request = Request::post(uri.clone())
+                    .header(CONTENT_TYPE, PROTOBUF_CONTENT_TYPE)
+                    .body(Body::from(request.encode_to_vec()))
+                    .expect("building the request should not fail");
+                let response = client.send(request).await.context(HttpSnafu)?;
+                let status = response.status();
+                if status.is_success() {
+                    Ok(())
+                } else {
+                    Err(OpenTelemetryMetricsError::Server { status })
+                }
+            }
+            Self::Grpc(client) => client.export(request).await.map(|_| ()).context(GrpcSnafu),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct OpenTelemetryMetricsRetryLogic;
+
+impl RetryLogic for OpenTelemetryMetricsRetryLogic {
+    type Error = OpenTelemetryMetricsError;
+    type Response = OpenTelemetryMetricsResponse;
+
+    // The retryable responses are listed by the OTLP specification:
+    // <https://opentelemetry.io/docs/specs/otlp/#failures>
+    fn is_retriable_error(&self, error: &Self::Error) -> bool {
+        use tonic::Code::*;
+
+        match error {
+            OpenTelemetryMetricsError::Http { .. } => true,
+            OpenTelemetryMetricsError::Server { status } => matches!(
+                *status,
+                StatusCode::TOO_MANY_REQUESTS
+                    | StatusCode::BAD_GATEWAY
+                    | StatusCode::SERVICE_UNAVAILABLE
+                    | StatusCode::GATEWAY_TIMEOUT
+            ),
+            OpenTelemetryMetricsError::Grpc { source } => matches!(
+                source.code(),
+                Cancelled
+                    | DeadlineExceeded
+                    | ResourceExhausted
+                    | Aborted
+                    | OutOfRange
+                    | Unavailable
+                    | DataLoss
+            ),
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+struct OpenTelemetryMetricsRequest {
+    finalizers: EventFinalizers,
+    metadata: RequestMetadata,
+    request: ExportMetricsServiceRequest,
+}
+
+impl Finalizable for OpenTelemetryMetricsRequest {
+    fn take_finalizers(&mut self) -> EventFinalizers {
+        self.finalizers.take_finalizers()
+    }
+}
+
+impl MetaDescriptive for OpenTelemetryMetricsRequest {
+    fn get_metadata(&self) -> &RequestMetadata {
+        &self.metadata
+    }
+
+    fn metadata_mut(&mut self) -> &mut RequestMetadata {
+        &mut self.metadata
+    }
+}
+
+struct OpenTelemetryMetricsResponse {
+    events_byte_size: GroupedCountByteSize,
+}
+
+impl DriverResponse for OpenTelemetryMetricsResponse {
+    fn event_status(&self) -> EventStatus {
+        EventStatus::Delivered
+    }
+
+    fn events_sent(&self) -> &GroupedCountByteSize {
+        &self.events_byte_size
+    }
+}
+
+#[derive(Clone, Debug)]
+struct OpenTelemetryMetricsService {
+    transport: Transport,
+}
+
+impl Service<OpenTelemetryMetricsRequest> for OpenTelemetryMetricsService {
+    type Response = OpenTelemetryMetricsResponse;
+    type Error = OpenTelemetryMetricsError;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, mut request: OpenTelemetryMetricsRequest) -> Self::Future {
+        let mut transport = self.transport.clone();
+        let events_byte_size =
+            std::mem::take(request.metadata_mut()).into_events_estimated_json_encoded_byte_size();
+
+        Box::pin(async move {
+            transport.export(request.request).await?;
+            Ok(OpenTelemetryMetricsResponse { events_byte_size })
+        })
+    }
+}
+
+/// A metric converted to OTLP, along with the data of its event.
+struct MetricData {
+    byte_size: usize,
+    json_byte_size: GroupedCountByteSize,
+    finalizers: EventFinalizers,
+    metric: OtlpMetric,
+}
+
+/// Temporary struct to collect metrics during batching.
+struct MetricCollection {
+    finalizers: EventFinalizers,
+    metrics: Vec<OtlpMetric>,
+    byte_size: usize,
+    json_byte_size: GroupedCountByteSize,
+}
+
+impl Default for MetricCollection {
+    fn default() -> Self {
+        Self {
+            finalizers: Default::default(),
+            metrics: Default::default(),
+            byte_size: Default::default(),
+            json_byte_size: telemetry().create_request_count_byte_size(),
+        }
+    }
+}
+
+struct OpenTelemetryMetricsSink<S> {
+    service: S,
+    batch_settings: BatcherSettings,
+    protocol: &'static str,
+}
+
+impl<S> OpenTelemetryMetricsSink<S>
+where
+    S: Service<OpenTelemetryMetricsRequest> + Send + 'static,
+    S::Future: Send + 'static,
+    S::Response: DriverResponse + Send + 'static,
+    S::Error: std::fmt::Debug + Into<crate::Error> + Send,
+{
+    async fn run_inner(self: Box<Self>, input: BoxStream<'_, Event>) -> Result<(), ()> {
+        input
+            .filter_map(|mut event| {
+                let mut json_byte_size = telemetry().create_request_count_byte_size();
+                json_byte_size.add_event(&event, event.estimated_json_encoded_size_of());
+                let byte_size = event.size_of();
+                let finalizers = event.take_finalizers();
+
+                let data = encode_metric(&event.into_metric()).map(|metric| MetricData {
+                    byte_size,
+                    json_byte_size,
+                    finalizers,
+                    metric,
+                });
+                future::ready(data)
+            })
+            .batched(self.batch_settings.as_reducer_config(
+                |data: &MetricData| data.metric.encoded_len(),
+                BatchReduce::new(|collection: &mut MetricCollection, item: MetricData| {
+                    collection.finalizers.merge(item.finalizers);
+                    collection.metrics.push(item.metric);
+                    collection.byte_size += item.byte_size;
+                    collection.json_byte_size += item.json_byte_size;
+                }),
+            ))
+            .map(|collection| {
+                let builder = RequestMetadataBuilder::new(
+                    collection.metrics.len(),
+                    collection.byte_size,
+                    collection.json_byte_size,
+                );
+
+                let request = export_request(collection.metrics);
+                let request_size = NonZeroUsize::new(request.encoded_len())
+                    .expect("payload should never be zero length");
+
+                OpenTelemetryMetricsRequest {
+                    finalizers: collection.finalizers,
+                    metadata: builder.with_request_size(request_size),
+                    request,
+                }
+            })
+            .into_driver(self.service)
+            .protocol(self.protocol)
+            .run()
+            .await
+    }
+}
+
+#[async_trait]
+impl<S> StreamSink<Event> for OpenTelemetryMetricsSink<S>
+where
+    S: Service<OpenTelemetryMetricsRequest> + Send + 'static,
+    S::Future: Send + 'static,
+    S::Response: DriverResponse + Send + 'static,
+    S::Error: std::fmt::Debug + Into<crate::Error> + Send,
+{
+    async fn run(self: Box<Self>, input: BoxStream<'_, Event>) -> Result<(), ()> {
+        self.run_inner(input).await
+    }
+}
+
+/// Wraps the metrics in a request, under a single resource and the `vector` scope.
+fn export_request(metrics: Vec<OtlpMetric>) -> ExportMetricsServiceRequest {
+    ExportMetricsServiceRequest {
+        resource_metrics: vec![ResourceMetrics {
+            resource: None,
+            scope_metrics: vec![ScopeMetrics {
+                scope: Some(InstrumentationScope {
+                    name: "vector".to_string(),
+                    ..Default::default()
+                }),
+                metrics,
+                schema_url: String::new(),
+            }],
+            schema_url: String::new(),
+        }],
+    }
+}
+
+/// Converts the metric to its OTLP equivalent: counters become monotonic sums, absolute gauges
+/// become gauges and incremental ones non-monotonic sums, aggregated histograms and summaries
+/// become histograms and summaries. Other metric types can't be converted, and are dropped.
+fn encode_metric(metric: &Metric) -> Option<OtlpMetric> {
+    let name = match metric.namespace() {
+        Some(namespace) => format!("{}.{}", namespace, metric.name()),
+        None => metric.name().to_string(),
+    };
+    let attributes = metric
+        .tags()
+        .map(|tags| {
+            tags.iter_single()
+                .map(|(key, value)| KeyValue {
+                    key: key.to_string(),
+                    value: Some(AnyValue {
+                        value: Some(any_value::Value::StringValue(value.to_string())),
+                    }),
+                })
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+    let timestamp = metric.timestamp().unwrap_or_else(Utc::now);
+    let time_unix_nano = timestamp.timestamp_nanos_opt().unwrap_or_default() as u64;
+    let start_time_unix_nano = metric
+        .interval_ms()
+        .map(|interval| time_unix_nano.saturating_sub(u64::from(interval.get()) * 1_000_000))
+        .unwrap_or_default();
+    let aggregation_temporality = match metric.kind() {
+        MetricKind::Incremental => AggregationTemporality::Delta,
+        MetricKind::Absolute => AggregationTemporality::Cumulative,
+    } as i32;
+
+    let number_data_points = |value: f64| {
+        vec![NumberDataPoint {
+            attributes: attributes.clone(),
+            start_time_unix_nano,
+            time_unix_nano,
+            value: Some(number_data_point::Value::AsDouble(value)),
+            ..Default::default()
+        }]
+    };
+
+    let data = match (metric.kind(), metric.value()) {
+        (_, MetricValue::Counter { value }) => Data::Sum(Sum {
+            data_points: number_data_points(*value),
+            aggregation_temporality,
+            is_monotonic: true,
+        }),
+        (MetricKind::Absolute, MetricValue::Gauge { value }) => Data::Gauge(Gauge {
+            data_points: number_data_points(*value),
+        }),
+        (MetricKind::Incremental, MetricValue::Gauge { value }) => Data::Sum(Sum {
+            data_points: number_data_points(*value),
+            aggregation_temporality,
+            is_monotonic: false,
+        }),
+        (
+            _,
+            MetricValue::AggregatedHistogram {
+                buckets,
+                count,
+                sum,
+            },
+        ) => {
+            let (explicit_bounds, bucket_counts) = histogram_buckets(buckets, *count);
+            Data::Histogram(Histogram {
+                data_points: vec![HistogramDataPoint {
+                    attributes,
+                    start_time_unix_nano,
+                    time_unix_nano,
+                    count: *count,
+                    sum: Some(*sum),
+                    bucket_counts,
+                    explicit_bounds,
+                    ..Default::default()
+                }],
+                aggregation_temporality,
+            })
+        }
+        (
+            _,
+            MetricValue::AggregatedSummary {
+                quantiles,
+                count,
+                sum,
+            },
+        ) => Data::Summary(Summary {
+            data_points: vec![SummaryDataPoint {
+                attributes,
+                start_time_unix_nano,
+                time_unix_nano,
+                count: *count,
+                sum: *sum,
+                quantile_values: quantiles
+                    .iter()
+                    .map(|Quantile { quantile, value }| ValueAtQuantile {
+                        quantile: *quantile,
+                        value: *value,
+                    })
+                    .collect(),
+                ..Default::default()
+            }],
+        }),
+        _ => {
+            emit!(ComponentEventsDropped::<UNINTENTIONAL> {
+                count: 1,
+                reason: "Metric type not supported by the OpenTelemetry protocol.",
+            });
+            return None;
+        }
+    };
+
+    Some(OtlpMetric {
+        name,
+        data: Some(data),
+        ..Default::default()
+    })
+}
+
+/// Splits the buckets into the bounds and counts of an OTLP histogram, whose last count is the
+/// number of values above the highest bound.
+fn histogram_buckets(buckets: &[Bucket], count: u64) -> (Vec<f64>, Vec<u64>) {
+    let mut explicit_bounds = Vec::with_capacity(buckets.len());
+    let mut bucket_counts = Vec::with_capacity(buckets.len() + 1);
+    let mut overflow = 0;
+    for bucket in buckets {
+        if bucket.upper_limit == f64::INFINITY {
+            overflow += bucket.count;
+        } else {
+            explicit_bounds.push(bucket.upper_limit);
+            bucket_counts.push(bucket.count);
+        }
+    }
+
+    let bucketed = bucket_counts.iter().sum::<u64>() + overflow;
+    bucket_counts.push(overflow + count.saturating_sub(bucketed));
+    (explicit_bounds, bucket_counts)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use vector_lib::metric_tags;
+
+    use super::*;
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<OpenTelemetryMetricsConfig>();
+    }
+
+    fn timestamp() -> chrono::DateTime<Utc> {
+        Utc.timestamp_opt(1_700_000_000, 0).unwrap()
+    }
+
+    #[test]
+    fn encodes_counter_with_attributes() {
+        let metric = Metric::new(
+            "requests",
+            MetricKind::Incremental,
+            MetricValue::Counter { value: 3.0 },
+        )
+        .with_namespace(Some("vector"))
+        .with_tags(Some(metric_tags!("host" => "localhost")))
+        .with_timestamp(Some(timestamp()));
+
+        let otlp = encode_metric(&metric).unwrap();
+        assert_eq!(otlp.name, "vector.requests");
+        let Some(Data::Sum(sum)) = otlp.data else {
+            panic!("Expected a sum");
+        };
+        assert!(sum.is_monotonic);
+        assert_eq!(
+            sum.aggregation_temporality,
+            AggregationTemporality::Delta as i32
+        );
+        let point = &sum.data_points[0];
+        assert_eq!(point.time_unix_nano, 1_700_000_000_000_000_000);
+        assert_eq!(point.value, Some(number_data_point::Value::AsDouble(3.0)));
+        assert_eq!(point.attributes[0].key, "host");
+        assert_eq!(
+            point.attributes[0].value,
+            Some(AnyValue {
+                value: Some(any_value::Value::StringValue("localhost".to_string())),
+            })
+        );
+    }
+
+    #[test]
+    fn encodes_gauges() {
+        let absolute = Metric::new(
+            "temperature",
+            MetricKind::Absolute,
+            MetricValue::Gauge { value: 21.5 },
+        );
+        assert!(matches!(
+            encode_metric(&absolute).unwrap().data,
+            Some(Data::Gauge(_))
+        ));
+
+        let incremental = Metric::new(
+            "connections",
+            MetricKind::Incremental,
+            MetricValue::Gauge { value: -2.0 },
+        );
+        let Some(Data::Sum(sum)) = encode_metric(&incremental).unwrap().data else {
+            panic!("Expected a sum");
+        };
+        assert!(!sum.is_monotonic);
+    }
+
+    #[test]
+    fn encodes_histogram() {
+        let metric = Metric::new(
+            "latency",
+            MetricKind::Absolute,
+            MetricValue::AggregatedHistogram {
+                buckets: vec![
+                    Bucket {
+                        upper_limit: 0.1,
+                        count: 2,
+                    },
+                    Bucket {
+                        upper_limit: 1.0,
+                        count: 3,
+                    },
+                ],
+                count: 6,
+                sum: 4.2,
+            },
+        );
+
+        let Some(Data::Histogram(histogram)) = encode_metric(&metric).unwrap().data else {
+            panic!("Expected a histogram");
+        };
+        assert_eq!(
+            histogram.aggregation_temporality,
+            AggregationTemporality::Cumulative as i32
+        );
+        let point = &histogram.data_points[0];
+        assert_eq!(point.explicit_bounds, vec![0.1, 1.0]);
+        assert_eq!(point.bucket_counts, vec![2, 3, 1]);
+        assert_eq!(point.count, 6);
+        assert_eq!(point.sum, Some(4.2));
+    }
+
+    #[test]
+    fn histogram_infinite_bucket_is_the_overflow() {
+        let buckets = [
+            Bucket {
+                upper_limit: 1.0,
+                count: 1,
+            },
+            Bucket {
+                upper_limit: f64::INFINITY,
+                count: 4,
+            },
+        ];
+
+        assert_eq!(histogram_buckets(&buckets, 5), (vec![1.0], vec![1, 4]));
+    }
+
+    #[test]
+    fn drops_unsupported_metrics() {
+        let metric = Metric::new(
+            "users",
+            MetricKind::Incremental,
+            MetricValue::Set {
+                values: ["alice".to_string()].into(),
+            },
+        );
+
+        assert!(encode_metric(&metric).is_none());
+    }
+}
@@ -8,6 +8,8 @@ use vector_lib::codecs::encoding::{FramingConfig, SerializerConfig};
 use vector_lib::codecs::JsonSerializerConfig;
 use vector_lib::configurable::configurable_component;
 
+pub mod metrics;
+
 /// Configuration for the `OpenTelemetry` sink.
 #[configurable_component(sink("opentelemetry", "Deliver OTLP data over HTTP."))]
 #[derive(Clone, Debug, Default)]
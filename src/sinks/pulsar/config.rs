@@ -52,15 +52,38 @@ pub struct PulsarSinkConfig {
     #[configurable(metadata(docs::examples = "my_field"))]
     pub(crate) partition_key_field: Option<OptionalTargetPath>,
 
+    /// The template used to render the partition key of each event.
+    ///
+    /// Can't be used together with `partition_key_field`. Events for which the template can't be
+    /// rendered are dropped.
+    ///
+    /// If omitted, the key is taken from `partition_key_field`.
+    #[configurable(metadata(docs::examples = "{{ .user_id }}"))]
+    #[configurable(metadata(docs::examples = "{{ .host }}-{{ .service }}"))]
+    pub(crate) partitioning_key: Option<Template>,
+
     /// The log field name to use for the Pulsar properties key.
     ///
     /// If omitted, no properties will be written.
     pub properties_key: Option<OptionalTargetPath>,
 
+    /// The delay, in seconds, before messages are delivered to consumers.
+    ///
+    /// Delayed delivery is only honored by the broker for `shared` and `key_shared` subscriptions.
+    ///
+    /// If omitted, messages are delivered immediately.
+    #[configurable(metadata(docs::type_unit = "seconds"))]
+    #[configurable(metadata(docs::examples = 60))]
+    pub delay_secs: Option<u64>,
+
     #[configurable(derived)]
     #[serde(default)]
     pub(crate) batch: PulsarBatchConfig,
 
+    #[configurable(derived)]
+    #[serde(default)]
+    pub(crate) batch_builder_type: PulsarBatchBuilderType,
+
     #[configurable(derived)]
     #[serde(default)]
     pub compression: PulsarCompression,
@@ -105,6 +128,23 @@ pub(crate) struct PulsarBatchConfig {
     pub max_bytes: Option<usize>,
 }
 
+/// How messages are grouped into batches.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum PulsarBatchBuilderType {
+    /// Messages sent to the same topic are batched together, regardless of their key.
+    #[default]
+    Default,
+
+    /// Only messages with the same partition key are batched together.
+    ///
+    /// The pending batch of a topic is sent as soon as a message with a different key is sent to
+    /// it, so that `key_shared` subscriptions dispatch every message to the consumer of its key.
+    #[serde(alias = "keybased")]
+    KeyBased,
+}
+
 /// Authentication configuration.
 #[configurable_component]
 #[derive(Clone, Debug)]
@@ -237,8 +277,11 @@ impl Default for PulsarSinkConfig {
                 .expect("Unable to parse default template topic"),
             producer_name: None,
             properties_key: None,
+            delay_secs: None,
             partition_key_field: None,
+            partitioning_key: None,
             batch: Default::default(),
+            batch_builder_type: Default::default(),
             compression: Default::default(),
             encoding: TextSerializerConfig::default().into(),
             auth: None,
@@ -382,6 +425,12 @@ impl GenerateConfig for PulsarSinkConfig {
 #[typetag::serde(name = "pulsar")]
 impl SinkConfig for PulsarSinkConfig {
     async fn build(&self, _cx: SinkContext) -> crate::Result<(VectorSink, Healthcheck)> {
+        if self.partitioning_key.is_some() && self.partition_key_field.is_some() {
+            return Err(
+                "only one of `partitioning_key` and `partition_key_field` can be set".into(),
+            );
+        }
+
         let client = self
             .create_pulsar_client()
             .await
@@ -93,6 +93,18 @@ async fn pulsar_happy() {
     pulsar_happy_reuse(cnf).await
 }
 
+#[tokio::test]
+async fn pulsar_happy_delayed() {
+    let cnf = PulsarSinkConfig {
+        endpoint: pulsar_address("pulsar", 6650),
+        delay_secs: Some(1),
+        // overriden by test
+        ..Default::default()
+    };
+
+    pulsar_happy_reuse(cnf).await
+}
+
 #[tokio::test]
 async fn pulsar_happy_tls() {
     let cnf = PulsarSinkConfig {
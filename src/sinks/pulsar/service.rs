@@ -1,14 +1,18 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::task::{Context, Poll};
+use std::time::Duration;
 
 use bytes::Bytes;
-use pulsar::producer::Message;
-use pulsar::{Error as PulsarError, Executor, MultiTopicProducer, ProducerOptions, Pulsar};
+use pulsar::producer::{Message, SendFuture};
+use pulsar::{Error as PulsarError, Executor, Producer, ProducerOptions, Pulsar};
 use tokio::sync::Mutex;
 
 use crate::internal_events::PulsarSendingError;
-use crate::sinks::{prelude::*, pulsar::request_builder::PulsarMetadata};
+use crate::sinks::{
+    prelude::*,
+    pulsar::{config::PulsarBatchBuilderType, request_builder::PulsarMetadata},
+};
 
 #[derive(Clone)]
 pub(super) struct PulsarRequest {
@@ -55,7 +59,57 @@ impl MetaDescriptive for PulsarRequest {
 pub struct PulsarService<Exe: Executor> {
     // NOTE: the reason for the Mutex here is because the `Producer` from the pulsar crate
     // needs to be `mut`, and the `Service::call()` returns a Future.
-    producer: Arc<Mutex<MultiTopicProducer<Exe>>>,
+    producers: Arc<Mutex<TopicProducers<Exe>>>,
+    delay: Option<Duration>,
+}
+
+/// The producers of the topics written to, created on first use.
+struct TopicProducers<Exe: Executor> {
+    client: Pulsar<Exe>,
+    options: ProducerOptions,
+    name: Option<String>,
+    batch_builder_type: PulsarBatchBuilderType,
+    producers: HashMap<String, TopicProducer<Exe>>,
+}
+
+struct TopicProducer<Exe: Executor> {
+    producer: Producer<Exe>,
+    /// The partition key of the messages in the pending batch.
+    batch_key: Option<String>,
+}
+
+impl<Exe: Executor> TopicProducers<Exe> {
+    async fn send(&mut self, topic: String, message: Message) -> Result<SendFuture, PulsarError> {
+        if !self.producers.contains_key(&topic) {
+            let mut builder = self
+                .client
+                .producer()
+                .with_topic(&topic)
+                .with_options(self.options.clone());
+            if let Some(name) = &self.name {
+                builder = builder.with_name(name.clone());
+            }
+            let producer = TopicProducer {
+                producer: builder.build().await?,
+                batch_key: None,
+            };
+            self.producers.insert(topic.clone(), producer);
+        }
+
+        let topic_producer = self
+            .producers
+            .get_mut(&topic)
+            .expect("the producer of the topic was just created");
+        if self.batch_builder_type == PulsarBatchBuilderType::KeyBased
+            && self.options.batch_size.is_some()
+            && topic_producer.batch_key != message.partition_key
+        {
+            // Messages with different keys can't share a batch, so the pending one is sent first.
+            topic_producer.producer.send_batch().await?;
+            topic_producer.batch_key.clone_from(&message.partition_key);
+        }
+        topic_producer.producer.send_non_blocking(message).await
+    }
 }
 
 impl<Exe: Executor> PulsarService<Exe> {
@@ -63,17 +117,20 @@ impl<Exe: Executor> PulsarService<Exe> {
         pulsar_client: Pulsar<Exe>,
         producer_options: ProducerOptions,
         producer_name: Option<String>,
+        batch_builder_type: PulsarBatchBuilderType,
+        delay: Option<Duration>,
     ) -> PulsarService<Exe> {
-        let mut builder = pulsar_client.producer().with_options(producer_options);
-
-        if let Some(name) = producer_name {
-            builder = builder.with_name(name);
-        }
-
-        let producer = builder.build_multi_topic();
+        let producers = TopicProducers {
+            client: pulsar_client,
+            options: producer_options,
+            name: producer_name,
+            batch_builder_type,
+            producers: HashMap::new(),
+        };
 
         PulsarService {
-            producer: Arc::new(Mutex::new(producer)),
+            producers: Arc::new(Mutex::new(producers)),
+            delay,
         }
     }
 }
@@ -84,20 +141,24 @@ impl<Exe: Executor> Service<PulsarRequest> for PulsarService<Exe> {
     type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
 
     fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
-        match self.producer.try_lock() {
+        match self.producers.try_lock() {
             Ok(_) => Poll::Ready(Ok(())),
             Err(_) => Poll::Pending,
         }
     }
 
     fn call(&mut self, request: PulsarRequest) -> Self::Future {
-        let producer = Arc::clone(&self.producer);
+        let producers = Arc::clone(&self.producers);
         let topic = request.metadata.topic.clone();
         let event_time = request
             .metadata
             .timestamp_millis
             .to_owned()
             .map(|t| t as u64);
+        let deliver_at_time = self
+            .delay
+            .and_then(|delay| chrono::Duration::from_std(delay).ok())
+            .map(|delay| (chrono::Utc::now() + delay).timestamp_millis());
 
         Box::pin(async move {
             let body = request.body.clone();
@@ -120,6 +181,7 @@ impl<Exe: Executor> Service<PulsarRequest> for PulsarService<Exe> {
                 properties,
                 partition_key,
                 event_time,
+                deliver_at_time,
                 ..Default::default()
             };
 
@@ -129,11 +191,7 @@ impl<Exe: Executor> Service<PulsarRequest> for PulsarService<Exe> {
             // `poll_ready()`. This sink is already limited to sequential request handling due to
             // the pulsar API, so this shouldn't impact performance from a concurrent requests
             // standpoint.
-            let fut = producer
-                .lock()
-                .await
-                .send_non_blocking(topic, message)
-                .await;
+            let fut = producers.lock().await.send(topic, message).await;
 
             match fut {
                 Ok(resp) => match resp.await {
@@ -4,6 +4,7 @@ use pulsar::{Pulsar, TokioExecutor};
 use serde::Serialize;
 use snafu::Snafu;
 use std::collections::HashMap;
+use std::time::Duration;
 use vrl::value::KeyString;
 
 use super::{
@@ -86,7 +87,13 @@ impl PulsarSink {
         let transformer = config.encoding.transformer();
         let serializer = config.encoding.build()?;
         let encoder = Encoder::<()>::new(serializer);
-        let service = PulsarService::new(client, producer_opts, config.producer_name.clone());
+        let service = PulsarService::new(
+            client,
+            producer_opts,
+            config.producer_name.clone(),
+            config.batch_builder_type,
+            config.delay_secs.map(Duration::from_secs),
+        );
         let topic_template = config.topic.clone();
 
         Ok(PulsarSink {
@@ -1,10 +1,12 @@
 use bytes::Bytes;
+use pulsar::message::proto;
 use vector_lib::configurable::component::GenerateConfig;
 use vector_lib::lookup::lookup_v2::OptionalTargetPath;
 use vrl::value::{ObjectMap, Value};
 
 use crate::event::{Event, LogEvent};
-use crate::sinks::pulsar::config::PulsarSinkConfig;
+use crate::sinks::pulsar::config::{PulsarBatchBuilderType, PulsarSinkConfig};
+use crate::template::Template;
 
 #[test]
 fn generate_config() {
@@ -28,3 +30,40 @@ fn pulsar_get_headers() {
     assert_eq!(properties.get("a-key").unwrap(), "a-value".as_bytes());
     assert_eq!(properties.get("b-key").unwrap(), "b-value".as_bytes());
 }
+
+#[test]
+fn pulsar_partitioning_key_template() {
+    let config = PulsarSinkConfig {
+        partitioning_key: Some(Template::try_from("{{ .user }}-key").unwrap()),
+        ..Default::default()
+    };
+
+    let mut log = LogEvent::from("hello");
+    log.insert("user", "alice");
+    let event = super::util::make_pulsar_event(&config.topic, &config, log.into()).unwrap();
+    assert_eq!(event.key.as_deref(), Some("alice-key".as_bytes()));
+
+    let log = LogEvent::from("hello");
+    assert!(super::util::make_pulsar_event(&config.topic, &config, log.into()).is_none());
+}
+
+#[test]
+fn pulsar_registers_avro_schema() {
+    let schema =
+        r#"{"type": "record", "name": "log", "fields": [{"name": "message", "type": "string"}]}"#;
+    let config = toml::from_str::<PulsarSinkConfig>(&format!(
+        r#"
+        endpoint = "pulsar://127.0.0.1:6650"
+        topic = "logs"
+        batch_builder_type = "keybased"
+        encoding.codec = "avro"
+        encoding.avro.schema = '{schema}'
+        "#
+    ))
+    .unwrap();
+    assert_eq!(config.batch_builder_type, PulsarBatchBuilderType::KeyBased);
+
+    let registered = config.build_producer_options().schema.unwrap();
+    assert_eq!(registered.r#type, proto::schema::Type::Avro as i32);
+    assert_eq!(registered.schema_data, schema.as_bytes());
+}
@@ -1,4 +1,4 @@
-use crate::internal_events::PulsarPropertyExtractionError;
+use crate::internal_events::{PulsarPropertyExtractionError, TemplateRenderingError};
 use crate::sinks::pulsar::config::PulsarSinkConfig;
 use crate::sinks::pulsar::sink::PulsarEvent;
 use crate::template::Template;
@@ -16,7 +16,21 @@ pub(super) fn make_pulsar_event(
     event: Event,
 ) -> Option<PulsarEvent> {
     let topic = topic.render_string(&event).ok()?;
-    let key = get_key(&event, &config.partition_key_field);
+    let key = match &config.partitioning_key {
+        Some(template) => Some(
+            template
+                .render(&event)
+                .map_err(|error| {
+                    emit!(TemplateRenderingError {
+                        error,
+                        field: Some("partitioning_key"),
+                        drop_event: true,
+                    })
+                })
+                .ok()?,
+        ),
+        None => get_key(&event, &config.partition_key_field),
+    };
     let timestamp_millis = get_timestamp_millis(&event);
     let properties = get_properties(&event, &config.properties_key);
     Some(PulsarEvent {
@@ -0,0 +1,218 @@
+use vector_lib::{configurable::configurable_component, schema::meaning};
+use vrl::value::Kind;
+
+use super::encoder::{Severity, SyslogEncoder};
+use crate::{
+    codecs::Transformer,
+    config::{AcknowledgementsConfig, GenerateConfig, Input, SinkConfig, SinkContext},
+    schema,
+    sinks::{
+        util::{tcp::TcpSinkConfig, udp::UdpSinkConfig},
+        Healthcheck, VectorSink,
+    },
+    template::Template,
+};
+
+/// Configuration for the `syslog` sink.
+#[configurable_component(sink("syslog", "Deliver log events to a syslog server."))]
+#[derive(Clone, Debug)]
+pub struct SyslogSinkConfig {
+    #[serde(flatten)]
+    pub mode: Mode,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub format: SyslogFormat,
+
+    /// The facility of the messages.
+    ///
+    /// This can be either a facility name, such as `local0`, or its number. Events for which the
+    /// facility can't be rendered, or isn't valid, are sent with the `user` facility.
+    #[serde(default = "default_facility")]
+    #[configurable(metadata(docs::templateable))]
+    #[configurable(metadata(docs::examples = "local0", docs::examples = "{{ facility }}"))]
+    pub facility: Template,
+
+    /// The severity of the messages of events without a valid severity.
+    ///
+    /// The severity of an event is taken from the field with the `severity` meaning, which can
+    /// hold either a severity name, such as `warning`, or its number.
+    #[configurable(derived)]
+    #[serde(default)]
+    pub default_severity: Severity,
+
+    /// The name of the application that emitted the messages.
+    #[serde(default = "default_app_name")]
+    #[configurable(metadata(docs::templateable))]
+    #[configurable(metadata(docs::examples = "{{ application }}", docs::examples = "vector"))]
+    pub app_name: Template,
+
+    /// The identifier of the process that emitted the messages.
+    ///
+    /// If unset, messages have no process identifier.
+    #[configurable(metadata(docs::templateable))]
+    #[configurable(metadata(docs::examples = "{{ pid }}"))]
+    pub proc_id: Option<Template>,
+
+    /// The type of the messages.
+    ///
+    /// Only used with the `rfc5424` format. If unset, messages have no message identifier.
+    #[configurable(metadata(docs::templateable))]
+    #[configurable(metadata(docs::examples = "{{ event_type }}"))]
+    pub msg_id: Option<Template>,
+
+    #[configurable(derived)]
+    #[serde(
+        default,
+        deserialize_with = "crate::serde::bool_or_struct",
+        skip_serializing_if = "crate::serde::is_default"
+    )]
+    pub acknowledgements: AcknowledgementsConfig,
+}
+
+/// The transport used to send messages.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(tag = "mode", rename_all = "snake_case")]
+#[configurable(metadata(docs::enum_tag_description = "The type of socket to use."))]
+pub enum Mode {
+    /// Send over TCP, optionally with TLS.
+    ///
+    /// Messages are delimited by newlines.
+    Tcp(TcpSinkConfig),
+
+    /// Send over UDP.
+    ///
+    /// Each message is sent in its own datagram.
+    Udp(UdpSinkConfig),
+}
+
+/// The format of the syslog messages.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SyslogFormat {
+    /// The [BSD syslog protocol][rfc3164].
+    ///
+    /// Messages look like `<PRI>TIMESTAMP HOSTNAME APP-NAME[PROCID]: MSG`.
+    ///
+    /// [rfc3164]: https://datatracker.ietf.org/doc/html/rfc3164
+    Rfc3164,
+
+    /// The [syslog protocol][rfc5424].
+    ///
+    /// Messages look like `<PRI>1 TIMESTAMP HOSTNAME APP-NAME PROCID MSGID STRUCTURED-DATA MSG`.
+    ///
+    /// [rfc5424]: https://datatracker.ietf.org/doc/html/rfc5424
+    #[default]
+    Rfc5424,
+}
+
+fn default_facility() -> Template {
+    Template::try_from("user").unwrap()
+}
+
+fn default_app_name() -> Template {
+    Template::try_from("vector").unwrap()
+}
+
+impl GenerateConfig for SyslogSinkConfig {
+    fn generate_config() -> toml::Value {
+        toml::from_str(
+            r#"address = "127.0.0.1:514"
+            mode = "udp"
+            format = "rfc5424""#,
+        )
+        .unwrap()
+    }
+}
+
+#[async_trait::async_trait]
+#[typetag::serde(name = "syslog")]
+impl SinkConfig for SyslogSinkConfig {
+    async fn build(&self, _cx: SinkContext) -> crate::Result<(VectorSink, Healthcheck)> {
+        let encoder = SyslogEncoder {
+            format: self.format,
+            facility: self.facility.clone(),
+            default_severity: self.default_severity,
+            app_name: self.app_name.clone(),
+            proc_id: self.proc_id.clone(),
+            msg_id: self.msg_id.clone(),
+            hostname: crate::get_hostname().ok(),
+            newline_delimited: matches!(self.mode, Mode::Tcp(_)),
+        };
+
+        match &self.mode {
+            Mode::Tcp(config) => config.build(Transformer::default(), encoder),
+            Mode::Udp(config) => config.build(Transformer::default(), encoder),
+        }
+    }
+
+    fn input(&self) -> Input {
+        let requirement = schema::Requirement::empty()
+            .optional_meaning(meaning::MESSAGE, Kind::any())
+            .optional_meaning(meaning::HOST, Kind::bytes())
+            .optional_meaning(meaning::TIMESTAMP, Kind::timestamp())
+            .optional_meaning(meaning::SEVERITY, Kind::bytes().or_integer());
+
+        Input::log().with_schema_requirement(requirement)
+    }
+
+    fn acknowledgements(&self) -> &AcknowledgementsConfig {
+        &self.acknowledgements
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::{future::ready, stream, StreamExt};
+    use tokio::net::TcpListener;
+    use tokio_util::codec::{FramedRead, LinesCodec};
+
+    use super::*;
+    use crate::{
+        event::{Event, LogEvent},
+        test_util::{
+            components::{run_and_assert_sink_compliance, SINK_TAGS},
+            next_addr, trace_init,
+        },
+    };
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<SyslogSinkConfig>();
+    }
+
+    #[tokio::test]
+    async fn tcp_sends_newline_delimited_messages() {
+        trace_init();
+
+        let addr = next_addr();
+        let listener = TcpListener::bind(&addr).await.unwrap();
+
+        let config: SyslogSinkConfig = toml::from_str(&format!(
+            r#"address = "{addr}"
+            mode = "tcp"
+            format = "rfc3164"
+            facility = "local3"
+            proc_id = "42""#
+        ))
+        .unwrap();
+        let (sink, _healthcheck) = config.build(SinkContext::default()).await.unwrap();
+
+        let mut log = LogEvent::from("hello syslog");
+        log.insert("host", "web-1");
+        run_and_assert_sink_compliance(sink, stream::once(ready(Event::Log(log))), &SINK_TAGS)
+            .await;
+
+        let (socket, _) = listener.accept().await.unwrap();
+        let line = FramedRead::new(socket, LinesCodec::new())
+            .next()
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert!(line.starts_with("<158>"), "{line}");
+        assert!(line.ends_with(" web-1 vector[42]: hello syslog"), "{line}");
+    }
+}
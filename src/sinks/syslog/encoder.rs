@@ -0,0 +1,381 @@
+use bytes::BytesMut;
+use chrono::{DateTime, SecondsFormat, Utc};
+use vector_lib::{configurable::configurable_component, schema::meaning};
+
+use super::config::SyslogFormat;
+use crate::{
+    event::{Event, LogEvent, Value},
+    internal_events::TemplateRenderingError,
+    template::Template,
+};
+
+/// The facility used when the configured one can't be rendered or isn't valid.
+const USER_FACILITY: u8 = 1;
+
+/// The syslog facility names, in the order of their numbers.
+const FACILITIES: [&str; 24] = [
+    "kern",
+    "user",
+    "mail",
+    "daemon",
+    "auth",
+    "syslog",
+    "lpr",
+    "news",
+    "uucp",
+    "cron",
+    "authpriv",
+    "ftp",
+    "ntp",
+    "security",
+    "console",
+    "solaris-cron",
+    "local0",
+    "local1",
+    "local2",
+    "local3",
+    "local4",
+    "local5",
+    "local6",
+    "local7",
+];
+
+/// The severity of a syslog message.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+    /// The system is unusable.
+    Emergency,
+
+    /// Action must be taken immediately.
+    Alert,
+
+    /// Critical conditions.
+    Critical,
+
+    /// Error conditions.
+    Error,
+
+    /// Warning conditions.
+    Warning,
+
+    /// Normal but significant conditions.
+    Notice,
+
+    /// Informational messages.
+    #[default]
+    Informational,
+
+    /// Debug-level messages.
+    Debug,
+}
+
+impl Severity {
+    const fn code(self) -> u8 {
+        match self {
+            Self::Emergency => 0,
+            Self::Alert => 1,
+            Self::Critical => 2,
+            Self::Error => 3,
+            Self::Warning => 4,
+            Self::Notice => 5,
+            Self::Informational => 6,
+            Self::Debug => 7,
+        }
+    }
+
+    /// Parses a severity from either its number or one of its usual names.
+    fn from_value(value: &Value) -> Option<Self> {
+        let severity = match value {
+            Value::Integer(0) => Self::Emergency,
+            Value::Integer(1) => Self::Alert,
+            Value::Integer(2) => Self::Critical,
+            Value::Integer(3) => Self::Error,
+            Value::Integer(4) => Self::Warning,
+            Value::Integer(5) => Self::Notice,
+            Value::Integer(6) => Self::Informational,
+            Value::Integer(7) => Self::Debug,
+            Value::Bytes(name) => match name.to_ascii_lowercase().as_slice() {
+                b"emerg" | b"emergency" | b"panic" => Self::Emergency,
+                b"alert" => Self::Alert,
+                b"crit" | b"critical" => Self::Critical,
+                b"err" | b"error" => Self::Error,
+                b"warn" | b"warning" => Self::Warning,
+                b"notice" => Self::Notice,
+                b"info" | b"informational" => Self::Informational,
+                b"debug" => Self::Debug,
+                _ => return None,
+            },
+            _ => return None,
+        };
+        Some(severity)
+    }
+}
+
+/// Parses a facility from either its number or its name.
+fn parse_facility(facility: &str) -> Option<u8> {
+    match facility.parse::<u8>() {
+        Ok(code) => (usize::from(code) < FACILITIES.len()).then_some(code),
+        Err(_) => FACILITIES
+            .iter()
+            .position(|name| name.eq_ignore_ascii_case(facility))
+            .and_then(|code| u8::try_from(code).ok()),
+    }
+}
+
+/// Formats log events as syslog messages.
+#[derive(Clone, Debug)]
+pub(super) struct SyslogEncoder {
+    pub(super) format: SyslogFormat,
+    pub(super) facility: Template,
+    pub(super) default_severity: Severity,
+    pub(super) app_name: Template,
+    pub(super) proc_id: Option<Template>,
+    pub(super) msg_id: Option<Template>,
+    /// The host name used for events without one.
+    pub(super) hostname: Option<String>,
+    pub(super) newline_delimited: bool,
+}
+
+impl tokio_util::codec::Encoder<Event> for SyslogEncoder {
+    type Error = vector_lib::codecs::encoding::Error;
+
+    fn encode(&mut self, event: Event, buffer: &mut BytesMut) -> Result<(), Self::Error> {
+        let log = event.into_log();
+
+        let facility = render(&self.facility, &log, "facility")
+            .and_then(|facility| parse_facility(&facility))
+            .unwrap_or(USER_FACILITY);
+        let severity = log
+            .get_by_meaning(meaning::SEVERITY)
+            .and_then(Severity::from_value)
+            .unwrap_or(self.default_severity);
+        let timestamp = log
+            .get_timestamp()
+            .and_then(Value::as_timestamp)
+            .copied()
+            .unwrap_or_else(Utc::now);
+        let hostname = log
+            .get_host()
+            .map(|host| host.to_string_lossy().into_owned())
+            .or_else(|| self.hostname.clone());
+        let app_name = render(&self.app_name, &log, "app_name");
+        let proc_id = self
+            .proc_id
+            .as_ref()
+            .and_then(|proc_id| render(proc_id, &log, "proc_id"));
+
+        let header = Header {
+            priority: facility * 8 + severity.code(),
+            timestamp,
+            hostname,
+            app_name,
+            proc_id,
+        };
+        let message = match self.format {
+            SyslogFormat::Rfc3164 => header.rfc3164(),
+            SyslogFormat::Rfc5424 => {
+                let msg_id = self
+                    .msg_id
+                    .as_ref()
+                    .and_then(|msg_id| render(msg_id, &log, "msg_id"));
+                header.rfc5424(msg_id)
+            }
+        };
+
+        buffer.extend_from_slice(message.as_bytes());
+        buffer.extend_from_slice(&message_body(&log));
+        if self.newline_delimited {
+            buffer.extend_from_slice(b"\n");
+        }
+
+        Ok(())
+    }
+}
+
+/// The fields of a syslog message preceding its body.
+struct Header {
+    priority: u8,
+    timestamp: DateTime<Utc>,
+    hostname: Option<String>,
+    app_name: Option<String>,
+    proc_id: Option<String>,
+}
+
+impl Header {
+    fn rfc3164(&self) -> String {
+        let tag = header_field(self.app_name.as_deref(), 32);
+        let tag = match &self.proc_id {
+            Some(proc_id) => format!("{tag}[{}]", header_field(Some(proc_id), 128)),
+            None => tag,
+        };
+        format!(
+            "<{}>{} {} {tag}: ",
+            self.priority,
+            self.timestamp.format("%b %e %H:%M:%S"),
+            header_field(self.hostname.as_deref(), 255),
+        )
+    }
+
+    fn rfc5424(&self, msg_id: Option<String>) -> String {
+        format!(
+            "<{}>1 {} {} {} {} {} - ",
+            self.priority,
+            self.timestamp.to_rfc3339_opts(SecondsFormat::Micros, true),
+            header_field(self.hostname.as_deref(), 255),
+            header_field(self.app_name.as_deref(), 48),
+            header_field(self.proc_id.as_deref(), 128),
+            header_field(msg_id.as_deref(), 32),
+        )
+    }
+}
+
+/// Returns a header field limited to `max_length` printable ASCII characters, with any other
+/// character replaced with `_`, or `-` if the field is missing.
+fn header_field(value: Option<&str>, max_length: usize) -> String {
+    match value {
+        Some(value) if !value.is_empty() => value
+            .chars()
+            .take(max_length)
+            .map(|c| if c.is_ascii_graphic() { c } else { '_' })
+            .collect(),
+        _ => "-".to_string(),
+    }
+}
+
+/// Returns the body of the message, which is the message of the event or, if it has none, the
+/// whole event encoded as JSON.
+fn message_body(log: &LogEvent) -> Vec<u8> {
+    match log.get_message() {
+        Some(Value::Bytes(message)) => message.to_vec(),
+        Some(message) => message.to_string_lossy().into_owned().into_bytes(),
+        None => serde_json::to_vec(log.value()).unwrap_or_default(),
+    }
+}
+
+fn render(template: &Template, log: &LogEvent, field: &str) -> Option<String> {
+    template
+        .render_string(log)
+        .map_err(|error| {
+            emit!(TemplateRenderingError {
+                error,
+                field: Some(field),
+                drop_event: false,
+            })
+        })
+        .ok()
+        .filter(|value| !value.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::TimeZone;
+    use tokio_util::codec::Encoder as _;
+    use vector_lib::{config::LogNamespace, event::EventMetadata, schema::Definition};
+    use vrl::{owned_value_path, path::OwnedTargetPath, value::Kind};
+
+    use super::*;
+
+    fn encoder(format: SyslogFormat) -> SyslogEncoder {
+        SyslogEncoder {
+            format,
+            facility: Template::try_from("{{ facility }}").unwrap(),
+            default_severity: Severity::Notice,
+            app_name: Template::try_from("my app").unwrap(),
+            proc_id: Some(Template::try_from("{{ pid }}").unwrap()),
+            msg_id: Some(Template::try_from("login").unwrap()),
+            hostname: Some("vector-host".to_string()),
+            newline_delimited: false,
+        }
+    }
+
+    fn log() -> LogEvent {
+        let definition = Definition::new_with_default_metadata(Kind::any(), [LogNamespace::Legacy])
+            .with_meaning(
+                OwnedTargetPath::event(owned_value_path!("level")),
+                meaning::SEVERITY,
+            );
+        let mut log = LogEvent::new_with_metadata(
+            EventMetadata::default().with_schema_definition(&Arc::new(definition)),
+        );
+        log.insert("message", "user logged in");
+        log.insert(
+            "timestamp",
+            Utc.with_ymd_and_hms(2024, 3, 5, 8, 9, 10).unwrap(),
+        );
+        log.insert("host", "web-1");
+        log.insert("facility", "auth");
+        log.insert("level", "warn");
+        log.insert("pid", 1234);
+        log
+    }
+
+    fn encode(encoder: &mut SyslogEncoder, log: LogEvent) -> String {
+        let mut buffer = BytesMut::new();
+        encoder.encode(Event::Log(log), &mut buffer).unwrap();
+        String::from_utf8(buffer.to_vec()).unwrap()
+    }
+
+    #[test]
+    fn formats_rfc3164() {
+        let message = encode(&mut encoder(SyslogFormat::Rfc3164), log());
+
+        assert_eq!(
+            message,
+            "<36>Mar  5 08:09:10 web-1 my_app[1234]: user logged in"
+        );
+    }
+
+    #[test]
+    fn formats_rfc5424() {
+        let message = encode(&mut encoder(SyslogFormat::Rfc5424), log());
+
+        assert_eq!(
+            message,
+            "<36>1 2024-03-05T08:09:10.000000Z web-1 my_app 1234 login - user logged in"
+        );
+    }
+
+    #[test]
+    fn falls_back_to_defaults() {
+        let mut log = log();
+        log.remove("host");
+        log.remove("pid");
+        log.insert("facility", "unknown");
+        log.insert("level", "loud");
+
+        let message = encode(&mut encoder(SyslogFormat::Rfc5424), log);
+
+        assert_eq!(
+            message,
+            "<13>1 2024-03-05T08:09:10.000000Z vector-host my_app - login - user logged in"
+        );
+    }
+
+    #[test]
+    fn parses_severities_and_facilities() {
+        assert_eq!(Severity::from_value(&Value::from(3)), Some(Severity::Error));
+        assert_eq!(
+            Severity::from_value(&Value::from("CRIT")),
+            Some(Severity::Critical)
+        );
+        assert_eq!(Severity::from_value(&Value::from(8)), None);
+
+        assert_eq!(parse_facility("local7"), Some(23));
+        assert_eq!(parse_facility("4"), Some(4));
+        assert_eq!(parse_facility("24"), None);
+    }
+
+    #[test]
+    fn terminates_tcp_messages_with_newlines() {
+        let mut encoder = encoder(SyslogFormat::Rfc3164);
+        encoder.newline_delimited = true;
+
+        let message = encode(&mut encoder, log());
+
+        assert!(message.ends_with("user logged in\n"), "{message}");
+    }
+}
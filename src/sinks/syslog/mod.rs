@@ -0,0 +1,13 @@
+//! The `syslog` [`vector_lib::sink::VectorSink`].
+//!
+//! This module contains the [`vector_lib::sink::VectorSink`] instance that is responsible for
+//! sending log events to a syslog server over UDP, TCP or TLS, formatted as [RFC 3164][rfc3164]
+//! or [RFC 5424][rfc5424] messages.
+//!
+//! [rfc3164]: https://datatracker.ietf.org/doc/html/rfc3164
+//! [rfc5424]: https://datatracker.ietf.org/doc/html/rfc5424
+
+mod config;
+mod encoder;
+
+pub use self::config::SyslogSinkConfig;
@@ -16,11 +16,16 @@ use crate::{
     internal_events::{
         AdaptiveConcurrencyAveragedRtt, AdaptiveConcurrencyInFlight, AdaptiveConcurrencyLimit,
         AdaptiveConcurrencyLimitData, AdaptiveConcurrencyObservedRtt,
+        AdaptiveConcurrencyRttPercentiles, AdaptiveConcurrencyRttPercentilesData,
     },
     sinks::util::retries::{RetryAction, RetryLogic},
     stats::{EwmaVar, Mean, MeanVariance},
 };
 
+/// The maximum number of RTT measurements kept per window for computing percentiles. Measurements
+/// beyond this are still included in the average RTT.
+const MAX_RTT_SAMPLES: usize = 1024;
+
 /// Shared class for `tokio::sync::Semaphore` that manages adjusting the
 /// semaphore size and other associated data.
 #[derive(Clone)]
@@ -37,6 +42,7 @@ pub(super) struct Controller<L> {
     in_flight: Registered<AdaptiveConcurrencyInFlight>,
     observed_rtt: Registered<AdaptiveConcurrencyObservedRtt>,
     averaged_rtt: Registered<AdaptiveConcurrencyAveragedRtt>,
+    rtt_percentiles: Registered<AdaptiveConcurrencyRttPercentiles>,
 }
 
 #[derive(Debug)]
@@ -46,6 +52,7 @@ pub(super) struct Inner {
     past_rtt: EwmaVar,
     next_update: Instant,
     current_rtt: Mean,
+    rtt_samples: Vec<f64>,
     had_back_pressure: bool,
     reached_limit: bool,
 }
@@ -69,7 +76,8 @@ impl<L> Controller<L> {
         // current limit and the maximum, effectively bypassing all the
         // mechanisms. Otherwise, the current limit is set to 1 and the
         // maximum to `settings.max_concurrency_limit`.
-        let current_limit = concurrency.unwrap_or(settings.initial_concurrency);
+        let current_limit =
+            concurrency.unwrap_or(settings.initial_concurrency.max(settings.min_concurrency));
         Self {
             semaphore: Arc::new(ShrinkableSemaphore::new(current_limit)),
             concurrency,
@@ -81,6 +89,7 @@ impl<L> Controller<L> {
                 past_rtt: EwmaVar::new(settings.ewma_alpha),
                 next_update: instant_now(),
                 current_rtt: Default::default(),
+                rtt_samples: Vec::new(),
                 had_back_pressure: false,
                 reached_limit: false,
             })),
@@ -90,6 +99,7 @@ impl<L> Controller<L> {
             in_flight: register!(AdaptiveConcurrencyInFlight),
             observed_rtt: register!(AdaptiveConcurrencyObservedRtt),
             averaged_rtt: register!(AdaptiveConcurrencyAveragedRtt),
+            rtt_percentiles: register!(AdaptiveConcurrencyRttPercentiles),
         }
     }
 
@@ -159,6 +169,9 @@ impl<L> Controller<L> {
 
         if use_rtt {
             inner.current_rtt.update(rtt);
+            if inner.rtt_samples.len() < MAX_RTT_SAMPLES {
+                inner.rtt_samples.push(rtt);
+            }
         }
         let current_rtt = inner.current_rtt.average();
 
@@ -194,6 +207,7 @@ impl<L> Controller<L> {
                     if let Some(current_rtt) = current_rtt {
                         self.averaged_rtt.emit(Duration::from_secs_f64(current_rtt));
                     }
+                    self.emit_rtt_percentiles(&mut inner.rtt_samples);
 
                     // Only manage the concurrency if `concurrency` was set to "adaptive"
                     if self.concurrency.is_none() {
@@ -206,6 +220,7 @@ impl<L> Controller<L> {
                     }
                     inner.next_update = now + Duration::from_secs_f64(past_rtt.mean);
                     inner.current_rtt = Default::default();
+                    inner.rtt_samples.clear();
                     inner.had_back_pressure = false;
                     inner.reached_limit = false;
                 }
@@ -213,6 +228,22 @@ impl<L> Controller<L> {
         }
     }
 
+    fn emit_rtt_percentiles(&self, samples: &mut [f64]) {
+        if samples.is_empty() {
+            return;
+        }
+        samples.sort_unstable_by(f64::total_cmp);
+        let percentile = |p: f64| {
+            let index = ((samples.len() - 1) as f64 * p).round() as usize;
+            Duration::from_secs_f64(samples[index])
+        };
+        self.rtt_percentiles
+            .emit(AdaptiveConcurrencyRttPercentilesData {
+                p50: percentile(0.5),
+                p90: percentile(0.9),
+            });
+    }
+
     fn manage_limit(
         &self,
         inner: &mut MutexGuard<Inner>,
@@ -221,6 +252,8 @@ impl<L> Controller<L> {
     ) {
         let past_rtt_deviation = past_rtt.variance.sqrt();
         let threshold = past_rtt_deviation * self.settings.rtt_deviation_scale;
+        let mut increased = false;
+        let mut decreased = false;
 
         // Normal quick responses trigger an increase in the
         // concurrency limit. Note that we only check this if we had
@@ -235,21 +268,23 @@ impl<L> Controller<L> {
             // Increase (additive) the current concurrency limit
             self.semaphore.add_permits(1);
             inner.current_limit += 1;
+            increased = true;
         }
         // Back pressure responses, either explicit or implicit due
         // to increasing response times, trigger a decrease in the
         // concurrency limit.
-        else if inner.current_limit > 1
+        else if inner.current_limit > self.settings.min_concurrency
             && (inner.had_back_pressure || current_rtt.unwrap_or(0.0) >= past_rtt.mean + threshold)
         {
             // Decrease (multiplicative) the current concurrency limit. The floor rounding in the
             // `usize` conversion guarantees the new limit is smaller than the current limit, and
-            // the `.max` ensures the new limit is above zero.
-            let new_limit =
-                ((inner.current_limit as f64 * self.settings.decrease_ratio) as usize).max(1);
+            // the `.max` ensures the new limit doesn't drop below the minimum, which is above zero.
+            let new_limit = ((inner.current_limit as f64 * self.settings.decrease_ratio) as usize)
+                .max(self.settings.min_concurrency);
             self.semaphore
                 .forget_permits(inner.current_limit - new_limit);
             inner.current_limit = new_limit;
+            decreased = true;
         }
         self.limit.emit(AdaptiveConcurrencyLimitData {
             concurrency: inner.current_limit as u64,
@@ -258,6 +293,8 @@ impl<L> Controller<L> {
             current_rtt: current_rtt.map(Duration::from_secs_f64),
             past_rtt: Duration::from_secs_f64(past_rtt.mean),
             past_rtt_deviation: Duration::from_secs_f64(past_rtt_deviation),
+            increased,
+            decreased,
         });
     }
 }
@@ -10,6 +10,7 @@ mod service;
 pub mod tests;
 
 pub(crate) use layer::AdaptiveConcurrencyLimitLayer;
+use serde::{Deserialize, Deserializer};
 pub(crate) use service::AdaptiveConcurrencyLimit;
 use vector_lib::configurable::configurable_component;
 
@@ -23,7 +24,7 @@ fn instant_now() -> std::time::Instant {
 /// unstable performance and sink behavior. Proceed with caution.
 // The defaults for these values were chosen after running several simulations on a test service that had
 // various responses to load. The values are the best balances found between competing outcomes.
-#[configurable_component]
+#[configurable_component(no_deser)]
 #[derive(Clone, Copy, Debug)]
 #[serde(deny_unknown_fields)]
 pub struct AdaptiveConcurrencySettings {
@@ -75,6 +76,14 @@ pub struct AdaptiveConcurrencySettings {
     #[configurable(validation(range(min = 1)))]
     #[serde(default = "default_max_concurrency_limit")]
     pub(super) max_concurrency_limit: usize,
+
+    /// The minimum concurrency limit.
+    ///
+    /// The adaptive request concurrency limit does not go below this bound, so that requests keep being sent during a
+    /// prolonged outage and the limit can recover once the service does.
+    #[configurable(validation(range(min = 1)))]
+    #[serde(default = "default_min_concurrency")]
+    pub(super) min_concurrency: usize,
 }
 
 const fn default_initial_concurrency() -> usize {
@@ -97,6 +106,51 @@ const fn default_max_concurrency_limit() -> usize {
     200
 }
 
+const fn default_min_concurrency() -> usize {
+    1
+}
+
+impl<'de> Deserialize<'de> for AdaptiveConcurrencySettings {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(deny_unknown_fields)]
+        struct AdaptiveConcurrencySettingsInner {
+            #[serde(default = "default_initial_concurrency")]
+            initial_concurrency: usize,
+            #[serde(default = "default_decrease_ratio")]
+            decrease_ratio: f64,
+            #[serde(default = "default_ewma_alpha")]
+            ewma_alpha: f64,
+            #[serde(default = "default_rtt_deviation_scale")]
+            rtt_deviation_scale: f64,
+            #[serde(default = "default_max_concurrency_limit")]
+            max_concurrency_limit: usize,
+            #[serde(default = "default_min_concurrency")]
+            min_concurrency: usize,
+        }
+
+        let inner: AdaptiveConcurrencySettingsInner = Deserialize::deserialize(deserializer)?;
+        if inner.min_concurrency > inner.max_concurrency_limit {
+            return Err(serde::de::Error::custom(format!(
+                "`min_concurrency` ({}) must not be greater than `max_concurrency_limit` ({})",
+                inner.min_concurrency, inner.max_concurrency_limit
+            )));
+        }
+
+        Ok(Self {
+            initial_concurrency: inner.initial_concurrency,
+            decrease_ratio: inner.decrease_ratio,
+            ewma_alpha: inner.ewma_alpha,
+            rtt_deviation_scale: inner.rtt_deviation_scale,
+            max_concurrency_limit: inner.max_concurrency_limit,
+            min_concurrency: inner.min_concurrency,
+        })
+    }
+}
+
 impl Default for AdaptiveConcurrencySettings {
     fn default() -> Self {
         Self {
@@ -105,6 +159,7 @@ impl Default for AdaptiveConcurrencySettings {
             ewma_alpha: default_ewma_alpha(),
             rtt_deviation_scale: default_rtt_deviation_scale(),
             max_concurrency_limit: default_max_concurrency_limit(),
+            min_concurrency: default_min_concurrency(),
         }
     }
 }
@@ -178,15 +178,8 @@ mod tests {
     }
 
     impl TestService {
-        fn start() -> Self {
-            let layer = AdaptiveConcurrencyLimitLayer::new(
-                None,
-                AdaptiveConcurrencySettings {
-                    decrease_ratio: 0.5,
-                    ..Default::default()
-                },
-                TestRetryLogic,
-            );
+        fn start(settings: AdaptiveConcurrencySettings) -> Self {
+            let layer = AdaptiveConcurrencyLimitLayer::new(None, settings, TestRetryLogic);
             let (service, handle) = mock::spawn_layer(layer);
             let controller = Arc::clone(&service.get_ref().controller);
             let inner = Arc::clone(&controller.inner);
@@ -205,7 +198,25 @@ mod tests {
             F: FnOnce(Self) -> Ret,
             Ret: Future<Output = ()>,
         {
-            let svc = Self::start();
+            Self::run_with(
+                AdaptiveConcurrencySettings {
+                    decrease_ratio: 0.5,
+                    ..Default::default()
+                },
+                doit,
+            )
+            .await
+        }
+
+        async fn run_with<F, Ret>(
+            settings: AdaptiveConcurrencySettings,
+            doit: F,
+        ) -> ControllerStatistics
+        where
+            F: FnOnce(Self) -> Ret,
+            Ret: Future<Output = ()>,
+        {
+            let svc = Self::start(settings);
             //let inner = svc.inner.clone();
             let stats = Arc::clone(&svc.stats);
             pause();
@@ -235,6 +246,18 @@ mod tests {
         fn inner(&self) -> MutexGuard<Inner> {
             self.inner.lock().unwrap()
         }
+
+        /// Sends `concurrent` requests at once, and responds to all of them after `rtt`.
+        async fn send_batch(&mut self, concurrent: usize, rtt: Duration) {
+            let mut reqs = Vec::with_capacity(concurrent);
+            for i in 0..concurrent {
+                reqs.push(self.send(i < concurrent - 1).await);
+            }
+            advance(rtt).await;
+            for req in reqs {
+                req.respond().await;
+            }
+        }
     }
 
     impl Send {
@@ -346,4 +369,69 @@ mod tests {
         })
         .await;
     }
+
+    #[tokio::test]
+    async fn recovers_after_latency() {
+        TestService::run(|mut svc| async move {
+            for &concurrent in &[1, 1, 2, 3] {
+                assert_eq!(svc.inner().current_limit, concurrent);
+                svc.send_batch(concurrent, Duration::from_secs(1)).await;
+            }
+            assert_eq!(svc.inner().current_limit, 4);
+
+            // Increased latency, without any explicit back pressure, decreases the limit.
+            svc.send_batch(4, Duration::from_secs(3)).await;
+            assert!(svc.inner().current_limit < 4);
+
+            // Once the latency is back to normal, the limit increases again.
+            for _ in 0..20 {
+                let concurrent = svc.inner().current_limit;
+                if concurrent >= 4 {
+                    break;
+                }
+                svc.send_batch(concurrent, Duration::from_secs(1)).await;
+                assert!(svc.inner().current_limit >= concurrent);
+            }
+            assert!(svc.inner().current_limit >= 4);
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn respects_min_concurrency() {
+        let settings = AdaptiveConcurrencySettings {
+            decrease_ratio: 0.5,
+            min_concurrency: 2,
+            ..Default::default()
+        };
+        TestService::run_with(settings, |mut svc| async move {
+            // The initial concurrency is raised to the minimum.
+            assert_eq!(svc.inner().current_limit, 2);
+            let req = svc.send(true).await;
+            advance(Duration::from_secs(1)).await;
+            req.respond().await;
+
+            let req = svc.send(true).await;
+            advance(Duration::from_secs(1)).await;
+            req.defer().await;
+            assert_eq!(svc.inner().current_limit, 2);
+        })
+        .await;
+    }
+
+    #[test]
+    fn rejects_min_concurrency_above_max() {
+        let error = toml::from_str::<AdaptiveConcurrencySettings>(
+            "min_concurrency = 10\nmax_concurrency_limit = 5",
+        )
+        .unwrap_err();
+        assert!(error.to_string().contains(
+            "`min_concurrency` (10) must not be greater than `max_concurrency_limit` (5)"
+        ));
+
+        let settings =
+            toml::from_str::<AdaptiveConcurrencySettings>("min_concurrency = 5").unwrap();
+        assert_eq!(settings.min_concurrency, 5);
+        assert_eq!(settings.max_concurrency_limit, 200);
+    }
 }
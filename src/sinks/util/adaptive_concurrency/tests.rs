@@ -490,7 +490,7 @@ async fn run_test(params: TestParams) -> TestResults {
     if params.concurrency == Concurrency::Adaptive {
         assert!(matches!(
             metrics.get("adaptive_concurrency_limit").unwrap().value(),
-            &MetricValue::AggregatedHistogram { .. }
+            &MetricValue::Gauge { .. }
         ));
     }
     assert!(matches!(
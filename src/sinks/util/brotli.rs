@@ -0,0 +1,121 @@
+use std::{fmt::Display, io};
+
+use super::buffer::compression::CompressionLevel;
+
+/// The size of the buffer holding the input of the encoder.
+const BUFFER_SIZE: usize = 4_096;
+
+/// The base two logarithm of the size of the sliding window, the default of the reference encoder.
+const WINDOW_SIZE_LOG2: u32 = 22;
+
+/// The quality of Brotli compression, from 0 to 11.
+#[derive(Debug)]
+pub struct BrotliQuality(u32);
+
+impl From<CompressionLevel> for BrotliQuality {
+    fn from(value: CompressionLevel) -> Self {
+        let quality = match value {
+            CompressionLevel::None => 0,
+            // The highest qualities are too slow to compress payloads on the fly.
+            CompressionLevel::Default => 6,
+            CompressionLevel::Best => 11,
+            CompressionLevel::Fast => 1,
+            CompressionLevel::Val(v) => v.min(11),
+        };
+        BrotliQuality(quality)
+    }
+}
+
+impl Display for BrotliQuality {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+pub struct BrotliEncoder<W: io::Write> {
+    inner: Box<brotli::CompressorWriter<W>>,
+}
+
+impl<W: io::Write> BrotliEncoder<W> {
+    pub fn new(writer: W, quality: BrotliQuality) -> Self {
+        let encoder =
+            brotli::CompressorWriter::new(writer, BUFFER_SIZE, quality.0, WINDOW_SIZE_LOG2);
+        Self {
+            inner: Box::new(encoder),
+        }
+    }
+
+    /// Finishes the stream, returning the inner writer.
+    ///
+    /// The encoder doesn't report errors writing the end of the stream, which the in-memory writers
+    /// of sinks can't run into.
+    pub fn finish(self) -> io::Result<W> {
+        Ok((*self.inner).into_inner())
+    }
+
+    pub fn get_ref(&self) -> &W {
+        self.inner.get_ref()
+    }
+}
+
+impl<W: io::Write> io::Write for BrotliEncoder<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        #[allow(clippy::disallowed_methods)] // Caller handles the result of `write`.
+        self.inner.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+impl<W: io::Write + std::fmt::Debug> std::fmt::Debug for BrotliEncoder<W> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BrotliEncoder")
+            .field("inner", &self.get_ref())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Read, Write};
+
+    use super::*;
+
+    #[test]
+    fn compresses_brotli_stream() {
+        let input =
+            b"It's going down, I'm yelling timber, You better move, you better dance".repeat(100);
+
+        let mut encoder = BrotliEncoder::new(Vec::new(), CompressionLevel::Default.into());
+        encoder.write_all(&input).unwrap();
+        let compressed = encoder.finish().unwrap();
+
+        // The stream starts with the size of the window: a set bit, followed by 22 - 17 in 3 bits.
+        assert_eq!(compressed[0] & 0x0f, 0b1011);
+        assert!(compressed.len() < input.len() / 10);
+
+        let mut decompressed = Vec::new();
+        brotli::Decompressor::new(&compressed[..], BUFFER_SIZE)
+            .read_to_end(&mut decompressed)
+            .unwrap();
+        assert_eq!(decompressed, input);
+    }
+
+    #[test]
+    fn clamps_quality() {
+        assert_eq!(
+            BrotliQuality::from(CompressionLevel::Val(20)).to_string(),
+            "11"
+        );
+        assert_eq!(
+            BrotliQuality::from(CompressionLevel::Best).to_string(),
+            "11"
+        );
+        assert_eq!(
+            BrotliQuality::from(CompressionLevel::Default).to_string(),
+            "6"
+        );
+    }
+}
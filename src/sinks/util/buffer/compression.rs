@@ -13,7 +13,7 @@ use vector_lib::configurable::{
     Configurable, GenerateError, Metadata, ToValue,
 };
 
-use crate::sinks::util::zstd::ZstdCompressionLevel;
+use crate::sinks::util::{brotli::BrotliQuality, zstd::ZstdCompressionLevel};
 
 /// Compression configuration.
 #[derive(Copy, Clone, Debug, Derivative, Eq, PartialEq)]
@@ -42,6 +42,16 @@ pub enum Compression {
     ///
     /// [snappy]: https://github.com/google/snappy/blob/main/docs/README.md
     Snappy,
+
+    /// [LZ4][lz4] compression, using the frame format.
+    ///
+    /// [lz4]: https://github.com/lz4/lz4/blob/dev/doc/lz4_Frame_format.md
+    Lz4,
+
+    /// [Brotli][brotli] compression.
+    ///
+    /// [brotli]: https://github.com/google/brotli
+    Brotli(CompressionLevel),
 }
 
 impl Compression {
@@ -69,6 +79,10 @@ impl Compression {
         Compression::Zstd(CompressionLevel::const_default())
     }
 
+    pub const fn brotli_default() -> Compression {
+        Compression::Brotli(CompressionLevel::const_default())
+    }
+
     pub const fn content_encoding(self) -> Option<&'static str> {
         match self {
             Self::None => None,
@@ -76,6 +90,8 @@ impl Compression {
             Self::Zlib(_) => Some("deflate"),
             Self::Zstd(_) => Some("zstd"),
             Self::Snappy => Some("snappy"),
+            Self::Lz4 => Some("lz4"),
+            Self::Brotli(_) => Some("br"),
         }
     }
 
@@ -85,6 +101,8 @@ impl Compression {
             Self::Zlib(_) => Some("deflate"),
             Self::Zstd(_) => Some("zstd"),
             Self::Snappy => Some("snappy"),
+            Self::Lz4 => Some("lz4"),
+            Self::Brotli(_) => Some("br"),
             _ => None,
         }
     }
@@ -96,6 +114,8 @@ impl Compression {
             Self::Zlib(_) => "log.zz",
             Self::Zstd(_) => "log.zst",
             Self::Snappy => "log.snappy",
+            Self::Lz4 => "log.lz4",
+            Self::Brotli(_) => "log.br",
         }
     }
 
@@ -106,13 +126,17 @@ impl Compression {
             Compression::Zlib(_) => 9,
             Compression::Zstd(_) => 21,
             Compression::Snappy => 0,
+            Compression::Lz4 => 0,
+            Compression::Brotli(_) => 11,
         }
     }
 
     pub const fn compression_level(self) -> CompressionLevel {
         match self {
-            Self::None | Self::Snappy => CompressionLevel::None,
-            Self::Gzip(level) | Self::Zlib(level) | Self::Zstd(level) => level,
+            Self::None | Self::Snappy | Self::Lz4 => CompressionLevel::None,
+            Self::Gzip(level) | Self::Zlib(level) | Self::Zstd(level) | Self::Brotli(level) => {
+                level
+            }
         }
     }
 }
@@ -127,6 +151,10 @@ impl fmt::Display for Compression {
                 write!(f, "zstd({})", ZstdCompressionLevel::from(*level))
             }
             Compression::Snappy => write!(f, "snappy"),
+            Compression::Lz4 => write!(f, "lz4"),
+            Compression::Brotli(ref level) => {
+                write!(f, "brotli({})", BrotliQuality::from(*level))
+            }
         }
     }
 }
@@ -155,6 +183,8 @@ impl<'de> de::Deserialize<'de> for Compression {
                     "zlib" => Ok(Compression::zlib_default()),
                     "zstd" => Ok(Compression::zstd_default()),
                     "snappy" => Ok(Compression::Snappy),
+                    "lz4" => Ok(Compression::Lz4),
+                    "brotli" => Ok(Compression::brotli_default()),
                     _ => Err(de::Error::invalid_value(
                         de::Unexpected::Str(s),
                         &r#""none" or "gzip" or "zlib" or "zstd""#,
@@ -202,9 +232,14 @@ impl<'de> de::Deserialize<'de> for Compression {
                         Some(_) => Err(de::Error::unknown_field("level", &[])),
                         None => Ok(Compression::Snappy),
                     },
+                    "lz4" => match level {
+                        Some(_) => Err(de::Error::unknown_field("level", &[])),
+                        None => Ok(Compression::Lz4),
+                    },
+                    "brotli" => Ok(Compression::Brotli(level.unwrap_or_default())),
                     algorithm => Err(de::Error::unknown_variant(
                         algorithm,
-                        &["none", "gzip", "zlib", "zstd", "snappy"],
+                        &["none", "gzip", "zlib", "zstd", "snappy", "lz4", "brotli"],
                     )),
                 }?;
 
@@ -268,6 +303,17 @@ impl ser::Serialize for Compression {
                 }
             }
             Compression::Snappy => serializer.serialize_str("snappy"),
+            Compression::Lz4 => serializer.serialize_str("lz4"),
+            Compression::Brotli(brotli_level) => {
+                if *brotli_level != CompressionLevel::Default {
+                    let mut map = serializer.serialize_map(None)?;
+                    map.serialize_entry("algorithm", "brotli")?;
+                    map.serialize_entry("level", &brotli_level)?;
+                    map.end()
+                } else {
+                    serializer.serialize_str("brotli")
+                }
+            }
         }
     }
 }
@@ -337,12 +383,26 @@ impl Configurable for Compression {
             "[snappy]: https://github.com/google/snappy/blob/main/docs/README.md",
         );
 
+        let lz4_string_subschema = generate_string_schema(
+            "Lz4",
+            Some("[LZ4][lz4] compression, using the frame format."),
+            "[lz4]: https://github.com/lz4/lz4/blob/dev/doc/lz4_Frame_format.md",
+        );
+
+        let brotli_string_subschema = generate_string_schema(
+            "Brotli",
+            Some("[Brotli][brotli] compression."),
+            "[brotli]: https://github.com/google/brotli",
+        );
+
         let mut all_string_oneof_subschema = generate_one_of_schema(&[
             none_string_subschema,
             gzip_string_subschema,
             zlib_string_subschema,
             zstd_string_subschema,
             snappy_string_subschema,
+            lz4_string_subschema,
+            brotli_string_subschema,
         ]);
         apply_base_metadata(&mut all_string_oneof_subschema, string_metadata);
 
@@ -532,6 +592,11 @@ mod test {
             (r#""gzip""#, Compression::Gzip(CompressionLevel::default())),
             (r#""zlib""#, Compression::Zlib(CompressionLevel::default())),
             (r#""snappy""#, Compression::Snappy),
+            (r#""lz4""#, Compression::Lz4),
+            (
+                r#""brotli""#,
+                Compression::Brotli(CompressionLevel::default()),
+            ),
             (r#"{"algorithm": "none"}"#, Compression::None),
             (
                 r#"{"algorithm": "gzip"}"#,
@@ -557,6 +622,10 @@ mod test {
                 r#"{"algorithm": "zlib", "level": 8}"#,
                 Compression::Zlib(CompressionLevel::Val(8)),
             ),
+            (
+                r#"{"algorithm": "brotli", "level": 11}"#,
+                Compression::Brotli(CompressionLevel::Val(11)),
+            ),
         ];
         for (sources, result) in fixtures_valid.iter() {
             let deserialized: Result<Compression, _> = serde_json::from_str(sources);
@@ -574,7 +643,7 @@ mod test {
             ),
             (
                 r#"{"algorithm": "b42"}"#,
-                r"unknown variant `b42`, expected one of `none`, `gzip`, `zlib`, `zstd`, `snappy` at line 1 column 20",
+                r"unknown variant `b42`, expected one of `none`, `gzip`, `zlib`, `zstd`, `snappy`, `lz4`, `brotli` at line 1 column 20",
             ),
             (
                 r#"{"algorithm": "none", "level": "default"}"#,
@@ -604,10 +673,18 @@ mod test {
                 r#"{"algorithm": "zstd", "level": 22}"#,
                 r"invalid value `22`, expected value in range [0, 21] at line 1 column 34",
             ),
+            (
+                r#"{"algorithm": "brotli", "level": 12}"#,
+                r"invalid value `12`, expected value in range [0, 11] at line 1 column 36",
+            ),
             (
                 r#"{"algorithm": "snappy", "level": 3}"#,
                 r"unknown field `level`, there are no fields at line 1 column 35",
             ),
+            (
+                r#"{"algorithm": "lz4", "level": 3}"#,
+                r"unknown field `level`, there are no fields at line 1 column 32",
+            ),
         ];
         for (source, result) in fixtures_invalid.iter() {
             let deserialized: Result<Compression, _> = serde_json::from_str(source);
@@ -644,6 +721,9 @@ mod test {
             Compression::Zstd(CompressionLevel::default()),
             Compression::Zstd(CompressionLevel::Best),
             Compression::Zstd(CompressionLevel::Fast),
+            Compression::Lz4,
+            Compression::Brotli(CompressionLevel::Val(4)),
+            Compression::Brotli(CompressionLevel::default()),
         ];
 
         for v in fixtures_valid {
@@ -2,9 +2,11 @@ use std::io::Write;
 
 use bytes::{BufMut, BytesMut};
 use flate2::write::{GzEncoder, ZlibEncoder};
+use lz4_flex::frame::FrameEncoder;
 
 use super::{
     batch::{err_event_too_large, Batch, BatchSize, PushResult},
+    brotli::BrotliEncoder,
     snappy::SnappyEncoder,
     zstd::ZstdEncoder,
 };
@@ -34,6 +36,8 @@ pub enum InnerBuffer {
     Zlib(ZlibEncoder<bytes::buf::Writer<BytesMut>>),
     Zstd(ZstdEncoder<bytes::buf::Writer<BytesMut>>),
     Snappy(SnappyEncoder<bytes::buf::Writer<BytesMut>>),
+    Lz4(FrameEncoder<bytes::buf::Writer<BytesMut>>),
+    Brotli(BrotliEncoder<bytes::buf::Writer<BytesMut>>),
 }
 
 impl Buffer {
@@ -65,6 +69,10 @@ impl Buffer {
                         .expect("Zstd encoder should not fail on init."),
                 ),
                 Compression::Snappy => InnerBuffer::Snappy(SnappyEncoder::new(writer)),
+                Compression::Lz4 => InnerBuffer::Lz4(FrameEncoder::new(writer)),
+                Compression::Brotli(level) => {
+                    InnerBuffer::Brotli(BrotliEncoder::new(writer, level.into()))
+                }
             }
         })
     }
@@ -85,6 +93,8 @@ impl Buffer {
                 inner.write_all(input).unwrap();
             }
             InnerBuffer::Snappy(inner) => inner.write_all(input).unwrap(),
+            InnerBuffer::Lz4(inner) => inner.write_all(input).unwrap(),
+            InnerBuffer::Brotli(inner) => inner.write_all(input).unwrap(),
         }
     }
 
@@ -97,6 +107,8 @@ impl Buffer {
                 InnerBuffer::Zlib(inner) => inner.get_ref().get_ref().is_empty(),
                 InnerBuffer::Zstd(inner) => inner.get_ref().get_ref().is_empty(),
                 InnerBuffer::Snappy(inner) => inner.is_empty(),
+                // These encoders hold back data until a whole block is filled.
+                InnerBuffer::Lz4(_) | InnerBuffer::Brotli(_) => self.num_items == 0,
             })
             .unwrap_or(true)
     }
@@ -151,6 +163,14 @@ impl Batch for Buffer {
                 .finish()
                 .expect("This can't fail because the inner writer is a Vec")
                 .into_inner(),
+            Some(InnerBuffer::Lz4(inner)) => inner
+                .finish()
+                .expect("This can't fail because the inner writer is a Vec")
+                .into_inner(),
+            Some(InnerBuffer::Brotli(inner)) => inner
+                .finish()
+                .expect("This can't fail because the inner writer is a Vec")
+                .into_inner(),
             None => BytesMut::new(),
         }
     }
@@ -0,0 +1,182 @@
+use std::{sync::Mutex, time::Duration};
+
+use tokio::time::Instant;
+
+/// The state of a [`CircuitBreaker`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CircuitState {
+    /// Requests flow normally.
+    Closed,
+    /// The endpoint is failing, so requests are short-circuited until the recovery timeout elapses.
+    Open,
+    /// The recovery timeout has elapsed, and a single probe is let through to check whether the
+    /// endpoint has recovered.
+    HalfOpen,
+}
+
+#[derive(Debug)]
+struct Inner {
+    state: CircuitState,
+    consecutive_failures: u32,
+    /// When the circuit was opened, or when the last probe was let through while half-open.
+    since: Instant,
+}
+
+/// Stops sending requests to an endpoint after a number of consecutive failures.
+///
+/// Once `threshold` consecutive failures have been recorded, the circuit opens and
+/// [`CircuitBreaker::allow`] rejects everything for `recovery_timeout`. The circuit is then
+/// half-open: a single probe is allowed, and its outcome either closes the circuit again or
+/// reopens it for another `recovery_timeout`. If the outcome of the probe is never recorded, for
+/// example because it was dropped before being sent, another probe is allowed after
+/// `recovery_timeout`.
+#[derive(Debug)]
+pub struct CircuitBreaker {
+    threshold: u32,
+    recovery_timeout: Duration,
+    inner: Mutex<Inner>,
+}
+
+impl CircuitBreaker {
+    pub fn new(threshold: u32, recovery_timeout: Duration) -> Self {
+        Self {
+            threshold: threshold.max(1),
+            recovery_timeout,
+            inner: Mutex::new(Inner {
+                state: CircuitState::Closed,
+                consecutive_failures: 0,
+                since: Instant::now(),
+            }),
+        }
+    }
+
+    /// Returns the current state of the circuit.
+    pub fn state(&self) -> CircuitState {
+        self.inner.lock().expect("lock poisoned").state
+    }
+
+    /// Returns whether a request may be sent, letting a probe through once the recovery timeout
+    /// has elapsed.
+    pub fn allow(&self) -> bool {
+        let mut inner = self.inner.lock().expect("lock poisoned");
+        match inner.state {
+            CircuitState::Closed => true,
+            CircuitState::Open | CircuitState::HalfOpen
+                if inner.since.elapsed() >= self.recovery_timeout =>
+            {
+                inner.state = CircuitState::HalfOpen;
+                inner.since = Instant::now();
+                true
+            }
+            CircuitState::Open | CircuitState::HalfOpen => false,
+        }
+    }
+
+    /// Records a request which reached the endpoint, closing the circuit.
+    pub fn record_success(&self) {
+        let mut inner = self.inner.lock().expect("lock poisoned");
+        if inner.state != CircuitState::Closed {
+            debug!(message = "Endpoint recovered; closing circuit breaker.");
+        }
+        inner.state = CircuitState::Closed;
+        inner.consecutive_failures = 0;
+    }
+
+    /// Records a request which failed because of the endpoint, opening the circuit once the
+    /// threshold of consecutive failures is reached or when a probe fails.
+    pub fn record_failure(&self) {
+        let mut inner = self.inner.lock().expect("lock poisoned");
+        inner.consecutive_failures = inner.consecutive_failures.saturating_add(1);
+
+        let open = match inner.state {
+            CircuitState::Closed => inner.consecutive_failures >= self.threshold,
+            CircuitState::HalfOpen => true,
+            // Failures of requests sent before the circuit opened don't extend the timeout.
+            CircuitState::Open => false,
+        };
+        if open {
+            warn!(
+                message = "Endpoint is failing; opening circuit breaker.",
+                consecutive_failures = inner.consecutive_failures,
+                recovery_secs = self.recovery_timeout.as_secs_f64(),
+            );
+            inner.state = CircuitState::Open;
+            inner.since = Instant::now();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RECOVERY: Duration = Duration::from_secs(10);
+
+    #[tokio::test(start_paused = true)]
+    async fn opens_after_threshold() {
+        let breaker = CircuitBreaker::new(3, RECOVERY);
+
+        breaker.record_failure();
+        breaker.record_failure();
+        assert!(breaker.allow());
+
+        // A success resets the count of consecutive failures.
+        breaker.record_success();
+        breaker.record_failure();
+        breaker.record_failure();
+        assert_eq!(breaker.state(), CircuitState::Closed);
+
+        breaker.record_failure();
+        assert_eq!(breaker.state(), CircuitState::Open);
+        assert!(!breaker.allow());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn probes_after_recovery_timeout() {
+        let breaker = CircuitBreaker::new(1, RECOVERY);
+        breaker.record_failure();
+
+        tokio::time::advance(RECOVERY / 2).await;
+        assert!(!breaker.allow());
+
+        tokio::time::advance(RECOVERY / 2).await;
+        assert!(breaker.allow());
+        assert_eq!(breaker.state(), CircuitState::HalfOpen);
+        // Only a single probe is let through.
+        assert!(!breaker.allow());
+
+        breaker.record_success();
+        assert_eq!(breaker.state(), CircuitState::Closed);
+        assert!(breaker.allow());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn reopens_when_probe_fails() {
+        let breaker = CircuitBreaker::new(5, RECOVERY);
+        for _ in 0..5 {
+            breaker.record_failure();
+        }
+
+        tokio::time::advance(RECOVERY).await;
+        assert!(breaker.allow());
+        breaker.record_failure();
+        assert_eq!(breaker.state(), CircuitState::Open);
+        assert!(!breaker.allow());
+
+        tokio::time::advance(RECOVERY).await;
+        assert!(breaker.allow());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn probes_again_when_outcome_is_unknown() {
+        let breaker = CircuitBreaker::new(1, RECOVERY);
+        breaker.record_failure();
+
+        tokio::time::advance(RECOVERY).await;
+        assert!(breaker.allow());
+        assert!(!breaker.allow());
+
+        tokio::time::advance(RECOVERY).await;
+        assert!(breaker.allow());
+    }
+}
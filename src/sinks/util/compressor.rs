@@ -2,8 +2,9 @@ use std::{io, io::BufWriter};
 
 use bytes::{BufMut, BytesMut};
 use flate2::write::{GzEncoder, ZlibEncoder};
+use lz4_flex::frame::FrameEncoder;
 
-use super::{snappy::SnappyEncoder, zstd::ZstdEncoder, Compression};
+use super::{brotli::BrotliEncoder, snappy::SnappyEncoder, zstd::ZstdEncoder, Compression};
 
 const GZIP_INPUT_BUFFER_CAPACITY: usize = 4_096;
 const ZLIB_INPUT_BUFFER_CAPACITY: usize = 4_096;
@@ -16,6 +17,8 @@ enum Writer {
     Zlib(BufWriter<ZlibEncoder<bytes::buf::Writer<BytesMut>>>),
     Zstd(ZstdEncoder<bytes::buf::Writer<BytesMut>>),
     Snappy(SnappyEncoder<bytes::buf::Writer<BytesMut>>),
+    Lz4(FrameEncoder<bytes::buf::Writer<BytesMut>>),
+    Brotli(BrotliEncoder<bytes::buf::Writer<BytesMut>>),
 }
 
 impl Writer {
@@ -26,6 +29,8 @@ impl Writer {
             Writer::Zlib(inner) => inner.get_ref().get_ref().get_ref(),
             Writer::Zstd(inner) => inner.get_ref().get_ref(),
             Writer::Snappy(inner) => inner.get_ref().get_ref(),
+            Writer::Lz4(inner) => inner.get_ref().get_ref(),
+            Writer::Brotli(inner) => inner.get_ref().get_ref(),
         }
     }
 
@@ -48,6 +53,12 @@ impl Writer {
             Writer::Snappy(writer) => writer
                 .finish()
                 .expect("snappy writer should not fail to finish"),
+            Writer::Lz4(writer) => writer
+                .finish()
+                .expect("lz4 writer should not fail to finish"),
+            Writer::Brotli(writer) => writer
+                .finish()
+                .expect("brotli writer should not fail to finish"),
         }
         .into_inner()
     }
@@ -59,6 +70,8 @@ impl Writer {
             Writer::Zlib(writer) => writer.into_inner()?.finish()?,
             Writer::Zstd(writer) => writer.finish()?,
             Writer::Snappy(writer) => writer.finish()?,
+            Writer::Lz4(writer) => writer.finish()?,
+            Writer::Brotli(writer) => writer.finish()?,
         }
         .into_inner();
 
@@ -91,6 +104,8 @@ impl From<Compression> for Writer {
                 Writer::Zstd(encoder)
             }
             Compression::Snappy => Writer::Snappy(SnappyEncoder::new(writer)),
+            Compression::Lz4 => Writer::Lz4(FrameEncoder::new(writer)),
+            Compression::Brotli(level) => Writer::Brotli(BrotliEncoder::new(writer, level.into())),
         }
     }
 }
@@ -104,6 +119,8 @@ impl io::Write for Writer {
             Writer::Zlib(writer) => writer.write(buf),
             Writer::Zstd(writer) => writer.write(buf),
             Writer::Snappy(writer) => writer.write(buf),
+            Writer::Lz4(writer) => writer.write(buf),
+            Writer::Brotli(writer) => writer.write(buf),
         }
     }
 
@@ -114,6 +131,8 @@ impl io::Write for Writer {
             Writer::Zlib(writer) => writer.flush(),
             Writer::Zstd(writer) => writer.flush(),
             Writer::Snappy(writer) => writer.flush(),
+            Writer::Lz4(writer) => writer.flush(),
+            Writer::Brotli(writer) => writer.flush(),
         }
     }
 }
@@ -5,7 +5,7 @@ use aws_types::region::Region;
 use bytes::{Buf, Bytes};
 use futures::{future::BoxFuture, Sink};
 use headers::HeaderName;
-use http::{header, HeaderValue, Request, Response, StatusCode};
+use http::{header, HeaderMap, HeaderValue, Request, Response, StatusCode};
 use hyper::{body, Body};
 use indexmap::IndexMap;
 use pin_project::pin_project;
@@ -437,7 +437,10 @@ where
 
         Box::pin(async move {
             let request = request_builder(body).await.inspect_err(|error| {
-                emit!(SinkRequestBuildError { error });
+                emit!(SinkRequestBuildError {
+                    error,
+                    error_code: "request_build_failed",
+                });
             })?;
 
             #[cfg(feature = "aws-core")]
@@ -536,6 +539,29 @@ impl RetryLogic for HttpRetryLogic {
             _ => RetryAction::DontRetry(format!("response status: {}", status).into()),
         }
     }
+
+    fn retry_after(&self, response: &Self::Response) -> Option<Duration> {
+        rate_limit_delay(response.status(), response.headers())
+    }
+}
+
+/// Returns the delay requested by a `429 Too Many Requests` response, either through its
+/// `Retry-After` header, in seconds, or its `X-RateLimit-Reset` header, as a Unix timestamp.
+pub fn rate_limit_delay(status: StatusCode, headers: &HeaderMap) -> Option<Duration> {
+    if status != StatusCode::TOO_MANY_REQUESTS {
+        return None;
+    }
+
+    let header_secs =
+        |name: &str| -> Option<u64> { headers.get(name)?.to_str().ok()?.trim().parse().ok() };
+
+    header_secs(header::RETRY_AFTER.as_str())
+        .map(Duration::from_secs)
+        .or_else(|| {
+            let reset = header_secs("x-ratelimit-reset")?;
+            let now = u64::try_from(chrono::Utc::now().timestamp()).ok()?;
+            Some(Duration::from_secs(reset.saturating_sub(now)))
+        })
 }
 
 /// A more generic version of `HttpRetryLogic` that accepts anything that can be converted
@@ -543,6 +569,7 @@ impl RetryLogic for HttpRetryLogic {
 #[derive(Debug)]
 pub struct HttpStatusRetryLogic<F, T> {
     func: F,
+    headers: Option<fn(&T) -> &HeaderMap>,
     request: PhantomData<T>,
 }
 
@@ -554,9 +581,17 @@ where
     pub const fn new(func: F) -> HttpStatusRetryLogic<F, T> {
         HttpStatusRetryLogic {
             func,
+            headers: None,
             request: PhantomData,
         }
     }
+
+    /// Reads the headers of rate limited responses to honor the delay they request before
+    /// retrying.
+    pub const fn with_headers(mut self, headers: fn(&T) -> &HeaderMap) -> Self {
+        self.headers = Some(headers);
+        self
+    }
 }
 
 impl<F, T> RetryLogic for HttpStatusRetryLogic<F, T>
@@ -587,6 +622,11 @@ where
             _ => RetryAction::DontRetry(format!("Http status: {}", status).into()),
         }
     }
+
+    fn retry_after(&self, response: &T) -> Option<Duration> {
+        let headers = self.headers?;
+        rate_limit_delay((self.func)(response), headers(response))
+    }
 }
 
 impl<F, T> Clone for HttpStatusRetryLogic<F, T>
@@ -596,6 +636,7 @@ where
     fn clone(&self) -> Self {
         Self {
             func: self.func.clone(),
+            headers: self.headers,
             request: PhantomData,
         }
     }
@@ -752,6 +793,7 @@ pub fn http_response_retry_logic() -> HttpStatusRetryLogic<
     HttpResponse,
 > {
     HttpStatusRetryLogic::new(|req: &HttpResponse| req.http_response.status())
+        .with_headers(|req: &HttpResponse| req.http_response.headers())
 }
 
 /// Uses the estimated json encoded size to determine batch sizing.
@@ -867,9 +909,17 @@ mod test {
         service::{make_service_fn, service_fn},
         Response, Server, Uri,
     };
+    use tokio::time;
+    use tokio_test::{assert_pending, assert_ready_ok, task};
+    use tower::retry::RetryLayer;
+    use tower_test::{assert_request_eq, mock};
 
     use super::*;
-    use crate::{config::ProxyConfig, test_util::next_addr};
+    use crate::{
+        config::ProxyConfig,
+        sinks::util::retries::{FibonacciRetryPolicy, JitterMode},
+        test_util::next_addr,
+    };
 
     #[test]
     fn util_http_retry_logic() {
@@ -891,6 +941,80 @@ mod test {
             .is_not_retryable());
     }
 
+    #[test]
+    fn util_http_rate_limit_delay() {
+        let rate_limited = |name: &str, value: String| {
+            Response::builder()
+                .status(429)
+                .header(name, value)
+                .body(Bytes::new())
+                .unwrap()
+        };
+
+        let response = rate_limited("Retry-After", "5".to_owned());
+        assert_eq!(
+            HttpRetryLogic.retry_after(&response),
+            Some(Duration::from_secs(5))
+        );
+
+        let reset = chrono::Utc::now().timestamp() + 30;
+        let response = rate_limited("X-RateLimit-Reset", reset.to_string());
+        let delay = HttpRetryLogic.retry_after(&response).unwrap();
+        assert!(delay > Duration::from_secs(28) && delay <= Duration::from_secs(30));
+
+        let response = rate_limited("Retry-After", "Wed, 21 Oct 2015 07:28:00 GMT".to_owned());
+        assert_eq!(HttpRetryLogic.retry_after(&response), None);
+
+        let response = Response::builder()
+            .status(503)
+            .header("Retry-After", "5")
+            .body(Bytes::new())
+            .unwrap();
+        assert_eq!(HttpRetryLogic.retry_after(&response), None);
+    }
+
+    #[tokio::test]
+    async fn util_http_retries_after_requested_delay() {
+        time::pause();
+
+        let policy = FibonacciRetryPolicy::new(
+            5,
+            Duration::from_secs(1),
+            Duration::from_secs(30),
+            http_response_retry_logic(),
+            JitterMode::None,
+        );
+        let (mut svc, mut handle) = mock::spawn_layer(RetryLayer::new(policy));
+        assert_ready_ok!(svc.poll_ready());
+
+        let mut fut = task::spawn(svc.call("hello"));
+        let http_response = Response::builder()
+            .status(429)
+            .header("Retry-After", "5")
+            .body(Bytes::new())
+            .unwrap();
+        assert_request_eq!(handle, "hello").send_response(HttpResponse {
+            http_response,
+            events_byte_size: GroupedCountByteSize::new_untagged(),
+            raw_byte_size: 0,
+        });
+        assert_pending!(fut.poll());
+
+        // The static backoff would have retried after a second.
+        time::advance(Duration::from_secs(4)).await;
+        assert_pending!(fut.poll());
+        assert!(handle.poll_request().is_pending());
+
+        time::advance(Duration::from_secs(1)).await;
+        assert_pending!(fut.poll());
+        assert_request_eq!(handle, "hello").send_response(HttpResponse {
+            http_response: Response::new(Bytes::new()),
+            events_byte_size: GroupedCountByteSize::new_untagged(),
+            raw_byte_size: 0,
+        });
+        assert!(fut.await.unwrap().http_response.status().is_success());
+    }
+
     #[tokio::test]
     async fn util_http_it_makes_http_requests() {
         let addr = next_addr();
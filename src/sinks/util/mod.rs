@@ -3,8 +3,10 @@ pub mod auth;
 // https://github.com/mcarton/rust-derivative/issues/112
 #[allow(clippy::non_canonical_clone_impl)]
 pub mod batch;
+pub mod brotli;
 pub mod buffer;
 pub mod builder;
+pub mod circuit_breaker;
 pub mod compressor;
 pub mod datagram;
 pub mod encoding;
@@ -15,6 +17,7 @@ pub mod partitioner;
 pub mod processed_event;
 pub mod request_builder;
 pub mod retries;
+pub mod retry_budget;
 pub mod service;
 pub mod sink;
 pub mod snappy;
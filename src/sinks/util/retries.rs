@@ -41,6 +41,14 @@ pub trait RetryLogic: Clone + Send + Sync + 'static {
         // Treat the default as the request is successful
         RetryAction::Successful
     }
+
+    /// When a response is retried, this function allows implementors to override the backoff
+    /// delay with one requested by the downstream service, for example through the
+    /// `Retry-After` header of an HTTP response. The delay is capped at the maximum backoff
+    /// duration.
+    fn retry_after(&self, _response: &Self::Response) -> Option<Duration> {
+        None
+    }
 }
 
 /// The jitter mode to use for retry backoff behavior.
@@ -120,12 +128,17 @@ impl<L: RetryLogic> FibonacciRetryPolicy<L> {
         self.current_jitter_duration = Self::add_full_jitter(next_duration);
     }
 
-    fn build_retry(&mut self) -> RetryPolicyFuture {
+    fn build_retry(&mut self, requested_delay: Option<Duration>) -> RetryPolicyFuture {
         self.advance();
-        let delay = Box::pin(sleep(self.backoff()));
+        let delay = requested_delay.map_or_else(
+            || self.backoff(),
+            |delay| cmp::min(delay, self.max_duration),
+        );
 
-        debug!(message = "Retrying request.", delay_ms = %self.backoff().as_millis());
-        RetryPolicyFuture { delay }
+        debug!(message = "Retrying request.", delay_ms = %delay.as_millis());
+        RetryPolicyFuture {
+            delay: Box::pin(sleep(delay)),
+        }
     }
 }
 
@@ -151,7 +164,8 @@ where
                     }
 
                     warn!(message = "Retrying after response.", reason = %reason, internal_log_rate_limit = true);
-                    Some(self.build_retry())
+                    let requested_delay = self.logic.retry_after(response);
+                    Some(self.build_retry(requested_delay))
                 }
 
                 RetryAction::DontRetry(reason) => {
@@ -170,7 +184,7 @@ where
                 if let Some(expected) = error.downcast_ref::<L::Error>() {
                     if self.logic.is_retriable_error(expected) {
                         warn!(message = "Retrying after error.", error = %expected, internal_log_rate_limit = true);
-                        Some(self.build_retry())
+                        Some(self.build_retry(None))
                     } else {
                         error!(
                             message = "Non-retriable error; dropping the request.",
@@ -184,7 +198,7 @@ where
                         message = "Request timed out. If this happens often while the events are actually reaching their destination, try decreasing `batch.max_bytes` and/or using `compression` if applicable. Alternatively `request.timeout_secs` can be increased.",
                         internal_log_rate_limit = true
                     );
-                    Some(self.build_retry())
+                    Some(self.build_retry(None))
                 } else {
                     error!(
                         message = "Unexpected error type; dropping the request.",
@@ -0,0 +1,111 @@
+use std::sync::Mutex;
+
+use tokio::time::Instant;
+
+/// The number of seconds of refills the budget can bank.
+const MAX_BALANCE_SECS: f64 = 10.0;
+
+/// The smallest number of retries the budget can bank, so that deposits aren't capped too low when
+/// the refill rate is small.
+const MIN_MAX_TOKENS: f64 = 100.0;
+
+#[derive(Debug)]
+struct Inner {
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+/// Limits the number of retries across all the requests of a sink, to avoid retry storms against a
+/// failing endpoint.
+///
+/// This is a token bucket: each retry withdraws a token, and every request sent for the first time
+/// deposits `token_ratio` tokens, so that at most this fraction of the requests is retried in the
+/// long run. On top of that, the bucket is refilled with `min_per_second` tokens every second, to
+/// allow retries when few requests are sent. Up to `MAX_BALANCE_SECS` seconds of refills, and no
+/// fewer than `MIN_MAX_TOKENS` tokens, are kept.
+#[derive(Debug)]
+pub struct RetryBudget {
+    token_ratio: f64,
+    min_per_second: f64,
+    max_tokens: f64,
+    inner: Mutex<Inner>,
+}
+
+impl RetryBudget {
+    pub fn new(token_ratio: f64, min_per_second: u32) -> Self {
+        let min_per_second = f64::from(min_per_second);
+        Self {
+            token_ratio,
+            min_per_second,
+            max_tokens: (min_per_second * MAX_BALANCE_SECS).max(MIN_MAX_TOKENS),
+            inner: Mutex::new(Inner {
+                tokens: min_per_second,
+                refilled_at: Instant::now(),
+            }),
+        }
+    }
+
+    /// Records a request sent for the first time.
+    pub fn deposit(&self) {
+        let mut inner = self.inner.lock().expect("lock poisoned");
+        inner.tokens = (inner.tokens + self.token_ratio).min(self.max_tokens);
+    }
+
+    /// Returns whether a request may be retried, withdrawing a token if so.
+    pub fn withdraw(&self) -> bool {
+        let mut inner = self.inner.lock().expect("lock poisoned");
+        let now = Instant::now();
+        let elapsed = now.duration_since(inner.refilled_at).as_secs_f64();
+        inner.tokens = (inner.tokens + elapsed * self.min_per_second).min(self.max_tokens);
+        inner.refilled_at = now;
+
+        if inner.tokens >= 1.0 {
+            inner.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[tokio::test(start_paused = true)]
+    async fn deposits_fund_retries() {
+        let budget = RetryBudget::new(0.5, 0);
+        assert!(!budget.withdraw());
+
+        budget.deposit();
+        assert!(!budget.withdraw());
+        budget.deposit();
+        assert!(budget.withdraw());
+        assert!(!budget.withdraw());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn refills_over_time() {
+        let budget = RetryBudget::new(0.0, 2);
+        assert!(budget.withdraw());
+        assert!(budget.withdraw());
+        assert!(!budget.withdraw());
+
+        tokio::time::advance(Duration::from_millis(500)).await;
+        assert!(budget.withdraw());
+        assert!(!budget.withdraw());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn caps_balance() {
+        let budget = RetryBudget::new(1.0, 0);
+        for _ in 0..1_000 {
+            budget.deposit();
+        }
+
+        let retries = std::iter::from_fn(|| budget.withdraw().then_some(())).count();
+        assert_eq!(retries, MIN_MAX_TOKENS as usize);
+    }
+}
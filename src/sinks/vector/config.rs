@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use http::Uri;
 use hyper::client::HttpConnector;
 use hyper_openssl::HttpsConnector;
@@ -29,6 +31,22 @@ use crate::{
     tls::{MaybeTlsSettings, TlsEnableableConfig},
 };
 
+/// The transport to connect to the downstream Vector instance over.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum VectorTransport {
+    /// Connect over TCP, to `address`.
+    #[default]
+    Tcp,
+
+    /// Connect over a Unix domain socket, at `socket_path`.
+    ///
+    /// Connections are reestablished with an exponential backoff if the socket is unreachable.
+    /// This is only supported on Unix platforms.
+    Uds,
+}
+
 /// Configuration for the `vector` sink.
 #[configurable_component(sink("vector", "Relay observability data to a Vector instance."))]
 #[derive(Clone, Debug)]
@@ -40,15 +58,25 @@ pub struct VectorConfig {
     #[configurable(metadata(docs::hidden))]
     version: Option<super::VectorConfigVersion>,
 
+    #[configurable(derived)]
+    #[serde(default, skip_serializing_if = "crate::serde::is_default")]
+    pub transport: VectorTransport,
+
     /// The downstream Vector address to which to connect.
     ///
     /// Both IP address and hostname are accepted formats.
     ///
-    /// The address _must_ include a port.
+    /// The address _must_ include a port. This is required with the `tcp` transport.
     #[configurable(validation(format = "uri"))]
     #[configurable(metadata(docs::examples = "92.12.333.224:6000"))]
     #[configurable(metadata(docs::examples = "https://somehost:6000"))]
-    address: String,
+    address: Option<String>,
+
+    /// The path of the Unix domain socket of the downstream Vector instance.
+    ///
+    /// This is required with the `uds` transport, and should be an absolute path.
+    #[configurable(metadata(docs::examples = "/var/run/vector.sock"))]
+    pub socket_path: Option<PathBuf>,
 
     /// Whether or not to compress requests.
     ///
@@ -92,6 +120,16 @@ impl VectorConfig {
         let addr = addr.to_string();
         default_config(addr.as_str())
     }
+
+    /// Creates a `VectorConfig` connecting to the Unix domain socket at the given path.
+    pub fn from_socket_path(path: PathBuf) -> Self {
+        Self {
+            transport: VectorTransport::Uds,
+            address: None,
+            socket_path: Some(path),
+            ..default_config("")
+        }
+    }
 }
 
 impl GenerateConfig for VectorConfig {
@@ -103,7 +141,9 @@ impl GenerateConfig for VectorConfig {
 fn default_config(address: &str) -> VectorConfig {
     VectorConfig {
         version: None,
-        address: address.to_owned(),
+        transport: VectorTransport::Tcp,
+        address: Some(address.to_owned()),
+        socket_path: None,
         compression: false,
         headers: None,
         batch: BatchConfig::default(),
@@ -118,21 +158,52 @@ fn default_config(address: &str) -> VectorConfig {
 impl SinkConfig for VectorConfig {
     async fn build(&self, cx: SinkContext) -> crate::Result<(VectorSinkType, Healthcheck)> {
         let tls = MaybeTlsSettings::from_config(self.tls.as_ref(), false)?;
-        let uri = with_default_scheme(&self.address, tls.is_tls())?;
-
         let headers = self.headers.clone();
 
-        let client = new_client(&tls, cx.proxy())?;
-
-        let healthcheck_uri = cx
-            .healthcheck
-            .uri
-            .clone()
-            .map(|uri| uri.uri)
-            .unwrap_or_else(|| uri.clone());
-        let healthcheck_client = VectorService::new(client.clone(), healthcheck_uri, false, headers.clone());
+        let (service, healthcheck_client) = match self.transport {
+            VectorTransport::Tcp => {
+                let address = self
+                    .address
+                    .as_deref()
+                    .ok_or(VectorSinkError::MissingAddress)?;
+                let uri = with_default_scheme(address, tls.is_tls())?;
+
+                let client = new_client(&tls, cx.proxy())?;
+
+                let healthcheck_uri = cx
+                    .healthcheck
+                    .uri
+                    .clone()
+                    .map(|uri| uri.uri)
+                    .unwrap_or_else(|| uri.clone());
+                (
+                    VectorService::new(client.clone(), uri, self.compression, headers.clone()),
+                    VectorService::new(client, healthcheck_uri, false, headers),
+                )
+            }
+            VectorTransport::Uds => {
+                let path = self
+                    .socket_path
+                    .clone()
+                    .ok_or(VectorSinkError::MissingSocketPath)?;
+                if tls.is_tls() {
+                    return Err(VectorSinkError::UdsTls.into());
+                }
+                #[cfg(unix)]
+                {
+                    (
+                        VectorService::new_uds(path.clone(), self.compression, headers.clone()),
+                        VectorService::new_uds(path, false, headers),
+                    )
+                }
+                #[cfg(not(unix))]
+                {
+                    _ = path;
+                    return Err(VectorSinkError::UdsUnsupported.into());
+                }
+            }
+        };
         let healthcheck = healthcheck(healthcheck_client, cx.healthcheck);
-        let service = VectorService::new(client, uri, self.compression, headers);
         let request_settings = self.request.into_settings();
         let batch_settings = self.batch.into_batcher_settings()?;
 
@@ -6,7 +6,7 @@ mod config;
 mod service;
 mod sink;
 
-pub use config::VectorConfig;
+pub use config::{VectorConfig, VectorTransport};
 
 /// Marker type for the version two of the configuration for the `vector` sink.
 #[configurable_component]
@@ -28,6 +28,19 @@ pub enum VectorSinkError {
 
     #[snafu(display("URL has no host."))]
     NoHost,
+
+    #[snafu(display("`address` must be set with the `tcp` transport"))]
+    MissingAddress,
+
+    #[snafu(display("`socket_path` must be set with the `uds` transport"))]
+    MissingSocketPath,
+
+    #[snafu(display("TLS is not supported with the `uds` transport"))]
+    UdsTls,
+
+    #[cfg(not(unix))]
+    #[snafu(display("The `uds` transport is only supported on Unix platforms"))]
+    UdsUnsupported,
 }
 
 #[cfg(test)]
@@ -258,3 +271,104 @@ mod tests {
         buf.split_to(len + GRPC_HEADER_SIZE).freeze()
     }
 }
+
+#[cfg(all(
+    test,
+    unix,
+    feature = "sources-vector",
+    feature = "sources-file",
+    feature = "sinks-file"
+))]
+mod uds_tests {
+    use std::{fs, io::Write as _, path::Path};
+
+    use crate::{
+        config::{self, Config, Format},
+        test_util::{lines_from_file, random_lines, start_topology, temp_dir, wait_for},
+    };
+
+    fn load_config(toml: &str, data_dir: &Path) -> Config {
+        let mut config = config::load_from_str(toml, Format::Toml).unwrap();
+        config.global.data_dir = Some(data_dir.to_path_buf());
+        config
+    }
+
+    #[tokio::test]
+    async fn relays_events_over_uds() {
+        let dir = temp_dir();
+        fs::create_dir_all(&dir).unwrap();
+        let input_path = dir.join("input.log");
+        let output_path = dir.join("output.log");
+        let socket_path = dir.join("vector.sock");
+        fs::File::create(&input_path).unwrap();
+
+        // The upstream instance starts first, so its sink has to reconnect once the downstream
+        // instance listens on the socket.
+        let upstream = load_config(
+            &format!(
+                r#"
+                [sources.in]
+                type = "file"
+                include = ["{}"]
+
+                [sinks.out]
+                type = "vector"
+                inputs = ["in"]
+                transport = "uds"
+                socket_path = "{}"
+                "#,
+                input_path.display(),
+                socket_path.display(),
+            ),
+            &dir,
+        );
+        let (upstream, _) = start_topology(upstream, false).await;
+
+        let downstream = load_config(
+            &format!(
+                r#"
+                [sources.in]
+                type = "vector"
+                transport = "uds"
+                socket_path = "{}"
+
+                [sinks.out]
+                type = "file"
+                inputs = ["in"]
+                path = "{}"
+                encoding.codec = "text"
+                "#,
+                socket_path.display(),
+                output_path.display(),
+            ),
+            &dir,
+        );
+        let (downstream, _) = start_topology(downstream, false).await;
+
+        let lines = random_lines(100).take(10).collect::<Vec<_>>();
+        let mut input = fs::OpenOptions::new()
+            .append(true)
+            .open(&input_path)
+            .unwrap();
+        for line in &lines {
+            writeln!(input, "{}", line).unwrap();
+        }
+        input.flush().unwrap();
+
+        wait_for({
+            let output_path = output_path.clone();
+            let count = lines.len();
+            move || {
+                let received = fs::read_to_string(&output_path)
+                    .is_ok_and(|output| output.lines().count() == count);
+                async move { received }
+            }
+        })
+        .await;
+
+        upstream.stop().await;
+        downstream.stop().await;
+
+        assert_eq!(lines_from_file(&output_path), lines);
+    }
+}
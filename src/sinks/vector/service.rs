@@ -1,4 +1,10 @@
 use std::task::{Context, Poll};
+#[cfg(unix)]
+use std::{
+    path::PathBuf,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
 
 use futures::{future::BoxFuture, TryFutureExt};
 use http::Uri;
@@ -20,10 +26,15 @@ use crate::{
     sinks::util::uri,
     Error,
 };
+#[cfg(unix)]
+use crate::{
+    internal_events::{UnixSocketConnectionEstablished, UnixSocketOutgoingConnectionError},
+    sinks::util::retries::ExponentialBackoff,
+};
 
 #[derive(Clone, Debug)]
 pub struct VectorService {
-    pub client: proto_vector::Client<HyperSvc>,
+    pub client: proto_vector::Client<TransportSvc>,
     pub protocol: String,
     pub endpoint: String,
     pub headers: Option<IndexMap<String, String>>,
@@ -74,11 +85,45 @@ impl VectorService {
         headers: Option<IndexMap<String, String>>,
     ) -> Self {
         let (protocol, endpoint) = uri::protocol_endpoint(uri.clone());
-        let mut proto_client = proto_vector::Client::new(HyperSvc {
+        let transport = TransportSvc::Tcp(HyperSvc {
             uri,
             client: hyper_client,
         });
 
+        Self::with_transport(transport, protocol, endpoint, compression, headers)
+    }
+
+    /// Creates a `VectorService` sending requests over the Unix domain socket at `path`.
+    #[cfg(unix)]
+    pub fn new_uds(
+        path: PathBuf,
+        compression: bool,
+        headers: Option<IndexMap<String, String>>,
+    ) -> Self {
+        let endpoint = path.to_string_lossy().into_owned();
+        // The URI is only used for the `:authority` pseudo-header, the socket path is what we
+        // connect to.
+        let channel = tonic::transport::Endpoint::from_static("http://localhost")
+            .connect_with_connector_lazy(UdsConnector::new(path));
+
+        Self::with_transport(
+            TransportSvc::Uds(channel),
+            "unix".to_string(),
+            endpoint,
+            compression,
+            headers,
+        )
+    }
+
+    fn with_transport(
+        transport: TransportSvc,
+        protocol: String,
+        endpoint: String,
+        compression: bool,
+        headers: Option<IndexMap<String, String>>,
+    ) -> Self {
+        let mut proto_client = proto_vector::Client::new(transport);
+
         if compression {
             proto_client = proto_client.send_compressed(tonic::codec::CompressionEncoding::Gzip);
         }
@@ -87,7 +132,7 @@ impl VectorService {
             client: proto_client,
             protocol,
             endpoint,
-            headers
+            headers,
         }
     }
 }
@@ -152,6 +197,36 @@ impl Service<VectorRequest> for VectorService {
     }
 }
 
+/// The transport the gRPC requests are sent over.
+#[derive(Clone, Debug)]
+pub enum TransportSvc {
+    Tcp(HyperSvc),
+    #[cfg(unix)]
+    Uds(tonic::transport::Channel),
+}
+
+impl Service<hyper::Request<BoxBody>> for TransportSvc {
+    type Response = hyper::Response<hyper::Body>;
+    type Error = Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        match self {
+            Self::Tcp(svc) => svc.poll_ready(cx).map_err(Into::into),
+            #[cfg(unix)]
+            Self::Uds(channel) => channel.poll_ready(cx).map_err(Into::into),
+        }
+    }
+
+    fn call(&mut self, req: hyper::Request<BoxBody>) -> Self::Future {
+        match self {
+            Self::Tcp(svc) => Box::pin(svc.call(req).err_into::<Error>()),
+            #[cfg(unix)]
+            Self::Uds(channel) => Box::pin(channel.call(req).err_into::<Error>()),
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct HyperSvc {
     uri: Uri,
@@ -182,3 +257,72 @@ impl Service<hyper::Request<BoxBody>> for HyperSvc {
         Box::pin(self.client.request(req))
     }
 }
+
+/// Connects to a Unix domain socket, waiting with an exponential backoff before each attempt that
+/// follows a failed one, so that the channel doesn't hammer the socket while reconnecting.
+#[cfg(unix)]
+#[derive(Clone, Debug)]
+struct UdsConnector {
+    path: PathBuf,
+    /// The backoff between connection attempts, set while the socket is unreachable.
+    backoff: Arc<Mutex<Option<ExponentialBackoff>>>,
+}
+
+#[cfg(unix)]
+impl UdsConnector {
+    fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            backoff: Arc::default(),
+        }
+    }
+
+    const fn fresh_backoff() -> ExponentialBackoff {
+        ExponentialBackoff::from_millis(2)
+            .factor(250)
+            .max_delay(Duration::from_secs(60))
+    }
+
+    async fn connect(self) -> std::io::Result<tokio::net::UnixStream> {
+        let delay = self
+            .backoff
+            .lock()
+            .expect("backoff lock poisoned")
+            .as_mut()
+            .and_then(Iterator::next);
+        if let Some(delay) = delay {
+            tokio::time::sleep(delay).await;
+        }
+
+        let result = tokio::net::UnixStream::connect(&self.path).await;
+        let mut backoff = self.backoff.lock().expect("backoff lock poisoned");
+        match &result {
+            Ok(_) => {
+                emit!(UnixSocketConnectionEstablished { path: &self.path });
+                *backoff = None;
+            }
+            Err(error) => {
+                emit!(UnixSocketOutgoingConnectionError {
+                    error: std::io::Error::new(error.kind(), error.to_string()),
+                });
+                backoff.get_or_insert_with(Self::fresh_backoff);
+            }
+        }
+        result
+    }
+}
+
+#[cfg(unix)]
+impl Service<Uri> for UdsConnector {
+    type Response = tokio::net::UnixStream;
+    type Error = std::io::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, _uri: Uri) -> Self::Future {
+        Box::pin(self.clone().connect())
+    }
+}
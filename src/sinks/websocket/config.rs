@@ -1,4 +1,4 @@
-use std::num::NonZeroU64;
+use std::num::{NonZeroU64, NonZeroUsize};
 
 use snafu::ResultExt;
 use vector_lib::codecs::JsonSerializerConfig;
@@ -53,6 +53,15 @@ pub struct WebSocketSinkConfig {
     #[configurable(metadata(docs::type_unit = "seconds"))]
     pub ping_timeout: Option<NonZeroU64>,
 
+    /// The maximum number of frames waiting to be sent to the remote peer.
+    ///
+    /// When set, events keep being read while the frames can't be sent, such as while
+    /// reconnecting, and the oldest frames are dropped once this many are waiting. Otherwise, no
+    /// events are read until the pending frame is sent, which applies backpressure to the
+    /// upstream components.
+    #[configurable(metadata(docs::examples = 1000))]
+    pub buffer_size: Option<NonZeroUsize>,
+
     #[configurable(derived)]
     #[serde(
         default,
@@ -73,6 +82,7 @@ impl GenerateConfig for WebSocketSinkConfig {
             encoding: JsonSerializerConfig::default().into(),
             ping_interval: None,
             ping_timeout: None,
+            buffer_size: None,
             acknowledgements: Default::default(),
             auth: None,
         })
@@ -1,15 +1,21 @@
 use std::{
+    collections::VecDeque,
     fmt::Debug,
     io,
     net::SocketAddr,
-    num::NonZeroU64,
+    num::{NonZeroU64, NonZeroUsize},
     task::{Context, Poll},
     time::{Duration, Instant},
 };
 
 use async_trait::async_trait;
 use bytes::BytesMut;
-use futures::{pin_mut, sink::SinkExt, stream::BoxStream, Sink, Stream, StreamExt};
+use futures::{
+    future, pin_mut,
+    sink::SinkExt,
+    stream::{BoxStream, FusedStream},
+    Sink, Stream, StreamExt,
+};
 use snafu::{ResultExt, Snafu};
 use tokio::{net::TcpStream, time};
 use tokio_tungstenite::{
@@ -27,15 +33,17 @@ use tokio_util::codec::Encoder as _;
 use vector_lib::{
     emit,
     internal_event::{
-        ByteSize, BytesSent, CountByteSize, EventsSent, InternalEventHandle as _, Output, Protocol,
+        ByteSize, BytesSent, ComponentEventsDropped, CountByteSize, EventsSent,
+        InternalEventHandle as _, Output, Protocol, UNINTENTIONAL,
     },
+    json_size::JsonSize,
     EstimatedJsonEncodedSizeOf,
 };
 
 use crate::{
     codecs::{Encoder, Transformer},
     dns,
-    event::{Event, EventStatus, Finalizable},
+    event::{Event, EventFinalizers, EventStatus, Finalizable},
     http::Auth,
     internal_events::{
         ConnectionOpen, OpenGauge, WsConnectionError, WsConnectionEstablished,
@@ -188,12 +196,71 @@ impl PingInterval {
     }
 }
 
+/// A frame waiting to be sent, along with the finalizers of its event.
+struct Frame {
+    message: Message,
+    event_byte_size: JsonSize,
+    finalizers: EventFinalizers,
+}
+
+/// The frames waiting to be sent to the remote peer.
+///
+/// With a configured `buffer_size`, events are read even while the frames can't be sent, and the
+/// oldest frames are dropped once the buffer is full. Otherwise, a single frame is buffered and
+/// events aren't read until it's sent.
+struct FrameBuffer {
+    frames: VecDeque<Frame>,
+    capacity: usize,
+    drop_oldest: bool,
+}
+
+impl FrameBuffer {
+    fn new(buffer_size: Option<NonZeroUsize>) -> Self {
+        Self {
+            frames: VecDeque::new(),
+            capacity: buffer_size.map_or(1, NonZeroUsize::get),
+            drop_oldest: buffer_size.is_some(),
+        }
+    }
+
+    /// Whether events can be read into the buffer.
+    fn accepts_events(&self) -> bool {
+        self.drop_oldest || self.frames.len() < self.capacity
+    }
+
+    fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    fn front(&self) -> Option<&Frame> {
+        self.frames.front()
+    }
+
+    fn pop_front(&mut self) -> Option<Frame> {
+        self.frames.pop_front()
+    }
+
+    fn push_back(&mut self, frame: Frame) {
+        if self.frames.len() >= self.capacity {
+            if let Some(dropped) = self.frames.pop_front() {
+                dropped.finalizers.update_status(EventStatus::Rejected);
+                emit!(ComponentEventsDropped::<UNINTENTIONAL> {
+                    count: 1,
+                    reason: "WebSocket frame buffer is full.",
+                });
+            }
+        }
+        self.frames.push_back(frame);
+    }
+}
+
 pub struct WebSocketSink {
     transformer: Transformer,
     encoder: Encoder<()>,
     connector: WebSocketConnector,
     ping_interval: Option<NonZeroU64>,
     ping_timeout: Option<NonZeroU64>,
+    buffer_size: Option<NonZeroUsize>,
 }
 
 impl WebSocketSink {
@@ -208,17 +275,32 @@ impl WebSocketSink {
             connector,
             ping_interval: config.ping_interval,
             ping_timeout: config.ping_timeout,
+            buffer_size: config.buffer_size,
         })
     }
 
-    async fn create_sink_and_stream(
-        &self,
-    ) -> (
-        impl Sink<Message, Error = WsError>,
-        impl Stream<Item = Result<Message, WsError>>,
-    ) {
-        let ws_stream = self.connector.connect_backoff().await;
-        ws_stream.split()
+    /// Connects to the remote peer, buffering the events read in the meantime.
+    async fn connect<I>(
+        &mut self,
+        input: &mut I,
+        frames: &mut FrameBuffer,
+    ) -> WsStream<MaybeTlsStream<TcpStream>>
+    where
+        I: Stream<Item = Event> + FusedStream + Unpin,
+    {
+        let connector = self.connector.clone();
+        let connect = connector.connect_backoff();
+        pin_mut!(connect);
+
+        loop {
+            let read_input = !input.is_terminated() && frames.accepts_events();
+
+            tokio::select! {
+                ws_stream = &mut connect => return ws_stream,
+
+                Some(event) = input.next(), if read_input => self.buffer_event(event, frames),
+            }
+        }
     }
 
     fn check_received_pong_time(&self, last_pong: Instant) -> Result<(), WsError> {
@@ -245,14 +327,44 @@ impl WebSocketSink {
         }
     }
 
+    /// Encodes an event into a frame waiting to be sent.
+    fn buffer_event(&mut self, mut event: Event, frames: &mut FrameBuffer) {
+        let finalizers = event.take_finalizers();
+
+        self.transformer.transform(&mut event);
+
+        let event_byte_size = event.estimated_json_encoded_size_of();
+
+        let mut bytes = BytesMut::new();
+        if self.encoder.encode(event, &mut bytes).is_err() {
+            // Error is handled by `Encoder`.
+            finalizers.update_status(EventStatus::Errored);
+            return;
+        }
+
+        let message = if self.should_encode_as_binary() {
+            Message::binary(bytes)
+        } else {
+            Message::text(String::from_utf8_lossy(&bytes))
+        };
+        frames.push_back(Frame {
+            message,
+            event_byte_size,
+            finalizers,
+        });
+    }
+
+    /// Sends the buffered frames and the incoming events until the input ends, returning an error
+    /// if the connection has to be re-established.
     async fn handle_events<I, WS, O>(
         &mut self,
         input: &mut I,
+        frames: &mut FrameBuffer,
         ws_stream: &mut WS,
         ws_sink: &mut O,
     ) -> Result<(), ()>
     where
-        I: Stream<Item = Event> + Unpin,
+        I: Stream<Item = Event> + FusedStream + Unpin,
         WS: Stream<Item = Result<Message, WsError>> + Unpin,
         O: Sink<Message, Error = WsError> + Unpin,
     {
@@ -270,9 +382,10 @@ impl WebSocketSink {
 
         let bytes_sent = register!(BytesSent::from(Protocol("websocket".into())));
         let events_sent = register!(EventsSent::from(Output(None)));
-        let encode_as_binary = self.should_encode_as_binary();
 
-        loop {
+        while !(input.is_terminated() && frames.is_empty()) {
+            let read_input = !input.is_terminated() && frames.accepts_events();
+
             let result = tokio::select! {
                 _ = ping_interval.tick() => {
                     match self.check_received_pong_time(last_pong) {
@@ -293,46 +406,25 @@ impl WebSocketSink {
                     }
                 },
 
-                event = input.next() => {
-                    let mut event = if let Some(event) = event {
-                        event
-                    } else {
-                        break;
-                    };
-
-                    let finalizers = event.take_finalizers();
-
-                    self.transformer.transform(&mut event);
-
-                    let event_byte_size = event.estimated_json_encoded_size_of();
-
-                    let mut bytes = BytesMut::new();
-                    let res = match self.encoder.encode(event, &mut bytes) {
-                        Ok(()) => {
-                            finalizers.update_status(EventStatus::Delivered);
-
-                            let message = if encode_as_binary {
-                                Message::binary(bytes)
-                            }
-                            else {
-                                Message::text(String::from_utf8_lossy(&bytes))
-                            };
-                            let message_len = message.len();
-
-                            ws_sink.send(message).await.map(|_| {
-                                events_sent.emit(CountByteSize(1, event_byte_size));
-                                bytes_sent.emit(ByteSize(message_len));
-                            })
-                        },
-                        Err(_) => {
-                            // Error is handled by `Encoder`.
-                            finalizers.update_status(EventStatus::Errored);
-                            Ok(())
-                        }
-                    };
+                Some(event) = input.next(), if read_input => {
+                    self.buffer_event(event, frames);
+                    Ok(())
+                },
 
-                    res
+                _ = future::ready(()), if !frames.is_empty() => {
+                    // The frame is only removed once sent, so that it's sent again after
+                    // reconnecting otherwise.
+                    let message = frames.front().expect("frames are buffered").message.clone();
+                    let message_len = message.len();
+
+                    ws_sink.send(message).await.map(|_| {
+                        let frame = frames.pop_front().expect("frames are buffered");
+                        frame.finalizers.update_status(EventStatus::Delivered);
+                        events_sent.emit(CountByteSize(1, frame.event_byte_size));
+                        bytes_sent.emit(ByteSize(message_len));
+                    })
                 },
+
                 else => break,
             };
 
@@ -353,22 +445,34 @@ impl WebSocketSink {
 #[async_trait]
 impl StreamSink<Event> for WebSocketSink {
     async fn run(mut self: Box<Self>, input: BoxStream<'_, Event>) -> Result<(), ()> {
-        let input = input.fuse().peekable();
-        pin_mut!(input);
+        let mut input = input.fuse();
+        let mut frames = FrameBuffer::new(self.buffer_size);
 
-        while input.as_mut().peek().await.is_some() {
-            let (ws_sink, ws_stream) = self.create_sink_and_stream().await;
+        loop {
+            // Only connect once there are frames to send.
+            if frames.is_empty() {
+                match input.next().await {
+                    Some(event) => {
+                        self.buffer_event(event, &mut frames);
+                        continue;
+                    }
+                    None => break,
+                }
+            }
+
+            let (ws_sink, ws_stream) = self.connect(&mut input, &mut frames).await.split();
             pin_mut!(ws_sink);
             pin_mut!(ws_stream);
 
             let _open_token = OpenGauge::new().open(|count| emit!(ConnectionOpen { count }));
 
             if self
-                .handle_events(&mut input, &mut ws_stream, &mut ws_sink)
+                .handle_events(&mut input, &mut frames, &mut ws_stream, &mut ws_sink)
                 .await
                 .is_ok()
             {
                 _ = ws_sink.close().await;
+                break;
             }
         }
 
@@ -402,6 +506,7 @@ mod tests {
     use super::*;
     use crate::{
         config::{SinkConfig, SinkContext},
+        event::{BatchNotifier, BatchStatus, EventFinalizer},
         test_util::{
             components::{run_and_assert_sink_compliance, SINK_TAGS},
             next_addr, random_lines_with_stream, trace_init, CountReceiver,
@@ -420,6 +525,7 @@ mod tests {
             encoding: JsonSerializerConfig::default().into(),
             ping_interval: None,
             ping_timeout: None,
+            buffer_size: None,
             acknowledgements: Default::default(),
             auth: None,
         };
@@ -443,6 +549,7 @@ mod tests {
             encoding: JsonSerializerConfig::default().into(),
             ping_interval: None,
             ping_timeout: None,
+            buffer_size: None,
             acknowledgements: Default::default(),
             auth,
         };
@@ -473,6 +580,7 @@ mod tests {
             encoding: JsonSerializerConfig::default().into(),
             ping_timeout: None,
             ping_interval: None,
+            buffer_size: None,
             acknowledgements: Default::default(),
             auth: None,
         };
@@ -491,6 +599,7 @@ mod tests {
             encoding: JsonSerializerConfig::default().into(),
             ping_interval: None,
             ping_timeout: None,
+            buffer_size: None,
             acknowledgements: Default::default(),
             auth: None,
         };
@@ -518,6 +627,43 @@ mod tests {
             .is_ok());
     }
 
+    fn frame(text: &str) -> Frame {
+        Frame {
+            message: Message::text(text),
+            event_byte_size: JsonSize::zero(),
+            finalizers: EventFinalizers::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn frame_buffer_drops_oldest_frames() {
+        let (batch, receiver) = BatchNotifier::new_with_receiver();
+        let mut frames = FrameBuffer::new(NonZeroUsize::new(2));
+
+        frames.push_back(Frame {
+            finalizers: EventFinalizers::new(EventFinalizer::new(batch)),
+            ..frame("first")
+        });
+        frames.push_back(frame("second"));
+        assert!(frames.accepts_events());
+        frames.push_back(frame("third"));
+
+        assert_eq!(receiver.await, BatchStatus::Rejected);
+        let messages = std::iter::from_fn(|| frames.pop_front())
+            .map(|frame| frame.message.into_text().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(messages, ["second", "third"]);
+    }
+
+    #[test]
+    fn frame_buffer_without_size_holds_one_frame() {
+        let mut frames = FrameBuffer::new(None);
+
+        assert!(frames.accepts_events());
+        frames.push_back(frame("first"));
+        assert!(!frames.accepts_events());
+    }
+
     async fn send_events_and_assert(
         addr: SocketAddr,
         config: WebSocketSinkConfig,
@@ -290,6 +290,25 @@ impl SourceSender {
         self.default_output_mut().send_event_stream(events).await
     }
 
+    /// Send a stream of events to a named output.
+    ///
+    /// This internally handles emitting [EventsSent] and [ComponentEventsDropped] events.
+    pub async fn send_event_stream_named<S, E>(
+        &mut self,
+        name: &str,
+        events: S,
+    ) -> Result<(), ClosedError>
+    where
+        S: Stream<Item = E> + Unpin,
+        E: Into<Event> + ByteSizeOf,
+    {
+        self.named_outputs
+            .get_mut(name)
+            .expect("unknown output")
+            .send_event_stream(events)
+            .await
+    }
+
     /// Send a batch of events to the default output.
     ///
     /// This internally handles emitting [EventsSent] and [ComponentEventsDropped] events.
@@ -707,10 +707,11 @@ mod integration_tests {
             None,
             None,
             Some(MultilineConfig {
-                start_pattern: "abc".to_owned(),
-                mode: line_agg::Mode::HaltWith,
-                condition_pattern: "geh".to_owned(),
+                start_pattern: Some("abc".to_owned()),
+                mode: Some(line_agg::Mode::HaltWith),
+                condition_pattern: Some("geh".to_owned()),
                 timeout_ms: Duration::from_millis(1000),
+                flush_condition_vrl: None,
             }),
             logs.join("\n").into_bytes(),
             vec!["abc\ndef\ngeh".to_owned()],
@@ -862,10 +862,11 @@ mod integration_tests {
                 include_containers: Some(vec![name.to_owned()]),
                 include_images: Some(vec!["busybox".to_owned()]),
                 multiline: Some(MultilineConfig {
-                    start_pattern: "^[^\\s]".to_owned(),
-                    condition_pattern: "^[\\s]+at".to_owned(),
-                    mode: line_agg::Mode::ContinueThrough,
+                    start_pattern: Some("^[^\\s]".to_owned()),
+                    condition_pattern: Some("^[\\s]+at".to_owned()),
+                    mode: Some(line_agg::Mode::ContinueThrough),
                     timeout_ms: Duration::from_millis(10),
+                    flush_condition_vrl: None,
                 }),
                 log_namespace: Some(true),
                 ..DockerLogsConfig::default()
@@ -933,10 +934,11 @@ mod integration_tests {
                 include_containers: Some(vec![name.to_owned()]),
                 include_images: Some(vec!["busybox".to_owned()]),
                 multiline: Some(MultilineConfig {
-                    start_pattern: "^[^\\s]".to_owned(),
-                    condition_pattern: "^[\\s]+at".to_owned(),
-                    mode: line_agg::Mode::ContinueThrough,
+                    start_pattern: Some("^[^\\s]".to_owned()),
+                    condition_pattern: Some("^[\\s]+at".to_owned()),
+                    mode: Some(line_agg::Mode::ContinueThrough),
                     timeout_ms: Duration::from_millis(10),
+                    flush_condition_vrl: None,
                 }),
                 ..DockerLogsConfig::default()
             };
@@ -1956,10 +1956,11 @@ mod tests {
         let config = file::FileConfig {
             include: vec![dir.path().join("*")],
             multiline: Some(MultilineConfig {
-                start_pattern: "INFO".to_owned(),
-                condition_pattern: "INFO".to_owned(),
-                mode: line_agg::Mode::HaltBefore,
+                start_pattern: Some("INFO".to_owned()),
+                condition_pattern: Some("INFO".to_owned()),
+                mode: Some(line_agg::Mode::HaltBefore),
                 timeout_ms: Duration::from_millis(25), // less than 50 in sleep()
+                flush_condition_vrl: None,
             }),
             ..test_default_file_config(&dir)
         };
@@ -2009,6 +2010,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_multi_line_flush_condition_excludes_patterns() {
+        let mut config = MultilineConfig {
+            start_pattern: None,
+            condition_pattern: None,
+            mode: None,
+            timeout_ms: Duration::from_millis(25),
+            flush_condition_vrl: Some("%accumulated_lines >= 2".to_owned()),
+        };
+        assert!(line_agg::Config::try_from(&config).is_ok());
+
+        config.start_pattern = Some("INFO".to_owned());
+        assert!(line_agg::Config::try_from(&config).is_err());
+
+        config.flush_condition_vrl = None;
+        assert!(line_agg::Config::try_from(&config).is_err());
+    }
+
     #[tokio::test]
     async fn test_multi_line_checkpointing() {
         let dir = tempdir().unwrap();
@@ -2016,10 +2035,11 @@ mod tests {
             include: vec![dir.path().join("*")],
             offset_key: Some(OptionalValuePath::from(owned_value_path!("offset"))),
             multiline: Some(MultilineConfig {
-                start_pattern: "INFO".to_owned(),
-                condition_pattern: "INFO".to_owned(),
-                mode: line_agg::Mode::HaltBefore,
+                start_pattern: Some("INFO".to_owned()),
+                condition_pattern: Some("INFO".to_owned()),
+                mode: Some(line_agg::Mode::HaltBefore),
                 timeout_ms: Duration::from_millis(25), // less than 50 in sleep()
+                flush_condition_vrl: None,
             }),
             ..test_default_file_config(&dir)
         };
@@ -0,0 +1,411 @@
+//! The `grpc` source. See [GrpcConfig].
+use std::{net::SocketAddr, path::PathBuf};
+
+use futures::TryFutureExt;
+use prost_reflect::{DescriptorPool, MethodDescriptor};
+use snafu::{OptionExt, ResultExt, Snafu};
+use tonic::metadata::AsciiMetadataKey;
+use vector_lib::configurable::configurable_component;
+use vector_lib::{config::LogNamespace, schema::Definition, sensitive_string::SensitiveString};
+use vrl::value::{kind::Collection, Kind};
+
+use crate::{
+    config::{
+        DataType, GenerateConfig, Resource, SourceAcknowledgementsConfig, SourceConfig,
+        SourceContext, SourceOutput,
+    },
+    serde::bool_or_struct,
+    sources::{util::grpc::run_dynamic_grpc_server, Source},
+    tls::{MaybeTlsSettings, TlsEnableableConfig},
+};
+
+mod service;
+
+use self::service::{Auth, Service};
+
+#[derive(Debug, Snafu)]
+enum BuildError {
+    #[snafu(display("Could not read descriptor file {}: {}", path.display(), source))]
+    ReadDescriptorFile {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[snafu(display("Could not decode descriptor file {}: {}", path.display(), source))]
+    DecodeDescriptorFile {
+        path: PathBuf,
+        source: prost_reflect::DescriptorError,
+    },
+    #[snafu(display(
+        "`service_method` must be formatted as `<package>.<service>/<method>`, got {:?}",
+        service_method
+    ))]
+    InvalidServiceMethod { service_method: String },
+    #[snafu(display("Service {:?} is not defined in the descriptor file", service))]
+    UnknownService { service: String },
+    #[snafu(display("Service {:?} has no method {:?}", service, method))]
+    UnknownMethod { service: String, method: String },
+    #[snafu(display(
+        "Method {:?} is client-streaming, only unary and server-streaming methods are supported",
+        service_method
+    ))]
+    ClientStreamingMethod { service_method: String },
+    #[snafu(display("Invalid `auth.metadata_key` {:?}: {}", metadata_key, source))]
+    InvalidMetadataKey {
+        metadata_key: String,
+        source: tonic::metadata::errors::InvalidMetadataKey,
+    },
+}
+
+/// Configuration for the `grpc` source.
+#[configurable_component(source("grpc", "Collect logs sent to a user-defined gRPC method."))]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct GrpcConfig {
+    /// The socket address to listen for connections on.
+    ///
+    /// It _must_ include a port.
+    #[configurable(metadata(docs::examples = "0.0.0.0:50051"))]
+    pub address: SocketAddr,
+
+    /// The path of the protobuf descriptor set defining the served method.
+    ///
+    /// This file is the output of `protoc --include_imports -o <desc output path> <proto>`.
+    #[configurable(metadata(docs::examples = "/etc/vector/logging.desc"))]
+    pub proto_descriptor_file: PathBuf,
+
+    /// The fully qualified name of the served method, formatted as
+    /// `<package>.<service>/<method>`.
+    ///
+    /// Only unary and server-streaming methods are supported. The fields of each request message
+    /// become the fields of a log event. Unary calls are replied to with a response message
+    /// holding only default values, and the response stream of server-streaming calls is closed
+    /// without sending messages, once the event is accepted.
+    #[configurable(metadata(docs::examples = "acme.logging.v1.LogService/StreamLogs"))]
+    pub service_method: String,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    tls: Option<TlsEnableableConfig>,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    auth: Option<GrpcAuthConfig>,
+
+    #[configurable(derived)]
+    #[serde(default, deserialize_with = "bool_or_struct")]
+    acknowledgements: SourceAcknowledgementsConfig,
+
+    /// The namespace to use for logs. This overrides the global setting.
+    #[serde(default)]
+    #[configurable(metadata(docs::hidden))]
+    pub log_namespace: Option<bool>,
+}
+
+/// Authentication of the calls through their metadata.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct GrpcAuthConfig {
+    /// The metadata key holding the token of the calls.
+    #[serde(default = "default_metadata_key")]
+    #[configurable(metadata(docs::examples = "x-api-key"))]
+    pub metadata_key: String,
+
+    /// The token the calls must hold.
+    ///
+    /// Calls without this exact token are rejected with the `UNAUTHENTICATED` status.
+    #[configurable(metadata(docs::examples = "Bearer ${GRPC_TOKEN}"))]
+    pub token: SensitiveString,
+}
+
+fn default_metadata_key() -> String {
+    "authorization".to_string()
+}
+
+impl GrpcAuthConfig {
+    fn build(&self) -> Result<Auth, BuildError> {
+        let metadata_key =
+            self.metadata_key
+                .parse::<AsciiMetadataKey>()
+                .context(InvalidMetadataKeySnafu {
+                    metadata_key: &self.metadata_key,
+                })?;
+
+        Ok(Auth {
+            metadata_key,
+            token: self.token.clone(),
+        })
+    }
+}
+
+impl GrpcConfig {
+    /// Finds the served method in the descriptor file.
+    fn method(&self) -> Result<MethodDescriptor, BuildError> {
+        let path = &self.proto_descriptor_file;
+        let descriptors = std::fs::read(path).context(ReadDescriptorFileSnafu { path })?;
+        let pool = DescriptorPool::decode(descriptors.as_slice())
+            .context(DecodeDescriptorFileSnafu { path })?;
+
+        let (service, method) = self
+            .service_method
+            .split_once('/')
+            .filter(|(service, method)| !service.is_empty() && !method.is_empty())
+            .context(InvalidServiceMethodSnafu {
+                service_method: &self.service_method,
+            })?;
+        let method = pool
+            .get_service_by_name(service)
+            .context(UnknownServiceSnafu { service })?
+            .methods()
+            .find(|descriptor| descriptor.name() == method)
+            .context(UnknownMethodSnafu { service, method })?;
+
+        if method.is_client_streaming() {
+            return ClientStreamingMethodSnafu {
+                service_method: &self.service_method,
+            }
+            .fail();
+        }
+
+        Ok(method)
+    }
+}
+
+impl GenerateConfig for GrpcConfig {
+    fn generate_config() -> toml::Value {
+        toml::from_str(
+            r#"address = "0.0.0.0:50051"
+            proto_descriptor_file = "/etc/vector/logging.desc"
+            service_method = "acme.logging.v1.LogService/PushLogs""#,
+        )
+        .unwrap()
+    }
+}
+
+#[async_trait::async_trait]
+#[typetag::serde(name = "grpc")]
+impl SourceConfig for GrpcConfig {
+    async fn build(&self, cx: SourceContext) -> crate::Result<Source> {
+        let method = self.method()?;
+        let auth = self.auth.as_ref().map(GrpcAuthConfig::build).transpose()?;
+        let tls_settings = MaybeTlsSettings::from_config(self.tls.as_ref(), true)?;
+        let acknowledgements = cx.do_acknowledgements(self.acknowledgements);
+        let log_namespace = cx.log_namespace(self.log_namespace);
+
+        let service = Service::new(method, auth, cx.out, acknowledgements, log_namespace);
+        let source = run_dynamic_grpc_server(self.address, tls_settings, service, cx.shutdown)
+            .map_err(|error| {
+                error!(message = "Source future failed.", %error);
+            });
+
+        Ok(Box::pin(source))
+    }
+
+    fn outputs(&self, global_log_namespace: LogNamespace) -> Vec<SourceOutput> {
+        let log_namespace = global_log_namespace.merge(self.log_namespace);
+
+        let schema_definition =
+            Definition::new_with_default_metadata(Kind::object(Collection::any()), [log_namespace])
+                .with_standard_vector_source_metadata();
+
+        vec![SourceOutput::new_maybe_logs(
+            DataType::Log,
+            schema_definition,
+        )]
+    }
+
+    fn resources(&self) -> Vec<Resource> {
+        vec![Resource::tcp(self.address)]
+    }
+
+    fn can_acknowledge(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::uri::PathAndQuery;
+    use prost_reflect::{DynamicMessage, Value as ProtoValue};
+    use tonic::{transport::Endpoint, Code, Request};
+    use vector_lib::config::log_schema;
+
+    use super::{service::DynamicCodec, *};
+    use crate::{
+        event::{Event, Value},
+        test_util::{
+            self,
+            components::{assert_source_compliance, SOURCE_TAGS},
+        },
+        SourceSender,
+    };
+
+    fn config(address: SocketAddr, service_method: &str) -> GrpcConfig {
+        toml::from_str(&format!(
+            r#"address = "{address}"
+            proto_descriptor_file = "tests/data/protobuf/grpc_source.desc"
+            service_method = "{service_method}"
+            auth.token = "Bearer secret""#
+        ))
+        .unwrap()
+    }
+
+    fn log_record(method: &MethodDescriptor) -> DynamicMessage {
+        let mut record = DynamicMessage::new(method.input());
+        record.set_field_by_name("message", ProtoValue::String("user logged in".into()));
+        record.set_field_by_name("count", ProtoValue::I64(3));
+        record.set_field_by_name(
+            "tags",
+            ProtoValue::List(vec![ProtoValue::String("auth".into())]),
+        );
+        record
+    }
+
+    /// Starts a source serving the given method, and returns a client calling it.
+    async fn start(
+        service_method: &str,
+    ) -> (
+        tonic::client::Grpc<tonic::transport::Channel>,
+        MethodDescriptor,
+        impl futures::Stream<Item = Event>,
+    ) {
+        let address = test_util::next_addr();
+        let config = config(address, service_method);
+        let method = config.method().unwrap();
+
+        let (tx, rx) = SourceSender::new_test();
+        let source = config
+            .build(SourceContext::new_test(tx, None))
+            .await
+            .unwrap();
+        tokio::spawn(source);
+        test_util::wait_for_tcp(address).await;
+
+        let channel = Endpoint::from_shared(format!("http://{address}"))
+            .unwrap()
+            .connect()
+            .await
+            .unwrap();
+        (tonic::client::Grpc::new(channel), method, rx)
+    }
+
+    fn request(method: &MethodDescriptor, token: &str) -> Request<DynamicMessage> {
+        let mut request = Request::new(log_record(method));
+        request
+            .metadata_mut()
+            .insert("authorization", token.parse().unwrap());
+        request
+    }
+
+    fn path(method: &MethodDescriptor) -> PathAndQuery {
+        format!("/{}/{}", method.parent_service().full_name(), method.name())
+            .parse()
+            .unwrap()
+    }
+
+    #[test]
+    fn generate_config() {
+        test_util::test_generate_config::<GrpcConfig>();
+    }
+
+    #[test]
+    fn rejects_unsupported_methods() {
+        let address = test_util::next_addr();
+
+        let error = config(address, "test_grpc.LogService/UploadLogs")
+            .method()
+            .unwrap_err();
+        assert!(
+            matches!(error, BuildError::ClientStreamingMethod { .. }),
+            "{error}"
+        );
+
+        let error = config(address, "test_grpc.LogService/DeleteLogs")
+            .method()
+            .unwrap_err();
+        assert!(matches!(error, BuildError::UnknownMethod { .. }), "{error}");
+
+        let error = config(address, "test_grpc.LogService")
+            .method()
+            .unwrap_err();
+        assert!(
+            matches!(error, BuildError::InvalidServiceMethod { .. }),
+            "{error}"
+        );
+    }
+
+    #[tokio::test]
+    async fn receives_unary_calls() {
+        assert_source_compliance(&SOURCE_TAGS, async {
+            let (mut client, method, rx) = start("test_grpc.LogService/PushLog").await;
+
+            client.ready().await.unwrap();
+            let response = client
+                .unary(
+                    request(&method, "Bearer secret"),
+                    path(&method),
+                    DynamicCodec {
+                        decoded: method.output(),
+                    },
+                )
+                .await
+                .unwrap();
+            assert_eq!(response.get_ref().descriptor(), method.output());
+
+            let events = test_util::collect_ready(rx).await;
+            assert_eq!(events.len(), 1);
+            let log = events[0].as_log();
+            assert_eq!(log["message"], Value::from("user logged in"));
+            assert_eq!(log["count"], Value::from(3));
+            assert_eq!(log["tags"], Value::from(vec![Value::from("auth")]));
+            assert_eq!(
+                log.get(log_schema().source_type_key_target_path().unwrap()),
+                Some(&Value::from("grpc"))
+            );
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn receives_server_streaming_calls() {
+        let (mut client, method, rx) = start("test_grpc.LogService/StreamLogs").await;
+
+        client.ready().await.unwrap();
+        let mut responses = client
+            .server_streaming(
+                request(&method, "Bearer secret"),
+                path(&method),
+                DynamicCodec {
+                    decoded: method.output(),
+                },
+            )
+            .await
+            .unwrap()
+            .into_inner();
+        assert!(responses.message().await.unwrap().is_none());
+
+        let events = test_util::collect_ready(rx).await;
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].as_log()["message"], Value::from("user logged in"));
+    }
+
+    #[tokio::test]
+    async fn rejects_unauthenticated_calls() {
+        let (mut client, method, rx) = start("test_grpc.LogService/PushLog").await;
+
+        client.ready().await.unwrap();
+        let status = client
+            .unary(
+                request(&method, "Bearer wrong"),
+                path(&method),
+                DynamicCodec {
+                    decoded: method.output(),
+                },
+            )
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), Code::Unauthenticated);
+
+        assert!(test_util::collect_ready(rx).await.is_empty());
+    }
+}
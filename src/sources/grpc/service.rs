@@ -0,0 +1,253 @@
+use std::{
+    convert::Infallible,
+    task::{Context, Poll},
+};
+
+use chrono::Utc;
+use futures::{future::BoxFuture, stream, TryFutureExt};
+use hyper::Body;
+use prost::Message as _;
+use prost_reflect::{DynamicMessage, MessageDescriptor, MethodDescriptor};
+use tonic::{
+    body::BoxBody,
+    codec::{Codec, CompressionEncoding, DecodeBuf, Decoder, EncodeBuf, Encoder},
+    metadata::{AsciiMetadataKey, MetadataMap},
+    server::{Grpc, ServerStreamingService, UnaryService},
+    Request, Response, Status,
+};
+use vector_lib::{
+    config::LogNamespace,
+    event::{BatchNotifier, BatchStatus, BatchStatusReceiver, Event, LogEvent},
+    internal_event::{CountByteSize, InternalEventHandle as _, Registered},
+    sensitive_string::SensitiveString,
+    EstimatedJsonEncodedSizeOf,
+};
+
+use super::GrpcConfig;
+use crate::{
+    config::SourceConfig,
+    internal_events::{EventsReceived, StreamClosedError},
+    SourceSender,
+};
+
+/// Encodes and decodes the messages of a method only known at runtime.
+#[derive(Clone, Debug)]
+pub(super) struct DynamicCodec {
+    /// The type of the decoded messages.
+    pub(super) decoded: MessageDescriptor,
+}
+
+impl Codec for DynamicCodec {
+    type Encode = DynamicMessage;
+    type Decode = DynamicMessage;
+    type Encoder = DynamicEncoder;
+    type Decoder = DynamicDecoder;
+
+    fn encoder(&mut self) -> Self::Encoder {
+        DynamicEncoder
+    }
+
+    fn decoder(&mut self) -> Self::Decoder {
+        DynamicDecoder(self.decoded.clone())
+    }
+}
+
+pub(super) struct DynamicEncoder;
+
+impl Encoder for DynamicEncoder {
+    type Item = DynamicMessage;
+    type Error = Status;
+
+    fn encode(&mut self, item: Self::Item, dst: &mut EncodeBuf<'_>) -> Result<(), Self::Error> {
+        item.encode(dst)
+            .map_err(|error| Status::internal(format!("Could not encode message: {error}")))
+    }
+}
+
+pub(super) struct DynamicDecoder(MessageDescriptor);
+
+impl Decoder for DynamicDecoder {
+    type Item = DynamicMessage;
+    type Error = Status;
+
+    fn decode(&mut self, src: &mut DecodeBuf<'_>) -> Result<Option<Self::Item>, Self::Error> {
+        DynamicMessage::decode(self.0.clone(), src)
+            .map(Some)
+            .map_err(|error| Status::invalid_argument(format!("Could not decode message: {error}")))
+    }
+}
+
+/// The metadata requests must hold to be accepted.
+#[derive(Clone, Debug)]
+pub(super) struct Auth {
+    pub(super) metadata_key: AsciiMetadataKey,
+    pub(super) token: SensitiveString,
+}
+
+impl Auth {
+    fn authenticate(&self, metadata: &MetadataMap) -> Result<(), Status> {
+        match metadata.get(&self.metadata_key) {
+            Some(value) if value.as_bytes() == self.token.inner().as_bytes() => Ok(()),
+            Some(_) => Err(Status::unauthenticated("Invalid token")),
+            None => Err(Status::unauthenticated(format!(
+                "Missing {} metadata",
+                self.metadata_key.as_str()
+            ))),
+        }
+    }
+}
+
+/// Serves the configured method, and rejects calls to any other method.
+#[derive(Clone)]
+pub(super) struct Service {
+    path: String,
+    method: MethodDescriptor,
+    handler: Handler,
+}
+
+impl Service {
+    pub(super) fn new(
+        method: MethodDescriptor,
+        auth: Option<Auth>,
+        pipeline: SourceSender,
+        acknowledgements: bool,
+        log_namespace: LogNamespace,
+    ) -> Self {
+        Self {
+            path: format!("/{}/{}", method.parent_service().full_name(), method.name()),
+            handler: Handler {
+                response: method.output(),
+                auth,
+                pipeline,
+                acknowledgements,
+                log_namespace,
+                events_received: register!(EventsReceived),
+            },
+            method,
+        }
+    }
+}
+
+impl tower::Service<http::Request<Body>> for Service {
+    type Response = http::Response<BoxBody>;
+    type Error = Infallible;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: http::Request<Body>) -> Self::Future {
+        if request.uri().path() != self.path {
+            let status = Status::unimplemented(format!("Unknown method {}", request.uri().path()));
+            return Box::pin(async move { Ok(status.to_http()) });
+        }
+
+        let mut grpc = Grpc::new(DynamicCodec {
+            decoded: self.method.input(),
+        })
+        .accept_compressed(CompressionEncoding::Gzip)
+        .max_decoding_message_size(usize::MAX);
+        let handler = self.handler.clone();
+        let server_streaming = self.method.is_server_streaming();
+
+        Box::pin(async move {
+            let response = if server_streaming {
+                grpc.server_streaming(handler, request).await
+            } else {
+                grpc.unary(handler, request).await
+            };
+            Ok(response)
+        })
+    }
+}
+
+/// Turns the request messages into log events.
+#[derive(Clone)]
+struct Handler {
+    response: MessageDescriptor,
+    auth: Option<Auth>,
+    pipeline: SourceSender,
+    acknowledgements: bool,
+    log_namespace: LogNamespace,
+    events_received: Registered<EventsReceived>,
+}
+
+impl Handler {
+    async fn handle_request(&self, request: Request<DynamicMessage>) -> Result<(), Status> {
+        if let Some(auth) = &self.auth {
+            auth.authenticate(request.metadata())?;
+        }
+
+        let message = prost_reflect::Value::Message(request.into_inner());
+        let value = vrl::protobuf::proto_to_value(&message, None).map_err(|error| {
+            Status::invalid_argument(format!("Could not convert message: {error}"))
+        })?;
+        let mut log = LogEvent::from(value);
+        self.log_namespace.insert_standard_vector_source_metadata(
+            &mut log,
+            GrpcConfig::NAME,
+            Utc::now(),
+        );
+
+        let mut events = vec![Event::Log(log)];
+        let count = events.len();
+        let byte_size = events.estimated_json_encoded_size_of();
+        self.events_received.emit(CountByteSize(count, byte_size));
+
+        let receiver = BatchNotifier::maybe_apply_to(self.acknowledgements, &mut events);
+
+        self.pipeline
+            .clone()
+            .send_batch(events)
+            .map_err(|error| {
+                let message = error.to_string();
+                emit!(StreamClosedError { count });
+                Status::unavailable(message)
+            })
+            .and_then(|_| handle_batch_status(receiver))
+            .await
+    }
+}
+
+/// Replies to unary calls with a response message holding only default values.
+impl UnaryService<DynamicMessage> for Handler {
+    type Response = DynamicMessage;
+    type Future = BoxFuture<'static, Result<Response<Self::Response>, Status>>;
+
+    fn call(&mut self, request: Request<DynamicMessage>) -> Self::Future {
+        let handler = self.clone();
+        Box::pin(async move {
+            handler.handle_request(request).await?;
+            Ok(Response::new(DynamicMessage::new(handler.response)))
+        })
+    }
+}
+
+/// Replies to server-streaming calls by closing the response stream without sending messages.
+impl ServerStreamingService<DynamicMessage> for Handler {
+    type Response = DynamicMessage;
+    type ResponseStream = stream::Empty<Result<Self::Response, Status>>;
+    type Future = BoxFuture<'static, Result<Response<Self::ResponseStream>, Status>>;
+
+    fn call(&mut self, request: Request<DynamicMessage>) -> Self::Future {
+        let handler = self.clone();
+        Box::pin(async move {
+            handler.handle_request(request).await?;
+            Ok(Response::new(stream::empty()))
+        })
+    }
+}
+
+async fn handle_batch_status(receiver: Option<BatchStatusReceiver>) -> Result<(), Status> {
+    let status = match receiver {
+        Some(receiver) => receiver.await,
+        None => BatchStatus::Delivered,
+    };
+
+    match status {
+        BatchStatus::Errored => Err(Status::internal("Delivery error")),
+        BatchStatus::Rejected => Err(Status::data_loss("Delivery failed")),
+        BatchStatus::Delivered => Ok(()),
+    }
+}
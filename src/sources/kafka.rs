@@ -4,12 +4,13 @@ use std::{
     pin::Pin,
     sync::{
         mpsc::{sync_channel, SyncSender},
-        Arc, OnceLock, Weak,
+        Arc, Mutex, OnceLock, Weak,
     },
     time::Duration,
 };
 
 use async_stream::stream;
+use bloomfilter::Bloom;
 use bytes::Bytes;
 use chrono::{DateTime, TimeZone, Utc};
 use futures::{Stream, StreamExt};
@@ -33,18 +34,25 @@ use tokio::{
         oneshot,
     },
     task::JoinSet,
-    time::Sleep,
+    time::{Instant, Sleep},
 };
 use tokio_util::codec::FramedRead;
 use tracing::{Instrument, Span};
 use vector_lib::codecs::{
-    decoding::{DeserializerConfig, FramingConfig},
+    decoding::{
+        format::{AvroDeserializer, AvroDeserializerConfig},
+        Deserializer, DeserializerConfig, FramingConfig,
+    },
     StreamDecodingError,
 };
-use vector_lib::lookup::{lookup_v2::OptionalValuePath, owned_value_path, path, OwnedValuePath};
+use vector_lib::lookup::{
+    lookup_v2::{ConfigTargetPath, OptionalValuePath},
+    owned_value_path, path, OwnedTargetPath, OwnedValuePath,
+};
 
 use vector_lib::configurable::configurable_component;
 use vector_lib::finalizer::OrderedFinalizer;
+use vector_lib::internal_event::error_stage;
 use vector_lib::{
     config::{LegacyKey, LogNamespace},
     EstimatedJsonEncodedSizeOf,
@@ -59,12 +67,16 @@ use crate::{
     },
     event::{BatchNotifier, BatchStatus, Event, Value},
     internal_events::{
-        KafkaBytesReceived, KafkaEventsReceived, KafkaOffsetUpdateError, KafkaReadError,
-        StreamClosedError,
+        KafkaBytesReceived, KafkaDuplicateEventsDropped, KafkaEventsReceived,
+        KafkaOffsetUpdateError, KafkaReadError, KafkaSchemaRegistryError, StreamClosedError,
+    },
+    kafka::{
+        self,
+        schema_registry::{self, SchemaRegistry},
     },
-    kafka,
     serde::{bool_or_struct, default_decoding, default_framing_message_based},
     shutdown::ShutdownSignal,
+    sinks::util::UriSerde,
     SourceSender,
 };
 
@@ -77,8 +89,17 @@ enum BuildError {
     },
     #[snafu(display("Could not create Kafka consumer: {}", source))]
     CreateError { source: rdkafka::error::KafkaError },
+    #[snafu(display("Invalid topic pattern {:?} in topic_outputs: {}", pattern, source))]
+    InvalidTopicOutputPattern {
+        pattern: String,
+        source: regex::Error,
+    },
+    #[snafu(display("Invalid deduplication options: {}", reason))]
+    InvalidDedupOptions { reason: &'static str },
     #[snafu(display("Could not subscribe to Kafka topics: {}", source))]
     SubscribeError { source: rdkafka::error::KafkaError },
+    #[snafu(display("`decoding` can't be set along with `schema_registry_url`"))]
+    SchemaRegistryDecoding,
 }
 
 /// Metrics (beta) configuration.
@@ -115,6 +136,14 @@ pub struct KafkaSourceConfig {
     ))]
     topics: Vec<String>,
 
+    /// Routes events from specific topics to named outputs.
+    ///
+    /// Events are sent to the output of the first entry with a topic matching the topic they were
+    /// consumed from. Events from topics that don't match any entry are sent to the default output.
+    #[serde(default)]
+    #[configurable(metadata(docs::advanced))]
+    topic_outputs: Vec<TopicOutput>,
+
     /// The consumer group name to be used to consume events from Kafka.
     #[configurable(metadata(docs::examples = "consumer-group-name"))]
     group_id: String,
@@ -217,6 +246,46 @@ pub struct KafkaSourceConfig {
     #[configurable(metadata(docs::examples = "headers"))]
     headers_key: OptionalValuePath,
 
+    /// The time window, in seconds, within which events with an already seen key are dropped as
+    /// duplicates.
+    ///
+    /// Messages can be consumed more than once, such as after a consumer group rebalance or a
+    /// crash. When set, the keys of the events consumed are tracked in a pair of bloom filters,
+    /// the oldest of which is discarded every `dedup_window_secs / 2` seconds. A key is therefore
+    /// remembered for at least half of the window, and at most the whole window.
+    ///
+    /// By default, events are not deduplicated.
+    #[configurable(metadata(docs::examples = 600))]
+    #[configurable(metadata(docs::advanced))]
+    #[configurable(metadata(docs::human_name = "Deduplication Window"))]
+    dedup_window_secs: Option<u64>,
+
+    /// The field holding the key events are deduplicated by.
+    ///
+    /// By default, events are deduplicated by the key of the Kafka message they were decoded from.
+    /// Events without a key are never dropped as duplicates.
+    #[configurable(metadata(docs::examples = "id", docs::examples = ".metadata.event_id"))]
+    #[configurable(metadata(docs::advanced))]
+    dedup_field: Option<ConfigTargetPath>,
+
+    /// The expected rate of false positives of the deduplication filters, which drop events with a
+    /// key that wasn't seen before.
+    ///
+    /// Must be greater than zero and lower than one.
+    #[serde(default = "default_dedup_false_positive_rate")]
+    #[derivative(Default(value = "default_dedup_false_positive_rate()"))]
+    #[configurable(metadata(docs::advanced))]
+    dedup_false_positive_rate: f64,
+
+    /// The number of distinct keys expected every `dedup_window_secs / 2` seconds.
+    ///
+    /// The deduplication filters are sized for this many keys. The rate of false positives rises
+    /// above `dedup_false_positive_rate` when more keys are seen.
+    #[serde(default = "default_dedup_capacity")]
+    #[derivative(Default(value = "default_dedup_capacity()"))]
+    #[configurable(metadata(docs::advanced))]
+    dedup_capacity: usize,
+
     /// Advanced options set directly on the underlying `librdkafka` client.
     ///
     /// See the [librdkafka documentation](https://github.com/edenhill/librdkafka/blob/master/CONFIGURATION.md) for details.
@@ -241,6 +310,18 @@ pub struct KafkaSourceConfig {
     #[derivative(Default(value = "default_decoding()"))]
     decoding: DeserializerConfig,
 
+    /// The URL of a [Confluent Schema Registry][schema_registry] to decode Avro messages with.
+    ///
+    /// When set, messages are expected to be prefixed with the ID of their schema, following the
+    /// [wire format][wire_format], and are decoded with that schema, which is fetched from the
+    /// registry and cached. Can't be used along with `decoding`.
+    ///
+    /// [schema_registry]: https://docs.confluent.io/platform/current/schema-registry/index.html
+    /// [wire_format]: https://docs.confluent.io/platform/current/schema-registry/fundamentals/serdes-develop/index.html#wire-format
+    #[configurable(metadata(docs::advanced))]
+    #[configurable(metadata(docs::examples = "http://localhost:8081"))]
+    schema_registry_url: Option<UriSerde>,
+
     #[configurable(derived)]
     #[serde(default, deserialize_with = "bool_or_struct")]
     acknowledgements: SourceAcknowledgementsConfig,
@@ -255,10 +336,135 @@ pub struct KafkaSourceConfig {
     metrics: Metrics,
 }
 
+/// Routes events from a set of topics to a named output.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct TopicOutput {
+    /// The topics whose events are sent to `output`.
+    ///
+    /// Regular expression syntax is supported if the topic begins with `^`.
+    #[configurable(metadata(
+        docs::examples = "^(prefix1|prefix2)-.+",
+        docs::examples = "topic-1"
+    ))]
+    topics: Vec<String>,
+
+    /// The name of the output events from `topics` are sent to.
+    #[configurable(metadata(docs::examples = "orders"))]
+    output: String,
+}
+
+/// A topic of a `topic_outputs` entry, with its regular expression compiled.
+#[derive(Clone, Debug)]
+enum TopicPattern {
+    Exact(String),
+    Regex(regex::Regex),
+}
+
+impl TopicPattern {
+    fn matches(&self, topic: &str) -> bool {
+        match self {
+            TopicPattern::Exact(pattern) => pattern == topic,
+            TopicPattern::Regex(regex) => regex.is_match(topic),
+        }
+    }
+}
+
+/// Routes the events of each topic to the output of the first `topic_outputs` entry matching it.
+///
+/// The patterns are compiled once when the source is built.
+#[derive(Clone, Debug, Default)]
+struct TopicRouter {
+    routes: Vec<(Vec<TopicPattern>, String)>,
+}
+
+impl TopicRouter {
+    /// Returns the named output events consumed from `topic` are sent to, or `None` if they are
+    /// sent to the default output.
+    fn output(&self, topic: &str) -> Option<&str> {
+        self.routes
+            .iter()
+            .find(|(patterns, _)| patterns.iter().any(|pattern| pattern.matches(topic)))
+            .map(|(_, output)| output.as_str())
+    }
+}
+
 impl KafkaSourceConfig {
     fn keys(&self) -> Keys {
         Keys::from(log_schema(), self)
     }
+
+    /// Compiles the patterns of `topic_outputs`.
+    fn topic_router(&self) -> crate::Result<TopicRouter> {
+        let routes = self
+            .topic_outputs
+            .iter()
+            .map(|topic_output| {
+                let patterns = topic_output
+                    .topics
+                    .iter()
+                    .map(|pattern| {
+                        if pattern.starts_with('^') {
+                            regex::Regex::new(pattern)
+                                .map(TopicPattern::Regex)
+                                .context(InvalidTopicOutputPatternSnafu { pattern })
+                        } else {
+                            Ok(TopicPattern::Exact(pattern.clone()))
+                        }
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok((patterns, topic_output.output.clone()))
+            })
+            .collect::<Result<Vec<_>, BuildError>>()?;
+        Ok(TopicRouter { routes })
+    }
+
+    fn validate_schema_registry(&self) -> crate::Result<()> {
+        if self.schema_registry_url.is_some() && !matches!(self.decoding, DeserializerConfig::Bytes)
+        {
+            return Err(SchemaRegistryDecodingSnafu.build().into());
+        }
+        Ok(())
+    }
+
+    fn validate_dedup(&self) -> crate::Result<()> {
+        if self.dedup_window_secs == Some(0) {
+            return Err(InvalidDedupOptionsSnafu {
+                reason: "dedup_window_secs must be greater than 0",
+            }
+            .build()
+            .into());
+        }
+        if !(self.dedup_false_positive_rate > 0.0 && self.dedup_false_positive_rate < 1.0) {
+            return Err(InvalidDedupOptionsSnafu {
+                reason: "dedup_false_positive_rate must be between 0 and 1",
+            }
+            .build()
+            .into());
+        }
+        if self.dedup_capacity == 0 {
+            return Err(InvalidDedupOptionsSnafu {
+                reason: "dedup_capacity must be greater than 0",
+            }
+            .build()
+            .into());
+        }
+        Ok(())
+    }
+
+    /// Returns the deduplicator shared by the consumers of all partitions, if deduplication is
+    /// enabled.
+    fn deduplicator(&self) -> Option<Deduplicator> {
+        self.dedup_window_secs.map(|window| {
+            Deduplicator::new(
+                Duration::from_secs(window),
+                self.dedup_field.clone().map(|field| field.0),
+                self.dedup_false_positive_rate,
+                self.dedup_capacity,
+            )
+        })
+    }
 }
 
 const fn default_session_timeout_ms() -> Duration {
@@ -277,6 +483,14 @@ const fn default_commit_interval_ms() -> Duration {
     Duration::from_millis(5000)
 }
 
+const fn default_dedup_false_positive_rate() -> f64 {
+    0.001
+}
+
+const fn default_dedup_capacity() -> usize {
+    100_000
+}
+
 fn default_auto_offset_reset() -> String {
     "largest".into() // default in librdkafka
 }
@@ -344,6 +558,15 @@ impl SourceConfig for KafkaSourceConfig {
             );
         }
 
+        let topic_router = self.topic_router()?;
+        self.validate_dedup()?;
+        self.validate_schema_registry()?;
+        let schema_registry = self
+            .schema_registry_url
+            .as_ref()
+            .map(|url| SchemaRegistry::new(url, &cx.proxy))
+            .transpose()?;
+
         let (consumer, callback_rx) = create_consumer(self, acknowledgements)?;
 
         Ok(Box::pin(kafka_source(
@@ -351,6 +574,8 @@ impl SourceConfig for KafkaSourceConfig {
             consumer,
             callback_rx,
             decoder,
+            schema_registry,
+            topic_router,
             cx.out,
             cx.shutdown,
             false,
@@ -362,9 +587,18 @@ impl SourceConfig for KafkaSourceConfig {
         let log_namespace = global_log_namespace.merge(self.log_namespace);
         let keys = self.keys();
 
-        let schema_definition = self
-            .decoding
-            .schema_definition(log_namespace)
+        let (output_type, schema_definition) = match self.schema_registry_url {
+            Some(_) => {
+                // The schema definition of Avro events doesn't depend on their schema.
+                let avro = AvroDeserializerConfig::new(String::new(), true);
+                (avro.output_type(), avro.schema_definition(log_namespace))
+            }
+            None => (
+                self.decoding.output_type(),
+                self.decoding.schema_definition(log_namespace),
+            ),
+        };
+        let schema_definition = schema_definition
             .with_standard_vector_source_metadata()
             .with_source_metadata(
                 Self::NAME,
@@ -409,10 +643,20 @@ impl SourceConfig for KafkaSourceConfig {
                 None,
             );
 
-        vec![SourceOutput::new_maybe_logs(
-            self.decoding.output_type(),
-            schema_definition,
-        )]
+        let mut outputs = vec![SourceOutput::new_maybe_logs(
+            output_type,
+            schema_definition.clone(),
+        )];
+        let mut ports = HashSet::new();
+        for topic_output in &self.topic_outputs {
+            if ports.insert(topic_output.output.as_str()) {
+                outputs.push(
+                    SourceOutput::new_maybe_logs(output_type, schema_definition.clone())
+                        .with_port(topic_output.output.clone()),
+                );
+            }
+        }
+        outputs
     }
 
     fn can_acknowledge(&self) -> bool {
@@ -426,6 +670,8 @@ async fn kafka_source(
     consumer: StreamConsumer<KafkaSourceContext>,
     callback_rx: UnboundedReceiver<KafkaCallback>,
     decoder: Decoder,
+    schema_registry: Option<SchemaRegistry>,
+    topic_router: TopicRouter,
     out: SourceSender,
     shutdown: ShutdownSignal,
     eof: bool,
@@ -455,8 +701,17 @@ async fn kafka_source(
         let drain_timeout_ms = config
             .drain_timeout_ms
             .map_or(config.session_timeout_ms / 2, Duration::from_millis);
-        let consumer_state =
-            ConsumerStateInner::<Consuming>::new(config, decoder, out, log_namespace, span);
+        let dedup = config.deduplicator().map(Arc::new);
+        let consumer_state = ConsumerStateInner::<Consuming>::new(
+            config,
+            decoder,
+            schema_registry,
+            topic_router,
+            dedup,
+            out,
+            log_namespace,
+            span,
+        );
         tokio::spawn(async move {
             coordinate_kafka_callbacks(
                 consumer,
@@ -503,6 +758,9 @@ async fn kafka_source(
 struct ConsumerStateInner<S> {
     config: KafkaSourceConfig,
     decoder: Decoder,
+    schema_registry: Option<SchemaRegistry>,
+    topic_router: TopicRouter,
+    dedup: Option<Arc<Deduplicator>>,
     out: SourceSender,
     log_namespace: LogNamespace,
     consumer_state: S,
@@ -564,6 +822,9 @@ impl ConsumerStateInner<Consuming> {
     const fn new(
         config: KafkaSourceConfig,
         decoder: Decoder,
+        schema_registry: Option<SchemaRegistry>,
+        topic_router: TopicRouter,
+        dedup: Option<Arc<Deduplicator>>,
         out: SourceSender,
         log_namespace: LogNamespace,
         span: Span,
@@ -571,6 +832,9 @@ impl ConsumerStateInner<Consuming> {
         Self {
             config,
             decoder,
+            schema_registry,
+            topic_router,
+            dedup,
             out,
             log_namespace,
             consumer_state: Consuming { span },
@@ -592,8 +856,12 @@ impl ConsumerStateInner<Consuming> {
     ) -> (oneshot::Sender<()>, tokio::task::AbortHandle) {
         let keys = self.config.keys();
         let decoder = self.decoder.clone();
+        let schema_registry = self.schema_registry.clone();
+        let dedup = self.dedup.clone();
         let log_namespace = self.log_namespace;
         let mut out = self.out.clone();
+        // All messages of a partition belong to the same topic, so they all go to the same output.
+        let output = self.topic_router.output(&tp.0).map(str::to_owned);
 
         let (end_tx, mut end_signal) = oneshot::channel::<()>();
 
@@ -654,7 +922,7 @@ impl ConsumerStateInner<Consuming> {
                                 topic: msg.topic(),
                                 partition: msg.partition(),
                             });
-                            parse_message(msg, decoder.clone(), &keys, &mut out, acknowledgements, &finalizer, log_namespace).await;
+                            parse_message(msg, decoder.clone(), schema_registry.as_ref(), &keys, dedup.as_deref(), &mut out, output.as_deref(), acknowledgements, &finalizer, log_namespace).await;
                         }
                     },
                 )
@@ -677,6 +945,9 @@ impl ConsumerStateInner<Consuming> {
         let draining = ConsumerStateInner {
             config: self.config,
             decoder: self.decoder,
+            schema_registry: self.schema_registry,
+            topic_router: self.topic_router,
+            dedup: self.dedup,
             out: self.out,
             log_namespace: self.log_namespace,
             consumer_state: Draining::new(sig, shutdown, self.consumer_state.span),
@@ -727,6 +998,9 @@ impl ConsumerStateInner<Draining> {
                 ConsumerState::Consuming(ConsumerStateInner {
                     config: self.config,
                     decoder: self.decoder,
+                    schema_registry: self.schema_registry,
+                    topic_router: self.topic_router,
+                    dedup: self.dedup,
                     out: self.out,
                     log_namespace: self.log_namespace,
                     consumer_state: Consuming {
@@ -951,16 +1225,36 @@ fn drive_kafka_consumer(
     });
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn parse_message(
     msg: BorrowedMessage<'_>,
     decoder: Decoder,
+    schema_registry: Option<&SchemaRegistry>,
     keys: &'_ Keys,
+    dedup: Option<&Deduplicator>,
     out: &mut SourceSender,
+    output: Option<&str>,
     acknowledgements: bool,
     finalizer: &Option<OrderedFinalizer<FinalizerEntry>>,
     log_namespace: LogNamespace,
 ) {
-    if let Some((count, stream)) = parse_stream(&msg, decoder, keys, log_namespace) {
+    let decoder = match (schema_registry, msg.payload()) {
+        (Some(schema_registry), Some(payload)) => {
+            match registry_decoder(payload, decoder, schema_registry).await {
+                Ok(decoder) => decoder,
+                Err(error) => {
+                    emit!(KafkaSchemaRegistryError {
+                        error: &error,
+                        stage: error_stage::RECEIVING,
+                    });
+                    return;
+                }
+            }
+        }
+        _ => decoder,
+    };
+
+    if let Some((count, stream)) = parse_stream(&msg, decoder, keys, dedup, log_namespace) {
         let (batch, receiver) = BatchNotifier::new_with_receiver();
         let mut stream = stream.map(|event| {
             // All acknowledgements flow through the normal Finalizer stream so
@@ -972,7 +1266,11 @@ async fn parse_message(
                 event
             }
         });
-        match out.send_event_stream(&mut stream).await {
+        let sent = match output {
+            Some(name) => out.send_event_stream_named(name, &mut stream).await,
+            None => out.send_event_stream(&mut stream).await,
+        };
+        match sent {
             Err(_) => {
                 emit!(StreamClosedError { count });
             }
@@ -988,11 +1286,26 @@ async fn parse_message(
     }
 }
 
+/// Returns a decoder for the Avro payload, using the schema whose ID it's prefixed with.
+async fn registry_decoder(
+    payload: &[u8],
+    decoder: Decoder,
+    registry: &SchemaRegistry,
+) -> crate::Result<Decoder> {
+    let schema_id = schema_registry::parse_schema_id(payload)?;
+    let schema = registry.schema(schema_id).await?;
+    Ok(Decoder {
+        deserializer: Deserializer::Avro(AvroDeserializer::new(schema, true)),
+        ..decoder
+    })
+}
+
 // Turn the received message into a stream of parsed events.
 fn parse_stream<'a>(
     msg: &BorrowedMessage<'a>,
     decoder: Decoder,
     keys: &'a Keys,
+    dedup: Option<&'a Deduplicator>,
     log_namespace: LogNamespace,
 ) -> Option<(usize, impl Stream<Item = Event> + 'a)> {
     let payload = msg.payload()?; // skip messages with empty payload
@@ -1013,10 +1326,18 @@ fn parse_stream<'a>(
                         topic: &rmsg.topic,
                         partition: rmsg.partition,
                     });
+                    let mut duplicates = 0;
                     for mut event in events {
                         rmsg.apply(keys, &mut event, log_namespace);
+                        if dedup.is_some_and(|dedup| dedup.is_duplicate(&event, &rmsg.key)) {
+                            duplicates += 1;
+                            continue;
+                        }
                         yield event;
                     }
+                    if duplicates > 0 {
+                        emit!(KafkaDuplicateEventsDropped { count: duplicates });
+                    }
                 },
                 Err(error) => {
                     // Error is logged by `codecs::Decoder`, no further handling
@@ -1055,6 +1376,79 @@ impl Keys {
     }
 }
 
+/// Drops events with a key seen within a sliding time window.
+///
+/// Keys are recorded in the current of two bloom filters, and looked up in both. Every half window,
+/// the previous filter is discarded and the current one takes its place.
+struct Deduplicator {
+    field: Option<OwnedTargetPath>,
+    rotation_interval: Duration,
+    false_positive_rate: f64,
+    capacity: usize,
+    filters: Mutex<DedupFilters>,
+}
+
+struct DedupFilters {
+    current: Bloom<[u8]>,
+    previous: Bloom<[u8]>,
+    rotated_at: Instant,
+}
+
+impl Deduplicator {
+    fn new(
+        window: Duration,
+        field: Option<OwnedTargetPath>,
+        false_positive_rate: f64,
+        capacity: usize,
+    ) -> Self {
+        let filter = || Bloom::new_for_fp_rate(capacity, false_positive_rate);
+        Self {
+            field,
+            rotation_interval: window / 2,
+            false_positive_rate,
+            capacity,
+            filters: Mutex::new(DedupFilters {
+                current: filter(),
+                previous: filter(),
+                rotated_at: Instant::now(),
+            }),
+        }
+    }
+
+    fn filter(&self) -> Bloom<[u8]> {
+        Bloom::new_for_fp_rate(self.capacity, self.false_positive_rate)
+    }
+
+    /// Returns whether the key of `event` was seen within the window, recording it as seen.
+    /// `message_key` is the key of the message the event was decoded from.
+    fn is_duplicate(&self, event: &Event, message_key: &Value) -> bool {
+        let key = match &self.field {
+            Some(field) => event.maybe_as_log().and_then(|log| log.get(field)),
+            None => Some(message_key),
+        };
+        let Some(key) = key.filter(|key| !key.is_null()) else {
+            return false;
+        };
+        let key = key.coerce_to_bytes();
+
+        let mut filters = self.filters.lock().expect("lock poisoned");
+        let elapsed = filters.rotated_at.elapsed();
+        if elapsed >= self.rotation_interval {
+            let previous = std::mem::replace(&mut filters.current, self.filter());
+            // After a whole window without rotating, the current filter's keys are outdated too.
+            filters.previous = if elapsed < self.rotation_interval * 2 {
+                previous
+            } else {
+                self.filter()
+            };
+            filters.rotated_at = Instant::now();
+        }
+        // Keys seen again are recorded in the current filter, so they stay tracked after rotating.
+        let seen = filters.current.check_and_set(&key);
+        seen || filters.previous.check(&key)
+    }
+}
+
 struct ReceivedMessage {
     timestamp: Option<DateTime<Utc>>,
     key: Value,
@@ -1391,6 +1785,7 @@ mod test {
     use vector_lib::schema::Definition;
 
     use super::*;
+    use crate::event::LogEvent;
 
     pub fn kafka_host() -> String {
         std::env::var("KAFKA_HOST").unwrap_or_else(|_| "localhost".into())
@@ -1409,6 +1804,145 @@ mod test {
         crate::test_util::test_generate_config::<KafkaSourceConfig>();
     }
 
+    fn topic_outputs_config() -> KafkaSourceConfig {
+        toml::from_str(
+            r#"
+            bootstrap_servers = "localhost:9092"
+            topics = ["orders", "payments", "^audit-.+", "other"]
+            group_id = "group"
+
+            [[topic_outputs]]
+            topics = ["orders", "payments"]
+            output = "billing"
+
+            [[topic_outputs]]
+            topics = ["^audit-.+"]
+            output = "audit"
+
+            [[topic_outputs]]
+            topics = ["payments"]
+            output = "unused"
+            "#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn routes_topics_to_outputs() {
+        let router = topic_outputs_config().topic_router().unwrap();
+
+        assert_eq!(router.output("orders"), Some("billing"));
+        // The first matching entry wins.
+        assert_eq!(router.output("payments"), Some("billing"));
+        assert_eq!(router.output("audit-eu"), Some("audit"));
+        assert_eq!(router.output("audit-"), None);
+        assert_eq!(router.output("other"), None);
+    }
+
+    #[test]
+    fn topic_outputs_are_exposed_as_ports() {
+        let ports = topic_outputs_config()
+            .outputs(LogNamespace::Legacy)
+            .into_iter()
+            .map(|output| output.port)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            ports,
+            vec![
+                None,
+                Some("billing".to_string()),
+                Some("audit".to_string()),
+                Some("unused".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_topic_output_pattern() {
+        let mut config = topic_outputs_config();
+        config.topic_outputs[1].topics = vec!["^audit-(".to_string()];
+
+        assert!(config.topic_router().is_err());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn drops_duplicates_within_window() {
+        let dedup = Deduplicator::new(Duration::from_secs(10), None, 0.001, 1_000);
+        let event = Event::Log(LogEvent::from("message"));
+        let key = |key: &str| Value::from(key);
+
+        assert!(!dedup.is_duplicate(&event, &key("a")));
+        assert!(dedup.is_duplicate(&event, &key("a")));
+        assert!(!dedup.is_duplicate(&event, &key("b")));
+        // Messages without a key are never duplicates.
+        assert!(!dedup.is_duplicate(&event, &Value::Null));
+        assert!(!dedup.is_duplicate(&event, &Value::Null));
+
+        // Keys seen in the previous half of the window are still duplicates, and seeing them again
+        // keeps them tracked for another half window.
+        tokio::time::advance(Duration::from_secs(5)).await;
+        assert!(dedup.is_duplicate(&event, &key("a")));
+        tokio::time::advance(Duration::from_secs(5)).await;
+        assert!(dedup.is_duplicate(&event, &key("a")));
+        assert!(!dedup.is_duplicate(&event, &key("b")));
+
+        // Nothing is remembered after a whole window without events.
+        tokio::time::advance(Duration::from_secs(10)).await;
+        assert!(!dedup.is_duplicate(&event, &key("a")));
+    }
+
+    #[test]
+    fn deduplicates_by_field() {
+        let dedup = Deduplicator::new(
+            Duration::from_secs(10),
+            Some(OwnedTargetPath::event(owned_value_path!("id"))),
+            0.001,
+            1_000,
+        );
+        let event = |id: &str| {
+            let mut log = LogEvent::from("message");
+            log.insert("id", id);
+            Event::Log(log)
+        };
+        let message_key = Value::from("key");
+
+        assert!(!dedup.is_duplicate(&event("1"), &message_key));
+        assert!(!dedup.is_duplicate(&event("2"), &message_key));
+        assert!(dedup.is_duplicate(&event("1"), &message_key));
+        let without_id = Event::Log(LogEvent::from("message"));
+        assert!(!dedup.is_duplicate(&without_id, &message_key));
+    }
+
+    #[test]
+    fn schema_registry_decodes_avro() {
+        let mut config = topic_outputs_config();
+        config.schema_registry_url = Some("http://localhost:8081".parse().unwrap());
+        assert!(config.validate_schema_registry().is_ok());
+
+        let outputs = config.outputs(LogNamespace::Legacy);
+        assert!(outputs
+            .iter()
+            .all(|output| output.ty == crate::config::DataType::Log));
+
+        config.decoding = DeserializerConfig::Json(Default::default());
+        assert!(config.validate_schema_registry().is_err());
+    }
+
+    #[test]
+    fn rejects_invalid_dedup_options() {
+        let mut config = topic_outputs_config();
+        config.dedup_window_secs = Some(600);
+        assert!(config.validate_dedup().is_ok());
+
+        config.dedup_false_positive_rate = 1.0;
+        assert!(config.validate_dedup().is_err());
+
+        config.dedup_false_positive_rate = 0.01;
+        config.dedup_window_secs = Some(0);
+        assert!(config.validate_dedup().is_err());
+    }
+
     pub(super) fn make_config(
         topic: &str,
         group: &str,
@@ -1771,6 +2305,55 @@ mod integration_test {
         }
     }
 
+    #[tokio::test]
+    async fn routes_topics_to_named_outputs() {
+        const SEND_COUNT: usize = 10;
+
+        let routed_topic = format!("test-topic-{}", random_string(10));
+        let default_topic = format!("test-topic-{}", random_string(10));
+        let group_id = format!("test-group-{}", random_string(10));
+
+        let mut config = make_config(&routed_topic, &group_id, LogNamespace::Legacy, None);
+        config.topics.push(default_topic.clone());
+        config.topic_outputs = vec![TopicOutput {
+            topics: vec![routed_topic.clone()],
+            output: "routed".to_string(),
+        }];
+
+        send_events(routed_topic.clone(), 1, SEND_COUNT).await;
+        send_events(default_topic.clone(), 1, SEND_COUNT).await;
+
+        let (mut tx, default_rx) = SourceSender::new_test_sender_with_buffer(100);
+        let routed_rx = tx.add_outputs(EventStatus::Delivered, "routed".to_string());
+        let (trigger_shutdown, shutdown_done) =
+            spawn_kafka(tx, config, false, false, LogNamespace::Legacy);
+
+        let routed = collect_n(
+            routed_rx.flat_map(|item| futures::stream::iter(item.events.into_events())),
+            SEND_COUNT,
+        )
+        .await;
+        let default = collect_n(
+            default_rx
+                .into_stream()
+                .flat_map(|item| futures::stream::iter(item.events.into_events())),
+            SEND_COUNT,
+        )
+        .await;
+
+        drop(trigger_shutdown);
+        shutdown_done.await;
+
+        assert_eq!(routed.len(), SEND_COUNT);
+        assert!(routed
+            .iter()
+            .all(|event| event.as_log()["topic"] == routed_topic.clone().into()));
+        assert_eq!(default.len(), SEND_COUNT);
+        assert!(default
+            .iter()
+            .all(|event| event.as_log()["topic"] == default_topic.clone().into()));
+    }
+
     fn make_rand_config() -> (String, String, KafkaSourceConfig) {
         let topic = format!("test-topic-{}", random_string(10));
         let group_id = format!("test-group-{}", random_string(10));
@@ -1819,12 +2402,15 @@ mod integration_test {
         .unwrap();
 
         let (consumer, callback_rx) = create_consumer(&config, acknowledgements).unwrap();
+        let topic_router = config.topic_router().unwrap();
 
         tokio::spawn(kafka_source(
             config,
             consumer,
             callback_rx,
             decoder,
+            None,
+            topic_router,
             out,
             shutdown,
             eof,
@@ -1,11 +1,14 @@
 use std::time::Duration;
 use std::{convert::Infallible, net::SocketAddr};
 
+use base64::prelude::{Engine as _, BASE64_STANDARD};
 use bytes::Bytes;
 use futures_util::FutureExt;
 use http::StatusCode;
 use hyper::{service::make_service_fn, Server};
 use prost::Message;
+use prost_reflect::{prost::Message as _, DeserializeOptions, DynamicMessage};
+use serde_json::Value as JsonValue;
 use snafu::Snafu;
 use tokio::net::TcpStream;
 use tower::ServiceBuilder;
@@ -13,10 +16,13 @@ use tracing::Span;
 use vector_lib::internal_event::{
     ByteSize, BytesReceived, CountByteSize, InternalEventHandle as _, Registered,
 };
-use vector_lib::opentelemetry::proto::collector::{
-    logs::v1::{ExportLogsServiceRequest, ExportLogsServiceResponse},
-    metrics::v1::{ExportMetricsServiceRequest, ExportMetricsServiceResponse},
-    trace::v1::{ExportTraceServiceRequest, ExportTraceServiceResponse},
+use vector_lib::opentelemetry::proto::{
+    collector::{
+        logs::v1::{ExportLogsServiceRequest, ExportLogsServiceResponse},
+        metrics::v1::{ExportMetricsServiceRequest, ExportMetricsServiceResponse},
+        trace::v1::{ExportTraceServiceRequest, ExportTraceServiceResponse},
+    },
+    protobuf_descriptors, LOGS_REQUEST_MESSAGE_TYPE, LOGS_RESPONSE_MESSAGE_TYPE,
 };
 use vector_lib::tls::MaybeTlsIncomingStream;
 use vector_lib::{
@@ -43,7 +49,10 @@ use crate::{
 };
 
 use super::OpentelemetryConfig;
-use super::{reply::protobuf, status::Status};
+use super::{
+    reply::{json, protobuf},
+    status::Status,
+};
 
 #[derive(Clone, Copy, Debug, Snafu)]
 pub(crate) enum ApiError {
@@ -52,6 +61,23 @@ pub(crate) enum ApiError {
 
 impl warp::reject::Reject for ApiError {}
 
+/// The encodings of OTLP/HTTP payloads, from their content type.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum OtlpEncoding {
+    Protobuf,
+    Json,
+}
+
+fn otlp_encoding() -> impl Filter<Extract = (OtlpEncoding,), Error = Rejection> + Clone {
+    warp::header::exact_ignore_case("content-type", "application/x-protobuf")
+        .map(|| OtlpEncoding::Protobuf)
+        .or(
+            warp::header::exact_ignore_case("content-type", "application/json")
+                .map(|| OtlpEncoding::Json),
+        )
+        .unify()
+}
+
 pub(crate) async fn run_http_server(
     address: SocketAddr,
     tls_settings: MaybeTlsSettings,
@@ -148,32 +174,32 @@ fn build_warp_log_filter(
 ) -> BoxedFilter<(Response,)> {
     warp::post()
         .and(warp::path!("v1" / "logs"))
-        .and(warp::header::exact_ignore_case(
-            "content-type",
-            "application/x-protobuf",
-        ))
+        .and(otlp_encoding())
         .and(warp::header::optional::<String>("content-encoding"))
         .and(warp::header::headers_cloned())
         .and(warp::body::bytes())
         .and_then(
-            move |encoding_header: Option<String>, headers_config: HeaderMap, body: Bytes| {
+            move |otlp_encoding: OtlpEncoding,
+                  encoding_header: Option<String>,
+                  headers_config: HeaderMap,
+                  body: Bytes| {
                 let events = decode(encoding_header.as_deref(), body)
                     .and_then(|body| {
                         bytes_received.emit(ByteSize(body.len()));
-                        decode_log_body(body, log_namespace, &events_received)
+                        decode_log_body(body, otlp_encoding, log_namespace, &events_received)
                     })
                     .map(|mut events| {
                         enrich_events(&mut events, &headers, &headers_config, log_namespace);
                         events
                     });
 
-                handle_request(
-                    events,
-                    acknowledgements,
-                    out.clone(),
-                    super::LOGS,
-                    ExportLogsServiceResponse::default(),
-                )
+                let response = ExportLogsServiceResponse::default();
+                let response = match otlp_encoding {
+                    OtlpEncoding::Protobuf => protobuf(response).into_response(),
+                    OtlpEncoding::Json => json(response, LOGS_RESPONSE_MESSAGE_TYPE),
+                };
+
+                handle_request(events, acknowledgements, out.clone(), super::LOGS, response)
             },
         )
         .boxed()
@@ -204,7 +230,7 @@ fn build_warp_metrics_filter(
                 acknowledgements,
                 out.clone(),
                 super::METRICS,
-                ExportMetricsServiceResponse::default(),
+                protobuf(ExportMetricsServiceResponse::default()).into_response(),
             )
         })
         .boxed()
@@ -235,7 +261,7 @@ fn build_warp_trace_filter(
                 acknowledgements,
                 out.clone(),
                 super::TRACES,
-                ExportTraceServiceResponse::default(),
+                protobuf(ExportTraceServiceResponse::default()).into_response(),
             )
         })
         .boxed()
@@ -268,10 +294,15 @@ fn decode_trace_body(
 
 fn decode_log_body(
     body: Bytes,
+    otlp_encoding: OtlpEncoding,
     log_namespace: LogNamespace,
     events_received: &Registered<EventsReceived>,
 ) -> Result<Vec<Event>, ErrorMessage> {
-    let request = ExportLogsServiceRequest::decode(body).map_err(|error| {
+    let request = match otlp_encoding {
+        OtlpEncoding::Protobuf => ExportLogsServiceRequest::decode(body).map_err(Into::into),
+        OtlpEncoding::Json => decode_json_log_request(&body),
+    }
+    .map_err(|error: crate::Error| {
         ErrorMessage::new(
             StatusCode::BAD_REQUEST,
             format!("Could not decode request: {}", error),
@@ -292,6 +323,64 @@ fn decode_log_body(
     Ok(events)
 }
 
+/// Decodes a logs request from the JSON encoding of OTLP, which follows the JSON mapping of
+/// Protocol Buffers, except for trace and span identifiers being hex rather than base64 strings.
+fn decode_json_log_request(body: &[u8]) -> crate::Result<ExportLogsServiceRequest> {
+    let mut request = serde_json::from_slice::<JsonValue>(body)?;
+    for resource_logs in json_array_items(&mut request, "resource_logs", "resourceLogs") {
+        for scope_logs in json_array_items(resource_logs, "scope_logs", "scopeLogs") {
+            for log_record in json_array_items(scope_logs, "log_records", "logRecords") {
+                hex_id_to_base64(log_record, "trace_id", "traceId");
+                hex_id_to_base64(log_record, "span_id", "spanId");
+            }
+        }
+    }
+
+    let descriptor = protobuf_descriptors()
+        .get_message_by_name(LOGS_REQUEST_MESSAGE_TYPE)
+        .expect("logs request message should be described");
+    // Receivers must ignore unknown fields, to stay compatible with newer versions of OTLP.
+    let options = DeserializeOptions::new().deny_unknown_fields(false);
+    let message = DynamicMessage::deserialize_with_options(descriptor, request, &options)?;
+
+    // The reflected messages use a different version of `prost` than the generated ones.
+    Ok(ExportLogsServiceRequest::decode(
+        message.encode_to_vec().as_slice(),
+    )?)
+}
+
+/// Returns the items of an array field of a JSON object, which may be named after either the
+/// field or its JSON name.
+fn json_array_items<'a>(
+    value: &'a mut JsonValue,
+    name: &str,
+    json_name: &str,
+) -> impl Iterator<Item = &'a mut JsonValue> {
+    value
+        .as_object_mut()
+        .and_then(|object| {
+            let key = if object.contains_key(json_name) {
+                json_name
+            } else {
+                name
+            };
+            object.get_mut(key)
+        })
+        .and_then(JsonValue::as_array_mut)
+        .into_iter()
+        .flatten()
+}
+
+fn hex_id_to_base64(value: &mut JsonValue, name: &str, json_name: &str) {
+    for key in [name, json_name] {
+        if let Some(JsonValue::String(id)) = value.get_mut(key) {
+            if let Ok(bytes) = hex::decode(id.as_str()) {
+                *id = BASE64_STANDARD.encode(bytes);
+            }
+        }
+    }
+}
+
 fn decode_metrics_body(
     body: Bytes,
     events_received: &Registered<EventsReceived>,
@@ -322,7 +411,7 @@ async fn handle_request(
     acknowledgements: bool,
     mut out: SourceSender,
     output: &str,
-    resp: impl Message,
+    resp: Response,
 ) -> Result<Response, Rejection> {
     match events {
         Ok(mut events) => {
@@ -335,9 +424,9 @@ async fn handle_request(
             })?;
 
             match receiver {
-                None => Ok(protobuf(resp).into_response()),
+                None => Ok(resp),
                 Some(receiver) => match receiver.await {
-                    BatchStatus::Delivered => Ok(protobuf(resp).into_response()),
+                    BatchStatus::Delivered => Ok(resp),
                     BatchStatus::Errored => Err(warp::reject::custom(Status {
                         code: 2, // UNKNOWN - OTLP doesn't require use of status.code, but we can't encode a None here
                         message: "Error delivering contents to sink".into(),
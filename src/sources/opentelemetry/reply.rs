@@ -1,6 +1,8 @@
 use bytes::BytesMut;
-use http::{header::CONTENT_TYPE, HeaderValue};
+use http::{header::CONTENT_TYPE, HeaderValue, StatusCode};
 use prost::Message;
+use prost_reflect::DynamicMessage;
+use vector_lib::opentelemetry::proto::protobuf_descriptors;
 use warp::{reply::Response, Reply};
 
 use super::status::Status;
@@ -20,6 +22,37 @@ where
     }
 }
 
+/// Encodes a message in the JSON encoding of OTLP, from the fully qualified name of its type.
+///
+/// If the message fails to be encoded as JSON, the error is logged at the `error` level, and the
+/// returned response is an empty `500 Internal Server Error` response.
+pub fn json<T>(val: T, message_type: &str) -> Response
+where
+    T: Message,
+{
+    let body = protobuf_descriptors()
+        .get_message_by_name(message_type)
+        .ok_or_else(|| format!("Unknown message type {message_type}"))
+        .and_then(|descriptor| {
+            DynamicMessage::decode(descriptor, val.encode_to_vec().as_slice())
+                .map_err(|err| err.to_string())
+        })
+        .and_then(|message| serde_json::to_vec(&message).map_err(|err| err.to_string()));
+
+    match body {
+        Ok(body) => {
+            let mut res = Response::new(body.into());
+            res.headers_mut()
+                .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+            res
+        }
+        Err(err) => {
+            error!("Failed to encode value: {}", err);
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
 /// A Protobuf formatted reply.
 #[allow(missing_debug_implementations)]
 pub struct Protobuf {
@@ -1211,6 +1211,63 @@ async fn http_headers() {
     .await;
 }
 
+#[tokio::test]
+async fn receive_http_json_logs() {
+    assert_source_compliance(&SOURCE_TAGS, async {
+        let env = build_otlp_test_env(LOGS, None).await;
+        let http_addr = env.config.http.address;
+        test_util::wait_for_tcp(http_addr).await;
+
+        let body = serde_json::json!({
+            "resourceLogs": [{
+                "resource": {
+                    "attributes": [{ "key": "service.name", "value": { "stringValue": "app" } }]
+                },
+                "scopeLogs": [{
+                    "scope": { "name": "some.scope" },
+                    "logRecords": [{
+                        "timeUnixNano": "1",
+                        "observedTimeUnixNano": "2",
+                        "severityNumber": 9,
+                        "severityText": "info",
+                        "body": { "stringValue": "log body" },
+                        "attributes": [{ "key": "count", "value": { "intValue": "3" } }],
+                        "flags": 4,
+                        "traceId": "4ac52aadf321c2e531db005df08792f5",
+                        "spanId": "0b9e4bda2a55530d",
+                        "unknownField": true
+                    }]
+                }]
+            }]
+        });
+        let response = reqwest::Client::new()
+            .post(format!("http://{}/v1/logs", http_addr))
+            .header("Content-Type", "application/json")
+            .body(body.to_string())
+            .send()
+            .await
+            .expect("Failed to send log to Opentelemetry Collector.");
+
+        assert_eq!(response.status(), 200);
+        assert_eq!(response.headers()["content-type"], "application/json");
+        assert_eq!(response.text().await.unwrap(), "{}");
+
+        let mut output = test_util::collect_ready(env.output).await;
+        assert_eq!(output.len(), 1);
+        let log = output.pop().unwrap().into_log();
+        assert_eq!(log["message"], "log body".into());
+        assert_eq!(log["severity_text"], "info".into());
+        assert_eq!(log["severity_number"], 9.into());
+        assert_eq!(log["attributes.count"], 3.into());
+        assert_eq!(log["resources.\"service.name\""], "app".into());
+        assert_eq!(log["scope.name"], "some.scope".into());
+        assert_eq!(log["trace_id"], "4ac52aadf321c2e531db005df08792f5".into());
+        assert_eq!(log["span_id"], "0b9e4bda2a55530d".into());
+        assert_eq!(log["timestamp"], Utc.timestamp_nanos(1).into());
+    })
+    .await;
+}
+
 pub struct OTelTestEnv {
     pub grpc_addr: String,
     pub config: OpentelemetryConfig,
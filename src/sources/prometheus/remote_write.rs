@@ -84,12 +84,14 @@ impl GenerateConfig for PrometheusRemoteWriteConfig {
 impl SourceConfig for PrometheusRemoteWriteConfig {
     async fn build(&self, cx: SourceContext) -> crate::Result<sources::Source> {
         let source = RemoteWriteSource;
+        // Remote write clients usually post to `/api/v1/write`, as they would to Prometheus, but
+        // some are configured with the bare address, so requests are accepted on any path.
         source.run(
             self.address,
             "",
             HttpMethod::Post,
             StatusCode::OK,
-            true,
+            false,
             self.tls.as_ref(),
             self.auth.as_ref(),
             cx,
@@ -172,15 +174,20 @@ mod test {
 
     #[tokio::test]
     async fn receives_metrics_over_http() {
-        receives_metrics(None).await;
+        receives_metrics(None, "/").await;
     }
 
     #[tokio::test]
     async fn receives_metrics_over_https() {
-        receives_metrics(Some(TlsEnableableConfig::test_config())).await;
+        receives_metrics(Some(TlsEnableableConfig::test_config()), "/").await;
     }
 
-    async fn receives_metrics(tls: Option<TlsEnableableConfig>) {
+    #[tokio::test]
+    async fn receives_metrics_on_prometheus_write_path() {
+        receives_metrics(None, "/api/v1/write").await;
+    }
+
+    async fn receives_metrics(tls: Option<TlsEnableableConfig>, path: &str) {
         let address = test_util::next_addr();
         let (tx, rx) = SourceSender::new_test_finalize(EventStatus::Delivered);
 
@@ -202,7 +209,7 @@ mod test {
         wait_for_tcp(address).await;
 
         let sink = RemoteWriteConfig {
-            endpoint: format!("{}://localhost:{}/", proto, address.port()),
+            endpoint: format!("{}://localhost:{}{}", proto, address.port(), path),
             tls: tls.map(|tls| tls.options),
             ..Default::default()
         };
@@ -6,7 +6,7 @@ use chrono::TimeZone;
 use futures_util::StreamExt;
 use pulsar::{
     authentication::oauth2::{OAuth2Authentication, OAuth2Params},
-    consumer::Message,
+    consumer::{InitialPosition, Message},
     message::proto::MessageIdData,
     Authentication, Consumer, Pulsar, SubType, TokioExecutor,
 };
@@ -67,6 +67,14 @@ pub struct PulsarSourceConfig {
     #[configurable(metadata(docs::examples = "subscription_name"))]
     subscription_name: Option<String>,
 
+    #[configurable(derived)]
+    #[serde(default)]
+    subscription_type: SubscriptionType,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    start_position: StartPosition,
+
     /// The consumer's priority level.
     ///
     /// The broker follows descending priorities. For example, 0=max-priority, 1, 2,...
@@ -107,6 +115,60 @@ pub struct PulsarSourceConfig {
     tls: Option<TlsOptions>,
 }
 
+/// The Pulsar subscription type.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+enum SubscriptionType {
+    /// Only a single consumer is allowed to attach to the subscription.
+    Exclusive,
+
+    /// Messages are distributed across all consumers attached to the subscription in a round-robin fashion.
+    #[default]
+    Shared,
+
+    /// Only one consumer receives messages at a time, with the remaining consumers acting as standbys.
+    Failover,
+
+    /// Messages are distributed across consumers, with all messages sharing a key delivered to the same consumer.
+    KeyShared,
+}
+
+impl From<SubscriptionType> for SubType {
+    fn from(subscription_type: SubscriptionType) -> Self {
+        match subscription_type {
+            SubscriptionType::Exclusive => SubType::Exclusive,
+            SubscriptionType::Shared => SubType::Shared,
+            SubscriptionType::Failover => SubType::Failover,
+            SubscriptionType::KeyShared => SubType::KeyShared,
+        }
+    }
+}
+
+/// The position in the topic at which a newly created subscription starts consuming.
+///
+/// This has no effect on a subscription that already exists, which resumes from its last acknowledged position.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+enum StartPosition {
+    /// Start from the oldest message available in the topic.
+    Earliest,
+
+    /// Start from the next message published to the topic.
+    #[default]
+    Latest,
+}
+
+impl From<StartPosition> for InitialPosition {
+    fn from(start_position: StartPosition) -> Self {
+        match start_position {
+            StartPosition::Earliest => InitialPosition::Earliest,
+            StartPosition::Latest => InitialPosition::Latest,
+        }
+    }
+}
+
 /// Authentication configuration.
 #[configurable_component]
 #[derive(Clone, Debug)]
@@ -268,7 +330,7 @@ impl SourceConfig for PulsarSourceConfig {
 impl PulsarSourceConfig {
     async fn create_consumer(
         &self,
-    ) -> crate::Result<pulsar::consumer::Consumer<String, TokioExecutor>> {
+    ) -> crate::Result<pulsar::consumer::Consumer<Vec<u8>, TokioExecutor>> {
         let mut builder = Pulsar::builder(&self.endpoint, TokioExecutor);
 
         if let Some(auth) = &self.auth {
@@ -300,9 +362,10 @@ impl PulsarSourceConfig {
         let mut consumer_builder: pulsar::ConsumerBuilder<TokioExecutor> = pulsar
             .consumer()
             .with_topics(&self.topics)
-            .with_subscription_type(SubType::Shared)
+            .with_subscription_type(self.subscription_type.into())
             .with_options(pulsar::consumer::ConsumerOptions {
                 priority_level: self.priority_level,
+                initial_position: self.start_position.into(),
                 ..Default::default()
             });
 
@@ -324,14 +387,14 @@ impl PulsarSourceConfig {
             consumer_builder = consumer_builder.with_subscription(subscription_name);
         }
 
-        let consumer = consumer_builder.build::<String>().await?;
+        let consumer = consumer_builder.build::<Vec<u8>>().await?;
 
         Ok(consumer)
     }
 }
 
 async fn pulsar_source(
-    mut consumer: Consumer<String, TokioExecutor>,
+    mut consumer: Consumer<Vec<u8>, TokioExecutor>,
     decoder: Decoder,
     mut shutdown: ShutdownSignal,
     mut out: SourceSender,
@@ -375,11 +438,11 @@ async fn pulsar_source(
 
 #[allow(clippy::too_many_arguments)]
 async fn parse_message(
-    msg: Message<String>,
+    msg: Message<Vec<u8>>,
     decoder: &Decoder,
     finalizer: &Option<OrderedFinalizer<FinalizerEntry>>,
     out: &mut SourceSender,
-    consumer: &mut Consumer<String, TokioExecutor>,
+    consumer: &mut Consumer<Vec<u8>, TokioExecutor>,
     log_namespace: LogNamespace,
     events_received: &Registered<EventsReceived>,
     pulsar_error_events: &Registered<PulsarErrorEvent>,
@@ -467,7 +530,7 @@ async fn parse_message(
 
 /// Send the event stream created by the framed read to the `out` stream.
 async fn finalize_event_stream(
-    consumer: &mut Consumer<String, TokioExecutor>,
+    consumer: &mut Consumer<Vec<u8>, TokioExecutor>,
     finalizer: &Option<OrderedFinalizer<FinalizerEntry>>,
     out: &mut SourceSender,
     mut stream: std::pin::Pin<Box<dyn futures_util::Stream<Item = Event> + Send + '_>>,
@@ -506,7 +569,7 @@ async fn finalize_event_stream(
 }
 
 async fn handle_ack(
-    consumer: &mut Consumer<String, TokioExecutor>,
+    consumer: &mut Consumer<Vec<u8>, TokioExecutor>,
     status: BatchStatus,
     entry: FinalizerEntry,
     pulsar_error_events: &Registered<PulsarErrorEvent>,
@@ -539,12 +602,42 @@ async fn handle_ack(
 
 #[cfg(test)]
 mod tests {
-    use crate::sources::pulsar::PulsarSourceConfig;
+    use crate::sources::pulsar::{PulsarSourceConfig, StartPosition, SubscriptionType};
 
     #[test]
     fn generate_config() {
         crate::test_util::test_generate_config::<PulsarSourceConfig>();
     }
+
+    #[test]
+    fn parses_subscription_options() {
+        let config: PulsarSourceConfig = toml::from_str(
+            r#"
+            endpoint = "pulsar://127.0.0.1:6650"
+            topics = ["my-topic"]
+            subscription_type = "key_shared"
+            start_position = "earliest"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.subscription_type, SubscriptionType::KeyShared);
+        assert_eq!(config.start_position, StartPosition::Earliest);
+    }
+
+    #[test]
+    fn subscription_options_default() {
+        let config: PulsarSourceConfig = toml::from_str(
+            r#"
+            endpoint = "pulsar://127.0.0.1:6650"
+            topics = ["my-topic"]
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.subscription_type, SubscriptionType::Shared);
+        assert_eq!(config.start_position, StartPosition::Latest);
+    }
 }
 
 #[cfg(feature = "pulsar-integration-tests")]
@@ -636,6 +729,8 @@ mod integration_tests {
             topics: vec![topic.clone()],
             consumer_name: None,
             subscription_name: None,
+            subscription_type: SubscriptionType::Shared,
+            start_position: StartPosition::Earliest,
             priority_level: None,
             batch_size: None,
             auth: None,
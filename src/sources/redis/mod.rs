@@ -16,18 +16,20 @@ use vector_lib::{
     config::{LegacyKey, LogNamespace},
     EstimatedJsonEncodedSizeOf,
 };
-use vrl::value::Kind;
+use vrl::value::{kind::Collection, Kind};
 
 use crate::{
     codecs::{Decoder, DecodingConfig},
-    config::{log_schema, GenerateConfig, SourceConfig, SourceContext, SourceOutput},
+    config::{log_schema, DataType, GenerateConfig, SourceConfig, SourceContext, SourceOutput},
     event::Event,
     internal_events::{EventsReceived, StreamClosedError},
+    schema::Definition,
     serde::{default_decoding, default_framing_message_based},
 };
 
 mod channel;
 mod list;
+mod stream;
 
 #[derive(Debug, Snafu)]
 enum BuildError {
@@ -49,6 +51,11 @@ pub enum DataTypeConfig {
     ///
     /// This is based on Redis' Pub/Sub capabilities.
     Channel,
+
+    /// The `stream` data type.
+    ///
+    /// Each stream entry is read as a log event, with the fields of the entry as its fields.
+    Stream,
 }
 
 /// Options for the Redis `list` data type.
@@ -74,6 +81,42 @@ pub enum Method {
     Rpop,
 }
 
+/// Options for the Redis `stream` data type.
+#[configurable_component]
+#[derive(Clone, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct StreamOption {
+    /// The keys of the streams to read entries from.
+    ///
+    /// If not set, entries are read from the stream in `key`.
+    #[serde(default)]
+    #[configurable(metadata(docs::examples = "logs"))]
+    stream_keys: Vec<String>,
+
+    /// The name of the consumer group to read entries with.
+    ///
+    /// If set, entries are read with `XREADGROUP` and acknowledged with `XACK` once they're sent
+    /// to the next components. Entries left pending by a previous run of the consumer are read
+    /// first.
+    ///
+    /// Otherwise, entries added to the streams after the source starts are read with `XREAD`.
+    #[configurable(metadata(docs::examples = "vector"))]
+    group_name: Option<String>,
+
+    /// The name of the consumer in the consumer group.
+    ///
+    /// If not set, the hostname is used.
+    #[configurable(metadata(docs::examples = "vector-1"))]
+    consumer_name: Option<String>,
+
+    /// Whether to create the consumer group when it doesn't exist.
+    ///
+    /// The group is created with `XGROUP CREATE ... MKSTREAM` when the source starts, which also
+    /// creates the streams that don't exist. It reads the entries added after its creation.
+    #[serde(default)]
+    auto_create_group: bool,
+}
+
 pub struct ConnectionInfo {
     protocol: &'static str,
     endpoint: String,
@@ -98,13 +141,16 @@ impl From<&redis::ConnectionInfo> for ConnectionInfo {
 #[derive(Clone, Debug, Derivative)]
 #[serde(deny_unknown_fields)]
 pub struct RedisSourceConfig {
-    /// The Redis data type (`list` or `channel`) to use.
+    /// The Redis data type (`list`, `channel` or `stream`) to use.
     #[serde(default)]
     data_type: DataTypeConfig,
 
     #[configurable(derived)]
     list: Option<ListOption>,
 
+    #[configurable(derived)]
+    stream: Option<StreamOption>,
+
     /// The Redis URL to connect to.
     ///
     /// The URL must take the form of `protocol://server:port/db` where the `protocol` can either be `redis` or `rediss` for connections secured using TLS.
@@ -112,6 +158,10 @@ pub struct RedisSourceConfig {
     url: String,
 
     /// The Redis key to read messages from.
+    ///
+    /// With the `stream` data type, this is the stream to read entries from if
+    /// `stream.stream_keys` isn't set.
+    #[serde(default)]
     #[configurable(metadata(docs::examples = "vector"))]
     key: String,
 
@@ -160,9 +210,22 @@ impl SourceConfig for RedisSourceConfig {
     async fn build(&self, cx: SourceContext) -> crate::Result<super::Source> {
         let log_namespace = cx.log_namespace(self.log_namespace);
 
-        // A key must be specified to actually query i.e. the list to pop from, or the channel to subscribe to.
-        if self.key.is_empty() {
-            return Err("`key` cannot be empty.".into());
+        // A key must be specified to actually query i.e. the list to pop from, the channel to
+        // subscribe to, or the streams to read.
+        match self.data_type {
+            DataTypeConfig::List | DataTypeConfig::Channel if self.key.is_empty() => {
+                return Err("`key` cannot be empty.".into());
+            }
+            DataTypeConfig::Stream
+                if self.key.is_empty()
+                    && self
+                        .stream
+                        .as_ref()
+                        .map_or(true, |stream| stream.stream_keys.is_empty()) =>
+            {
+                return Err("Either `key` or `stream.stream_keys` must be set.".into());
+            }
+            _ => {}
         }
         let redis_key = self.redis_key.clone().and_then(|k| k.path);
 
@@ -193,6 +256,10 @@ impl SourceConfig for RedisSourceConfig {
                 handler.watch(method).await
             }
             DataTypeConfig::Channel => handler.subscribe(connection_info).await,
+            DataTypeConfig::Stream => {
+                let options = self.stream.clone().unwrap_or_default();
+                handler.read_streams(options).await
+            }
         }
     }
 
@@ -205,6 +272,35 @@ impl SourceConfig for RedisSourceConfig {
             .and_then(|k| k.path)
             .map(LegacyKey::InsertIfEmpty);
 
+        if let DataTypeConfig::Stream = self.data_type {
+            let schema_definition = Definition::new_with_default_metadata(
+                Kind::object(Collection::any()),
+                [log_namespace],
+            )
+            .with_source_metadata(
+                Self::NAME,
+                redis_key_path,
+                &owned_value_path!("key"),
+                Kind::bytes(),
+                None,
+            )
+            .with_source_metadata(
+                Self::NAME,
+                Some(LegacyKey::InsertIfEmpty(owned_value_path!(
+                    stream::STREAM_ID_LEGACY_KEY
+                ))),
+                &owned_value_path!("stream_id"),
+                Kind::bytes(),
+                None,
+            )
+            .with_standard_vector_source_metadata();
+
+            return vec![SourceOutput::new_maybe_logs(
+                DataType::Log,
+                schema_definition,
+            )];
+        }
+
         let schema_definition = self
             .decoding
             .schema_definition(log_namespace)
@@ -345,6 +441,7 @@ mod integration_test {
             list: Some(ListOption {
                 method: Method::Rpop,
             }),
+            stream: None,
             url: REDIS_SERVER.to_owned(),
             key: key.clone(),
             redis_key: None,
@@ -386,6 +483,7 @@ mod integration_test {
             list: Some(ListOption {
                 method: Method::Rpop,
             }),
+            stream: None,
             url: REDIS_SERVER.to_owned(),
             key: key.clone(),
             redis_key: Some(OptionalValuePath::from(owned_value_path!("remapped_key"))),
@@ -427,6 +525,7 @@ mod integration_test {
             list: Some(ListOption {
                 method: Method::Lpop,
             }),
+            stream: None,
             url: REDIS_SERVER.to_owned(),
             key: key.clone(),
             redis_key: None,
@@ -451,6 +550,101 @@ mod integration_test {
         );
     }
 
+    fn stream_config(key: &str, stream: StreamOption) -> RedisSourceConfig {
+        RedisSourceConfig {
+            data_type: DataTypeConfig::Stream,
+            list: None,
+            stream: Some(stream),
+            url: REDIS_SERVER.to_owned(),
+            key: key.to_owned(),
+            redis_key: None,
+            framing: default_framing_message_based(),
+            decoding: default_decoding(),
+            log_namespace: Some(false),
+        }
+    }
+
+    #[tokio::test]
+    async fn redis_source_stream_xread() {
+        let key = format!("test-stream-{}", random_string(10));
+        let client = redis::Client::open(REDIS_SERVER).unwrap();
+        let mut conn = client.get_connection_manager().await.unwrap();
+
+        // Entries added before the source starts aren't read without a consumer group.
+        let _: String = conn.xadd(&key, "*", &[("message", "old")]).await.unwrap();
+
+        let config = stream_config(&key, StreamOption::default());
+        let (tx, rx) = SourceSender::new_test();
+        let source = config
+            .build(SourceContext::new_test(tx, None))
+            .await
+            .expect("source should not fail to build");
+        tokio::spawn(source);
+        tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
+
+        let mut ids = Vec::new();
+        for message in ["1", "2", "3"] {
+            let id: String = conn
+                .xadd(&key, "*", &[("message", message), ("host", "web-1")])
+                .await
+                .unwrap();
+            ids.push(id);
+        }
+
+        let events = collect_n(rx, 3).await;
+        for ((event, message), id) in events.iter().zip(["1", "2", "3"]).zip(ids) {
+            let log = event.as_log();
+            assert_eq!(log["message"], message.into());
+            assert_eq!(log["host"], "web-1".into());
+            assert_eq!(log["_redis_stream_id"], id.into());
+            assert_eq!(
+                log[log_schema().source_type_key().unwrap().to_string()],
+                RedisSourceConfig::NAME.into()
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn redis_source_stream_xreadgroup() {
+        let key = format!("test-stream-{}", random_string(10));
+        let config = stream_config(
+            "",
+            StreamOption {
+                stream_keys: vec![key.clone()],
+                group_name: Some("vector".to_owned()),
+                consumer_name: Some("vector-test".to_owned()),
+                auto_create_group: true,
+            },
+        );
+
+        let (tx, rx) = SourceSender::new_test();
+        let source = config
+            .build(SourceContext::new_test(tx, None))
+            .await
+            .expect("source should not fail to build");
+        tokio::spawn(source);
+        tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
+
+        let client = redis::Client::open(REDIS_SERVER).unwrap();
+        let mut conn = client.get_connection_manager().await.unwrap();
+        for message in ["1", "2", "3"] {
+            let _: String = conn.xadd(&key, "*", &[("message", message)]).await.unwrap();
+        }
+
+        let events = collect_n(rx, 3).await;
+        let messages = events
+            .iter()
+            .map(|event| event.as_log()["message"].clone())
+            .collect::<Vec<_>>();
+        assert_eq!(messages, ["1", "2", "3"].map(Into::into));
+
+        // The entries are acknowledged once they're sent.
+        tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
+        let pending: redis::streams::StreamPendingReply =
+            conn.xpending(&key, "vector").await.unwrap();
+        assert_eq!(pending.count(), 0);
+    }
+
     #[tokio::test]
     async fn redis_source_channel_consume_event() {
         let key = format!("test-channel-{}", random_string(10));
@@ -460,6 +654,7 @@ mod integration_test {
         let config = RedisSourceConfig {
             data_type: DataTypeConfig::Channel,
             list: None,
+            stream: None,
             url: REDIS_SERVER.to_owned(),
             key: key.clone(),
             redis_key: None,
@@ -0,0 +1,289 @@
+use chrono::{DateTime, Utc};
+use redis::{
+    aio::ConnectionManager,
+    streams::{StreamId, StreamKey, StreamRangeReply, StreamReadOptions, StreamReadReply},
+    AsyncCommands, ErrorKind, RedisError, RedisResult,
+};
+use snafu::{ResultExt, Snafu};
+use vector_lib::config::LegacyKey;
+use vector_lib::internal_event::{ByteSize, CountByteSize, InternalEventHandle as _};
+use vector_lib::lookup::path;
+use vector_lib::EstimatedJsonEncodedSizeOf;
+
+use super::{list::backoff_exponential, InputHandler, RedisSourceConfig, StreamOption};
+use crate::{
+    event::{Event, LogEvent, ObjectMap, Value},
+    internal_events::{RedisReceiveEventError, RedisStreamAckError, StreamClosedError},
+    sources::Source,
+};
+
+/// The field the ID of stream entries is stored in, with the legacy log namespace.
+pub(super) const STREAM_ID_LEGACY_KEY: &str = "_redis_stream_id";
+
+/// The maximum number of entries read from each stream at once.
+const READ_COUNT: usize = 100;
+
+/// How long a read waits for new entries, in milliseconds, before it's sent again.
+const READ_BLOCK_MS: usize = 1000;
+
+/// The ID `XREADGROUP` reads the entries never delivered to the consumer group with.
+const UNDELIVERED_ID: &str = ">";
+
+#[derive(Debug, Snafu)]
+enum BuildError {
+    #[snafu(display("Failed to create connection: {}", source))]
+    Connection { source: RedisError },
+    #[snafu(display("Failed to create consumer group for stream {:?}: {}", key, source))]
+    CreateGroup { key: String, source: RedisError },
+    #[snafu(display("Failed to get the last entry of stream {:?}: {}", key, source))]
+    LastEntry { key: String, source: RedisError },
+    #[snafu(display("Failed to get hostname for the consumer name: {}", source))]
+    Hostname { source: std::io::Error },
+}
+
+/// The consumer group the streams are read with.
+struct Group {
+    name: String,
+    consumer: String,
+}
+
+/// Tracks the position of the source in each stream it reads.
+struct StreamReader {
+    keys: Vec<String>,
+    ids: Vec<String>,
+    group: Option<Group>,
+}
+
+impl StreamReader {
+    async fn read(&self, conn: &mut ConnectionManager) -> RedisResult<StreamReadReply> {
+        let mut options = StreamReadOptions::default()
+            .count(READ_COUNT)
+            .block(READ_BLOCK_MS);
+        if let Some(group) = &self.group {
+            options = options.group(&group.name, &group.consumer);
+        }
+        conn.xread_options(self.keys.as_slice(), self.ids.as_slice(), &options)
+            .await
+    }
+
+    /// Moves past the entries of the reply.
+    ///
+    /// Without a consumer group, the next read starts after the last entry read from each stream.
+    /// With a consumer group, the pending entries of each stream are read until there are none
+    /// left, and the entries never delivered to the group are read afterwards.
+    fn advance(&mut self, reply: &StreamReadReply) {
+        for (key, id) in self.keys.iter().zip(&mut self.ids) {
+            let last = reply
+                .keys
+                .iter()
+                .find(|stream| &stream.key == key)
+                .and_then(|stream| stream.ids.last());
+            match (&self.group, last) {
+                (None, Some(entry)) => id.clone_from(&entry.id),
+                (None, None) => {}
+                (Some(_), _) if *id == UNDELIVERED_ID => {}
+                (Some(_), Some(entry)) => id.clone_from(&entry.id),
+                (Some(_), None) => *id = UNDELIVERED_ID.to_string(),
+            }
+        }
+    }
+}
+
+impl InputHandler {
+    pub(super) async fn read_streams(mut self, options: StreamOption) -> crate::Result<Source> {
+        let mut conn = self
+            .client
+            .get_connection_manager()
+            .await
+            .context(ConnectionSnafu {})?;
+
+        let keys = if options.stream_keys.is_empty() {
+            vec![self.key.clone()]
+        } else {
+            options.stream_keys
+        };
+
+        let (group, ids) = match options.group_name {
+            Some(name) => {
+                let consumer = match options.consumer_name {
+                    Some(consumer) => consumer,
+                    None => crate::get_hostname().context(HostnameSnafu)?,
+                };
+                if options.auto_create_group {
+                    for key in &keys {
+                        create_group(&mut conn, key, &name).await?;
+                    }
+                }
+                // Start with the entries left pending by a previous run of the consumer.
+                let ids = vec!["0".to_string(); keys.len()];
+                (Some(Group { name, consumer }), ids)
+            }
+            None => {
+                let mut ids = Vec::with_capacity(keys.len());
+                for key in &keys {
+                    ids.push(last_entry_id(&mut conn, key).await?);
+                }
+                (None, ids)
+            }
+        };
+        let mut reader = StreamReader { keys, ids, group };
+
+        Ok(Box::pin(async move {
+            let mut shutdown = self.cx.shutdown.clone();
+            let mut retry: u32 = 0;
+            loop {
+                let res = tokio::select! {
+                    res = reader.read(&mut conn) => res,
+                    _ = &mut shutdown => break
+                };
+
+                match res {
+                    Err(error) => {
+                        let kind = error.kind();
+
+                        emit!(RedisReceiveEventError::from(error));
+
+                        if kind == ErrorKind::IoError {
+                            retry += 1;
+                            backoff_exponential(retry).await
+                        }
+                    }
+                    Ok(reply) => {
+                        if retry > 0 {
+                            retry = 0
+                        }
+                        reader.advance(&reply);
+                        let group = reader.group.as_ref().map(|group| group.name.as_str());
+                        if let Err(()) = self.handle_stream_reply(&mut conn, group, reply).await {
+                            break;
+                        }
+                    }
+                }
+            }
+            Ok(())
+        }))
+    }
+
+    async fn handle_stream_reply(
+        &mut self,
+        conn: &mut ConnectionManager,
+        group: Option<&str>,
+        reply: StreamReadReply,
+    ) -> Result<(), ()> {
+        let now = Utc::now();
+
+        let byte_size = reply
+            .keys
+            .iter()
+            .flat_map(|stream| &stream.ids)
+            .map(entry_byte_size)
+            .sum();
+        self.bytes_received.emit(ByteSize(byte_size));
+
+        for StreamKey { key, ids: entries } in reply.keys {
+            if entries.is_empty() {
+                continue;
+            }
+
+            let ids = entries
+                .iter()
+                .map(|entry| entry.id.clone())
+                .collect::<Vec<_>>();
+            let events = entries
+                .into_iter()
+                .map(|entry| Event::Log(self.stream_entry_log(&key, entry, now)))
+                .collect::<Vec<_>>();
+
+            let count = events.len();
+            let byte_size = events.estimated_json_encoded_size_of();
+            self.events_received.emit(CountByteSize(count, byte_size));
+
+            if (self.cx.out.send_batch(events).await).is_err() {
+                emit!(StreamClosedError { count });
+                return Err(());
+            }
+
+            if let Some(group) = group {
+                let acked: RedisResult<usize> = conn.xack(&key, group, ids.as_slice()).await;
+                if let Err(error) = acked {
+                    emit!(RedisStreamAckError { error });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn stream_entry_log(&self, key: &str, entry: StreamId, now: DateTime<Utc>) -> LogEvent {
+        let fields = entry
+            .map
+            .into_iter()
+            .filter_map(|(field, value)| {
+                let value = redis::from_redis_value::<Vec<u8>>(&value).ok()?;
+                Some((field.into(), Value::Bytes(value.into())))
+            })
+            .collect::<ObjectMap>();
+
+        let mut log = self.log_namespace.new_log_from_data(fields);
+        self.log_namespace.insert_standard_vector_source_metadata(
+            &mut log,
+            RedisSourceConfig::NAME,
+            now,
+        );
+        self.log_namespace.insert_source_metadata(
+            RedisSourceConfig::NAME,
+            &mut log,
+            self.redis_key.as_ref().map(LegacyKey::InsertIfEmpty),
+            path!("key"),
+            key,
+        );
+        self.log_namespace.insert_source_metadata(
+            RedisSourceConfig::NAME,
+            &mut log,
+            Some(LegacyKey::InsertIfEmpty(path!(STREAM_ID_LEGACY_KEY))),
+            path!("stream_id"),
+            entry.id,
+        );
+        log
+    }
+}
+
+/// Creates the consumer group of the stream, along with the stream if it doesn't exist.
+async fn create_group(
+    conn: &mut ConnectionManager,
+    key: &str,
+    group: &str,
+) -> Result<(), BuildError> {
+    let created: RedisResult<()> = conn.xgroup_create_mkstream(key, group, "$").await;
+    match created {
+        Err(error) if error.code() == Some("BUSYGROUP") => Ok(()),
+        result => result.context(CreateGroupSnafu { key }),
+    }
+}
+
+/// Gets the ID of the last entry of the stream, so that the entries added afterwards are read.
+async fn last_entry_id(conn: &mut ConnectionManager, key: &str) -> Result<String, BuildError> {
+    let reply: StreamRangeReply = conn
+        .xrevrange_count(key, "+", "-", 1)
+        .await
+        .context(LastEntrySnafu { key })?;
+    Ok(reply
+        .ids
+        .into_iter()
+        .next()
+        .map_or_else(|| "0-0".to_string(), |entry| entry.id))
+}
+
+fn entry_byte_size(entry: &StreamId) -> usize {
+    entry
+        .map
+        .iter()
+        .map(|(field, value)| {
+            let value_len = match value {
+                redis::Value::BulkString(bytes) => bytes.len(),
+                redis::Value::SimpleString(string) => string.len(),
+                _ => 0,
+            };
+            field.len() + value_len
+        })
+        .sum()
+}
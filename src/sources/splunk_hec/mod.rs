@@ -96,6 +96,7 @@ pub struct SplunkConfig {
     ///
     /// If _not_ supplied, the `Authorization` header is ignored and requests are not authenticated.
     #[configurable(metadata(docs::examples = "A94A8FE5CCB19BA61C4C08"))]
+    #[serde(alias = "tokens")]
     valid_tokens: Option<Vec<SensitiveString>>,
 
     /// Whether or not to forward the Splunk HEC authentication token with events.
@@ -1331,6 +1332,16 @@ mod tests {
         crate::test_util::test_generate_config::<SplunkConfig>();
     }
 
+    #[test]
+    fn tokens_alias() {
+        let config: SplunkConfig =
+            toml::from_str(r#"tokens = ["token", "secondary-token"]"#).unwrap();
+
+        let tokens = config.valid_tokens.unwrap();
+        assert_eq!(tokens.len(), 2);
+        assert_eq!(tokens[1].inner(), "secondary-token");
+    }
+
     /// Splunk token
     const TOKEN: &str = "token";
     const VALID_TOKENS: &[&str; 2] = &[TOKEN, "secondary-token"];
@@ -1,13 +1,17 @@
 use crate::{
     internal_events::{GrpcServerRequestReceived, GrpcServerResponseSent},
     shutdown::{ShutdownSignal, ShutdownSignalToken},
-    tls::MaybeTlsSettings,
+    tls::{MaybeTlsIncomingStream, MaybeTlsListener, MaybeTlsSettings, TlsError},
 };
-use futures::FutureExt;
+use futures::{FutureExt, Stream};
 use http::{Request, Response};
 use hyper::Body;
 use std::{convert::Infallible, net::SocketAddr, time::Duration};
-use tonic::transport::server::Routes;
+use tokio::{
+    io::{AsyncRead, AsyncWrite},
+    net::TcpStream,
+};
+use tonic::transport::server::{Connected, Routes};
 use tonic::{body::BoxBody, server::NamedService, transport::server::Server};
 use tower::Service;
 use tower_http::{
@@ -19,6 +23,37 @@ use tracing::Span;
 mod decompression;
 pub use self::decompression::{DecompressionAndMetrics, DecompressionAndMetricsLayer};
 
+/// A listener accepting the connections served by a gRPC server.
+pub trait Accept {
+    /// The type of the accepted connections.
+    type Conn: AsyncRead + AsyncWrite + Connected + Unpin + Send + 'static;
+
+    /// The type of the errors accepting connections.
+    type Error: Into<crate::Error>;
+
+    /// Turns the listener into a stream of accepted connections.
+    fn into_incoming(self) -> impl Stream<Item = Result<Self::Conn, Self::Error>> + Send;
+}
+
+impl Accept for MaybeTlsListener {
+    type Conn = MaybeTlsIncomingStream<TcpStream>;
+    type Error = TlsError;
+
+    fn into_incoming(self) -> impl Stream<Item = Result<Self::Conn, Self::Error>> + Send {
+        self.accept_stream()
+    }
+}
+
+#[cfg(unix)]
+impl Accept for tokio::net::UnixListener {
+    type Conn = tokio::net::UnixStream;
+    type Error = std::io::Error;
+
+    fn into_incoming(self) -> impl Stream<Item = Result<Self::Conn, Self::Error>> + Send {
+        tokio_stream::wrappers::UnixListenerStream::new(self)
+    }
+}
+
 pub async fn run_grpc_server<S>(
     address: SocketAddr,
     tls_settings: MaybeTlsSettings,
@@ -33,13 +68,33 @@ where
         + 'static,
     S::Future: Send + 'static,
 {
-    let span = Span::current();
-    let (tx, rx) = tokio::sync::oneshot::channel::<ShutdownSignalToken>();
     let listener = tls_settings.bind(&address).await?;
-    let stream = listener.accept_stream();
 
     info!(%address, "Building gRPC server.");
 
+    run_grpc_server_with_listener(listener, service, shutdown).await
+}
+
+/// Runs a gRPC server serving the connections accepted by the given listener.
+pub async fn run_grpc_server_with_listener<L, S>(
+    listener: L,
+    service: S,
+    shutdown: ShutdownSignal,
+) -> crate::Result<()>
+where
+    L: Accept,
+    <L::Conn as Connected>::ConnectInfo: Clone + Send + Sync + 'static,
+    S: Service<Request<Body>, Response = Response<BoxBody>, Error = Infallible>
+        + NamedService
+        + Clone
+        + Send
+        + 'static,
+    S::Future: Send + 'static,
+{
+    let span = Span::current();
+    let (tx, rx) = tokio::sync::oneshot::channel::<ShutdownSignalToken>();
+    let stream = listener.into_incoming();
+
     Server::builder()
         .layer(build_grpc_trace_layer(span.clone()))
         // This layer explicitly decompresses payloads, if compressed, and reports the number of message bytes we've
@@ -88,6 +143,47 @@ pub async fn run_grpc_server_with_routes(
     Ok(())
 }
 
+/// Runs a gRPC server passing every request to the given service, whatever its path.
+///
+/// This serves services only known at runtime, which can't implement [NamedService] to be routed
+/// by the server, so the service must reject the methods it doesn't implement itself.
+#[cfg(feature = "sources-grpc")]
+pub async fn run_dynamic_grpc_server<S>(
+    address: SocketAddr,
+    tls_settings: MaybeTlsSettings,
+    service: S,
+    shutdown: ShutdownSignal,
+) -> crate::Result<()>
+where
+    S: Service<Request<Body>, Response = Response<BoxBody>, Error = Infallible>
+        + Clone
+        + Send
+        + 'static,
+    S::Future: Send + 'static,
+{
+    let span = Span::current();
+    let (tx, rx) = tokio::sync::oneshot::channel::<ShutdownSignalToken>();
+    let listener = tls_settings.bind(&address).await?;
+    let stream = listener.accept_stream();
+
+    info!(%address, "Building gRPC server.");
+
+    Server::builder()
+        .layer(build_grpc_trace_layer(span.clone()))
+        .layer(DecompressionAndMetricsLayer)
+        // The innermost layer replaces the empty routes of the server with the service.
+        .layer(tower::layer::layer_fn(move |_routes: Routes| {
+            service.clone()
+        }))
+        .add_routes(Routes::default())
+        .serve_with_incoming_shutdown(stream, shutdown.map(|token| tx.send(token).unwrap()))
+        .await?;
+
+    drop(rx.await);
+
+    Ok(())
+}
+
 /// Builds a [TraceLayer] configured for a gRPC server.
 ///
 /// This layer emits gPRC specific telemetry for messages received/sent and handler duration.
@@ -5,9 +5,12 @@ use serde_with::serde_as;
 use snafu::{ResultExt, Snafu};
 use vector_lib::configurable::configurable_component;
 
-use crate::line_agg;
+use crate::{conditions::VrlConfig, line_agg};
 
 /// Configuration of multi-line aggregation.
+///
+/// Lines are either grouped with the `start_pattern`, `condition_pattern` and `mode` settings, or
+/// with the `flush_condition_vrl` setting.
 #[serde_as]
 #[configurable_component]
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -18,7 +21,7 @@ pub struct MultilineConfig {
     #[configurable(metadata(docs::examples = "\\\\$"))]
     #[configurable(metadata(docs::examples = "^(INFO|ERROR) "))]
     #[configurable(metadata(docs::examples = ";$"))]
-    pub start_pattern: String,
+    pub start_pattern: Option<String>,
 
     /// Regular expression pattern that is used to determine whether or not more lines should be read.
     ///
@@ -27,13 +30,13 @@ pub struct MultilineConfig {
     #[configurable(metadata(docs::examples = "\\\\$"))]
     #[configurable(metadata(docs::examples = "^(INFO|ERROR) "))]
     #[configurable(metadata(docs::examples = ";$"))]
-    pub condition_pattern: String,
+    pub condition_pattern: Option<String>,
 
     /// Aggregation mode.
     ///
     /// This setting must be configured in conjunction with `condition_pattern`.
     #[configurable(derived)]
-    pub mode: line_agg::Mode,
+    pub mode: Option<line_agg::Mode>,
 
     /// The maximum amount of time to wait for the next additional line, in milliseconds.
     ///
@@ -43,6 +46,22 @@ pub struct MultilineConfig {
     #[configurable(metadata(docs::examples = 600000))]
     #[configurable(metadata(docs::human_name = "Timeout"))]
     pub timeout_ms: Duration,
+
+    /// A [Vector Remap Language][vrl] (VRL) condition that is used to determine when the buffered
+    /// lines are flushed as a message.
+    ///
+    /// The condition is evaluated each time a line is added to the buffer. The event it runs
+    /// against is the buffered lines joined with newlines, and the number of buffered lines is
+    /// available as `%accumulated_lines`. When the condition returns `true`, the buffer is
+    /// flushed.
+    ///
+    /// This setting can't be configured in conjunction with `start_pattern`, `condition_pattern`
+    /// and `mode`.
+    ///
+    /// [vrl]: https://vector.dev/docs/reference/vrl
+    #[configurable(metadata(docs::examples = "ends_with(string!(.), \"}\")"))]
+    #[configurable(metadata(docs::examples = "%accumulated_lines >= 10"))]
+    pub flush_condition_vrl: Option<String>,
 }
 
 impl TryFrom<&MultilineConfig> for line_agg::Config {
@@ -54,19 +73,37 @@ impl TryFrom<&MultilineConfig> for line_agg::Config {
             condition_pattern,
             mode,
             timeout_ms,
+            flush_condition_vrl,
         } = config;
 
-        let start_pattern = Regex::new(start_pattern)
-            .with_context(|_| InvalidMultilineStartPatternSnafu { start_pattern })?;
-        let condition_pattern = Regex::new(condition_pattern)
-            .with_context(|_| InvalidMultilineConditionPatternSnafu { condition_pattern })?;
-        let timeout = *timeout_ms;
+        let aggregation = match (start_pattern, condition_pattern, mode, flush_condition_vrl) {
+            (Some(start_pattern), Some(condition_pattern), Some(mode), None) => {
+                let start_pattern = Regex::new(start_pattern)
+                    .with_context(|_| InvalidMultilineStartPatternSnafu { start_pattern })?;
+                let condition_pattern = Regex::new(condition_pattern).with_context(|_| {
+                    InvalidMultilineConditionPatternSnafu { condition_pattern }
+                })?;
+
+                line_agg::Aggregation::Patterns {
+                    start_pattern,
+                    condition_pattern,
+                    mode: *mode,
+                }
+            }
+            (None, None, None, Some(flush_condition_vrl)) => {
+                let condition = VrlConfig::new(flush_condition_vrl.as_str())
+                    .build_uncolored(&Default::default())
+                    .map_err(|reason| Error::InvalidMultilineFlushCondition { reason })?;
+
+                line_agg::Aggregation::FlushCondition(condition)
+            }
+            (_, _, _, Some(_)) => return Err(Error::ConflictingMultilineSettings),
+            (_, _, _, None) => return Err(Error::MissingMultilineSettings),
+        };
 
         Ok(Self {
-            start_pattern,
-            condition_pattern,
-            mode: *mode,
-            timeout,
+            aggregation,
+            timeout: *timeout_ms,
         })
     }
 }
@@ -91,4 +128,16 @@ pub enum Error {
         condition_pattern: String,
         source: regex::Error,
     },
+    #[snafu(display("unable to compile multiline flush condition: {}", reason))]
+    InvalidMultilineFlushCondition { reason: String },
+    #[snafu(display(
+        "multiline `flush_condition_vrl` can't be configured in conjunction with \
+         `start_pattern`, `condition_pattern` or `mode`"
+    ))]
+    ConflictingMultilineSettings,
+    #[snafu(display(
+        "multiline requires either `start_pattern`, `condition_pattern` and `mode`, \
+         or `flush_condition_vrl`"
+    ))]
+    MissingMultilineSettings,
 }
@@ -1,8 +1,9 @@
 //! The `vector` source. See [VectorConfig].
-use std::net::SocketAddr;
+use std::{net::SocketAddr, path::PathBuf};
 
 use chrono::Utc;
-use futures::TryFutureExt;
+use futures::{FutureExt, TryFutureExt};
+use snafu::Snafu;
 use tonic::{Request, Response, Status};
 use vector_lib::codecs::NativeDeserializerConfig;
 use vector_lib::configurable::configurable_component;
@@ -25,6 +26,11 @@ use crate::{
     tls::{MaybeTlsSettings, TlsEnableableConfig},
     SourceSender,
 };
+#[cfg(unix)]
+use crate::{
+    internal_events::UnixSocketFileDeleteError,
+    sources::util::{change_socket_permissions, grpc::run_grpc_server_with_listener},
+};
 
 /// Marker type for version two of the configuration for the `vector` source.
 #[configurable_component]
@@ -35,6 +41,34 @@ enum VectorConfigVersion {
     V2,
 }
 
+/// The transport to accept connections over.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum VectorTransport {
+    /// Accept connections over TCP, on `address`.
+    #[default]
+    Tcp,
+
+    /// Accept connections over a Unix domain socket, at `socket_path`.
+    ///
+    /// This is only supported on Unix platforms.
+    Uds,
+}
+
+#[derive(Debug, Snafu)]
+enum BuildError {
+    #[snafu(display("`address` must be set with the `tcp` transport"))]
+    MissingAddress,
+    #[snafu(display("`socket_path` must be set with the `uds` transport"))]
+    MissingSocketPath,
+    #[snafu(display("TLS is not supported with the `uds` transport"))]
+    UdsTls,
+    #[cfg(not(unix))]
+    #[snafu(display("The `uds` transport is only supported on Unix platforms"))]
+    UdsUnsupported,
+}
+
 #[derive(Debug, Clone)]
 struct Service {
     pipeline: SourceSender,
@@ -121,10 +155,32 @@ pub struct VectorConfig {
     /// Version of the configuration.
     version: Option<VectorConfigVersion>,
 
+    #[configurable(derived)]
+    #[serde(default, skip_serializing_if = "crate::serde::is_default")]
+    pub transport: VectorTransport,
+
     /// The socket address to listen for connections on.
     ///
-    /// It _must_ include a port.
-    pub address: SocketAddr,
+    /// It _must_ include a port. This is required with the `tcp` transport.
+    #[configurable(metadata(docs::examples = "0.0.0.0:6000"))]
+    pub address: Option<SocketAddr>,
+
+    /// The path of the Unix domain socket to listen for connections on.
+    ///
+    /// This is required with the `uds` transport, and should be an absolute path.
+    #[configurable(metadata(docs::examples = "/var/run/vector.sock"))]
+    pub socket_path: Option<PathBuf>,
+
+    /// Unix file mode bits to be applied to the socket file as its designated file permissions.
+    ///
+    /// This is only used with the `uds` transport.
+    ///
+    /// Note: The file mode value can be specified in any numeric format supported by your
+    /// configuration language, but it is most intuitive to use an octal number.
+    #[configurable(metadata(docs::examples = 0o777))]
+    #[configurable(metadata(docs::examples = 0o600))]
+    #[configurable(metadata(docs::examples = 508))]
+    pub mode: Option<u32>,
 
     #[configurable(derived)]
     #[serde(default)]
@@ -144,7 +200,17 @@ impl VectorConfig {
     /// Creates a `VectorConfig` with the given address.
     pub fn from_address(addr: SocketAddr) -> Self {
         Self {
-            address: addr,
+            address: Some(addr),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a `VectorConfig` listening on the Unix domain socket at the given path.
+    pub fn from_socket_path(path: PathBuf) -> Self {
+        Self {
+            transport: VectorTransport::Uds,
+            address: None,
+            socket_path: Some(path),
             ..Default::default()
         }
     }
@@ -154,7 +220,10 @@ impl Default for VectorConfig {
     fn default() -> Self {
         Self {
             version: None,
-            address: "0.0.0.0:6000".parse().unwrap(),
+            transport: VectorTransport::Tcp,
+            address: Some("0.0.0.0:6000".parse().unwrap()),
+            socket_path: None,
+            mode: None,
             tls: None,
             acknowledgements: Default::default(),
             log_namespace: None,
@@ -185,12 +254,38 @@ impl SourceConfig for VectorConfig {
         // Tonic added a default of 4MB in 0.9. This replaces the old behavior.
         .max_decoding_message_size(usize::MAX);
 
-        let source =
-            run_grpc_server(self.address, tls_settings, service, cx.shutdown).map_err(|error| {
-                error!(message = "Source future failed.", %error);
-            });
+        let source = match self.transport {
+            VectorTransport::Tcp => {
+                let address = self.address.ok_or(BuildError::MissingAddress)?;
+                run_grpc_server(address, tls_settings, service, cx.shutdown).boxed()
+            }
+            VectorTransport::Uds => {
+                let path = self
+                    .socket_path
+                    .clone()
+                    .ok_or(BuildError::MissingSocketPath)?;
+                if self
+                    .tls
+                    .as_ref()
+                    .is_some_and(|tls| tls.enabled.unwrap_or(false))
+                {
+                    return Err(BuildError::UdsTls.into());
+                }
+                #[cfg(unix)]
+                {
+                    run_uds_server(path, self.mode, service, cx.shutdown).boxed()
+                }
+                #[cfg(not(unix))]
+                {
+                    _ = path;
+                    return Err(BuildError::UdsUnsupported.into());
+                }
+            }
+        };
 
-        Ok(Box::pin(source))
+        Ok(Box::pin(source.map_err(|error| {
+            error!(message = "Source future failed.", %error);
+        })))
     }
 
     fn outputs(&self, global_log_namespace: LogNamespace) -> Vec<SourceOutput> {
@@ -207,7 +302,10 @@ impl SourceConfig for VectorConfig {
     }
 
     fn resources(&self) -> Vec<Resource> {
-        vec![Resource::tcp(self.address)]
+        match self.transport {
+            VectorTransport::Tcp => self.address.map(Resource::tcp).into_iter().collect(),
+            VectorTransport::Uds => Vec::new(),
+        }
     }
 
     fn can_acknowledge(&self) -> bool {
@@ -215,6 +313,40 @@ impl SourceConfig for VectorConfig {
     }
 }
 
+/// Serves the gRPC service on a Unix domain socket at `path`, deleting the socket file once the
+/// server is shut down.
+#[cfg(unix)]
+async fn run_uds_server<S>(
+    path: PathBuf,
+    mode: Option<u32>,
+    service: S,
+    shutdown: crate::shutdown::ShutdownSignal,
+) -> crate::Result<()>
+where
+    S: tower::Service<
+            http::Request<hyper::Body>,
+            Response = http::Response<tonic::body::BoxBody>,
+            Error = std::convert::Infallible,
+        > + tonic::server::NamedService
+        + Clone
+        + Send
+        + 'static,
+    S::Future: Send + 'static,
+{
+    let listener = tokio::net::UnixListener::bind(&path)?;
+    change_socket_permissions(&path, mode)?;
+
+    info!(message = "Building gRPC server.", path = ?path);
+
+    let result = run_grpc_server_with_listener(listener, service, shutdown).await;
+
+    if let Err(error) = std::fs::remove_file(&path) {
+        emit!(UnixSocketFileDeleteError { path: &path, error });
+    }
+
+    result
+}
+
 #[cfg(test)]
 mod test {
     use vector_lib::lookup::owned_value_path;
@@ -273,6 +405,56 @@ mod test {
 
         assert_eq!(definitions, Some(expected_definition))
     }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn receive_message_over_uds() {
+        use std::os::unix::fs::PermissionsExt;
+
+        use crate::{config::SourceContext, proto::vector as proto, test_util, SourceSender};
+
+        let path = test_util::temp_file();
+        let mut config = VectorConfig::from_socket_path(path.clone());
+        config.mode = Some(0o600);
+
+        let (tx, rx) = SourceSender::new_test();
+        let server = config
+            .build(SourceContext::new_test(tx, None))
+            .await
+            .unwrap();
+        tokio::spawn(server);
+        test_util::wait_for({
+            let path = path.clone();
+            move || {
+                let path = path.clone();
+                async move { tokio::net::UnixStream::connect(path).await.is_ok() }
+            }
+        })
+        .await;
+
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+
+        let channel = tonic::transport::Endpoint::from_static("http://localhost")
+            .connect_with_connector(tower::service_fn(move |_: http::Uri| {
+                tokio::net::UnixStream::connect(path.clone())
+            }))
+            .await
+            .unwrap();
+        let (events, _) = test_util::random_events_with_stream(100, 10, None);
+        proto::Client::new(channel)
+            .push_events(proto::PushEventsRequest {
+                events: events.iter().cloned().map(Into::into).collect(),
+            })
+            .await
+            .unwrap();
+
+        let output = test_util::collect_ready(rx).await;
+        assert_eq!(output.len(), events.len());
+        for (input, output) in events.iter().zip(output) {
+            assert_eq!(input.as_log().get_message(), output.as_log().get_message());
+        }
+    }
 }
 
 #[cfg(feature = "sinks-vector")]
@@ -0,0 +1,478 @@
+//! Status subcommand
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Write as _,
+    time::{Duration, Instant},
+};
+
+use clap::Parser;
+use colored::*;
+use futures_util::StreamExt;
+use url::Url;
+use vector_lib::api_client::{
+    connect_subscription_client,
+    gql::{ComponentErrorsTotalsSubscription, ComponentsQueryExt, MetricsSubscriptionExt},
+    BoxedSubscription, Client, SubscriptionClient,
+};
+
+use crate::config::api::default_graphql_url;
+
+/// Exit code used when at least one component is not healthy.
+const UNHEALTHY: exitcode::ExitCode = 1;
+
+#[derive(Parser, Debug, Clone)]
+#[command(rename_all = "kebab-case")]
+pub struct Opts {
+    /// GraphQL API server endpoint
+    #[arg(short, long)]
+    api_address: Option<Url>,
+
+    /// Interval over which rates are sampled, in milliseconds
+    #[arg(default_value = "1000", short = 'i', long)]
+    interval: u32,
+
+    /// Continuously refresh the status until interrupted
+    #[arg(short, long)]
+    watch: bool,
+
+    /// Only show a detailed view of the component with the given ID
+    #[arg(short, long)]
+    component: Option<String>,
+}
+
+impl Opts {
+    /// Use the provided URL as the Vector GraphQL API server, or default to the local port
+    /// provided by the API config.
+    fn url(&self) -> Url {
+        self.api_address.clone().unwrap_or_else(default_graphql_url)
+    }
+
+    /// URL with scheme set to WebSockets
+    fn web_socket_url(&self) -> Url {
+        let mut url = self.url();
+        url.set_scheme(match url.scheme() {
+            "https" => "wss",
+            _ => "ws",
+        })
+        .expect("Couldn't build WebSocket URL. Please report.");
+
+        url
+    }
+}
+
+/// Health of a single component, derived from its metrics over the sampling interval.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Health {
+    /// No errors were reported during the interval.
+    Healthy,
+    /// Errors were reported, but the component is still sending events.
+    Degraded,
+    /// Errors were reported, and the component sent no events.
+    Failed,
+}
+
+impl Health {
+    const fn as_str(self) -> &'static str {
+        match self {
+            Health::Healthy => "healthy",
+            Health::Degraded => "degraded",
+            Health::Failed => "failed",
+        }
+    }
+
+    fn colorize(self, color: bool) -> String {
+        if !color {
+            return self.as_str().to_string();
+        }
+        match self {
+            Health::Healthy => self.as_str().green(),
+            Health::Degraded => self.as_str().yellow(),
+            Health::Failed => self.as_str().red(),
+        }
+        .to_string()
+    }
+}
+
+/// Totals reported for a component by a single components query.
+#[derive(Debug, Clone, Default)]
+struct Totals {
+    kind: String,
+    component_type: String,
+    received_events: i64,
+    sent_events: i64,
+    received_bytes: i64,
+    sent_bytes: i64,
+    outputs: Vec<(String, i64)>,
+}
+
+impl Totals {
+    /// Network bytes handled by the component. Only sources receive, and only sinks send, bytes.
+    const fn bytes(&self) -> i64 {
+        self.received_bytes + self.sent_bytes
+    }
+}
+
+/// Status of a component, computed from two samples taken `elapsed` apart.
+#[derive(Debug, Clone)]
+struct ComponentStatus {
+    id: String,
+    totals: Totals,
+    errors_total: i64,
+    events_per_sec: f64,
+    bytes_per_sec: f64,
+    errors_per_sec: f64,
+    health: Health,
+}
+
+impl ComponentStatus {
+    fn new(
+        id: String,
+        before: Option<&Totals>,
+        after: Totals,
+        errors: (i64, i64),
+        elapsed: Duration,
+    ) -> Self {
+        let secs = elapsed.as_secs_f64().max(f64::EPSILON);
+        let rate = |before: i64, after: i64| (after - before).max(0) as f64 / secs;
+
+        let (events_per_sec, bytes_per_sec) = match before {
+            Some(before) => (
+                rate(before.sent_events, after.sent_events),
+                rate(before.bytes(), after.bytes()),
+            ),
+            None => (0.0, 0.0),
+        };
+        let new_errors = (errors.1 - errors.0).max(0);
+        let sent_events = before.map_or(0, |before| after.sent_events - before.sent_events);
+
+        let health = if new_errors == 0 {
+            Health::Healthy
+        } else if sent_events > 0 {
+            Health::Degraded
+        } else {
+            Health::Failed
+        };
+
+        Self {
+            id,
+            totals: after,
+            errors_total: errors.1,
+            events_per_sec,
+            bytes_per_sec,
+            errors_per_sec: new_errors as f64 / secs,
+            health,
+        }
+    }
+}
+
+/// CLI command func for printing the health and throughput of components running in a
+/// local/remote Vector instance.
+pub async fn cmd(opts: &Opts, color: bool) -> exitcode::ExitCode {
+    let url = opts.url();
+    let client = Client::new(url.clone());
+    #[allow(clippy::print_stderr)]
+    if client.healthcheck().await.is_err() {
+        eprintln!(
+            indoc::indoc! {"
+            Vector API server isn't reachable ({}).
+
+            Have you enabled the API?
+
+            To enable the API, add the following to your Vector config file:
+
+            [api]
+                enabled = true"},
+            url
+        );
+        return exitcode::UNAVAILABLE;
+    }
+
+    let subscription_client = match connect_subscription_client(opts.web_socket_url()).await {
+        Ok(client) => client,
+        Err(error) => {
+            #[allow(clippy::print_stderr)]
+            {
+                eprintln!("Couldn't connect to Vector API via WebSockets: {}", error);
+            }
+            return exitcode::UNAVAILABLE;
+        }
+    };
+
+    loop {
+        let statuses = match sample(&client, &subscription_client, opts.interval).await {
+            Ok(statuses) => statuses,
+            Err(error) => {
+                #[allow(clippy::print_stderr)]
+                {
+                    eprintln!("Couldn't retrieve component status: {}", error);
+                }
+                return exitcode::UNAVAILABLE;
+            }
+        };
+
+        let output = match &opts.component {
+            Some(id) => match statuses.iter().find(|status| &status.id == id) {
+                Some(status) => format_detail(status, color),
+                None => {
+                    #[allow(clippy::print_stderr)]
+                    {
+                        eprintln!("Component \"{}\" not found.", id);
+                    }
+                    return exitcode::DATAERR;
+                }
+            },
+            None => format_table(&statuses, color),
+        };
+
+        #[allow(clippy::print_stdout)]
+        {
+            if opts.watch {
+                // Clear the screen and move the cursor to the top-left corner.
+                print!("\x1b[2J\x1b[H");
+            }
+            print!("{}", output);
+        }
+
+        if !opts.watch {
+            let unhealthy = statuses
+                .iter()
+                .filter(|status| opts.component.as_ref().is_none_or(|id| &status.id == id))
+                .any(|status| status.health != Health::Healthy);
+            return if unhealthy { UNHEALTHY } else { exitcode::OK };
+        }
+    }
+}
+
+/// Samples component totals and error counts twice, `interval` milliseconds apart.
+async fn sample(
+    client: &Client,
+    subscription_client: &SubscriptionClient,
+    interval: u32,
+) -> Result<Vec<ComponentStatus>, String> {
+    let mut errors = subscription_client.component_errors_totals_subscription(interval as i64);
+
+    let before = components(client).await?;
+    let started = Instant::now();
+    let errors_before = next_errors_totals(&mut errors).await?;
+    let errors_after = next_errors_totals(&mut errors).await?;
+    let after = components(client).await?;
+    let elapsed = started.elapsed();
+
+    Ok(after
+        .into_iter()
+        .map(|(id, totals)| {
+            let errors = (
+                errors_before.get(&id).copied().unwrap_or_default(),
+                errors_after.get(&id).copied().unwrap_or_default(),
+            );
+            let before = before.get(&id);
+            ComponentStatus::new(id, before, totals, errors, elapsed)
+        })
+        .collect())
+}
+
+/// Waits for the next error totals of every component, keyed by component ID.
+async fn next_errors_totals(
+    errors: &mut BoxedSubscription<ComponentErrorsTotalsSubscription>,
+) -> Result<HashMap<String, i64>, String> {
+    match errors.next().await {
+        Some(Some(response)) => Ok(response
+            .data
+            .map(|data| {
+                data.component_errors_totals
+                    .into_iter()
+                    .map(|c| (c.component_id, c.metric.errors_total as i64))
+                    .collect()
+            })
+            .unwrap_or_default()),
+        _ => Err("errors subscription closed".to_string()),
+    }
+}
+
+/// Retrieves the totals of every component, keyed by component ID.
+async fn components(client: &Client) -> Result<BTreeMap<String, Totals>, String> {
+    // As with `vector top`, an artificially high page size is used to capture all likely
+    // component configurations.
+    let data = client
+        .components_query(i16::MAX as i64)
+        .await
+        .map_err(|error| error.to_string())?
+        .data
+        .ok_or_else(|| "empty components response".to_string())?;
+
+    Ok(data
+        .components
+        .edges
+        .into_iter()
+        .map(|edge| {
+            let node = edge.node;
+            let totals = Totals {
+                kind: node.on.to_string(),
+                component_type: node.component_type,
+                received_events: node.on.received_events_total(),
+                sent_events: node.on.sent_events_total(),
+                received_bytes: node.on.received_bytes_total(),
+                sent_bytes: node.on.sent_bytes_total(),
+                outputs: node.on.outputs(),
+            };
+            (node.component_id, totals)
+        })
+        .collect())
+}
+
+fn format_table(statuses: &[ComponentStatus], color: bool) -> String {
+    const HEADERS: [&str; 7] = [
+        "ID",
+        "KIND",
+        "TYPE",
+        "STATUS",
+        "EVENTS/SEC",
+        "BYTES/SEC",
+        "ERRORS/SEC",
+    ];
+
+    let rows = statuses
+        .iter()
+        .map(|status| {
+            [
+                status.id.clone(),
+                status.totals.kind.clone(),
+                status.totals.component_type.clone(),
+                status.health.as_str().to_string(),
+                format!("{:.2}", status.events_per_sec),
+                format!("{:.2}", status.bytes_per_sec),
+                format!("{:.2}", status.errors_per_sec),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    let mut widths = HEADERS.map(str::len);
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+    }
+
+    let mut output = String::new();
+    let header = HEADERS
+        .iter()
+        .zip(widths)
+        .map(|(header, width)| format!("{:<width$}", header))
+        .collect::<Vec<_>>()
+        .join("  ");
+    writeln!(output, "{}", header.trim_end()).expect("writing to a string can't fail");
+
+    for (row, status) in rows.iter().zip(statuses) {
+        let line = row
+            .iter()
+            .zip(widths)
+            .enumerate()
+            .map(|(column, (cell, width))| {
+                // Pad before coloring so the escape codes don't count towards the width.
+                let padded = format!("{:<width$}", cell);
+                if column == 3 {
+                    padded.replace(cell.as_str(), &status.health.colorize(color))
+                } else {
+                    padded
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("  ");
+        writeln!(output, "{}", line.trim_end()).expect("writing to a string can't fail");
+    }
+
+    output
+}
+
+fn format_detail(status: &ComponentStatus, color: bool) -> String {
+    let totals = &status.totals;
+    let mut output = String::new();
+    let mut line = |name: &str, value: String| {
+        writeln!(output, "{:<22}{}", format!("{}:", name), value)
+            .expect("writing to a string can't fail");
+    };
+
+    line("ID", status.id.clone());
+    line("Kind", totals.kind.clone());
+    line("Type", totals.component_type.clone());
+    line("Status", status.health.colorize(color));
+    line("Events/sec", format!("{:.2}", status.events_per_sec));
+    line("Bytes/sec", format!("{:.2}", status.bytes_per_sec));
+    line("Errors/sec", format!("{:.2}", status.errors_per_sec));
+    line("Received events total", totals.received_events.to_string());
+    line("Sent events total", totals.sent_events.to_string());
+    line("Received bytes total", totals.received_bytes.to_string());
+    line("Sent bytes total", totals.sent_bytes.to_string());
+    line("Errors total", status.errors_total.to_string());
+    for (output_id, sent_events) in &totals.outputs {
+        line(
+            &format!("Output {}", output_id),
+            format!("{} events sent", sent_events),
+        );
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn totals(sent_events: i64, sent_bytes: i64) -> Totals {
+        Totals {
+            kind: "sink".to_string(),
+            component_type: "blackhole".to_string(),
+            sent_events,
+            sent_bytes,
+            ..Default::default()
+        }
+    }
+
+    fn status(before: Totals, after: Totals, errors: (i64, i64)) -> ComponentStatus {
+        ComponentStatus::new(
+            "out".to_string(),
+            Some(&before),
+            after,
+            errors,
+            Duration::from_secs(2),
+        )
+    }
+
+    #[test]
+    fn computes_rates() {
+        let status = status(totals(10, 100), totals(30, 500), (1, 1));
+
+        assert_eq!(status.events_per_sec, 10.0);
+        assert_eq!(status.bytes_per_sec, 200.0);
+        assert_eq!(status.errors_per_sec, 0.0);
+        assert_eq!(status.health, Health::Healthy);
+    }
+
+    #[test]
+    fn degraded_when_erroring_but_sending() {
+        let status = status(totals(10, 0), totals(20, 0), (0, 4));
+
+        assert_eq!(status.errors_per_sec, 2.0);
+        assert_eq!(status.health, Health::Degraded);
+    }
+
+    #[test]
+    fn failed_when_erroring_and_not_sending() {
+        let status = status(totals(10, 0), totals(10, 0), (0, 1));
+
+        assert_eq!(status.health, Health::Failed);
+    }
+
+    #[test]
+    fn formats_table_without_color() {
+        let statuses = vec![status(totals(0, 0), totals(2, 4), (0, 0))];
+
+        assert_eq!(
+            format_table(&statuses, false),
+            indoc::indoc! {"
+                ID   KIND  TYPE       STATUS   EVENTS/SEC  BYTES/SEC  ERRORS/SEC
+                out  sink  blackhole  healthy  1.00        2.00       0.00
+            "}
+        );
+    }
+}
@@ -30,6 +30,7 @@ use vector_lib::{
         BufferType, WhenFull,
     },
     schema::Definition,
+    sink::PauseSignal,
     EstimatedJsonEncodedSizeOf,
 };
 
@@ -84,6 +85,7 @@ struct Builder<'a> {
     inputs: HashMap<ComponentKey, (BufferSender<EventArray>, Inputs<OutputId>)>,
     healthchecks: HashMap<ComponentKey, Task>,
     detach_triggers: HashMap<ComponentKey, Trigger>,
+    pause_signals: HashMap<ComponentKey, PauseSignal>,
     extra_context: ExtraContext,
     utilization_emitter: UtilizationEmitter,
 }
@@ -106,6 +108,7 @@ impl<'a> Builder<'a> {
             inputs: HashMap::new(),
             healthchecks: HashMap::new(),
             detach_triggers: HashMap::new(),
+            pause_signals: HashMap::new(),
             extra_context,
             utilization_emitter: UtilizationEmitter::new(),
         }
@@ -131,6 +134,7 @@ impl<'a> Builder<'a> {
                 healthchecks: self.healthchecks,
                 shutdown_coordinator: self.shutdown_coordinator,
                 detach_triggers: self.detach_triggers,
+                pause_signals: self.pause_signals,
                 utilization_emitter: Some(self.utilization_emitter),
             })
         } else {
@@ -515,13 +519,21 @@ impl<'a> Builder<'a> {
             self.inputs
                 .insert(key.clone(), (input_tx, node.inputs.clone()));
 
+            let pause = PauseSignal::default();
             let (transform_task, transform_outputs) = {
                 let _span = span.enter();
-                build_transform(transform, node, input_rx, &mut self.utilization_emitter)
+                build_transform(
+                    transform,
+                    node,
+                    input_rx,
+                    pause.clone(),
+                    &mut self.utilization_emitter,
+                )
             };
 
             self.outputs.extend(transform_outputs);
             self.tasks.insert(key.clone(), transform_task);
+            self.pause_signals.insert(key.clone(), pause);
         }
     }
 
@@ -598,6 +610,8 @@ impl<'a> Builder<'a> {
                 }
             };
 
+            let pause = PauseSignal::default();
+            let supports_pause = sink.inner.supports_pause();
             let cx = SinkContext {
                 healthcheck,
                 globals: self.config.global.clone(),
@@ -607,6 +621,7 @@ impl<'a> Builder<'a> {
                 app_name: crate::get_app_name().to_string(),
                 app_name_slug: crate::get_slugified_app_name(),
                 extra_context: self.extra_context.clone(),
+                pause: pause.clone(),
             };
 
             let (sink, healthcheck) = match sink.inner.build(cx).await {
@@ -707,6 +722,9 @@ impl<'a> Builder<'a> {
             self.healthchecks.insert(key.clone(), healthcheck_task);
             self.tasks.insert(key.clone(), task);
             self.detach_triggers.insert(key.clone(), trigger);
+            if supports_pause {
+                self.pause_signals.insert(key.clone(), pause);
+            }
         }
     }
 }
@@ -719,6 +737,7 @@ pub struct TopologyPieces {
     pub(super) healthchecks: HashMap<ComponentKey, Task>,
     pub(crate) shutdown_coordinator: SourceShutdownCoordinator,
     pub(crate) detach_triggers: HashMap<ComponentKey, Trigger>,
+    pub(crate) pause_signals: HashMap<ComponentKey, PauseSignal>,
     pub(crate) utilization_emitter: Option<UtilizationEmitter>,
 }
 
@@ -798,17 +817,21 @@ fn build_transform(
     transform: Transform,
     node: TransformNode,
     input_rx: BufferReceiver<EventArray>,
+    pause: PauseSignal,
     utilization_emitter: &mut UtilizationEmitter,
 ) -> (Task, HashMap<OutputId, fanout::ControlChannel>) {
     match transform {
         // TODO: avoid the double boxing for function transforms here
         Transform::Function(t) => {
-            build_sync_transform(Box::new(t), node, input_rx, utilization_emitter)
+            build_sync_transform(Box::new(t), node, input_rx, pause, utilization_emitter)
+        }
+        Transform::Synchronous(t) => {
+            build_sync_transform(t, node, input_rx, pause, utilization_emitter)
         }
-        Transform::Synchronous(t) => build_sync_transform(t, node, input_rx, utilization_emitter),
         Transform::Task(t) => build_task_transform(
             t,
             input_rx,
+            pause,
             node.input_details.data_type(),
             node.typetag,
             &node.key,
@@ -822,12 +845,20 @@ fn build_sync_transform(
     t: Box<dyn SyncTransform>,
     node: TransformNode,
     input_rx: BufferReceiver<EventArray>,
+    pause: PauseSignal,
     utilization_emitter: &mut UtilizationEmitter,
 ) -> (Task, HashMap<OutputId, fanout::ControlChannel>) {
     let (outputs, controls) = TransformOutputs::new(node.outputs, &node.key);
 
     let sender = utilization_emitter.add_component(node.key.clone(), gauge!("utilization"));
-    let runner = Runner::new(t, input_rx, sender, node.input_details.data_type(), outputs);
+    let runner = Runner::new(
+        t,
+        input_rx,
+        pause,
+        sender,
+        node.input_details.data_type(),
+        outputs,
+    );
     let transform = if node.enable_concurrency {
         runner.run_concurrently().boxed()
     } else {
@@ -865,6 +896,7 @@ fn build_sync_transform(
 struct Runner {
     transform: Box<dyn SyncTransform>,
     input_rx: Option<BufferReceiver<EventArray>>,
+    pause: PauseSignal,
     input_type: DataType,
     outputs: TransformOutputs,
     timer_tx: UtilizationComponentSender,
@@ -875,6 +907,7 @@ impl Runner {
     fn new(
         transform: Box<dyn SyncTransform>,
         input_rx: BufferReceiver<EventArray>,
+        pause: PauseSignal,
         timer_tx: UtilizationComponentSender,
         input_type: DataType,
         outputs: TransformOutputs,
@@ -882,6 +915,7 @@ impl Runner {
         Self {
             transform,
             input_rx: Some(input_rx),
+            pause,
             input_type,
             outputs,
             timer_tx,
@@ -909,12 +943,13 @@ impl Runner {
 
         let mut outputs_buf = self.outputs.new_buf_with_capacity(INLINE_BATCH_SIZE);
 
-        let mut input_rx = self
+        let input_rx = self
             .input_rx
             .take()
             .expect("can't run runner twice")
             .into_stream()
             .filter(move |events| ready(filter_events_type(events, self.input_type)));
+        let mut input_rx = Box::pin(self.pause.clone().hold(input_rx));
 
         self.timer_tx.try_send_start_wait();
         while let Some(events) = input_rx.next().await {
@@ -935,6 +970,7 @@ impl Runner {
             .expect("can't run runner twice")
             .into_stream()
             .filter(move |events| ready(filter_events_type(events, self.input_type)));
+        let input_rx = Box::pin(self.pause.clone().hold(input_rx));
 
         let mut input_rx =
             super::ready_arrays::ReadyArrays::with_capacity(input_rx, READY_ARRAY_CAPACITY);
@@ -999,6 +1035,7 @@ impl Runner {
 fn build_task_transform(
     t: Box<dyn TaskTransform<EventArray>>,
     input_rx: BufferReceiver<EventArray>,
+    pause: PauseSignal,
     input_type: DataType,
     typetag: &str,
     key: &ComponentKey,
@@ -1008,7 +1045,11 @@ fn build_task_transform(
     let (mut fanout, control) = Fanout::new();
 
     let sender = utilization_emitter.add_component(key.clone(), gauge!("utilization"));
-    let input_rx = wrap(sender, key.clone(), input_rx.into_stream());
+    let input_rx = wrap(
+        sender,
+        key.clone(),
+        Box::pin(pause.hold(input_rx.into_stream())),
+    );
 
     let events_received = register!(EventsReceived);
     let filtered = input_rx
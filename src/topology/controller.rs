@@ -7,6 +7,8 @@ use crate::internal_events::{VectorRecoveryError, VectorReloadError, VectorReloa
 use futures_util::FutureExt as _;
 use tokio::sync::{Mutex, MutexGuard};
 
+#[cfg(feature = "api")]
+use crate::signal::SignalTx;
 use crate::{config, signal::ShutdownError, topology::RunningTopology};
 
 #[derive(Clone, Debug)]
@@ -36,6 +38,9 @@ pub struct TopologyController {
     pub require_healthy: Option<bool>,
     #[cfg(feature = "api")]
     pub api_server: Option<api::Server>,
+    /// Passed to the API server, so that its mutations can reload the topology.
+    #[cfg(feature = "api")]
+    pub signal_tx: SignalTx,
     pub extra_context: ExtraContext,
 }
 
@@ -52,7 +57,8 @@ impl std::fmt::Debug for TopologyController {
 pub enum ReloadOutcome {
     MissingApiKey,
     Success,
-    RolledBack,
+    /// The new config couldn't be loaded, and the previous config was restored.
+    RolledBack(Vec<String>),
     FatalError(ShutdownError),
 }
 
@@ -80,6 +86,7 @@ impl TopologyController {
                 self.topology.config(),
                 self.topology.watch(),
                 Arc::<AtomicBool>::clone(&self.topology.running),
+                self.signal_tx.clone(),
                 &Handle::current(),
             ) {
                 Ok(api_server) => {
@@ -101,10 +108,10 @@ impl TopologyController {
 
         match self
             .topology
-            .reload_config_and_respawn(new_config, self.extra_context.clone())
+            .reload_config_and_respawn_with_errors(new_config, self.extra_context.clone())
             .await
         {
-            Ok(true) => {
+            Ok(errors) if errors.is_empty() => {
                 #[cfg(feature = "api")]
                 // Pass the new config to the API server.
                 if let Some(ref api_server) = self.api_server {
@@ -116,9 +123,9 @@ impl TopologyController {
                 });
                 ReloadOutcome::Success
             }
-            Ok(false) => {
+            Ok(errors) => {
                 emit!(VectorReloadError);
-                ReloadOutcome::RolledBack
+                ReloadOutcome::RolledBack(errors)
             }
             // Trigger graceful shutdown for what remains of the topology
             Err(()) => {
@@ -30,13 +30,16 @@ use tokio::{
 use tracing::Instrument;
 use vector_lib::tap::topology::{TapOutput, TapResource, WatchRx, WatchTx};
 use vector_lib::trigger::DisabledTrigger;
-use vector_lib::{buffers::topology::channel::BufferSender, shutdown::ShutdownSignal};
+use vector_lib::{
+    buffers::topology::channel::BufferSender, shutdown::ShutdownSignal, sink::PauseSignal,
+};
 
 pub type ShutdownErrorReceiver = mpsc::UnboundedReceiver<ShutdownError>;
 
 #[allow(dead_code)]
 pub struct RunningTopology {
     inputs: HashMap<ComponentKey, BufferSender<EventArray>>,
+    sink_inputs: HashMap<ComponentKey, Arc<BufferSender<EventArray>>>,
     inputs_tap_metadata: HashMap<ComponentKey, Inputs<OutputId>>,
     outputs: HashMap<OutputId, ControlChannel>,
     outputs_tap_metadata: HashMap<ComponentKey, (&'static str, String)>,
@@ -44,6 +47,8 @@ pub struct RunningTopology {
     tasks: HashMap<ComponentKey, TaskHandle>,
     shutdown_coordinator: SourceShutdownCoordinator,
     detach_triggers: HashMap<ComponentKey, DisabledTrigger>,
+    pauses: HashMap<ComponentKey, PauseSignal>,
+    paused_during_reload: HashSet<ComponentKey>,
     pub(crate) config: Config,
     pub(crate) abort_tx: mpsc::UnboundedSender<ShutdownError>,
     watch: (WatchTx, WatchRx),
@@ -58,11 +63,14 @@ impl RunningTopology {
     pub fn new(config: Config, abort_tx: mpsc::UnboundedSender<ShutdownError>) -> Self {
         Self {
             inputs: HashMap::new(),
+            sink_inputs: HashMap::new(),
             inputs_tap_metadata: HashMap::new(),
             outputs: HashMap::new(),
             outputs_tap_metadata: HashMap::new(),
             shutdown_coordinator: SourceShutdownCoordinator::default(),
             detach_triggers: HashMap::new(),
+            pauses: HashMap::new(),
+            paused_during_reload: HashSet::new(),
             source_tasks: HashMap::new(),
             tasks: HashMap::new(),
             abort_tx,
@@ -124,6 +132,11 @@ impl RunningTopology {
     pub fn stop(self) -> impl Future<Output = ()> {
         // Update the API's health endpoint to signal shutdown
         self.running.store(false, Ordering::Relaxed);
+        // Paused components would otherwise hold on to their input until being killed. Releasing
+        // the signals also keeps the API from pausing them again while shutting down.
+        for pause in self.pauses.values() {
+            pause.release();
+        }
         // Create handy handles collections of all tasks for the subsequent
         // operations.
         let mut wait_handles = Vec::new();
@@ -249,6 +262,28 @@ impl RunningTopology {
         new_config: Config,
         extra_context: ExtraContext,
     ) -> Result<bool, ()> {
+        self.reload_config_and_respawn_with_errors(new_config, extra_context)
+            .await
+            .map(|errors| errors.is_empty())
+    }
+
+    /// Attempts to load a new configuration and update this running topology, like
+    /// [`Self::reload_config_and_respawn`], returning the reasons the new configuration couldn't
+    /// be loaded.
+    ///
+    /// An empty list is returned if the new configuration was loaded. Otherwise, the topology is
+    /// brought back to its previous state and the errors preventing the new configuration from
+    /// being loaded are returned.
+    ///
+    /// # Errors
+    ///
+    /// If all changes from the new configuration cannot be made, and the current configuration
+    /// cannot be fully restored, then `Err(())` is returned.
+    pub async fn reload_config_and_respawn_with_errors(
+        &mut self,
+        new_config: Config,
+        extra_context: ExtraContext,
+    ) -> Result<Vec<String>, ()> {
         info!("Reloading running topology with new configuration.");
 
         if self.config.global != new_config.global {
@@ -256,7 +291,9 @@ impl RunningTopology {
                 message =
                 "Global options can't be changed while reloading config file; reload aborted. Please restart Vector to reload the configuration file."
             );
-            return Ok(false);
+            return Ok(vec![
+                "Global options can't be changed while reloading the configuration".to_owned(),
+            ]);
         }
 
         // Calculate the change between the current configuration and the new configuration, and
@@ -282,30 +319,35 @@ impl RunningTopology {
         // Try to build all of the new components coming from the new configuration.  If we can
         // successfully build them, we'll attempt to connect them up to the topology and spawn their
         // respective component tasks.
-        if let Some(mut new_pieces) = TopologyPieces::build_or_log_errors(
-            &new_config,
-            &diff,
-            buffers.clone(),
-            extra_context.clone(),
-        )
-        .await
-        {
-            // If healthchecks are configured for any of the changing/new components, try running
-            // them before moving forward with connecting and spawning.  In some cases, healthchecks
-            // failing may be configured as a non-blocking issue and so we'll still continue on.
-            if self
-                .run_healthchecks(&diff, &mut new_pieces, new_config.healthchecks)
-                .await
-            {
-                self.connect_diff(&diff, &mut new_pieces).await;
-                self.spawn_diff(&diff, new_pieces);
-                self.config = new_config;
+        let new_pieces =
+            TopologyPieces::build(&new_config, &diff, buffers.clone(), extra_context.clone()).await;
+        let errors = match new_pieces {
+            Ok(mut new_pieces) => {
+                // If healthchecks are configured for any of the changing/new components, try
+                // running them before moving forward with connecting and spawning.  In some cases,
+                // healthchecks failing may be configured as a non-blocking issue and so we'll still
+                // continue on.
+                if self
+                    .run_healthchecks(&diff, &mut new_pieces, new_config.healthchecks)
+                    .await
+                {
+                    self.connect_diff(&diff, &mut new_pieces).await;
+                    self.spawn_diff(&diff, new_pieces);
+                    self.config = new_config;
 
-                info!("New configuration loaded successfully.");
+                    info!("New configuration loaded successfully.");
 
-                return Ok(true);
+                    return Ok(Vec::new());
+                }
+                vec!["Sinks unhealthy".to_owned()]
             }
-        }
+            Err(errors) => {
+                for error in &errors {
+                    error!(message = "Configuration error.", %error);
+                }
+                errors
+            }
+        };
 
         // We failed to build, connect, and spawn all of the changed/new components, so we flip
         // around the configuration differential to generate all the components that we need to
@@ -326,7 +368,7 @@ impl RunningTopology {
 
                 info!("Old configuration restored successfully.");
 
-                return Ok(false);
+                return Ok(errors);
             }
         }
 
@@ -735,6 +777,9 @@ impl RunningTopology {
         for key in diff.sinks.changed_and_added() {
             debug!(component = %key, "Connecting inputs for sink.");
             self.setup_inputs(key, diff, new_pieces).await;
+            if let Some(tx) = self.inputs.get(key) {
+                self.sink_inputs.insert(key.clone(), Arc::new(tx.clone()));
+            }
         }
         let added_changed_tables: Vec<&ComponentKey> = diff
             .enrichment_tables
@@ -782,6 +827,14 @@ impl RunningTopology {
 
             let mut removals = diff.sources.to_remove.clone();
             removals.extend(diff.transforms.to_remove.iter().cloned());
+
+            // Subscribers only get weak handles, so that the inputs of sinks still close once the
+            // sinks are removed.
+            let sink_inputs = self
+                .sink_inputs
+                .iter()
+                .map(|(key, sender)| (key.clone(), Arc::downgrade(sender)))
+                .collect();
             self.watch
                 .0
                 .send(TapResource {
@@ -800,6 +853,8 @@ impl RunningTopology {
                     // Note, only sources and transforms are relevant. Sinks do
                     // not have outputs to tap.
                     removals,
+                    sink_inputs,
+                    component_pauses: self.pauses.clone(),
                 })
                 .expect("Couldn't broadcast config changes.");
         }
@@ -868,6 +923,13 @@ impl RunningTopology {
             .detach_triggers
             .remove(key)
             .map(|trigger| self.detach_triggers.insert(key.clone(), trigger.into()));
+        let was_paused = self.paused_during_reload.remove(key);
+        if let Some(pause) = new_pieces.pause_signals.remove(key) {
+            if was_paused {
+                pause.pause();
+            }
+            self.pauses.insert(key.clone(), pause);
+        }
     }
 
     fn remove_outputs(&mut self, key: &ComponentKey) {
@@ -876,7 +938,16 @@ impl RunningTopology {
 
     async fn remove_inputs(&mut self, key: &ComponentKey, diff: &ConfigDiff, new_config: &Config) {
         self.inputs.remove(key);
+        self.sink_inputs.remove(key);
         self.detach_triggers.remove(key);
+        // A paused component would never drain its input, and so never shut down. If it's only
+        // being changed, its replacement starts out paused instead.
+        if let Some(pause) = self.pauses.remove(key) {
+            if pause.is_paused() && !diff.is_removed(key) {
+                self.paused_during_reload.insert(key.clone());
+            }
+            pause.release();
+        }
 
         let old_inputs = self.config.inputs_for_node(key).expect("node exists");
         let new_inputs = new_config
@@ -1,11 +1,14 @@
 use std::{
     collections::{hash_map::Entry, HashMap},
+    num::NonZeroUsize,
     pin::Pin,
     time::Duration,
 };
 
 use async_stream::stream;
 use futures::{Stream, StreamExt};
+use lru::LruCache;
+use tokio::time::Instant;
 use vector_lib::{config::LogNamespace, event::MetricValue};
 use vector_lib::{
     configurable::configurable_component,
@@ -15,7 +18,9 @@ use vector_lib::{
 use crate::{
     config::{DataType, Input, OutputId, TransformConfig, TransformContext, TransformOutput},
     event::{Event, EventMetadata},
-    internal_events::{AggregateEventRecorded, AggregateFlushed, AggregateUpdateFailed},
+    internal_events::{
+        AggregateEventRecorded, AggregateFlushed, AggregateSessionEvicted, AggregateUpdateFailed,
+    },
     schema,
     transforms::{TaskTransform, Transform},
 };
@@ -37,6 +42,27 @@ pub struct AggregateConfig {
     #[serde(default = "default_mode")]
     #[configurable(derived)]
     pub mode: AggregationMode,
+
+    /// The name of the tag whose value groups metrics into sessions.
+    ///
+    /// The metrics of a session are aggregated together until no metric arrives for the session
+    /// for `session_expiry_secs`, when the aggregate of the session is flushed. Sessions are
+    /// checked for expiry at every flush interval. Metrics without the tag are flushed at every
+    /// interval.
+    #[configurable(metadata(docs::examples = "user_id"))]
+    pub session_key: Option<String>,
+
+    /// The time after which a session that received no metrics expires, in seconds.
+    #[serde(default = "default_session_expiry_secs")]
+    #[configurable(metadata(docs::type_unit = "seconds"))]
+    pub session_expiry_secs: u64,
+
+    /// The maximum number of sessions tracked at once.
+    ///
+    /// When a new session starts while this many sessions are tracked, the least recently updated
+    /// session is flushed and evicted.
+    #[serde(default = "default_max_sessions")]
+    pub max_sessions: usize,
 }
 
 #[configurable_component]
@@ -80,6 +106,14 @@ const fn default_interval_ms() -> u64 {
     10 * 1000
 }
 
+const fn default_session_expiry_secs() -> u64 {
+    300
+}
+
+const fn default_max_sessions() -> usize {
+    10_000
+}
+
 impl_generate_config_from_default!(AggregateConfig);
 
 #[async_trait::async_trait]
@@ -112,17 +146,99 @@ pub struct Aggregate {
     prev_map: HashMap<MetricSeries, MetricEntry>,
     multi_map: HashMap<MetricSeries, Vec<MetricEntry>>,
     mode: AggregationMode,
+    sessions: Option<Sessions>,
+}
+
+/// The sessions of metrics sharing the value of the session key tag, in the order of their last
+/// update.
+#[derive(Debug)]
+struct Sessions {
+    key: String,
+    expiry: Duration,
+    table: LruCache<String, Session>,
+}
+
+#[derive(Debug)]
+struct Session {
+    aggregate: Aggregate,
+    last_event: Instant,
 }
 
 impl Aggregate {
     pub fn new(config: &AggregateConfig) -> crate::Result<Self> {
-        Ok(Self {
-            interval: Duration::from_millis(config.interval_ms),
+        let mut aggregate = Self::with_mode(
+            Duration::from_millis(config.interval_ms),
+            config.mode.clone(),
+        );
+
+        if let Some(key) = &config.session_key {
+            let max_sessions =
+                NonZeroUsize::new(config.max_sessions).ok_or("`max_sessions` must be non-zero")?;
+            aggregate.sessions = Some(Sessions {
+                key: key.clone(),
+                expiry: Duration::from_secs(config.session_expiry_secs),
+                table: LruCache::new(max_sessions),
+            });
+        }
+
+        Ok(aggregate)
+    }
+
+    fn with_mode(interval: Duration, mode: AggregationMode) -> Self {
+        Self {
+            interval,
             map: Default::default(),
             prev_map: Default::default(),
             multi_map: Default::default(),
-            mode: config.mode.clone(),
-        })
+            mode,
+            sessions: None,
+        }
+    }
+
+    /// Records an event into the aggregate of its session, or into the aggregate flushed at every
+    /// interval if sessions aren't enabled or the event has no session key.
+    fn record_event(&mut self, event: Event, output: &mut Vec<Event>) {
+        let Some(sessions) = self.sessions.as_mut() else {
+            return self.record(event);
+        };
+        let Some(key) = event.as_metric().tag_value(&sessions.key) else {
+            return self.record(event);
+        };
+
+        if !sessions.table.contains(&key) {
+            let session = Session {
+                aggregate: Self::with_mode(self.interval, self.mode.clone()),
+                last_event: Instant::now(),
+            };
+            if let Some((_, mut evicted)) = sessions.table.push(key.clone(), session) {
+                emit!(AggregateSessionEvicted);
+                evicted.aggregate.flush_into(output);
+            }
+        }
+
+        let session = sessions
+            .table
+            .get_mut(&key)
+            .expect("session should have been inserted");
+        session.last_event = Instant::now();
+        session.aggregate.record(event);
+    }
+
+    /// Flushes the aggregates of the sessions that received no metrics for the expiry time, or of
+    /// all the sessions if `all` is set.
+    fn flush_sessions_into(&mut self, output: &mut Vec<Event>, all: bool) {
+        let Some(sessions) = self.sessions.as_mut() else {
+            return;
+        };
+
+        while let Some((_, session)) = sessions.table.peek_lru() {
+            if !all && session.last_event.elapsed() < sessions.expiry {
+                break;
+            }
+            if let Some((_, mut session)) = sessions.table.pop_lru() {
+                session.aggregate.flush_into(output);
+            }
+        }
     }
 
     fn record(&mut self, event: Event) {
@@ -337,14 +453,16 @@ impl TaskTransform<Event> for Aggregate {
                 tokio::select! {
                     _ = flush_stream.tick() => {
                         self.flush_into(&mut output);
+                        self.flush_sessions_into(&mut output, false);
                     },
                     maybe_event = input_rx.next() => {
                         match maybe_event {
                             None => {
                                 self.flush_into(&mut output);
+                                self.flush_sessions_into(&mut output, true);
                                 done = true;
                             }
-                            Some(event) => self.record(event),
+                            Some(event) => self.record_event(event, &mut output),
                         }
                     }
                 };
@@ -363,7 +481,7 @@ mod tests {
     use futures::stream;
     use tokio::sync::mpsc;
     use tokio_stream::wrappers::ReceiverStream;
-    use vector_lib::config::ComponentKey;
+    use vector_lib::{config::ComponentKey, metric_tags};
     use vrl::value::Kind;
 
     use super::*;
@@ -400,6 +518,7 @@ mod tests {
         let mut agg = Aggregate::new(&AggregateConfig {
             interval_ms: 1000_u64,
             mode: AggregationMode::Auto,
+            ..Default::default()
         })
         .unwrap();
 
@@ -471,6 +590,7 @@ mod tests {
         let mut agg = Aggregate::new(&AggregateConfig {
             interval_ms: 1000_u64,
             mode: AggregationMode::Auto,
+            ..Default::default()
         })
         .unwrap();
 
@@ -537,6 +657,7 @@ mod tests {
         let mut agg = Aggregate::new(&AggregateConfig {
             interval_ms: 1000_u64,
             mode: AggregationMode::Count,
+            ..Default::default()
         })
         .unwrap();
 
@@ -593,6 +714,7 @@ mod tests {
         let mut agg = Aggregate::new(&AggregateConfig {
             interval_ms: 1000_u64,
             mode: AggregationMode::Max,
+            ..Default::default()
         })
         .unwrap();
 
@@ -639,6 +761,7 @@ mod tests {
         let mut agg = Aggregate::new(&AggregateConfig {
             interval_ms: 1000_u64,
             mode: AggregationMode::Min,
+            ..Default::default()
         })
         .unwrap();
 
@@ -685,6 +808,7 @@ mod tests {
         let mut agg = Aggregate::new(&AggregateConfig {
             interval_ms: 1000_u64,
             mode: AggregationMode::Diff,
+            ..Default::default()
         })
         .unwrap();
 
@@ -741,6 +865,7 @@ mod tests {
         let mut agg = Aggregate::new(&AggregateConfig {
             interval_ms: 1000_u64,
             mode: AggregationMode::Diff,
+            ..Default::default()
         })
         .unwrap();
 
@@ -776,6 +901,7 @@ mod tests {
         let mut agg = Aggregate::new(&AggregateConfig {
             interval_ms: 1000_u64,
             mode: AggregationMode::Mean,
+            ..Default::default()
         })
         .unwrap();
 
@@ -833,6 +959,7 @@ mod tests {
         let mut agg = Aggregate::new(&AggregateConfig {
             interval_ms: 1000_u64,
             mode: AggregationMode::Stdev,
+            ..Default::default()
         })
         .unwrap();
 
@@ -893,6 +1020,7 @@ mod tests {
         let mut agg = Aggregate::new(&AggregateConfig {
             interval_ms: 1000_u64,
             mode: AggregationMode::Auto,
+            ..Default::default()
         })
         .unwrap();
 
@@ -951,6 +1079,7 @@ mod tests {
         let mut agg = Aggregate::new(&AggregateConfig {
             interval_ms: 1000_u64,
             mode: AggregationMode::Auto,
+            ..Default::default()
         })
         .unwrap();
 
@@ -1001,6 +1130,78 @@ mod tests {
         assert_eq!(&summed, &out[0]);
     }
 
+    fn make_session_counter(user: Option<&'static str>, value: f64) -> Event {
+        let metric = make_metric(
+            "requests",
+            MetricKind::Incremental,
+            MetricValue::Counter { value },
+        )
+        .into_metric();
+        let tags = user.map(|user| metric_tags!("user" => user));
+        Event::Metric(metric.with_tags(tags))
+    }
+
+    fn session_config(max_sessions: usize) -> AggregateConfig {
+        AggregateConfig {
+            interval_ms: 1000_u64,
+            mode: AggregationMode::Sum,
+            session_key: Some("user".to_owned()),
+            session_expiry_secs: 60,
+            max_sessions,
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn sessions_flush_on_expiry() {
+        let mut agg = Aggregate::new(&session_config(10)).unwrap();
+        let mut out = vec![];
+
+        agg.record_event(make_session_counter(Some("a"), 1.0), &mut out);
+        agg.record_event(make_session_counter(Some("a"), 2.0), &mut out);
+        agg.record_event(make_session_counter(Some("b"), 5.0), &mut out);
+        agg.record_event(make_session_counter(None, 7.0), &mut out);
+
+        // Only metrics without a session are flushed at the interval
+        agg.flush_into(&mut out);
+        agg.flush_sessions_into(&mut out, false);
+        assert_eq!(out, vec![make_session_counter(None, 7.0)]);
+
+        out.clear();
+        tokio::time::advance(Duration::from_secs(30)).await;
+        agg.record_event(make_session_counter(Some("b"), 1.0), &mut out);
+        tokio::time::advance(Duration::from_secs(30)).await;
+        agg.flush_sessions_into(&mut out, false);
+        assert_eq!(out, vec![make_session_counter(Some("a"), 3.0)]);
+
+        out.clear();
+        tokio::time::advance(Duration::from_secs(30)).await;
+        agg.flush_sessions_into(&mut out, false);
+        assert_eq!(out, vec![make_session_counter(Some("b"), 6.0)]);
+    }
+
+    #[test]
+    fn sessions_evicted_when_full() {
+        let mut agg = Aggregate::new(&session_config(1)).unwrap();
+        let mut out = vec![];
+
+        agg.record_event(make_session_counter(Some("a"), 1.0), &mut out);
+        agg.record_event(make_session_counter(Some("a"), 2.0), &mut out);
+        assert!(out.is_empty());
+
+        // The least recently updated session is flushed when a new one starts
+        agg.record_event(make_session_counter(Some("b"), 5.0), &mut out);
+        assert_eq!(out, vec![make_session_counter(Some("a"), 3.0)]);
+
+        out.clear();
+        agg.flush_sessions_into(&mut out, true);
+        assert_eq!(out, vec![make_session_counter(Some("b"), 5.0)]);
+    }
+
+    #[test]
+    fn sessions_require_max_sessions() {
+        assert!(Aggregate::new(&session_config(0)).is_err());
+    }
+
     #[tokio::test]
     async fn transform_shutdown() {
         let agg = toml::from_str::<AggregateConfig>(
@@ -13,7 +13,10 @@ use crate::{
 };
 
 use super::{
-    common::{default_cache_config, fill_default_fields_match, CacheConfig, FieldMatchConfig},
+    common::{
+        default_cache_config, fill_default_fields_match, CacheConfig, DedupeBackend,
+        FieldMatchConfig,
+    },
     transform::Dedupe,
 };
 
@@ -29,6 +32,10 @@ pub struct DedupeConfig {
     #[configurable(derived)]
     #[serde(default = "default_cache_config")]
     pub cache: CacheConfig,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub backend: DedupeBackend,
 }
 
 impl GenerateConfig for DedupeConfig {
@@ -36,6 +43,7 @@ impl GenerateConfig for DedupeConfig {
         toml::Value::try_from(Self {
             fields: None,
             cache: default_cache_config(),
+            backend: DedupeBackend::default(),
         })
         .unwrap()
     }
@@ -45,10 +53,12 @@ impl GenerateConfig for DedupeConfig {
 #[typetag::serde(name = "dedupe")]
 impl TransformConfig for DedupeConfig {
     async fn build(&self, _context: &TransformContext) -> crate::Result<Transform> {
-        Ok(Transform::event_task(Dedupe::new(
-            self.cache.num_events,
-            fill_default_fields_match(self.fields.as_ref()),
-        )))
+        let fields = fill_default_fields_match(self.fields.as_ref());
+        let dedupe = match &self.backend {
+            DedupeBackend::Lru => Dedupe::new(self.cache.num_events, fields),
+            DedupeBackend::CuckooFilter(config) => Dedupe::new_with_cuckoo_filter(config, fields)?,
+        };
+        Ok(Transform::event_task(dedupe))
     }
 
     fn input(&self) -> Input {
@@ -72,6 +82,7 @@ impl TransformConfig for DedupeConfig {
 mod tests {
     use std::sync::Arc;
 
+    use indoc::indoc;
     use tokio::sync::mpsc;
     use tokio_stream::wrappers::ReceiverStream;
     use vector_lib::config::ComponentKey;
@@ -83,7 +94,7 @@ mod tests {
         event::{Event, LogEvent, ObjectMap, Value},
         test_util::components::assert_transform_compliance,
         transforms::{
-            dedupe::config::{CacheConfig, DedupeConfig, FieldMatchConfig},
+            dedupe::config::{CacheConfig, DedupeBackend, DedupeConfig, FieldMatchConfig},
             test::create_topology,
         },
     };
@@ -101,6 +112,7 @@ mod tests {
             cache: CacheConfig {
                 num_events: std::num::NonZeroUsize::new(num_events).expect("non-zero num_events"),
             },
+            backend: DedupeBackend::Lru,
             fields: Some(FieldMatchConfig::MatchFields(fields)),
         }
     }
@@ -117,6 +129,7 @@ mod tests {
             cache: CacheConfig {
                 num_events: std::num::NonZeroUsize::new(num_events).expect("non-zero num_events"),
             },
+            backend: DedupeBackend::Lru,
             fields: Some(FieldMatchConfig::IgnoreFields(fields)),
         }
     }
@@ -127,6 +140,18 @@ mod tests {
         basic(transform_config, "matched", "unmatched").await;
     }
 
+    #[tokio::test]
+    async fn dedupe_match_basic_cuckoo_filter() {
+        let transform_config = toml::from_str::<DedupeConfig>(indoc! {r#"
+            fields.match = ["matched"]
+            backend.type = "cuckoo_filter"
+            backend.capacity = 100
+            backend.false_positive_rate = 0.001
+        "#})
+        .unwrap();
+        basic(transform_config, "matched", "unmatched").await;
+    }
+
     #[tokio::test]
     async fn dedupe_ignore_basic() {
         let transform_config = make_ignore_transform_config(5, vec!["unmatched".into()]);
@@ -6,7 +6,7 @@ pub mod transform;
 
 #[cfg(feature = "transforms-impl-dedupe")]
 pub mod common {
-    use std::num::NonZeroUsize;
+    use std::num::{NonZeroU64, NonZeroUsize};
 
     use vector_lib::{configurable::configurable_component, lookup::lookup_v2::ConfigTargetPath};
 
@@ -27,6 +27,70 @@ pub mod common {
         }
     }
 
+    /// The data structure used to remember the events seen by the transform.
+    #[configurable_component]
+    #[derive(Clone, Debug, Default)]
+    #[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+    #[configurable(metadata(
+        docs::enum_tag_description = "The data structure used to remember the events seen."
+    ))]
+    pub enum DedupeBackend {
+        /// Remembers the last `cache.num_events` events in an LRU cache.
+        ///
+        /// Events are only dropped if they really are duplicates, but every entry holds the
+        /// matched fields of its event.
+        #[default]
+        Lru,
+
+        /// Remembers the fingerprints of events in a Cuckoo filter.
+        ///
+        /// This takes a few bytes per event, which makes it better suited to a lot of unique events
+        /// than `lru`, but a small share of new events are taken for duplicates and dropped.
+        CuckooFilter(CuckooFilterConfig),
+    }
+
+    /// Cuckoo filter configuration.
+    #[configurable_component]
+    #[derive(Clone, Debug)]
+    #[serde(deny_unknown_fields)]
+    pub struct CuckooFilterConfig {
+        /// The number of events the filter can hold.
+        #[configurable(metadata(docs::examples = 1000000))]
+        pub capacity: NonZeroUsize,
+
+        /// The share of new events that may be taken for duplicates.
+        ///
+        /// Lower rates take more memory per event, as the filter keeps longer fingerprints.
+        #[serde(default = "default_false_positive_rate")]
+        pub false_positive_rate: f64,
+
+        /// How often the filter is emptied, in seconds.
+        ///
+        /// Entries can't be evicted from the filter one by one, so events are remembered until the
+        /// next reset. The filter is also emptied early if it gets full.
+        #[serde(default = "default_reset_interval_secs")]
+        #[configurable(metadata(docs::type_unit = "seconds"))]
+        pub reset_interval_secs: NonZeroU64,
+    }
+
+    const fn default_false_positive_rate() -> f64 {
+        0.01
+    }
+
+    const fn default_reset_interval_secs() -> NonZeroU64 {
+        NonZeroU64::new(3600).expect("static non-zero number")
+    }
+
+    impl CuckooFilterConfig {
+        /// The configured false positive rate, if it's between 0 and 1.
+        pub fn false_positive_rate(&self) -> crate::Result<f64> {
+            if !(self.false_positive_rate > 0.0 && self.false_positive_rate < 1.0) {
+                return Err("`false_positive_rate` must be between 0 and 1".into());
+            }
+            Ok(self.false_positive_rate)
+        }
+    }
+
     /// Options to control what fields to match against.
     ///
     /// When no field matching configuration is specified, events are matched using the `timestamp`,
@@ -1,8 +1,14 @@
-use std::{future::ready, num::NonZeroUsize, pin::Pin};
+use std::{
+    future::ready,
+    num::NonZeroUsize,
+    pin::Pin,
+    time::{Duration, Instant},
+};
 
 use bytes::Bytes;
 use futures::{Stream, StreamExt};
 use lru::LruCache;
+use scalable_cuckoo_filter::ScalableCuckooFilter;
 use vector_lib::lookup::lookup_v2::ConfigTargetPath;
 use vrl::path::OwnedTargetPath;
 
@@ -12,12 +18,67 @@ use crate::{
     transforms::TaskTransform,
 };
 
-use super::common::FieldMatchConfig;
+use super::common::{CuckooFilterConfig, FieldMatchConfig};
 
-#[derive(Clone)]
 pub struct Dedupe {
     fields: FieldMatchConfig,
-    cache: LruCache<CacheEntry, bool>,
+    cache: Cache,
+}
+
+/// Remembers the events seen by the transform.
+enum Cache {
+    Lru(LruCache<CacheEntry, bool>),
+    CuckooFilter(ResettingCuckooFilter),
+}
+
+impl Cache {
+    /// Adds an entry to the cache, returning whether it was already there.
+    fn insert(&mut self, entry: CacheEntry) -> bool {
+        match self {
+            Self::Lru(cache) => cache.put(entry, true).is_some(),
+            Self::CuckooFilter(filter) => filter.insert(&entry),
+        }
+    }
+}
+
+/// A Cuckoo filter that is emptied every `reset_interval`, as its entries can't be evicted one by
+/// one.
+struct ResettingCuckooFilter {
+    filter: ScalableCuckooFilter<CacheEntry>,
+    capacity: usize,
+    false_positive_rate: f64,
+    reset_interval: Duration,
+    last_reset: Instant,
+}
+
+impl ResettingCuckooFilter {
+    fn new(capacity: usize, false_positive_rate: f64, reset_interval: Duration) -> Self {
+        Self {
+            filter: ScalableCuckooFilter::new(capacity, false_positive_rate),
+            capacity,
+            false_positive_rate,
+            reset_interval,
+            last_reset: Instant::now(),
+        }
+    }
+
+    fn reset(&mut self) {
+        self.filter = ScalableCuckooFilter::new(self.capacity, self.false_positive_rate);
+        self.last_reset = Instant::now();
+    }
+
+    fn insert(&mut self, entry: &CacheEntry) -> bool {
+        // The filter grows to hold more entries than its capacity, so it's emptied early instead to
+        // bound its memory.
+        if self.last_reset.elapsed() >= self.reset_interval || self.filter.len() >= self.capacity {
+            self.reset();
+        }
+        if self.filter.contains(entry) {
+            return true;
+        }
+        self.filter.insert(entry);
+        false
+    }
 }
 
 type TypeId = u8;
@@ -70,13 +131,29 @@ impl Dedupe {
     pub fn new(num_entries: NonZeroUsize, fields: FieldMatchConfig) -> Self {
         Self {
             fields,
-            cache: LruCache::new(num_entries),
+            cache: Cache::Lru(LruCache::new(num_entries)),
         }
     }
 
+    pub fn new_with_cuckoo_filter(
+        config: &CuckooFilterConfig,
+        fields: FieldMatchConfig,
+    ) -> crate::Result<Self> {
+        let reset_interval = Duration::from_secs(config.reset_interval_secs.get());
+        let filter = ResettingCuckooFilter::new(
+            config.capacity.get(),
+            config.false_positive_rate()?,
+            reset_interval,
+        );
+        Ok(Self {
+            fields,
+            cache: Cache::CuckooFilter(filter),
+        })
+    }
+
     pub fn transform_one(&mut self, event: Event) -> Option<Event> {
         let cache_entry = build_cache_entry(&event, &self.fields);
-        if self.cache.put(cache_entry, true).is_some() {
+        if self.cache.insert(cache_entry) {
             emit!(DedupeEventsDropped { count: 1 });
             None
         } else {
@@ -85,7 +162,7 @@ impl Dedupe {
     }
 }
 
-/// Takes in an Event and returns a CacheEntry to place into the LRU cache
+/// Takes in an Event and returns a CacheEntry to place into the cache
 /// containing all relevant information for the fields that need matching
 /// against according to the specified FieldMatchConfig.
 fn build_cache_entry(event: &Event, fields: &FieldMatchConfig) -> CacheEntry {
@@ -0,0 +1,203 @@
+use std::collections::{BTreeSet, HashSet};
+
+use vector_lib::config::clone_input_definitions;
+use vector_lib::configurable::configurable_component;
+use vrl::parser::ast::{Container, Expr, Literal, RootExpr};
+
+use crate::config::{
+    DataType, GenerateConfig, Input, LogNamespace, OutputId, TransformConfig, TransformContext,
+    TransformOutput,
+};
+use crate::schema;
+use crate::transforms::dynamic_route::transform::DynamicRoute;
+use crate::transforms::Transform;
+
+pub(super) const UNMATCHED_ROUTE: &str = "_unmatched";
+
+/// Configuration for the `dynamic_route` transform.
+#[configurable_component(transform(
+    "dynamic_route",
+    "Split a stream of events into sub-streams named by a VRL expression."
+))]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct DynamicRouteConfig {
+    /// The VRL expression computing the outputs to send each event to.
+    ///
+    /// It must return the name of an output, or an array of names. Each event is sent to every
+    /// output returned, and to the `_unmatched` output if the expression fails or returns no name.
+    #[configurable(metadata(docs::examples = ".level"))]
+    #[configurable(metadata(
+        docs::examples = "if .env == \"prod\" { \"production\" } else { \"staging\" }"
+    ))]
+    pub source: String,
+
+    /// The names of the outputs the expression can send events to.
+    ///
+    /// Each output can then be referenced as an input by other components with the name
+    /// `<transform_name>.<name>`. The `_unmatched` name is reserved and thus cannot be used.
+    #[configurable(metadata(docs::examples = "production", docs::examples = "staging"))]
+    pub outputs: Vec<String>,
+
+    /// Whether the expression may return names that are not listed in `outputs`.
+    ///
+    /// When enabled, events are intentionally dropped from the outputs that don't exist. When
+    /// disabled, the configuration is rejected if the expression returns the literal name of an
+    /// output that isn't listed. Names computed from events are then checked at runtime: unknown
+    /// ones are reported as errors, and events that aren't sent to any listed output are sent to
+    /// the `_unmatched` output instead.
+    #[serde(default = "crate::serde::default_true")]
+    pub allow_unknown_outputs: bool,
+}
+
+impl GenerateConfig for DynamicRouteConfig {
+    fn generate_config() -> toml::Value {
+        toml::Value::try_from(Self {
+            source: r#"if .env == "prod" { "production" } else { "staging" }"#.to_owned(),
+            outputs: vec!["production".to_owned(), "staging".to_owned()],
+            allow_unknown_outputs: true,
+        })
+        .unwrap()
+    }
+}
+
+#[async_trait::async_trait]
+#[typetag::serde(name = "dynamic_route")]
+impl TransformConfig for DynamicRouteConfig {
+    async fn build(&self, context: &TransformContext) -> crate::Result<Transform> {
+        let route = DynamicRoute::new(self, context)?;
+        Ok(Transform::synchronous(route))
+    }
+
+    fn input(&self) -> Input {
+        Input::all()
+    }
+
+    fn validate(&self, _: &schema::Definition) -> Result<(), Vec<String>> {
+        let mut errors = Vec::new();
+
+        let mut names = HashSet::new();
+        let duplicates = self
+            .outputs
+            .iter()
+            .filter(|name| !names.insert(name.as_str()))
+            .collect::<BTreeSet<_>>();
+        if !duplicates.is_empty() {
+            errors.push(format!(
+                "Found outputs with duplicate names: {:?}",
+                duplicates
+            ));
+        }
+
+        if names.contains(UNMATCHED_ROUTE) {
+            errors.push(format!("Using reserved '{UNMATCHED_ROUTE}' name."));
+        }
+
+        if !self.allow_unknown_outputs {
+            let undeclared = literal_names(&self.source)
+                .into_iter()
+                .filter(|name| !names.contains(name.as_str()))
+                .collect::<BTreeSet<_>>();
+            if !undeclared.is_empty() {
+                errors.push(format!(
+                    "Found references to undeclared outputs: {:?}",
+                    undeclared
+                ));
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    fn outputs(
+        &self,
+        _: vector_lib::enrichment::TableRegistry,
+        input_definitions: &[(OutputId, schema::Definition)],
+        _: LogNamespace,
+    ) -> Vec<TransformOutput> {
+        self.outputs
+            .iter()
+            .map(String::as_str)
+            .chain([UNMATCHED_ROUTE])
+            .map(|name| {
+                TransformOutput::new(
+                    DataType::all_bits(),
+                    clone_input_definitions(input_definitions),
+                )
+                .with_port(name)
+            })
+            .collect()
+    }
+
+    fn enable_concurrency(&self) -> bool {
+        true
+    }
+}
+
+/// Collects the output names the expression returns as string literals.
+///
+/// Names computed from events can't be known before running the expression.
+fn literal_names(source: &str) -> BTreeSet<String> {
+    let mut names = BTreeSet::new();
+    // Invalid expressions are reported when building the transform.
+    if let Ok(program) = vrl::parser::parse(source) {
+        if let Some(RootExpr::Expr(expr)) = program.0.last().map(|node| node.inner()) {
+            collect_literal_names(expr.inner(), &mut names);
+        }
+    }
+    names
+}
+
+fn collect_literal_names(expr: &Expr, names: &mut BTreeSet<String>) {
+    match expr {
+        Expr::Literal(literal) => match literal.inner() {
+            Literal::String(template) => {
+                if let Some(name) = template.as_literal_string() {
+                    names.insert(name.to_owned());
+                }
+            }
+            Literal::RawString(name) => {
+                names.insert(name.clone());
+            }
+            _ => {}
+        },
+        Expr::Container(container) => match container.inner() {
+            Container::Group(group) => collect_literal_names(group.inner().0.inner(), names),
+            Container::Block(block) => {
+                if let Some(expr) = block.inner().0.last() {
+                    collect_literal_names(expr.inner(), names);
+                }
+            }
+            Container::Array(array) => {
+                for expr in &array.inner().0 {
+                    collect_literal_names(expr.inner(), names);
+                }
+            }
+            Container::Object(_) => {}
+        },
+        Expr::IfStatement(statement) => {
+            let statement = statement.inner();
+            let blocks = std::iter::once(&statement.if_node).chain(&statement.else_node);
+            for block in blocks {
+                if let Some(expr) = block.inner().0.last() {
+                    collect_literal_names(expr.inner(), names);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DynamicRouteConfig;
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<DynamicRouteConfig>();
+    }
+}
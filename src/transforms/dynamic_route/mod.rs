@@ -0,0 +1,4 @@
+pub mod config;
+#[cfg(test)]
+mod tests;
+pub mod transform;
@@ -0,0 +1,124 @@
+use crate::config::{DataType, TransformOutput};
+use crate::event::{Event, LogEvent, Value};
+use std::collections::HashMap;
+
+use indoc::indoc;
+use vector_lib::transform::TransformOutputsBuf;
+
+use crate::config::TransformConfig;
+use crate::schema;
+use crate::transforms::dynamic_route::config::{DynamicRouteConfig, UNMATCHED_ROUTE};
+use crate::transforms::dynamic_route::transform::DynamicRoute;
+use crate::transforms::SyncTransform;
+
+fn get_outputs_buf() -> (Vec<&'static str>, TransformOutputsBuf) {
+    let names = vec!["a", "b", UNMATCHED_ROUTE];
+    let buf = TransformOutputsBuf::new_with_capacity(
+        names
+            .iter()
+            .map(|output_name| {
+                TransformOutput::new(DataType::all_bits(), HashMap::new())
+                    .with_port(output_name.to_owned())
+            })
+            .collect(),
+        1,
+    );
+    (names, buf)
+}
+
+/// Sends an event with the given `services` through a transform routing events to them, returning
+/// the outputs that got it.
+fn route(allow_unknown_outputs: bool, services: Value) -> Vec<&'static str> {
+    let mut config = serde_yaml::from_str::<DynamicRouteConfig>(indoc! {r#"
+            source: .services
+            outputs: [a, b]
+        "#})
+    .unwrap();
+    config.allow_unknown_outputs = allow_unknown_outputs;
+    let mut transform = DynamicRoute::new(&config, &Default::default()).unwrap();
+
+    let event = Event::Log(LogEvent::from(btreemap! {
+        "services" => services
+    }));
+    let (output_names, mut outputs) = get_outputs_buf();
+    transform.transform(event.clone(), &mut outputs);
+
+    output_names
+        .into_iter()
+        .filter(|name| {
+            let events: Vec<_> = outputs.drain_named(name).collect();
+            assert!(events.iter().all(|output| *output == event));
+            !events.is_empty()
+        })
+        .collect()
+}
+
+#[test]
+fn routes_to_outputs_named_by_expression() {
+    assert_eq!(route(true, Value::from("a")), ["a"]);
+    assert_eq!(route(true, Value::from(vec!["b", "a", "b"])), ["a", "b"]);
+    assert_eq!(route(true, Value::from(vec!["a", "c"])), ["a"]);
+    // Unknown outputs drop the event.
+    assert!(route(true, Value::from("c")).is_empty());
+    // Anything other than names goes to the unmatched output.
+    assert_eq!(route(true, Value::from(42_i64)), [UNMATCHED_ROUTE]);
+    assert_eq!(
+        route(true, Value::from(Vec::<Value>::new())),
+        [UNMATCHED_ROUTE]
+    );
+}
+
+#[test]
+fn routes_unknown_outputs_to_unmatched_when_not_allowed() {
+    assert_eq!(route(false, Value::from(vec!["a", "c"])), ["a"]);
+    assert_eq!(route(false, Value::from("c")), [UNMATCHED_ROUTE]);
+}
+
+#[test]
+fn rejects_invalid_outputs() {
+    let config = serde_yaml::from_str::<DynamicRouteConfig>(indoc! {r#"
+            source: .service
+            outputs: [a, b, a, _unmatched]
+        "#})
+    .unwrap();
+
+    let errors = config.validate(&schema::Definition::any()).unwrap_err();
+    assert_eq!(
+        errors,
+        [
+            r#"Found outputs with duplicate names: {"a"}"#,
+            "Using reserved '_unmatched' name.",
+        ]
+    );
+}
+
+#[test]
+fn rejects_undeclared_outputs_when_not_allowed() {
+    let mut config = serde_yaml::from_str::<DynamicRouteConfig>(indoc! {r#"
+            source: |
+              if .env == "prod" { ["a", "c"] } else if exists(.service) { .service } else { "d" }
+            outputs: [a, b]
+        "#})
+    .unwrap();
+    config.allow_unknown_outputs = false;
+
+    let errors = config.validate(&schema::Definition::any()).unwrap_err();
+    assert_eq!(
+        errors,
+        [r#"Found references to undeclared outputs: {"c", "d"}"#]
+    );
+
+    config.allow_unknown_outputs = true;
+    assert!(config.validate(&schema::Definition::any()).is_ok());
+}
+
+#[test]
+fn rejects_expressions_not_returning_names() {
+    let config = serde_yaml::from_str::<DynamicRouteConfig>(indoc! {r#"
+            source: "42"
+            outputs: [a]
+        "#})
+    .unwrap();
+
+    assert!(DynamicRoute::new(&config, &Default::default()).is_err());
+}
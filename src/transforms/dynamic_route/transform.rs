@@ -0,0 +1,144 @@
+use vector_lib::{compile_vrl, transform::SyncTransform, TimeZone};
+use vrl::compiler::runtime::Runtime;
+use vrl::compiler::{CompilationResult, CompileConfig, Program, TypeState};
+use vrl::diagnostic::Formatter;
+use vrl::value::Value;
+
+use crate::config::LogNamespace;
+use crate::event::{TargetEvents, VrlTarget};
+use crate::internal_events::{
+    DynamicRouteExecutionError, DynamicRouteUndeclaredOutputError, DynamicRouteUnknownOutput,
+};
+use crate::transforms::dynamic_route::config::{DynamicRouteConfig, UNMATCHED_ROUTE};
+use crate::transforms::TransformOutputsBuf;
+use crate::{config::TransformContext, event::Event};
+
+#[derive(Clone)]
+pub struct DynamicRoute {
+    program: Program,
+    outputs: Vec<String>,
+    allow_unknown_outputs: bool,
+    timezone: TimeZone,
+}
+
+impl DynamicRoute {
+    pub fn new(config: &DynamicRouteConfig, context: &TransformContext) -> crate::Result<Self> {
+        let functions = vrl::stdlib::all()
+            .into_iter()
+            .chain(vector_lib::enrichment::vrl_functions());
+        #[cfg(feature = "sources-dnstap")]
+        let functions = functions.chain(dnstap_parser::vrl_functions());
+        let functions = functions
+            .chain(vector_vrl_functions::all())
+            .collect::<Vec<_>>();
+
+        let mut compile_config = CompileConfig::default();
+        compile_config.set_custom(context.enrichment_tables.clone());
+        compile_config.set_read_only();
+
+        let CompilationResult {
+            program,
+            warnings,
+            config: _,
+        } = compile_vrl(
+            &config.source,
+            &functions,
+            &TypeState::default(),
+            compile_config,
+        )
+        .map_err(|diagnostics| {
+            Formatter::new(&config.source, diagnostics)
+                .colored()
+                .to_string()
+        })?;
+
+        let result = &program.final_type_info().result;
+        if !result.contains_bytes() && !result.contains_array() {
+            return Err(
+                "VRL route expressions must return a string or an array of strings.".into(),
+            );
+        }
+
+        if !warnings.is_empty() {
+            let warnings = Formatter::new(&config.source, warnings)
+                .colored()
+                .to_string();
+            warn!(message = "VRL compilation warning.", %warnings);
+        }
+
+        Ok(Self {
+            program,
+            outputs: config.outputs.clone(),
+            allow_unknown_outputs: config.allow_unknown_outputs,
+            timezone: context.globals.timezone(),
+        })
+    }
+
+    /// Evaluates the route expression against the event, returning the names it resolved to.
+    fn route_names(&self, event: Event) -> (Event, Vec<Value>) {
+        let log_namespace = event
+            .maybe_as_log()
+            .map(|log| log.namespace())
+            .unwrap_or(LogNamespace::Legacy);
+        let mut target = VrlTarget::new(event, self.program.info(), false);
+        let result = Runtime::default().resolve(&mut target, &self.program, &self.timezone);
+        let event = match target.into_events(log_namespace) {
+            TargetEvents::One(event) => event,
+            _ => panic!("Event was modified in a route expression. This is an internal error."),
+        };
+
+        let names = match result {
+            Ok(Value::Array(names)) => names,
+            Ok(name) => vec![name],
+            Err(error) => {
+                emit!(DynamicRouteExecutionError {
+                    error: &error.to_string()
+                });
+                Vec::new()
+            }
+        };
+        (event, names)
+    }
+}
+
+impl SyncTransform for DynamicRoute {
+    fn transform(&mut self, event: Event, output: &mut TransformOutputsBuf) {
+        let (event, names) = self.route_names(event);
+
+        let mut routes = Vec::with_capacity(names.len());
+        let mut dropped = false;
+        for name in names {
+            let Value::Bytes(name) = name else {
+                emit!(DynamicRouteExecutionError {
+                    error: &format!("route names must be strings, got {}", name.kind_str())
+                });
+                continue;
+            };
+            match self.outputs.iter().find(|output| output.as_bytes() == name) {
+                Some(route) if !routes.contains(&route) => routes.push(route),
+                Some(_) => {}
+                None if self.allow_unknown_outputs => {
+                    emit!(DynamicRouteUnknownOutput {
+                        output: &String::from_utf8_lossy(&name)
+                    });
+                    dropped = true;
+                }
+                None => emit!(DynamicRouteUndeclaredOutputError {
+                    output: &String::from_utf8_lossy(&name)
+                }),
+            }
+        }
+
+        match routes.split_last() {
+            Some((last, rest)) => {
+                for route in rest {
+                    output.push(Some(route.as_str()), event.clone());
+                }
+                output.push(Some(last.as_str()), event);
+            }
+            // The event was only sent to unknown outputs, which already accounted for it.
+            None if dropped => {}
+            None => output.push(Some(UNMATCHED_ROUTE), event),
+        }
+    }
+}
@@ -0,0 +1,398 @@
+use std::{
+    cell::RefCell,
+    sync::{Arc, Weak},
+};
+
+use mlua::FromLua;
+use snafu::{ResultExt, Snafu};
+use vector_lib::codecs::MetricTagValues;
+use vector_lib::configurable::configurable_component;
+
+use crate::config::OutputId;
+use crate::event::lua::event::LuaEvent;
+use crate::schema::Definition;
+use crate::{
+    config::{DataType, Input, TransformOutput},
+    event::Event,
+    internal_events::{LuaGcTriggered, LuaScriptError},
+    schema,
+    transforms::{FunctionTransform, OutputBuffer, Transform},
+};
+
+#[derive(Debug, Snafu)]
+pub enum BuildError {
+    #[snafu(display("Cannot evaluate Lua code in \"source\": {}", source))]
+    InvalidSource { source: mlua::Error },
+    #[snafu(display(
+        "Lua code in \"source\" must define a \"process\" function: {}",
+        source
+    ))]
+    MissingProcess { source: mlua::Error },
+}
+
+/// Configuration for version three of the `lua` transform.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct LuaConfig {
+    /// The Lua program to initialize the transform with, which must define a `process` function.
+    ///
+    /// The `process` function is called for each incoming event, with a table representing the
+    /// event as its single parameter. It returns the events to forward: either the modified event,
+    /// `nil` to drop the event, or an array of events.
+    ///
+    /// Each thread processing events runs the program in its own Lua state, so global variables
+    /// aren't shared between threads.
+    #[configurable(metadata(
+        docs::examples = "function process(event)\n\tevent.log.processed = true\n\treturn event\nend",
+    ))]
+    source: String,
+
+    /// Whether the Lua program can access the host.
+    ///
+    /// By default, the program runs in a sandbox without the `io`, `os` and `package` libraries,
+    /// and without the `require`, `dofile` and `loadfile` functions.
+    #[serde(default)]
+    unsafe_lua: bool,
+
+    /// When set to `single`, metric tag values are exposed as single strings. Tags with multiple
+    /// values show the last assigned value, and null values are ignored.
+    ///
+    /// When set to `full`, all metric tags are exposed as arrays of either string or null
+    /// values.
+    #[serde(default)]
+    metric_tag_values: MetricTagValues,
+}
+
+impl LuaConfig {
+    pub fn build(&self) -> crate::Result<Transform> {
+        let program = LuaProgram {
+            source: self.source.clone(),
+            unsafe_lua: self.unsafe_lua,
+            multi_value_tags: self.metric_tag_values == MetricTagValues::Full,
+        };
+
+        // The Lua states are created lazily on the threads processing events, so the program is
+        // checked once beforehand to report errors when building the transform.
+        let lua = program.new_state().context(InvalidSourceSnafu)?;
+        lua.globals()
+            .get::<mlua::Function>("process")
+            .context(MissingProcessSnafu)?;
+
+        Ok(Transform::function(Lua {
+            program: Arc::new(program),
+        }))
+    }
+
+    pub fn input(&self) -> Input {
+        Input::new(DataType::Metric | DataType::Log)
+    }
+
+    pub fn outputs(
+        &self,
+        input_definitions: &[(OutputId, schema::Definition)],
+    ) -> Vec<TransformOutput> {
+        // Lua causes the type definition to be reset
+        let namespaces = input_definitions
+            .iter()
+            .flat_map(|(_output, definition)| definition.log_namespaces().clone())
+            .collect();
+
+        let definition = input_definitions
+            .iter()
+            .map(|(output, _definition)| {
+                (
+                    output.clone(),
+                    Definition::default_for_namespace(&namespaces),
+                )
+            })
+            .collect();
+
+        vec![TransformOutput::new(
+            DataType::Metric | DataType::Log,
+            definition,
+        )]
+    }
+}
+
+// Lua's garbage collector sometimes seems to be not executed automatically on high event rates,
+// which leads to leak-like RAM consumption pattern. This constant sets the number of invocations of
+// a Lua state after which GC would be called, thus ensuring that the RAM usage is not too high.
+const GC_INTERVAL: usize = 16;
+
+/// The functions of the base library removed from the sandboxed Lua states, as they read files.
+const SANDBOXED_FUNCTIONS: [&str; 2] = ["dofile", "loadfile"];
+
+#[derive(Debug)]
+struct LuaProgram {
+    source: String,
+    unsafe_lua: bool,
+    multi_value_tags: bool,
+}
+
+impl LuaProgram {
+    fn new_state(&self) -> mlua::Result<mlua::Lua> {
+        let lua = if self.unsafe_lua {
+            // In order to support loading C modules in Lua, we need to create unsafe instance
+            // without debug library.
+            unsafe {
+                mlua::Lua::unsafe_new_with(mlua::StdLib::ALL_SAFE, mlua::LuaOptions::default())
+            }
+        } else {
+            // The sandboxed states only load the standard libraries that can't access the host.
+            let libs = mlua::StdLib::COROUTINE
+                | mlua::StdLib::TABLE
+                | mlua::StdLib::STRING
+                | mlua::StdLib::UTF8
+                | mlua::StdLib::MATH;
+            let lua = mlua::Lua::new_with(libs, mlua::LuaOptions::default())?;
+            for name in SANDBOXED_FUNCTIONS {
+                lua.globals().raw_set(name, mlua::Nil)?;
+            }
+            lua
+        };
+
+        lua.load(&self.source).exec()?;
+        Ok(lua)
+    }
+}
+
+struct LuaState {
+    program: Weak<LuaProgram>,
+    lua: mlua::Lua,
+    invocations_after_gc: usize,
+}
+
+thread_local! {
+    /// The Lua states of the `lua` transforms that processed events on this thread.
+    ///
+    /// Keeping a state per thread lets the transform process events concurrently without
+    /// contending on a lock.
+    static LUA_STATES: RefCell<Vec<LuaState>> = const { RefCell::new(Vec::new()) };
+}
+
+#[derive(Clone, Debug)]
+pub struct Lua {
+    program: Arc<LuaProgram>,
+}
+
+impl Lua {
+    fn process(&self, event: Event) -> mlua::Result<Vec<Event>> {
+        LUA_STATES.with_borrow_mut(|states| {
+            // Drop the states of the transforms that were stopped.
+            states.retain(|state| state.program.strong_count() > 0);
+
+            let position = states
+                .iter()
+                .position(|state| Weak::as_ptr(&state.program) == Arc::as_ptr(&self.program));
+            let state = match position {
+                Some(position) => &mut states[position],
+                None => {
+                    states.push(LuaState {
+                        program: Arc::downgrade(&self.program),
+                        lua: self.program.new_state()?,
+                        invocations_after_gc: 0,
+                    });
+                    states.last_mut().expect("state was just pushed")
+                }
+            };
+
+            let lua = &state.lua;
+            let result = lua
+                .globals()
+                .get::<mlua::Function>("process")?
+                .call::<mlua::Value>(LuaEvent {
+                    event,
+                    metric_multi_value_tags: self.program.multi_value_tags,
+                })
+                .and_then(|value| events_from_lua(value, lua));
+
+            state.invocations_after_gc += 1;
+            if state.invocations_after_gc % GC_INTERVAL == 0 {
+                emit!(LuaGcTriggered {
+                    used_memory: lua.used_memory()
+                });
+                lua.gc_collect()?;
+                state.invocations_after_gc = 0;
+            }
+
+            result
+        })
+    }
+}
+
+/// Converts the value returned by the `process` function to the events to forward.
+fn events_from_lua(value: mlua::Value, lua: &mlua::Lua) -> mlua::Result<Vec<Event>> {
+    match value {
+        mlua::Value::Nil => Ok(Vec::new()),
+        mlua::Value::Table(table) if table.raw_len() > 0 || table.is_empty() => {
+            table.sequence_values::<Event>().collect()
+        }
+        value => Event::from_lua(value, lua).map(|event| vec![event]),
+    }
+}
+
+impl FunctionTransform for Lua {
+    fn transform(&mut self, output: &mut OutputBuffer, event: Event) {
+        let source_id = event.source_id().cloned();
+        match self.process(event) {
+            Ok(events) => {
+                for mut event in events {
+                    if let Some(source_id) = &source_id {
+                        event.set_source_id(Arc::clone(source_id));
+                    }
+                    output.push(event);
+                }
+            }
+            Err(error) => emit!(LuaScriptError { error }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        event::{LogEvent, Value},
+        transforms::test::transform_one,
+    };
+
+    fn build(source: &str, unsafe_lua: bool) -> crate::Result<Transform> {
+        LuaConfig {
+            source: source.to_string(),
+            unsafe_lua,
+            metric_tag_values: Default::default(),
+        }
+        .build()
+    }
+
+    fn transform(source: &str, unsafe_lua: bool, event: Event) -> Vec<Event> {
+        let mut transform = build(source, unsafe_lua).unwrap().into_function();
+        let mut output = OutputBuffer::with_capacity(1);
+        transform.transform(&mut output, event);
+        output.into_events().collect()
+    }
+
+    #[test]
+    fn lua_modifies_event() {
+        let mut transform = build(
+            r#"
+            function process(event)
+                event.log.hello = "goodbye"
+                event.log.count = nil
+                return event
+            end
+            "#,
+            false,
+        )
+        .unwrap()
+        .into_function();
+
+        let mut log = LogEvent::from("program me");
+        log.insert("count", 1);
+        let event = transform_one(transform.as_mut(), log.into()).unwrap();
+
+        assert_eq!(event.as_log()["hello"], Value::from("goodbye"));
+        assert_eq!(event.as_log()["message"], Value::from("program me"));
+        assert!(event.as_log().get("count").is_none());
+    }
+
+    #[test]
+    fn lua_drops_event() {
+        let events = transform(
+            r#"
+            function process(event)
+                if event.log.drop then
+                    return nil
+                end
+                return event
+            end
+            "#,
+            false,
+            LogEvent::from_iter([("drop".to_string(), Value::from(true))]).into(),
+        );
+
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn lua_fans_out_events() {
+        let events = transform(
+            r#"
+            function process(event)
+                local events = {}
+                for word in string.gmatch(event.log.message, "%S+") do
+                    table.insert(events, { log = { message = word } })
+                end
+                return events
+            end
+            "#,
+            false,
+            LogEvent::from("hello lua world").into(),
+        );
+
+        let messages = events
+            .iter()
+            .map(|event| event.as_log()["message"].clone())
+            .collect::<Vec<_>>();
+        assert_eq!(messages, ["hello", "lua", "world"].map(Value::from));
+    }
+
+    #[test]
+    fn lua_keeps_state_between_events() {
+        let mut transform = build(
+            r#"
+            count = 0
+            function process(event)
+                count = count + 1
+                event.log.count = count
+                return event
+            end
+            "#,
+            false,
+        )
+        .unwrap()
+        .into_function();
+
+        for count in 1..=3 {
+            let event = transform_one(transform.as_mut(), LogEvent::from("hi").into()).unwrap();
+            assert_eq!(event.as_log()["count"], Value::from(count));
+        }
+    }
+
+    #[test]
+    fn lua_sandboxes_program_by_default() {
+        for source in [
+            "os.exit(1)",
+            "io.open('/etc/hosts')",
+            "require('os')",
+            "dofile('/etc/hosts')",
+        ] {
+            let source = format!("function process(event)\n{source}\nreturn event\nend");
+            let events = transform(&source, false, LogEvent::from("hi").into());
+            assert!(events.is_empty(), "{source}");
+        }
+    }
+
+    #[test]
+    fn lua_allows_host_access_when_unsafe() {
+        let events = transform(
+            r#"
+            function process(event)
+                event.log.home = os.getenv("HOME") ~= nil
+                return event
+            end
+            "#,
+            true,
+            LogEvent::from("hi").into(),
+        );
+
+        assert_eq!(events.len(), 1);
+    }
+
+    #[test]
+    fn lua_requires_process_function() {
+        let error = build("function hello() end", false).unwrap_err();
+        assert!(error.to_string().contains("\"process\""), "{error}");
+    }
+}
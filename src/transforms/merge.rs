@@ -0,0 +1,459 @@
+use std::{collections::HashMap, pin::Pin, slice};
+
+use async_stream::stream;
+use futures::{Stream, StreamExt};
+use indexmap::IndexMap;
+use vector_lib::config::LogNamespace;
+use vector_lib::configurable::configurable_component;
+use vector_lib::lookup::lookup_v2::ConfigTargetPath;
+use vrl::path::{OwnedTargetPath, PathPrefix};
+use vrl::prelude::{Collection, Kind};
+
+use crate::{
+    conditions::{Condition, ConditionalConfig, VrlConfig},
+    config::{
+        DataType, GenerateConfig, Input, OutputId, TransformConfig, TransformContext,
+        TransformOutput,
+    },
+    event::{discriminant::Discriminant, Event, LogEvent, Value},
+    internal_events::MergeBufferFull,
+    schema::Definition,
+    transforms::{TaskTransform, Transform},
+};
+
+/// Configuration for the `merge` transform.
+#[configurable_component(transform(
+    "merge",
+    "Merge consecutive log events sharing a key into a single event."
+))]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct MergeConfig {
+    /// The field holding the key of the events.
+    ///
+    /// Events with the same value for this field are merged together, independently of the
+    /// events with other values. Events without this field are merged together.
+    #[configurable(metadata(docs::examples = "transaction_id", docs::examples = "thread"))]
+    pub key_field: String,
+
+    /// A [VRL boolean expression][vrl] evaluated against each incoming event.
+    ///
+    /// When it returns `true`, the event is merged into the events buffered with the same key,
+    /// and the merged event is flushed.
+    ///
+    /// [vrl]: https://vector.dev/docs/reference/vrl/
+    #[configurable(metadata(docs::examples = "starts_with(string!(.message), \"END\")"))]
+    pub flush_condition_vrl: String,
+
+    /// The fields whose values are combined when merging events.
+    ///
+    /// The other fields of the merged event are taken from the first of the merged events.
+    #[serde(default)]
+    pub fields: Vec<MergeField>,
+
+    /// The string inserted between the values of fields merged with the `concat` strategy.
+    #[serde(default = "default_separator")]
+    pub separator: String,
+
+    /// The maximum number of events buffered for a key.
+    ///
+    /// When this many events are buffered without the flush condition matching, they are merged
+    /// and flushed anyway.
+    #[serde(default = "default_max_buffered_events")]
+    pub max_buffered_events: usize,
+}
+
+/// A field combined when merging events.
+#[configurable_component]
+#[derive(Clone, Debug)]
+pub struct MergeField {
+    /// The path of the field.
+    #[configurable(metadata(docs::examples = "message"))]
+    pub field: ConfigTargetPath,
+
+    #[configurable(derived)]
+    pub strategy: MergeStrategy,
+}
+
+/// How the values of a field are combined.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum MergeStrategy {
+    /// Join the values as strings, delimited with the `separator`.
+    Concat,
+
+    /// Add up the numeric values.
+    Sum,
+
+    /// Collect the values into an array.
+    Array,
+
+    /// Keep the last value.
+    KeepLast,
+}
+
+impl MergeStrategy {
+    fn merge(self, mut values: Vec<Value>, separator: &str) -> Option<Value> {
+        match self {
+            Self::Concat if !values.is_empty() => Some(Value::from(
+                values
+                    .iter()
+                    .map(Value::to_string_lossy)
+                    .collect::<Vec<_>>()
+                    .join(separator),
+            )),
+            Self::Sum => {
+                let mut integer = None::<i64>;
+                let mut float = None::<f64>;
+                for value in values {
+                    match value {
+                        Value::Integer(value) => {
+                            integer = Some(integer.unwrap_or_default().saturating_add(value));
+                        }
+                        Value::Float(value) => {
+                            float = Some(float.unwrap_or_default() + value.into_inner());
+                        }
+                        _ => {}
+                    }
+                }
+                match (integer, float) {
+                    (integer, Some(float)) => Some(Value::from_f64_or_zero(
+                        float + integer.unwrap_or_default() as f64,
+                    )),
+                    (integer, None) => integer.map(Value::Integer),
+                }
+            }
+            Self::Array if !values.is_empty() => Some(Value::Array(values)),
+            Self::KeepLast => values.pop(),
+            Self::Concat | Self::Array => None,
+        }
+    }
+
+    fn merged_kind(self, kind: &Kind) -> Kind {
+        let merged = match self {
+            Self::Concat => Kind::bytes(),
+            Self::Sum => match (kind.contains_integer(), kind.contains_float()) {
+                (true, true) => Kind::float().or_integer(),
+                (true, false) => Kind::integer(),
+                (false, true) => Kind::float(),
+                (false, false) => Kind::undefined(),
+            },
+            Self::Array => {
+                Kind::array(Collection::empty().with_unknown(kind.clone().without_undefined()))
+            }
+            Self::KeepLast => kind.clone(),
+        };
+
+        // The field is only merged when at least one of the events holds it.
+        if kind.contains_undefined() {
+            merged.or_undefined()
+        } else {
+            merged
+        }
+    }
+}
+
+fn default_separator() -> String {
+    "\n".to_string()
+}
+
+const fn default_max_buffered_events() -> usize {
+    1000
+}
+
+impl GenerateConfig for MergeConfig {
+    fn generate_config() -> toml::Value {
+        toml::from_str(
+            r#"key_field = "transaction_id"
+            flush_condition_vrl = "starts_with(string!(.message), \"END\")"
+            fields = [{ field = "message", strategy = "concat" }]"#,
+        )
+        .unwrap()
+    }
+}
+
+#[async_trait::async_trait]
+#[typetag::serde(name = "merge")]
+impl TransformConfig for MergeConfig {
+    async fn build(&self, context: &TransformContext) -> crate::Result<Transform> {
+        let flush_condition =
+            VrlConfig::new(&self.flush_condition_vrl).build(&context.enrichment_tables)?;
+        Ok(Transform::event_task(Merge::new(self, flush_condition)))
+    }
+
+    fn input(&self) -> Input {
+        Input::log()
+    }
+
+    fn outputs(
+        &self,
+        _: vector_lib::enrichment::TableRegistry,
+        input_definitions: &[(OutputId, Definition)],
+        _: LogNamespace,
+    ) -> Vec<TransformOutput> {
+        // Events may be merged with events from other inputs, so all of the definitions must be
+        // merged.
+        let mut definition = input_definitions
+            .iter()
+            .map(|(_output, definition)| definition.clone())
+            .reduce(Definition::merge)
+            .unwrap_or_else(Definition::any);
+
+        for MergeField { field, strategy } in &self.fields {
+            let kind = match field.0.prefix {
+                PathPrefix::Event => definition.event_kind().at_path(&field.0.path),
+                PathPrefix::Metadata => definition.metadata_kind().at_path(&field.0.path),
+            };
+            definition = definition.with_field(&field.0, strategy.merged_kind(&kind), None);
+        }
+
+        let output_definitions: HashMap<_, _> = input_definitions
+            .iter()
+            .map(|(output, _)| (output.clone(), definition.clone()))
+            .collect();
+
+        vec![TransformOutput::new(DataType::Log, output_definitions)]
+    }
+}
+
+pub struct Merge {
+    key_field: String,
+    flush_condition: Condition,
+    fields: Vec<(OwnedTargetPath, MergeStrategy)>,
+    separator: String,
+    max_buffered_events: usize,
+    groups: IndexMap<Discriminant, Vec<LogEvent>>,
+}
+
+impl Merge {
+    fn new(config: &MergeConfig, flush_condition: Condition) -> Self {
+        Self {
+            key_field: config.key_field.clone(),
+            flush_condition,
+            fields: config
+                .fields
+                .iter()
+                .map(|field| (field.field.0.clone(), field.strategy))
+                .collect(),
+            separator: config.separator.clone(),
+            max_buffered_events: config.max_buffered_events,
+            groups: IndexMap::new(),
+        }
+    }
+
+    fn transform_one(&mut self, output: &mut Vec<Event>, event: Event) {
+        let (flush, event) = self.flush_condition.check(event);
+        let event = event.into_log();
+        let key = Discriminant::from_log_event(&event, slice::from_ref(&self.key_field));
+
+        let group = self.groups.entry(key.clone()).or_default();
+        group.push(event);
+
+        let full = group.len() >= self.max_buffered_events;
+        if full && !flush {
+            emit!(MergeBufferFull {
+                max_buffered_events: self.max_buffered_events,
+            });
+        }
+        if flush || full {
+            if let Some(events) = self.groups.shift_remove(&key) {
+                output.push(self.merge(events).into());
+            }
+        }
+    }
+
+    fn flush_all_into(&mut self, output: &mut Vec<Event>) {
+        let groups = std::mem::take(&mut self.groups);
+        output.extend(groups.into_values().map(|events| self.merge(events).into()));
+    }
+
+    fn merge(&self, events: Vec<LogEvent>) -> LogEvent {
+        let mut events = events.into_iter();
+        let mut merged = events.next().expect("groups are never empty");
+        let mut values: Vec<Vec<Value>> = self
+            .fields
+            .iter()
+            .map(|(path, _)| merged.get(path).cloned().into_iter().collect())
+            .collect();
+
+        for event in events {
+            for ((path, _), values) in self.fields.iter().zip(&mut values) {
+                values.extend(event.get(path).cloned());
+            }
+            let (_, metadata) = event.into_parts();
+            merged.metadata_mut().merge(metadata);
+        }
+
+        for ((path, strategy), values) in self.fields.iter().zip(values) {
+            if let Some(value) = strategy.merge(values, &self.separator) {
+                merged.insert(path, value);
+            }
+        }
+        merged
+    }
+}
+
+impl TaskTransform<Event> for Merge {
+    fn transform(
+        self: Box<Self>,
+        mut input_rx: Pin<Box<dyn Stream<Item = Event> + Send>>,
+    ) -> Pin<Box<dyn Stream<Item = Event> + Send>>
+    where
+        Self: 'static,
+    {
+        let mut me = self;
+
+        Box::pin(stream! {
+            let mut output = Vec::new();
+            while let Some(event) = input_rx.next().await {
+                me.transform_one(&mut output, event);
+                for event in output.drain(..) {
+                    yield event;
+                }
+            }
+
+            me.flush_all_into(&mut output);
+            for event in output {
+                yield event;
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::sync::mpsc;
+    use tokio_stream::wrappers::ReceiverStream;
+
+    use super::*;
+    use crate::test_util::components::assert_transform_compliance;
+    use crate::transforms::test::create_topology;
+
+    fn config(fields: &str, max_buffered_events: usize) -> MergeConfig {
+        toml::from_str(&format!(
+            r#"key_field = "id"
+            flush_condition_vrl = "exists(.end)"
+            separator = " | "
+            max_buffered_events = {max_buffered_events}
+            fields = {fields}"#
+        ))
+        .unwrap()
+    }
+
+    fn log(id: &str, message: &str, count: Value) -> Event {
+        let mut log = LogEvent::from(message);
+        log.insert("id", id);
+        log.insert("count", count);
+        log.into()
+    }
+
+    async fn run(config: MergeConfig, events: Vec<Event>) -> Vec<LogEvent> {
+        assert_transform_compliance(async move {
+            let (tx, rx) = mpsc::channel(events.len());
+            let (topology, mut out) = create_topology(ReceiverStream::new(rx), config).await;
+
+            for event in events {
+                tx.send(event).await.unwrap();
+            }
+            drop(tx);
+
+            let mut merged = Vec::new();
+            while let Some(event) = out.recv().await {
+                merged.push(event.into_log());
+            }
+            topology.stop().await;
+            merged
+        })
+        .await
+    }
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<MergeConfig>();
+    }
+
+    #[tokio::test]
+    async fn merges_events_until_flush_condition() {
+        let config = config(
+            r#"[
+                { field = "message", strategy = "concat" },
+                { field = "count", strategy = "sum" },
+                { field = "tags", strategy = "array" },
+            ]"#,
+            10,
+        );
+
+        let mut end = log("1", "END", Value::from(3.5));
+        end.as_mut_log().insert("end", true);
+        end.as_mut_log().insert("tags", "b");
+        let mut second = log("1", "second", Value::from(2));
+        second.as_mut_log().insert("tags", "a");
+        let events = vec![
+            log("1", "BEGIN", Value::from(1)),
+            log("2", "other", Value::from(5)),
+            second,
+            end,
+        ];
+
+        let merged = run(config, events).await;
+
+        assert_eq!(merged.len(), 2);
+        assert_eq!(merged[0]["message"], "BEGIN | second | END".into());
+        assert_eq!(merged[0]["count"], Value::from(6.5));
+        assert_eq!(merged[0]["tags"], Value::from(vec!["a", "b"]));
+        assert!(merged[0].contains("end"));
+        // The group without an end is flushed when the input ends.
+        assert_eq!(merged[1]["message"], "other".into());
+        assert_eq!(merged[1]["count"], Value::from(5));
+    }
+
+    #[tokio::test]
+    async fn flushes_full_buffers() {
+        let config = config(
+            r#"[
+                { field = "message", strategy = "keep_last" },
+                { field = "count", strategy = "sum" },
+            ]"#,
+            2,
+        );
+
+        let events = vec![
+            log("1", "a", Value::from(1)),
+            log("1", "b", Value::from(2)),
+            log("1", "c", Value::from(3)),
+        ];
+
+        let merged = run(config, events).await;
+
+        assert_eq!(merged.len(), 2);
+        assert_eq!(merged[0]["message"], "b".into());
+        assert_eq!(merged[0]["count"], Value::from(3));
+        assert_eq!(merged[1]["message"], "c".into());
+        assert_eq!(merged[1]["count"], Value::from(3));
+    }
+
+    #[test]
+    fn merges_values() {
+        let values = vec![Value::from("a"), Value::from(1), Value::from(2.5)];
+
+        assert_eq!(
+            MergeStrategy::Concat.merge(values.clone(), ","),
+            Some(Value::from("a,1,2.5"))
+        );
+        assert_eq!(
+            MergeStrategy::Sum.merge(values.clone(), ","),
+            Some(Value::from(3.5))
+        );
+        assert_eq!(
+            MergeStrategy::Array.merge(values.clone(), ","),
+            Some(Value::Array(values.clone()))
+        );
+        assert_eq!(
+            MergeStrategy::KeepLast.merge(values, ","),
+            Some(Value::from(2.5))
+        );
+        assert_eq!(MergeStrategy::Sum.merge(vec![Value::from("a")], ","), None);
+        assert_eq!(MergeStrategy::Concat.merge(Vec::new(), ","), None);
+    }
+}
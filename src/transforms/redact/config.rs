@@ -0,0 +1,200 @@
+use vector_lib::config::{clone_input_definitions, LogNamespace};
+use vector_lib::configurable::configurable_component;
+use vector_lib::lookup::lookup_v2::ConfigTargetPath;
+use vrl::value::Kind;
+
+use crate::{
+    config::{
+        DataType, GenerateConfig, Input, OutputId, TransformConfig, TransformContext,
+        TransformOutput,
+    },
+    schema,
+    transforms::Transform,
+};
+
+use super::transform::Redact;
+
+/// The hash algorithm used by the `hash` strategy.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum HashAlgorithm {
+    /// SHA-256.
+    #[default]
+    Sha256,
+
+    /// BLAKE3.
+    Blake3,
+}
+
+const fn default_mask_char() -> char {
+    '*'
+}
+
+/// How redacted values are rewritten.
+#[configurable_component]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[configurable(metadata(docs::enum_tag_description = "The redaction strategy to use."))]
+pub enum RedactStrategy {
+    /// Replace characters with a mask character.
+    Mask {
+        /// The character used to replace redacted characters.
+        #[serde(default = "default_mask_char")]
+        char: char,
+
+        /// Whether to mask every character.
+        ///
+        /// By default, only letters and digits are masked, so that the format of the value, such as
+        /// the `-` in a phone number or the `@` in an email address, is preserved.
+        #[serde(default)]
+        full: bool,
+    },
+
+    /// Replace the value with the hex-encoded hash of the value.
+    ///
+    /// Equal values produce equal hashes, so redacted values can still be correlated.
+    Hash {
+        #[configurable(derived)]
+        #[serde(default)]
+        algorithm: HashAlgorithm,
+    },
+
+    /// Replace the value with a fixed string.
+    Replace {
+        /// The string that replaces redacted values.
+        #[configurable(metadata(docs::examples = "[REDACTED]"))]
+        value: String,
+    },
+
+    /// Mask all but the first and last characters of the value with `*`.
+    ///
+    /// Values too short to keep any characters hidden are masked entirely.
+    Partial {
+        /// The number of leading characters to keep.
+        #[serde(default)]
+        keep_first: usize,
+
+        /// The number of trailing characters to keep.
+        #[serde(default)]
+        keep_last: usize,
+    },
+}
+
+impl Default for RedactStrategy {
+    fn default() -> Self {
+        Self::Mask {
+            char: default_mask_char(),
+            full: false,
+        }
+    }
+}
+
+/// Configuration for the `redact` transform.
+#[configurable_component(transform(
+    "redact",
+    "Redact sensitive data from log events without running a VRL program."
+))]
+#[derive(Clone, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct RedactConfig {
+    /// The fields whose values are redacted entirely.
+    ///
+    /// Non-string values are converted to strings before being redacted, and every value
+    /// nested within an object or array field is redacted. `null` values are left unchanged.
+    #[serde(default)]
+    #[configurable(metadata(docs::examples = "user.email", docs::examples = "password"))]
+    pub fields: Vec<ConfigTargetPath>,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub strategy: RedactStrategy,
+
+    /// Regular expressions matched against every string value of the event.
+    ///
+    /// Only the matched parts of the string are redacted.
+    #[serde(default)]
+    #[configurable(metadata(docs::examples = "token=[a-zA-Z0-9]+"))]
+    pub patterns: Vec<String>,
+
+    /// Whether to detect and redact common formats of personally identifiable information in
+    /// every string value of the event.
+    ///
+    /// Email addresses, phone numbers, US Social Security numbers, and credit card numbers are
+    /// detected. Detection is heuristic, so it may miss some values or redact unrelated values
+    /// with the same format.
+    #[serde(default)]
+    pub detect_pii: bool,
+}
+
+impl GenerateConfig for RedactConfig {
+    fn generate_config() -> toml::Value {
+        toml::from_str(
+            r#"fields = ["password"]
+detect_pii = true"#,
+        )
+        .unwrap()
+    }
+}
+
+#[async_trait::async_trait]
+#[typetag::serde(name = "redact")]
+impl TransformConfig for RedactConfig {
+    async fn build(&self, _context: &TransformContext) -> crate::Result<Transform> {
+        Ok(Transform::function(Redact::new(self)?))
+    }
+
+    fn input(&self) -> Input {
+        Input::new(DataType::Log)
+    }
+
+    fn outputs(
+        &self,
+        _: vector_lib::enrichment::TableRegistry,
+        input_definitions: &[(OutputId, schema::Definition)],
+        _: LogNamespace,
+    ) -> Vec<TransformOutput> {
+        // Redacted values of the listed fields are converted to strings, so their type is no
+        // longer known.
+        let mut output_definitions = clone_input_definitions(input_definitions);
+        for definition in output_definitions.values_mut() {
+            for field in &self.fields {
+                *definition = definition.clone().with_field(&field.0, Kind::any(), None);
+            }
+        }
+
+        vec![TransformOutput::new(DataType::Log, output_definitions)]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<RedactConfig>();
+    }
+
+    #[test]
+    fn parses_strategies() {
+        let config: RedactConfig = toml::from_str(
+            r#"
+            fields = ["password"]
+            strategy.type = "partial"
+            strategy.keep_last = 4
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            config.strategy,
+            RedactStrategy::Partial {
+                keep_first: 0,
+                keep_last: 4
+            }
+        );
+
+        let config: RedactConfig = toml::from_str(r#"strategy.type = "mask""#).unwrap();
+        assert_eq!(config.strategy, RedactStrategy::default());
+    }
+}
@@ -0,0 +1,503 @@
+use std::ops::Range;
+
+use regex::{Regex, RegexSet};
+use sha2::{Digest, Sha256};
+use vrl::path::OwnedTargetPath;
+
+use crate::{
+    event::{Event, Value},
+    internal_events::RedactFieldsMasked,
+    transforms::{FunctionTransform, OutputBuffer},
+};
+
+use super::config::{HashAlgorithm, RedactConfig, RedactStrategy};
+
+/// Heuristics for personally identifiable information, checked in order, along with whether
+/// matches must pass the Luhn check. Credit card numbers and SSNs come before phone numbers so
+/// that they aren't partially matched as one.
+const PII_PATTERNS: &[(&str, bool)] = &[
+    // Email addresses.
+    (r"[A-Za-z0-9._%+-]+@[A-Za-z0-9.-]+\.[A-Za-z]{2,}", false),
+    // Credit card numbers: 13 to 19 digits, optionally grouped with spaces or dashes.
+    (r"\b\d(?:[ -]?\d){12,18}\b", true),
+    // US Social Security numbers.
+    (r"\b\d{3}-\d{2}-\d{4}\b", false),
+    // Phone numbers, with an optional country code and area code parentheses.
+    (
+        r"(?:\+\d{1,3}[ .-]?)?(?:\(\d{3}\)|\b\d{3})[ .-]?\d{3}[ .-]?\d{4}\b",
+        false,
+    ),
+];
+
+/// A pattern whose matches are redacted.
+#[derive(Clone, Debug)]
+struct Pattern {
+    regex: Regex,
+    /// Whether matches are only redacted if they pass the Luhn check, as for credit card numbers.
+    luhn: bool,
+}
+
+/// The patterns to redact, in order of precedence.
+#[derive(Clone, Debug)]
+struct Patterns {
+    /// Finds which patterns match a string in a single scan, so that the patterns themselves only
+    /// run on strings they match.
+    set: RegexSet,
+    patterns: Vec<Pattern>,
+}
+
+impl Patterns {
+    /// Returns the ranges of `text` to redact, in order. Matches of earlier patterns take
+    /// precedence, and matches overlapping an earlier one are skipped, so that redacted text is
+    /// never matched again.
+    fn find(&self, text: &str) -> Vec<Range<usize>> {
+        let mut ranges: Vec<Range<usize>> = Vec::new();
+        for index in self.set.matches(text).iter() {
+            let pattern = &self.patterns[index];
+            for found in pattern.regex.find_iter(text) {
+                let overlaps = ranges
+                    .iter()
+                    .any(|range| found.start() < range.end && range.start < found.end());
+                if found.is_empty() || overlaps || (pattern.luhn && !luhn_valid(found.as_str())) {
+                    continue;
+                }
+                ranges.push(found.range());
+            }
+        }
+        ranges.sort_by_key(|range| range.start);
+        ranges
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Redact {
+    fields: Vec<(OwnedTargetPath, String)>,
+    strategy: RedactStrategy,
+    patterns: Option<Patterns>,
+}
+
+impl Redact {
+    pub fn new(config: &RedactConfig) -> crate::Result<Self> {
+        let mut patterns = config
+            .patterns
+            .iter()
+            .map(|pattern| {
+                Regex::new(pattern)
+                    .map(|regex| Pattern { regex, luhn: false })
+                    .map_err(|error| format!("invalid pattern \"{}\": {}", pattern, error))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if config.detect_pii {
+            for (pattern, luhn) in PII_PATTERNS {
+                patterns.push(Pattern {
+                    regex: Regex::new(pattern)?,
+                    luhn: *luhn,
+                });
+            }
+        }
+
+        let patterns = if patterns.is_empty() {
+            None
+        } else {
+            Some(Patterns {
+                set: RegexSet::new(patterns.iter().map(|pattern| pattern.regex.as_str()))?,
+                patterns,
+            })
+        };
+
+        Ok(Self {
+            fields: config
+                .fields
+                .iter()
+                .map(|field| (field.0.clone(), field.to_string()))
+                .collect(),
+            strategy: config.strategy.clone(),
+            patterns,
+        })
+    }
+
+    /// Redacts every value within `value`, returning the number of values redacted.
+    fn redact_value(&self, value: &mut Value) -> usize {
+        match value {
+            Value::Null => 0,
+            Value::Object(fields) => fields
+                .values_mut()
+                .map(|value| self.redact_value(value))
+                .sum(),
+            Value::Array(values) => values
+                .iter_mut()
+                .map(|value| self.redact_value(value))
+                .sum(),
+            Value::Bytes(bytes) => {
+                *value = Value::from(redact(&self.strategy, &String::from_utf8_lossy(bytes)));
+                1
+            }
+            other => {
+                *other = Value::from(redact(&self.strategy, &other.to_string_lossy()));
+                1
+            }
+        }
+    }
+
+    /// Redacts the parts of every string within `value` that match the patterns, counting the
+    /// number of matches for each field. Values within arrays are counted for the array's field,
+    /// so that the number of distinct fields stays bounded.
+    fn redact_matches(&self, patterns: &Patterns, value: &mut Value, path: &mut String) {
+        match value {
+            Value::Object(fields) => {
+                for (key, value) in fields.iter_mut() {
+                    let len = path.len();
+                    if !path.is_empty() {
+                        path.push('.');
+                    }
+                    path.push_str(key);
+                    self.redact_matches(patterns, value, path);
+                    path.truncate(len);
+                }
+            }
+            Value::Array(values) => {
+                for value in values.iter_mut() {
+                    self.redact_matches(patterns, value, path);
+                }
+            }
+            Value::Bytes(bytes) => {
+                let text = String::from_utf8_lossy(bytes);
+                let ranges = patterns.find(&text);
+                if ranges.is_empty() {
+                    return;
+                }
+
+                let mut redacted = String::with_capacity(text.len());
+                let mut end = 0;
+                for range in &ranges {
+                    redacted.push_str(&text[end..range.start]);
+                    redacted.push_str(&redact(&self.strategy, &text[range.clone()]));
+                    end = range.end;
+                }
+                redacted.push_str(&text[end..]);
+
+                *value = Value::from(redacted);
+                emit!(RedactFieldsMasked {
+                    field: if path.is_empty() { "." } else { path.as_str() },
+                    count: ranges.len(),
+                });
+            }
+            _ => {}
+        }
+    }
+}
+
+impl FunctionTransform for Redact {
+    fn transform(&mut self, output: &mut OutputBuffer, mut event: Event) {
+        let log = event.as_mut_log();
+
+        for (path, name) in &self.fields {
+            if let Some(value) = log.get_mut(path) {
+                let count = self.redact_value(value);
+                if count > 0 {
+                    emit!(RedactFieldsMasked { field: name, count });
+                }
+            }
+        }
+
+        if let Some(patterns) = &self.patterns {
+            self.redact_matches(patterns, log.value_mut(), &mut String::new());
+        }
+
+        output.push(event);
+    }
+}
+
+/// Applies the redaction strategy to a single string.
+fn redact(strategy: &RedactStrategy, value: &str) -> String {
+    match strategy {
+        RedactStrategy::Mask { char, full } => value
+            .chars()
+            .map(|c| {
+                if *full || c.is_alphanumeric() {
+                    *char
+                } else {
+                    c
+                }
+            })
+            .collect(),
+        RedactStrategy::Hash { algorithm } => match algorithm {
+            HashAlgorithm::Sha256 => format!("{:x}", Sha256::digest(value.as_bytes())),
+            HashAlgorithm::Blake3 => blake3::hash(value.as_bytes()).to_hex().to_string(),
+        },
+        RedactStrategy::Replace { value: replacement } => replacement.clone(),
+        RedactStrategy::Partial {
+            keep_first,
+            keep_last,
+        } => {
+            let len = value.chars().count();
+            if len <= keep_first + keep_last {
+                return "*".repeat(len);
+            }
+            value
+                .chars()
+                .enumerate()
+                .map(|(index, c)| {
+                    if index < *keep_first || index >= len - keep_last {
+                        c
+                    } else {
+                        '*'
+                    }
+                })
+                .collect()
+        }
+    }
+}
+
+/// Checks a candidate credit card number with the Luhn algorithm.
+fn luhn_valid(number: &str) -> bool {
+    let digits = number
+        .chars()
+        .filter_map(|c| c.to_digit(10))
+        .collect::<Vec<_>>();
+    let sum: u32 = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(index, digit)| match index % 2 {
+            0 => *digit,
+            _ if *digit > 4 => digit * 2 - 9,
+            _ => digit * 2,
+        })
+        .sum();
+    sum % 10 == 0
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use vector_lib::lookup::lookup_v2::ConfigTargetPath;
+
+    use super::*;
+    use crate::{
+        event::LogEvent,
+        test_util::components::assert_transform_compliance,
+        transforms::test::{create_topology, transform_one},
+    };
+
+    fn path(path: &str) -> ConfigTargetPath {
+        ConfigTargetPath::try_from(path.to_string()).unwrap()
+    }
+
+    fn config(strategy: RedactStrategy) -> RedactConfig {
+        RedactConfig {
+            fields: vec![path("secret")],
+            strategy,
+            ..Default::default()
+        }
+    }
+
+    fn redact_event(config: RedactConfig, fields: serde_json::Value) -> serde_json::Value {
+        let mut transform = Redact::new(&config).unwrap();
+        let event = Event::from(LogEvent::try_from(fields).unwrap());
+        let output = transform_one(&mut transform, event).unwrap();
+        serde_json::to_value(output.as_log().value()).unwrap()
+    }
+
+    #[test]
+    fn mask_strategy() {
+        let masked = redact_event(
+            config(RedactStrategy::default()),
+            json!({ "secret": "555-12-3456", "other": "kept" }),
+        );
+        assert_eq!(masked, json!({ "secret": "***-**-****", "other": "kept" }));
+
+        let masked = redact_event(
+            config(RedactStrategy::Mask {
+                char: '#',
+                full: true,
+            }),
+            json!({ "secret": "555-12-3456" }),
+        );
+        assert_eq!(masked, json!({ "secret": "###########" }));
+    }
+
+    #[test]
+    fn hash_strategy() {
+        let hashed = redact_event(
+            config(RedactStrategy::Hash {
+                algorithm: HashAlgorithm::Sha256,
+            }),
+            json!({ "secret": "hello" }),
+        );
+        assert_eq!(
+            hashed,
+            json!({ "secret": "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824" })
+        );
+
+        let hashed = redact_event(
+            config(RedactStrategy::Hash {
+                algorithm: HashAlgorithm::Blake3,
+            }),
+            json!({ "secret": "hello" }),
+        );
+        assert_eq!(
+            hashed,
+            json!({ "secret": blake3::hash(b"hello").to_hex().to_string() })
+        );
+    }
+
+    #[test]
+    fn replace_strategy() {
+        let replaced = redact_event(
+            config(RedactStrategy::Replace {
+                value: "[REDACTED]".into(),
+            }),
+            json!({ "secret": 1234 }),
+        );
+        assert_eq!(replaced, json!({ "secret": "[REDACTED]" }));
+    }
+
+    #[test]
+    fn partial_strategy() {
+        let strategy = RedactStrategy::Partial {
+            keep_first: 2,
+            keep_last: 4,
+        };
+        let partial = redact_event(
+            config(strategy.clone()),
+            json!({ "secret": "4111111111111111" }),
+        );
+        assert_eq!(partial, json!({ "secret": "41**********1111" }));
+
+        let partial = redact_event(config(strategy), json!({ "secret": "short" }));
+        assert_eq!(partial, json!({ "secret": "*****" }));
+    }
+
+    #[test]
+    fn nested_field_paths() {
+        let config = RedactConfig {
+            fields: vec![path("user.email"), path("tokens"), path("missing.field")],
+            strategy: RedactStrategy::Replace { value: "x".into() },
+            ..Default::default()
+        };
+        let redacted = redact_event(
+            config,
+            json!({
+                "user": { "email": "a@b.c", "name": "kept" },
+                "tokens": [{ "value": "t1" }, "t2", null],
+            }),
+        );
+        assert_eq!(
+            redacted,
+            json!({
+                "user": { "email": "x", "name": "kept" },
+                "tokens": [{ "value": "x" }, "x", null],
+            })
+        );
+    }
+
+    #[test]
+    fn patterns() {
+        let config = RedactConfig {
+            patterns: vec!["token=[a-z0-9]+".into()],
+            strategy: RedactStrategy::Replace {
+                value: "token=***".into(),
+            },
+            ..Default::default()
+        };
+        let redacted = redact_event(
+            config,
+            json!({ "message": "auth token=abc123 accepted", "nested": { "url": "/?token=xyz" } }),
+        );
+        assert_eq!(
+            redacted,
+            json!({ "message": "auth token=*** accepted", "nested": { "url": "/?token=***" } })
+        );
+    }
+
+    #[test]
+    fn earlier_patterns_take_precedence() {
+        let config = RedactConfig {
+            patterns: vec!["id=[0-9]+".into(), "[0-9]{3}".into()],
+            strategy: RedactStrategy::Replace {
+                value: "<x>".into(),
+            },
+            ..Default::default()
+        };
+        let redacted = redact_event(config, json!({ "message": "id=123456 code 789" }));
+        assert_eq!(redacted, json!({ "message": "<x> code <x>" }));
+    }
+
+    #[test]
+    fn rejects_invalid_pattern() {
+        let config = RedactConfig {
+            patterns: vec!["(unclosed".into()],
+            ..Default::default()
+        };
+        assert!(Redact::new(&config).is_err());
+    }
+
+    #[test]
+    fn detects_pii() {
+        let config = RedactConfig {
+            detect_pii: true,
+            strategy: RedactStrategy::Replace {
+                value: "<pii>".into(),
+            },
+            ..Default::default()
+        };
+        let redacted = redact_event(
+            config,
+            json!({
+                "email": "contact jane.doe+logs@example.co.uk now",
+                "phones": ["call (555) 123-4567", "or +1 555.123.4567", "or 5551234567"],
+                "ssn": "ssn 123-45-6789",
+                "card": "card 4111 1111 1111 1111 and 4111-1111-1111-1111",
+                "not_card": "order 1234567890123",
+                "version": "v1.2.3",
+            }),
+        );
+        assert_eq!(
+            redacted,
+            json!({
+                "email": "contact <pii> now",
+                "phones": ["call <pii>", "or <pii>", "or <pii>"],
+                "ssn": "ssn <pii>",
+                "card": "card <pii> and <pii>",
+                "not_card": "order 1234567890123",
+                "version": "v1.2.3",
+            })
+        );
+    }
+
+    #[test]
+    fn luhn() {
+        assert!(luhn_valid("4111 1111 1111 1111"));
+        assert!(luhn_valid("5500-0000-0000-0004"));
+        assert!(!luhn_valid("1234567890123"));
+    }
+
+    #[tokio::test]
+    async fn emits_internal_events() {
+        assert_transform_compliance(async move {
+            let config = RedactConfig {
+                fields: vec![path("secret")],
+                detect_pii: true,
+                ..Default::default()
+            };
+            let (tx, rx) = tokio::sync::mpsc::channel(1);
+            let (topology, mut out) =
+                create_topology(tokio_stream::wrappers::ReceiverStream::new(rx), config).await;
+
+            let event = Event::from(
+                LogEvent::try_from(json!({ "secret": "abc", "message": "mail a@b.io" })).unwrap(),
+            );
+            tx.send(event).await.unwrap();
+            let output = out.recv().await.unwrap();
+            assert_eq!(output.as_log()["secret"], "***".into());
+            assert_eq!(output.as_log()["message"], "mail *@*.**".into());
+
+            drop(tx);
+            topology.stop().await;
+            assert_eq!(out.recv().await, None);
+        })
+        .await;
+    }
+}
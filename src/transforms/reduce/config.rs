@@ -15,7 +15,7 @@ use crate::config::{
     TransformOutput,
 };
 use crate::schema::Definition;
-use crate::transforms::reduce::merge_strategy::MergeStrategy;
+use crate::transforms::reduce::merge_strategy::{percentile_field, MergeStrategy};
 use crate::transforms::{reduce::transform::Reduce, Transform};
 
 /// Configuration for the `reduce` transform.
@@ -51,6 +51,10 @@ pub struct ReduceConfig {
     pub flush_period_ms: Duration,
 
     /// The maximum number of events to group together.
+    ///
+    /// A group is flushed as soon as it holds this many events, whether or not it expired. Groups
+    /// with fewer events are still flushed by `expire_after_ms`, `end_every_period_ms`, and the
+    /// `starts_when` and `ends_when` conditions.
     pub max_events: Option<NonZeroUsize>,
 
     /// An ordered list of fields by which to group events.
@@ -209,6 +213,22 @@ impl TransformConfig for ReduceConfig {
                     }
                     Kind::array(Collection::empty().with_unknown(array_elements))
                 }
+                MergeStrategy::Percentiles { percentiles, .. } => {
+                    // the field is replaced by a float field for each percentile
+                    if input_kind.contains_integer() || input_kind.contains_float() {
+                        for percentile in percentiles {
+                            let field = percentile_field(&key.to_string(), *percentile);
+                            if let Ok(path) = parse_target_path(&field) {
+                                schema_definition = schema_definition.with_field(
+                                    &path,
+                                    Kind::float().or_undefined(),
+                                    None,
+                                );
+                            }
+                        }
+                    }
+                    Kind::undefined()
+                }
             };
 
             // all of the merge strategies are optional. They won't produce a value unless a value actually exists
@@ -4,7 +4,9 @@ use crate::event::{LogEvent, Value};
 use bytes::{Bytes, BytesMut};
 use chrono::{DateTime, Utc};
 use dyn_clone::DynClone;
+use hdrhistogram::Histogram;
 use ordered_float::NotNan;
+use tdigest::TDigest;
 use vector_lib::configurable::configurable_component;
 use vrl::path::OwnedTargetPath;
 
@@ -51,6 +53,58 @@ pub enum MergeStrategy {
 
     /// Create a flattened array of all unique values.
     FlatUnique,
+
+    /// Estimate percentiles of all numeric values.
+    ///
+    /// The field itself is replaced by a field for each percentile, named after the field with the
+    /// percentile as a suffix. For example, the 95th percentile of `latency` is added as
+    /// `latency_p95`, and the 99.9th as `latency_p99_9`.
+    Percentiles {
+        /// The percentiles to estimate, between 0 and 100.
+        percentiles: Vec<f64>,
+
+        #[configurable(derived)]
+        #[serde(default)]
+        digest_type: DigestType,
+
+        /// The maximum number of centroids kept by the t-digest of each group.
+        ///
+        /// Bounds the memory used by each group, at the cost of accuracy. Only used by the
+        /// `t_digest` digest type.
+        #[serde(default = "default_max_size")]
+        max_size: usize,
+    },
+}
+
+/// The data structure used to estimate percentiles.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "proptest", derive(proptest_derive::Arbitrary))]
+#[serde(rename_all = "snake_case")]
+pub enum DigestType {
+    /// A [t-digest][t_digest], which is most accurate for the extreme percentiles.
+    ///
+    /// [t_digest]: https://github.com/tdunning/t-digest
+    #[default]
+    TDigest,
+
+    /// An [HDR histogram][hdr_histogram], recording values with three significant digits.
+    ///
+    /// Values are rounded to the nearest integer, and negative values are rejected.
+    ///
+    /// [hdr_histogram]: https://hdrhistogram.github.io/HdrHistogram/
+    HdrHistogram,
+}
+
+const fn default_max_size() -> usize {
+    100
+}
+
+const HDR_SIGNIFICANT_DIGITS: u8 = 3;
+
+/// Returns the field the percentile of the field at the given path is inserted into.
+pub(crate) fn percentile_field(path: &str, percentile: f64) -> String {
+    format!("{}_p{}", path, percentile.to_string().replace('.', "_"))
 }
 
 #[derive(Debug, Clone)]
@@ -567,6 +621,111 @@ impl ReduceValueMerger for MinNumberMerger {
     }
 }
 
+#[derive(Debug, Clone)]
+enum Digest {
+    TDigest {
+        digest: TDigest,
+        // Values are merged into the digest in batches of `max_size`, which bounds the buffer too.
+        buffer: Vec<f64>,
+    },
+    HdrHistogram(Histogram<u64>),
+}
+
+#[derive(Debug, Clone)]
+struct PercentilesMerger {
+    digest: Digest,
+    percentiles: Vec<f64>,
+    max_size: usize,
+}
+
+impl PercentilesMerger {
+    fn new(percentiles: Vec<f64>, digest_type: DigestType, max_size: usize) -> Self {
+        let digest = match digest_type {
+            DigestType::TDigest => Digest::TDigest {
+                digest: TDigest::new_with_size(max_size),
+                buffer: Vec::with_capacity(max_size),
+            },
+            DigestType::HdrHistogram => Digest::HdrHistogram(
+                Histogram::new(HDR_SIGNIFICANT_DIGITS).expect("valid significant digits"),
+            ),
+        };
+        Self {
+            digest,
+            percentiles,
+            max_size,
+        }
+    }
+
+    fn record(&mut self, f: f64) -> Result<(), String> {
+        match &mut self.digest {
+            Digest::TDigest { digest, buffer } => {
+                buffer.push(f);
+                if buffer.len() >= self.max_size {
+                    *digest = digest.merge_unsorted(std::mem::take(buffer));
+                }
+            }
+            Digest::HdrHistogram(histogram) => {
+                if f < 0.0 {
+                    return Err(format!("expected non-negative value, found: '{}'", f));
+                }
+                histogram
+                    .record(f.round() as u64)
+                    .map_err(|error| format!("failed to record value '{}': {:?}", f, error))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl ReduceValueMerger for PercentilesMerger {
+    fn add(&mut self, v: Value) -> Result<(), String> {
+        match v {
+            Value::Integer(i) => self.record(i as f64),
+            Value::Float(f) => self.record(f.into_inner()),
+            _ => Err(format!(
+                "expected numeric value, found: '{}'",
+                v.to_string_lossy()
+            )),
+        }
+    }
+
+    fn insert_into(
+        self: Box<Self>,
+        path: &OwnedTargetPath,
+        v: &mut LogEvent,
+    ) -> Result<(), String> {
+        let PercentilesMerger {
+            digest,
+            percentiles,
+            ..
+        } = *self;
+        let estimates: Vec<f64> = match digest {
+            Digest::TDigest { digest, buffer } => {
+                let digest = digest.merge_unsorted(buffer);
+                percentiles
+                    .iter()
+                    .map(|p| digest.estimate_quantile(p / 100.0))
+                    .collect()
+            }
+            Digest::HdrHistogram(histogram) => percentiles
+                .iter()
+                .map(|p| histogram.value_at_quantile(p / 100.0) as f64)
+                .collect(),
+        };
+
+        let path = path.to_string();
+        for (percentile, estimate) in percentiles.into_iter().zip(estimates) {
+            let estimate = NotNan::new(estimate)
+                .map_err(|_| format!("percentile {} of '{}' is NaN", percentile, path))?;
+            v.insert(
+                percentile_field(&path, percentile).as_str(),
+                Value::Float(estimate),
+            );
+        }
+        Ok(())
+    }
+}
+
 pub trait ReduceValueMerger: std::fmt::Debug + Send + Sync + DynClone {
     fn add(&mut self, v: Value) -> Result<(), String>;
     fn insert_into(self: Box<Self>, path: &OwnedTargetPath, v: &mut LogEvent)
@@ -660,6 +819,15 @@ pub(crate) fn get_value_merger(
         MergeStrategy::Discard => Ok(Box::new(DiscardMerger::new(v))),
         MergeStrategy::Retain => Ok(Box::new(RetainMerger::new(v))),
         MergeStrategy::FlatUnique => Ok(Box::new(FlatUniqueMerger::new(v))),
+        MergeStrategy::Percentiles {
+            percentiles,
+            digest_type,
+            max_size,
+        } => {
+            let mut merger = PercentilesMerger::new(percentiles.clone(), *digest_type, *max_size);
+            merger.add(v)?;
+            Ok(Box::new(merger))
+        }
     }
 }
 
@@ -925,6 +1093,49 @@ mod test {
         }
     }
 
+    #[test]
+    fn merge_percentiles() {
+        for digest_type in [DigestType::TDigest, DigestType::HdrHistogram] {
+            let strategy = MergeStrategy::Percentiles {
+                percentiles: vec![50.0, 95.0, 99.9],
+                digest_type,
+                max_size: 10,
+            };
+            let mut merger = get_value_merger(1.into(), &strategy).unwrap();
+            for i in 2..=1000 {
+                merger.add(i.into()).unwrap();
+            }
+            assert!(merger.add("foo".into()).is_err());
+
+            let mut output = LogEvent::default();
+            merger
+                .insert_into(&owned_event_path!("latency"), &mut output)
+                .unwrap();
+            assert!(output.get("latency").is_none());
+            for (field, expected) in [
+                ("latency_p50", 500.0),
+                ("latency_p95", 950.0),
+                ("latency_p99_9", 999.0),
+            ] {
+                let Some(Value::Float(estimate)) = output.get(field) else {
+                    panic!("missing field {field}");
+                };
+                assert!(
+                    (estimate.into_inner() - expected).abs() < 10.0,
+                    "{field} is {estimate} for {digest_type:?}"
+                );
+            }
+        }
+
+        let strategy = MergeStrategy::Percentiles {
+            percentiles: vec![50.0],
+            digest_type: DigestType::HdrHistogram,
+            max_size: default_max_size(),
+        };
+        assert!(get_value_merger((-1).into(), &strategy).is_err());
+        assert!(get_value_merger("foo".into(), &strategy).is_err());
+    }
+
     fn merge(initial: Value, additional: Value, strategy: &MergeStrategy) -> Result<Value, String> {
         let mut merger = get_value_merger(initial, strategy)?;
         merger.add(additional)?;
@@ -146,7 +146,7 @@ pub struct Reduce {
 }
 
 fn validate_merge_strategies(strategies: IndexMap<KeyString, MergeStrategy>) -> crate::Result<()> {
-    for (path, _) in &strategies {
+    for (path, strategy) in &strategies {
         let contains_index = parse_target_path(path)
             .map_err(|_| format!("Could not parse path: `{path}`"))?
             .path
@@ -159,6 +159,26 @@ fn validate_merge_strategies(strategies: IndexMap<KeyString, MergeStrategy>) ->
             )
             .into());
         }
+
+        if let MergeStrategy::Percentiles {
+            percentiles,
+            max_size,
+            ..
+        } = strategy
+        {
+            if percentiles.is_empty() {
+                return Err(format!("At least one percentile must be set. Path: `{path}`").into());
+            }
+            if let Some(percentile) = percentiles.iter().find(|p| !(0.0..=100.0).contains(*p)) {
+                return Err(format!(
+                    "Percentiles must be between 0 and 100, found {percentile}. Path: `{path}`"
+                )
+                .into());
+            }
+            if *max_size == 0 {
+                return Err(format!("`max_size` must be greater than 0. Path: `{path}`").into());
+            }
+        }
     }
 
     Ok(())
@@ -753,6 +773,48 @@ max_events = 3
         .await
     }
 
+    #[tokio::test]
+    async fn max_events_or_expiry() {
+        let reduce_config = toml::from_str::<ReduceConfig>(
+            r#"
+group_by = [ "id" ]
+merge_strategies.id = "retain"
+merge_strategies.message = "array"
+max_events = 2
+expire_after_ms = 200
+flush_period_ms = 50
+            "#,
+        )
+        .unwrap();
+
+        assert_transform_compliance(async move {
+            let (tx, rx) = mpsc::channel(1);
+            let (topology, mut out) = create_topology(ReceiverStream::new(rx), reduce_config).await;
+
+            let event = |message: &str, id: &str| {
+                let mut log = LogEvent::from(message);
+                log.insert("id", id);
+                Event::from(log)
+            };
+
+            tx.send(event("test 1", "1")).await.unwrap();
+            tx.send(event("test 2", "2")).await.unwrap();
+            tx.send(event("test 3", "1")).await.unwrap();
+
+            // The full group is flushed right away, before the other one expires.
+            let output_1 = out.recv().await.unwrap().into_log();
+            assert_eq!(output_1["message"], vec!["test 1", "test 3"].into());
+
+            let output_2 = out.recv().await.unwrap().into_log();
+            assert_eq!(output_2["message"], vec!["test 2"].into());
+
+            drop(tx);
+            topology.stop().await;
+            assert_eq!(out.recv().await, None);
+        })
+        .await
+    }
+
     #[tokio::test]
     async fn arrays() {
         let reduce_config = toml::from_str::<ReduceConfig>(
@@ -943,6 +1005,60 @@ merge_strategies.bar = "concat"
         );
     }
 
+    #[test]
+    fn invalid_percentiles() {
+        let config = toml::from_str::<ReduceConfig>(indoc!(
+            r#"
+            group_by = [ "id" ]
+
+            merge_strategies.latency.percentiles = { percentiles = [50, 100.5] }
+            "#,
+        ))
+        .unwrap();
+        let error = Reduce::new(&config, &TableRegistry::default()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Percentiles must be between 0 and 100, found 100.5. Path: `latency`"
+        );
+    }
+
+    #[tokio::test]
+    async fn reduce_percentiles() {
+        let config = toml::from_str::<ReduceConfig>(indoc!(
+            r#"
+            group_by = [ "id" ]
+            max_events = 100
+
+            merge_strategies.latency.percentiles = { percentiles = [50, 99] }
+            "#,
+        ))
+        .unwrap();
+
+        assert_transform_compliance(async move {
+            let (tx, rx) = mpsc::channel(1);
+            let (topology, mut out) = create_topology(ReceiverStream::new(rx), config).await;
+
+            for latency in 1..=100 {
+                let mut event = LogEvent::from("request");
+                event.insert("id", 1);
+                event.insert("latency", latency);
+                tx.send(event.into()).await.unwrap();
+            }
+
+            let output = out.recv().await.unwrap().into_log();
+            assert!(output.get("latency").is_none());
+            let p50 = output["latency_p50"].as_float().unwrap().into_inner();
+            let p99 = output["latency_p99"].as_float().unwrap().into_inner();
+            assert!((49.0..=52.0).contains(&p50), "p50 is {p50}");
+            assert!((98.0..=100.0).contains(&p99), "p99 is {p99}");
+
+            drop(tx);
+            topology.stop().await;
+            assert_eq!(out.recv().await, None);
+        })
+        .await;
+    }
+
     #[tokio::test]
     async fn merge_objects_in_array() {
         let config = toml::from_str::<ReduceConfig>(indoc!(
@@ -380,6 +380,16 @@ impl TransformConfig for RemapConfig {
         }
     }
 
+    fn check(
+        &self,
+        enrichment_tables: TableRegistry,
+        merged_definition: &schema::Definition,
+    ) -> std::result::Result<(), Vec<String>> {
+        self.compile_vrl_program(enrichment_tables, merged_definition.clone())
+            .map(|_| ())
+            .map_err(|error| vec![error.to_string()])
+    }
+
     fn enable_concurrency(&self) -> bool {
         true
     }
@@ -5,7 +5,7 @@ use vector_lib::lookup::{lookup_v2::OptionalValuePath, owned_value_path};
 use vrl::value::Kind;
 
 use crate::{
-    conditions::AnyCondition,
+    conditions::{AnyCondition, ConditionalConfig, VrlConfig},
     config::{
         DataType, GenerateConfig, Input, OutputId, TransformConfig, TransformContext,
         TransformOutput,
@@ -91,6 +91,14 @@ pub struct SampleConfig {
 
     /// A logical condition used to exclude events from sampling.
     pub exclude: Option<AnyCondition>,
+
+    /// A VRL boolean expression evaluated against each event to decide whether it is sampled.
+    ///
+    /// Events for which the expression returns `true` are always forwarded, while the other events
+    /// are sampled at the configured `rate` or `ratio`. The outcome is counted by the
+    /// `sampled_events_total` metric, with an `outcome` tag of `always_pass` or `rate_pass`.
+    #[configurable(metadata(docs::examples = ".level == \"error\""))]
+    pub vrl_expression: Option<String>,
 }
 
 impl SampleConfig {
@@ -124,6 +132,7 @@ impl GenerateConfig for SampleConfig {
             group_by: None,
             exclude: None::<AnyCondition>,
             sample_rate_key: default_sample_rate_key(),
+            vrl_expression: None,
         })
         .unwrap()
     }
@@ -133,17 +142,26 @@ impl GenerateConfig for SampleConfig {
 #[typetag::serde(name = "sample")]
 impl TransformConfig for SampleConfig {
     async fn build(&self, context: &TransformContext) -> crate::Result<Transform> {
-        Ok(Transform::function(Sample::new(
-            Self::NAME.to_string(),
-            self.sample_rate()?,
-            self.key_field.clone(),
-            self.group_by.clone(),
-            self.exclude
-                .as_ref()
-                .map(|condition| condition.build(&context.enrichment_tables))
-                .transpose()?,
-            self.sample_rate_key.clone(),
-        )))
+        let vrl_expression = self
+            .vrl_expression
+            .as_ref()
+            .map(|source| VrlConfig::new(source.as_str()).build(&context.enrichment_tables))
+            .transpose()?;
+
+        Ok(Transform::function(
+            Sample::new(
+                Self::NAME.to_string(),
+                self.sample_rate()?,
+                self.key_field.clone(),
+                self.group_by.clone(),
+                self.exclude
+                    .as_ref()
+                    .map(|condition| condition.build(&context.enrichment_tables))
+                    .transpose()?,
+                self.sample_rate_key.clone(),
+            )
+            .with_vrl_expression(vrl_expression),
+        ))
     }
 
     fn input(&self) -> Input {
@@ -28,6 +28,7 @@ async fn emits_internal_events() {
             group_by: None,
             exclude: None,
             sample_rate_key: default_sample_rate_key(),
+            vrl_expression: None,
         };
         let (tx, rx) = mpsc::channel(1);
         let (topology, mut out) = create_topology(ReceiverStream::new(rx), config).await;
@@ -156,6 +157,43 @@ fn always_passes_events_matching_pass_list() {
     }
 }
 
+#[test]
+fn always_passes_events_matching_vrl_expression() {
+    let vrl_expression = VrlConfig {
+        source: r#".level == "error""#.to_string(),
+        runtime: Default::default(),
+    }
+    .build(&Default::default())
+    .unwrap();
+    let mut sampler = Sample::new(
+        "sample".to_string(),
+        SampleMode::new_rate(1000),
+        None,
+        None,
+        None,
+        default_sample_rate_key(),
+    )
+    .with_vrl_expression(Some(vrl_expression));
+
+    let events = |level: &str| {
+        (0..100).map(move |_| {
+            let mut log = LogEvent::from("hello");
+            log.insert("level", level);
+            Event::Log(log)
+        })
+    };
+    let errors_passed = events("error")
+        .filter_map(|event| transform_one(&mut sampler, event))
+        .count();
+    assert_eq!(errors_passed, 100);
+
+    let infos_passed = events("info")
+        .filter_map(|event| transform_one(&mut sampler, event))
+        .collect::<Vec<_>>();
+    assert_eq!(infos_passed.len(), 1);
+    assert_eq!(infos_passed[0].as_log()["sample_rate"], "1000".into());
+}
+
 #[test]
 fn handles_group_by() {
     for group_by in &[None, Some(Template::try_from("{{ other_field }}").unwrap())] {
@@ -4,7 +4,7 @@ use vector_lib::config::LegacyKey;
 use crate::{
     conditions::Condition,
     event::Event,
-    internal_events::SampleEventDiscarded,
+    internal_events::{SampleEventDiscarded, SampleEventPassed},
     sinks::prelude::TemplateRenderingError,
     template::Template,
     transforms::{FunctionTransform, OutputBuffer},
@@ -108,6 +108,7 @@ pub struct Sample {
     group_by: Option<Template>,
     exclude: Option<Condition>,
     sample_rate_key: OptionalValuePath,
+    vrl_expression: Option<Condition>,
 }
 
 impl Sample {
@@ -129,9 +130,17 @@ impl Sample {
             group_by,
             exclude,
             sample_rate_key,
+            vrl_expression: None,
         }
     }
 
+    /// Sets the VRL expression always passing the events for which it returns `true`, rather than
+    /// sampling them.
+    pub fn with_vrl_expression(mut self, vrl_expression: Option<Condition>) -> Self {
+        self.vrl_expression = vrl_expression;
+        self
+    }
+
     #[cfg(test)]
     pub fn ratio(&self) -> f64 {
         match self.rate {
@@ -143,7 +152,7 @@ impl Sample {
 
 impl FunctionTransform for Sample {
     fn transform(&mut self, output: &mut OutputBuffer, event: Event) {
-        let mut event = {
+        let event = {
             if let Some(condition) = self.exclude.as_ref() {
                 let (result, event) = condition.check(event);
                 if result {
@@ -157,6 +166,21 @@ impl FunctionTransform for Sample {
             }
         };
 
+        let mut event = match self.vrl_expression.as_ref() {
+            Some(expression) => {
+                let (result, event) = expression.check(event);
+                if result {
+                    emit!(SampleEventPassed {
+                        outcome: "always_pass"
+                    });
+                    output.push(event);
+                    return;
+                }
+                event
+            }
+            None => event,
+        };
+
         let value = self
             .key_field
             .as_ref()
@@ -217,6 +241,11 @@ impl FunctionTransform for Sample {
                     Event::Metric(_) => panic!("component can never receive metric events"),
                 };
             }
+            if self.vrl_expression.is_some() {
+                emit!(SampleEventPassed {
+                    outcome: "rate_pass"
+                });
+            }
             output.push(event);
         } else {
             emit!(SampleEventDiscarded);
@@ -26,7 +26,8 @@ pub struct ThrottleInternalMetricsConfig {
     /// metric.
     ///
     /// Note that this defaults to false because the `key` tag has potentially unbounded cardinality.
-    /// Only set this to true if you know that the number of unique keys is bounded.
+    /// Only set this to true if you know that the number of unique keys is bounded. Keys longer
+    /// than 64 bytes are truncated in the tag.
     #[serde(default)]
     pub emit_events_discarded_per_key: bool,
 }
@@ -51,6 +52,9 @@ pub struct ThrottleConfig {
     ///
     /// If left unspecified, or if the event doesn't have `key_field`, then the event is not rate
     /// limited separately.
+    ///
+    /// The bucket of each key is created when its first event comes in, and dropped once the key
+    /// has been idle for a while to keep the number of buckets bounded.
     #[configurable(metadata(docs::examples = "{{ message }}", docs::examples = "{{ hostname }}",))]
     pub key_field: Option<Template>,
 
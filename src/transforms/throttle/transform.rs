@@ -18,6 +18,13 @@ use crate::{
     transforms::TaskTransform,
 };
 
+/// The number of windows between sweeps of the rate limiter buckets.
+///
+/// The bucket of a key that has been idle for a whole window is full again, and so can be dropped
+/// and lazily created anew. Sweeping goes through every key though, so it's only done every few
+/// windows to keep it from getting in the way of events when there are a lot of keys.
+const FLUSH_KEYS_WINDOWS: u32 = 10;
+
 #[derive(Clone)]
 pub struct Throttle<C: clock::Clock<Instant = I>, I: clock::Reference> {
     pub quota: Quota,
@@ -38,7 +45,7 @@ where
         context: &TransformContext,
         clock: C,
     ) -> crate::Result<Self> {
-        let flush_keys_interval = config.window_secs;
+        let flush_keys_interval = config.window_secs * FLUSH_KEYS_WINDOWS;
 
         let threshold = match NonZeroU32::new(config.threshold) {
             Some(threshold) => threshold,
@@ -46,7 +53,7 @@ where
         };
 
         let quota = match Quota::with_period(Duration::from_secs_f64(
-            flush_keys_interval.as_secs_f64() / f64::from(threshold.get()),
+            config.window_secs.as_secs_f64() / f64::from(threshold.get()),
         )) {
             Some(quota) => quota.allow_burst(threshold),
             None => return Err(Box::new(ConfigError::NonZero)),
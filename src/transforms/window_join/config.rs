@@ -0,0 +1,212 @@
+use std::num::NonZeroUsize;
+use std::time::Duration;
+
+use serde_with::serde_as;
+use vector_lib::config::LogNamespace;
+use vector_lib::configurable::configurable_component;
+
+use crate::{
+    config::{
+        DataType, GenerateConfig, Input, OutputId, TransformConfig, TransformContext,
+        TransformOutput,
+    },
+    schema::Definition,
+    transforms::Transform,
+};
+
+use super::transform::WindowJoin;
+
+/// The kind of join to perform, which determines what happens to events without a match.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum JoinType {
+    /// Only joined events are forwarded. Unmatched events are dropped.
+    #[default]
+    Inner,
+
+    /// Joined events are forwarded, as are `left` events which expire without a match.
+    Left,
+
+    /// Joined events are forwarded, as are `right` events which expire without a match.
+    Right,
+
+    /// Joined events are forwarded, as are events from either side which expire without a match.
+    Full,
+}
+
+impl JoinType {
+    /// Whether unmatched `left` events are forwarded.
+    pub const fn keeps_left(self) -> bool {
+        matches!(self, JoinType::Left | JoinType::Full)
+    }
+
+    /// Whether unmatched `right` events are forwarded.
+    pub const fn keeps_right(self) -> bool {
+        matches!(self, JoinType::Right | JoinType::Full)
+    }
+}
+
+/// Configuration for the `window_join` transform.
+#[serde_as]
+#[configurable_component(transform(
+    "window_join",
+    "Correlate events from two streams that share the same key within a time window."
+))]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct WindowJoinConfig {
+    /// The components whose events make up the left side of the join.
+    ///
+    /// Each entry is either a component ID or the ID of a specific component output, such as
+    /// `my_route.errors`. Every entry must also be listed in `inputs`.
+    #[configurable(metadata(docs::examples = "dns_lookups"))]
+    pub left: Vec<String>,
+
+    /// The components whose events make up the right side of the join.
+    ///
+    /// Each entry is either a component ID or the ID of a specific component output, such as
+    /// `my_route.errors`. Every entry must also be listed in `inputs`.
+    #[configurable(metadata(docs::examples = "network_connections"))]
+    pub right: Vec<String>,
+
+    /// The fields which must be equal for a `left` and a `right` event to be joined.
+    ///
+    /// Events missing any of these fields never match.
+    #[configurable(metadata(docs::examples = "host", docs::examples = "ip"))]
+    pub join_key_fields: Vec<String>,
+
+    /// The maximum time between a `left` and a `right` event for them to be joined, in seconds.
+    #[serde_as(as = "serde_with::DurationSecondsWithFrac<f64>")]
+    #[configurable(metadata(docs::human_name = "Join Window"))]
+    pub window_secs: Duration,
+
+    /// The maximum number of events buffered while waiting for a match, across all keys.
+    ///
+    /// When the buffer is full, the oldest buffered event is removed to make room for the new one,
+    /// and is dropped if it was never joined. There is no limit by default.
+    #[configurable(metadata(docs::examples = 100000))]
+    pub max_buffered: Option<NonZeroUsize>,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub join_type: JoinType,
+
+    /// A prefix prepended to the name of each top-level field taken from the `left` event.
+    ///
+    /// When neither prefix is set and both events have a field with the same name, the value from
+    /// the `right` event is kept.
+    #[configurable(metadata(docs::examples = "dns_"))]
+    pub left_prefix: Option<String>,
+
+    /// A prefix prepended to the name of each top-level field taken from the `right` event.
+    #[configurable(metadata(docs::examples = "conn_"))]
+    pub right_prefix: Option<String>,
+}
+
+impl WindowJoinConfig {
+    /// Checks that every entry of `left` and `right` refers to one of `inputs`.
+    fn check_inputs<'a>(&self, inputs: impl Iterator<Item = &'a OutputId>) -> crate::Result<()> {
+        let inputs = inputs.collect::<Vec<_>>();
+        let missing = self
+            .left
+            .iter()
+            .chain(&self.right)
+            .filter(|entry| {
+                !inputs
+                    .iter()
+                    .any(|input| *entry == &input.to_string() || *entry == input.component.id())
+            })
+            .map(|entry| format!("\"{}\"", entry))
+            .collect::<Vec<_>>();
+
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(format!(
+                "`left` and `right` refer to components that aren't inputs: {}",
+                missing.join(", ")
+            )
+            .into())
+        }
+    }
+}
+
+impl GenerateConfig for WindowJoinConfig {
+    fn generate_config() -> toml::Value {
+        toml::from_str(
+            r#"left = ["left_source"]
+right = ["right_source"]
+join_key_fields = ["host"]
+window_secs = 10.0"#,
+        )
+        .unwrap()
+    }
+}
+
+#[async_trait::async_trait]
+#[typetag::serde(name = "window_join")]
+impl TransformConfig for WindowJoinConfig {
+    async fn build(&self, context: &TransformContext) -> crate::Result<Transform> {
+        // The definitions of the default output are keyed by the inputs of the transform.
+        if let Some(definitions) = context.schema_definitions.get(&None) {
+            self.check_inputs(definitions.keys())?;
+        }
+        WindowJoin::new(self).map(Transform::event_task)
+    }
+
+    fn input(&self) -> Input {
+        Input::log()
+    }
+
+    fn outputs(
+        &self,
+        _: vector_lib::enrichment::TableRegistry,
+        input_definitions: &[(OutputId, Definition)],
+        _: LogNamespace,
+    ) -> Vec<TransformOutput> {
+        // Joined events contain fields from both sides, possibly renamed, so the schema of the
+        // output can't be derived from any single input.
+        let definition = Definition::any();
+        let output_definitions = input_definitions
+            .iter()
+            .map(|(output, _)| (output.clone(), definition.clone()))
+            .collect();
+
+        vec![TransformOutput::new(DataType::Log, output_definitions)]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<WindowJoinConfig>();
+    }
+
+    #[test]
+    fn sides_must_be_inputs() {
+        let config: WindowJoinConfig = toml::from_str(
+            r#"left = ["dns", "route.errors"]
+right = ["conn"]
+join_key_fields = ["host"]
+window_secs = 10.0"#,
+        )
+        .unwrap();
+
+        let inputs = [
+            OutputId::from("dns"),
+            OutputId::from(("route".to_owned(), Some("errors".to_owned()))),
+            OutputId::from("conn"),
+        ];
+        assert!(config.check_inputs(inputs.iter()).is_ok());
+
+        let error = config.check_inputs(inputs[..2].iter()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "`left` and `right` refer to components that aren't inputs: \"conn\""
+        );
+    }
+}
@@ -0,0 +1,622 @@
+use std::collections::{hash_map::Entry, HashMap, VecDeque};
+use std::num::NonZeroUsize;
+use std::pin::Pin;
+use std::time::{Duration, Instant};
+
+use futures::Stream;
+use vector_lib::stream::expiration_map::{map_with_expiration, Emitter};
+
+use crate::{
+    event::{discriminant::Discriminant, Event, EventMetadata, LogEvent, ObjectMap, Value},
+    internal_events::WindowJoinEventsDropped,
+    transforms::TaskTransform,
+};
+
+use super::config::{JoinType, WindowJoinConfig};
+
+/// The longest time between two checks for expired events.
+const MAX_EXPIRE_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Side {
+    Left,
+    Right,
+}
+
+#[derive(Debug)]
+struct Pending {
+    event: LogEvent,
+    matched: bool,
+}
+
+/// Events waiting for a match, for a single join key.
+#[derive(Debug, Default)]
+struct Buffers {
+    left: VecDeque<Pending>,
+    right: VecDeque<Pending>,
+}
+
+impl Buffers {
+    /// Returns the buffer for `side`, followed by the buffer for the opposite side.
+    fn sides_mut(&mut self, side: Side) -> (&mut VecDeque<Pending>, &mut VecDeque<Pending>) {
+        match side {
+            Side::Left => (&mut self.left, &mut self.right),
+            Side::Right => (&mut self.right, &mut self.left),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.left.is_empty() && self.right.is_empty()
+    }
+}
+
+pub struct WindowJoin {
+    left: Vec<String>,
+    right: Vec<String>,
+    join_key_fields: Vec<String>,
+    window: Duration,
+    join_type: JoinType,
+    left_prefix: String,
+    right_prefix: String,
+    max_buffered: Option<NonZeroUsize>,
+    buffers: HashMap<Discriminant, Buffers>,
+    /// When each buffered event was received, along with where it is buffered, oldest first.
+    ///
+    /// Events are only ever removed from the front of their buffer, in the order they were
+    /// received, so the front of this queue is always the front of one of the buffers.
+    arrivals: VecDeque<(Instant, Side, Discriminant)>,
+}
+
+impl WindowJoin {
+    pub fn new(config: &WindowJoinConfig) -> crate::Result<Self> {
+        if config.left.is_empty() || config.right.is_empty() {
+            return Err("both `left` and `right` must list at least one input".into());
+        }
+        if let Some(input) = config
+            .left
+            .iter()
+            .find(|input| config.right.contains(input))
+        {
+            return Err(format!("input \"{}\" can't be on both sides of the join", input).into());
+        }
+        if config.join_key_fields.is_empty() {
+            return Err("`join_key_fields` must list at least one field".into());
+        }
+        if config.window_secs.is_zero() {
+            return Err("`window_secs` must be greater than zero".into());
+        }
+
+        Ok(Self {
+            left: config.left.clone(),
+            right: config.right.clone(),
+            join_key_fields: config.join_key_fields.clone(),
+            window: config.window_secs,
+            join_type: config.join_type,
+            left_prefix: config.left_prefix.clone().unwrap_or_default(),
+            right_prefix: config.right_prefix.clone().unwrap_or_default(),
+            max_buffered: config.max_buffered,
+            buffers: HashMap::new(),
+            arrivals: VecDeque::new(),
+        })
+    }
+
+    fn side_of(&self, event: &LogEvent) -> Option<Side> {
+        let upstream_id = event.metadata().upstream_id()?;
+        let output = upstream_id.to_string();
+        let matches = |input: &String| input == &output || input == upstream_id.component.id();
+
+        if self.left.iter().any(matches) {
+            Some(Side::Left)
+        } else if self.right.iter().any(matches) {
+            Some(Side::Right)
+        } else {
+            None
+        }
+    }
+
+    const fn keeps(&self, side: Side) -> bool {
+        match side {
+            Side::Left => self.join_type.keeps_left(),
+            Side::Right => self.join_type.keeps_right(),
+        }
+    }
+
+    fn prefix(&self, side: Side) -> &str {
+        match side {
+            Side::Left => &self.left_prefix,
+            Side::Right => &self.right_prefix,
+        }
+    }
+
+    /// Forwards an event which will never be joined, if the join type keeps it.
+    fn emit_unmatched(&self, output: &mut Vec<Event>, side: Side, event: LogEvent) {
+        if self.keeps(side) {
+            let mut fields = ObjectMap::new();
+            let metadata = insert_fields(&mut fields, self.prefix(side), event);
+            output.push(LogEvent::from_map(fields, metadata).into());
+        } else {
+            emit!(WindowJoinEventsDropped {
+                count: 1,
+                reason: "Event had no match within the join window.",
+            });
+        }
+    }
+
+    fn transform_one(&mut self, output: &mut Vec<Event>, event: Event, now: Instant) {
+        self.expire(output, now);
+
+        let event = event.into_log();
+        let Some(side) = self.side_of(&event) else {
+            emit!(WindowJoinEventsDropped {
+                count: 1,
+                reason: "Event was not received from a `left` or `right` input.",
+            });
+            return;
+        };
+
+        let has_key = self.join_key_fields.iter().all(|field| {
+            event
+                .parse_path_and_get_value(field.as_str())
+                .ok()
+                .flatten()
+                .is_some()
+        });
+        if !has_key {
+            self.emit_unmatched(output, side, event);
+            return;
+        }
+
+        if self
+            .max_buffered
+            .is_some_and(|max| self.arrivals.len() >= max.get())
+            && self.remove_oldest().is_some()
+        {
+            emit!(WindowJoinEventsDropped {
+                count: 1,
+                reason: "Join buffer was full.",
+            });
+        }
+
+        let key = Discriminant::from_log_event(&event, &self.join_key_fields);
+        self.arrivals.push_back((now, side, key.clone()));
+        let (own, other) = self.buffers.entry(key).or_default().sides_mut(side);
+
+        let mut matched = false;
+        for pending in other.iter_mut() {
+            matched = true;
+            pending.matched = true;
+
+            let (left, right) = match side {
+                Side::Left => (&event, &pending.event),
+                Side::Right => (&pending.event, &event),
+            };
+            output.push(join(left, right, &self.left_prefix, &self.right_prefix).into());
+        }
+
+        own.push_back(Pending { event, matched });
+    }
+
+    /// Removes the oldest buffered event, returning it along with its side if it was never joined.
+    fn remove_oldest(&mut self) -> Option<(Side, LogEvent)> {
+        let (_, side, key) = self.arrivals.pop_front()?;
+        let Entry::Occupied(mut entry) = self.buffers.entry(key) else {
+            unreachable!("every arrival has a buffered event");
+        };
+        let pending = entry
+            .get_mut()
+            .sides_mut(side)
+            .0
+            .pop_front()
+            .expect("every arrival has a buffered event");
+        if entry.get().is_empty() {
+            entry.remove();
+        }
+        (!pending.matched).then_some((side, pending.event))
+    }
+
+    /// Removes events that have been buffered for longer than the window.
+    fn expire(&mut self, output: &mut Vec<Event>, now: Instant) {
+        while self
+            .arrivals
+            .front()
+            .is_some_and(|(received, _, _)| now.duration_since(*received) >= self.window)
+        {
+            if let Some((side, event)) = self.remove_oldest() {
+                self.emit_unmatched(output, side, event);
+            }
+        }
+    }
+
+    /// Releases all buffered events, as the input has ended.
+    fn flush_all(&mut self, output: &mut Vec<Event>) {
+        self.arrivals.clear();
+        let buffers = std::mem::take(&mut self.buffers);
+        for (_, buffers) in buffers {
+            for (side, buffer) in [(Side::Left, buffers.left), (Side::Right, buffers.right)] {
+                for pending in buffer.into_iter().filter(|pending| !pending.matched) {
+                    self.emit_unmatched(output, side, pending.event);
+                }
+            }
+        }
+    }
+}
+
+/// Moves the top-level fields of `event` into `fields`, with `prefix` prepended to their names,
+/// returning the metadata of the event.
+fn insert_fields(fields: &mut ObjectMap, prefix: &str, event: LogEvent) -> EventMetadata {
+    let (value, metadata) = event.into_parts();
+    match value {
+        Value::Object(object) => {
+            for (name, value) in object {
+                fields.insert(format!("{}{}", prefix, name).into(), value);
+            }
+        }
+        value => {
+            fields.insert(format!("{}message", prefix).into(), value);
+        }
+    }
+    metadata
+}
+
+/// Builds a single event out of a `left` and a `right` event.
+fn join(left: &LogEvent, right: &LogEvent, left_prefix: &str, right_prefix: &str) -> LogEvent {
+    let mut fields = ObjectMap::new();
+    let mut metadata = insert_fields(&mut fields, left_prefix, left.clone());
+    metadata.merge(insert_fields(&mut fields, right_prefix, right.clone()));
+    LogEvent::from_map(fields, metadata)
+}
+
+fn emit_all(output: Vec<Event>, emitter: &mut Emitter<Event>) {
+    for event in output {
+        emitter.emit(event);
+    }
+}
+
+impl TaskTransform<Event> for WindowJoin {
+    fn transform(
+        self: Box<Self>,
+        input_rx: Pin<Box<dyn Stream<Item = Event> + Send>>,
+    ) -> Pin<Box<dyn Stream<Item = Event> + Send>>
+    where
+        Self: 'static,
+    {
+        let expire_interval = self.window.min(MAX_EXPIRE_INTERVAL);
+        Box::pin(map_with_expiration(
+            self,
+            input_rx,
+            expire_interval,
+            |me, event, emitter| {
+                let mut output = Vec::new();
+                me.transform_one(&mut output, event, Instant::now());
+                emit_all(output, emitter);
+            },
+            |me, emitter| {
+                let mut output = Vec::new();
+                me.expire(&mut output, Instant::now());
+                emit_all(output, emitter);
+            },
+            |me, emitter| {
+                let mut output = Vec::new();
+                me.flush_all(&mut output);
+                emit_all(output, emitter);
+            },
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use serde_json::json;
+
+    use super::*;
+    use crate::config::OutputId;
+
+    fn config(join_type: JoinType) -> WindowJoinConfig {
+        WindowJoinConfig {
+            left: vec!["dns".into()],
+            right: vec!["conn".into()],
+            join_key_fields: vec!["host".into()],
+            window_secs: Duration::from_secs(10),
+            max_buffered: None,
+            join_type,
+            left_prefix: None,
+            right_prefix: None,
+        }
+    }
+
+    fn event(input: &str, fields: serde_json::Value) -> Event {
+        let upstream_id = match input.split_once('.') {
+            Some((component, port)) => {
+                OutputId::from((component.to_owned(), Some(port.to_owned())))
+            }
+            None => OutputId::from(input),
+        };
+        let mut log = LogEvent::try_from(fields).unwrap();
+        log.metadata_mut().set_upstream_id(Arc::new(upstream_id));
+        log.into()
+    }
+
+    fn values(output: Vec<Event>) -> Vec<serde_json::Value> {
+        output
+            .into_iter()
+            .map(|event| serde_json::to_value(event.into_log().value()).unwrap())
+            .collect()
+    }
+
+    /// Sends `events` through a join, each `step` apart, then lets all buffered events expire.
+    fn run(config: WindowJoinConfig, events: Vec<Event>, step: Duration) -> Vec<serde_json::Value> {
+        let mut join = WindowJoin::new(&config).unwrap();
+        let mut output = Vec::new();
+        let mut now = Instant::now();
+        for event in events {
+            join.transform_one(&mut output, event, now);
+            now += step;
+        }
+        join.expire(&mut output, now + config.window_secs);
+        assert!(join.buffers.is_empty());
+        assert!(join.arrivals.is_empty());
+        values(output)
+    }
+
+    fn correlated_events() -> Vec<Event> {
+        vec![
+            event("dns", json!({ "host": "a", "query": "example.com" })),
+            event("dns", json!({ "host": "b", "query": "vector.dev" })),
+            event("conn", json!({ "host": "a", "port": 443 })),
+            event("conn", json!({ "host": "c", "port": 80 })),
+        ]
+    }
+
+    #[test]
+    fn inner_join() {
+        let output = run(
+            config(JoinType::Inner),
+            correlated_events(),
+            Duration::from_secs(1),
+        );
+        assert_eq!(
+            output,
+            vec![json!({ "host": "a", "query": "example.com", "port": 443 })]
+        );
+    }
+
+    #[test]
+    fn left_join() {
+        let output = run(
+            config(JoinType::Left),
+            correlated_events(),
+            Duration::from_secs(1),
+        );
+        assert_eq!(
+            output,
+            vec![
+                json!({ "host": "a", "query": "example.com", "port": 443 }),
+                json!({ "host": "b", "query": "vector.dev" }),
+            ]
+        );
+    }
+
+    #[test]
+    fn right_join() {
+        let output = run(
+            config(JoinType::Right),
+            correlated_events(),
+            Duration::from_secs(1),
+        );
+        assert_eq!(
+            output,
+            vec![
+                json!({ "host": "a", "query": "example.com", "port": 443 }),
+                json!({ "host": "c", "port": 80 }),
+            ]
+        );
+    }
+
+    #[test]
+    fn full_join() {
+        let mut output = run(
+            config(JoinType::Full),
+            correlated_events(),
+            Duration::from_secs(1),
+        );
+        let joined = output.remove(0);
+        output.sort_by_key(|value| value["host"].as_str().unwrap().to_owned());
+        assert_eq!(
+            joined,
+            json!({ "host": "a", "query": "example.com", "port": 443 })
+        );
+        assert_eq!(
+            output,
+            vec![
+                json!({ "host": "b", "query": "vector.dev" }),
+                json!({ "host": "c", "port": 80 }),
+            ]
+        );
+    }
+
+    #[test]
+    fn joins_right_before_left() {
+        let output = run(
+            config(JoinType::Inner),
+            vec![
+                event("conn", json!({ "host": "a", "port": 443 })),
+                event("dns", json!({ "host": "a", "query": "example.com" })),
+            ],
+            Duration::from_secs(1),
+        );
+        assert_eq!(
+            output,
+            vec![json!({ "host": "a", "query": "example.com", "port": 443 })]
+        );
+    }
+
+    #[test]
+    fn joins_every_match_in_window() {
+        let output = run(
+            config(JoinType::Inner),
+            vec![
+                event("dns", json!({ "host": "a", "query": "one" })),
+                event("dns", json!({ "host": "a", "query": "two" })),
+                event("conn", json!({ "host": "a", "port": 443 })),
+            ],
+            Duration::from_secs(1),
+        );
+        assert_eq!(
+            output,
+            vec![
+                json!({ "host": "a", "query": "one", "port": 443 }),
+                json!({ "host": "a", "query": "two", "port": 443 }),
+            ]
+        );
+    }
+
+    #[test]
+    fn key_collision_keeps_right_value() {
+        let output = run(
+            config(JoinType::Inner),
+            vec![
+                event("dns", json!({ "host": "a", "status": "resolved" })),
+                event("conn", json!({ "host": "a", "status": "refused" })),
+            ],
+            Duration::from_secs(1),
+        );
+        assert_eq!(output, vec![json!({ "host": "a", "status": "refused" })]);
+    }
+
+    #[test]
+    fn key_collision_with_prefixes() {
+        let mut config = config(JoinType::Left);
+        config.left_prefix = Some("dns_".into());
+        config.right_prefix = Some("conn_".into());
+
+        let output = run(
+            config,
+            vec![
+                event("dns", json!({ "host": "a", "status": "resolved" })),
+                event("conn", json!({ "host": "a", "status": "refused" })),
+                event("dns", json!({ "host": "b", "status": "resolved" })),
+            ],
+            Duration::from_secs(1),
+        );
+        assert_eq!(
+            output,
+            vec![
+                json!({
+                    "dns_host": "a",
+                    "dns_status": "resolved",
+                    "conn_host": "a",
+                    "conn_status": "refused",
+                }),
+                json!({ "dns_host": "b", "dns_status": "resolved" }),
+            ]
+        );
+    }
+
+    #[test]
+    fn window_expiry() {
+        let output = run(
+            config(JoinType::Full),
+            vec![
+                event("dns", json!({ "host": "a", "query": "example.com" })),
+                event("conn", json!({ "host": "a", "port": 443 })),
+            ],
+            Duration::from_secs(10),
+        );
+        assert_eq!(
+            output,
+            vec![
+                json!({ "host": "a", "query": "example.com" }),
+                json!({ "host": "a", "port": 443 }),
+            ]
+        );
+    }
+
+    #[test]
+    fn expires_in_arrival_order_across_keys() {
+        let output = run(
+            config(JoinType::Left),
+            vec![
+                event("dns", json!({ "host": "a", "query": "one" })),
+                event("dns", json!({ "host": "b", "query": "two" })),
+                event("dns", json!({ "host": "a", "query": "three" })),
+                event("conn", json!({ "host": "a", "port": 443 })),
+            ],
+            Duration::from_secs(4),
+        );
+        assert_eq!(
+            output,
+            vec![
+                json!({ "host": "a", "query": "one" }),
+                json!({ "host": "a", "query": "three", "port": 443 }),
+                json!({ "host": "b", "query": "two" }),
+            ]
+        );
+    }
+
+    #[test]
+    fn max_buffered_drops_oldest() {
+        let mut config = config(JoinType::Left);
+        config.max_buffered = NonZeroUsize::new(2);
+
+        let output = run(
+            config,
+            vec![
+                event("dns", json!({ "host": "a", "query": "one" })),
+                event("dns", json!({ "host": "b", "query": "two" })),
+                event("conn", json!({ "host": "a", "port": 443 })),
+            ],
+            Duration::from_secs(1),
+        );
+        assert_eq!(output, vec![json!({ "host": "b", "query": "two" })]);
+    }
+
+    #[test]
+    fn events_without_key_never_match() {
+        let output = run(
+            config(JoinType::Left),
+            vec![
+                event("dns", json!({ "query": "example.com" })),
+                event("conn", json!({ "port": 443 })),
+            ],
+            Duration::from_secs(1),
+        );
+        assert_eq!(output, vec![json!({ "query": "example.com" })]);
+    }
+
+    #[test]
+    fn input_output_ports() {
+        let mut config = config(JoinType::Inner);
+        config.left = vec!["route.dns".into()];
+
+        let output = run(
+            config,
+            vec![
+                event("route.dns", json!({ "host": "a", "query": "example.com" })),
+                event("route.other", json!({ "host": "a", "other": true })),
+                event("conn", json!({ "host": "a", "port": 443 })),
+            ],
+            Duration::from_secs(1),
+        );
+        assert_eq!(
+            output,
+            vec![json!({ "host": "a", "query": "example.com", "port": 443 })]
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_config() {
+        let mut overlapping = config(JoinType::Inner);
+        overlapping.right = vec!["dns".into()];
+        assert!(WindowJoin::new(&overlapping).is_err());
+
+        let mut no_keys = config(JoinType::Inner);
+        no_keys.join_key_fields.clear();
+        assert!(WindowJoin::new(&no_keys).is_err());
+
+        let mut no_window = config(JoinType::Inner);
+        no_window.window_secs = Duration::ZERO;
+        assert!(WindowJoin::new(&no_window).is_err());
+    }
+}
@@ -1,7 +1,13 @@
 use std::collections::HashSet;
-use std::{fs::read_dir, process::Command};
+use std::{
+    fs::read_dir,
+    process::{Command, Stdio},
+    thread::sleep,
+    time::Duration,
+};
 
 use assert_cmd::prelude::*;
+use vector::test_util::next_addr;
 
 use crate::{create_directory, create_file, overwrite_file};
 
@@ -150,3 +156,209 @@ fn validate(config: &str) -> i32 {
     );
     output.status.code().unwrap()
 }
+
+const STATUS_CONFIG: &str = r#"
+data_dir = "${VECTOR_DATA_DIR}"
+
+[api]
+    enabled = true
+    address = "${VECTOR_TEST_ADDRESS}"
+
+[sources.in]
+    type = "demo_logs"
+    format = "shuffle"
+    lines = ["log"]
+    interval = 0.1
+
+[sinks.out]
+    inputs = ["in"]
+    type = "blackhole"
+"#;
+
+/// Runs `vector status` with `arguments` against a Vector instance running `STATUS_CONFIG`,
+/// returning its exit code and `stdout`.
+fn status(arguments: Vec<&str>) -> (i32, String) {
+    let address = next_addr();
+    let mut vector = Command::cargo_bin("vector")
+        .unwrap()
+        .arg("-c")
+        .arg(create_file(STATUS_CONFIG))
+        .arg("--quiet")
+        .env("VECTOR_DATA_DIR", create_directory())
+        .env("VECTOR_TEST_ADDRESS", address.to_string())
+        .stdout(Stdio::null())
+        .spawn()
+        .unwrap();
+
+    // Give vector time to start.
+    sleep(Duration::from_secs(2));
+
+    let output = Command::cargo_bin("vector")
+        .unwrap()
+        .arg("status")
+        .arg("--api-address")
+        .arg(format!("http://{}/graphql", address))
+        .args(arguments)
+        .env("VECTOR_COLOR", "never")
+        .output()
+        .unwrap();
+
+    vector.kill().unwrap();
+    vector.wait().unwrap();
+
+    (
+        output.status.code().unwrap(),
+        String::from_utf8(output.stdout).expect("Vector output isn't a valid utf8 string"),
+    )
+}
+
+#[test]
+fn status_lists_components() {
+    let (code, output) = status(vec![]);
+    assert_eq!(code, exitcode::OK, "unexpected output: {}", output);
+
+    let mut lines = output.lines();
+    let header = lines.next().unwrap();
+    assert!(header.starts_with("ID"));
+    assert!(header.contains("STATUS"));
+
+    let components = lines
+        .map(|line| line.split_whitespace().take(4).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        components,
+        vec![
+            vec!["in", "source", "demo_logs", "healthy"],
+            vec!["out", "sink", "blackhole", "healthy"],
+        ]
+    );
+}
+
+#[test]
+fn status_single_component() {
+    let (code, output) = status(vec!["--component", "out"]);
+    assert_eq!(code, exitcode::OK, "unexpected output: {}", output);
+    assert!(output
+        .lines()
+        .any(|line| line == format!("{:<22}out", "ID:")));
+    assert!(!output.contains("demo_logs"));
+}
+
+#[test]
+fn status_unknown_component() {
+    let (code, _) = status(vec!["--component", "missing"]);
+    assert_eq!(code, exitcode::DATAERR);
+}
+
+#[test]
+fn status_unreachable_api() {
+    let output = Command::cargo_bin("vector")
+        .unwrap()
+        .arg("status")
+        .arg("--api-address")
+        .arg(format!("http://{}/graphql", next_addr()))
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code().unwrap(), exitcode::UNAVAILABLE);
+}
+
+const INGEST_CONFIG: &str = r#"
+data_dir = "${VECTOR_DATA_DIR}"
+
+[api]
+    enabled = true
+    address = "${VECTOR_TEST_ADDRESS}"
+    allow_mutations = true
+
+[sources.in]
+    type = "socket"
+    mode = "tcp"
+    address = "${VECTOR_TEST_SOURCE_ADDRESS}"
+
+[sinks.out]
+    inputs = ["in"]
+    type = "file"
+    path = "${VECTOR_TEST_OUTPUT}"
+    encoding.codec = "json"
+"#;
+
+#[test]
+fn ingest_events_into_sink() {
+    let address = next_addr();
+    let output_path = create_directory().join("out.log");
+    let mut vector = Command::cargo_bin("vector")
+        .unwrap()
+        .arg("-c")
+        .arg(create_file(INGEST_CONFIG))
+        .arg("--quiet")
+        .env("VECTOR_DATA_DIR", create_directory())
+        .env("VECTOR_TEST_ADDRESS", address.to_string())
+        .env("VECTOR_TEST_SOURCE_ADDRESS", next_addr().to_string())
+        .env("VECTOR_TEST_OUTPUT", &output_path)
+        .stdout(Stdio::null())
+        .spawn()
+        .unwrap();
+
+    // Give vector time to start.
+    sleep(Duration::from_secs(2));
+
+    let input = (0..100)
+        .map(|i| format!("{{\"message\":\"event {}\"}}\n", i))
+        .collect::<String>();
+    let output = assert_cmd::Command::cargo_bin("vector")
+        .unwrap()
+        .arg("ingest")
+        .arg("--sink")
+        .arg("out")
+        .arg("--api-address")
+        .arg(format!("http://{}/graphql", address))
+        .write_stdin(input)
+        .output()
+        .unwrap();
+
+    // Give the sink time to flush.
+    sleep(Duration::from_secs(1));
+    vector.kill().unwrap();
+    vector.wait().unwrap();
+
+    assert_eq!(output.status.code().unwrap(), exitcode::OK);
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "Accepted: 100, rejected: 0\n"
+    );
+
+    let messages = std::fs::read_to_string(output_path)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap()["message"].clone())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        messages,
+        (0..100)
+            .map(|i| serde_json::Value::from(format!("event {}", i)))
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn ingest_dry_run() {
+    let output = assert_cmd::Command::cargo_bin("vector")
+        .unwrap()
+        .arg("ingest")
+        .arg("--sink")
+        .arg("out")
+        .arg("--format")
+        .arg("logfmt")
+        .arg("--dry-run")
+        .arg("--api-address")
+        .arg(format!("http://{}/graphql", next_addr()))
+        .write_stdin("level=info msg=one\nlevel=warn msg=\"two\"\nbroken\n")
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code().unwrap(), exitcode::DATAERR);
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "Valid: 2, invalid: 1\n"
+    );
+}
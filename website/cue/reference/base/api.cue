@@ -1,6 +1,19 @@
 package metadata
 
 base: api: configuration: api: {
+	allow_mutations: {
+		common: false
+		description: """
+			Whether the GraphQL mutations changing the running topology, such as ingesting events
+			into a sink, are enabled for the API.
+
+			The API doesn't authenticate requests, so only enable this when the API address can't be
+			reached by untrusted clients. Mutations sent from a browser page of another origin are
+			always rejected.
+			"""
+		required: false
+		type: bool: default: false
+	}
 	address: {
 		common: true
 		description: """
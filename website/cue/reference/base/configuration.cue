@@ -107,6 +107,27 @@ base: configuration: configuration: {
 				required:      false
 				relevant_when: "type = \"file\""
 			}
+			cache_size: {
+				type: uint: default: 1000
+				description: """
+					The number of lookup results to keep in memory.
+
+					The most recently used keys are answered from this cache without querying the database.
+					Set to `0` to disable the cache.
+					"""
+				required:      false
+				relevant_when: "type = \"sqlite\""
+			}
+			columns: {
+				type: array: items: type: string: examples: ["name", "region"]
+				description: """
+					The columns returned for each row.
+
+					If unset, all the columns of the table are returned.
+					"""
+				required:      false
+				relevant_when: "type = \"sqlite\""
+			}
 			flush_interval: {
 				type: uint: {}
 				description: """
@@ -136,6 +157,12 @@ base: configuration: configuration: {
 				required:      false
 				relevant_when: "type = \"memory\""
 			}
+			key_column: {
+				type: string: {}
+				description:   "The column holding the keys rows are looked up by."
+				required:      true
+				relevant_when: "type = \"sqlite\""
+			}
 			max_byte_size: {
 				type: uint: {}
 				description: """
@@ -237,7 +264,139 @@ base: configuration: configuration: {
 					[geolite2]: https://dev.maxmind.com/geoip/geoip2/geolite2/#Download_Access
 					"""
 				required:      true
-				relevant_when: "type = \"geoip\" or type = \"mmdb\""
+				relevant_when: "type = \"geoip\" or type = \"mmdb\" or type = \"sqlite\" or type = \"rocksdb\""
+			}
+			table: {
+				type: string: {}
+				description:   "The name of the database table to look rows up in."
+				required:      true
+				relevant_when: "type = \"sqlite\""
+			}
+			key_field: {
+				type: string: examples: ["tenant_id"]
+				description:   "The field of the objects holding the keys they are looked up by."
+				required:      true
+				relevant_when: "type = \"http\" or type = \"rocksdb\""
+			}
+			tls: {
+				type: object: options: {
+					alpn_protocols: {
+						type: array: items: type: string: examples: ["h2"]
+						description: """
+														Sets the list of supported ALPN protocols.
+
+														Declare the supported ALPN protocols, which are used during negotiation with a peer. They are prioritized in the order
+														that they are defined.
+														"""
+						required: false
+					}
+					ca_file: {
+						type: string: examples: ["/path/to/certificate_authority.crt"]
+						description: """
+														Absolute path to an additional CA certificate file.
+
+														The certificate must be in the DER or PEM (X.509) format. Additionally, the certificate can be provided as an inline string in PEM format.
+														"""
+						required: false
+					}
+					crt_file: {
+						type: string: examples: ["/path/to/host_certificate.crt"]
+						description: """
+														Absolute path to a certificate file used to identify this server.
+
+														The certificate must be in DER, PEM (X.509), or PKCS#12 format. Additionally, the certificate can be provided as
+														an inline string in PEM format.
+
+														If this is set _and_ is not a PKCS#12 archive, `key_file` must also be set.
+														"""
+						required: false
+					}
+					key_file: {
+						type: string: examples: ["/path/to/host_certificate.key"]
+						description: """
+														Absolute path to a private key file used to identify this server.
+
+														The key must be in DER or PEM (PKCS#8) format. Additionally, the key can be provided as an inline string in PEM format.
+														"""
+						required: false
+					}
+					key_pass: {
+						type: string: examples: ["${KEY_PASS_ENV_VAR}", "PassWord1"]
+						description: """
+														Passphrase used to unlock the encrypted key file.
+
+														This has no effect unless `key_file` is set.
+														"""
+						required: false
+					}
+					server_name: {
+						type: string: examples: ["www.example.com"]
+						description: """
+														Server name to use when using Server Name Indication (SNI).
+
+														Only relevant for outgoing connections.
+														"""
+						required: false
+					}
+					verify_certificate: {
+						type: bool: {}
+						description: """
+														Enables certificate verification. For components that create a server, this requires that the
+														client connections have a valid client certificate. For components that initiate requests,
+														this validates that the upstream has a valid certificate.
+
+														If enabled, certificates must not be expired and must be issued by a trusted
+														issuer. This verification operates in a hierarchical manner, checking that the leaf certificate (the
+														certificate presented by the client/server) is not only valid, but that the issuer of that certificate is also valid, and
+														so on, until the verification process reaches a root certificate.
+
+														Do NOT set this to `false` unless you understand the risks of not verifying the validity of certificates.
+														"""
+						required: false
+					}
+					verify_hostname: {
+						type: bool: {}
+						description: """
+														Enables hostname verification.
+
+														If enabled, the hostname used to connect to the remote host must be present in the TLS certificate presented by
+														the remote host, either as the Common Name or as an entry in the Subject Alternative Name extension.
+
+														Only relevant for outgoing connections.
+
+														Do NOT set this to `false` unless you understand the risks of not verifying the remote hostname.
+														"""
+						required: false
+					}
+				}
+				description:   "TLS configuration."
+				required:      false
+				relevant_when: "type = \"http\""
+			}
+			ttl_secs: {
+				type: uint: {
+					default: 300
+					unit:    "seconds"
+				}
+				description: """
+					How long, in seconds, the fetched data is used before it's fetched again.
+
+					Once the data is older than this, the next lookup triggers a refresh in the background,
+					and lookups keep using the previously fetched data until the refresh completes. If the
+					refresh fails, the previously fetched data is kept.
+					"""
+				required:      false
+				relevant_when: "type = \"http\""
+			}
+			url: {
+				type: string: examples: ["https://example.com/tenants.json"]
+				description: """
+					The URL to fetch the enrichment data from.
+
+					The response must be a JSON array of objects.
+					"""
+				required:      true
+				relevant_when: "type = \"http\""
 			}
 			type: {
 				required: true
@@ -258,6 +417,18 @@ base: configuration: configuration: {
 
 						[maxmind]: https://www.maxmind.com/
 						"""
+					sqlite: """
+						Exposes the rows of a [SQLite][sqlite] database table as an enrichment table.
+
+						[sqlite]: https://www.sqlite.org/
+						"""
+					http: "Exposes the rows of a JSON array fetched from an HTTP endpoint as an enrichment table."
+					rocksdb: """
+						Exposes the rows of a [RocksDB][rocksdb] database as an enrichment table, without loading
+						them into memory.
+
+						[rocksdb]: https://rocksdb.org/
+						"""
 				}
 				description: "enrichment table type"
 			}
@@ -269,6 +440,9 @@ base: configuration: configuration: {
 			* [CSV](https://en.wikipedia.org/wiki/Comma-separated_values) files
 			* [MaxMind](https://www.maxmind.com/en/home) databases
 			* In-memory storage
+			* [SQLite](https://www.sqlite.org/) databases
+			* JSON data fetched from HTTP endpoints
+			* [RocksDB](https://rocksdb.org/) databases
 
 			For the lookup in the enrichment tables to be as performant as possible, the data is indexed according
 			to the fields that are used in the search. Note that indices can only be created for fields for which an
@@ -532,7 +706,7 @@ base: configuration: configuration: {
 				}
 				description:   "TLS configuration."
 				required:      false
-				relevant_when: "type = \"aws_secrets_manager\""
+				relevant_when: "type = \"aws_secrets_manager\" or type = \"hashicorp_vault\""
 			}
 			endpoint: {
 				type: string: examples: ["http://127.0.0.0:5000/path/to/service"]
@@ -550,6 +724,78 @@ base: configuration: configuration: {
 				required:      false
 				relevant_when: "type = \"aws_secrets_manager\""
 			}
+			address: {
+				type: string: examples: ["https://vault.example.com:8200"]
+				description:   "The address of the Vault server."
+				required:      true
+				relevant_when: "type = \"hashicorp_vault\""
+			}
+			approle: {
+				type: object: options: {
+					mount_path: {
+						type: string: default: "approle"
+						description: "The path the AppRole auth method is mounted at."
+						required:    false
+					}
+					role_id: {
+						type: string: {}
+						description: "The role ID."
+						required:    true
+					}
+					secret_id: {
+						type: string: {}
+						description: "The secret ID."
+						required:    true
+					}
+				}
+				description: """
+					Credentials used to log in with the [AppRole][approle] auth method.
+
+					[approle]: https://developer.hashicorp.com/vault/docs/auth/approle
+					"""
+				required:      false
+				relevant_when: "type = \"hashicorp_vault\""
+			}
+			cache_ttl_secs: {
+				type: uint: {
+					default: 300
+					unit:    "seconds"
+				}
+				description: """
+					How long, in seconds, a fetched secret is reused before it's fetched again.
+
+					Secrets are only fetched when the configuration is loaded or reloaded, so they aren't
+					refreshed in the background once this expires: a changed secret is picked up by the first
+					reload after that.
+
+					If Vault can't be reached when the secret is fetched again, the previously fetched secret
+					is used and a warning is logged.
+					"""
+				required:      false
+				relevant_when: "type = \"hashicorp_vault\""
+			}
+			mount_path: {
+				type: string: default: "secret"
+				description:   "The path the KV secrets engine is mounted at."
+				required:      false
+				relevant_when: "type = \"hashicorp_vault\""
+			}
+			secret_path: {
+				type: string: examples: ["vector/production"]
+				description:   "The path of the secret to resolve, relative to `mount_path`."
+				required:      true
+				relevant_when: "type = \"hashicorp_vault\""
+			}
+			token: {
+				type: string: {}
+				description: """
+					The token used to authenticate to Vault.
+
+					Takes precedence over `approle`.
+					"""
+				required:      false
+				relevant_when: "type = \"hashicorp_vault\""
+			}
 			type: {
 				required: true
 				type: string: enum: {
@@ -557,6 +803,7 @@ base: configuration: configuration: {
 					directory:           "Directory."
 					exec:                "Exec."
 					aws_secrets_manager: "AWS Secrets Manager."
+					hashicorp_vault:     "HashiCorp Vault."
 				}
 				description: "secret type"
 			}
@@ -204,6 +204,60 @@ cli: {
 	options: _core_options
 
 	commands: {
+		"check-config": {
+			description: """
+				Check the target config without starting any component, then exit. The errors found, if
+				any, are printed to stdout as a JSON object with an `errors` array, whose items have the
+				`component_id` and `field_path` the error is about, when known, and a `message`. Exits
+				with code 1 if any error is found.
+
+				Along with the checks of `vector validate --no-environment`, the VRL programs of `remap`
+				transforms are compiled.
+				"""
+
+			example: "vector check-config --config /etc/vector/vector.yaml"
+
+			options: {
+				"config": {
+					_short:      "c"
+					description: env_vars.VECTOR_CONFIG.description
+					type:        "string"
+					default:     env_vars.VECTOR_CONFIG.type.string.default
+					env_var:     "VECTOR_CONFIG"
+				}
+				"config-dir": {
+					_short:      "C"
+					description: env_vars.VECTOR_CONFIG_DIR.description
+					type:        "string"
+					env_var:     "VECTOR_CONFIG_DIR"
+				}
+			}
+		}
+		"enrichment build-rocksdb": {
+			description: """
+				Build a RocksDB database, to be used by a `rocksdb` enrichment table, from a CSV file
+				whose first row holds the names of its columns. Each row is stored as a JSON object, keyed
+				by the value of its key column. Meant to be run ahead of deployment, for example in a CI
+				pipeline. Only available in builds with the `enrichment-tables-rocksdb` feature enabled.
+				"""
+
+			example: "vector enrichment build-rocksdb --input-csv data.csv --output-path /data/enrichment.db"
+
+			options: {
+				"input-csv": {
+					description: "The CSV file to read the rows from"
+					type:        "string"
+				}
+				"output-path": {
+					description: "The directory to write the database to, which must not exist"
+					type:        "string"
+				}
+				"key-column": {
+					description: "The column holding the keys rows are looked up by. Defaults to the first column"
+					type:        "string"
+				}
+			}
+		}
 		"graph": {
 			description: """
 				Generate a visual representation of topologies. The output is in the [DOT format](\(urls.dot_format)),
@@ -216,6 +270,43 @@ cli: {
 
 			options: _core_options
 		}
+		"healthcheck": {
+			description: """
+				Run the healthchecks of the sinks of the target config, then exit. The sinks are built but
+				not started, so no event is sent, and their healthchecks run in parallel even if they're
+				disabled. Exits with code 1 if any sink is not healthy.
+				"""
+
+			example: "vector healthcheck --config /etc/vector/vector.yaml --component-id my_sink"
+
+			options: {
+				"config": {
+					_short:      "c"
+					description: env_vars.VECTOR_CONFIG.description
+					type:        "string"
+					default:     env_vars.VECTOR_CONFIG.type.string.default
+					env_var:     "VECTOR_CONFIG"
+				}
+				"config-dir": {
+					_short:      "C"
+					description: env_vars.VECTOR_CONFIG_DIR.description
+					type:        "string"
+					env_var:     "VECTOR_CONFIG_DIR"
+				}
+				"component-id": {
+					description: "Only check the health of the sink with the given ID"
+					type:        "string"
+				}
+				"format": {
+					description: "The format the results are printed in"
+					default:     "text"
+					enum: {
+						text: "A table of the results, or a single line when checking a single sink"
+						json: "A JSON object with a `healthchecks` array of results"
+					}
+				}
+			}
+		}
 		"generate": {
 			description: "Generate a Vector configuration containing a list of components"
 
@@ -246,6 +337,43 @@ cli: {
 			description: "Prints this message or the help of the given subcommand(s)"
 		}
 
+		"ingest": {
+			description: """
+				Write events read from stdin, one per line, directly into a sink of a local
+				or remote Vector instance. The instance must have `api.allow_mutations` enabled
+				"""
+
+			flags: _default_flags & {
+				"dry-run": {
+					description: "Validate the events without submitting them"
+				}
+			}
+
+			options: {
+				"api-address": {
+					_short:      "a"
+					description: "Vector GraphQL API server endpoint"
+					type:        "string"
+				}
+				"format": {
+					_short:      "f"
+					description: "Format of the events read from stdin, one event per line"
+					default:     "json"
+					enum: {
+						json:   "A JSON object per line"
+						logfmt: "`key=value` pairs per line, with optionally quoted values"
+						csv:    "Comma-separated values, with field names taken from the first line"
+					}
+				}
+				"sink": {
+					_short:      "s"
+					description: "ID of the sink to write events into"
+					type:        "string"
+					required:    true
+				}
+			}
+		}
+
 		"list": {
 			description: "List available components, then exit"
 
@@ -379,6 +507,39 @@ cli: {
 			}
 		}
 
+		"status": {
+			description: """
+				Display the health and throughput of each component of a local or remote
+				Vector instance. Exits with code 1 if any component is not healthy.
+				"""
+
+			flags: _default_flags & {
+				"watch": {
+					_short:      "w"
+					description: "Continuously refresh the status until interrupted"
+				}
+			}
+
+			options: {
+				"api-address": {
+					_short:      "a"
+					description: "Vector GraphQL API server endpoint"
+					type:        "string"
+				}
+				"component": {
+					_short:      "c"
+					description: "Only show a detailed view of the component with the given ID"
+					type:        "string"
+				}
+				"interval": {
+					_short:      "i"
+					description: "Interval over which rates are sampled, in milliseconds"
+					type:        "integer"
+					default:     1000
+				}
+			}
+		}
+
 		"top": {
 			description: """
 				Display topology and metrics in the console, for a local or remote Vector
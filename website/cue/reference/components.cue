@@ -194,6 +194,7 @@ components: {
 		if Args.kind == "transform" {
 			aggregate?:       #FeaturesAggregate
 			convert?:         #FeaturesConvert
+			dynamic_route?:   #FeaturesDynamicRoute
 			enrich?:          #FeaturesEnrich
 			filter?:          #FeaturesFilter
 			parse?:           #FeaturesParse
@@ -332,6 +333,8 @@ components: {
 
 	#FeaturesExclusiveRoute: {}
 
+	#FeaturesDynamicRoute: {}
+
 	#FeaturesSanitize: {}
 
 	#FeaturesShape: {}
@@ -124,6 +124,17 @@ components: sinks: aws_s3: components._aws & {
 			]
 		}
 
+		object_integrity: {
+			title: "Object integrity"
+			body:  """
+				Vector sends the base64-encoded MD5 digest of every object in its `Content-MD5`
+				header, computed on the bytes actually uploaded, after compression. AWS S3 rejects
+				the upload with an `InvalidDigest` error if the bytes it received don't match the
+				digest, in which case Vector retries the request rather than dropping the events.
+				This header is also required to write to buckets with object lock enabled.
+				"""
+		}
+
 		object_naming: {
 			title: "Object naming"
 			body:  """
@@ -181,6 +192,18 @@ components: sinks: aws_s3: components._aws & {
 				"""
 		}
 
+		parquet: {
+			title: "Parquet files"
+			body:  """
+				Setting `batch_encoding.codec` to `parquet` writes each batch of events as an
+				[Apache Parquet](\(urls.apache_parquet)) file, which data lake query engines such as
+				Athena can read directly. The schema of each file is inferred from the first event of
+				its batch, and the events are split in row groups of at most `batch_encoding.max_rows`
+				rows. Objects are still flushed according to the `batch` options, and their columns
+				are compressed with `batch_encoding.parquet_compression` rather than `compression`.
+				"""
+		}
+
 		server_side_encryption: {
 			title: "Server-Side Encryption (SSE)"
 			body:  """
@@ -189,6 +189,11 @@ base: components: sinks: amqp: configuration: {
 
 						[logfmt]: https://brandur.org/logfmt
 						"""
+					msgpack: """
+						Encodes an event as [MessagePack][msgpack].
+
+						[msgpack]: https://msgpack.org/
+						"""
 					native: """
 						Encodes an event in the [native Protocol Buffers format][vector_native_protobuf].
 
@@ -349,6 +354,20 @@ base: components: sinks: amqp: configuration: {
 					}
 				}
 			}
+			msgpack: {
+				description:   "MessagePack-specific encoding options."
+				relevant_when: "codec = \"msgpack\""
+				required:      false
+				type: object: options: compact_integers: {
+					description: """
+						Whether to encode integers using the smallest MessagePack integer type that fits the value.
+
+						When disabled, all integers are encoded as signed 64-bit integers.
+						"""
+					required: false
+					type: bool: default: true
+				}
+			}
 			only_fields: {
 				description: "List of fields that are included in the encoded event."
 				required:    false
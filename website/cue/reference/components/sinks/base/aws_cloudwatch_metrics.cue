@@ -191,11 +191,21 @@ base: components: sinks: aws_cloudwatch_metrics: configuration: {
 		type: string: {
 			default: "none"
 			enum: {
+				brotli: """
+					[Brotli][brotli] compression.
+
+					[brotli]: https://github.com/google/brotli
+					"""
 				gzip: """
 					[Gzip][gzip] compression.
 
 					[gzip]: https://www.gzip.org/
 					"""
+				lz4: """
+					[LZ4][lz4] compression, using the frame format.
+
+					[lz4]: https://github.com/lz4/lz4/blob/dev/doc/lz4_Frame_format.md
+					"""
 				none: "No compression."
 				snappy: """
 					[Snappy][snappy] compression.
@@ -305,6 +315,16 @@ base: components: sinks: aws_cloudwatch_metrics: configuration: {
 						required: false
 						type: uint: default: 200
 					}
+					min_concurrency: {
+						description: """
+																The minimum concurrency limit.
+
+																The adaptive request concurrency limit does not go below this bound, so that requests keep being sent during a
+																prolonged outage and the limit can recover once the service does.
+																"""
+						required: false
+						type: uint: default: 1
+					}
 					rtt_deviation_scale: {
 						description: """
 																Scale of RTT deviations which are not considered anomalous.
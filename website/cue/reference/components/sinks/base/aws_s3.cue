@@ -256,6 +256,71 @@ base: components: sinks: aws_s3: configuration: {
 			}
 		}
 	}
+	batch_encoding: {
+		description: """
+			Encoding configuration for whole batches of events.
+
+			When set, each object is written in this format instead of encoding its events one by one
+			with `encoding.codec`. The other `encoding` options still apply, while `compression` is
+			ignored as the format compresses its contents itself.
+			"""
+		required: false
+		type: object: options: {
+			codec: {
+				description: "The codec to use for encoding batches."
+				required:    true
+				type: string: enum: parquet: """
+					Encodes each batch of events as an [Apache Parquet][apache_parquet] file.
+
+					The schema of the file is inferred from the top-level fields of the first event of the
+					batch, and all the columns are optional. Fields missing from the first event are dropped,
+					and values whose type doesn't match their column are written as nulls. Nested objects and
+					arrays are written as JSON strings.
+
+					[apache_parquet]: https://parquet.apache.org/
+					"""
+			}
+			max_rows: {
+				description: """
+					The maximum number of rows of each row group.
+
+					Batches with more events are written as several row groups.
+					"""
+				relevant_when: "codec = \"parquet\""
+				required:      false
+				type: uint: {
+					default: 10000
+					unit:    "rows"
+				}
+			}
+			parquet_compression: {
+				description:   "The compression applied to the columns of the Parquet files."
+				relevant_when: "codec = \"parquet\""
+				required:      false
+				type: string: {
+					default: "snappy"
+					enum: {
+						gzip: """
+							[Gzip][gzip] compression.
+
+							[gzip]: https://www.gzip.org/
+							"""
+						none: "No compression."
+						snappy: """
+							[Snappy][snappy] compression.
+
+							[snappy]: https://github.com/google/snappy
+							"""
+						zstd: """
+							[Zstandard][zstd] compression.
+
+							[zstd]: https://facebook.github.io/zstd/
+							"""
+					}
+				}
+			}
+		}
+	}
 	bucket: {
 		description: """
 			The S3 bucket name.
@@ -278,11 +343,21 @@ base: components: sinks: aws_s3: configuration: {
 		type: string: {
 			default: "gzip"
 			enum: {
+				brotli: """
+					[Brotli][brotli] compression.
+
+					[brotli]: https://github.com/google/brotli
+					"""
 				gzip: """
 					[Gzip][gzip] compression.
 
 					[gzip]: https://www.gzip.org/
 					"""
+				lz4: """
+					[LZ4][lz4] compression, using the frame format.
+
+					[lz4]: https://github.com/lz4/lz4/blob/dev/doc/lz4_Frame_format.md
+					"""
 				none: "No compression."
 				snappy: """
 					[Snappy][snappy] compression.
@@ -473,6 +548,11 @@ base: components: sinks: aws_s3: configuration: {
 
 						[logfmt]: https://brandur.org/logfmt
 						"""
+					msgpack: """
+						Encodes an event as [MessagePack][msgpack].
+
+						[msgpack]: https://msgpack.org/
+						"""
 					native: """
 						Encodes an event in the [native Protocol Buffers format][vector_native_protobuf].
 
@@ -633,6 +713,20 @@ base: components: sinks: aws_s3: configuration: {
 					}
 				}
 			}
+			msgpack: {
+				description:   "MessagePack-specific encoding options."
+				relevant_when: "codec = \"msgpack\""
+				required:      false
+				type: object: options: compact_integers: {
+					description: """
+						Whether to encode integers using the smallest MessagePack integer type that fits the value.
+
+						When disabled, all integers are encoded as signed 64-bit integers.
+						"""
+					required: false
+					type: bool: default: true
+				}
+			}
 			only_fields: {
 				description: "List of fields that are included in the encoded event."
 				required:    false
@@ -761,7 +855,7 @@ base: components: sinks: aws_s3: configuration: {
 						type: bool: default: true
 					}
 					length_field_length: {
-						description: "Number of bytes representing the field length"
+						description: "Number of bytes representing the field length, from 1 to 8"
 						required:    false
 						type: uint: default: 4
 					}
@@ -926,6 +1020,16 @@ base: components: sinks: aws_s3: configuration: {
 						required: false
 						type: uint: default: 200
 					}
+					min_concurrency: {
+						description: """
+																The minimum concurrency limit.
+
+																The adaptive request concurrency limit does not go below this bound, so that requests keep being sent during a
+																prolonged outage and the limit can recover once the service does.
+																"""
+						required: false
+						type: uint: default: 1
+					}
 					rtt_deviation_scale: {
 						description: """
 																Scale of RTT deviations which are not considered anomalous.
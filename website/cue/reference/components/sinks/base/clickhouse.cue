@@ -246,11 +246,21 @@ base: components: sinks: clickhouse: configuration: {
 		type: string: {
 			default: "gzip"
 			enum: {
+				brotli: """
+					[Brotli][brotli] compression.
+
+					[brotli]: https://github.com/google/brotli
+					"""
 				gzip: """
 					[Gzip][gzip] compression.
 
 					[gzip]: https://www.gzip.org/
 					"""
+				lz4: """
+					[LZ4][lz4] compression, using the frame format.
+
+					[lz4]: https://github.com/lz4/lz4/blob/dev/doc/lz4_Frame_format.md
+					"""
 				none: "No compression."
 				snappy: """
 					[Snappy][snappy] compression.
@@ -283,6 +293,17 @@ base: components: sinks: clickhouse: configuration: {
 		required:    false
 		type: bool: default: false
 	}
+	dedup_key_field: {
+		description: """
+			The field to deduplicate rows by, for tables using the `ReplacingMergeTree` engine.
+
+			When set, the events of each batch are sorted by the value of this field before being
+			inserted, and each insert carries a deduplication token computed as the SHA-256 of its
+			payload, so that ClickHouse ignores the inserts retried after a failure.
+			"""
+		required: false
+		type: string: examples: ["id"]
+	}
 	encoding: {
 		description: "Transformations to prepare an event for serialization."
 		required:    false
@@ -401,6 +422,16 @@ base: components: sinks: clickhouse: configuration: {
 						required: false
 						type: uint: default: 200
 					}
+					min_concurrency: {
+						description: """
+																The minimum concurrency limit.
+
+																The adaptive request concurrency limit does not go below this bound, so that requests keep being sent during a
+																prolonged outage and the limit can recover once the service does.
+																"""
+						required: false
+						type: uint: default: 1
+					}
 					rtt_deviation_scale: {
 						description: """
 																Scale of RTT deviations which are not considered anomalous.
@@ -27,6 +27,57 @@ base: components: sinks: datadog_logs: configuration: {
 			type: bool: {}
 		}
 	}
+	agent_normalization_exclude_fields: {
+		description: """
+			Root fields left in place when events are normalized to the Datadog Agent standard.
+
+			Normalizing an event nests every field that isn't a reserved attribute under `message`.
+			The fields listed here stay at the root of the event instead.
+			"""
+		required: false
+		type: array: {
+			default: []
+			items: type: string: examples: ["_internal_vector_id"]
+		}
+	}
+	api_key_field: {
+		description: """
+			The event field holding the Datadog API key to send each event with.
+
+			This allows events from multiple tenants to be sent through the same sink. The field is
+			removed from the events before they are sent. Events without the field are sent with the
+			default API key, and events where the field is not a non-empty string of printable ASCII
+			characters are dropped.
+
+			If omitted, the API key attached to the event by the `datadog_agent` source is used.
+			"""
+		required: false
+		type: string: examples: ["_dd_api_key"]
+	}
+	api_key_metrics: {
+		description: """
+			When enabled, the events sent and the failed requests of each API key are counted in the
+			`datadog_logs_api_key_sent_events_total` and `datadog_logs_api_key_errors_total` metrics.
+
+			The metrics are tagged with the first six characters of the API key, in the
+			`api_key_prefix` tag, to tell apart the tenants of a sink sending events with several API
+			keys. This adds a metric series per API key, which may increase the cardinality of metrics
+			significantly.
+			"""
+		required: false
+		type: bool: default: false
+	}
+	api_key_vrl: {
+		description: """
+			A VRL program computing the Datadog API key to send each event with.
+
+			The value returned by the program is converted to a string. Events for which the program
+			returns `null` or an empty string, or fails, are sent with the default API key. The program
+			can't modify the events. Can't be used along with `api_key_field`.
+			"""
+		required: false
+		type: string: examples: ["get_env_var!(\"DD_API_KEY_PREFIX\") + string!(.tenant.id)"]
+	}
 	batch: {
 		description: "Event batching behavior."
 		required:    false
@@ -62,6 +113,30 @@ base: components: sinks: datadog_logs: configuration: {
 			}
 		}
 	}
+	circuit_breaker_recovery_secs: {
+		description: "The time to wait after the circuit breaker opens before probing the intake again."
+		required:    false
+		type: uint: {
+			default: 30
+			unit:    "seconds"
+		}
+	}
+	circuit_breaker_threshold: {
+		description: """
+			The number of consecutive failed requests after which the sink stops sending events to the
+			intake.
+
+			Requests fail when the intake responds with a server error or times out, or when it can't be
+			reached. While the circuit breaker is open, incoming events are dropped and failed requests
+			are no longer retried, instead of piling up in memory. After
+			`circuit_breaker_recovery_secs`, a single event is sent to check whether the intake has
+			recovered.
+
+			Set to `0`, the default, to disable the circuit breaker.
+			"""
+		required: false
+		type: uint: default: 0
+	}
 	compression: {
 		description: """
 			Compression configuration.
@@ -70,11 +145,21 @@ base: components: sinks: datadog_logs: configuration: {
 			"""
 		required: false
 		type: string: enum: {
+			brotli: """
+				[Brotli][brotli] compression.
+
+				[brotli]: https://github.com/google/brotli
+				"""
 			gzip: """
 				[Gzip][gzip] compression.
 
 				[gzip]: https://www.gzip.org/
 				"""
+			lz4: """
+				[LZ4][lz4] compression, using the frame format.
+
+				[lz4]: https://github.com/lz4/lz4/blob/dev/doc/lz4_Frame_format.md
+				"""
 			none: "No compression."
 			snappy: """
 				[Snappy][snappy] compression.
@@ -93,6 +178,19 @@ base: components: sinks: datadog_logs: configuration: {
 				"""
 		}
 	}
+	conflict_rename_prefix: {
+		description: """
+			The prefix added to the name of an attribute that is renamed to make room for a reserved
+			attribute.
+
+			When an event has a semantically defined reserved attribute, such as the host, outside of
+			the location the logs intake expects it in, it is moved there. If another attribute is
+			already at that location, it is preserved by renaming it to this prefix followed by the
+			meaning of the reserved attribute. Defaults to `_RESERVED_`.
+			"""
+		required: false
+		type: string: examples: ["__orig__"]
+	}
 	conforms_as_agent: {
 		description: """
 			When enabled this sink will normalize events to conform to the Datadog Agent standard. This
@@ -103,6 +201,22 @@ base: components: sinks: datadog_logs: configuration: {
 		required: false
 		type: bool: default: false
 	}
+	ddtags_invalid_value_action: {
+		description: """
+			What to do with the `ddtags` entries holding commas.
+
+			When `ddtags` is an array, its entries are joined with commas, so an entry holding commas would
+			otherwise be split into several tags by the logs intake.
+			"""
+		required: false
+		type: string: {
+			default: "replace"
+			enum: {
+				drop:    "Drop the entry."
+				replace: "Replace the commas of the entry with underscores."
+			}
+		}
+	}
 	default_api_key: {
 		description: """
 			The default Datadog [API key][api_key] to use in authentication of HTTP requests.
@@ -119,6 +233,20 @@ base: components: sinks: datadog_logs: configuration: {
 		required: false
 		type: string: examples: ["${DATADOG_API_KEY_ENV_VAR}", "ef8d5de700e7989468166c40fc8a0ccd"]
 	}
+	dlq: {
+		description: "Configuration of the dead-letter queue of the `datadog_logs` sink."
+		required:    false
+		type: object: options: uri: {
+			description: """
+				The URI of the HTTP endpoint receiving the events that can't be sent to Datadog.
+
+				Events are sent as newline-delimited JSON, each with a `_vector_dlq_reason` field holding
+				the reason it was routed to the dead-letter queue.
+				"""
+			required: true
+			type: string: examples: ["https://dead-letters.example.com/logs"]
+		}
+	}
 	encoding: {
 		description: "Transformations to prepare an event for serialization."
 		required:    false
@@ -160,6 +288,132 @@ base: components: sinks: datadog_logs: configuration: {
 		required: false
 		type: string: examples: ["http://127.0.0.1:8080", "http://example.com:12345"]
 	}
+	exact_payload_size: {
+		description: """
+			When enabled, each event is serialized on its own to measure its exact size before being
+			added to a request, rather than relying on its estimated size.
+
+			This costs an extra copy of every event, but avoids oversized allocations when the estimated
+			size is far off, such as for events with a lot of multi-byte Unicode content.
+			"""
+		required: false
+		type: bool: default: false
+	}
+	fallback_endpoint: {
+		description: """
+			The endpoint to send requests to while the configured `site` or `endpoint` is failing, such
+			as the logs intake of another Datadog region.
+
+			After `failover_threshold_errors` consecutive failed requests, requests are sent to this
+			endpoint instead. While failed over, a request is sent to the configured endpoint every
+			minute, and the sink switches back to it once one succeeds. Events sent to the site read
+			from `site_field` don't fail over.
+
+			The endpoint has the same format as `endpoint`.
+			"""
+		required: false
+		type: string: examples: ["https://http-intake.logs.datadoghq.eu"]
+	}
+	failover_threshold_errors: {
+		description: """
+			The number of consecutive failed requests after which requests are sent to the
+			`fallback_endpoint`.
+
+			Requests fail when the endpoint responds with a server error or times out, or when it can't
+			be reached.
+			"""
+		required: false
+		type: uint: default: 5
+	}
+	flush_on_signal: {
+		description: """
+			When enabled, the batches in progress are sent as soon as Vector receives `SIGUSR1`, without
+			waiting for them to fill up or time out.
+
+			This allows events to be flushed ahead of a rolling restart, for instance, while the sink
+			keeps running. Only supported on Unix.
+			"""
+		required: false
+		type: bool: default: false
+	}
+	http2: {
+		description: """
+			When enabled, requests are sent over HTTP/2, multiplexing concurrent requests over a single
+			connection to the intake.
+
+			Over TLS, HTTP/2 is negotiated with ALPN unless `tls.alpn_protocols` is set. Over plain
+			HTTP, the endpoint must accept HTTP/2 without prior negotiation.
+			"""
+		required: false
+		type: bool: default: false
+	}
+	linger_ms: {
+		description: """
+			How long to wait for another event before flushing a batch, in milliseconds.
+
+			The wait restarts with every event added to the batch, so that bursts of events are sent
+			in fewer, larger requests. Batches are still flushed once they reach `batch.timeout_secs`,
+			which remains the longest time an event waits in a batch. By default, batches are only
+			flushed on `batch.timeout_secs`.
+			"""
+		required: false
+		type: uint: {
+			examples: [50]
+			unit: "milliseconds"
+		}
+	}
+	max_compressed_bytes: {
+		description: """
+			The maximum size of a compressed request body, in bytes.
+
+			Requests are built from at most 5 MB of uncompressed events, so a larger compressed body
+			is a sign of a compression bug or corrupted data. Such requests are dropped along with their
+			events, rather than being rejected by the intake.
+			"""
+		required: false
+		type: uint: {
+			default: 10000000
+			unit:    "bytes"
+		}
+	}
+	max_single_event_bytes: {
+		description: """
+			The maximum estimated size of a single event, in bytes.
+
+			Larger events are sent to the dead-letter queue if `dlq` is configured, or dropped
+			otherwise, before being normalized. Events too large to fit in a request are always
+			dropped, so this only makes the limit lower and explicit.
+			"""
+		required: false
+		type: uint: {
+			examples: [1000000]
+			unit: "bytes"
+		}
+	}
+	priority_field: {
+		description: """
+			The event field holding the priority of each event, such as `_priority`.
+
+			When set, the events of each batch are sent in descending order of priority, so that
+			important events, such as audit logs, are sent first when a batch is split into several
+			requests. Events without the field, or where it doesn't hold an integer, have a priority of
+			`0`. Events of the same priority are sent in the order they were received.
+			"""
+		required: false
+		type: string: examples: ["_priority"]
+	}
+	propagate_trace_context: {
+		description: """
+			When enabled, requests carry the W3C trace context of the first event of their batch in the
+			`traceparent` and `tracestate` headers.
+
+			The trace context is read from the `_vector_trace_id`, `_vector_span_id`, and
+			`_vector_trace_state` metadata fields of the event. Trace and span IDs that are absent or
+			invalid are generated at random.
+			"""
+		required: false
+		type: bool: default: false
+	}
 	request: {
 		description: "Outbound HTTP request settings."
 		required:    false
@@ -218,6 +472,16 @@ base: components: sinks: datadog_logs: configuration: {
 						required: false
 						type: uint: default: 200
 					}
+					min_concurrency: {
+						description: """
+																The minimum concurrency limit.
+
+																The adaptive request concurrency limit does not go below this bound, so that requests keep being sent during a
+																prolonged outage and the limit can recover once the service does.
+																"""
+						required: false
+						type: uint: default: 1
+					}
 					rtt_deviation_scale: {
 						description: """
 																Scale of RTT deviations which are not considered anomalous.
@@ -355,6 +619,40 @@ base: components: sinks: datadog_logs: configuration: {
 			}
 		}
 	}
+	retry_budget_min_per_second: {
+		description: """
+			The number of retries added to the retry budget every second, regardless of the number of
+			requests sent.
+			"""
+		required: false
+		type: uint: default: 0
+	}
+	retry_budget_token_ratio: {
+		description: """
+			The fraction of requests which may be retried, across all the requests of the sink.
+
+			Every request sent for the first time adds this many retries to a budget shared by all
+			requests, and each retry spends one. Once the budget is spent, failed requests are no longer
+			retried, which keeps retries from piling up against a struggling intake. The budget is also
+			refilled with `retry_budget_min_per_second` retries every second.
+
+			Set both options to `0`, the default, to disable the retry budget.
+			"""
+		required: false
+		type: float: default: 0.0
+	}
+	send_idempotency_key: {
+		description: """
+			When enabled, each request is sent with a `DD-Request-ID` header holding the SHA-256 hash of
+			its uncompressed payload.
+
+			Retries of a request carry the same ID, which lets the intake recognize them and avoid
+			ingesting the same events twice. Retries of partially accepted requests only hold the
+			rejected events, so they are sent without it.
+			"""
+		required: false
+		type: bool: default: false
+	}
 	site: {
 		description: """
 			The Datadog [site][dd_site] to send observability data to.
@@ -370,6 +668,41 @@ base: components: sinks: datadog_logs: configuration: {
 		required: false
 		type: string: examples: ["us3.datadoghq.com", "datadoghq.eu"]
 	}
+	site_field: {
+		description: """
+			The event field holding the Datadog site to send each event to, such as `datadoghq.eu`.
+
+			This allows events for Datadog organizations in different sites to be sent through the same
+			sink, along with `api_key_field`. Only the Datadog sites `datadoghq.com`, `datadoghq.eu`,
+			`us3.datadoghq.com`, `us5.datadoghq.com`, `ap1.datadoghq.com`, and `ddog-gov.com` are
+			accepted. Events without the field, or where it holds another value, are sent to the
+			configured `site` or `endpoint`.
+			"""
+		required: false
+		type: string: examples: ["_dd_site"]
+	}
+	strip_nulls: {
+		description: """
+			When enabled, fields holding `null` are removed from events before they are sent.
+
+			The logs intake ignores null fields, so removing them only reduces the size of payloads.
+			Nested objects, including objects within arrays, are stripped as well, while null array
+			elements are kept.
+			"""
+		required: false
+		type: bool: default: false
+	}
+	track_estimated_size_error: {
+		description: """
+			When enabled, the difference between the serialized size of each event and its estimated
+			size is recorded in the `estimated_size_error_bytes` histogram.
+
+			Overestimated events are recorded as negative values. This helps tell whether the
+			`exact_payload_size` option is worth its cost.
+			"""
+		required: false
+		type: bool: default: false
+	}
 	tls: {
 		description: "Configures the TLS options for incoming/outgoing connections."
 		required:    false
@@ -474,4 +807,16 @@ base: components: sinks: datadog_logs: configuration: {
 			}
 		}
 	}
+	use_v2_api: {
+		description: """
+			When enabled, events are sent to the logs intake as newline-delimited JSON instead of a JSON
+			array.
+
+			The intake may then accept only part of a request, responding with `207 Multi-Status` and
+			the list of rejected events. Only the events rejected with a retriable status are retried,
+			rather than the whole request.
+			"""
+		required: false
+		type: bool: default: false
+	}
 }
@@ -335,11 +335,21 @@ base: components: sinks: elasticsearch: configuration: {
 		type: string: {
 			default: "none"
 			enum: {
+				brotli: """
+					[Brotli][brotli] compression.
+
+					[brotli]: https://github.com/google/brotli
+					"""
 				gzip: """
 					[Gzip][gzip] compression.
 
 					[gzip]: https://www.gzip.org/
 					"""
+				lz4: """
+					[LZ4][lz4] compression, using the frame format.
+
+					[lz4]: https://github.com/lz4/lz4/blob/dev/doc/lz4_Frame_format.md
+					"""
 				none: "No compression."
 				snappy: """
 					[Snappy][snappy] compression.
@@ -515,6 +525,54 @@ base: components: sinks: elasticsearch: configuration: {
 		required: false
 		type: string: examples: ["id", "_id"]
 	}
+	manage_templates: {
+		description: """
+			Elasticsearch index template management configuration.
+
+			When set, the sink creates or updates an [index template][es_index_templates] when it starts,
+			so that the indices it writes to are created with the expected mappings and settings.
+
+			[es_index_templates]: https://www.elastic.co/guide/en/elasticsearch/reference/current/index-templates.html
+			"""
+		required: false
+		type: object: options: {
+			auto_update: {
+				description: """
+					Whether to update the index template if it already exists and differs from `template_body`.
+
+					The existing template is compared with the one returned by Elasticsearch. If this is
+					disabled and they differ, the existing template is left unchanged and a warning is logged.
+					"""
+				required: false
+				type: bool: default: false
+			}
+			fail_on_error: {
+				description: """
+					Whether failing to apply the index template prevents the sink from starting.
+
+					If this is disabled, a warning is logged and the sink starts anyway.
+					"""
+				required: false
+				type: bool: default: true
+			}
+			template_body: {
+				description: """
+					The index template, as a JSON object.
+
+					This is sent as is to the [index template API][es_put_index_template].
+
+					[es_put_index_template]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-put-template.html
+					"""
+				required: true
+				type: string: examples: ["{\"index_patterns\": [\"vector-*\"], \"template\": {\"mappings\": {\"properties\": {\"message\": {\"type\": \"text\"}}}}}"]
+			}
+			template_name: {
+				description: "The name of the index template to create or update."
+				required:    true
+				type: string: examples: ["vector-logs"]
+			}
+		}
+	}
 	metrics: {
 		description: "Configuration for the `metric_to_log` transform."
 		required:    false
@@ -696,6 +754,16 @@ base: components: sinks: elasticsearch: configuration: {
 						required: false
 						type: uint: default: 200
 					}
+					min_concurrency: {
+						description: """
+																The minimum concurrency limit.
+
+																The adaptive request concurrency limit does not go below this bound, so that requests keep being sent during a
+																prolonged outage and the limit can recover once the service does.
+																"""
+						required: false
+						type: uint: default: 1
+					}
 					rtt_deviation_scale: {
 						description: """
 																Scale of RTT deviations which are not considered anomalous.
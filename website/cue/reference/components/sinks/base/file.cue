@@ -193,6 +193,11 @@ base: components: sinks: file: configuration: {
 
 						[logfmt]: https://brandur.org/logfmt
 						"""
+					msgpack: """
+						Encodes an event as [MessagePack][msgpack].
+
+						[msgpack]: https://msgpack.org/
+						"""
 					native: """
 						Encodes an event in the [native Protocol Buffers format][vector_native_protobuf].
 
@@ -353,6 +358,20 @@ base: components: sinks: file: configuration: {
 					}
 				}
 			}
+			msgpack: {
+				description:   "MessagePack-specific encoding options."
+				relevant_when: "codec = \"msgpack\""
+				required:      false
+				type: object: options: compact_integers: {
+					description: """
+						Whether to encode integers using the smallest MessagePack integer type that fits the value.
+
+						When disabled, all integers are encoded as signed 64-bit integers.
+						"""
+					required: false
+					type: bool: default: true
+				}
+			}
 			only_fields: {
 				description: "List of fields that are included in the encoded event."
 				required:    false
@@ -420,7 +439,7 @@ base: components: sinks: file: configuration: {
 						type: bool: default: true
 					}
 					length_field_length: {
-						description: "Number of bytes representing the field length"
+						description: "Number of bytes representing the field length, from 1 to 8"
 						required:    false
 						type: uint: default: 4
 					}
@@ -493,6 +512,30 @@ base: components: sinks: file: configuration: {
 			syntax: "template"
 		}
 	}
+	rotation: {
+		description: """
+			File rotation configuration.
+
+			Files can also be rotated over time by using a time-based `path`, such as
+			`/tmp/vector-%Y-%m-%d.log`.
+			"""
+		required: false
+		type: object: options: max_events: {
+			description: """
+				The maximum number of events written to a file before it is rotated.
+
+				Once this many events have been written to a file since it was opened, the file is closed
+				and renamed by appending the current UTC time to its path, such as
+				`<path>.20240105T070000.123456789Z`. The events that follow are written to a new file at
+				the original path.
+				"""
+			required: false
+			type: uint: {
+				examples: [100000]
+				unit: "events"
+			}
+		}
+	}
 	timezone: {
 		description: """
 			Timezone to use for any date specifiers in template strings.
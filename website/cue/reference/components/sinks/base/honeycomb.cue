@@ -70,11 +70,21 @@ base: components: sinks: honeycomb: configuration: {
 		type: string: {
 			default: "zstd"
 			enum: {
+				brotli: """
+					[Brotli][brotli] compression.
+
+					[brotli]: https://github.com/google/brotli
+					"""
 				gzip: """
 					[Gzip][gzip] compression.
 
 					[gzip]: https://www.gzip.org/
 					"""
+				lz4: """
+					[LZ4][lz4] compression, using the frame format.
+
+					[lz4]: https://github.com/lz4/lz4/blob/dev/doc/lz4_Frame_format.md
+					"""
 				none: "No compression."
 				snappy: """
 					[Snappy][snappy] compression.
@@ -199,6 +209,16 @@ base: components: sinks: honeycomb: configuration: {
 						required: false
 						type: uint: default: 200
 					}
+					min_concurrency: {
+						description: """
+																The minimum concurrency limit.
+
+																The adaptive request concurrency limit does not go below this bound, so that requests keep being sent during a
+																prolonged outage and the limit can recover once the service does.
+																"""
+						required: false
+						type: uint: default: 1
+					}
 					rtt_deviation_scale: {
 						description: """
 																Scale of RTT deviations which are not considered anomalous.
@@ -321,4 +341,16 @@ base: components: sinks: honeycomb: configuration: {
 			}
 		}
 	}
+	sample_rate_field: {
+		description: """
+			The event field holding the sample rate of each event.
+
+			When set, the field is removed from the events, and its value is sent as the sample rate
+			of the event, which Honeycomb uses to weigh the event in its queries. Events without the
+			field, or where it doesn't hold a positive integer, are sent without a sample rate, which
+			Honeycomb treats as a sample rate of `1`.
+			"""
+		required: false
+		type: string: examples: ["sample_rate"]
+	}
 }
@@ -228,6 +228,11 @@ base: components: sinks: kafka: configuration: {
 
 						[logfmt]: https://brandur.org/logfmt
 						"""
+					msgpack: """
+						Encodes an event as [MessagePack][msgpack].
+
+						[msgpack]: https://msgpack.org/
+						"""
 					native: """
 						Encodes an event in the [native Protocol Buffers format][vector_native_protobuf].
 
@@ -388,6 +393,20 @@ base: components: sinks: kafka: configuration: {
 					}
 				}
 			}
+			msgpack: {
+				description:   "MessagePack-specific encoding options."
+				relevant_when: "codec = \"msgpack\""
+				required:      false
+				type: object: options: compact_integers: {
+					description: """
+						Whether to encode integers using the smallest MessagePack integer type that fits the value.
+
+						When disabled, all integers are encoded as signed 64-bit integers.
+						"""
+					required: false
+					type: bool: default: true
+				}
+			}
 			only_fields: {
 				description: "List of fields that are included in the encoded event."
 				required:    false
@@ -434,6 +453,9 @@ base: components: sinks: kafka: configuration: {
 		description: """
 			The log field name to use for the Kafka headers.
 
+			The field must hold an object, whose keys are the names of the headers. The values which
+			aren't strings are written as their string representation.
+
 			If omitted, no headers are written.
 			"""
 		required: false
@@ -462,6 +484,17 @@ base: components: sinks: kafka: configuration: {
 		required: false
 		type: string: examples: ["user_id", ".my_topic", "%my_topic"]
 	}
+	key_vrl: {
+		description: """
+			A VRL program computing the key of each message.
+
+			The value returned by the program is converted to a string. Messages for which the program
+			returns `null`, or fails, are sent without a key. The program can't modify the events.
+			Can't be used along with `key_field`.
+			"""
+		required: false
+		type: string: examples: ["string(to_int!(.tenant_id) % 16)"]
+	}
 	librdkafka_options: {
 		description: """
 			A map of advanced options to pass directly to the underlying `librdkafka` client.
@@ -484,6 +517,18 @@ base: components: sinks: kafka: configuration: {
 			}
 		}
 	}
+	max_header_value_bytes: {
+		description: """
+			The maximum size of the value of a Kafka header, in bytes.
+
+			Longer values read from the `headers_key` field are truncated.
+			"""
+		required: false
+		type: uint: {
+			default: 1024
+			unit:    "bytes"
+		}
+	}
 	message_timeout_ms: {
 		description: "Local message timeout, in milliseconds."
 		required:    false
@@ -547,6 +592,21 @@ base: components: sinks: kafka: configuration: {
 			}
 		}
 	}
+	schema_registry_url: {
+		description: """
+			The URL of a [Confluent Schema Registry][schema_registry] to register the schema of the
+			`avro` codec with.
+
+			When set, the schema is looked up, or registered if it's missing, under the subject named
+			by `subject_name_strategy`, and the messages are prefixed with its ID, following the
+			[wire format][wire_format]. Schema IDs are cached for the lifetime of the sink.
+
+			[schema_registry]: https://docs.confluent.io/platform/current/schema-registry/index.html
+			[wire_format]: https://docs.confluent.io/platform/current/schema-registry/fundamentals/serdes-develop/index.html#wire-format
+			"""
+		required: false
+		type: string: examples: ["http://localhost:8081"]
+	}
 	socket_timeout_ms: {
 		description: "Default timeout, in milliseconds, for network requests."
 		required:    false
@@ -556,6 +616,21 @@ base: components: sinks: kafka: configuration: {
 			unit: "milliseconds"
 		}
 	}
+	subject_name_strategy: {
+		description: "The strategy used to name the subject a schema is registered under."
+		required:    false
+		type: string: {
+			default: "topic_name"
+			enum: {
+				record_name: "The subject is the fully qualified name of the Avro record."
+				topic_name:  "The subject is the name of the topic, suffixed with `-value`."
+				topic_record_name: """
+					The subject is the name of the topic and the fully qualified name of the Avro record,
+					separated by `-`.
+					"""
+			}
+		}
+	}
 	tls: {
 		description: "Configures the TLS options for incoming/outgoing connections."
 		required:    false
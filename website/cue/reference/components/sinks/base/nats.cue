@@ -273,6 +273,11 @@ base: components: sinks: nats: configuration: {
 
 						[logfmt]: https://brandur.org/logfmt
 						"""
+					msgpack: """
+						Encodes an event as [MessagePack][msgpack].
+
+						[msgpack]: https://msgpack.org/
+						"""
 					native: """
 						Encodes an event in the [native Protocol Buffers format][vector_native_protobuf].
 
@@ -433,6 +438,20 @@ base: components: sinks: nats: configuration: {
 					}
 				}
 			}
+			msgpack: {
+				description:   "MessagePack-specific encoding options."
+				relevant_when: "codec = \"msgpack\""
+				required:      false
+				type: object: options: compact_integers: {
+					description: """
+						Whether to encode integers using the smallest MessagePack integer type that fits the value.
+
+						When disabled, all integers are encoded as signed 64-bit integers.
+						"""
+					required: false
+					type: bool: default: true
+				}
+			}
 			only_fields: {
 				description: "List of fields that are included in the encoded event."
 				required:    false
@@ -486,6 +505,23 @@ base: components: sinks: nats: configuration: {
 		required: false
 		type: bool: default: false
 	}
+	max_outstanding_acks: {
+		description: """
+			The maximum number of JetStream publish acknowledgements to wait for at once.
+
+			Events are only considered delivered once JetStream acknowledged them. Raising this
+			limit lets further messages be published while earlier ones are still awaiting their
+			acknowledgement. When `request.concurrency` is left to `none`, the sink sends as many
+			requests concurrently as this limit allows.
+
+			Only used if `jetstream` is enabled.
+			"""
+		required: false
+		type: uint: {
+			default: 1
+			examples: [256]
+		}
+	}
 	request: {
 		description: """
 			Middleware settings for outbound requests.
@@ -550,6 +586,16 @@ base: components: sinks: nats: configuration: {
 						required: false
 						type: uint: default: 200
 					}
+					min_concurrency: {
+						description: """
+																The minimum concurrency limit.
+
+																The adaptive request concurrency limit does not go below this bound, so that requests keep being sent during a
+																prolonged outage and the limit can recover once the service does.
+																"""
+						required: false
+						type: uint: default: 1
+					}
 					rtt_deviation_scale: {
 						description: """
 																Scale of RTT deviations which are not considered anomalous.
@@ -249,11 +249,21 @@ base: components: sinks: opentelemetry: configuration: protocol: {
 			type: string: {
 				default: "none"
 				enum: {
+					brotli: """
+						[Brotli][brotli] compression.
+
+						[brotli]: https://github.com/google/brotli
+						"""
 					gzip: """
 						[Gzip][gzip] compression.
 
 						[gzip]: https://www.gzip.org/
 						"""
+					lz4: """
+						[LZ4][lz4] compression, using the frame format.
+
+						[lz4]: https://github.com/lz4/lz4/blob/dev/doc/lz4_Frame_format.md
+						"""
 					none: "No compression."
 					snappy: """
 						[Snappy][snappy] compression.
@@ -419,6 +429,11 @@ base: components: sinks: opentelemetry: configuration: protocol: {
 
 																			[logfmt]: https://brandur.org/logfmt
 																			"""
+						msgpack: """
+																			Encodes an event as [MessagePack][msgpack].
+
+																			[msgpack]: https://msgpack.org/
+																			"""
 						native: """
 																			Encodes an event in the [native Protocol Buffers format][vector_native_protobuf].
 
@@ -579,6 +594,20 @@ base: components: sinks: opentelemetry: configuration: protocol: {
 						}
 					}
 				}
+				msgpack: {
+					description:   "MessagePack-specific encoding options."
+					relevant_when: "codec = \"msgpack\""
+					required:      false
+					type: object: options: compact_integers: {
+						description: """
+							Whether to encode integers using the smallest MessagePack integer type that fits the value.
+
+							When disabled, all integers are encoded as signed 64-bit integers.
+							"""
+						required: false
+						type: bool: default: true
+					}
+				}
 				only_fields: {
 					description: "List of fields that are included in the encoded event."
 					required:    false
@@ -646,7 +675,7 @@ base: components: sinks: opentelemetry: configuration: protocol: {
 							type: bool: default: true
 						}
 						length_field_length: {
-							description: "Number of bytes representing the field length"
+							description: "Number of bytes representing the field length, from 1 to 8"
 							required:    false
 							type: uint: default: 4
 						}
@@ -792,6 +821,16 @@ base: components: sinks: opentelemetry: configuration: protocol: {
 							required: false
 							type: uint: default: 200
 						}
+						min_concurrency: {
+							description: """
+																				The minimum concurrency limit.
+
+																				The adaptive request concurrency limit does not go below this bound, so that requests keep being sent during a
+																				prolonged outage and the limit can recover once the service does.
+																				"""
+							required: false
+							type: uint: default: 1
+						}
 						rtt_deviation_scale: {
 							description: """
 																				Scale of RTT deviations which are not considered anomalous.
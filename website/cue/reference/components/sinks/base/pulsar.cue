@@ -107,6 +107,22 @@ base: components: sinks: pulsar: configuration: {
 			}
 		}
 	}
+	batch_builder_type: {
+		description: "How messages are grouped into batches."
+		required:    false
+		type: string: {
+			default: "default"
+			enum: {
+				default: "Messages sent to the same topic are batched together, regardless of their key."
+				key_based: """
+					Only messages with the same partition key are batched together.
+
+					The pending batch of a topic is sent as soon as a message with a different key is sent to
+					it, so that `key_shared` subscriptions dispatch every message to the consumer of its key.
+					"""
+			}
+		}
+	}
 	compression: {
 		description: "Supported compression types for Pulsar."
 		required:    false
@@ -161,6 +177,20 @@ base: components: sinks: pulsar: configuration: {
 			}
 		}
 	}
+	delay_secs: {
+		description: """
+			The delay, in seconds, before messages are delivered to consumers.
+
+			Delayed delivery is only honored by the broker for `shared` and `key_shared` subscriptions.
+
+			If omitted, messages are delivered immediately.
+			"""
+		required: false
+		type: uint: {
+			examples: [60]
+			unit: "seconds"
+		}
+	}
 	encoding: {
 		description: """
 			Encoding configuration.
@@ -307,6 +337,11 @@ base: components: sinks: pulsar: configuration: {
 
 						[logfmt]: https://brandur.org/logfmt
 						"""
+					msgpack: """
+						Encodes an event as [MessagePack][msgpack].
+
+						[msgpack]: https://msgpack.org/
+						"""
 					native: """
 						Encodes an event in the [native Protocol Buffers format][vector_native_protobuf].
 
@@ -467,6 +502,20 @@ base: components: sinks: pulsar: configuration: {
 					}
 				}
 			}
+			msgpack: {
+				description:   "MessagePack-specific encoding options."
+				relevant_when: "codec = \"msgpack\""
+				required:      false
+				type: object: options: compact_integers: {
+					description: """
+						Whether to encode integers using the smallest MessagePack integer type that fits the value.
+
+						When disabled, all integers are encoded as signed 64-bit integers.
+						"""
+					required: false
+					type: bool: default: true
+				}
+			}
 			only_fields: {
 				description: "List of fields that are included in the encoded event."
 				required:    false
@@ -531,6 +580,21 @@ base: components: sinks: pulsar: configuration: {
 		required: false
 		type: string: examples: ["message", "my_field"]
 	}
+	partitioning_key: {
+		description: """
+			The template used to render the partition key of each event.
+
+			Can't be used together with `partition_key_field`. Events for which the template can't be
+			rendered are dropped.
+
+			If omitted, the key is taken from `partition_key_field`.
+			"""
+		required: false
+		type: string: {
+			examples: ["{{ .user_id }}", "{{ .host }}-{{ .service }}"]
+			syntax: "template"
+		}
+	}
 	producer_name: {
 		description: "The name of the producer. If not specified, the default name assigned by Pulsar is used."
 		required:    false
@@ -0,0 +1,280 @@
+package metadata
+
+base: components: sinks: syslog: configuration: {
+	acknowledgements: {
+		description: """
+			Controls how acknowledgements are handled for this sink.
+
+			See [End-to-end Acknowledgements][e2e_acks] for more information on how event acknowledgement is handled.
+
+			[e2e_acks]: https://vector.dev/docs/architecture/end-to-end-acknowledgements/
+			"""
+		required: false
+		type: object: options: enabled: {
+			description: """
+				Whether or not end-to-end acknowledgements are enabled.
+
+				When enabled for a sink, any source that supports end-to-end
+				acknowledgements that is connected to that sink waits for events
+				to be acknowledged by **all connected sinks** before acknowledging them at the source.
+
+				Enabling or disabling acknowledgements at the sink level takes precedence over any global
+				[`acknowledgements`][global_acks] configuration.
+
+				[global_acks]: https://vector.dev/docs/reference/configuration/global-options/#acknowledgements
+				"""
+			required: false
+			type: bool: {}
+		}
+	}
+	address: {
+		description: """
+			The address to connect to.
+
+			Both IP address and hostname are accepted formats.
+
+			The address _must_ include a port.
+			"""
+		relevant_when: "mode = \"tcp\" or mode = \"udp\""
+		required:      true
+		type: string: examples: ["92.12.333.224:5000", "https://somehost:5000"]
+	}
+	app_name: {
+		description: "The name of the application that emitted the messages."
+		required:    false
+		type: string: {
+			default: "vector"
+			examples: ["{{ application }}", "vector"]
+			syntax: "template"
+		}
+	}
+	default_severity: {
+		description: """
+			The severity of the messages of events without a valid severity.
+
+			The severity of an event is taken from the field with the `severity` meaning, which can
+			hold either a severity name, such as `warning`, or its number.
+			"""
+		required: false
+		type: string: {
+			default: "informational"
+			enum: {
+				alert:         "Action must be taken immediately."
+				critical:      "Critical conditions."
+				debug:         "Debug-level messages."
+				emergency:     "The system is unusable."
+				error:         "Error conditions."
+				informational: "Informational messages."
+				notice:        "Normal but significant conditions."
+				warning:       "Warning conditions."
+			}
+		}
+	}
+	facility: {
+		description: """
+			The facility of the messages.
+
+			This can be either a facility name, such as `local0`, or its number. Events for which the
+			facility can't be rendered, or isn't valid, are sent with the `user` facility.
+			"""
+		required: false
+		type: string: {
+			default: "user"
+			examples: ["local0", "{{ facility }}"]
+			syntax: "template"
+		}
+	}
+	format: {
+		description: "The format of the syslog messages."
+		required:    false
+		type: string: {
+			default: "rfc5424"
+			enum: {
+				rfc3164: """
+					The [BSD syslog protocol][rfc3164].
+
+					Messages look like `<PRI>TIMESTAMP HOSTNAME APP-NAME[PROCID]: MSG`.
+
+					[rfc3164]: https://datatracker.ietf.org/doc/html/rfc3164
+					"""
+				rfc5424: """
+					The [syslog protocol][rfc5424].
+
+					Messages look like `<PRI>1 TIMESTAMP HOSTNAME APP-NAME PROCID MSGID STRUCTURED-DATA MSG`.
+
+					[rfc5424]: https://datatracker.ietf.org/doc/html/rfc5424
+					"""
+			}
+		}
+	}
+	keepalive: {
+		description:   "TCP keepalive settings for socket-based components."
+		relevant_when: "mode = \"tcp\""
+		required:      false
+		type: object: options: time_secs: {
+			description: "The time to wait before starting to send TCP keepalive probes on an idle connection."
+			required:    false
+			type: uint: unit: "seconds"
+		}
+	}
+	mode: {
+		description: "The type of socket to use."
+		required:    true
+		type: string: enum: {
+			tcp: """
+				Send over TCP, optionally with TLS.
+
+				Messages are delimited by newlines.
+				"""
+			udp: """
+				Send over UDP.
+
+				Each message is sent in its own datagram.
+				"""
+		}
+	}
+	msg_id: {
+		description: """
+			The type of the messages.
+
+			Only used with the `rfc5424` format. If unset, messages have no message identifier.
+			"""
+		required: false
+		type: string: {
+			examples: ["{{ event_type }}"]
+			syntax: "template"
+		}
+	}
+	proc_id: {
+		description: """
+			The identifier of the process that emitted the messages.
+
+			If unset, messages have no process identifier.
+			"""
+		required: false
+		type: string: {
+			examples: ["{{ pid }}"]
+			syntax: "template"
+		}
+	}
+	send_buffer_bytes: {
+		description: """
+			The size of the socket's send buffer.
+
+			If set, the value of the setting is passed via the `SO_SNDBUF` option.
+			"""
+		relevant_when: "mode = \"tcp\" or mode = \"udp\""
+		required:      false
+		type: uint: {
+			examples: [
+				65536,
+			]
+			unit: "bytes"
+		}
+	}
+	tls: {
+		description:   "Configures the TLS options for incoming/outgoing connections."
+		relevant_when: "mode = \"tcp\""
+		required:      false
+		type: object: options: {
+			alpn_protocols: {
+				description: """
+					Sets the list of supported ALPN protocols.
+
+					Declare the supported ALPN protocols, which are used during negotiation with a peer. They are prioritized in the order
+					that they are defined.
+					"""
+				required: false
+				type: array: items: type: string: examples: ["h2"]
+			}
+			ca_file: {
+				description: """
+					Absolute path to an additional CA certificate file.
+
+					The certificate must be in the DER or PEM (X.509) format. Additionally, the certificate can be provided as an inline string in PEM format.
+					"""
+				required: false
+				type: string: examples: ["/path/to/certificate_authority.crt"]
+			}
+			crt_file: {
+				description: """
+					Absolute path to a certificate file used to identify this server.
+
+					The certificate must be in DER, PEM (X.509), or PKCS#12 format. Additionally, the certificate can be provided as
+					an inline string in PEM format.
+
+					If this is set _and_ is not a PKCS#12 archive, `key_file` must also be set.
+					"""
+				required: false
+				type: string: examples: ["/path/to/host_certificate.crt"]
+			}
+			enabled: {
+				description: """
+					Whether or not to require TLS for incoming or outgoing connections.
+
+					When enabled and used for incoming connections, an identity certificate is also required. See `tls.crt_file` for
+					more information.
+					"""
+				required: false
+				type: bool: {}
+			}
+			key_file: {
+				description: """
+					Absolute path to a private key file used to identify this server.
+
+					The key must be in DER or PEM (PKCS#8) format. Additionally, the key can be provided as an inline string in PEM format.
+					"""
+				required: false
+				type: string: examples: ["/path/to/host_certificate.key"]
+			}
+			key_pass: {
+				description: """
+					Passphrase used to unlock the encrypted key file.
+
+					This has no effect unless `key_file` is set.
+					"""
+				required: false
+				type: string: examples: ["${KEY_PASS_ENV_VAR}", "PassWord1"]
+			}
+			server_name: {
+				description: """
+					Server name to use when using Server Name Indication (SNI).
+
+					Only relevant for outgoing connections.
+					"""
+				required: false
+				type: string: examples: ["www.example.com"]
+			}
+			verify_certificate: {
+				description: """
+					Enables certificate verification. For components that create a server, this requires that the
+					client connections have a valid client certificate. For components that initiate requests,
+					this validates that the upstream has a valid certificate.
+
+					If enabled, certificates must not be expired and must be issued by a trusted
+					issuer. This verification operates in a hierarchical manner, checking that the leaf certificate (the
+					certificate presented by the client/server) is not only valid, but that the issuer of that certificate is also valid, and
+					so on, until the verification process reaches a root certificate.
+
+					Do NOT set this to `false` unless you understand the risks of not verifying the validity of certificates.
+					"""
+				required: false
+				type: bool: {}
+			}
+			verify_hostname: {
+				description: """
+					Enables hostname verification.
+
+					If enabled, the hostname used to connect to the remote host must be present in the TLS certificate presented by
+					the remote host, either as the Common Name or as an entry in the Subject Alternative Name extension.
+
+					Only relevant for outgoing connections.
+
+					Do NOT set this to `false` unless you understand the risks of not verifying the remote hostname.
+					"""
+				required: false
+				type: bool: {}
+			}
+		}
+	}
+}
@@ -33,9 +33,9 @@ base: components: sinks: vector: configuration: {
 
 			Both IP address and hostname are accepted formats.
 
-			The address _must_ include a port.
+			The address _must_ include a port. This is required with the `tcp` transport.
 			"""
-		required: true
+		required: false
 		type: string: examples: ["92.12.333.224:6000", "https://somehost:6000"]
 	}
 	batch: {
@@ -145,6 +145,16 @@ base: components: sinks: vector: configuration: {
 						required: false
 						type: uint: default: 200
 					}
+					min_concurrency: {
+						description: """
+																The minimum concurrency limit.
+
+																The adaptive request concurrency limit does not go below this bound, so that requests keep being sent during a
+																prolonged outage and the limit can recover once the service does.
+																"""
+						required: false
+						type: uint: default: 1
+					}
 					rtt_deviation_scale: {
 						description: """
 																Scale of RTT deviations which are not considered anomalous.
@@ -267,6 +277,15 @@ base: components: sinks: vector: configuration: {
 			}
 		}
 	}
+	socket_path: {
+		description: """
+			The path of the Unix domain socket of the downstream Vector instance.
+
+			This is required with the `uds` transport, and should be an absolute path.
+			"""
+		required: false
+		type: string: examples: ["/var/run/vector.sock"]
+	}
 	tls: {
 		description: "Configures the TLS options for incoming/outgoing connections."
 		required:    false
@@ -371,4 +390,20 @@ base: components: sinks: vector: configuration: {
 			}
 		}
 	}
+	transport: {
+		description: "The transport to connect to the downstream Vector instance over."
+		required:    false
+		type: string: {
+			default: "tcp"
+			enum: {
+				tcp: "Connect over TCP, to `address`."
+				uds: """
+					Connect over a Unix domain socket, at `socket_path`.
+
+					Connections are reestablished with an exponential backoff if the socket is unreachable.
+					This is only supported on Unix platforms.
+					"""
+			}
+		}
+	}
 }
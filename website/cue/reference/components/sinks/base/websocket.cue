@@ -204,6 +204,18 @@ base: components: sinks: websocket: configuration: {
 			}
 		}
 	}
+	buffer_size: {
+		description: """
+			The maximum number of frames waiting to be sent to the remote peer.
+
+			When set, events keep being read while the frames can't be sent, such as while
+			reconnecting, and the oldest frames are dropped once this many are waiting. Otherwise, no
+			events are read until the pending frame is sent, which applies backpressure to the
+			upstream components.
+			"""
+		required: false
+		type: uint: examples: [1000]
+	}
 	encoding: {
 		description: """
 			Encoding configuration.
@@ -350,6 +362,11 @@ base: components: sinks: websocket: configuration: {
 
 						[logfmt]: https://brandur.org/logfmt
 						"""
+					msgpack: """
+						Encodes an event as [MessagePack][msgpack].
+
+						[msgpack]: https://msgpack.org/
+						"""
 					native: """
 						Encodes an event in the [native Protocol Buffers format][vector_native_protobuf].
 
@@ -510,6 +527,20 @@ base: components: sinks: websocket: configuration: {
 					}
 				}
 			}
+			msgpack: {
+				description:   "MessagePack-specific encoding options."
+				relevant_when: "codec = \"msgpack\""
+				required:      false
+				type: object: options: compact_integers: {
+					description: """
+						Whether to encode integers using the smallest MessagePack integer type that fits the value.
+
+						When disabled, all integers are encoded as signed 64-bit integers.
+						"""
+					required: false
+					type: bool: default: true
+				}
+			}
 			only_fields: {
 				description: "List of fields that are included in the encoded event."
 				required:    false
@@ -229,6 +229,11 @@ base: components: sinks: websocket_server: configuration: {
 
 						[logfmt]: https://brandur.org/logfmt
 						"""
+					msgpack: """
+						Encodes an event as [MessagePack][msgpack].
+
+						[msgpack]: https://msgpack.org/
+						"""
 					native: """
 						Encodes an event in the [native Protocol Buffers format][vector_native_protobuf].
 
@@ -389,6 +394,20 @@ base: components: sinks: websocket_server: configuration: {
 					}
 				}
 			}
+			msgpack: {
+				description:   "MessagePack-specific encoding options."
+				relevant_when: "codec = \"msgpack\""
+				required:      false
+				type: object: options: compact_integers: {
+					description: """
+						Whether to encode integers using the smallest MessagePack integer type that fits the value.
+
+						When disabled, all integers are encoded as signed 64-bit integers.
+						"""
+					required: false
+					type: bool: default: true
+				}
+			}
 			only_fields: {
 				description: "List of fields that are included in the encoded event."
 				required:    false
@@ -566,6 +585,13 @@ base: components: sinks: websocket_server: configuration: {
 
 																							[json]: https://www.json.org/
 																							"""
+										msgpack: """
+																							Decodes the raw bytes as [MessagePack][msgpack].
+
+																							A top-level array is decoded as one event per element.
+
+																							[msgpack]: https://msgpack.org/
+																							"""
 										native: """
 																							Decodes the raw bytes as [native Protocol Buffers format][vector_native_protobuf].
 
@@ -80,4 +80,9 @@ components: sinks: clickhouse: {
 		metrics: null
 		traces:  false
 	}
+
+	telemetry: metrics: {
+		clickhouse_inserted_rows_total: components.sources.internal_metrics.output.metrics.clickhouse_inserted_rows_total
+		clickhouse_written_rows_total:  components.sources.internal_metrics.output.metrics.clickhouse_written_rows_total
+	}
 }
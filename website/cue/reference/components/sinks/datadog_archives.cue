@@ -0,0 +1,121 @@
+package metadata
+
+components: sinks: datadog_archives: components._aws & {
+	title: "Datadog Archives"
+
+	classes: {
+		commonly_used: false
+		delivery:      "at_least_once"
+		development:   "beta"
+		egress_method: "batch"
+		service_providers: ["AWS", "Datadog"]
+		stateful: false
+	}
+
+	features: {
+		acknowledgements: true
+		auto_generated:   true
+		healthcheck: enabled: true
+		send: {
+			batch: {
+				enabled:      true
+				common:       false
+				max_bytes:    100000000
+				timeout_secs: 900.0
+			}
+			compression: enabled: false
+			encoding: enabled:    false
+			proxy: enabled:       true
+			request: {
+				enabled: true
+				headers: false
+			}
+			tls: {
+				enabled:                true
+				can_verify_certificate: true
+				can_verify_hostname:    true
+				enabled_default:        true
+				enabled_by_scheme:      true
+			}
+			to: {
+				service: services.aws_s3
+
+				interface: {
+					socket: {
+						api: {
+							title: "AWS S3 API"
+							url:   urls.aws_s3_endpoints
+						}
+						direction: "outgoing"
+						protocols: ["http"]
+						ssl: "required"
+					}
+				}
+			}
+		}
+	}
+
+	support: {
+		requirements: []
+		notices: []
+		warnings: []
+	}
+
+	configuration: base.components.sinks.datadog_archives.configuration & {
+		_aws_include: false
+	}
+
+	input: {
+		logs:    true
+		metrics: null
+		traces:  false
+	}
+
+	how_it_works: {
+		archive_format: {
+			title: "Archive format"
+			body:  """
+				Log events are written in the format of [Datadog log archives](\(urls.datadog_log_archives)),
+				so that they can be rehydrated into Datadog. Each archive is a gzipped file holding one
+				JSON object per line, with the following fields:
+
+				* `_id`: a unique identifier generated for the event.
+				* `date`: the timestamp of the event, or the time it was archived if it has none.
+				* `message` and `host`: the message and host of the event.
+				* `service`, `source`, `status`, and `tags`: the fields of the event with these meanings.
+				* `attributes`: all the other fields of the event.
+				"""
+		}
+
+		object_naming: {
+			title: "Object naming"
+			body:  """
+				Log events are grouped by the hour of their timestamp, and each batch is written under
+				the following key:
+
+				```text
+				<prefix>/year=YYYY/month=MM/day=DD/hour=HH/<uuidv4>.json.gz
+				```
+				"""
+		}
+	}
+
+	permissions: iam: [
+		{
+			platform:      "aws"
+			_service:      "s3"
+			_docs_tag:     "AmazonS3"
+			_url_fragment: "API"
+
+			policies: [
+				{
+					_action: "ListBucket"
+					required_for: ["healthcheck"]
+				},
+				{
+					_action: "PutObject"
+				},
+			]
+		},
+	]
+}
@@ -75,5 +75,22 @@ components: sinks: datadog_logs: {
 				If your event contains any of these fields they will be used as described by the [API reference](https://docs.datadoghq.com/api/latest/logs/#send-logs).
 				"""
 		}
+		brotli_compression: {
+			title: "Brotli compression"
+			body: """
+				The `brotli` compression algorithm isn't accepted by Datadog's logs intake. It is intended for use
+				with a custom `endpoint`, such as a proxy or another HTTP service, which accepts requests sent
+				with `Content-Encoding: br`. A warning is logged when it is selected without a custom `endpoint`.
+				"""
+		}
+		lz4_compression: {
+			title: "LZ4 compression"
+			body: """
+				The `lz4` compression algorithm may not be accepted by Datadog's logs intake. It is intended for
+				use with a custom `endpoint`, such as a proxy or another HTTP service, which supports LZ4 frames
+				sent with `Content-Encoding: lz4`. A warning is logged when it is selected without a custom
+				`endpoint`.
+				"""
+		}
 	}
 }
@@ -129,6 +129,20 @@ components: sinks: elasticsearch: {
 				"""
 		}
 
+		index_templates: {
+			title: "Index templates"
+			body: """
+				Vector can create the [index template](\(urls.elasticsearch_index_templates)) that
+				maps the fields of the indices it writes to, instead of creating it outside of Vector.
+				When `manage_templates` is set, Vector creates the template named `template_name` with
+				the JSON in `template_body` when the sink starts, through the first endpoint that accepts it.
+
+				If the template already exists and differs from `template_body`, it's only updated when
+				`auto_update` is enabled. By default, failing to apply the template prevents the sink from
+				starting. Set `fail_on_error` to `false` to log a warning and start the sink anyway.
+				"""
+		}
+
 		partial_failures: {
 			title: "Partial Failures"
 			body:  """
@@ -0,0 +1,93 @@
+package metadata
+
+components: sinks: gcp_chronicle_udm: {
+	title: "GCP Chronicle UDM"
+
+	classes: {
+		commonly_used: true
+		delivery:      "at_least_once"
+		development:   "beta"
+		egress_method: "batch"
+		service_providers: ["GCP"]
+		stateful: false
+	}
+
+	features: {
+		auto_generated:   true
+		acknowledgements: true
+		healthcheck: enabled: true
+		send: {
+			batch: {
+				enabled:      true
+				common:       false
+				max_bytes:    1_000_000
+				max_events:   1_000
+				timeout_secs: 15.0
+			}
+			compression: enabled: false
+			encoding: {
+				enabled: true
+				codec: enabled: false
+			}
+			proxy: enabled: true
+			request: {
+				enabled:        true
+				rate_limit_num: 1000
+				headers:        false
+			}
+			tls: {
+				enabled:                true
+				can_verify_certificate: true
+				can_verify_hostname:    true
+				enabled_default:        true
+				enabled_by_scheme:      true
+			}
+			to: {
+				service: services.gcp_chronicle
+
+				interface: {
+					socket: {
+						api: {
+							title: "GCP XML Interface"
+							url:   urls.gcp_xml_interface
+						}
+						direction: "outgoing"
+						protocols: ["http"]
+						ssl: "required"
+					}
+				}
+			}
+		}
+	}
+
+	support: {
+		requirements: []
+		warnings: []
+		notices: []
+	}
+
+	configuration: base.components.sinks.gcp_chronicle_udm.configuration
+
+	input: {
+		logs:    true
+		metrics: null
+		traces:  false
+	}
+
+	how_it_works: {
+		udm_events: {
+			title: "UDM events"
+			body: """
+				Each log event is sent as a [Unified Data Model][udm] event, so its fields must follow the
+				UDM schema, which can be done with a `remap` transform. The `log_type` is set as the
+				`metadata.log_type` of the events, and their timestamp as their `metadata.event_timestamp`,
+				unless those fields are already set.
+
+				A request contains at most 1000 events and 1 MB of data, which are the limits of the
+				Chronicle Ingestion API, so larger batches are split into multiple requests.
+
+				[udm]: https://cloud.google.com/chronicle/docs/reference/udm-field-list
+				"""
+		}
+	}
+}
@@ -81,5 +81,14 @@ components: sinks: honeycomb: {
 				curl option and use the key provided with the curl example.
 				"""
 		}
+		batching: {
+			title: "Batching"
+			body: """
+				Each batch is sent to the [batch events API](\(urls.honeycomb_batch)) as a JSON array with an
+				object per event, holding the fields of the event in `data`, its timestamp in `time` and,
+				when `sample_rate_field` is set, its sample rate in `samplerate`. As Honeycomb rejects
+				request bodies larger than 5 MB, `batch.max_bytes` can't be set above 5000000.
+				"""
+		}
 	}
 }
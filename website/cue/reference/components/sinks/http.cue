@@ -37,7 +37,7 @@ components: sinks: http: {
 				codec: {
 					enabled: true
 					framing: true
-					enum: ["json", "text"]
+					enum: ["gelf", "json", "text"]
 				}
 			}
 			proxy: enabled: true
@@ -91,4 +91,21 @@ components: sinks: http: {
 		}
 		traces: true
 	}
+
+	how_it_works: {
+		gelf: {
+			title: "GELF encoding"
+			body:  """
+				With `encoding.codec` set to `gelf`, log events are converted to GELF 1.1 messages. The
+				`short_message`, `host` and `timestamp` fields are taken from the message, host and
+				timestamp of the event, and `level` from the field with the `severity` meaning, either
+				as a numeric level or as a syslog severity name such as `warning`.
+
+				All the other fields become additional fields prefixed with `_`, with nested fields
+				flattened into dotted names and arrays encoded as JSON strings. Fields whose names
+				collide with the reserved fields of GELF, such as `host` or `_id`, are prefixed with
+				`_vector_` instead.
+				"""
+		}
+	}
 }
@@ -64,6 +64,7 @@ components: sinks: nats: {
 	how_it_works: components._nats.how_it_works
 
 	telemetry: metrics: {
+		nats_publish_ack_latency_seconds: components.sources.internal_metrics.output.metrics.nats_publish_ack_latency_seconds
 		send_errors_total: components.sources.internal_metrics.output.metrics.send_errors_total
 	}
 }
@@ -0,0 +1,104 @@
+package metadata
+
+components: sinks: opentelemetry_metrics: {
+	title: "OpenTelemetry Metrics"
+
+	classes: {
+		commonly_used: false
+		delivery:      "at_least_once"
+		development:   "beta"
+		egress_method: "batch"
+		stateful:      false
+	}
+
+	features: {
+		auto_generated:   true
+		acknowledgements: true
+		healthcheck: enabled: true
+		send: {
+			batch: {
+				enabled:      true
+				common:       false
+				max_bytes:    1_000_000
+				max_events:   1_000
+				timeout_secs: 1.0
+			}
+			compression: enabled: false
+			encoding: enabled:    false
+			proxy: enabled:       true
+			request: {
+				enabled: true
+				headers: false
+			}
+			tls: {
+				enabled:                true
+				can_verify_certificate: true
+				can_verify_hostname:    true
+				enabled_default:        false
+				enabled_by_scheme:      true
+			}
+			to: {
+				service: services.opentelemetry
+
+				interface: {
+					socket: {
+						direction: "outgoing"
+						protocols: ["http"]
+						ssl: "optional"
+					}
+				}
+			}
+		}
+	}
+
+	input: {
+		logs: false
+		metrics: {
+			counter:      true
+			distribution: false
+			gauge:        true
+			histogram:    true
+			set:          false
+			summary:      true
+		}
+		traces: false
+	}
+
+	support: {
+		requirements: []
+		warnings: []
+		notices: []
+	}
+
+	configuration: base.components.sinks.opentelemetry_metrics.configuration
+
+	how_it_works: {
+		metric_types: {
+			title: "Metric types"
+			body: """
+				Metrics are exported as an `ExportMetricsServiceRequest`, encoded with protobuf, in the
+				`vector` instrumentation scope. Their tags become the attributes of their data points, and
+				their namespace is prepended to their name, separated by a `.`.
+
+				| Vector metric                 | OTLP metric                                 |
+				|-------------------------------|---------------------------------------------|
+				| Counter                       | Monotonic sum                               |
+				| Absolute gauge                | Gauge                                       |
+				| Incremental gauge             | Non-monotonic sum, with delta temporality   |
+				| Aggregated histogram          | Histogram                                   |
+				| Aggregated summary            | Summary                                     |
+
+				Incremental metrics have a delta aggregation temporality, and absolute ones a cumulative
+				temporality. Other metric types are dropped.
+				"""
+		}
+		transport: {
+			title: "Transport"
+			body: """
+				Metrics are sent over HTTP to the `endpoint` URL by default, with the
+				`application/x-protobuf` content type. Setting `grpc_mode` to `true` sends them with the
+				gRPC `MetricsService` instead.
+				"""
+		}
+	}
+}
@@ -0,0 +1,99 @@
+package metadata
+
+components: sinks: syslog: {
+	title: "Syslog"
+
+	classes: {
+		commonly_used: false
+		delivery:      "best_effort"
+		development:   "beta"
+		egress_method: "stream"
+		service_providers: []
+		stateful: false
+	}
+
+	features: {
+		acknowledgements: true
+		auto_generated:   true
+		healthcheck: enabled: true
+		send: {
+			compression: enabled: false
+			encoding: enabled:    false
+			send_buffer_bytes: {
+				enabled:       true
+				relevant_when: "mode = `tcp` or mode = `udp`"
+			}
+			keepalive: enabled: true
+			request: enabled:   false
+			tls: {
+				enabled:                true
+				can_verify_certificate: true
+				can_verify_hostname:    true
+				enabled_default:        false
+				enabled_by_scheme:      false
+			}
+			to: {
+				service: services.syslog
+
+				interface: {
+					socket: {
+						direction: "outgoing"
+						protocols: ["tcp", "udp"]
+						ssl: "optional"
+					}
+				}
+			}
+		}
+	}
+
+	support: {
+		requirements: []
+		warnings: []
+		notices: []
+	}
+
+	configuration: base.components.sinks.syslog.configuration
+
+	input: {
+		logs:    true
+		metrics: null
+		traces:  false
+	}
+
+	how_it_works: {
+		message_fields: {
+			title: "Message fields"
+			body:  """
+				The fields of the syslog messages are taken from the fields of the events with the
+				matching semantic meanings:
+
+				- The body of the message is the `message` of the event, or the whole event encoded
+				  as JSON if it has none.
+				- The hostname is the `host` of the event, or the hostname of the machine Vector runs
+				  on.
+				- The timestamp is the `timestamp` of the event, or the time the event is sent.
+				- The [severity](\(urls.syslog_levels)) is the `severity` of the event, either as a
+				  name or a number, or `default_severity`.
+
+				The [facility](\(urls.syslog_facility)), application name, process identifier and
+				message identifier are rendered from the `facility`, `app_name`, `proc_id` and
+				`msg_id` templates. Header fields are truncated to the lengths allowed by
+				[RFC 5424](\(urls.syslog_5424)), and characters other than printable ASCII
+				characters are replaced with `_`.
+				"""
+		}
+
+		formats: {
+			title: "Formats"
+			body:  """
+				Messages follow either the [RFC 3164](\(urls.syslog_3164)) or the
+				[RFC 5424](\(urls.syslog_5424)) format, depending on the `format` option. RFC 3164
+				timestamps are written in UTC, without the year, while RFC 5424 timestamps are
+				written in RFC 3339 format. RFC 5424 messages have no structured data.
+
+				Over TCP, messages are delimited by newlines, following the non-transparent framing
+				of [RFC 6587](\(urls.syslog_6587)).
+				"""
+		}
+	}
+}
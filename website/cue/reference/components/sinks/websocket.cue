@@ -79,6 +79,22 @@ components: sinks: websocket: {
 		traces: true
 	}
 
+	how_it_works: {
+		buffering: {
+			title: "Buffering"
+			body: """
+				Events are encoded into frames as they are read, and the frames are sent in order over a
+				single connection, which is re-established with an exponential backoff when it drops. A
+				frame is only considered delivered once it's written to the connection, and frames that
+				couldn't be written are sent again after reconnecting.
+
+				By default, no events are read while a frame is waiting to be sent. When `buffer_size` is
+				set, events keep being read while frames are waiting, so that the sink never applies
+				backpressure, and the oldest frames are dropped once the buffer is full.
+				"""
+		}
+	}
+
 	telemetry: metrics: {
 		open_connections:             components.sources.internal_metrics.output.metrics.open_connections
 		connection_established_total: components.sources.internal_metrics.output.metrics.connection_established_total
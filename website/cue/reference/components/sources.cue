@@ -201,6 +201,13 @@ components: sources: [Name=string]: {
 											[rfc3164]: https://www.ietf.org/rfc/rfc3164.txt
 											[rfc5424]: https://www.ietf.org/rfc/rfc5424.txt
 											"""
+										msgpack: """
+											Decodes the raw bytes as [MessagePack][msgpack].
+
+											A top-level array is decoded as one event per element.
+
+											[msgpack]: https://msgpack.org/
+											"""
 										native: """
 											Decodes the raw bytes as Vector’s [native Protocol Buffers format][vector_native_protobuf].
 
@@ -239,6 +239,13 @@ base: components: sources: aws_s3: configuration: {
 
 															[json]: https://www.json.org/
 															"""
+						msgpack: """
+															Decodes the raw bytes as [MessagePack][msgpack].
+
+															A top-level array is decoded as one event per element.
+
+															[msgpack]: https://msgpack.org/
+															"""
 						native: """
 															Decodes the raw bytes as [native Protocol Buffers format][vector_native_protobuf].
 
@@ -537,7 +544,7 @@ base: components: sources: aws_s3: configuration: {
 						type: bool: default: true
 					}
 					length_field_length: {
-						description: "Number of bytes representing the field length"
+						description: "Number of bytes representing the field length, from 1 to 8"
 						required:    false
 						type: uint: default: 4
 					}
@@ -624,16 +631,34 @@ base: components: sources: aws_s3: configuration: {
 
 					This setting must be configured in conjunction with `mode`.
 					"""
-				required: true
+				required: false
 				type: string: examples: ["^[\\s]+", "\\\\$", "^(INFO|ERROR) ", ";$"]
 			}
+			flush_condition_vrl: {
+				description: """
+					A [Vector Remap Language][vrl] (VRL) condition that is used to determine when the buffered
+					lines are flushed as a message.
+
+					The condition is evaluated each time a line is added to the buffer. The event it runs
+					against is the buffered lines joined with newlines, and the number of buffered lines is
+					available as `%accumulated_lines`. When the condition returns `true`, the buffer is
+					flushed.
+
+					This setting can't be configured in conjunction with `start_pattern`, `condition_pattern`
+					and `mode`.
+
+					[vrl]: https://vector.dev/docs/reference/vrl
+					"""
+				required: false
+				type: string: examples: ["ends_with(string!(.), \"}\")", "%accumulated_lines >= 10"]
+			}
 			mode: {
 				description: """
 					Aggregation mode.
 
 					This setting must be configured in conjunction with `condition_pattern`.
 					"""
-				required: true
+				required: false
 				type: string: enum: {
 					continue_past: """
 						All consecutive lines matching this pattern, plus one additional line, are included in the group.
@@ -663,7 +688,7 @@ base: components: sources: aws_s3: configuration: {
 			}
 			start_pattern: {
 				description: "Regular expression pattern that is used to match the start of a new message."
-				required:    true
+				required:    false
 				type: string: examples: ["^[\\s]+", "\\\\$", "^(INFO|ERROR) ", ";$"]
 			}
 			timeout_ms: {
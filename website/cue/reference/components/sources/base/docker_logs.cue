@@ -94,16 +94,34 @@ base: components: sources: docker_logs: configuration: {
 
 					This setting must be configured in conjunction with `mode`.
 					"""
-				required: true
+				required: false
 				type: string: examples: ["^[\\s]+", "\\\\$", "^(INFO|ERROR) ", ";$"]
 			}
+			flush_condition_vrl: {
+				description: """
+					A [Vector Remap Language][vrl] (VRL) condition that is used to determine when the buffered
+					lines are flushed as a message.
+
+					The condition is evaluated each time a line is added to the buffer. The event it runs
+					against is the buffered lines joined with newlines, and the number of buffered lines is
+					available as `%accumulated_lines`. When the condition returns `true`, the buffer is
+					flushed.
+
+					This setting can't be configured in conjunction with `start_pattern`, `condition_pattern`
+					and `mode`.
+
+					[vrl]: https://vector.dev/docs/reference/vrl
+					"""
+				required: false
+				type: string: examples: ["ends_with(string!(.), \"}\")", "%accumulated_lines >= 10"]
+			}
 			mode: {
 				description: """
 					Aggregation mode.
 
 					This setting must be configured in conjunction with `condition_pattern`.
 					"""
-				required: true
+				required: false
 				type: string: enum: {
 					continue_past: """
 						All consecutive lines matching this pattern, plus one additional line, are included in the group.
@@ -133,7 +151,7 @@ base: components: sources: docker_logs: configuration: {
 			}
 			start_pattern: {
 				description: "Regular expression pattern that is used to match the start of a new message."
-				required:    true
+				required:    false
 				type: string: examples: ["^[\\s]+", "\\\\$", "^(INFO|ERROR) ", ";$"]
 			}
 			timeout_ms: {
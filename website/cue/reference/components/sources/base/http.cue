@@ -151,6 +151,13 @@ base: components: sources: http: configuration: {
 
 						[json]: https://www.json.org/
 						"""
+					msgpack: """
+						Decodes the raw bytes as [MessagePack][msgpack].
+
+						A top-level array is decoded as one event per element.
+
+						[msgpack]: https://msgpack.org/
+						"""
 					native: """
 						Decodes the raw bytes as [native Protocol Buffers format][vector_native_protobuf].
 
@@ -448,7 +455,7 @@ base: components: sources: http: configuration: {
 						type: bool: default: true
 					}
 					length_field_length: {
-						description: "Number of bytes representing the field length"
+						description: "Number of bytes representing the field length, from 1 to 8"
 						required:    false
 						type: uint: default: 4
 					}
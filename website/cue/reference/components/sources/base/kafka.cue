@@ -130,6 +130,13 @@ base: components: sources: kafka: configuration: {
 
 															[json]: https://www.json.org/
 															"""
+						msgpack: """
+															Decodes the raw bytes as [MessagePack][msgpack].
+
+															A top-level array is decoded as one event per element.
+
+															[msgpack]: https://msgpack.org/
+															"""
 						native: """
 															Decodes the raw bytes as [native Protocol Buffers format][vector_native_protobuf].
 
@@ -311,6 +318,51 @@ base: components: sources: kafka: configuration: {
 			}
 		}
 	}
+	dedup_capacity: {
+		description: """
+			The number of distinct keys expected every `dedup_window_secs / 2` seconds.
+
+			The deduplication filters are sized for this many keys. The rate of false positives rises
+			above `dedup_false_positive_rate` when more keys are seen.
+			"""
+		required: false
+		type: uint: default: 100000
+	}
+	dedup_false_positive_rate: {
+		description: """
+			The expected rate of false positives of the deduplication filters, which drop events with a
+			key that wasn't seen before.
+
+			Must be greater than zero and lower than one.
+			"""
+		required: false
+		type: float: default: 0.001
+	}
+	dedup_field: {
+		description: """
+			The field holding the key events are deduplicated by.
+
+			By default, events are deduplicated by the key of the Kafka message they were decoded from.
+			Events without a key are never dropped as duplicates.
+			"""
+		required: false
+		type: string: examples: ["id", ".metadata.event_id"]
+	}
+	dedup_window_secs: {
+		description: """
+			The time window, in seconds, within which events with an already seen key are dropped as
+			duplicates.
+
+			Messages can be consumed more than once, such as after a consumer group rebalance or a
+			crash. When set, the keys of the events consumed are tracked in a pair of bloom filters,
+			the oldest of which is discarded every `dedup_window_secs / 2` seconds. A key is therefore
+			remembered for at least half of the window, and at most the whole window.
+
+			By default, events are not deduplicated.
+			"""
+		required: false
+		type: uint: examples: [600]
+	}
 	drain_timeout_ms: {
 		description: """
 			Timeout to drain pending acknowledgements during shutdown or a Kafka
@@ -438,7 +490,7 @@ base: components: sources: kafka: configuration: {
 						type: bool: default: true
 					}
 					length_field_length: {
-						description: "Number of bytes representing the field length"
+						description: "Number of bytes representing the field length, from 1 to 8"
 						required:    false
 						type: uint: default: 4
 					}
@@ -641,6 +693,20 @@ base: components: sources: kafka: configuration: {
 			}
 		}
 	}
+	schema_registry_url: {
+		description: """
+			The URL of a [Confluent Schema Registry][schema_registry] to decode Avro messages with.
+
+			When set, messages are expected to be prefixed with the ID of their schema, following the
+			[wire format][wire_format], and are decoded with that schema, which is fetched from the
+			registry and cached. Can't be used along with `decoding`.
+
+			[schema_registry]: https://docs.confluent.io/platform/current/schema-registry/index.html
+			[wire_format]: https://docs.confluent.io/platform/current/schema-registry/fundamentals/serdes-develop/index.html#wire-format
+			"""
+		required: false
+		type: string: examples: ["http://localhost:8081"]
+	}
 	session_timeout_ms: {
 		description: "The Kafka session timeout."
 		required:    false
@@ -779,6 +845,34 @@ base: components: sources: kafka: configuration: {
 			]
 		}
 	}
+	topic_outputs: {
+		description: """
+			Routes events from specific topics to named outputs.
+
+			Events are sent to the output of the first entry with a topic matching the topic they were
+			consumed from. Events from topics that don't match any entry are sent to the default output.
+			"""
+		required: false
+		type: array: {
+			default: []
+			items: type: object: options: {
+				output: {
+					description: "The name of the output events from `topics` are sent to."
+					required:    true
+					type: string: examples: ["orders"]
+				}
+				topics: {
+					description: """
+						The topics whose events are sent to `output`.
+
+						Regular expression syntax is supported if the topic begins with `^`.
+						"""
+					required: true
+					type: array: items: type: string: examples: ["^(prefix1|prefix2)-.+", "topic-1"]
+				}
+			}
+		}
+	}
 	topics: {
 		description: """
 			The Kafka topics names to read events from.
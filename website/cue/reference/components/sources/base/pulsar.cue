@@ -179,6 +179,13 @@ base: components: sources: pulsar: configuration: {
 
 															[json]: https://www.json.org/
 															"""
+						msgpack: """
+															Decodes the raw bytes as [MessagePack][msgpack].
+
+															A top-level array is decoded as one event per element.
+
+															[msgpack]: https://msgpack.org/
+															"""
 						native: """
 															Decodes the raw bytes as [native Protocol Buffers format][vector_native_protobuf].
 
@@ -468,7 +475,7 @@ base: components: sources: pulsar: configuration: {
 						type: bool: default: true
 					}
 					length_field_length: {
-						description: "Number of bytes representing the field length"
+						description: "Number of bytes representing the field length, from 1 to 8"
 						required:    false
 						type: uint: default: 4
 					}
@@ -552,11 +559,39 @@ base: components: sources: pulsar: configuration: {
 		required: false
 		type: int: {}
 	}
+	start_position: {
+		description: """
+			The position in the topic at which a newly created subscription starts consuming.
+
+			This has no effect on a subscription that already exists, which resumes from its last acknowledged position.
+			"""
+		required: false
+		type: string: {
+			default: "latest"
+			enum: {
+				earliest: "Start from the oldest message available in the topic."
+				latest:   "Start from the next message published to the topic."
+			}
+		}
+	}
 	subscription_name: {
 		description: "The Pulsar subscription name."
 		required:    false
 		type: string: examples: ["subscription_name"]
 	}
+	subscription_type: {
+		description: "The Pulsar subscription type."
+		required:    false
+		type: string: {
+			default: "shared"
+			enum: {
+				exclusive:  "Only a single consumer is allowed to attach to the subscription."
+				failover:   "Only one consumer receives messages at a time, with the remaining consumers acting as standbys."
+				key_shared: "Messages are distributed across consumers, with all messages sharing a key delivered to the same consumer."
+				shared:     "Messages are distributed across all consumers attached to the subscription in a round-robin fashion."
+			}
+		}
+	}
 	tls: {
 		description: "TLS options configuration for the Pulsar client."
 		required:    false
@@ -2,7 +2,7 @@ package metadata
 
 base: components: sources: redis: configuration: {
 	data_type: {
-		description: "The Redis data type (`list` or `channel`) to use."
+		description: "The Redis data type (`list`, `channel` or `stream`) to use."
 		required:    false
 		type: string: {
 			default: "list"
@@ -13,6 +13,11 @@ base: components: sources: redis: configuration: {
 					This is based on Redis' Pub/Sub capabilities.
 					"""
 				list: "The `list` data type."
+				stream: """
+					The `stream` data type.
+
+					Each stream entry is read as a log event, with the fields of the entry as its fields.
+					"""
 			}
 		}
 	}
@@ -91,6 +96,13 @@ base: components: sources: redis: configuration: {
 
 															[json]: https://www.json.org/
 															"""
+						msgpack: """
+															Decodes the raw bytes as [MessagePack][msgpack].
+
+															A top-level array is decoded as one event per element.
+
+															[msgpack]: https://msgpack.org/
+															"""
 						native: """
 															Decodes the raw bytes as [native Protocol Buffers format][vector_native_protobuf].
 
@@ -375,7 +387,7 @@ base: components: sources: redis: configuration: {
 						type: bool: default: true
 					}
 					length_field_length: {
-						description: "Number of bytes representing the field length"
+						description: "Number of bytes representing the field length, from 1 to 8"
 						required:    false
 						type: uint: default: 4
 					}
@@ -449,11 +461,17 @@ base: components: sources: redis: configuration: {
 		}
 	}
 	key: {
-		description: "The Redis key to read messages from."
-		required:    true
-		type: string: examples: [
-			"vector",
-		]
+		description: """
+			The Redis key to read messages from.
+
+			With the `stream` data type, this is the stream to read entries from if
+			`stream.stream_keys` isn't set.
+			"""
+		required: false
+		type: string: {
+			default: ""
+			examples: ["vector"]
+		}
 	}
 	list: {
 		description: "Options for the Redis `list` data type."
@@ -478,6 +496,56 @@ base: components: sources: redis: configuration: {
 		required: false
 		type: string: examples: ["redis_key"]
 	}
+	stream: {
+		description: "Options for the Redis `stream` data type."
+		required:    false
+		type: object: options: {
+			auto_create_group: {
+				description: """
+					Whether to create the consumer group when it doesn't exist.
+
+					The group is created with `XGROUP CREATE ... MKSTREAM` when the source starts, which also
+					creates the streams that don't exist. It reads the entries added after its creation.
+					"""
+				required: false
+				type: bool: default: false
+			}
+			consumer_name: {
+				description: """
+					The name of the consumer in the consumer group.
+
+					If not set, the hostname is used.
+					"""
+				required: false
+				type: string: examples: ["vector-1"]
+			}
+			group_name: {
+				description: """
+					The name of the consumer group to read entries with.
+
+					If set, entries are read with `XREADGROUP` and acknowledged with `XACK` once they're sent
+					to the next components. Entries left pending by a previous run of the consumer are read
+					first.
+
+					Otherwise, entries added to the streams after the source starts are read with `XREAD`.
+					"""
+				required: false
+				type: string: examples: ["vector"]
+			}
+			stream_keys: {
+				description: """
+					The keys of the streams to read entries from.
+
+					If not set, entries are read from the stream in `key`.
+					"""
+				required: false
+				type: array: {
+					default: []
+					items: type: string: examples: ["logs"]
+				}
+			}
+		}
+	}
 	url: {
 		description: """
 			The Redis URL to connect to.
@@ -26,10 +26,31 @@ base: components: sources: vector: configuration: {
 		description: """
 			The socket address to listen for connections on.
 
-			It _must_ include a port.
+			It _must_ include a port. This is required with the `tcp` transport.
 			"""
-		required: true
-		type: string: {}
+		required: false
+		type: string: examples: ["0.0.0.0:6000"]
+	}
+	mode: {
+		description: """
+			Unix file mode bits to be applied to the socket file as its designated file permissions.
+
+			This is only used with the `uds` transport.
+
+			Note: The file mode value can be specified in any numeric format supported by your
+			configuration language, but it is most intuitive to use an octal number.
+			"""
+		required: false
+		type: uint: examples: [511, 384, 508]
+	}
+	socket_path: {
+		description: """
+			The path of the Unix domain socket to listen for connections on.
+
+			This is required with the `uds` transport, and should be an absolute path.
+			"""
+		required: false
+		type: string: examples: ["/var/run/vector.sock"]
 	}
 	tls: {
 		description: "Configures the TLS options for incoming/outgoing connections."
@@ -135,6 +156,21 @@ base: components: sources: vector: configuration: {
 			}
 		}
 	}
+	transport: {
+		description: "The transport to accept connections over."
+		required:    false
+		type: string: {
+			default: "tcp"
+			enum: {
+				tcp: "Accept connections over TCP, on `address`."
+				uds: """
+					Accept connections over a Unix domain socket, at `socket_path`.
+
+					This is only supported on Unix platforms.
+					"""
+			}
+		}
+	}
 	version: {
 		description: "Version of the configuration."
 		required:    false
@@ -366,6 +366,41 @@ components: sources: file: {
 							a timestamp sequence.
 						"""#
 				},
+				{
+					title: "Example 4: Flush Condition"
+					body: #"""
+						Some messages are easier to delimit with a condition on the whole
+						message than with patterns on each line, such as JSON objects
+						spread over several lines:
+
+						```text
+						{
+						  "status": "ok"
+						}
+						```
+
+						To consume these lines as a single event, use the following Vector
+						configuration:
+
+						```toml
+						[sources.my_file_source]
+						type = "file"
+						# ...
+
+						[sources.my_file_source.multiline]
+						flush_condition_vrl = 'ends_with(string!(.), "}") || %accumulated_lines >= 100'
+						timeout_ms = 1000
+						```
+
+						* `flush_condition_vrl` is a VRL condition evaluated each time a
+							line is buffered. `.` is the buffered lines joined with newlines,
+							and `%accumulated_lines` is the number of buffered lines. Vector
+							flushes the buffer as a single event once the condition is `true`.
+
+						`flush_condition_vrl` can't be used with `start_pattern`,
+						`condition_pattern` and `mode`.
+						"""#
+				},
 			]
 		}
 
@@ -0,0 +1,117 @@
+package metadata
+
+components: sources: grpc: {
+	_port: 50051
+
+	title: "gRPC"
+
+	description: """
+		Receives logs from \(services.grpc.name) clients calling a method defined by a user-provided
+		protobuf schema.
+		"""
+
+	classes: {
+		commonly_used: false
+		delivery:      "at_least_once"
+		deployment_roles: ["aggregator"]
+		development:   "beta"
+		egress_method: "stream"
+		stateful:      false
+	}
+
+	features: {
+		auto_generated:   true
+		acknowledgements: true
+		multiline: enabled: false
+		receive: {
+			from: {
+				service: services.grpc
+
+				interface: socket: {
+					direction: "incoming"
+					port:      _port
+					protocols: ["http"]
+					ssl: "optional"
+				}
+			}
+			receive_buffer_bytes: enabled: false
+			keepalive: enabled:            true
+			tls: {
+				enabled:                true
+				can_verify_certificate: true
+				enabled_default:        false
+			}
+		}
+	}
+
+	support: {
+		requirements: []
+		warnings: []
+		notices: []
+	}
+
+	installation: {
+		platform_name: null
+	}
+
+	configuration: base.components.sources.grpc.configuration
+
+	output: logs: event: {
+		description: "An event decoded from a request message"
+		fields: {
+			source_type: {
+				description: "The name of the source type."
+				required:    true
+				type: string: {
+					examples: ["grpc"]
+				}
+			}
+			timestamp: fields._current_timestamp
+			"*": {
+				description: "The fields of the request message."
+				required:    true
+				type: "*": {}
+			}
+		}
+	}
+
+	how_it_works: {
+		decoding: {
+			title: "Decoding request messages"
+			body: """
+				The source serves a single method, named by the `service_method` option, of a service defined
+				in the [protobuf](\(urls.protobuf)) descriptor set read from `proto_descriptor_file`. Calls to
+				any other method are rejected with the `UNIMPLEMENTED` status.
+
+				Each request message is decoded into a log event, whose fields are the fields of the message,
+				with nested messages as objects and repeated fields as arrays. Fields holding their default
+				values are omitted, as they aren't sent by clients.
+				"""
+		}
+		replies: {
+			title: "Replies"
+			body: """
+				Unary calls are replied to with a response message holding only default values, and the
+				response stream of server-streaming calls is closed without sending messages, once the event
+				is accepted. With acknowledgements enabled, the reply is sent once the event is delivered, and
+				calls whose event fails to be delivered are replied to with an error
+				[status](\(urls.grpc_status_code)). Client-streaming and bidirectional-streaming methods aren't
+				supported.
+				"""
+		}
+		auth: {
+			title: "Authentication"
+			body: """
+				When the `auth` option is set, calls must hold the configured token in the metadata key set by
+				`auth.metadata_key`, which defaults to `authorization`. Other calls are rejected with the
+				`UNAUTHENTICATED` status.
+				"""
+		}
+	}
+
+	telemetry: metrics: {
+		grpc_server_handler_duration_seconds: components.sources.internal_metrics.output.metrics.grpc_server_handler_duration_seconds
+		grpc_server_messages_received_total:  components.sources.internal_metrics.output.metrics.grpc_server_messages_received_total
+		grpc_server_messages_sent_total:      components.sources.internal_metrics.output.metrics.grpc_server_messages_sent_total
+	}
+}
@@ -135,15 +135,27 @@ components: sources: internal_metrics: {
 			default_namespace: "vector"
 			tags:              _component_tags
 		}
+		adaptive_concurrency_decrease_total: {
+			description:       "The total number of times the adaptive concurrency limit was decreased."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
 		adaptive_concurrency_in_flight: {
 			description:       "The number of outbound requests currently awaiting a response."
 			type:              "histogram"
 			default_namespace: "vector"
 			tags:              _component_tags
 		}
+		adaptive_concurrency_increase_total: {
+			description:       "The total number of times the adaptive concurrency limit was increased."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
 		adaptive_concurrency_limit: {
-			description:       "The concurrency limit that the adaptive concurrency feature has decided on for this current window."
-			type:              "histogram"
+			description:       "The concurrency limit that the adaptive concurrency feature has most recently decided on."
+			type:              "gauge"
 			default_namespace: "vector"
 			tags:              _component_tags
 		}
@@ -153,6 +165,18 @@ components: sources: internal_metrics: {
 			default_namespace: "vector"
 			tags:              _component_tags
 		}
+		adaptive_concurrency_rtt_p50_ms: {
+			description:       "The median round-trip time (RTT) of requests in the most recent window, in milliseconds."
+			type:              "gauge"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
+		adaptive_concurrency_rtt_p90_ms: {
+			description:       "The 90th percentile round-trip time (RTT) of requests in the most recent window, in milliseconds."
+			type:              "gauge"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
 		checkpoints_total: {
 			description:       "The total number of files checkpointed."
 			type:              "counter"
@@ -167,6 +191,18 @@ components: sources: internal_metrics: {
 				file: _file
 			}
 		}
+		clickhouse_inserted_rows_total: {
+			description:       "The total number of rows sent in successful inserts by the `clickhouse` sink."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
+		clickhouse_written_rows_total: {
+			description:       "The total number of rows written by ClickHouse for the successful inserts of the `clickhouse` sink. This is lower than `clickhouse_inserted_rows_total` when inserts are deduplicated."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
 		collect_completed_total: {
 			description:       "The total number of metrics collections completed for this component."
 			type:              "counter"
@@ -223,6 +259,54 @@ components: sources: internal_metrics: {
 			default_namespace: "vector"
 			tags:              _component_tags
 		}
+		datadog_logs_dead_letter_overflow_events_total: {
+			description:       "The total number of events the `datadog_logs` sink dropped because its dead-letter queue was full."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
+		datadog_logs_dead_letter_sent_event_bytes_total: {
+			description:       "The total number of bytes of the events the `datadog_logs` sink sent to its dead-letter queue. These events aren't counted by `component_sent_event_bytes_total`."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
+		datadog_logs_dead_letter_sent_events_total: {
+			description:       "The total number of events the `datadog_logs` sink sent to its dead-letter queue. These events aren't counted by `component_sent_events_total`."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
+		datadog_logs_dynamic_payload_limit_bytes: {
+			description:       "The current maximum uncompressed payload size used by the `datadog_logs` sink, which shrinks when the intake rejects payloads as too large."
+			type:              "gauge"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
+		datadog_logs_fields_normalized_total: {
+			description:       "The total number of non-reserved fields moved under `message` by the `datadog_logs` sink when `conforms_as_agent` is enabled. A sudden increase typically indicates a misconfigured upstream source."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
+		datadog_logs_retry_budget_exceeded_total: {
+			description:       "The total number of requests the `datadog_logs` sink didn't retry because its retry budget was spent."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
+		datadog_logs_timestamp_parse_errors_total: {
+			description:       "The total number of string timestamps the `datadog_logs` sink couldn't parse as RFC 3339, and sent unchanged."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
+		estimated_size_error_bytes: {
+			description:       "The difference between the serialized size of each event and its estimated size, recorded by the `datadog_logs` sink when `track_estimated_size_error` is enabled. Overestimated events are recorded as negative values."
+			type:              "histogram"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
 		k8s_format_picker_edge_cases_total: {
 			description:       "The total number of edge cases encountered while picking format of the Kubernetes log message."
 			type:              "counter"
@@ -682,6 +766,12 @@ components: sources: internal_metrics: {
 			default_namespace: "vector"
 			tags:              _component_tags
 		}
+		nats_publish_ack_latency_seconds: {
+			description:       "The time between publishing a message to NATS JetStream and receiving its acknowledgement."
+			type:              "histogram"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
 		open_connections: {
 			description:       "The number of current open connections to Vector."
 			type:              "gauge"
@@ -694,12 +784,44 @@ components: sources: internal_metrics: {
 			default_namespace: "vector"
 			tags:              _component_tags
 		}
+		redact_fields_masked_total: {
+			description:       "The total number of values redacted by the `redact` transform."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _component_tags & {
+				field: {
+					description: "The path of the field containing the redacted values. Values within arrays are counted for the path of the array."
+					required:    true
+				}
+			}
+		}
+		sampled_events_total: {
+			description:       "The total number of events forwarded by the `sample` transform configured with a `vrl_expression`."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _component_tags & {
+				outcome: {
+					description: "Why the event was forwarded."
+					required:    true
+					enum: {
+						always_pass: "The expression returned `true`."
+						rate_pass:   "The expression returned `false`, and the event was selected by the sampling rate."
+					}
+				}
+			}
+		}
 		send_errors_total: {
 			description:       "The total number of errors sending messages."
 			type:              "counter"
 			default_namespace: "vector"
 			tags:              _component_tags
 		}
+		sessions_evicted_total: {
+			description:       "The number of sessions the aggregate transform evicted because `max_sessions` sessions were tracked."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
 		source_lag_time_seconds: {
 			description:       "The difference between the timestamp recorded in each event and the time when it was ingested, expressed as fractional seconds."
 			type:              "histogram"
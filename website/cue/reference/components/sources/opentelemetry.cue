@@ -249,6 +249,15 @@ components: sources: opentelemetry: {
 	}
 
 	how_it_works: {
+		http_json: {
+			title: "OTLP/HTTP JSON encoding"
+			body:  """
+				The HTTP server accepts logs sent to `/v1/logs` with either the `application/x-protobuf` or
+				the `application/json` content type, and replies with the same encoding. The JSON encoding
+				follows the OTLP specification, with trace and span identifiers as hex strings, and unknown
+				fields are ignored. Metrics and traces are only accepted in the Protobuf encoding.
+				"""
+		}
 		tls: {
 			title: "Transport Layer Security (TLS)"
 			body:  """
@@ -58,6 +58,16 @@ components: sources: prometheus_remote_write: {
 	}
 
 	how_it_works: {
+		request_paths: {
+			title: "Request paths"
+			body: """
+				Write requests are accepted on any path, so remote write clients can be pointed at
+				the `/api/v1/write` path they would use with Prometheus itself, or at the bare
+				address of the source. Request bodies are expected to be snappy compressed unless
+				another `Content-Encoding` is given.
+				"""
+		}
+
 		metric_types: {
 			title: "Metric type interpretation"
 			body: """
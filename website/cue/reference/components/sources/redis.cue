@@ -83,6 +83,15 @@ components: sources: redis: {
 						default: null
 					}
 				}
+				_redis_stream_id: {
+					description: "The ID of the stream entry the event was read from, with the `stream` data type."
+					required:    false
+					common:      false
+					type: string: {
+						examples: ["1526919030474-55"]
+						default: null
+					}
+				}
 			}
 		}
 		metrics: "": {
@@ -102,5 +111,20 @@ components: sources: redis: {
 				API.
 				"""
 		}
+
+		streams: {
+			title: "Streams"
+			body:  """
+				With the `stream` data type, the source reads the entries of the streams in `stream.stream_keys`,
+				or of the stream in `key`. Each entry is read as a log event, with the fields of the entry as its
+				fields and the ID of the entry in the `_redis_stream_id` field.
+
+				Without a consumer group, the source reads the entries added after it starts with `XREAD`. With
+				`stream.group_name`, it reads them with `XREADGROUP` as the consumer `stream.consumer_name`, and
+				acknowledges them with `XACK` once they're sent to the next components. When the source starts,
+				the entries the consumer read without acknowledging them are read again. Set
+				`stream.auto_create_group` to create the consumer group and the streams when they don't exist.
+				"""
+		}
 	}
 }
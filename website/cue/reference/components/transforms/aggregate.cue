@@ -196,5 +196,6 @@ components: transforms: aggregate: {
 		aggregate_events_recorded_total: components.sources.internal_metrics.output.metrics.aggregate_events_recorded_total
 		aggregate_failed_updates:        components.sources.internal_metrics.output.metrics.aggregate_failed_updates
 		aggregate_flushes_total:         components.sources.internal_metrics.output.metrics.aggregate_flushes_total
+		sessions_evicted_total:          components.sources.internal_metrics.output.metrics.sessions_evicted_total
 	}
 }
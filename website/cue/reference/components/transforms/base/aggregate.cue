@@ -32,4 +32,34 @@ base: components: transforms: aggregate: configuration: {
 			}
 		}
 	}
+	max_sessions: {
+		description: """
+			The maximum number of sessions tracked at once.
+
+			When a new session starts while this many sessions are tracked, the least recently updated
+			session is flushed and evicted.
+			"""
+		required: false
+		type: uint: default: 10000
+	}
+	session_expiry_secs: {
+		description: "The time after which a session that received no metrics expires, in seconds."
+		required:    false
+		type: uint: {
+			default: 300
+			unit:    "seconds"
+		}
+	}
+	session_key: {
+		description: """
+			The name of the tag whose value groups metrics into sessions.
+
+			The metrics of a session are aggregated together until no metric arrives for the session
+			for `session_expiry_secs`, when the aggregate of the session is flushed. Sessions are
+			checked for expiry at every flush interval. Metrics without the tag are flushed at every
+			interval.
+			"""
+		required: false
+		type: string: examples: ["user_id"]
+	}
 }
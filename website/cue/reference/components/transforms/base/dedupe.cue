@@ -1,6 +1,60 @@
 package metadata
 
 base: components: transforms: dedupe: configuration: {
+	backend: {
+		description: "The data structure used to remember the events seen by the transform."
+		required:    false
+		type: object: options: {
+			capacity: {
+				description:   "The number of events the filter can hold."
+				relevant_when: "type = \"cuckoo_filter\""
+				required:      true
+				type: uint: examples: [1000000]
+			}
+			false_positive_rate: {
+				description: """
+					The share of new events that may be taken for duplicates.
+
+					Lower rates take more memory per event, as the filter keeps longer fingerprints.
+					"""
+				relevant_when: "type = \"cuckoo_filter\""
+				required:      false
+				type: float: default: 0.01
+			}
+			reset_interval_secs: {
+				description: """
+					How often the filter is emptied, in seconds.
+
+					Entries can't be evicted from the filter one by one, so events are remembered until the
+					next reset. The filter is also emptied early if it gets full.
+					"""
+				relevant_when: "type = \"cuckoo_filter\""
+				required:      false
+				type: uint: {
+					default: 3600
+					unit:    "seconds"
+				}
+			}
+			type: {
+				description: "The data structure used to remember the events seen."
+				required:    true
+				type: string: enum: {
+					cuckoo_filter: """
+						Remembers the fingerprints of events in a Cuckoo filter.
+
+						This takes a few bytes per event, which makes it better suited to a lot of unique events
+						than `lru`, but a small share of new events are taken for duplicates and dropped.
+						"""
+					lru: """
+						Remembers the last `cache.num_events` events in an LRU cache.
+
+						Events are only dropped if they really are duplicates, but every entry holds the
+						matched fields of its event.
+						"""
+				}
+			}
+		}
+	}
 	cache: {
 		description: "Caching configuration for deduplication."
 		required:    false
@@ -0,0 +1,37 @@
+package metadata
+
+base: components: transforms: dynamic_route: configuration: {
+	allow_unknown_outputs: {
+		description: """
+			Whether the expression may return names that are not listed in `outputs`.
+
+			When enabled, events are intentionally dropped from the outputs that don't exist. When
+			disabled, the configuration is rejected if the expression returns the literal name of an
+			output that isn't listed. Names computed from events are then checked at runtime: unknown
+			ones are reported as errors, and events that aren't sent to any listed output are sent to
+			the `_unmatched` output instead.
+			"""
+		required: false
+		type: bool: default: true
+	}
+	outputs: {
+		description: """
+			The names of the outputs the expression can send events to.
+
+			Each output can then be referenced as an input by other components with the name
+			`<transform_name>.<name>`. The `_unmatched` name is reserved and thus cannot be used.
+			"""
+		required: true
+		type: array: items: type: string: examples: ["production", "staging"]
+	}
+	source: {
+		description: """
+			The VRL expression computing the outputs to send each event to.
+
+			It must return the name of an output, or an array of names. Each event is sent to every
+			output returned, and to the `_unmatched` output if the expression fails or returns no name.
+			"""
+		required: true
+		type: string: examples: [".level", "if .env == \"prod\" { \"production\" } else { \"staging\" }"]
+	}
+}
@@ -167,6 +167,16 @@ base: components: transforms: lua: configuration: {
 			}
 		}
 	}
+	unsafe_lua: {
+		description: """
+			Whether the Lua program can access the host.
+
+			By default, the program runs in a sandbox without the `io`, `os` and `package` libraries,
+			and without the `require`, `dofile` and `loadfile` functions.
+			"""
+		required: false
+		type: bool: default: false
+	}
 	version: {
 		description: """
 			Transform API version.
@@ -181,6 +191,7 @@ base: components: transforms: lua: configuration: {
 				This version is deprecated and will be removed in a future version.
 				"""
 			"2": "Lua transform API version 2."
+			"3": "Lua transform API version 3."
 		}
 	}
 }
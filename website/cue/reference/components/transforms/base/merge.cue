@@ -0,0 +1,69 @@
+package metadata
+
+base: components: transforms: merge: configuration: {
+	fields: {
+		description: """
+			The fields whose values are combined when merging events.
+
+			The other fields of the merged event are taken from the first of the merged events.
+			"""
+		required: false
+		type: array: {
+			default: []
+			items: type: object: options: {
+				field: {
+					description: "The path of the field."
+					required:    true
+					type: string: examples: ["message"]
+				}
+				strategy: {
+					description: "How the values of a field are combined."
+					required:    true
+					type: string: enum: {
+						array:     "Collect the values into an array."
+						concat:    "Join the values as strings, delimited with the `separator`."
+						keep_last: "Keep the last value."
+						sum:       "Add up the numeric values."
+					}
+				}
+			}
+		}
+	}
+	flush_condition_vrl: {
+		description: """
+			A [VRL boolean expression][vrl] evaluated against each incoming event.
+
+			When it returns `true`, the event is merged into the events buffered with the same key,
+			and the merged event is flushed.
+
+			[vrl]: https://vector.dev/docs/reference/vrl/
+			"""
+		required: true
+		type: string: examples: ["starts_with(string!(.message), \"END\")"]
+	}
+	key_field: {
+		description: """
+			The field holding the key of the events.
+
+			Events with the same value for this field are merged together, independently of the
+			events with other values. Events without this field are merged together.
+			"""
+		required: true
+		type: string: examples: ["transaction_id", "thread"]
+	}
+	max_buffered_events: {
+		description: """
+			The maximum number of events buffered for a key.
+
+			When this many events are buffered without the flush condition matching, they are merged
+			and flushed anyway.
+			"""
+		required: false
+		type: uint: default: 1000
+	}
+	separator: {
+		description: "The string inserted between the values of fields merged with the `concat` strategy."
+		required:    false
+		type: string: default: "\n"
+	}
+}
@@ -0,0 +1,115 @@
+package metadata
+
+base: components: transforms: redact: configuration: {
+	detect_pii: {
+		description: """
+			Whether to detect and redact common formats of personally identifiable information in
+			every string value of the event.
+
+			Email addresses, phone numbers, US Social Security numbers, and credit card numbers are
+			detected. Detection is heuristic, so it may miss some values or redact unrelated values
+			with the same format.
+			"""
+		required: false
+		type: bool: default: false
+	}
+	fields: {
+		description: """
+			The fields whose values are redacted entirely.
+
+			Non-string values are converted to strings before being redacted, and every value
+			nested within an object or array field is redacted. `null` values are left unchanged.
+			"""
+		required: false
+		type: array: {
+			default: []
+			items: type: string: examples: ["user.email", "password"]
+		}
+	}
+	patterns: {
+		description: """
+			Regular expressions matched against every string value of the event.
+
+			Only the matched parts of the string are redacted.
+			"""
+		required: false
+		type: array: {
+			default: []
+			items: type: string: examples: ["token=[a-zA-Z0-9]+"]
+		}
+	}
+	strategy: {
+		description: "How redacted values are rewritten."
+		required:    false
+		type: object: options: {
+			algorithm: {
+				description:   "The hash algorithm used by the `hash` strategy."
+				relevant_when: "type = \"hash\""
+				required:      false
+				type: string: {
+					default: "sha256"
+					enum: {
+						blake3: "BLAKE3."
+						sha256: "SHA-256."
+					}
+				}
+			}
+			char: {
+				description:   "The character used to replace redacted characters."
+				relevant_when: "type = \"mask\""
+				required:      false
+				type: string: default: "*"
+			}
+			full: {
+				description: """
+					Whether to mask every character.
+
+					By default, only letters and digits are masked, so that the format of the value, such as
+					the `-` in a phone number or the `@` in an email address, is preserved.
+					"""
+				relevant_when: "type = \"mask\""
+				required:      false
+				type: bool: default: false
+			}
+			keep_first: {
+				description:   "The number of leading characters to keep."
+				relevant_when: "type = \"partial\""
+				required:      false
+				type: uint: default: 0
+			}
+			keep_last: {
+				description:   "The number of trailing characters to keep."
+				relevant_when: "type = \"partial\""
+				required:      false
+				type: uint: default: 0
+			}
+			type: {
+				description: "The redaction strategy to use."
+				required:    false
+				type: string: {
+					default: "mask"
+					enum: {
+						hash: """
+							Replace the value with the hex-encoded hash of the value.
+
+							Equal values produce equal hashes, so redacted values can still be correlated.
+							"""
+						mask: "Replace characters with a mask character."
+						partial: """
+							Mask all but the first and last characters of the value with `*`.
+
+							Values too short to keep any characters hidden are masked entirely.
+							"""
+						replace: "Replace the value with a fixed string."
+					}
+				}
+			}
+			value: {
+				description:   "The string that replaces redacted values."
+				relevant_when: "type = \"replace\""
+				required:      true
+				type: string: examples: ["[REDACTED]"]
+			}
+		}
+	}
+}
@@ -59,8 +59,14 @@ base: components: transforms: reduce: configuration: {
 		}
 	}
 	max_events: {
-		description: "The maximum number of events to group together."
-		required:    false
+		description: """
+			The maximum number of events to group together.
+
+			A group is flushed as soon as it holds this many events, whether or not it expired. Groups
+			with fewer events are still flushed by `expire_after_ms`, `end_every_period_ms`, and the
+			`starts_when` and `ends_when` conditions.
+			"""
+		required: false
 		type: uint: {}
 	}
 	merge_strategies: {
@@ -82,23 +88,73 @@ base: components: transforms: reduce: configuration: {
 		type: object: options: "*": {
 			description: "An individual merge strategy."
 			required:    true
-			type: string: enum: {
-				array:          "Append each value to an array."
-				concat:         "Concatenate each string value, delimited with a space."
-				concat_newline: "Concatenate each string value, delimited with a newline."
-				concat_raw:     "Concatenate each string, without a delimiter."
-				discard:        "Discard all but the first value found."
-				flat_unique:    "Create a flattened array of all unique values."
-				longest_array:  "Keep the longest array seen."
-				max:            "Keep the maximum numeric value seen."
-				min:            "Keep the minimum numeric value seen."
-				retain: """
-					Discard all but the last value found.
-
-					Works as a way to coalesce by not retaining `null`.
-					"""
-				shortest_array: "Keep the shortest array seen."
-				sum:            "Sum all numeric values."
+			type: {
+				object: options: percentiles: {
+					description: """
+						Estimate percentiles of all numeric values.
+
+						The field itself is replaced by a field for each percentile, named after the field with the
+						percentile as a suffix. For example, the 95th percentile of `latency` is added as
+						`latency_p95`, and the 99.9th as `latency_p99_9`.
+						"""
+					required: true
+					type: object: options: {
+						digest_type: {
+							description: "The data structure used to estimate percentiles."
+							required:    false
+							type: string: {
+								default: "t_digest"
+								enum: {
+									hdr_histogram: """
+										An [HDR histogram][hdr_histogram], recording values with three significant digits.
+
+										Values are rounded to the nearest integer, and negative values are rejected.
+
+										[hdr_histogram]: https://hdrhistogram.github.io/HdrHistogram/
+										"""
+									t_digest: """
+										A [t-digest][t_digest], which is most accurate for the extreme percentiles.
+
+										[t_digest]: https://github.com/tdunning/t-digest
+										"""
+								}
+							}
+						}
+						max_size: {
+							description: """
+								The maximum number of centroids kept by the t-digest of each group.
+
+								Bounds the memory used by each group, at the cost of accuracy. Only used by the
+								`t_digest` digest type.
+								"""
+							required: false
+							type: uint: default: 100
+						}
+						percentiles: {
+							description: "The percentiles to estimate, between 0 and 100."
+							required:    true
+							type: array: items: type: float: {}
+						}
+					}
+				}
+				string: enum: {
+					array:          "Append each value to an array."
+					concat:         "Concatenate each string value, delimited with a space."
+					concat_newline: "Concatenate each string value, delimited with a newline."
+					concat_raw:     "Concatenate each string, without a delimiter."
+					discard:        "Discard all but the first value found."
+					flat_unique:    "Create a flattened array of all unique values."
+					longest_array:  "Keep the longest array seen."
+					max:            "Keep the maximum numeric value seen."
+					min:            "Keep the minimum numeric value seen."
+					retain: """
+						Discard all but the last value found.
+
+						Works as a way to coalesce by not retaining `null`.
+						"""
+					shortest_array: "Keep the shortest array seen."
+					sum:            "Sum all numeric values."
+				}
 			}
 		}
 	}
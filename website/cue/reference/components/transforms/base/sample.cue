@@ -71,4 +71,15 @@ base: components: transforms: sample: configuration: {
 			examples: ["sample_rate"]
 		}
 	}
+	vrl_expression: {
+		description: """
+			A VRL boolean expression evaluated against each event to decide whether it is sampled.
+
+			Events for which the expression returns `true` are always forwarded, while the other events
+			are sampled at the configured `rate` or `ratio`. The outcome is counted by the
+			`sampled_events_total` metric, with an `outcome` tag of `always_pass` or `rate_pass`.
+			"""
+		required: false
+		type: string: examples: [".level == \"error\""]
+	}
 }
@@ -19,7 +19,8 @@ base: components: transforms: throttle: configuration: {
 				metric.
 
 				Note that this defaults to false because the `key` tag has potentially unbounded cardinality.
-				Only set this to true if you know that the number of unique keys is bounded.
+				Only set this to true if you know that the number of unique keys is bounded. Keys longer
+				than 64 bytes are truncated in the tag.
 				"""
 			required: false
 			type: bool: default: false
@@ -31,6 +32,9 @@ base: components: transforms: throttle: configuration: {
 
 			If left unspecified, or if the event doesn't have `key_field`, then the event is not rate
 			limited separately.
+
+			The bucket of each key is created when its first event comes in, and dropped once the key
+			has been idle for a while to keep the number of buckets bounded.
 			"""
 		required: false
 		type: string: {
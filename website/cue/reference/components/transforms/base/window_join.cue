@@ -0,0 +1,76 @@
+package metadata
+
+base: components: transforms: window_join: configuration: {
+	join_key_fields: {
+		description: """
+			The fields which must be equal for a `left` and a `right` event to be joined.
+
+			Events missing any of these fields never match.
+			"""
+		required: true
+		type: array: items: type: string: examples: ["host", "ip"]
+	}
+	join_type: {
+		description: "The kind of join to perform, which determines what happens to events without a match."
+		required:    false
+		type: string: {
+			default: "inner"
+			enum: {
+				full:  "Joined events are forwarded, as are events from either side which expire without a match."
+				inner: "Only joined events are forwarded. Unmatched events are dropped."
+				left:  "Joined events are forwarded, as are `left` events which expire without a match."
+				right: "Joined events are forwarded, as are `right` events which expire without a match."
+			}
+		}
+	}
+	left: {
+		description: """
+			The components whose events make up the left side of the join.
+
+			Each entry is either a component ID or the ID of a specific component output, such as
+			`my_route.errors`. Every entry must also be listed in `inputs`.
+			"""
+		required: true
+		type: array: items: type: string: examples: ["dns_lookups"]
+	}
+	left_prefix: {
+		description: """
+			A prefix prepended to the name of each top-level field taken from the `left` event.
+
+			When neither prefix is set and both events have a field with the same name, the value from
+			the `right` event is kept.
+			"""
+		required: false
+		type: string: examples: ["dns_"]
+	}
+	max_buffered: {
+		description: """
+			The maximum number of events buffered while waiting for a match, across all keys.
+
+			When the buffer is full, the oldest buffered event is removed to make room for the new one,
+			and is dropped if it was never joined. There is no limit by default.
+			"""
+		required: false
+		type: uint: examples: [100000]
+	}
+	right: {
+		description: """
+			The components whose events make up the right side of the join.
+
+			Each entry is either a component ID or the ID of a specific component output, such as
+			`my_route.errors`. Every entry must also be listed in `inputs`.
+			"""
+		required: true
+		type: array: items: type: string: examples: ["network_connections"]
+	}
+	right_prefix: {
+		description: "A prefix prepended to the name of each top-level field taken from the `right` event."
+		required:    false
+		type: string: examples: ["conn_"]
+	}
+	window_secs: {
+		description: "The maximum time between a `left` and a `right` event for them to be joined, in seconds."
+		required:    true
+		type: float: unit: "seconds"
+	}
+}
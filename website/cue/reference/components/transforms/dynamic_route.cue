@@ -0,0 +1,104 @@
+package metadata
+
+components: transforms: dynamic_route: {
+	title: "Dynamic Route"
+
+	description: """
+		Routes events from one or more streams to sub-streams named by a VRL expression evaluated
+		against each event.
+
+		Also, see the [Route](\(urls.vector_route_transform)) transform for routing events based on a
+		set of conditions.
+		"""
+
+	classes: {
+		commonly_used: false
+		development:   "beta"
+		egress_method: "stream"
+		stateful:      false
+	}
+
+	features: {
+		dynamic_route: {}
+	}
+
+	support: {
+		requirements: []
+		warnings: []
+		notices: []
+	}
+
+	configuration: base.components.transforms.dynamic_route.configuration
+
+	input: {
+		logs: true
+		metrics: {
+			counter:      true
+			distribution: true
+			gauge:        true
+			histogram:    true
+			set:          true
+			summary:      true
+		}
+		traces: true
+	}
+
+	output: {
+		logs: "": {
+			description: "The input `log` event."
+		}
+		metrics: "": {
+			description: "The input `metric` event."
+		}
+		traces: "": {
+			description: "The input `trace` event."
+		}
+	}
+
+	outputs: [
+		{
+			name:        "<output_name>"
+			description: "Each output listed in `outputs` can be referenced as an input by other components with the name `<transform_name>.<output_name>`."
+		},
+		{
+			name:        "_unmatched"
+			description: "Events for which the expression fails or returns no listed output are sent to `<transform_name>._unmatched`."
+		},
+	]
+
+	how_it_works: {
+		routing_by_expression: {
+			title: "Routing by expression"
+			body: """
+				The `source` expression is evaluated against each event, and must return the name of an
+				output or an array of names. The event is sent to every output returned, so that the
+				following configuration sends each event to the output named after its `level` field:
+
+				```yaml
+				transforms:
+					by_level:
+						inputs:
+							- source0
+						type: dynamic_route
+						source: .level
+						outputs:
+							- debug
+							- info
+							- error
+				```
+
+				The outputs must be listed in the configuration, as Vector wires components together
+				when it starts.
+				"""
+		}
+		unknown_outputs: {
+			title: "Unknown outputs"
+			body: """
+				By default, events are intentionally dropped from the names returned by the expression
+				that are not listed in `outputs`. When `allow_unknown_outputs` is disabled, these names
+				are reported as errors, and events that aren't sent to any listed output are sent to the
+				`_unmatched` output instead.
+				"""
+		}
+	}
+}
@@ -266,6 +266,18 @@ components: transforms: lua: {
 	]
 
 	how_it_works: {
+		api_version_3: {
+			title: "API Version 3"
+			body:  """
+				With `version = "3"`, the `source` program defines a `process(event)` function, called
+				for each event with the same table representation as the `process` hook. The function
+				returns the modified event, `nil` to drop the event, or an array of events to emit several.
+
+				Events are processed concurrently, each thread running the program in its own Lua state, so
+				global variables aren't shared between threads. The program runs in a sandbox without the
+				`io`, `os` and `package` libraries unless `unsafe_lua` is set.
+				"""
+		}
 		event_data_model: {
 			title: "Event Data Model"
 			body:  """
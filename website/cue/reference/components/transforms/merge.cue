@@ -0,0 +1,82 @@
+package metadata
+
+components: transforms: merge: {
+	title: "Merge"
+
+	description: """
+		Merges consecutive log events sharing a key into a single event, flushed when a VRL
+		condition matches an incoming event.
+		"""
+
+	classes: {
+		commonly_used: false
+		development:   "beta"
+		egress_method: "stream"
+		stateful:      true
+	}
+
+	features: {
+		reduce: {}
+	}
+
+	support: {
+		requirements: []
+		warnings: []
+		notices: []
+	}
+
+	configuration: base.components.transforms.merge.configuration
+
+	input: {
+		logs:    true
+		metrics: null
+		traces:  false
+	}
+
+	output: {
+		logs: "": {
+			description: "The merged log events."
+		}
+	}
+
+	examples: [
+		{
+			title: "Merge transaction logs"
+			input: [
+				{log: {txn: "1", message: "BEGIN", rows: 0}},
+				{log: {txn: "1", message: "UPDATE accounts", rows: 2}},
+				{log: {txn: "1", message: "END", rows: 1}},
+			]
+
+			configuration: {
+				key_field:           "txn"
+				flush_condition_vrl: #".message == "END""#
+				separator:           "; "
+				fields: [
+					{field: "message", strategy: "concat"},
+					{field: "rows", strategy: "sum"},
+				]
+			}
+
+			output: [
+				{log: {txn: "1", message: "BEGIN; UPDATE accounts; END", rows: 3}},
+			]
+		},
+	]
+
+	how_it_works: {
+		buffering: {
+			title: "Buffering"
+			body: """
+				Events are buffered in memory per value of the `key_field` until an incoming event
+				matches the `flush_condition_vrl` expression. That event is merged with the buffered
+				ones, and the merged event is sent right away. The fields listed in `fields` are
+				combined with their strategy, and the other fields are taken from the first event.
+
+				If `max_buffered_events` events are buffered for a key before the condition matches,
+				they are merged and sent anyway, and a warning is logged. The events still buffered
+				when Vector stops are merged and sent as well.
+				"""
+		}
+	}
+}
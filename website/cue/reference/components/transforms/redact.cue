@@ -0,0 +1,90 @@
+package metadata
+
+components: transforms: redact: {
+	title: "Redact"
+
+	description: """
+		Redacts sensitive data, such as passwords or personally identifiable information, from log
+		events without the overhead of running a VRL program.
+		"""
+
+	classes: {
+		commonly_used: false
+		development:   "beta"
+		egress_method: "stream"
+		stateful:      false
+	}
+
+	features: {
+		sanitize: {}
+	}
+
+	support: {
+		requirements: []
+		warnings: []
+		notices: []
+	}
+
+	configuration: base.components.transforms.redact.configuration
+
+	input: {
+		logs:    true
+		metrics: null
+		traces:  false
+	}
+
+	output: {
+		logs: "": {
+			description: "The input `log` event, with sensitive values redacted."
+		}
+	}
+
+	examples: [
+		{
+			title: "Mask fields and detected PII"
+			input: log: {
+				password: "hunter2"
+				message:  "Signup from jane@example.com"
+			}
+			configuration: {
+				fields: ["password"]
+				detect_pii: true
+			}
+			output: log: {
+				password: "*******"
+				message:  "Signup from ****@*******.***"
+			}
+		},
+		{
+			title: "Keep the last digits of a card number"
+			input: log: card: "4111-1111-1111-1111"
+			configuration: {
+				fields: ["card"]
+				strategy: {
+					type:      "partial"
+					keep_last: 4
+				}
+			}
+			output: log: card: "***************1111"
+		},
+	]
+
+	how_it_works: {
+		redaction_scope: {
+			title: "Redaction Scope"
+			body: """
+				Values of the fields listed in `fields` are redacted entirely. `patterns` and
+				`detect_pii` are instead matched against every string value of the event, and only
+				the matched parts of each string are redacted with the configured `strategy`.
+
+				When matches of several patterns overlap, the pattern listed first wins, and the
+				`detect_pii` heuristics come after all of `patterns`. Redacted text is never matched
+				again.
+				"""
+		}
+	}
+
+	telemetry: metrics: {
+		redact_fields_masked_total: components.sources.internal_metrics.output.metrics.redact_fields_masked_total
+	}
+}
@@ -40,4 +40,8 @@ components: transforms: sample: {
 			description: "The modified input `trace` event."
 		}
 	}
+
+	telemetry: metrics: {
+		sampled_events_total: components.sources.internal_metrics.output.metrics.sampled_events_total
+	}
 }
@@ -0,0 +1,92 @@
+package metadata
+
+components: transforms: window_join: {
+	title: "Window Join"
+
+	description: """
+		Correlates events from two streams that share the same key within a time window, producing a
+		single event with the fields of both.
+		"""
+
+	classes: {
+		commonly_used: false
+		development:   "beta"
+		egress_method: "stream"
+		stateful:      true
+	}
+
+	features: {
+		reduce: {}
+	}
+
+	support: {
+		requirements: []
+		warnings: []
+		notices: []
+	}
+
+	configuration: base.components.transforms.window_join.configuration
+
+	input: {
+		logs:    true
+		metrics: null
+		traces:  false
+	}
+
+	output: {
+		logs: "": {
+			description: "Joined `log` events, and unmatched events kept by the `join_type`."
+		}
+	}
+
+	examples: [
+		{
+			title: "Correlate DNS lookups with network connections"
+			input: [
+				{log: {host: "10.0.0.1", query: "example.com"}},
+				{log: {host: "10.0.0.1", port:  443}},
+			]
+
+			configuration: {
+				left: ["dns"]
+				right: ["connections"]
+				join_key_fields: ["host"]
+				window_secs:  10.0
+				right_prefix: "conn_"
+			}
+
+			output: [
+				{log: {host: "10.0.0.1", query: "example.com", conn_host: "10.0.0.1", conn_port: 443}},
+			]
+		},
+	]
+
+	how_it_works: {
+		join_window: {
+			title: "Join Window"
+			body: """
+				Events from both sides are buffered in memory for `window_secs`, grouped by the values of
+				`join_key_fields`. When an event arrives, it is joined with every buffered event from the
+				other side that has the same key, so each pair of matching events within the window is
+				joined exactly once.
+
+				When an event expires without having been joined, it is forwarded on its own if the
+				`join_type` keeps that side, and dropped otherwise. All buffered events are released
+				when Vector shuts down. The buffer is not persistent, so in case of a hard system crash,
+				all the buffered events will be lost.
+
+				Set `max_buffered` to bound the memory used by the buffer. Once it's full, the oldest
+				buffered event makes room for each new one, and is dropped if it was never joined.
+				"""
+		}
+
+		identifying_sides: {
+			title: "Identifying Sides"
+			body: """
+				The side of each event is determined by the component that sent it, so every component
+				listed in `left` and `right` must also be listed in the transform's `inputs`, or the
+				transform fails to start. Events from any other input are dropped.
+				"""
+		}
+	}
+}